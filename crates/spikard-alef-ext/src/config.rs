@@ -6,7 +6,10 @@
 //! accommodate consumer-specific extension sections until alef's config schema
 //! is relaxed.
 
-use crate::ir::{ErrorTypeDef, LifecycleHookDef, SseRouteDef, WebSocketRouteDef};
+use crate::ir::{
+    DataLoaderDef, ErrorTypeDef, HealthCheckDef, LifecycleHookDef, LifespanHookDef, RateLimitKeyExtractorDef,
+    SseRouteDef, WebSocketRouteDef,
+};
 use anyhow::{Context as _, Result};
 use serde::Deserialize;
 
@@ -15,6 +18,14 @@ use serde::Deserialize;
 pub struct HttpExtensionConfig {
     #[serde(default, rename = "lifecycle_hooks")]
     pub lifecycle_hooks: Vec<LifecycleHookDef>,
+    #[serde(default, rename = "lifespan_hooks")]
+    pub lifespan_hooks: Vec<LifespanHookDef>,
+    #[serde(default, rename = "health_checks")]
+    pub health_checks: Vec<HealthCheckDef>,
+    #[serde(default, rename = "rate_limit_key_extractors")]
+    pub rate_limit_key_extractors: Vec<RateLimitKeyExtractorDef>,
+    #[serde(default, rename = "data_loaders")]
+    pub data_loaders: Vec<DataLoaderDef>,
     #[serde(default, rename = "websocket_routes")]
     pub websocket_routes: Vec<WebSocketRouteDef>,
     #[serde(default, rename = "sse_routes")]
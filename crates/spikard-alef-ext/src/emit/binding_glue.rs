@@ -0,0 +1,344 @@
+//! Hand-written binding glue spliced into the backend-generated binding files.
+//!
+//! Some of the binding surface cannot be derived from the Rust API: wrappers over
+//! types that borrow the `App` or hold a guard, methods taking JSON from the host,
+//! and enums flattened into host objects. The Rust items are excluded in `alef.toml`,
+//! and the bindings are spliced in here instead, so `alef generate` reproduces them.
+//!
+//! Each [`Splice`] inserts its code before an anchor in the generated file, and the
+//! splices of a file are listed in file order. A [`Rewrite`] replaces generated code
+//! that is wrong for a binding. Spliced code lives under `templates/<lang>/` as
+//! `<file>_<item>.<ext>.jinja`, emitted verbatim.
+
+use alef::core::backend::GeneratedFile;
+use alef::core::config::Language;
+
+/// Binding code inserted into a generated file ahead of `anchor`.
+pub struct Splice {
+    /// File name of the generated file, e.g. `lib.rs`.
+    pub file: &'static str,
+    /// Generated text the code is inserted before; occurs once in the file.
+    pub anchor: &'static str,
+    /// The inserted code.
+    pub insertion: &'static str,
+}
+
+/// Generated code replaced with hand-written code.
+pub struct Rewrite {
+    /// File name of the generated file, e.g. `lib.rs`.
+    pub file: &'static str,
+    /// Generated text to replace; occurs once in the file.
+    pub from: &'static str,
+    /// The replacement.
+    pub to: &'static str,
+}
+
+/// Glue spliced into the Python (`PyO3`) binding.
+const PYTHON: &[Splice] = &[
+    // `RouteGroup` over an owned `RouteGroupScope`
+    Splice {
+        file: "lib.rs",
+        anchor: "#[derive(Clone, Default, serde::Serialize, serde::Deserialize)]\n#[pyclass(frozen, from_py_object)]\npub struct JsonRpcMethodInfo {",
+        insertion: include_str!("../templates/pyo3/lib_rs_route_group.rs.jinja"),
+    },
+    // `ResponseSnapshot.to_snapshot_json`
+    Splice {
+        file: "lib.rs",
+        anchor: "    #[staticmethod]\n    fn from_json(json_str: String) -> pyo3::PyResult<Self> {\n        serde_json::from_str::<Self>(&json_str).map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))\n    }\n}\n\n#[derive(Clone, Default, serde::Serialize, serde::Deserialize)]\n#[pyclass(frozen, from_py_object)]\npub struct TestingSseEvent {",
+        insertion: include_str!("../templates/pyo3/lib_rs_to_snapshot_json.rs.jinja"),
+    },
+    // `SnapshotOptions` and `SnapshotOutcome`
+    Splice {
+        file: "lib.rs",
+        anchor: "#[derive(Clone, Default, serde::Serialize, serde::Deserialize)]\n#[pyclass(frozen, from_py_object)]\npub struct TestingSseEvent {",
+        insertion: include_str!("../templates/pyo3/lib_rs_snapshot_types.rs.jinja"),
+    },
+    // `TestClient` dependency overrides
+    Splice {
+        file: "lib.rs",
+        anchor: "    #[allow(clippy::missing_errors_doc)]\n    #[pyo3(signature = (endpoint, query, variables=None, operation_name=None))]\n    pub fn graphql_at<'py>(",
+        insertion: include_str!("../templates/pyo3/lib_rs_test_client_overrides.rs.jinja"),
+    },
+    // `DependencyOverrideGuard`
+    Splice {
+        file: "lib.rs",
+        anchor: "#[derive(Clone)]\n#[pyclass(unsendable, from_py_object)]\npub struct HandlerResult {",
+        insertion: include_str!("../templates/pyo3/lib_rs_dependency_override_guard.rs.jinja"),
+    },
+    // `check_snapshot`, `snapshot_path` and `assert_snapshot_file`
+    Splice {
+        file: "lib.rs",
+        anchor: "// Error types\npyo3::create_exception!(_spikard, AppError, pyo3::exceptions::PyException);",
+        insertion: include_str!("../templates/pyo3/lib_rs_snapshot_functions.rs.jinja"),
+    },
+    // Snapshot option and outcome conversions
+    Splice {
+        file: "lib.rs",
+        anchor: "#[allow(clippy::redundant_closure, clippy::useless_conversion)]\nimpl From<spikard_http::testing::SseEvent> for TestingSseEvent {",
+        insertion: include_str!("../templates/pyo3/lib_rs_snapshot_conversions.rs.jinja"),
+    },
+    Splice {
+        file: "lib.rs",
+        anchor: "    m.add_class::<JsonRpcMethodInfo>()?;",
+        insertion: "    m.add_class::<RouteGroup>()?;\n",
+    },
+    Splice {
+        file: "lib.rs",
+        anchor: "    m.add_class::<TestingSseEvent>()?;",
+        insertion: "    m.add_class::<SnapshotOptions>()?;\n    m.add_class::<SnapshotOutcome>()?;\n",
+    },
+    Splice {
+        file: "lib.rs",
+        anchor: "    m.add_class::<HandlerResult>()?;",
+        insertion: "    m.add_class::<DependencyOverrideGuard>()?;\n",
+    },
+    Splice {
+        file: "lib.rs",
+        anchor: "    m.add(\"RouteError\", m.py().get_type::<RouteError>())?;",
+        insertion: "    m.add_function(wrap_pyfunction!(check_snapshot, m)?)?;\n    m.add_function(wrap_pyfunction!(snapshot_path, m)?)?;\n    m.add_function(wrap_pyfunction!(assert_snapshot_file, m)?)?;\n",
+    },
+    Splice {
+        file: "_spikard.pyi",
+        anchor: "\nclass TestingSseEvent:",
+        insertion: "    def to_snapshot_json(self, options: SnapshotOptions | None = None) -> str: ...\n",
+    },
+    // `SnapshotOptions` and `SnapshotOutcome`
+    Splice {
+        file: "_spikard.pyi",
+        anchor: "\nclass TestingSseEvent:",
+        insertion: include_str!("../templates/pyo3/spikard_pyi_snapshot_types.pyi.jinja"),
+    },
+    // `RouteGroup` over an owned `RouteGroupScope`
+    Splice {
+        file: "_spikard.pyi",
+        anchor: "class TestClient:",
+        insertion: include_str!("../templates/pyo3/spikard_pyi_route_group.pyi.jinja"),
+    },
+    Splice {
+        file: "_spikard.pyi",
+        anchor: "    async def graphql_at(",
+        insertion: "    def override_dependency(self, key: str, value: str) -> None: ...\n    def override_dependency_scope(self, key: str, value: str) -> DependencyOverrideGuard: ...\n    def restore_dependency(self, key: str) -> None: ...\n    def clear_dependency_overrides(self) -> None: ...\n",
+    },
+    // `DependencyOverrideGuard`
+    Splice {
+        file: "_spikard.pyi",
+        anchor: "class HandlerResult: ...",
+        insertion: include_str!("../templates/pyo3/spikard_pyi_dependency_override_guard.pyi.jinja"),
+    },
+    // `check_snapshot`, `snapshot_path` and `assert_snapshot_file`
+    Splice {
+        file: "_spikard.pyi",
+        anchor: "def app_run(registrations: list[Any]) -> None: ...",
+        insertion: include_str!("../templates/pyo3/spikard_pyi_snapshot_functions.pyi.jinja"),
+    },
+];
+
+/// Glue spliced into the Node (`NAPI-RS`) binding.
+const NODE: &[Splice] = &[
+    // `RouteGroup` over an owned `RouteGroupScope`
+    Splice {
+        file: "lib.rs",
+        anchor: "/// JSON-RPC method metadata for routes that support JSON-RPC",
+        insertion: include_str!("../templates/napi/lib_rs_route_group.rs.jinja"),
+    },
+    // `SnapshotOptions` and `SnapshotOutcome`
+    Splice {
+        file: "lib.rs",
+        anchor: "/// A single Server-Sent Event.",
+        insertion: include_str!("../templates/napi/lib_rs_snapshot_types.rs.jinja"),
+    },
+    // `TestClient` dependency overrides
+    Splice {
+        file: "lib.rs",
+        anchor: "    ///  a GraphQL query/mutation to a custom endpoint",
+        insertion: include_str!("../templates/napi/lib_rs_test_client_overrides.rs.jinja"),
+    },
+    // `DependencyOverrideGuard`
+    Splice {
+        file: "lib.rs",
+        anchor: "#[derive(Clone)]\n#[napi(js_name = \"HandlerResult\")]",
+        insertion: include_str!("../templates/napi/lib_rs_dependency_override_guard.rs.jinja"),
+    },
+    // `check_snapshot`, `snapshot_path` and `assert_snapshot_file`
+    Splice {
+        file: "lib.rs",
+        anchor: "pub mod service;",
+        insertion: include_str!("../templates/napi/lib_rs_snapshot_functions.rs.jinja"),
+    },
+    // Snapshot option and outcome conversions
+    Splice {
+        file: "lib.rs",
+        anchor: "#[allow(clippy::redundant_closure, clippy::useless_conversion)]\nimpl From<spikard_http::testing::SseEvent> for JsTestingSseEvent {",
+        insertion: include_str!("../templates/napi/lib_rs_snapshot_conversions.rs.jinja"),
+    },
+];
+
+/// Glue spliced into the Ruby (`Magnus`) binding.
+const RUBY: &[Splice] = &[
+    // `RouteGroup` over an owned `RouteGroupScope`
+    Splice {
+        file: "lib.rs",
+        anchor: "#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]\n#[magnus::wrap(class = \"Spikard::JsonRpcMethodInfo\")]",
+        insertion: include_str!("../templates/magnus/lib_rs_route_group.rs.jinja"),
+    },
+    // `ResponseSnapshot.to_snapshot_json`
+    Splice {
+        file: "lib.rs",
+        anchor: "}\n\n#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]\n#[magnus::wrap(class = \"Spikard::TestingSseEvent\")]",
+        insertion: include_str!("../templates/magnus/lib_rs_to_snapshot_json.rs.jinja"),
+    },
+    // `SnapshotOptions` and `SnapshotOutcome`
+    Splice {
+        file: "lib.rs",
+        anchor: "#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]\n#[magnus::wrap(class = \"Spikard::TestingSseEvent\")]",
+        insertion: include_str!("../templates/magnus/lib_rs_snapshot_types.rs.jinja"),
+    },
+    // `DependencyOverrideGuard`
+    Splice {
+        file: "lib.rs",
+        anchor: "#[derive(Clone)]\n#[magnus::wrap(class = \"Spikard::TestClient\")]",
+        insertion: include_str!("../templates/magnus/lib_rs_dependency_override_guard.rs.jinja"),
+    },
+    // `TestClient` dependency overrides
+    Splice {
+        file: "lib.rs",
+        anchor: "    fn graphql_at_async(",
+        insertion: include_str!("../templates/magnus/lib_rs_test_client_overrides.rs.jinja"),
+    },
+    // `check_snapshot`, `snapshot_path` and `assert_snapshot_file`
+    Splice {
+        file: "lib.rs",
+        anchor: "#[allow(clippy::redundant_closure, clippy::useless_conversion)]\nimpl From<spikard::UploadFile> for UploadFile {",
+        insertion: include_str!("../templates/magnus/lib_rs_snapshot_functions.rs.jinja"),
+    },
+    // Snapshot option and outcome conversions
+    Splice {
+        file: "lib.rs",
+        anchor: "impl From<ResponseSnapshot> for spikard_http::ResponseSnapshot {",
+        insertion: include_str!("../templates/magnus/lib_rs_snapshot_conversions.rs.jinja"),
+    },
+    // `RouteGroup` class registration
+    Splice {
+        file: "lib.rs",
+        anchor: "    let class = module.define_class(\"JsonRpcMethodInfo\", ruby.class_object())?;",
+        insertion: include_str!("../templates/magnus/lib_rs_route_group_registration.rs.jinja"),
+    },
+    // Snapshot class registration
+    Splice {
+        file: "lib.rs",
+        anchor: "    let class = module.define_class(\"TestingSseEvent\", ruby.class_object())?;",
+        insertion: include_str!("../templates/magnus/lib_rs_snapshot_registration.rs.jinja"),
+    },
+    // `DependencyOverrideGuard` class registration
+    Splice {
+        file: "lib.rs",
+        anchor: "    let class = module.define_class(\"TestClient\", ruby.class_object())?;",
+        insertion: include_str!("../templates/magnus/lib_rs_dependency_override_guard_registration.rs.jinja"),
+    },
+    // `TestClient` dependency override registration
+    Splice {
+        file: "lib.rs",
+        anchor: "    class.define_method(\"graphql_at_async\", method!(TestClient::graphql_at_async, 4))?;",
+        insertion: include_str!("../templates/magnus/lib_rs_test_client_overrides_registration.rs.jinja"),
+    },
+    // Snapshot function registration
+    Splice {
+        file: "lib.rs",
+        anchor: "    let graph_q_l_error_info_class = module.define_class(\"GraphQLErrorInfo\", ruby.class_object())?;",
+        insertion: include_str!("../templates/magnus/lib_rs_snapshot_functions_registration.rs.jinja"),
+    },
+    // `RouteGroup` over an owned `RouteGroupScope`
+    Splice {
+        file: "types.rbs",
+        anchor: "    class JsonRpcMethodInfo",
+        insertion: include_str!("../templates/magnus/types_rbs_route_group.rbs.jinja"),
+    },
+    Splice {
+        file: "types.rbs",
+        anchor: "    end\n\n    class TestingSseEvent",
+        insertion: "    def to_snapshot_json: (?SnapshotOptions? options) -> String\n",
+    },
+    // `SnapshotOptions` and `SnapshotOutcome`
+    Splice {
+        file: "types.rbs",
+        anchor: "    class TestingSseEvent",
+        insertion: include_str!("../templates/magnus/types_rbs_snapshot_types.rbs.jinja"),
+    },
+    // `DependencyOverrideGuard`
+    Splice {
+        file: "types.rbs",
+        anchor: "    class TestClient",
+        insertion: include_str!("../templates/magnus/types_rbs_dependency_override_guard.rbs.jinja"),
+    },
+    Splice {
+        file: "types.rbs",
+        anchor: "    def graphql_at: (String endpoint, String query, ?json_value variables, ?String operation_name) -> ResponseSnapshot",
+        insertion: "    def override_dependency: (String key, String value) -> void\n    def override_dependency_scope: (String key, String value) -> DependencyOverrideGuard\n    def restore_dependency: (String key) -> void\n    def clear_dependency_overrides: () -> void\n",
+    },
+    // `check_snapshot`, `snapshot_path` and `assert_snapshot_file`
+    Splice {
+        file: "types.rbs",
+        anchor: "    class GraphQLErrorInfo",
+        insertion: include_str!("../templates/magnus/types_rbs_snapshot_functions.rbs.jinja"),
+    },
+];
+
+/// Glue spliced into the PHP (`ext-php-rs`) binding.
+const PHP: &[Splice] = &[
+    // `RouteGroup` over an owned `RouteGroupScope`
+    Splice {
+        file: "lib.rs",
+        anchor: "#[derive(Clone, serde::Serialize, serde::Deserialize, Default)]\n#[php_class]\n#[php(name = \"Spikard\\\\Php\\\\JsonRpcMethodInfo\")]",
+        insertion: include_str!("../templates/php/lib_rs_route_group.rs.jinja"),
+    },
+    Splice {
+        file: "lib.rs",
+        anchor: "            .class::<JsonRpcMethodInfo>()",
+        insertion: "            .class::<RouteGroup>()\n",
+    },
+    // `RouteGroup` over an owned `RouteGroupScope`
+    Splice {
+        file: "spikard_php_extension.php",
+        anchor: "    /**\n     * JSON-RPC method metadata for routes that support JSON-RPC",
+        insertion: include_str!("../templates/php/stubs_php_route_group.php.jinja"),
+    },
+];
+
+/// Rewrites applied to the Node (`NAPI-RS`) binding.
+///
+/// `register_graphql_sdl_route` converts its config through JSON, and the camelCase
+/// `fieldLoaders` key does not deserialize into the core `field_loaders`; the generated
+/// `From` conversion keeps them.
+const NODE_REWRITES: &[Rewrite] = &[Rewrite {
+    file: "lib.rs",
+    from: "        let config_json = serde_json::to_string(&config)\n            .map_err(|e| napi::Error::new(napi::Status::GenericFailure, e.to_string()))?;\n        let config_core: spikard::DynamicSchemaConfig = serde_json::from_str(&config_json)\n            .map_err(|e| napi::Error::new(napi::Status::GenericFailure, e.to_string()))?;\n",
+    to: "        let config_core: spikard::DynamicSchemaConfig = config.into();\n",
+}];
+
+/// Splice the hand-written glue into the generated binding files of `language`.
+///
+/// Idempotent, like [`super::insert_before`]: re-running the transform leaves the files unchanged.
+pub fn wire_binding_glue(files: &mut [GeneratedFile], language: Language) {
+    let splices = match language {
+        Language::Python => PYTHON,
+        Language::Node => NODE,
+        Language::Ruby => RUBY,
+        Language::Php => PHP,
+        _ => return,
+    };
+    let rewrites: &[Rewrite] = match language {
+        Language::Node => NODE_REWRITES,
+        _ => &[],
+    };
+    for file in files.iter_mut() {
+        let Some(name) = file.path.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+        for splice in splices.iter().filter(|splice| splice.file == name) {
+            super::insert_before(&mut file.content, splice.anchor, splice.insertion);
+        }
+        for rewrite in rewrites.iter().filter(|rewrite| rewrite.file == name) {
+            file.content = file.content.replace(rewrite.from, rewrite.to);
+        }
+    }
+}
@@ -1,8 +1,11 @@
-//! Ruby (magnus) emission for HTTP extension: lifecycle hooks, error classes,
-//! WebSocket/SSE methods.
+//! Ruby (magnus) emission for HTTP extension: lifecycle and lifespan hooks, health
+//! checks, rate-limit key extractors, data loaders, error classes, WebSocket/SSE methods.
 
 use crate::config::HttpExtensionConfig;
-use crate::ir::{LifecycleHookDef, SseRouteDef, WebSocketRouteDef};
+use crate::ir::{
+    DataLoaderDef, HealthCheckDef, LifecycleHookDef, LifespanHookDef, RateLimitKeyExtractorDef, SseRouteDef,
+    WebSocketRouteDef,
+};
 use alef::core::backend::GeneratedFile;
 use alef::core::ir::ApiSurface;
 use anyhow::Result;
@@ -31,6 +34,36 @@ fn make_env() -> Environment<'static> {
         include_str!("../templates/magnus/service_rb_lifecycle_hook.rb.jinja").to_owned(),
     )
     .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_rb_lifespan_hook.rb.jinja".to_owned(),
+        include_str!("../templates/magnus/service_rb_lifespan_hook.rb.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_rs_lifespan_bridge.rs.jinja".to_owned(),
+        include_str!("../templates/magnus/service_rs_lifespan_bridge.rs.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_rs_lifespan_arm.rs.jinja".to_owned(),
+        include_str!("../templates/magnus/service_rs_lifespan_arm.rs.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_rb_health_check.rb.jinja".to_owned(),
+        include_str!("../templates/magnus/service_rb_health_check.rb.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_rs_health_check_bridge.rs.jinja".to_owned(),
+        include_str!("../templates/magnus/service_rs_health_check_bridge.rs.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_rs_health_check_arm.rs.jinja".to_owned(),
+        include_str!("../templates/magnus/service_rs_health_check_arm.rs.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
     env.add_template_owned(
         "service_rb_websocket_method.rb.jinja".to_owned(),
         include_str!("../templates/magnus/service_rb_websocket_method.rb.jinja").to_owned(),
@@ -56,6 +89,36 @@ fn make_env() -> Environment<'static> {
         include_str!("../templates/magnus/introspection.rb.jinja").to_owned(),
     )
     .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_rb_rate_limit_key.rb.jinja".to_owned(),
+        include_str!("../templates/magnus/service_rb_rate_limit_key.rb.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_rs_rate_limit_key_bridge.rs.jinja".to_owned(),
+        include_str!("../templates/magnus/service_rs_rate_limit_key_bridge.rs.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_rs_rate_limit_key_arm.rs.jinja".to_owned(),
+        include_str!("../templates/magnus/service_rs_rate_limit_key_arm.rs.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_rb_data_loader.rb.jinja".to_owned(),
+        include_str!("../templates/magnus/service_rb_data_loader.rb.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_rs_data_loader_bridge.rs.jinja".to_owned(),
+        include_str!("../templates/magnus/service_rs_data_loader_bridge.rs.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_rs_data_loader_arm.rs.jinja".to_owned(),
+        include_str!("../templates/magnus/service_rs_data_loader_arm.rs.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
     env
 }
 
@@ -124,6 +187,66 @@ fn gen_lifecycle_hooks(env: &Environment<'static>, out: &mut String, hooks: &[Li
     }
 }
 
+fn gen_lifespan_hooks(env: &Environment<'static>, out: &mut String, hooks: &[LifespanHookDef]) {
+    for hook in hooks {
+        let doc_comment = format_ruby_comment(&hook.doc, 6);
+        out.push_str(&render(
+            env,
+            "service_rb_lifespan_hook.rb.jinja",
+            context! {
+                method_name => &hook.name,
+                doc_comment => doc_comment,
+            },
+        ));
+    }
+}
+
+fn gen_health_checks(env: &Environment<'static>, out: &mut String, checks: &[HealthCheckDef]) {
+    for check in checks {
+        let doc_comment = format_ruby_comment(&check.doc, 6);
+        out.push_str(&render(
+            env,
+            "service_rb_health_check.rb.jinja",
+            context! {
+                method_name => &check.name,
+                doc_comment => doc_comment,
+            },
+        ));
+    }
+}
+
+fn gen_rate_limit_key_extractors(
+    env: &Environment<'static>,
+    out: &mut String,
+    extractors: &[RateLimitKeyExtractorDef],
+) {
+    for extractor in extractors {
+        let doc_comment = format_ruby_comment(&extractor.doc, 6);
+        out.push_str(&render(
+            env,
+            "service_rb_rate_limit_key.rb.jinja",
+            context! {
+                method_name => &extractor.name,
+                doc_comment => doc_comment,
+            },
+        ));
+    }
+}
+
+fn gen_data_loaders(env: &Environment<'static>, out: &mut String, loaders: &[DataLoaderDef]) {
+    for loader in loaders {
+        let doc_comment = format_ruby_comment(&loader.doc, 6);
+        out.push_str(&render(
+            env,
+            "service_rb_data_loader.rb.jinja",
+            context! {
+                method_name => &loader.name,
+                doc_comment => doc_comment,
+            },
+        ));
+    }
+}
+
 fn gen_websocket_methods(env: &Environment<'static>, out: &mut String, routes: &[WebSocketRouteDef]) {
     for ws in routes {
         let doc_comment = format_ruby_comment(&ws.doc, 6);
@@ -195,6 +318,10 @@ fn gen_error_classes(env: &Environment<'static>, api: &ApiSurface, cfg: &HttpExt
 fn gen_service_additions(env: &Environment<'static>, cfg: &HttpExtensionConfig) -> String {
     let mut out = String::new();
     gen_lifecycle_hooks(env, &mut out, &cfg.lifecycle_hooks);
+    gen_lifespan_hooks(env, &mut out, &cfg.lifespan_hooks);
+    gen_health_checks(env, &mut out, &cfg.health_checks);
+    gen_rate_limit_key_extractors(env, &mut out, &cfg.rate_limit_key_extractors);
+    gen_data_loaders(env, &mut out, &cfg.data_loaders);
     gen_websocket_methods(env, &mut out, &cfg.websocket_routes);
     gen_sse_methods(env, &mut out, &cfg.sse_routes);
     out
@@ -239,6 +366,10 @@ pub fn emit(api: &ApiSurface, cfg: &HttpExtensionConfig) -> Result<Vec<Generated
     files.extend(emit_ergonomic(&env));
 
     if !cfg.lifecycle_hooks.is_empty()
+        || !cfg.lifespan_hooks.is_empty()
+        || !cfg.health_checks.is_empty()
+        || !cfg.rate_limit_key_extractors.is_empty()
+        || !cfg.data_loaders.is_empty()
         || !cfg.websocket_routes.is_empty()
         || !cfg.sse_routes.is_empty()
         || !cfg.error_types.is_empty()
@@ -265,6 +396,99 @@ pub fn emit(api: &ApiSurface, cfg: &HttpExtensionConfig) -> Result<Vec<Generated
     Ok(files)
 }
 
+/// Anchor in the generated `service.rs` the lifespan bridges are inserted before.
+const BRIDGE_ANCHOR: &str = "/// Drive `spikard::App::run` from Ruby.";
+
+/// Anchor in the generated `service.rs`: the catch-all arm of the registration
+/// `match` in both `app_run` and `app_into_router`.
+const ARM_ANCHOR: &str = "            _ => {\n                return Err(magnus::Error::new(\n                    ruby.exception_arg_error(),";
+
+/// Wire the `[[lifespan_hooks]]`, `[[health_checks]]`, `[[rate_limit_key_extractors]]` and
+/// `[[data_loaders]]` contracts into the backend-generated Ruby binding.
+///
+/// alef core only knows handler contracts, so these registrations are spliced into
+/// `service.rs`: a GVL-acquiring bridge per callback contract and a registration
+/// `match` arm per callback. The host registration methods are emitted into
+/// `service_http_additions.rb` by [`emit`]. Every splice is idempotent.
+pub fn wire_host_callbacks(files: &mut [GeneratedFile], cfg: &HttpExtensionConfig) {
+    if cfg.lifespan_hooks.is_empty()
+        && cfg.health_checks.is_empty()
+        && cfg.rate_limit_key_extractors.is_empty()
+        && cfg.data_loaders.is_empty()
+    {
+        return;
+    }
+    let env = make_env();
+    let mut bridges = String::new();
+    for contract in super::distinct_contracts(cfg.lifespan_hooks.iter().map(|h| h.callback_contract.as_str())) {
+        bridges.push_str(&render(
+            &env,
+            "service_rs_lifespan_bridge.rs.jinja",
+            context! { contract => contract },
+        ));
+    }
+    for contract in super::distinct_contracts(cfg.health_checks.iter().map(|c| c.callback_contract.as_str())) {
+        bridges.push_str(&render(
+            &env,
+            "service_rs_health_check_bridge.rs.jinja",
+            context! { contract => contract },
+        ));
+    }
+    for contract in super::distinct_contracts(
+        cfg.rate_limit_key_extractors
+            .iter()
+            .map(|e| e.callback_contract.as_str()),
+    ) {
+        bridges.push_str(&render(
+            &env,
+            "service_rs_rate_limit_key_bridge.rs.jinja",
+            context! { contract => contract },
+        ));
+    }
+    for contract in super::distinct_contracts(cfg.data_loaders.iter().map(|l| l.callback_contract.as_str())) {
+        bridges.push_str(&render(
+            &env,
+            "service_rs_data_loader_bridge.rs.jinja",
+            context! { contract => contract },
+        ));
+    }
+    let mut arms = String::new();
+    for hook in &cfg.lifespan_hooks {
+        arms.push_str(&render(
+            &env,
+            "service_rs_lifespan_arm.rs.jinja",
+            context! { hook_name => &hook.name, contract => &hook.callback_contract },
+        ));
+    }
+    for check in &cfg.health_checks {
+        arms.push_str(&render(
+            &env,
+            "service_rs_health_check_arm.rs.jinja",
+            context! { check_name => &check.name, contract => &check.callback_contract },
+        ));
+    }
+    for extractor in &cfg.rate_limit_key_extractors {
+        arms.push_str(&render(
+            &env,
+            "service_rs_rate_limit_key_arm.rs.jinja",
+            context! { extractor_name => &extractor.name, contract => &extractor.callback_contract },
+        ));
+    }
+    for loader in &cfg.data_loaders {
+        arms.push_str(&render(
+            &env,
+            "service_rs_data_loader_arm.rs.jinja",
+            context! { loader_name => &loader.name, contract => &loader.callback_contract },
+        ));
+    }
+    for file in files.iter_mut() {
+        if file.path.file_name().and_then(|n| n.to_str()) == Some("service.rs") {
+            super::insert_before(&mut file.content, BRIDGE_ANCHOR, &bridges);
+            super::insert_before(&mut file.content, ARM_ANCHOR, &arms);
+        }
+    }
+}
+
 /// Ruby impl files that reopen the native `Spikard::App` class and call
 /// runtime-only native methods. Steep type-checks against RBS and cannot see the
 /// native surface, so every one of them yields false positives; they must be
@@ -1,5 +1,6 @@
 //! Per-language emission dispatch for the HTTP extension.
 
+pub mod binding_glue;
 pub mod csharp;
 pub mod dart;
 pub mod go;
@@ -17,18 +18,20 @@ use crate::config::HttpExtensionConfig;
 use alef::core::backend::GeneratedFile;
 use alef::core::config::Language;
 use alef::core::ir::ApiSurface;
-use anyhow::Result;
+use anyhow::{Result, bail};
 
 /// Dispatch to the correct language emitter.
 ///
 /// # Errors
 ///
-/// Returns an error if the selected language emitter fails to render templates.
+/// Returns an error if the selected language emitter fails to render templates, or if
+/// `[[lifespan_hooks]]` are configured for a binding that cannot register them.
 pub fn emit_for_language(
     api: &ApiSurface,
     cfg: &HttpExtensionConfig,
     language: Language,
 ) -> Result<Vec<GeneratedFile>> {
+    check_lifespan_support(cfg, language)?;
     match language {
         Language::Python => pyo3::emit(api, cfg),
         Language::Node => napi::emit(api, cfg),
@@ -45,3 +48,65 @@ pub fn emit_for_language(
         Language::Ffi | Language::Wasm | Language::R | Language::Rust | Language::Gleam | Language::C => Ok(vec![]),
     }
 }
+
+/// Refuse to generate a server binding that would silently drop configured lifespan hooks.
+///
+/// Only the Python, Node, Ruby and PHP bindings register `[[lifespan_hooks]]`; the
+/// remaining server bindings are listed in `docs/adr/0005-lifecycle-hooks.md`.
+///
+/// # Errors
+///
+/// Returns an error naming the hooks when `language` is one of the unsupported bindings.
+pub fn check_lifespan_support(cfg: &HttpExtensionConfig, language: Language) -> Result<()> {
+    let unsupported = matches!(
+        language,
+        Language::Go
+            | Language::Jni
+            | Language::Java
+            | Language::Csharp
+            | Language::Kotlin
+            | Language::KotlinAndroid
+            | Language::Dart
+            | Language::Swift
+            | Language::Zig
+            | Language::Elixir
+    );
+    if unsupported && !cfg.lifespan_hooks.is_empty() {
+        let hooks = cfg
+            .lifespan_hooks
+            .iter()
+            .map(|hook| hook.name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        bail!(
+            "spikard-http.toml declares lifespan hooks ({hooks}), but the {language:?} binding cannot register \
+             them yet; implement its lifespan emitter or drop {language:?} from the generated languages"
+        );
+    }
+    Ok(())
+}
+
+/// Insert `insertion` immediately before every occurrence of `anchor` in `content`.
+///
+/// Used to splice extension-owned code into backend-generated binding files at a
+/// stable anchor (e.g. the catch-all arm of the registration `match`). Idempotent:
+/// content already carrying `insertion` is left untouched, so re-running the
+/// transform never duplicates it. A missing anchor is a no-op.
+pub fn insert_before(content: &mut String, anchor: &str, insertion: &str) {
+    if insertion.is_empty() || content.contains(insertion) {
+        return;
+    }
+    *content = content.replace(anchor, &format!("{insertion}{anchor}"));
+}
+
+/// Distinct callback contracts, in first-registration order, so each bridge type
+/// is emitted once however many hooks or checks share it.
+pub fn distinct_contracts<'a>(contracts: impl IntoIterator<Item = &'a str>) -> Vec<&'a str> {
+    let mut distinct: Vec<&str> = Vec::new();
+    for contract in contracts {
+        if !distinct.contains(&contract) {
+            distinct.push(contract);
+        }
+    }
+    distinct
+}
@@ -43,9 +43,87 @@ fn make_env() -> Environment<'static> {
         include_str!("../templates/napi/service_ts_lifecycle_hook.jinja").to_owned(),
     )
     .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_ts_lifespan_hook.jinja".to_owned(),
+        include_str!("../templates/napi/service_ts_lifespan_hook.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_rs_lifespan_bridge.rs.jinja".to_owned(),
+        include_str!("../templates/napi/service_rs_lifespan_bridge.rs.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_rs_lifespan_method.rs.jinja".to_owned(),
+        include_str!("../templates/napi/service_rs_lifespan_method.rs.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_ts_health_check.jinja".to_owned(),
+        include_str!("../templates/napi/service_ts_health_check.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_rs_health_check_bridge.rs.jinja".to_owned(),
+        include_str!("../templates/napi/service_rs_health_check_bridge.rs.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_rs_health_check_method.rs.jinja".to_owned(),
+        include_str!("../templates/napi/service_rs_health_check_method.rs.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_ts_rate_limit_key.jinja".to_owned(),
+        include_str!("../templates/napi/service_ts_rate_limit_key.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_rs_rate_limit_key_bridge.rs.jinja".to_owned(),
+        include_str!("../templates/napi/service_rs_rate_limit_key_bridge.rs.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_rs_rate_limit_key_method.rs.jinja".to_owned(),
+        include_str!("../templates/napi/service_rs_rate_limit_key_method.rs.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_ts_data_loader.jinja".to_owned(),
+        include_str!("../templates/napi/service_ts_data_loader.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_rs_data_loader_bridge.rs.jinja".to_owned(),
+        include_str!("../templates/napi/service_rs_data_loader_bridge.rs.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_rs_data_loader_method.rs.jinja".to_owned(),
+        include_str!("../templates/napi/service_rs_data_loader_method.rs.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
     env
 }
 
+fn render(env: &Environment<'static>, name: &str, ctx: minijinja::Value) -> String {
+    env.get_template(name)
+        .expect("template must exist")
+        .render(ctx)
+        .unwrap_or_default()
+}
+
+/// Anchor in the generated `service.rs` the lifespan bridges are inserted before.
+const BRIDGE_ANCHOR: &str = "/// Drive `spikard::App::run` from JavaScript.";
+
+/// Anchor inside the generated `impl JsApp` block the `#[napi]` registration
+/// methods are inserted before.
+const NATIVE_METHOD_ANCHOR: &str = "    /// Apply configuration via the `config` configurator on the native app.";
+
+/// Anchor in the generated `service.ts` / `service.cjs` the host registration
+/// methods are inserted before.
+const HOST_METHOD_ANCHOR: &str = "  /**\n   * Run the HTTP server using the configured routes.";
+
 /// Emit TypeScript HTTP extension files: the ergonomic App layer and optional lifecycle hooks.
 ///
 /// # Errors
@@ -161,3 +239,145 @@ fn wire_package_json(content: &mut String) {
         }
     }
 }
+
+/// Wire the `[[lifespan_hooks]]`, `[[health_checks]]`, `[[rate_limit_key_extractors]]` and
+/// `[[data_loaders]]` contracts into the backend-generated Node binding.
+///
+/// alef core only knows handler contracts, so these registrations are spliced in
+/// here: a `ThreadsafeFunction` bridge per callback contract and a `#[napi]`
+/// registration method per callback in `service.rs`, plus the matching host method
+/// on the low-level `App` in `service.ts` and `service.cjs`. Every splice is
+/// idempotent.
+pub fn wire_host_callbacks(files: &mut [GeneratedFile], cfg: &HttpExtensionConfig) {
+    if cfg.lifespan_hooks.is_empty()
+        && cfg.health_checks.is_empty()
+        && cfg.rate_limit_key_extractors.is_empty()
+        && cfg.data_loaders.is_empty()
+    {
+        return;
+    }
+    let env = make_env();
+    let mut bridges = String::new();
+    for contract in super::distinct_contracts(cfg.lifespan_hooks.iter().map(|h| h.callback_contract.as_str())) {
+        bridges.push_str(&render(
+            &env,
+            "service_rs_lifespan_bridge.rs.jinja",
+            context! { contract => contract },
+        ));
+    }
+    for contract in super::distinct_contracts(cfg.health_checks.iter().map(|c| c.callback_contract.as_str())) {
+        bridges.push_str(&render(
+            &env,
+            "service_rs_health_check_bridge.rs.jinja",
+            context! { contract => contract },
+        ));
+    }
+    for contract in super::distinct_contracts(
+        cfg.rate_limit_key_extractors
+            .iter()
+            .map(|e| e.callback_contract.as_str()),
+    ) {
+        bridges.push_str(&render(
+            &env,
+            "service_rs_rate_limit_key_bridge.rs.jinja",
+            context! { contract => contract },
+        ));
+    }
+    for contract in super::distinct_contracts(cfg.data_loaders.iter().map(|l| l.callback_contract.as_str())) {
+        bridges.push_str(&render(
+            &env,
+            "service_rs_data_loader_bridge.rs.jinja",
+            context! { contract => contract },
+        ));
+    }
+    let mut native_methods = String::new();
+    let mut ts_methods = String::new();
+    let mut cjs_methods = String::new();
+    for hook in &cfg.lifespan_hooks {
+        let method_name = hook.name.to_lower_camel_case();
+        native_methods.push_str(&render(
+            &env,
+            "service_rs_lifespan_method.rs.jinja",
+            context! { hook_name => &hook.name, contract => &hook.callback_contract, doc => &hook.doc },
+        ));
+        ts_methods.push_str(&render(
+            &env,
+            "service_ts_lifespan_hook.jinja",
+            context! { method_name => &method_name, doc => &hook.doc, typed => true },
+        ));
+        cjs_methods.push_str(&render(
+            &env,
+            "service_ts_lifespan_hook.jinja",
+            context! { method_name => &method_name, doc => &hook.doc, typed => false },
+        ));
+    }
+    for check in &cfg.health_checks {
+        let method_name = check.name.to_lower_camel_case();
+        native_methods.push_str(&render(
+            &env,
+            "service_rs_health_check_method.rs.jinja",
+            context! { check_name => &check.name, contract => &check.callback_contract, doc => &check.doc },
+        ));
+        ts_methods.push_str(&render(
+            &env,
+            "service_ts_health_check.jinja",
+            context! { method_name => &method_name, doc => &check.doc, typed => true },
+        ));
+        cjs_methods.push_str(&render(
+            &env,
+            "service_ts_health_check.jinja",
+            context! { method_name => &method_name, doc => &check.doc, typed => false },
+        ));
+    }
+    for extractor in &cfg.rate_limit_key_extractors {
+        let method_name = extractor.name.to_lower_camel_case();
+        native_methods.push_str(&render(
+            &env,
+            "service_rs_rate_limit_key_method.rs.jinja",
+            context! {
+                extractor_name => &extractor.name,
+                contract => &extractor.callback_contract,
+                doc => &extractor.doc,
+            },
+        ));
+        ts_methods.push_str(&render(
+            &env,
+            "service_ts_rate_limit_key.jinja",
+            context! { method_name => &method_name, doc => &extractor.doc, typed => true },
+        ));
+        cjs_methods.push_str(&render(
+            &env,
+            "service_ts_rate_limit_key.jinja",
+            context! { method_name => &method_name, doc => &extractor.doc, typed => false },
+        ));
+    }
+    for loader in &cfg.data_loaders {
+        let method_name = loader.name.to_lower_camel_case();
+        native_methods.push_str(&render(
+            &env,
+            "service_rs_data_loader_method.rs.jinja",
+            context! { loader_name => &loader.name, contract => &loader.callback_contract, doc => &loader.doc },
+        ));
+        ts_methods.push_str(&render(
+            &env,
+            "service_ts_data_loader.jinja",
+            context! { method_name => &method_name, doc => &loader.doc, typed => true },
+        ));
+        cjs_methods.push_str(&render(
+            &env,
+            "service_ts_data_loader.jinja",
+            context! { method_name => &method_name, doc => &loader.doc, typed => false },
+        ));
+    }
+    for file in files.iter_mut() {
+        match file.path.file_name().and_then(|n| n.to_str()) {
+            Some("service.rs") => {
+                super::insert_before(&mut file.content, BRIDGE_ANCHOR, &bridges);
+                super::insert_before(&mut file.content, NATIVE_METHOD_ANCHOR, &native_methods);
+            }
+            Some("service.ts") => super::insert_before(&mut file.content, HOST_METHOD_ANCHOR, &ts_methods),
+            Some("service.cjs") => super::insert_before(&mut file.content, HOST_METHOD_ANCHOR, &cjs_methods),
+            _ => {}
+        }
+    }
+}
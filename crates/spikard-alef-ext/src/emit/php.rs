@@ -1,15 +1,108 @@
 //! PHP (ext-php-rs) emission for HTTP extension.
 //!
-//! Emits the ergonomic `App.php` typed-handler layer. The remaining HTTP
-//! surface (lifecycle hooks, WebSocket/SSE routes, error types) is not yet
-//! implemented for PHP and is logged at debug level.
+//! Emits the ergonomic `App.php` typed-handler layer and wires the lifespan hooks
+//! and health checks into the generated binding. The remaining HTTP surface (lifecycle hooks,
+//! WebSocket/SSE routes, error types) is not yet implemented for PHP and is logged
+//! at debug level.
 
 use crate::config::HttpExtensionConfig;
 use alef::core::backend::GeneratedFile;
 use alef::core::ir::ApiSurface;
 use anyhow::Result;
+use heck::ToLowerCamelCase;
+use minijinja::{Environment, context};
 use std::path::PathBuf;
 
+/// Anchor in the generated `service.rs` the lifespan bridges are inserted before.
+const BRIDGE_ANCHOR: &str = "/// Drive `spikard::App::run` from PHP.";
+
+/// Anchor in the generated `service.rs`: the catch-all arm of the registration
+/// `match` in both `app_run` and `app_into_router`.
+const ARM_ANCHOR: &str = "                    _ => {\n                        return Err(PhpException::default(format!(\n                            \"unknown registration method";
+
+/// Anchor in the generated `Service.php` the host registration methods are inserted before.
+const METHOD_ANCHOR: &str = "    /**\n     * Run the HTTP server using the configured routes.";
+
+fn make_env() -> Environment<'static> {
+    let mut env = Environment::new();
+    env.set_trim_blocks(true);
+    env.set_lstrip_blocks(true);
+    env.set_keep_trailing_newline(true);
+    env.add_template_owned(
+        "service_rs_lifespan_bridge.rs.jinja".to_owned(),
+        include_str!("../templates/php/service_rs_lifespan_bridge.rs.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_rs_lifespan_arm.rs.jinja".to_owned(),
+        include_str!("../templates/php/service_rs_lifespan_arm.rs.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_php_lifespan_hook.php.jinja".to_owned(),
+        include_str!("../templates/php/service_php_lifespan_hook.php.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_rs_health_check_bridge.rs.jinja".to_owned(),
+        include_str!("../templates/php/service_rs_health_check_bridge.rs.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_rs_health_check_arm.rs.jinja".to_owned(),
+        include_str!("../templates/php/service_rs_health_check_arm.rs.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_php_health_check.php.jinja".to_owned(),
+        include_str!("../templates/php/service_php_health_check.php.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_rs_hook_helpers.rs.jinja".to_owned(),
+        include_str!("../templates/php/service_rs_hook_helpers.rs.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_rs_rate_limit_key_bridge.rs.jinja".to_owned(),
+        include_str!("../templates/php/service_rs_rate_limit_key_bridge.rs.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_rs_rate_limit_key_arm.rs.jinja".to_owned(),
+        include_str!("../templates/php/service_rs_rate_limit_key_arm.rs.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_php_rate_limit_key.php.jinja".to_owned(),
+        include_str!("../templates/php/service_php_rate_limit_key.php.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_rs_data_loader_bridge.rs.jinja".to_owned(),
+        include_str!("../templates/php/service_rs_data_loader_bridge.rs.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_rs_data_loader_arm.rs.jinja".to_owned(),
+        include_str!("../templates/php/service_rs_data_loader_arm.rs.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_php_data_loader.php.jinja".to_owned(),
+        include_str!("../templates/php/service_php_data_loader.php.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
+    env
+}
+
+fn render(env: &Environment<'static>, name: &str, ctx: minijinja::Value) -> String {
+    env.get_template(name)
+        .expect("template must exist")
+        .render(ctx)
+        .unwrap_or_default()
+}
+
 /// Emit PHP HTTP extension files.
 ///
 /// # Errors
@@ -48,3 +141,131 @@ pub fn emit(_api: &ApiSurface, cfg: &HttpExtensionConfig) -> Result<Vec<Generate
     }
     Ok(files)
 }
+
+/// Wire the `[[lifespan_hooks]]`, `[[health_checks]]`, `[[rate_limit_key_extractors]]` and
+/// `[[data_loaders]]` contracts into the backend-generated PHP binding.
+///
+/// alef core only knows handler contracts, so these registrations are spliced in
+/// here: a registry-backed bridge per callback contract and a registration `match`
+/// arm per callback in `service.rs`, plus a host registration method per callback
+/// on the low-level `App` in `Service.php`. Every splice is idempotent.
+pub fn wire_host_callbacks(files: &mut [GeneratedFile], cfg: &HttpExtensionConfig) {
+    if cfg.lifespan_hooks.is_empty()
+        && cfg.health_checks.is_empty()
+        && cfg.rate_limit_key_extractors.is_empty()
+        && cfg.data_loaders.is_empty()
+    {
+        return;
+    }
+    let env = make_env();
+    let mut bridges = String::new();
+    for contract in super::distinct_contracts(cfg.lifespan_hooks.iter().map(|h| h.callback_contract.as_str())) {
+        bridges.push_str(&render(
+            &env,
+            "service_rs_lifespan_bridge.rs.jinja",
+            context! { contract => contract },
+        ));
+    }
+    for contract in super::distinct_contracts(cfg.health_checks.iter().map(|c| c.callback_contract.as_str())) {
+        bridges.push_str(&render(
+            &env,
+            "service_rs_health_check_bridge.rs.jinja",
+            context! { contract => contract },
+        ));
+    }
+    for contract in super::distinct_contracts(
+        cfg.rate_limit_key_extractors
+            .iter()
+            .map(|e| e.callback_contract.as_str()),
+    ) {
+        bridges.push_str(&render(
+            &env,
+            "service_rs_rate_limit_key_bridge.rs.jinja",
+            context! { contract => contract },
+        ));
+    }
+    for contract in super::distinct_contracts(cfg.data_loaders.iter().map(|l| l.callback_contract.as_str())) {
+        bridges.push_str(&render(
+            &env,
+            "service_rs_data_loader_bridge.rs.jinja",
+            context! { contract => contract },
+        ));
+    }
+    bridges.push_str(&render(&env, "service_rs_hook_helpers.rs.jinja", context! {}));
+    let mut arms = String::new();
+    let mut methods = String::new();
+    for hook in &cfg.lifespan_hooks {
+        arms.push_str(&render(
+            &env,
+            "service_rs_lifespan_arm.rs.jinja",
+            context! { hook_name => &hook.name, contract => &hook.callback_contract },
+        ));
+        methods.push_str(&render(
+            &env,
+            "service_php_lifespan_hook.php.jinja",
+            context! {
+                hook_name => &hook.name,
+                method_name => hook.name.to_lower_camel_case(),
+                doc => &hook.doc,
+            },
+        ));
+    }
+    for check in &cfg.health_checks {
+        arms.push_str(&render(
+            &env,
+            "service_rs_health_check_arm.rs.jinja",
+            context! { check_name => &check.name, contract => &check.callback_contract },
+        ));
+        methods.push_str(&render(
+            &env,
+            "service_php_health_check.php.jinja",
+            context! {
+                check_name => &check.name,
+                method_name => check.name.to_lower_camel_case(),
+                doc => &check.doc,
+            },
+        ));
+    }
+    for extractor in &cfg.rate_limit_key_extractors {
+        arms.push_str(&render(
+            &env,
+            "service_rs_rate_limit_key_arm.rs.jinja",
+            context! { extractor_name => &extractor.name, contract => &extractor.callback_contract },
+        ));
+        methods.push_str(&render(
+            &env,
+            "service_php_rate_limit_key.php.jinja",
+            context! {
+                extractor_name => &extractor.name,
+                method_name => extractor.name.to_lower_camel_case(),
+                doc => &extractor.doc,
+            },
+        ));
+    }
+    for loader in &cfg.data_loaders {
+        arms.push_str(&render(
+            &env,
+            "service_rs_data_loader_arm.rs.jinja",
+            context! { loader_name => &loader.name, contract => &loader.callback_contract },
+        ));
+        methods.push_str(&render(
+            &env,
+            "service_php_data_loader.php.jinja",
+            context! {
+                loader_name => &loader.name,
+                method_name => loader.name.to_lower_camel_case(),
+                doc => &loader.doc,
+            },
+        ));
+    }
+    for file in files.iter_mut() {
+        match file.path.file_name().and_then(|n| n.to_str()) {
+            Some("service.rs") => {
+                super::insert_before(&mut file.content, BRIDGE_ANCHOR, &bridges);
+                super::insert_before(&mut file.content, ARM_ANCHOR, &arms);
+            }
+            Some("Service.php") => super::insert_before(&mut file.content, METHOD_ANCHOR, &methods),
+            _ => {}
+        }
+    }
+}
@@ -15,6 +15,7 @@ use crate::config::HttpExtensionConfig;
 use alef::core::backend::GeneratedFile;
 use alef::core::ir::ApiSurface;
 use anyhow::Result;
+use minijinja::{Environment, context};
 use std::path::PathBuf;
 
 /// Package-relative destination directory for every emitted file.
@@ -74,6 +75,96 @@ const STATIC_FILES: &[(&str, &str)] = &[
     ),
 ];
 
+/// Anchor in the generated `service.rs` the lifespan bridges are inserted before.
+const BRIDGE_ANCHOR: &str = "/// Drive `spikard::App::run` from Python.";
+
+/// Anchor in the generated `service.rs`: the catch-all arm of the registration
+/// `match` in both `app_run` and `app_into_router`.
+const ARM_ANCHOR: &str = "            _ => {\n                return Err(pyo3::exceptions::PyValueError::new_err(format!(\n                    \"unknown registration method";
+
+/// Anchor in the generated `service.py` the host registration methods are inserted before.
+const METHOD_ANCHOR: &str = "    def run(self) -> None:";
+
+fn make_env() -> Environment<'static> {
+    let mut env = Environment::new();
+    env.set_trim_blocks(true);
+    env.set_lstrip_blocks(true);
+    env.set_keep_trailing_newline(true);
+    env.add_template_owned(
+        "service_rs_lifespan_bridge.rs.jinja".to_owned(),
+        include_str!("../templates/pyo3/service_rs_lifespan_bridge.rs.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_rs_lifespan_arm.rs.jinja".to_owned(),
+        include_str!("../templates/pyo3/service_rs_lifespan_arm.rs.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_py_lifespan_hook.py.jinja".to_owned(),
+        include_str!("../templates/pyo3/service_py_lifespan_hook.py.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_rs_health_check_bridge.rs.jinja".to_owned(),
+        include_str!("../templates/pyo3/service_rs_health_check_bridge.rs.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_rs_health_check_arm.rs.jinja".to_owned(),
+        include_str!("../templates/pyo3/service_rs_health_check_arm.rs.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_py_health_check.py.jinja".to_owned(),
+        include_str!("../templates/pyo3/service_py_health_check.py.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_rs_rate_limit_key_bridge.rs.jinja".to_owned(),
+        include_str!("../templates/pyo3/service_rs_rate_limit_key_bridge.rs.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_rs_rate_limit_key_arm.rs.jinja".to_owned(),
+        include_str!("../templates/pyo3/service_rs_rate_limit_key_arm.rs.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_py_rate_limit_key.py.jinja".to_owned(),
+        include_str!("../templates/pyo3/service_py_rate_limit_key.py.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_rs_data_loader_bridge.rs.jinja".to_owned(),
+        include_str!("../templates/pyo3/service_rs_data_loader_bridge.rs.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_rs_data_loader_arm.rs.jinja".to_owned(),
+        include_str!("../templates/pyo3/service_rs_data_loader_arm.rs.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_py_data_loader.py.jinja".to_owned(),
+        include_str!("../templates/pyo3/service_py_data_loader.py.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
+    env.add_template_owned(
+        "service_rs_hook_helpers.rs.jinja".to_owned(),
+        include_str!("../templates/pyo3/service_rs_hook_helpers.rs.jinja").to_owned(),
+    )
+    .expect("built-in template parse failed");
+    env
+}
+
+fn render(env: &Environment<'static>, name: &str, ctx: minijinja::Value) -> String {
+    env.get_template(name)
+        .expect("template must exist")
+        .render(ctx)
+        .unwrap_or_default()
+}
+
 /// Emit the Python HTTP extension files.
 ///
 /// # Errors
@@ -104,3 +195,115 @@ pub fn emit(_api: &ApiSurface, cfg: &HttpExtensionConfig) -> Result<Vec<Generate
 
     Ok(files)
 }
+
+/// Wire the `[[lifespan_hooks]]`, `[[health_checks]]`, `[[rate_limit_key_extractors]]` and
+/// `[[data_loaders]]` contracts into the backend-generated Python binding.
+///
+/// alef core only knows handler contracts, so these registrations are spliced in
+/// here: a `PyO3` bridge per callback contract and a registration `match` arm per
+/// callback in `service.rs`, plus a host registration method per callback on the
+/// low-level `App` in `service.py`. Every splice is idempotent.
+pub fn wire_host_callbacks(files: &mut [GeneratedFile], cfg: &HttpExtensionConfig) {
+    if cfg.lifespan_hooks.is_empty()
+        && cfg.health_checks.is_empty()
+        && cfg.rate_limit_key_extractors.is_empty()
+        && cfg.data_loaders.is_empty()
+    {
+        return;
+    }
+    let env = make_env();
+    let mut bridges = String::new();
+    for contract in super::distinct_contracts(cfg.lifespan_hooks.iter().map(|h| h.callback_contract.as_str())) {
+        bridges.push_str(&render(
+            &env,
+            "service_rs_lifespan_bridge.rs.jinja",
+            context! { contract => contract },
+        ));
+    }
+    for contract in super::distinct_contracts(cfg.health_checks.iter().map(|c| c.callback_contract.as_str())) {
+        bridges.push_str(&render(
+            &env,
+            "service_rs_health_check_bridge.rs.jinja",
+            context! { contract => contract },
+        ));
+    }
+    for contract in super::distinct_contracts(
+        cfg.rate_limit_key_extractors
+            .iter()
+            .map(|e| e.callback_contract.as_str()),
+    ) {
+        bridges.push_str(&render(
+            &env,
+            "service_rs_rate_limit_key_bridge.rs.jinja",
+            context! { contract => contract },
+        ));
+    }
+    for contract in super::distinct_contracts(cfg.data_loaders.iter().map(|l| l.callback_contract.as_str())) {
+        bridges.push_str(&render(
+            &env,
+            "service_rs_data_loader_bridge.rs.jinja",
+            context! { contract => contract },
+        ));
+    }
+    bridges.push_str(&render(&env, "service_rs_hook_helpers.rs.jinja", context! {}));
+    let mut arms = String::new();
+    let mut methods = String::new();
+    for hook in &cfg.lifespan_hooks {
+        arms.push_str(&render(
+            &env,
+            "service_rs_lifespan_arm.rs.jinja",
+            context! { hook_name => &hook.name, contract => &hook.callback_contract },
+        ));
+        methods.push_str(&render(
+            &env,
+            "service_py_lifespan_hook.py.jinja",
+            context! { hook_name => &hook.name, doc => &hook.doc },
+        ));
+    }
+    for check in &cfg.health_checks {
+        arms.push_str(&render(
+            &env,
+            "service_rs_health_check_arm.rs.jinja",
+            context! { check_name => &check.name, contract => &check.callback_contract },
+        ));
+        methods.push_str(&render(
+            &env,
+            "service_py_health_check.py.jinja",
+            context! { check_name => &check.name, doc => &check.doc },
+        ));
+    }
+    for extractor in &cfg.rate_limit_key_extractors {
+        arms.push_str(&render(
+            &env,
+            "service_rs_rate_limit_key_arm.rs.jinja",
+            context! { extractor_name => &extractor.name, contract => &extractor.callback_contract },
+        ));
+        methods.push_str(&render(
+            &env,
+            "service_py_rate_limit_key.py.jinja",
+            context! { extractor_name => &extractor.name, doc => &extractor.doc },
+        ));
+    }
+    for loader in &cfg.data_loaders {
+        arms.push_str(&render(
+            &env,
+            "service_rs_data_loader_arm.rs.jinja",
+            context! { loader_name => &loader.name, contract => &loader.callback_contract },
+        ));
+        methods.push_str(&render(
+            &env,
+            "service_py_data_loader.py.jinja",
+            context! { loader_name => &loader.name, doc => &loader.doc },
+        ));
+    }
+    for file in files.iter_mut() {
+        match file.path.file_name().and_then(|n| n.to_str()) {
+            Some("service.rs") => {
+                super::insert_before(&mut file.content, BRIDGE_ANCHOR, &bridges);
+                super::insert_before(&mut file.content, ARM_ANCHOR, &arms);
+            }
+            Some("service.py") => super::insert_before(&mut file.content, METHOD_ANCHOR, &methods),
+            _ => {}
+        }
+    }
+}
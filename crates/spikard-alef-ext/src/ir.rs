@@ -23,6 +23,79 @@ pub struct LifecycleHookDef {
     pub is_async: bool,
 }
 
+/// Phase of the application lifespan a [`LifespanHookDef`] runs in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LifespanPhase {
+    /// Before the listener binds; a failing hook aborts boot.
+    Startup,
+    /// After the server stops, in reverse registration order.
+    Shutdown,
+}
+
+/// A lifespan hook contract — a callback run once per boot rather than per request.
+///
+/// The host callable takes no arguments and is always awaited when it returns an
+/// awaitable; raising (or rejecting) fails the hook.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LifespanHookDef {
+    /// Name of the generated registration method, e.g. `"on_startup"`.
+    pub name: String,
+    /// Lifespan phase the hook runs in.
+    pub phase: LifespanPhase,
+    /// Name of the callback contract (trait) the bridged host callable implements.
+    pub callback_contract: String,
+    /// Documentation for the generated registration method.
+    #[serde(default)]
+    pub doc: String,
+}
+
+/// A readiness check contract — a named callback run on every readiness probe.
+///
+/// The host callable takes no arguments and is awaited when it returns an
+/// awaitable; raising (or rejecting) reports the application as not ready.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HealthCheckDef {
+    /// Name of the generated registration method, e.g. `"health_check"`.
+    pub name: String,
+    /// Name of the callback contract (trait) the bridged host callable implements.
+    pub callback_contract: String,
+    /// Documentation for the generated registration method.
+    #[serde(default)]
+    pub doc: String,
+}
+
+/// A rate-limit key contract — a callback that keys rate limits configured with a custom key.
+///
+/// The host callable is synchronous: it receives the serialized
+/// `RateLimitKeyRequest` and returns the bucket key, or nothing to fall back to
+/// the client IP.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RateLimitKeyExtractorDef {
+    /// Name of the generated registration method, e.g. `"rate_limit_key_extractor"`.
+    pub name: String,
+    /// Name of the callback contract (trait) the bridged host callable implements.
+    pub callback_contract: String,
+    /// Documentation for the generated registration method.
+    #[serde(default)]
+    pub doc: String,
+}
+
+/// A data loader contract — a named batch loader for dynamic-SDL GraphQL routes.
+///
+/// The host callable (sync or async) receives a list of keys and returns one
+/// value per key, in order, with nothing marking a missing key.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DataLoaderDef {
+    /// Name of the generated registration method, e.g. `"data_loader"`.
+    pub name: String,
+    /// Name of the callback contract (trait) the bridged host callable implements.
+    pub callback_contract: String,
+    /// Documentation for the generated registration method.
+    #[serde(default)]
+    pub doc: String,
+}
+
 /// A WebSocket route registration contract.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WebSocketRouteDef {
@@ -158,6 +158,12 @@ impl Extension for HttpExtension {
     /// `revive` var-naming on alef-core-owned code this repository does not control —
     /// without editing alef or duplicating its templates.
     ///
+    /// For Python, Node, Ruby and PHP, splice the `[[lifespan_hooks]]`,
+    /// `[[health_checks]]`, `[[rate_limit_key_extractors]]` and `[[data_loaders]]`
+    /// bridges and registrations into the generated binding files, since alef core
+    /// only wires handler contracts. The hand-written glue for items excluded in
+    /// `alef.toml` is spliced in as well; see [`emit::binding_glue`].
+    ///
     /// # Errors
     ///
     /// Never fails; always returns `Ok(())`.
@@ -169,9 +175,16 @@ impl Extension for HttpExtension {
         files: &mut Vec<GeneratedFile>,
         _env: &TemplateEnv,
     ) -> Result<()> {
-        if language == Language::Go {
-            emit::go::add_generated_headers(files);
+        let cfg = self.loaded_config();
+        match language {
+            Language::Go => emit::go::add_generated_headers(files),
+            Language::Python => emit::pyo3::wire_host_callbacks(files, cfg),
+            Language::Node => emit::napi::wire_host_callbacks(files, cfg),
+            Language::Ruby => emit::magnus::wire_host_callbacks(files, cfg),
+            Language::Php => emit::php::wire_host_callbacks(files, cfg),
+            _ => {}
         }
+        emit::binding_glue::wire_binding_glue(files, language);
         Ok(())
     }
 
@@ -207,14 +220,20 @@ impl Extension for HttpExtension {
 /// Raw lines appended to `packages/python/spikard/__init__.py` to expose the ergonomic surface.
 ///
 /// Ordering matters: `from .app import App` is last among the `App` bindings so it shadows the
-/// low-level `from .service import App` the backend already emitted. The final line extends the
-/// backend-generated `__all__` with the ergonomic names (`App` is already listed by the backend).
+/// low-level `from .service import App` the backend already emitted. The `__all__ +=` lines extend
+/// the backend-generated `__all__` with the ergonomic names (`App` is already listed by the backend)
+/// and the bindings spliced in by `emit::binding_glue`.
 const PYTHON_INIT_ADDITIONS: &[&str] = &[
     // `# noqa: F811`: the re-import deliberately shadows the low-level
     "from .app import App  # noqa: F811",
     "from .params import Body, Cookie, Header, Path, Query",
     "from ._internal.converters import register_decoder",
+    // Hand-written bindings from `emit::binding_glue`, which alef core does not export
+    "from ._spikard import DependencyOverrideGuard, SnapshotOptions, SnapshotOutcome",
+    "from ._spikard import assert_snapshot_file, check_snapshot, snapshot_path",
     "__all__ += [\"Body\", \"Cookie\", \"Header\", \"Path\", \"Query\", \"register_decoder\"]",
+    "__all__ += [\"DependencyOverrideGuard\", \"SnapshotOptions\", \"SnapshotOutcome\"]",
+    "__all__ += [\"assert_snapshot_file\", \"check_snapshot\", \"snapshot_path\"]",
 ];
 
 /// Raw lines appended to `packages/node/@spikard/node/index.ts` to expose the ergonomic surface.
@@ -238,9 +257,13 @@ const RUBY_INIT_ADDITIONS: &[&str] = &[
 #[cfg(test)]
 mod tests {
     use super::{HttpExtension, NODE_INIT_ADDITIONS, PYTHON_INIT_ADDITIONS, RUBY_INIT_ADDITIONS};
+    use crate::config::HttpExtensionConfig;
+    use crate::ir::{DataLoaderDef, HealthCheckDef, LifespanHookDef, LifespanPhase, RateLimitKeyExtractorDef};
     use alef::Extension;
+    use alef::core::backend::GeneratedFile;
     use alef::core::config::Language;
     use alef::core::ir::ApiSurface;
+    use std::path::PathBuf;
 
     #[test]
     fn python_additions_expose_ergonomic_surface() {
@@ -309,4 +332,90 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn lifespan_hooks_fail_generation_for_bindings_without_them() {
+        let cfg = HttpExtensionConfig {
+            lifespan_hooks: vec![LifespanHookDef {
+                name: "on_startup".to_owned(),
+                phase: LifespanPhase::Startup,
+                callback_contract: "LifespanHook".to_owned(),
+                doc: "Called once before the listener binds.".to_owned(),
+            }],
+            ..HttpExtensionConfig::default()
+        };
+        let api = ApiSurface::default();
+
+        for lang in [Language::Csharp, Language::Elixir, Language::Java, Language::Zig] {
+            let err = crate::emit::emit_for_language(&api, &cfg, lang).unwrap_err();
+            assert!(err.to_string().contains("on_startup"), "{lang:?}: {err}");
+        }
+        assert!(crate::emit::check_lifespan_support(&cfg, Language::Python).is_ok());
+        assert!(crate::emit::check_lifespan_support(&HttpExtensionConfig::default(), Language::Zig).is_ok());
+    }
+
+    #[test]
+    fn host_callbacks_are_wired_once_into_both_registration_matches() {
+        let cfg = HttpExtensionConfig {
+            lifespan_hooks: vec![LifespanHookDef {
+                name: "on_startup".to_owned(),
+                phase: LifespanPhase::Startup,
+                callback_contract: "LifespanHook".to_owned(),
+                doc: "Called once before the listener binds.".to_owned(),
+            }],
+            health_checks: vec![HealthCheckDef {
+                name: "health_check".to_owned(),
+                callback_contract: "HealthCheck".to_owned(),
+                doc: "Readiness check.".to_owned(),
+            }],
+            rate_limit_key_extractors: vec![RateLimitKeyExtractorDef {
+                name: "rate_limit_key_extractor".to_owned(),
+                callback_contract: "RateLimitKeyExtractor".to_owned(),
+                doc: "Rate-limit bucket key.".to_owned(),
+            }],
+            data_loaders: vec![DataLoaderDef {
+                name: "data_loader".to_owned(),
+                callback_contract: "BatchLoader".to_owned(),
+                doc: "Batch loader.".to_owned(),
+            }],
+            ..HttpExtensionConfig::default()
+        };
+        let catch_all = "            _ => {\n                return Err(pyo3::exceptions::PyValueError::new_err(format!(\n                    \"unknown registration method: {method_name}\"\n";
+        let mut files = vec![GeneratedFile {
+            path: PathBuf::from("crates/spikard-py/src/service.rs"),
+            content: format!("/// Drive `spikard::App::run` from Python.\n{catch_all}{catch_all}"),
+            generated_header: true,
+        }];
+
+        crate::emit::pyo3::wire_host_callbacks(&mut files, &cfg);
+        crate::emit::pyo3::wire_host_callbacks(&mut files, &cfg);
+
+        let content = &files[0].content;
+        assert_eq!(content.matches("pub struct PyLifespanHookBridge").count(), 1);
+        assert_eq!(content.matches("pub struct PyHealthCheckBridge").count(), 1);
+        assert_eq!(content.matches("async fn call_py_hook").count(), 1);
+        assert_eq!(content.matches("owner.on_startup(hook);").count(), 2);
+        assert_eq!(content.matches("owner.health_check(check);").count(), 2);
+        assert_eq!(content.matches("pub struct PyRateLimitKeyExtractorBridge").count(), 1);
+        assert_eq!(content.matches("owner.rate_limit_key_extractor(extractor);").count(), 2);
+        assert_eq!(content.matches("pub struct PyBatchLoaderBridge").count(), 1);
+        assert_eq!(content.matches("owner.data_loader(name, loader);").count(), 2);
+    }
+
+    #[test]
+    fn binding_glue_is_spliced_once_before_its_anchor() {
+        let mut files = vec![GeneratedFile {
+            path: PathBuf::from("crates/spikard-py/src/lib.rs"),
+            content: "    m.add_class::<HandlerResult>()?;\n".to_owned(),
+            generated_header: true,
+        }];
+
+        crate::emit::binding_glue::wire_binding_glue(&mut files, Language::Python);
+        crate::emit::binding_glue::wire_binding_glue(&mut files, Language::Python);
+
+        assert_eq!(
+            files[0].content,
+            "    m.add_class::<DependencyOverrideGuard>()?;\n    m.add_class::<HandlerResult>()?;\n"
+        );
+    }
 }
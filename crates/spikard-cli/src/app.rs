@@ -4,9 +4,11 @@ use crate::codegen::{
     CodegenEngine, CodegenOutcome, CodegenRequest, GeneratedAsset, PhpDtoGenerator, detect_primary_protocol,
     parse_asyncapi_schema,
 };
+use crate::contract::ContractRequest;
 use crate::init::{InitEngine, InitRequest, InitResponse};
 use anyhow::{Context, Result};
 use serde::Serialize;
+use spikard_http::testing::ContractReport;
 use std::fs;
 use std::path::Path;
 
@@ -86,6 +88,12 @@ pub fn generate_php_dto(output: &Path) -> Result<Vec<GeneratedAsset>> {
     Ok(assets)
 }
 
+/// Run contract tests against a running application.
+pub fn run_contract_tests(request: &ContractRequest) -> Result<ContractReport> {
+    let runtime = tokio::runtime::Runtime::new().context("Failed to create Tokio runtime for contract tests")?;
+    runtime.block_on(crate::contract::run(request))
+}
+
 /// Validate an AsyncAPI schema and return the structured summary.
 pub fn validate_asyncapi_schema(schema: &Path) -> Result<AsyncApiValidationSummary> {
     let spec = parse_asyncapi_schema(schema).context("Failed to parse AsyncAPI schema")?;
@@ -5,11 +5,13 @@ use crate::codegen::{
     self, CodegenOutcome, CodegenRequest, CodegenTargetKind, DtoConfig, NodeDtoStyle, PythonDtoStyle, RubyDtoStyle,
     SchemaKind, TargetLanguage,
 };
+use crate::contract::ContractRequest;
 use crate::init::{InitRequest, InitResponse};
 use anyhow::{Context, Result, bail};
 use clap::{Args, Parser, Subcommand, ValueEnum};
 use scythe_core::dialect::SqlDialect;
 use spikard_codegen::sql::DecimalMode;
+use spikard_http::testing::ContractReport;
 use std::ffi::OsString;
 use std::path::PathBuf;
 
@@ -38,6 +40,11 @@ enum Commands {
         #[command(subcommand)]
         target: TestingCommand,
     },
+    /// Test a running application against its schemas
+    Test {
+        #[command(subcommand)]
+        target: TestCommand,
+    },
     /// Validate an `AsyncAPI` specification
     ValidateAsyncapi {
         /// Path to `AsyncAPI` schema file (JSON or YAML)
@@ -313,6 +320,25 @@ enum TestingCommand {
     },
 }
 
+#[derive(Subcommand, Debug)]
+enum TestCommand {
+    /// Send valid, boundary and invalid requests generated from the route schemas
+    Contract(ContractArgs),
+}
+
+#[derive(Args, Debug)]
+struct ContractArgs {
+    /// Base URL of the running application
+    #[arg(long, default_value = "http://127.0.0.1:8000")]
+    url: String,
+    /// `OpenAPI` document describing the routes (default: fetched from the application)
+    #[arg(long)]
+    spec: Option<PathBuf>,
+    /// Path the application serves its `OpenAPI` document at
+    #[arg(long = "openapi-path", default_value = "/openapi.json")]
+    openapi_path: String,
+}
+
 #[derive(Subcommand, Debug)]
 enum AsyncapiTestingTarget {
     /// Generate test fixtures from message schemas
@@ -710,6 +736,21 @@ fn run(cli: Cli) -> Result<()> {
                 }
             },
         },
+        Commands::Test { target } => match target {
+            TestCommand::Contract(args) => {
+                println!("Running contract tests against {}...", args.url);
+                let request = ContractRequest {
+                    base_url: args.url,
+                    spec: args.spec,
+                    openapi_path: args.openapi_path,
+                };
+                let report = app::run_contract_tests(&request).context("Contract tests could not run")?;
+                print_contract_report(&report);
+                if !report.is_success() {
+                    bail!("{} contract case(s) failed", report.failures.len());
+                }
+            }
+        },
         Commands::Features => {
             print_feature_summary(app::feature_summary());
         }
@@ -760,6 +801,29 @@ fn print_feature_summary(summary: app::FeatureSummary) {
     println!("\nDocumentation: {}", summary.documentation_url);
 }
 
+fn print_contract_report(report: &ContractReport) {
+    for failure in &report.failures {
+        println!(
+            "\n✗ {}: {} ({})",
+            failure.route, failure.case.description, failure.case.kind
+        );
+        println!("  {}", failure.reason);
+        println!("  Status: {}", failure.status);
+        println!("  Request: {}", failure.case);
+    }
+    println!(
+        "\n{} routes, {} cases: {} passed, {} failed, {} skipped",
+        report.routes,
+        report.cases,
+        report.passed,
+        report.failures.len(),
+        report.skipped
+    );
+    if report.is_success() {
+        println!("✓ All contract cases passed");
+    }
+}
+
 fn print_asyncapi_validation(summary: app::AsyncApiValidationSummary) {
     println!("✓ AsyncAPI schema is valid");
     println!("  Spec Version: {}", summary.spec_version);
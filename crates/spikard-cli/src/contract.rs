@@ -0,0 +1,173 @@
+//! Contract testing of a running application over HTTP
+//!
+//! Routes and their schemas come from an `OpenAPI` document, either a local file or the
+//! one the application serves. Cases are generated and checked by
+//! `spikard_http::testing::run_contract`; this module only supplies the HTTP transport.
+
+use anyhow::{Context, Result, anyhow, bail};
+use axum::body::Bytes;
+use axum::http::{HeaderName, HeaderValue, Method, Request, Uri};
+use http_body_util::{BodyExt, Full};
+use hyper_util::rt::TokioIo;
+use serde_json::Value;
+use spikard_http::testing::{
+    ContractCase, ContractReport, ContractRoute, ContractTransport, ResponseSnapshot, SnapshotError, run_contract,
+};
+use std::collections::HashMap;
+use std::future::Future;
+use std::path::Path;
+use std::pin::Pin;
+use tokio::net::TcpStream;
+
+/// Request to run contract tests against a running application.
+#[derive(Debug, Clone)]
+pub struct ContractRequest {
+    /// Base URL of the application, e.g. `http://127.0.0.1:8000`.
+    pub base_url: String,
+    /// Local `OpenAPI` document (JSON or YAML); fetched from the application when absent.
+    pub spec: Option<std::path::PathBuf>,
+    /// Path of the served `OpenAPI` document, relative to the base URL.
+    pub openapi_path: String,
+}
+
+/// Generate contract cases from the application's `OpenAPI` document and send them over HTTP.
+pub async fn run(request: &ContractRequest) -> Result<ContractReport> {
+    let transport = HttpTransport::new(&request.base_url)?;
+    let spec = match &request.spec {
+        Some(path) => read_spec(path)?,
+        None => transport
+            .fetch_json(&request.openapi_path)
+            .await
+            .with_context(|| format!("Failed to fetch the OpenAPI document from {}", request.openapi_path))?,
+    };
+
+    let routes = ContractRoute::from_openapi(&spec);
+    if routes.is_empty() {
+        bail!("The OpenAPI document declares no operations");
+    }
+    run_contract(&transport, &routes)
+        .await
+        .map_err(|error| anyhow!(error.to_string()))
+}
+
+fn read_spec(path: &Path) -> Result<Value> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read OpenAPI document from {}", path.display()))?;
+    serde_json::from_str(&content)
+        .or_else(|_| serde_saphyr::from_str(&content).context("Failed to parse as JSON or YAML"))
+        .with_context(|| format!("Failed to parse OpenAPI document from {}", path.display()))
+}
+
+/// Sends requests to an application listening on plain HTTP/1.1, one connection per request.
+struct HttpTransport {
+    /// `host:port` to connect to.
+    address: String,
+    /// Authority sent in the `host` header.
+    host: String,
+    /// Path prefix of the base URL, without a trailing slash.
+    prefix: String,
+}
+
+impl HttpTransport {
+    fn new(base_url: &str) -> Result<Self> {
+        let uri: Uri = base_url
+            .parse()
+            .with_context(|| format!("Invalid base URL '{base_url}'"))?;
+        match uri.scheme_str() {
+            Some("http") => {}
+            Some(other) => bail!("Unsupported scheme '{other}': contract tests connect over plain http://"),
+            None => bail!("Base URL '{base_url}' must start with http://"),
+        }
+        let authority = uri.authority().context("Base URL has no host")?;
+        Ok(Self {
+            address: format!("{}:{}", authority.host(), authority.port_u16().unwrap_or(80)),
+            host: authority.to_string(),
+            prefix: uri.path().trim_end_matches('/').to_string(),
+        })
+    }
+
+    async fn fetch_json(&self, path: &str) -> Result<Value> {
+        let response = self
+            .send_request(Method::GET, path, Vec::new(), Bytes::new())
+            .await
+            .map_err(|error| anyhow!(error.to_string()))?;
+        if response.status != 200 {
+            bail!("GET {path} answered {}", response.status);
+        }
+        response.json().context("Response is not JSON")
+    }
+
+    async fn send_request(
+        &self,
+        method: Method,
+        path_and_query: &str,
+        headers: Vec<(String, String)>,
+        body: Bytes,
+    ) -> Result<ResponseSnapshot, SnapshotError> {
+        let transport_error = |error: &dyn std::fmt::Display| {
+            SnapshotError::Decompression(format!("{} {}: {}", self.address, path_and_query, error))
+        };
+
+        let mut request = Request::builder()
+            .method(method)
+            .uri(format!("{}{}", self.prefix, path_and_query))
+            .header("host", &self.host);
+        for (name, value) in headers {
+            let name = HeaderName::from_bytes(name.as_bytes())
+                .map_err(|e| SnapshotError::InvalidHeader(format!("Invalid header name: {}", e)))?;
+            let value = HeaderValue::from_str(&value)
+                .map_err(|e| SnapshotError::InvalidHeader(format!("Invalid header value: {}", e)))?;
+            request = request.header(name, value);
+        }
+        let request = request
+            .body(Full::new(body))
+            .map_err(|e| SnapshotError::InvalidHeader(format!("Invalid request: {}", e)))?;
+
+        let stream = TcpStream::connect(&self.address)
+            .await
+            .map_err(|e| transport_error(&e))?;
+        let (mut sender, connection) = hyper::client::conn::http1::handshake(TokioIo::new(stream))
+            .await
+            .map_err(|e| transport_error(&e))?;
+        tokio::spawn(connection);
+
+        let response = sender.send_request(request).await.map_err(|e| transport_error(&e))?;
+        let status = response.status().as_u16();
+        let headers = response
+            .headers()
+            .iter()
+            .map(|(name, value)| {
+                (
+                    name.as_str().to_ascii_lowercase(),
+                    String::from_utf8_lossy(value.as_bytes()).into_owned(),
+                )
+            })
+            .collect::<HashMap<_, _>>();
+        let body = response
+            .into_body()
+            .collect()
+            .await
+            .map_err(|e| transport_error(&e))?
+            .to_bytes();
+
+        Ok(ResponseSnapshot {
+            status,
+            headers,
+            body: body.to_vec(),
+        })
+    }
+}
+
+impl ContractTransport for HttpTransport {
+    fn send<'a>(
+        &'a self,
+        case: &'a ContractCase,
+    ) -> Pin<Box<dyn Future<Output = Result<ResponseSnapshot, SnapshotError>> + Send + 'a>> {
+        Box::pin(async move {
+            let method = Method::from_bytes(case.method.as_bytes())
+                .map_err(|e| SnapshotError::Decompression(format!("Invalid method {}: {}", case.method, e)))?;
+            self.send_request(method, &case.uri(), case.headers(), case.body_bytes())
+                .await
+        })
+    }
+}
@@ -4,6 +4,7 @@
 pub mod app;
 pub mod cli;
 pub mod codegen;
+pub mod contract;
 pub mod init;
 #[cfg(feature = "mcp")]
 pub mod mcp;
@@ -0,0 +1,169 @@
+//! `spikard test contract` runs schema-generated requests against a live application.
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use serde_json::{Value, json};
+use spikard_http::server::build_router_with_handlers_and_config;
+use spikard_http::{
+    Handler, HandlerResult, OpenApiConfig, RequestData, Route, RouteMetadata, SchemaRegistry, ServerConfig,
+};
+use std::future::Future;
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::process::Command;
+use std::sync::Arc;
+
+/// Echoes the created item; names of length `fail_at` crash the handler.
+struct CreateItem {
+    fail_at: Option<usize>,
+}
+
+impl Handler for CreateItem {
+    fn call(
+        &self,
+        _request: Request<Body>,
+        request_data: RequestData,
+    ) -> Pin<Box<dyn Future<Output = HandlerResult> + Send + '_>> {
+        Box::pin(async move {
+            let body: Value = match &request_data.raw_body {
+                Some(raw) => serde_json::from_slice(raw).unwrap_or(Value::Null),
+                None => (*request_data.body).clone(),
+            };
+            let name = body["name"].as_str().unwrap_or_default().to_string();
+            if self.fail_at == Some(name.chars().count()) {
+                return Err((StatusCode::INTERNAL_SERVER_ERROR, "name overflow".to_string()));
+            }
+            Ok(axum::http::Response::builder()
+                .status(StatusCode::CREATED)
+                .header("content-type", "application/json")
+                .body(Body::from(json!({"id": 1, "name": name}).to_string()))
+                .expect("response"))
+        })
+    }
+}
+
+fn create_item() -> RouteMetadata {
+    serde_json::from_value(json!({
+        "method": "POST",
+        "path": "/items",
+        "handler_name": "create_item",
+        "request_schema": {
+            "type": "object",
+            "properties": {
+                "name": {"type": "string", "minLength": 1, "maxLength": 5},
+                "tags": {"type": "array", "items": {"type": "string"}, "maxItems": 3}
+            },
+            "required": ["name"]
+        },
+        "response_schema": {
+            "type": "object",
+            "properties": {"id": {"type": "integer"}, "name": {"type": "string"}},
+            "required": ["id", "name"]
+        },
+        "parameter_schema": {
+            "type": "object",
+            "properties": {"dry_run": {"type": "boolean", "source": "query"}}
+        },
+        "is_async": true,
+        "cors": null
+    }))
+    .expect("route metadata")
+}
+
+/// Serve the item route on a random local port for the rest of the test process.
+fn serve(fail_at: Option<usize>) -> SocketAddr {
+    let metadata = create_item();
+    let route = Route::from_metadata(metadata.clone(), &SchemaRegistry::new()).expect("route");
+    let config = ServerConfig {
+        openapi: Some(OpenApiConfig {
+            enabled: true,
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let handler: Arc<dyn Handler> = Arc::new(CreateItem { fail_at });
+    let router = build_router_with_handlers_and_config(vec![(route, handler)], config, vec![metadata]).expect("router");
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("bind");
+    listener.set_nonblocking(true).expect("nonblocking");
+    let addr = listener.local_addr().expect("addr");
+    std::thread::spawn(move || {
+        let runtime = tokio::runtime::Runtime::new().expect("runtime");
+        runtime.block_on(async move {
+            let listener = tokio::net::TcpListener::from_std(listener).expect("listener");
+            axum::serve(listener, router).await.expect("serve");
+        });
+    });
+    addr
+}
+
+fn run_contract(args: &[&str]) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_spikard"))
+        .args(["test", "contract"])
+        .args(args)
+        .output()
+        .expect("run spikard")
+}
+
+#[test]
+fn contract_passes_against_the_served_openapi_document() {
+    let addr = serve(None);
+    let output = run_contract(&["--url", &format!("http://{addr}")]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(
+        output.status.success(),
+        "stdout: {stdout}\nstderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(stdout.contains("All contract cases passed"), "{stdout}");
+    assert!(stdout.contains("1 routes"), "{stdout}");
+}
+
+#[test]
+fn contract_reports_shrunk_failures_and_exits_nonzero() {
+    let addr = serve(Some(5));
+    let dir = tempfile::tempdir().expect("tempdir");
+    let spec_path = dir.path().join("openapi.json");
+    let spec = json!({
+        "openapi": "3.1.0",
+        "info": {"title": "Items", "version": "1.0.0"},
+        "paths": {
+            "/items": {
+                "post": {
+                    "requestBody": {"content": {"application/json": {"schema": {"$ref": "#/components/schemas/NewItem"}}}},
+                    "responses": {"201": {"description": "created"}}
+                }
+            }
+        },
+        "components": {"schemas": {"NewItem": create_item().request_schema.expect("schema")}}
+    });
+    std::fs::write(&spec_path, spec.to_string()).expect("write spec");
+
+    let output = run_contract(&[
+        "--url",
+        &format!("http://{addr}/"),
+        "--spec",
+        spec_path.to_str().expect("utf-8 path"),
+    ]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(!output.status.success(), "{stdout}");
+    assert!(
+        stdout.contains("✗ POST /items: body `/name` at maxLength 5 (boundary)"),
+        "{stdout}"
+    );
+    assert!(stdout.contains(r#"Request: POST /items {"name":"aaaaa"}"#), "{stdout}");
+    assert!(
+        String::from_utf8_lossy(&output.stderr).contains("contract case(s) failed"),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn contract_requires_a_reachable_http_url() {
+    let output = run_contract(&["--url", "https://127.0.0.1:1"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("plain http://"));
+}
@@ -5,175 +5,15 @@ use super::spec::{
 };
 use crate::error::{CodegenError, Result};
 use indexmap::IndexMap;
-use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
 
-/// Test fixture structure (matching `testing_data`/*.json)
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Fixture {
-    pub name: String,
-    pub description: String,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub category: Option<String>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub handler: Option<FixtureHandler>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub streaming: Option<FixtureStreaming>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub background: Option<FixtureBackground>,
-
-    pub request: FixtureRequest,
-    pub expected_response: FixtureExpectedResponse,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub tags: Option<Vec<String>>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct FixtureStreaming {
-    /// Optional explicit content type for the stream (overrides headers)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_type: Option<String>,
-
-    /// Stream chunks that will be yielded sequentially
-    pub chunks: Vec<FixtureStreamChunk>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct FixtureBackground {
-    pub state_path: String,
-    pub state_key: String,
-    pub value_field: String,
-    pub expected_state: Vec<Value>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(tag = "type", rename_all = "snake_case")]
-pub enum FixtureStreamChunk {
-    /// UTF-8 text chunk
-    Text { value: String },
-    /// Arbitrary bytes encoded as base64 for portability
-    Bytes { base64: String },
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct FixtureHandler {
-    pub route: String,
-    pub method: String,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub parameters: Option<Value>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub body_schema: Option<Value>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub response_schema: Option<Value>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub cors: Option<Value>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub middleware: Option<Value>,
-
-    /// Dependency injection: app-level dependencies
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub dependencies: Option<Value>,
-
-    /// Dependency injection: dependencies required by this handler
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub handler_dependencies: Option<Value>,
-
-    /// Dependency injection: route-level dependency overrides
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub route_overrides: Option<Value>,
-
-    /// Dependency injection: injection strategy
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub injection_strategy: Option<String>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct FixtureRequest {
-    pub method: String,
-    pub path: String,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub query_params: Option<HashMap<String, Value>>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub headers: Option<HashMap<String, String>>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub cookies: Option<HashMap<String, String>>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub body: Option<Value>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub data: Option<HashMap<String, Value>>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub form_data: Option<HashMap<String, Value>>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub files: Option<Vec<FixtureFile>>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_type: Option<String>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct FixtureFile {
-    pub field_name: String,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub filename: Option<String>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub content: Option<String>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_type: Option<String>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_encoding: Option<String>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub magic_bytes: Option<String>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct FixtureExpectedResponse {
-    pub status_code: u16,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub body: Option<Value>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub body_partial: Option<Value>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub headers: Option<HashMap<String, String>>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub validation_errors: Option<Vec<ValidationError>>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ValidationError {
-    #[serde(rename = "type")]
-    pub error_type: String,
-    pub loc: Vec<String>,
-    pub msg: String,
-}
+pub use spikard_fixtures::{
+    Fixture, FixtureBackground, FixtureExpectedResponse, FixtureFile, FixtureHandler, FixtureRequest,
+    FixtureStreamChunk, FixtureStreaming, ValidationError,
+};
 
 /// Options for `OpenAPI` generation
 #[derive(Debug, Clone)]
@@ -271,11 +111,17 @@ pub fn load_fixtures_from_dir(dir: &Path) -> Result<Vec<Fixture>> {
 }
 
 /// Group fixtures by (path, method)
+///
+/// The handler's route template is preferred over the request path, so recorded requests
+/// to `/users/1` and `/users/2` describe the same `/users/{id}` operation.
 fn group_fixtures_by_route(fixtures: &[Fixture]) -> HashMap<(String, String), Vec<Fixture>> {
     let mut grouped: HashMap<(String, String), Vec<Fixture>> = HashMap::new();
 
     for fixture in fixtures {
-        let path = fixture.request.path.clone();
+        let path = fixture
+            .handler
+            .as_ref()
+            .map_or_else(|| fixture.request.path.clone(), |handler| handler.route.clone());
         let method = fixture.request.method.to_uppercase();
 
         grouped.entry((path, method)).or_default().push(fixture.clone());
@@ -312,6 +158,25 @@ fn build_operation(fixtures: &[Fixture], method: &str) -> Operation {
         }
     }
 
+    if operation.parameters.is_none() {
+        let parameters = infer_parameters(fixtures);
+        if !parameters.is_empty() {
+            operation.parameters = Some(parameters);
+        }
+    }
+
+    if operation.request_body.is_none()
+        && let Some(body) = fixtures
+            .iter()
+            .find_map(|fixture| fixture.request.body.as_ref().filter(|body| body.is_object()))
+    {
+        let mut request_body = build_request_body(&infer_schema(body));
+        if let Some(media_type) = request_body.content.get_mut("application/json") {
+            media_type.example = Some(body.clone());
+        }
+        operation.request_body = Some(request_body);
+    }
+
     let mut responses = IndexMap::new();
     for fixture in fixtures {
         let status = fixture.expected_response.status_code.to_string();
@@ -383,6 +248,75 @@ fn extract_parameters(params_schema: &Value) -> Vec<Parameter> {
     parameters
 }
 
+/// Infer path and query parameters from fixtures that carry no parameter schema
+///
+/// Path parameters come from the route template; query parameters are required when
+/// every fixture sends them, and typed after the first value seen.
+fn infer_parameters(fixtures: &[Fixture]) -> Vec<Parameter> {
+    let route = fixtures[0]
+        .handler
+        .as_ref()
+        .map_or(fixtures[0].request.path.as_str(), |handler| handler.route.as_str());
+
+    let mut parameters: Vec<Parameter> = route
+        .split('/')
+        .filter_map(|segment| segment.strip_prefix('{')?.strip_suffix('}'))
+        .map(|name| Parameter {
+            name: name.split(':').next().unwrap_or(name).to_string(),
+            location: "path".to_string(),
+            description: None,
+            required: Some(true),
+            schema: Some(json_to_schema(&serde_json::json!({"type": "string"}))),
+        })
+        .collect();
+
+    let mut query: IndexMap<String, (Value, usize)> = IndexMap::new();
+    for params in fixtures
+        .iter()
+        .filter_map(|fixture| fixture.request.query_params.as_ref())
+    {
+        let mut names: Vec<&String> = params.keys().collect();
+        names.sort();
+        for name in names {
+            query.entry(name.clone()).or_insert_with(|| (params[name].clone(), 0)).1 += 1;
+        }
+    }
+    for (name, (example, count)) in query {
+        parameters.push(Parameter {
+            name,
+            location: "query".to_string(),
+            description: None,
+            required: Some(count == fixtures.len()),
+            schema: Some(json_to_schema(&infer_schema(&example))),
+        });
+    }
+
+    parameters
+}
+
+/// Infer a JSON Schema describing an example value
+fn infer_schema(example: &Value) -> Value {
+    match example {
+        Value::Null => serde_json::json!({"type": "null"}),
+        Value::Bool(_) => serde_json::json!({"type": "boolean"}),
+        Value::Number(number) if number.is_f64() => serde_json::json!({"type": "number"}),
+        Value::Number(_) => serde_json::json!({"type": "integer"}),
+        Value::String(_) => serde_json::json!({"type": "string"}),
+        Value::Array(items) => items.first().map_or_else(
+            || serde_json::json!({"type": "array"}),
+            |item| serde_json::json!({"type": "array", "items": infer_schema(item)}),
+        ),
+        Value::Object(fields) => serde_json::json!({
+            "type": "object",
+            "properties": fields
+                .iter()
+                .map(|(name, value)| (name.clone(), infer_schema(value)))
+                .collect::<serde_json::Map<_, _>>(),
+            "required": fields.keys().collect::<Vec<_>>(),
+        }),
+    }
+}
+
 /// Build request body from schema
 fn build_request_body(schema: &Value) -> RequestBody {
     let mut content = IndexMap::new();
@@ -524,4 +458,62 @@ mod tests {
         assert_eq!(sanitize_path("/users/{id}"), "users_id");
         assert_eq!(sanitize_path("/api/v1/posts"), "api_v1_posts");
     }
+
+    #[test]
+    fn test_recorded_fixtures_are_grouped_by_route_template() {
+        let fixtures: Vec<Fixture> = serde_json::from_value(serde_json::json!([
+            {
+                "name": "get_users_id_200",
+                "description": "Recorded GET /users/1 -> 200",
+                "handler": {"route": "/users/{id}", "method": "GET"},
+                "request": {"method": "GET", "path": "/users/1", "query_params": {"expand": "teams", "page": "1"}},
+                "expected_response": {"status_code": 200, "body": {"id": 1}}
+            },
+            {
+                "name": "get_users_id_200_2",
+                "description": "Recorded GET /users/2 -> 404",
+                "handler": {"route": "/users/{id}", "method": "GET"},
+                "request": {"method": "GET", "path": "/users/2", "query_params": {"page": "1"}},
+                "expected_response": {"status_code": 404}
+            },
+            {
+                "name": "post_users_201",
+                "description": "Recorded POST /users -> 201",
+                "handler": {"route": "/users", "method": "POST"},
+                "request": {"method": "POST", "path": "/users", "body": {"name": "Ada", "tags": ["admin"], "age": 36}},
+                "expected_response": {"status_code": 201}
+            }
+        ]))
+        .unwrap();
+
+        let spec = fixtures_to_openapi(&fixtures, OpenApiOptions::default()).unwrap();
+        assert_eq!(spec.paths.len(), 2);
+
+        let get = spec.paths["/users/{id}"].get.as_ref().unwrap();
+        assert_eq!(get.responses.keys().collect::<Vec<_>>(), ["200", "404"]);
+        let parameters = get.parameters.as_ref().unwrap();
+        let summary: Vec<_> = parameters
+            .iter()
+            .map(|p| (p.name.as_str(), p.location.as_str(), p.required))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("id", "path", Some(true)),
+                ("expand", "query", Some(false)),
+                ("page", "query", Some(true))
+            ]
+        );
+
+        let post = spec.paths["/users"].post.as_ref().unwrap();
+        let media_type = &post.request_body.as_ref().unwrap().content["application/json"];
+        let Some(Schema::Object(schema)) = media_type.schema.as_ref() else {
+            panic!("expected an object schema");
+        };
+        let properties = schema.properties.as_ref().unwrap();
+        assert!(matches!(properties["age"].as_ref(), Schema::Object(age) if age.schema_type == "integer"));
+        assert!(matches!(properties["tags"].as_ref(), Schema::Object(tags) if tags.schema_type == "array"));
+        assert_eq!(schema.required.as_ref().unwrap().len(), 3);
+        assert_eq!(media_type.example.as_ref().unwrap()["name"], "Ada");
+    }
 }
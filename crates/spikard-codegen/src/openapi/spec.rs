@@ -69,12 +69,14 @@ pub struct Operation {
     pub description: Option<String>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "operationId")]
     pub operation_id: Option<String>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parameters: Option<Vec<Parameter>>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "requestBody")]
     pub request_body: Option<RequestBody>,
 
     pub responses: IndexMap<String, Response>,
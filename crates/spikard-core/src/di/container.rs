@@ -6,15 +6,29 @@
 use super::dependency::Dependency;
 use super::error::DependencyError;
 use super::graph::DependencyGraph;
+use super::report::{
+    DependencyConsumer, DependencyNode, DependencyReport, RouteDependencies, ScopeViolation, UnknownDependency,
+};
 use super::resolved::ResolvedDependencies;
 use crate::request_data::RequestData;
 use http::Request;
 use indexmap::IndexMap;
 use std::any::Any;
-use std::collections::HashMap;
-use std::sync::Arc;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::{Arc, PoisonError};
 use tokio::sync::RwLock;
 
+type SingletonCache = Arc<RwLock<HashMap<String, Arc<dyn Any + Send + Sync>>>>;
+
+/// Replacements laid over the registered dependencies
+#[derive(Default)]
+struct Overrides {
+    /// Replacement dependencies by key
+    dependencies: HashMap<String, Arc<dyn Dependency>>,
+    /// Singletons resolved for overridden keys and their dependents
+    singleton_cache: SingletonCache,
+}
+
 /// Main dependency injection container
 ///
 /// The container manages:
@@ -22,6 +36,7 @@ use tokio::sync::RwLock;
 /// - Batched parallel resolution using topological sorting
 /// - Singleton caching (global across all requests)
 /// - Request-scoped caching (within a single request)
+/// - Overrides that swap a registered dependency for tests
 ///
 /// # Thread Safety
 ///
@@ -75,7 +90,9 @@ pub struct DependencyContainer {
     /// Dependency graph for topological sorting and cycle detection
     dependency_graph: DependencyGraph,
     /// Global singleton cache
-    singleton_cache: Arc<RwLock<HashMap<String, Arc<dyn Any + Send + Sync>>>>,
+    singleton_cache: SingletonCache,
+    /// Active overrides, consulted on every resolution
+    overrides: std::sync::RwLock<Overrides>,
 }
 
 impl DependencyContainer {
@@ -94,6 +111,7 @@ impl DependencyContainer {
             dependencies: IndexMap::new(),
             dependency_graph: DependencyGraph::new(),
             singleton_cache: Arc::new(RwLock::new(HashMap::new())),
+            overrides: std::sync::RwLock::new(Overrides::default()),
         }
     }
 
@@ -225,10 +243,26 @@ impl DependencyContainer {
             }
         }
 
-        let batches = self.dependency_graph.calculate_batches(deps)?;
+        let (overrides, override_cache) = {
+            let active = self.overrides.read().unwrap_or_else(PoisonError::into_inner);
+            (active.dependencies.clone(), Arc::clone(&active.singleton_cache))
+        };
+
+        let batches = if overrides.is_empty() {
+            self.dependency_graph.calculate_batches(deps)?
+        } else {
+            let mut graph = self.dependency_graph.clone();
+            for (key, dep) in &overrides {
+                graph.replace_dependency(key, dep.depends_on());
+            }
+            graph.calculate_batches(deps)?
+        };
 
         let mut resolved = ResolvedDependencies::new();
         let mut request_cache: HashMap<String, Arc<dyn Any + Send + Sync>> = HashMap::new();
+        // Keys whose value may differ from the un-overridden one; their
+        // singletons live in the override cache so the real ones survive
+        let mut affected: HashSet<String> = HashSet::new();
 
         for batch in batches {
             // NOTE: We resolve sequentially within each batch to ensure cleanup tasks
@@ -237,13 +271,21 @@ impl DependencyContainer {
             sorted_keys.sort_by_key(|key| self.dependencies.get_index_of(*key).unwrap_or(usize::MAX));
 
             for key in sorted_keys {
-                let dep = self
-                    .dependencies
+                let dep = overrides
                     .get(key)
+                    .or_else(|| self.dependencies.get(key))
                     .ok_or_else(|| DependencyError::NotFound { key: key.clone() })?;
 
+                let singleton_cache =
+                    if overrides.contains_key(key) || dep.depends_on().iter().any(|parent| affected.contains(parent)) {
+                        affected.insert(key.clone());
+                        &override_cache
+                    } else {
+                        &self.singleton_cache
+                    };
+
                 if dep.singleton() {
-                    let cache = self.singleton_cache.read().await;
+                    let cache = singleton_cache.read().await;
                     if let Some(cached) = cache.get(key) {
                         resolved.insert(key.clone(), Arc::clone(cached));
                         continue;
@@ -260,7 +302,7 @@ impl DependencyContainer {
                 let result = dep.resolve(req, data, &resolved).await?;
 
                 if dep.singleton() {
-                    let mut cache = self.singleton_cache.write().await;
+                    let mut cache = singleton_cache.write().await;
                     cache.insert(key.clone(), Arc::clone(&result));
                 } else if dep.cacheable() {
                     request_cache.insert(key.clone(), Arc::clone(&result));
@@ -353,6 +395,260 @@ impl DependencyContainer {
         let mut cache = self.singleton_cache.write().await;
         cache.clear();
     }
+
+    /// Validate the wiring of the registered dependencies against the routes using them
+    ///
+    /// `routes` pairs a route label (`METHOD /path`) with the keys its handler
+    /// requires. The report lists unknown keys, singletons depending on
+    /// per-request dependencies, cycles and registrations no route reaches, and
+    /// carries the checked graph for debugging.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use spikard_core::di::{DependencyContainer, ValueDependency};
+    /// use std::sync::Arc;
+    ///
+    /// let mut container = DependencyContainer::new();
+    /// container
+    ///     .register("config".to_string(), Arc::new(ValueDependency::new("config", "production")))
+    ///     .unwrap();
+    ///
+    /// let deps = vec!["config".to_string(), "db".to_string()];
+    /// let report = container.validate([("GET /users", deps.as_slice())]);
+    /// assert!(!report.is_valid());
+    /// assert_eq!(report.unknown[0].key, "db");
+    /// ```
+    #[must_use]
+    pub fn validate<'a, I>(&self, routes: I) -> DependencyReport
+    where
+        I: IntoIterator<Item = (&'a str, &'a [String])>,
+    {
+        let mut report = DependencyReport::default();
+        let mut reachable: HashSet<&str> = HashSet::new();
+        let mut to_visit: VecDeque<&str> = VecDeque::new();
+
+        for (route, keys) in routes {
+            if keys.is_empty() {
+                continue;
+            }
+            for key in keys {
+                if !self.dependencies.contains_key(key) {
+                    report.unknown.push(UnknownDependency {
+                        key: key.clone(),
+                        required_by: DependencyConsumer::Route(route.to_string()),
+                    });
+                }
+                to_visit.push_back(key);
+            }
+            report.routes.push(RouteDependencies {
+                route: route.to_string(),
+                dependencies: keys.to_vec(),
+            });
+        }
+
+        for (key, dep) in &self.dependencies {
+            let depends_on = dep.depends_on();
+            for parent in &depends_on {
+                match self.dependencies.get(parent) {
+                    None => report.unknown.push(UnknownDependency {
+                        key: parent.clone(),
+                        required_by: DependencyConsumer::Dependency(key.clone()),
+                    }),
+                    Some(parent_dep) if dep.singleton() && !parent_dep.singleton() => {
+                        report.scope_violations.push(ScopeViolation {
+                            singleton: key.clone(),
+                            depends_on: parent.clone(),
+                        });
+                    }
+                    Some(_) => {}
+                }
+            }
+            report.dependencies.push(DependencyNode {
+                key: key.clone(),
+                depends_on,
+                singleton: dep.singleton(),
+                cacheable: dep.cacheable(),
+            });
+        }
+
+        if let Err(DependencyError::CircularDependency { cycle }) =
+            self.dependency_graph.calculate_batches(&self.keys())
+        {
+            report.cycle = Some(cycle);
+        }
+
+        while let Some(key) = to_visit.pop_front() {
+            if reachable.insert(key)
+                && let Some(node) = report.dependencies.iter().find(|node| node.key == key)
+            {
+                to_visit.extend(node.depends_on.iter().map(String::as_str));
+            }
+        }
+        report.unused = self
+            .dependencies
+            .keys()
+            .filter(|key| !reachable.contains(key.as_str()))
+            .cloned()
+            .collect();
+
+        report
+    }
+
+    /// Replace a registered dependency until the override is removed
+    ///
+    /// The override is used by every resolution that needs `key`, including
+    /// dependencies that depend on it. Singletons of overridden keys and their
+    /// dependents are cached separately, so the original singletons are used
+    /// again once the override is removed.
+    ///
+    /// # Returns
+    ///
+    /// The override previously in place for `key`, if any.
+    ///
+    /// # Errors
+    ///
+    /// `DependencyError::NotFound` if no dependency is registered under `key`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use spikard_core::di::{DependencyContainer, ValueDependency};
+    /// use std::sync::Arc;
+    ///
+    /// let mut container = DependencyContainer::new();
+    /// container
+    ///     .register("db_url".to_string(), Arc::new(ValueDependency::new("db_url", "postgres://prod")))
+    ///     .unwrap();
+    ///
+    /// container
+    ///     .set_override("db_url", Arc::new(ValueDependency::new("db_url", "sqlite::memory:")))
+    ///     .unwrap();
+    /// assert!(container.has_override("db_url"));
+    /// ```
+    pub fn set_override(
+        &self,
+        key: impl Into<String>,
+        dep: Arc<dyn Dependency>,
+    ) -> Result<Option<Arc<dyn Dependency>>, DependencyError> {
+        let key = key.into();
+        if !self.dependencies.contains_key(&key) {
+            return Err(DependencyError::NotFound { key });
+        }
+
+        let mut overrides = self.overrides.write().unwrap_or_else(PoisonError::into_inner);
+        overrides.singleton_cache = SingletonCache::default();
+        Ok(overrides.dependencies.insert(key, dep))
+    }
+
+    /// Remove the override for `key`, restoring the registered dependency
+    ///
+    /// Returns the removed override, if any.
+    pub fn remove_override(&self, key: &str) -> Option<Arc<dyn Dependency>> {
+        let mut overrides = self.overrides.write().unwrap_or_else(PoisonError::into_inner);
+        let removed = overrides.dependencies.remove(key);
+        if removed.is_some() {
+            overrides.singleton_cache = SingletonCache::default();
+        }
+        removed
+    }
+
+    /// Remove every override, restoring all registered dependencies
+    pub fn clear_overrides(&self) {
+        let mut overrides = self.overrides.write().unwrap_or_else(PoisonError::into_inner);
+        *overrides = Overrides::default();
+    }
+
+    /// Check if `key` is currently overridden
+    #[must_use]
+    pub fn has_override(&self, key: &str) -> bool {
+        self.overrides
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .dependencies
+            .contains_key(key)
+    }
+
+    /// Override `key` until the returned guard is dropped
+    ///
+    /// Dropping the guard puts back whatever override was in place before,
+    /// or the registered dependency if there was none, which makes nested
+    /// scopes restore in order.
+    ///
+    /// # Errors
+    ///
+    /// `DependencyError::NotFound` if no dependency is registered under `key`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use spikard_core::di::{DependencyContainer, ValueDependency};
+    /// use std::sync::Arc;
+    ///
+    /// let mut container = DependencyContainer::new();
+    /// container
+    ///     .register("db_pool".to_string(), Arc::new(ValueDependency::new("db_pool", "real")))
+    ///     .unwrap();
+    /// let container = Arc::new(container);
+    ///
+    /// {
+    ///     let _guard = container
+    ///         .override_scope("db_pool", Arc::new(ValueDependency::new("db_pool", "fake")))
+    ///         .unwrap();
+    ///     assert!(container.has_override("db_pool"));
+    /// }
+    /// assert!(!container.has_override("db_pool"));
+    /// ```
+    pub fn override_scope(
+        self: &Arc<Self>,
+        key: impl Into<String>,
+        dep: Arc<dyn Dependency>,
+    ) -> Result<DependencyOverrideGuard, DependencyError> {
+        let key = key.into();
+        let previous = self.set_override(key.clone(), dep)?;
+        Ok(DependencyOverrideGuard {
+            container: Arc::clone(self),
+            key,
+            previous,
+        })
+    }
+}
+
+/// Restores a dependency overridden through [`DependencyContainer::override_scope`] when dropped
+#[must_use = "the override is removed as soon as the guard is dropped"]
+pub struct DependencyOverrideGuard {
+    container: Arc<DependencyContainer>,
+    key: String,
+    previous: Option<Arc<dyn Dependency>>,
+}
+
+impl DependencyOverrideGuard {
+    /// The overridden dependency key
+    #[must_use]
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+}
+
+impl Drop for DependencyOverrideGuard {
+    fn drop(&mut self) {
+        match self.previous.take() {
+            Some(previous) => {
+                let _ = self.container.set_override(self.key.clone(), previous);
+            }
+            None => {
+                self.container.remove_override(&self.key);
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for DependencyOverrideGuard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DependencyOverrideGuard")
+            .field("key", &self.key)
+            .finish_non_exhaustive()
+    }
 }
 
 impl Default for DependencyContainer {
@@ -708,4 +1004,171 @@ mod tests {
         let debug_str = format!("{container:?}");
         assert!(debug_str.contains("DependencyContainer"));
     }
+
+    fn db_pool(calls: &Arc<AtomicU32>) -> FactoryDependency {
+        let calls = Arc::clone(calls);
+        FactoryDependency::builder("db_pool")
+            .depends_on(vec!["db_url".to_string()])
+            .singleton(true)
+            .factory(move |_req, _data, resolved| {
+                calls.fetch_add(1, Ordering::SeqCst);
+                let url: Arc<&'static str> = resolved.get("db_url").unwrap();
+                Box::pin(async move { Ok(Arc::new(format!("pool({url})")) as Arc<dyn Any + Send + Sync>) })
+            })
+            .build()
+            .unwrap()
+    }
+
+    async fn resolve_pool(container: &DependencyContainer) -> String {
+        let resolved = container
+            .resolve_for_handler(&["db_pool".to_string()], &make_request(), &make_request_data())
+            .await
+            .unwrap();
+        let pool: Arc<String> = resolved.get("db_pool").unwrap();
+        pool.as_str().to_string()
+    }
+
+    #[tokio::test]
+    async fn test_override_reaches_singleton_dependents_and_restores() {
+        let calls = Arc::new(AtomicU32::new(0));
+        let mut container = DependencyContainer::new();
+        container
+            .register("db_url".to_string(), Arc::new(ValueDependency::new("db_url", "prod")))
+            .unwrap();
+        container
+            .register("db_pool".to_string(), Arc::new(db_pool(&calls)))
+            .unwrap();
+
+        assert_eq!(resolve_pool(&container).await, "pool(prod)");
+
+        container
+            .set_override("db_url", Arc::new(ValueDependency::new("db_url", "test")))
+            .unwrap();
+        assert_eq!(resolve_pool(&container).await, "pool(test)");
+        assert_eq!(resolve_pool(&container).await, "pool(test)");
+
+        assert!(container.remove_override("db_url").is_some());
+        assert_eq!(resolve_pool(&container).await, "pool(prod)");
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_override_scope_restores_previous_override() {
+        let calls = Arc::new(AtomicU32::new(0));
+        let mut container = DependencyContainer::new();
+        container
+            .register("db_url".to_string(), Arc::new(ValueDependency::new("db_url", "prod")))
+            .unwrap();
+        container
+            .register("db_pool".to_string(), Arc::new(db_pool(&calls)))
+            .unwrap();
+        let container = Arc::new(container);
+
+        let fake = |name: &'static str| Arc::new(ValueDependency::new("db_pool", name.to_string()));
+        let outer = container.override_scope("db_pool", fake("outer")).unwrap();
+        {
+            let _inner = container.override_scope("db_pool", fake("inner")).unwrap();
+            assert_eq!(resolve_pool(&container).await, "inner");
+        }
+        assert_eq!(resolve_pool(&container).await, "outer");
+        drop(outer);
+
+        assert!(!container.has_override("db_pool"));
+        assert_eq!(resolve_pool(&container).await, "pool(prod)");
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_override_requires_registered_key() {
+        let container = DependencyContainer::new();
+        let result = container.set_override("missing", Arc::new(ValueDependency::new("missing", 1i32)));
+        assert!(matches!(result, Err(DependencyError::NotFound { .. })));
+        assert!(!container.has_override("missing"));
+    }
+
+    #[test]
+    fn test_validate_reports_wiring_problems() {
+        let calls = Arc::new(AtomicU32::new(0));
+        let mut container = DependencyContainer::new();
+        let request_id = FactoryDependency::builder("request_id")
+            .factory(|_req, _data, _resolved| Box::pin(async { Ok(Arc::new(1u32) as Arc<dyn Any + Send + Sync>) }))
+            .build()
+            .unwrap();
+        let session = FactoryDependency::builder("session")
+            .depends_on(vec!["request_id".to_string(), "secret".to_string()])
+            .singleton(true)
+            .factory(|_req, _data, _resolved| Box::pin(async { Ok(Arc::new(2u32) as Arc<dyn Any + Send + Sync>) }))
+            .build()
+            .unwrap();
+        container
+            .register("request_id".to_string(), Arc::new(request_id))
+            .unwrap();
+        container.register("session".to_string(), Arc::new(session)).unwrap();
+        container
+            .register("db_url".to_string(), Arc::new(ValueDependency::new("db_url", "prod")))
+            .unwrap();
+        container
+            .register("db_pool".to_string(), Arc::new(db_pool(&calls)))
+            .unwrap();
+        container
+            .register("metrics".to_string(), Arc::new(ValueDependency::new("metrics", 0u8)))
+            .unwrap();
+
+        let users = vec!["db_pool".to_string(), "session".to_string()];
+        let orders = vec!["cart".to_string()];
+        let report = container.validate([("GET /users", users.as_slice()), ("GET /orders", orders.as_slice())]);
+
+        assert!(!report.is_valid());
+        let unknown: Vec<_> = report
+            .unknown
+            .iter()
+            .map(|u| (u.key.as_str(), u.required_by.to_string()))
+            .collect();
+        assert_eq!(
+            unknown,
+            vec![
+                ("cart", "route GET /orders".to_string()),
+                ("secret", "dependency 'session'".to_string())
+            ]
+        );
+        assert_eq!(report.scope_violations.len(), 1);
+        assert_eq!(report.scope_violations[0].singleton, "session");
+        assert_eq!(report.scope_violations[0].depends_on, "request_id");
+        assert!(report.cycle.is_none());
+        assert_eq!(report.unused, vec!["metrics".to_string()]);
+
+        let text = report.to_string();
+        assert!(
+            text.contains("unknown dependency 'cart' required by route GET /orders"),
+            "{text}"
+        );
+        assert!(text.contains("unused registration 'metrics'"), "{text}");
+    }
+
+    #[test]
+    fn test_validate_dumps_graph() {
+        let calls = Arc::new(AtomicU32::new(0));
+        let mut container = DependencyContainer::new();
+        container
+            .register("db_url".to_string(), Arc::new(ValueDependency::new("db_url", "prod")))
+            .unwrap();
+        container
+            .register("db_pool".to_string(), Arc::new(db_pool(&calls)))
+            .unwrap();
+
+        let deps = vec!["db_pool".to_string()];
+        let report = container.validate([("GET /users", deps.as_slice())]);
+        assert!(report.is_clean());
+
+        let json = report.to_json();
+        assert_eq!(json["dependencies"][1]["key"], "db_pool");
+        assert_eq!(json["dependencies"][1]["depends_on"][0], "db_url");
+        assert_eq!(json["routes"][0]["route"], "GET /users");
+
+        let dot = report.to_dot();
+        assert!(dot.starts_with("digraph dependencies {"));
+        assert!(dot.contains("\"db_pool\" [shape=box];"), "{dot}");
+        assert!(dot.contains("\"GET /users\" -> \"db_pool\";"), "{dot}");
+        assert!(dot.contains("\"db_pool\" -> \"db_url\";"), "{dot}");
+    }
 }
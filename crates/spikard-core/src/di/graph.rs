@@ -103,6 +103,14 @@ impl DependencyGraph {
         Ok(())
     }
 
+    /// Replace the dependencies of `key`, adding it if it is not present
+    ///
+    /// Used to lay dependency overrides over the registered graph, where the
+    /// key is known to exist and only its edges change.
+    pub(crate) fn replace_dependency(&mut self, key: &str, depends_on: Vec<String>) {
+        self.graph.insert(key.to_string(), depends_on);
+    }
+
     /// Check if adding a new dependency would create a cycle
     ///
     /// Uses depth-first search to detect cycles in the graph if the new
@@ -180,12 +180,16 @@ mod dependency;
 pub mod error;
 mod factory;
 mod graph;
+mod report;
 mod resolved;
 mod value;
 
-pub use container::DependencyContainer;
+pub use container::{DependencyContainer, DependencyOverrideGuard};
 pub use dependency::Dependency;
 pub use error::DependencyError;
 pub use factory::{FactoryDependency, FactoryDependencyBuilder};
+pub use report::{
+    DependencyConsumer, DependencyNode, DependencyReport, RouteDependencies, ScopeViolation, UnknownDependency,
+};
 pub use resolved::ResolvedDependencies;
 pub use value::ValueDependency;
@@ -0,0 +1,176 @@
+//! Startup validation of dependency wiring
+//!
+//! This module provides [`DependencyReport`], produced by
+//! [`DependencyContainer::validate`](super::DependencyContainer::validate). It lists
+//! the wiring problems that would otherwise only surface when a request resolves
+//! the affected dependency, and can dump the graph it checked as JSON or DOT.
+
+use serde::Serialize;
+use std::fmt;
+use std::fmt::Write as _;
+
+/// Something that requires a dependency
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", content = "name", rename_all = "snake_case")]
+pub enum DependencyConsumer {
+    /// A route, identified as `METHOD /path`
+    Route(String),
+    /// Another registered dependency
+    Dependency(String),
+}
+
+impl fmt::Display for DependencyConsumer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Route(route) => write!(f, "route {route}"),
+            Self::Dependency(key) => write!(f, "dependency '{key}'"),
+        }
+    }
+}
+
+/// A dependency key that is required but not registered
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct UnknownDependency {
+    /// The missing key
+    pub key: String,
+    /// What requires it
+    pub required_by: DependencyConsumer,
+}
+
+/// A singleton that depends on a dependency resolved per request
+///
+/// The singleton would capture the value resolved for the first request and
+/// hand it to every later one.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ScopeViolation {
+    /// The singleton dependency
+    pub singleton: String,
+    /// The per-request dependency it depends on
+    pub depends_on: String,
+}
+
+/// A registered dependency as seen by the validator
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DependencyNode {
+    /// Dependency key
+    pub key: String,
+    /// Keys this dependency depends on
+    pub depends_on: Vec<String>,
+    /// Resolved once and shared across requests
+    pub singleton: bool,
+    /// Cached within a single request
+    pub cacheable: bool,
+}
+
+/// The dependencies a route asks for
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RouteDependencies {
+    /// Route, identified as `METHOD /path`
+    pub route: String,
+    /// Keys the handler requires
+    pub dependencies: Vec<String>,
+}
+
+/// Result of validating the dependency wiring of an application
+///
+/// Unknown keys, scope violations and cycles make the wiring invalid. Unused
+/// registrations are reported but do not, since dependencies may be consumed
+/// outside of routes (lifecycle hooks, GraphQL resolvers).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct DependencyReport {
+    /// Required keys that are not registered
+    pub unknown: Vec<UnknownDependency>,
+    /// Singletons depending on per-request dependencies
+    pub scope_violations: Vec<ScopeViolation>,
+    /// A cycle among the registered dependencies, e.g. `["a", "b", "a"]`
+    pub cycle: Option<Vec<String>>,
+    /// Registered keys that no route requires, directly or transitively
+    pub unused: Vec<String>,
+    /// Every registered dependency, in registration order
+    pub dependencies: Vec<DependencyNode>,
+    /// Every route that requires dependencies
+    pub routes: Vec<RouteDependencies>,
+}
+
+impl DependencyReport {
+    /// Check that no dependency can fail to resolve because of its wiring
+    #[must_use]
+    pub const fn is_valid(&self) -> bool {
+        self.unknown.is_empty() && self.scope_violations.is_empty() && self.cycle.is_none()
+    }
+
+    /// Check that the wiring is valid and every registration is used
+    #[must_use]
+    pub const fn is_clean(&self) -> bool {
+        self.is_valid() && self.unused.is_empty()
+    }
+
+    /// Dump the report, including the checked graph, as JSON
+    #[must_use]
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or(serde_json::Value::Null)
+    }
+
+    /// Dump the checked graph in Graphviz DOT format
+    ///
+    /// Singletons are drawn as boxes, per-request dependencies as ellipses,
+    /// routes as notes, and unknown keys in red.
+    #[must_use]
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph dependencies {\n    rankdir=LR;\n");
+        for node in &self.dependencies {
+            let shape = if node.singleton { "box" } else { "ellipse" };
+            let _ = writeln!(dot, "    {} [shape={shape}];", quote(&node.key));
+        }
+        for unknown in &self.unknown {
+            let _ = writeln!(dot, "    {} [color=red, style=dashed];", quote(&unknown.key));
+        }
+        for route in &self.routes {
+            let _ = writeln!(dot, "    {} [shape=note];", quote(&route.route));
+            for key in &route.dependencies {
+                let _ = writeln!(dot, "    {} -> {};", quote(&route.route), quote(key));
+            }
+        }
+        for node in &self.dependencies {
+            for parent in &node.depends_on {
+                let _ = writeln!(dot, "    {} -> {};", quote(&node.key), quote(parent));
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+impl fmt::Display for DependencyReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_clean() {
+            return write!(f, "dependency wiring is valid");
+        }
+        write!(f, "dependency wiring problems:")?;
+        for unknown in &self.unknown {
+            write!(
+                f,
+                "\n  - unknown dependency '{}' required by {}",
+                unknown.key, unknown.required_by
+            )?;
+        }
+        for violation in &self.scope_violations {
+            write!(
+                f,
+                "\n  - singleton '{}' depends on per-request dependency '{}'",
+                violation.singleton, violation.depends_on
+            )?;
+        }
+        if let Some(cycle) = &self.cycle {
+            write!(f, "\n  - circular dependency: {}", cycle.join(" -> "))?;
+        }
+        for key in &self.unused {
+            write!(f, "\n  - unused registration '{key}'")?;
+        }
+        Ok(())
+    }
+}
+
+fn quote(id: &str) -> String {
+    format!("\"{}\"", id.replace('\\', "\\\\").replace('"', "\\\""))
+}
@@ -0,0 +1,116 @@
+//! String-keyed map for per-client state that expires
+//!
+//! Rate-limit buckets, in-memory sessions and GraphQL cost budgets all keep one entry per
+//! client and drop entries once they expire. Expired entries are swept lazily: a sweep only
+//! runs once the map has grown to twice its size after the previous sweep, so the cost of
+//! sweeping stays amortised over the inserts that made it necessary.
+
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+
+/// Minimum number of entries before expired ones are swept
+pub const SWEEP_THRESHOLD: usize = 4096;
+
+/// Map whose expired entries are removed by [`ExpiringMap::sweep`]
+#[derive(Debug, Clone)]
+pub struct ExpiringMap<V> {
+    entries: HashMap<String, V>,
+    sweep_at: usize,
+}
+
+impl<V> ExpiringMap<V> {
+    /// Create an empty map
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            entries: HashMap::new(),
+            sweep_at: SWEEP_THRESHOLD,
+        }
+    }
+
+    /// Number of entries, including expired ones not swept yet
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the map holds no entries
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    #[must_use]
+    pub fn get(&self, key: &str) -> Option<&V> {
+        self.entries.get(key)
+    }
+
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut V> {
+        self.entries.get_mut(key)
+    }
+
+    pub fn entry(&mut self, key: String) -> Entry<'_, String, V> {
+        self.entries.entry(key)
+    }
+
+    pub fn insert(&mut self, key: String, value: V) -> Option<V> {
+        self.entries.insert(key, value)
+    }
+
+    pub fn remove(&mut self, key: &str) -> Option<V> {
+        self.entries.remove(key)
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &String> {
+        self.entries.keys()
+    }
+
+    /// Drop every entry for which `live` returns `false`, once the map is due for a sweep
+    ///
+    /// Call this after inserting; it is a no-op until the map reaches its sweep threshold.
+    pub fn sweep(&mut self, mut live: impl FnMut(&V) -> bool) {
+        if self.entries.len() >= self.sweep_at {
+            self.entries.retain(|_, value| live(value));
+            self.sweep_at = (self.entries.len() * 2).max(SWEEP_THRESHOLD);
+        }
+    }
+}
+
+impl<V> Default for ExpiringMap<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sweep_waits_for_threshold() {
+        let mut map = ExpiringMap::new();
+        for key in 0..SWEEP_THRESHOLD - 1 {
+            map.insert(key.to_string(), false);
+        }
+        map.sweep(|live| *live);
+        assert_eq!(map.len(), SWEEP_THRESHOLD - 1);
+
+        map.insert("live".to_string(), true);
+        map.sweep(|live| *live);
+        assert_eq!(map.keys().collect::<Vec<_>>(), ["live"]);
+    }
+
+    #[test]
+    fn test_sweep_threshold_grows_with_live_entries() {
+        let mut map = ExpiringMap::new();
+        for key in 0..SWEEP_THRESHOLD {
+            map.insert(key.to_string(), true);
+        }
+        map.sweep(|live| *live);
+        assert_eq!(map.sweep_at, SWEEP_THRESHOLD * 2);
+
+        map.insert("dead".to_string(), false);
+        map.sweep(|live| *live);
+        assert!(map.get("dead").is_some());
+    }
+}
@@ -194,6 +194,15 @@ pub struct RouteMetadata {
     /// Optional per-route request timeout in seconds, overriding the server-global default
     #[serde(skip_serializing_if = "Option::is_none")]
     pub request_timeout_secs: Option<u64>,
+    /// Optional per-route rate limit, applied in addition to the server-global limit
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate_limit: Option<RateLimitConfig>,
+    /// Optional HTTP caching metadata (`ETag`, `Cache-Control`, `Vary`) and conditional request handling
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub caching: Option<CachingConfig>,
+    /// Optional server-side caching of whole responses (GET routes only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response_cache: Option<ResponseCacheConfig>,
     /// Name of the body parameter (defaults to "body" if not specified)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub body_param_name: Option<String>,
@@ -209,6 +218,12 @@ pub struct RouteMetadata {
     /// middleware pipeline for maximum throughput.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub static_response: Option<Value>,
+    /// `OpenAPI` tags, e.g. inherited from the route's groups
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Optional per-route authentication, applied in addition to server-wide authentication
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth: Option<RouteAuthConfig>,
 }
 
 impl Default for RouteMetadata {
@@ -231,6 +246,11 @@ impl Default for RouteMetadata {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            tags: Vec::new(),
+            auth: None,
         }
     }
 }
@@ -275,16 +295,58 @@ impl Default for CompressionConfig {
     }
 }
 
+/// Source of the key a rate limit bucket is tracked under
+///
+/// Requests that share a key share a bucket. When the key cannot be derived from a
+/// request (no JWT, no API key header, ...), the client IP is used instead.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RateLimitKey {
+    /// Peer IP address of the client
+    #[default]
+    Ip,
+    /// A single bucket shared by every client
+    Global,
+    /// The `sub` claim of a validated JWT
+    JwtSubject,
+    /// The API key sent by the client (defaults to the configured API key header)
+    ApiKey {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        header: Option<String>,
+    },
+    /// The value of an arbitrary request header
+    Header { name: String },
+    /// A key computed by a registered key extractor (e.g. a binding callback)
+    Custom,
+}
+
 /// Rate limiting configuration shared across runtimes
+///
+/// Used both server-wide and per route via [`RouteMetadata::rate_limit`].
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RateLimitConfig {
     /// Requests per second
     pub per_second: u64,
     /// Burst allowance
     pub burst: u32,
-    /// Use IP-based rate limiting
+    /// Use IP-based rate limiting (ignored when `key` is set)
     #[serde(default = "default_true")]
     pub ip_based: bool,
+    /// How requests are grouped into buckets; derived from `ip_based` when unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<RateLimitKey>,
+}
+
+impl RateLimitConfig {
+    /// The key source in effect, honouring the legacy `ip_based` flag when `key` is unset
+    #[must_use]
+    pub fn effective_key(&self) -> RateLimitKey {
+        self.key.clone().unwrap_or(if self.ip_based {
+            RateLimitKey::Ip
+        } else {
+            RateLimitKey::Global
+        })
+    }
 }
 
 impl Default for RateLimitConfig {
@@ -293,10 +355,142 @@ impl Default for RateLimitConfig {
             per_second: 100,
             burst: 200,
             ip_based: true,
+            key: None,
+        }
+    }
+}
+
+/// How a route's `ETag` is produced
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ETagMode {
+    /// Weak validator (`W/"..."`) hashed from the response body
+    #[default]
+    Weak,
+    /// Strong validator hashed from the response body
+    Strong,
+    /// Only use an `ETag` header set by the handler
+    Handler,
+}
+
+/// HTTP caching metadata for a route
+///
+/// Successful `GET`/`HEAD` responses get an `ETag` (unless the handler sets one), the
+/// configured `Cache-Control` and `Vary` headers, and are answered with `304 Not Modified`
+/// when `If-None-Match` or `If-Modified-Since` match. `If-Match` and `If-Unmodified-Since`
+/// on unsafe methods are checked against the path's `GET` representation and answered with
+/// `412 Precondition Failed` when they do not match.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct CachingConfig {
+    /// How the `ETag` is produced
+    #[serde(default)]
+    pub etag: ETagMode,
+    /// `Cache-Control` header added unless the handler sets one (e.g. `"private, max-age=60"`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache_control: Option<String>,
+    /// Request headers the response varies by, added to `Vary`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub vary: Vec<String>,
+}
+
+/// Server-side response caching for a route
+///
+/// Successful responses are stored under a key built from the method, route template,
+/// request path, the selected query parameters and headers, and the JWT subject or API-key
+/// identity when the request is authenticated. Concurrent misses for the same key share a
+/// single handler call.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResponseCacheConfig {
+    /// How long a stored response is served without calling the handler
+    #[serde(default = "default_response_cache_ttl_secs")]
+    pub ttl_secs: u64,
+    /// How long after expiry a stale response is still served while it is refreshed in the background
+    #[serde(default)]
+    pub stale_while_revalidate_secs: u64,
+    /// Query parameters that are part of the cache key (others are ignored)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub query_params: Vec<String>,
+    /// Request headers that are part of the cache key
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub headers: Vec<String>,
+    /// Tags attached to stored responses; `{name}` is replaced by the path parameter `name`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Larger responses, and responses of unknown length, are passed through uncached
+    #[serde(default = "default_response_cache_max_entry_bytes")]
+    pub max_entry_bytes: usize,
+}
+
+const fn default_response_cache_ttl_secs() -> u64 {
+    60
+}
+
+const fn default_response_cache_max_entry_bytes() -> usize {
+    1024 * 1024
+}
+
+impl Default for ResponseCacheConfig {
+    fn default() -> Self {
+        Self {
+            ttl_secs: default_response_cache_ttl_secs(),
+            stale_while_revalidate_secs: 0,
+            query_params: Vec::new(),
+            headers: Vec::new(),
+            tags: Vec::new(),
+            max_entry_bytes: default_response_cache_max_entry_bytes(),
         }
     }
 }
 
+/// JWT authentication configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JwtConfig {
+    /// Secret key for JWT verification
+    pub secret: String,
+    /// Required algorithm (HS256, HS384, HS512, RS256, etc.)
+    #[serde(default = "default_jwt_algorithm")]
+    pub algorithm: String,
+    /// Required audience claim
+    pub audience: Option<Vec<String>>,
+    /// Required issuer claim
+    pub issuer: Option<String>,
+    /// Leeway for expiration checks (seconds)
+    #[serde(default)]
+    pub leeway: u64,
+}
+
+fn default_jwt_algorithm() -> String {
+    "HS256".to_string()
+}
+
+/// API Key authentication configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiKeyConfig {
+    /// Valid API keys
+    pub keys: Vec<String>,
+    /// Header name to check (e.g., "X-API-Key")
+    #[serde(default = "default_api_key_header")]
+    pub header_name: String,
+}
+
+fn default_api_key_header() -> String {
+    "X-API-Key".to_string()
+}
+
+/// Authentication required by a route
+///
+/// Used per route via [`RouteMetadata::auth`], on top of the server-wide `jwt_auth` and
+/// `api_key_auth`. When both schemes are set a request must satisfy both.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RouteAuthConfig {
+    /// Require a valid JWT bearer token
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jwt: Option<JwtConfig>,
+    /// Require a valid API key
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_key: Option<ApiKeyConfig>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -476,6 +670,30 @@ mod tests {
         assert_eq!(config.per_second, 100);
         assert_eq!(config.burst, 200);
         assert!(config.ip_based);
+        assert_eq!(config.effective_key(), RateLimitKey::Ip);
+    }
+
+    #[test]
+    fn test_rate_limit_key_overrides_ip_based() {
+        let config: RateLimitConfig = serde_json::from_value(serde_json::json!({
+            "per_second": 5,
+            "burst": 10,
+            "ip_based": true,
+            "key": {"type": "header", "name": "x-tenant"}
+        }))
+        .unwrap();
+        assert_eq!(
+            config.effective_key(),
+            RateLimitKey::Header {
+                name: "x-tenant".to_string()
+            }
+        );
+
+        let legacy = RateLimitConfig {
+            ip_based: false,
+            ..RateLimitConfig::default()
+        };
+        assert_eq!(legacy.effective_key(), RateLimitKey::Global);
     }
 
     #[test]
@@ -511,6 +729,7 @@ mod tests {
             per_second: 50,
             burst: 100,
             ip_based: false,
+            key: None,
         };
         assert_eq!(config.per_second, 50);
         assert_eq!(config.burst, 100);
@@ -553,6 +772,11 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            tags: Vec::new(),
+            auth: None,
         };
         assert_eq!(metadata.method, "GET");
         assert_eq!(metadata.path, "/api/users");
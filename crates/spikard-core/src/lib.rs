@@ -2,6 +2,7 @@ pub mod bindings;
 #[cfg(feature = "di")]
 pub mod di;
 pub mod errors;
+pub mod expiring;
 pub mod http;
 pub mod lifecycle;
 pub mod parameters;
@@ -15,10 +16,14 @@ pub mod validation;
 pub use bindings::response::{RawResponse, StaticAsset};
 #[cfg(feature = "di")]
 pub use di::{
-    Dependency, DependencyContainer, DependencyError, FactoryDependency, FactoryDependencyBuilder,
-    ResolvedDependencies, ValueDependency,
+    Dependency, DependencyContainer, DependencyError, DependencyOverrideGuard, DependencyReport, FactoryDependency,
+    FactoryDependencyBuilder, ResolvedDependencies, ValueDependency,
+};
+pub use expiring::ExpiringMap;
+pub use http::{
+    ApiKeyConfig, CachingConfig, CompressionConfig, CorsConfig, ETagMode, JwtConfig, Method, RateLimitConfig,
+    RateLimitKey, ResponseCacheConfig, RouteAuthConfig, RouteMetadata,
 };
-pub use http::{CompressionConfig, CorsConfig, Method, RateLimitConfig, RouteMetadata};
 pub use lifecycle::{HookResult, LifecycleHook, LifecycleHooks, LifecycleHooksBuilder, request_hook, response_hook};
 pub use parameters::ParameterValidator;
 pub use problem::ProblemDetails;
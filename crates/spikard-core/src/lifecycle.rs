@@ -62,7 +62,6 @@ type CoreHook<Req, Resp> = dyn LocalLifecycleHook<Req, Resp>;
 pub type TargetLifecycleHooks<Req, Resp> = LifecycleHooks<Req, Resp>;
 
 /// Container for all lifecycle hooks
-#[derive(Clone)]
 pub struct LifecycleHooks<Req, Resp> {
     on_request: Vec<Arc<CoreHook<Req, Resp>>>,
     pre_validation: Vec<Arc<CoreHook<Req, Resp>>>,
@@ -71,6 +70,19 @@ pub struct LifecycleHooks<Req, Resp> {
     on_error: Vec<Arc<CoreHook<Req, Resp>>>,
 }
 
+// Manual impl: hooks are shared through `Arc`, so the carriers need not be `Clone`
+impl<Req, Resp> Clone for LifecycleHooks<Req, Resp> {
+    fn clone(&self) -> Self {
+        Self {
+            on_request: self.on_request.clone(),
+            pre_validation: self.pre_validation.clone(),
+            pre_handler: self.pre_handler.clone(),
+            on_response: self.on_response.clone(),
+            on_error: self.on_error.clone(),
+        }
+    }
+}
+
 impl<Req, Resp> Default for LifecycleHooks<Req, Resp> {
     fn default() -> Self {
         Self {
@@ -118,6 +130,18 @@ impl<Req, Resp> LifecycleHooks<Req, Resp> {
             && self.on_error.is_empty()
     }
 
+    /// Append every hook registered in `other` after the hooks already registered here
+    ///
+    /// Used to layer route- and group-scoped hooks on top of the global hooks, so the
+    /// global hooks of each phase always run first.
+    pub fn extend(&mut self, other: &Self) {
+        self.on_request.extend(other.on_request.iter().cloned());
+        self.pre_validation.extend(other.pre_validation.iter().cloned());
+        self.pre_handler.extend(other.pre_handler.iter().cloned());
+        self.on_response.extend(other.on_response.iter().cloned());
+        self.on_error.extend(other.on_error.iter().cloned());
+    }
+
     pub fn add_on_request(&mut self, hook: Arc<CoreHook<Req, Resp>>) {
         self.on_request.push(hook);
     }
@@ -100,6 +100,14 @@ pub struct Route {
     pub body_limit: Option<usize>,
     /// Optional per-route request timeout in seconds, overriding the server-global default
     pub request_timeout_secs: Option<u64>,
+    /// Optional per-route rate limit, applied in addition to the server-global limit
+    pub rate_limit: Option<crate::http::RateLimitConfig>,
+    /// Optional HTTP caching metadata and conditional request handling
+    pub caching: Option<crate::http::CachingConfig>,
+    /// Optional server-side response caching
+    pub response_cache: Option<crate::http::ResponseCacheConfig>,
+    /// Optional per-route authentication, applied in addition to server-wide authentication
+    pub auth: Option<crate::http::RouteAuthConfig>,
 }
 
 impl Default for Route {
@@ -121,6 +129,10 @@ impl Default for Route {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            auth: None,
         }
     }
 }
@@ -203,6 +215,10 @@ impl Route {
             compression: metadata.compression,
             body_limit: metadata.body_limit,
             request_timeout_secs: metadata.request_timeout_secs,
+            rate_limit: metadata.rate_limit,
+            caching: metadata.caching,
+            response_cache: metadata.response_cache,
+            auth: metadata.auth,
         })
     }
 
@@ -301,6 +317,11 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            tags: Vec::new(),
+            auth: None,
             #[cfg(feature = "di")]
             handler_dependencies: None,
         };
@@ -339,6 +360,11 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            tags: Vec::new(),
+            auth: None,
             #[cfg(feature = "di")]
             handler_dependencies: None,
         };
@@ -375,6 +401,11 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            tags: Vec::new(),
+            auth: None,
             #[cfg(feature = "di")]
             handler_dependencies: None,
         };
@@ -395,6 +426,11 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            tags: Vec::new(),
+            auth: None,
             #[cfg(feature = "di")]
             handler_dependencies: None,
         };
@@ -477,6 +513,11 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            tags: Vec::new(),
+            auth: None,
             #[cfg(feature = "di")]
             handler_dependencies: None,
         };
@@ -554,6 +595,11 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            tags: Vec::new(),
+            auth: None,
             #[cfg(feature = "di")]
             handler_dependencies: None,
         };
@@ -0,0 +1,182 @@
+//! Shared e2e fixture format for Spikard
+//!
+//! The JSON fixtures under `testing_data/` describe a handler, a request and the expected
+//! response. They are read by `spikard-codegen` to derive `OpenAPI` specs and written by the
+//! recording middleware in `spikard-http`, so both sides use the types defined here. Maps are
+//! serialized with sorted keys so written fixtures are stable across runs.
+
+use serde::{Deserialize, Serialize, Serializer};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+
+/// Test fixture structure (matching `testing_data`/*.json)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Fixture {
+    pub name: String,
+    pub description: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub handler: Option<FixtureHandler>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub streaming: Option<FixtureStreaming>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub background: Option<FixtureBackground>,
+
+    pub request: FixtureRequest,
+    pub expected_response: FixtureExpectedResponse,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FixtureStreaming {
+    /// Optional explicit content type for the stream (overrides headers)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+
+    /// Stream chunks that will be yielded sequentially
+    pub chunks: Vec<FixtureStreamChunk>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FixtureBackground {
+    pub state_path: String,
+    pub state_key: String,
+    pub value_field: String,
+    pub expected_state: Vec<Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum FixtureStreamChunk {
+    /// UTF-8 text chunk
+    Text { value: String },
+    /// Arbitrary bytes encoded as base64 for portability
+    Bytes { base64: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct FixtureHandler {
+    pub route: String,
+    pub method: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body_schema: Option<Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_schema: Option<Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cors: Option<Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub middleware: Option<Value>,
+
+    /// Dependency injection: app-level dependencies
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dependencies: Option<Value>,
+
+    /// Dependency injection: dependencies required by this handler
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub handler_dependencies: Option<Value>,
+
+    /// Dependency injection: route-level dependency overrides
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub route_overrides: Option<Value>,
+
+    /// Dependency injection: injection strategy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub injection_strategy: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct FixtureRequest {
+    pub method: String,
+    pub path: String,
+
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "sorted")]
+    pub query_params: Option<HashMap<String, Value>>,
+
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "sorted")]
+    pub headers: Option<HashMap<String, String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "sorted")]
+    pub cookies: Option<HashMap<String, String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "sorted")]
+    pub data: Option<HashMap<String, Value>>,
+
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "sorted")]
+    pub form_data: Option<HashMap<String, Value>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub files: Option<Vec<FixtureFile>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FixtureFile {
+    pub field_name: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filename: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_encoding: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub magic_bytes: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct FixtureExpectedResponse {
+    pub status_code: u16,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body_partial: Option<Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "sorted")]
+    pub headers: Option<HashMap<String, String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub validation_errors: Option<Vec<ValidationError>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValidationError {
+    #[serde(rename = "type")]
+    pub error_type: String,
+    pub loc: Vec<String>,
+    pub msg: String,
+}
+
+/// Serialize a map with its keys in sorted order
+#[expect(clippy::ref_option, reason = "serde's serialize_with passes the field by reference")]
+fn sorted<S: Serializer, V: Serialize>(map: &Option<HashMap<String, V>>, serializer: S) -> Result<S::Ok, S::Error> {
+    map.as_ref()
+        .map(|map| map.iter().collect::<BTreeMap<_, _>>())
+        .serialize(serializer)
+}
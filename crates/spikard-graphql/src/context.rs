@@ -0,0 +1,206 @@
+//! Per-request context injected into GraphQL resolvers.
+//!
+//! Both the typed [`GraphQLExecutor`](crate::executor::GraphQLExecutor) and the dynamic-SDL
+//! executor in [`crate::dynamic`] accept an optional [`GraphQLRequestContext`]. When present, its
+//! parts are attached to the `async-graphql` request as context data, so resolvers can read them
+//! with `ctx.data::<T>()`:
+//!
+//! | Type | Present when |
+//! |------|--------------|
+//! | [`RequestData`] | always |
+//! | [`Claims`] | the JWT middleware validated a bearer token |
+//! | `Arc<ResolvedDependencies>` | the route was wrapped by the DI handler (`di` feature) |
+//!
+//! ```ignore
+//! #[Object]
+//! impl Query {
+//!     async fn me(&self, ctx: &Context<'_>) -> async_graphql::Result<String> {
+//!         let claims = ctx.data::<Claims>()?;
+//!         Ok(claims.sub.clone())
+//!     }
+//! }
+//! ```
+
+use axum::body::Body;
+use axum::extract::ConnectInfo;
+use axum::http::Request as HttpRequest;
+use spikard_http::auth::INTERNAL_JWT_CLAIMS_HEADER;
+use spikard_http::{Claims, ClientInfo, RequestData};
+use std::net::{IpAddr, SocketAddr};
+
+#[cfg(feature = "di")]
+use spikard_core::di::ResolvedDependencies;
+#[cfg(feature = "di")]
+use std::sync::Arc;
+
+/// Caller identity, headers and DI-resolved services for a single GraphQL request.
+#[derive(Debug, Clone)]
+pub struct GraphQLRequestContext {
+    request_data: RequestData,
+    claims: Option<Claims>,
+    client_ip: Option<IpAddr>,
+}
+
+impl GraphQLRequestContext {
+    /// Build a context from the extracted request data alone.
+    ///
+    /// Claims are recovered from the internal claims header that the server inserts for
+    /// handlers that receive headers.
+    #[must_use]
+    pub fn new(request_data: RequestData) -> Self {
+        let claims = request_data
+            .headers
+            .get(INTERNAL_JWT_CLAIMS_HEADER)
+            .and_then(|raw| serde_json::from_str::<Claims>(raw).ok());
+        let client_ip = request_data.client_ip.as_deref().and_then(|ip| ip.parse().ok());
+        Self {
+            request_data,
+            claims,
+            client_ip,
+        }
+    }
+
+    /// Build a context from the raw HTTP request and its extracted data.
+    ///
+    /// Claims placed in the request extensions by the JWT middleware take precedence over the
+    /// internal claims header. The client IP is the one resolved through trusted proxies, falling
+    /// back to the connection info when the server records it.
+    #[must_use]
+    pub fn from_request(request: &HttpRequest<Body>, request_data: RequestData) -> Self {
+        let mut context = Self::new(request_data);
+        if let Some(claims) = request.extensions().get::<Claims>() {
+            context.claims = Some(claims.clone());
+        }
+        if let Some(client) = request.extensions().get::<ClientInfo>() {
+            context.client_ip = client.ip;
+        } else if let Some(ConnectInfo(addr)) = request.extensions().get::<ConnectInfo<SocketAddr>>() {
+            context.client_ip = Some(addr.ip());
+        }
+        context
+    }
+
+    /// Override the decoded auth claims.
+    #[must_use]
+    pub fn with_claims(mut self, claims: Claims) -> Self {
+        self.claims = Some(claims);
+        self
+    }
+
+    /// Override the client IP address.
+    #[must_use]
+    pub const fn with_client_ip(mut self, client_ip: IpAddr) -> Self {
+        self.client_ip = Some(client_ip);
+        self
+    }
+
+    /// The extracted request data (headers, cookies, path, query params, body).
+    #[must_use]
+    pub const fn request_data(&self) -> &RequestData {
+        &self.request_data
+    }
+
+    /// The decoded JWT claims, if the request was authenticated.
+    #[must_use]
+    pub const fn claims(&self) -> Option<&Claims> {
+        self.claims.as_ref()
+    }
+
+    /// The client IP address, if known.
+    #[must_use]
+    pub const fn client_ip(&self) -> Option<IpAddr> {
+        self.client_ip
+    }
+
+    /// Look up a request header by lowercase name.
+    #[must_use]
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.request_data.headers.get(name).map(String::as_str)
+    }
+
+    /// The dependencies resolved by the DI handler for this request.
+    #[cfg(feature = "di")]
+    #[must_use]
+    pub const fn dependencies(&self) -> Option<&Arc<ResolvedDependencies>> {
+        self.request_data.dependencies.as_ref()
+    }
+
+    /// Attach this context to an `async-graphql` request as resolver context data.
+    #[must_use]
+    pub fn apply(self, mut request: async_graphql::Request) -> async_graphql::Request {
+        #[cfg(feature = "di")]
+        if let Some(dependencies) = self.request_data.dependencies.clone() {
+            request = request.data(dependencies);
+        }
+        if let Some(claims) = self.claims {
+            request = request.data(claims);
+        }
+        request.data(self.request_data)
+    }
+}
+
+/// Build a `RequestData` for unit tests without depending on which optional fields are enabled.
+#[cfg(test)]
+pub(crate) fn request_data_for_tests(path: &str, headers: &std::collections::HashMap<String, String>) -> RequestData {
+    serde_json::from_value(serde_json::json!({
+        "path_params": {},
+        "query_params": {},
+        "raw_query_params": {},
+        "body": null,
+        "headers": headers,
+        "cookies": {},
+        "method": "POST",
+        "path": path,
+    }))
+    .expect("valid request data")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn claims(sub: &str) -> Claims {
+        Claims {
+            sub: sub.to_string(),
+            exp: 4_102_444_800,
+            iat: None,
+            nbf: None,
+            aud: None,
+            iss: None,
+        }
+    }
+
+    #[test]
+    fn test_claims_recovered_from_internal_header() {
+        let mut headers = HashMap::new();
+        headers.insert(
+            INTERNAL_JWT_CLAIMS_HEADER.to_string(),
+            serde_json::to_string(&claims("user-1")).unwrap(),
+        );
+        let request_data = request_data_for_tests("/graphql", &headers);
+
+        let context = GraphQLRequestContext::new(request_data);
+        assert_eq!(context.claims().map(|c| c.sub.as_str()), Some("user-1"));
+    }
+
+    #[test]
+    fn test_extension_claims_take_precedence() {
+        let mut request = HttpRequest::new(Body::empty());
+        request.extensions_mut().insert(claims("from-extension"));
+
+        let context =
+            GraphQLRequestContext::from_request(&request, request_data_for_tests("/graphql", &HashMap::new()));
+        assert_eq!(context.claims().map(|c| c.sub.as_str()), Some("from-extension"));
+    }
+
+    #[test]
+    fn test_header_lookup() {
+        let mut headers = HashMap::new();
+        headers.insert("x-tenant".to_string(), "acme".to_string());
+        let request_data = request_data_for_tests("/graphql", &headers);
+
+        let context = GraphQLRequestContext::new(request_data);
+        assert_eq!(context.header("x-tenant"), Some("acme"));
+        assert!(context.claims().is_none());
+    }
+}
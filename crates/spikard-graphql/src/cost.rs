@@ -0,0 +1,767 @@
+//! Operation cost analysis and per-client cost budgets.
+//!
+//! `max_complexity` caps a single query using `async-graphql`'s built-in complexity counter,
+//! which treats every field alike. Cost analysis instead weighs fields individually:
+//!
+//! - Field weights come from `@cost(weight: N)` directives in the SDL, on a field definition or
+//!   on an object type (applying to every field returning that type). Weights can also be set
+//!   per `"Type.field"` in [`CostConfig::field_costs`], which is how typed schemas opt in.
+//! - Every other field costs [`CostConfig::default_field_cost`]; introspection fields are free.
+//! - A query's cost is the sum of the weights of every selected field, including fields reached
+//!   through fragments. The selections under a list field count once per expected item: the
+//!   field's `first`, `last` or `limit` argument, or [`CostConfig::default_list_size`] without one.
+//!
+//! The computed cost is reported in the response `extensions.cost` object. An operation above
+//! [`CostConfig::max_cost`] is rejected with [`GraphQLError::CostLimitExceeded`], which handlers
+//! turn into a 400 `ProblemDetails` response reporting the cost. With a [`CostBudgetConfig`],
+//! every client also gets a rolling budget: a query that would push the client's spend over
+//! `max_cost_per_window` within the last `window_secs` seconds is rejected with
+//! [`GraphQLError::CostBudgetExceeded`], which handlers turn into a 429 `ProblemDetails`
+//! response carrying `Retry-After`.
+//!
+//! ```graphql
+//! directive @cost(weight: Int!) on FIELD_DEFINITION | OBJECT
+//!
+//! type Query {
+//!   search(term: String!, first: Int): [Result!]! @cost(weight: 10)
+//! }
+//! ```
+
+use async_graphql::{Name, Value as ConstValue};
+use async_graphql_parser::Positioned;
+use async_graphql_parser::types::{
+    BaseType, ConstDirective, DocumentOperations, ExecutableDocument, Field, FragmentDefinition, OperationType,
+    Selection, SelectionSet, TypeDefinition, TypeKind, TypeSystemDefinition,
+};
+use axum::body::Body;
+use axum::http::{Response, StatusCode};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value as JsonValue, json};
+use spikard_core::ExpiringMap;
+use spikard_http::ProblemDetails;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::context::GraphQLRequestContext;
+use crate::error::GraphQLError;
+
+/// Name of the SDL directive carrying a field or type weight.
+pub const COST_DIRECTIVE: &str = "cost";
+
+/// Arguments read as the number of items a list field returns, in order of precedence.
+const LIST_SIZE_ARGUMENTS: [&str; 3] = ["first", "last", "limit"];
+
+/// Type URI used for cost-budget rejections.
+const TYPE_COST_BUDGET_EXCEEDED: &str = "https://spikard.dev/errors/graphql-cost-budget-exceeded";
+
+/// Type URI used for per-operation cost limit rejections.
+const TYPE_COST_LIMIT_EXCEEDED: &str = "https://spikard.dev/errors/graphql-cost-limit-exceeded";
+
+/// Cost analysis settings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CostConfig {
+    /// Weight of a field with no `@cost` directive or override.
+    pub default_field_cost: u64,
+    /// Per-field weight overrides keyed by `"Type.field"`; take precedence over SDL directives.
+    pub field_costs: HashMap<String, u64>,
+    /// Items assumed for a list field without a `first`, `last` or `limit` argument.
+    pub default_list_size: u64,
+    /// Maximum cost of a single operation (`None` = unlimited).
+    pub max_cost: Option<u64>,
+    /// Rolling per-client budget (`None` = no budget).
+    pub budget: Option<CostBudgetConfig>,
+}
+
+impl Default for CostConfig {
+    fn default() -> Self {
+        Self {
+            default_field_cost: 1,
+            field_costs: HashMap::new(),
+            default_list_size: 10,
+            max_cost: None,
+            budget: None,
+        }
+    }
+}
+
+/// Where to take the client identity that a cost budget is keyed by.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ClientKeySource {
+    /// JWT subject, then API key, then client IP.
+    #[default]
+    Auto,
+    /// The API key header.
+    ApiKey,
+    /// The JWT `sub` claim.
+    JwtSubject,
+    /// The client IP address.
+    Ip,
+}
+
+/// A rolling cost budget applied to each client separately.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CostBudgetConfig {
+    /// Total cost a client may spend within one window.
+    pub max_cost_per_window: u64,
+    /// Window length in seconds.
+    pub window_secs: u64,
+    /// How clients are identified.
+    #[serde(default)]
+    pub client_key: ClientKeySource,
+    /// Header carrying the API key for [`ClientKeySource::ApiKey`] and `Auto`.
+    #[serde(default = "default_api_key_header")]
+    pub api_key_header: String,
+}
+
+fn default_api_key_header() -> String {
+    "x-api-key".to_string()
+}
+
+/// Field weights extracted from a schema, used to price operations.
+#[derive(Debug, Clone)]
+pub struct CostAnalyzer {
+    default_field_cost: u64,
+    default_list_size: u64,
+    field_weights: HashMap<(String, String), u64>,
+    type_weights: HashMap<String, u64>,
+    field_types: HashMap<(String, String), String>,
+    list_fields: HashSet<(String, String)>,
+    query_root: String,
+    mutation_root: String,
+    subscription_root: String,
+}
+
+impl CostAnalyzer {
+    /// Build an analyzer from SDL, reading `@cost` directives and applying `config` overrides.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GraphQLError::SchemaBuildError`] if the SDL cannot be parsed or an override key
+    /// is not of the form `"Type.field"`.
+    pub fn from_sdl(sdl: &str, config: &CostConfig) -> Result<Self, GraphQLError> {
+        let document = async_graphql_parser::parse_schema(sdl)
+            .map_err(|e| GraphQLError::SchemaBuildError(format!("invalid SDL: {e}")))?;
+        let roots = crate::dynamic::schema_roots(&document);
+
+        let mut analyzer = Self {
+            default_field_cost: config.default_field_cost,
+            default_list_size: config.default_list_size,
+            field_weights: HashMap::new(),
+            type_weights: HashMap::new(),
+            field_types: HashMap::new(),
+            list_fields: HashSet::new(),
+            query_root: roots.query,
+            mutation_root: roots.mutation.unwrap_or_else(|| "Mutation".to_string()),
+            subscription_root: roots.subscription.unwrap_or_else(|| "Subscription".to_string()),
+        };
+
+        for definition in &document.definitions {
+            if let TypeSystemDefinition::Type(positioned) = definition {
+                analyzer.index_type(&positioned.node);
+            }
+        }
+
+        for (path, weight) in &config.field_costs {
+            let (type_name, field_name) = path.split_once('.').ok_or_else(|| {
+                GraphQLError::SchemaBuildError(format!("cost override \"{path}\" must be of the form \"Type.field\""))
+            })?;
+            analyzer
+                .field_weights
+                .insert((type_name.to_string(), field_name.to_string()), *weight);
+        }
+
+        Ok(analyzer)
+    }
+
+    fn index_type(&mut self, type_def: &TypeDefinition) {
+        let type_name = type_def.name.node.to_string();
+        if let Some(weight) = cost_weight(&type_def.directives) {
+            self.type_weights.insert(type_name.clone(), weight);
+        }
+        let fields = match &type_def.kind {
+            TypeKind::Object(object) => &object.fields,
+            TypeKind::Interface(interface) => &interface.fields,
+            _ => return,
+        };
+        for field in fields {
+            let key = (type_name.clone(), field.node.name.node.to_string());
+            if let Some(weight) = cost_weight(&field.node.directives) {
+                self.field_weights.insert(key.clone(), weight);
+            }
+            if matches!(field.node.ty.node.base, BaseType::List(_)) {
+                self.list_fields.insert(key.clone());
+            }
+            self.field_types.insert(key, named_type(&field.node.ty.node.base));
+        }
+    }
+
+    /// Price the operation selected by `operation_name` in `query`.
+    ///
+    /// `variables` supply list sizes passed as variables; operation defaults apply otherwise.
+    /// Returns `None` when the document cannot be parsed or the operation cannot be found; the
+    /// executor then reports the corresponding GraphQL error as usual.
+    #[must_use]
+    pub fn estimate(&self, query: &str, operation_name: Option<&str>, variables: Option<&JsonValue>) -> Option<u64> {
+        let document = async_graphql_parser::parse_query(query).ok()?;
+        let operation = match (&document.operations, operation_name) {
+            (DocumentOperations::Single(operation), _) => &operation.node,
+            (DocumentOperations::Multiple(operations), Some(name)) => &operations.get(name)?.node,
+            (DocumentOperations::Multiple(operations), None) if operations.len() == 1 => {
+                &operations.values().next()?.node
+            }
+            (DocumentOperations::Multiple(_), None) => return None,
+        };
+        let root = match operation.ty {
+            OperationType::Query => &self.query_root,
+            OperationType::Mutation => &self.mutation_root,
+            OperationType::Subscription => &self.subscription_root,
+        };
+
+        let mut values: HashMap<Name, ConstValue> = operation
+            .variable_definitions
+            .iter()
+            .filter_map(|definition| {
+                let default = definition.node.default_value()?.clone();
+                Some((definition.node.name.node.clone(), default))
+            })
+            .collect();
+        if let Some(JsonValue::Object(provided)) = variables {
+            for (name, value) in provided {
+                if let Ok(value) = ConstValue::from_json(value.clone()) {
+                    values.insert(Name::new(name), value);
+                }
+            }
+        }
+
+        let mut visited = HashSet::new();
+        Some(self.selection_cost(root, &operation.selection_set.node, &document, &values, &mut visited))
+    }
+
+    /// Items a list field is expected to return, from its size argument or the default.
+    fn list_size(&self, field: &Field, variables: &HashMap<Name, ConstValue>) -> u64 {
+        LIST_SIZE_ARGUMENTS
+            .iter()
+            .find_map(|name| {
+                let value = field.get_argument(name)?.node.clone();
+                let value = value
+                    .into_const_with(|variable| Ok::<_, ()>(variables.get(&variable).cloned().unwrap_or_default()))
+                    .ok()?;
+                match value {
+                    ConstValue::Number(number) => number.as_u64(),
+                    _ => None,
+                }
+            })
+            .unwrap_or(self.default_list_size)
+    }
+
+    fn selection_cost(
+        &self,
+        parent_type: &str,
+        selection_set: &SelectionSet,
+        document: &ExecutableDocument,
+        variables: &HashMap<Name, ConstValue>,
+        visited_fragments: &mut HashSet<Name>,
+    ) -> u64 {
+        let mut total: u64 = 0;
+        for selection in &selection_set.items {
+            let cost = match &selection.node {
+                Selection::Field(field) => {
+                    let field_name = field.node.name.node.as_str();
+                    if field_name.starts_with("__") {
+                        continue;
+                    }
+                    let key = (parent_type.to_string(), field_name.to_string());
+                    let child_type = self.field_types.get(&key).map_or("", String::as_str);
+                    let weight = self
+                        .field_weights
+                        .get(&key)
+                        .or_else(|| self.type_weights.get(child_type))
+                        .copied()
+                        .unwrap_or(self.default_field_cost);
+                    let children = self.selection_cost(
+                        child_type,
+                        &field.node.selection_set.node,
+                        document,
+                        variables,
+                        visited_fragments,
+                    );
+                    let children = if self.list_fields.contains(&key) {
+                        children.saturating_mul(self.list_size(&field.node, variables))
+                    } else {
+                        children
+                    };
+                    weight.saturating_add(children)
+                }
+                Selection::FragmentSpread(spread) => {
+                    let name = &spread.node.fragment_name.node;
+                    match document.fragments.get(name) {
+                        Some(fragment) if visited_fragments.insert(name.clone()) => {
+                            let cost = self.fragment_cost(&fragment.node, document, variables, visited_fragments);
+                            visited_fragments.remove(name);
+                            cost
+                        }
+                        _ => 0,
+                    }
+                }
+                Selection::InlineFragment(inline) => {
+                    let type_name = inline
+                        .node
+                        .type_condition
+                        .as_ref()
+                        .map_or(parent_type, |condition| condition.node.on.node.as_str());
+                    self.selection_cost(
+                        type_name,
+                        &inline.node.selection_set.node,
+                        document,
+                        variables,
+                        visited_fragments,
+                    )
+                }
+            };
+            total = total.saturating_add(cost);
+        }
+        total
+    }
+
+    fn fragment_cost(
+        &self,
+        fragment: &FragmentDefinition,
+        document: &ExecutableDocument,
+        variables: &HashMap<Name, ConstValue>,
+        visited_fragments: &mut HashSet<Name>,
+    ) -> u64 {
+        self.selection_cost(
+            fragment.type_condition.node.on.node.as_str(),
+            &fragment.selection_set.node,
+            document,
+            variables,
+            visited_fragments,
+        )
+    }
+}
+
+fn cost_weight(directives: &[Positioned<ConstDirective>]) -> Option<u64> {
+    directives
+        .iter()
+        .find(|directive| directive.node.name.node.as_str() == COST_DIRECTIVE)
+        .and_then(|directive| {
+            directive
+                .node
+                .arguments
+                .iter()
+                .find(|(name, _)| name.node.as_str() == "weight")
+                .and_then(|(_, value)| match &value.node {
+                    ConstValue::Number(number) => number.as_u64(),
+                    _ => None,
+                })
+        })
+}
+
+fn named_type(base: &BaseType) -> String {
+    match base {
+        BaseType::Named(name) => name.to_string(),
+        BaseType::List(inner) => named_type(&inner.base),
+    }
+}
+
+/// Sliding-log cost budget shared by all requests to one GraphQL route.
+#[derive(Debug)]
+pub struct CostBudget {
+    config: CostBudgetConfig,
+    spent: Mutex<ExpiringMap<VecDeque<(Instant, u64)>>>,
+}
+
+impl CostBudget {
+    /// Create an empty budget.
+    #[must_use]
+    pub fn new(config: CostBudgetConfig) -> Self {
+        Self {
+            config,
+            spent: Mutex::new(ExpiringMap::new()),
+        }
+    }
+
+    /// Identify the client of a request according to [`CostBudgetConfig::client_key`].
+    #[must_use]
+    pub fn client_key(&self, context: Option<&GraphQLRequestContext>) -> String {
+        let Some(context) = context else {
+            return "anonymous".to_string();
+        };
+        let subject = || context.claims().map(|claims| format!("sub:{}", claims.sub));
+        let api_key = || {
+            context
+                .header(&self.config.api_key_header)
+                .map(|key| format!("key:{key}"))
+        };
+        let ip = || context.client_ip().map(|ip| format!("ip:{ip}"));
+
+        let key = match self.config.client_key {
+            ClientKeySource::Auto => subject().or_else(api_key).or_else(ip),
+            ClientKeySource::ApiKey => api_key(),
+            ClientKeySource::JwtSubject => subject(),
+            ClientKeySource::Ip => ip(),
+        };
+        key.unwrap_or_else(|| "anonymous".to_string())
+    }
+
+    /// Charge `cost` to `client`, returning the remaining budget.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GraphQLError::CostBudgetExceeded`] without charging anything if `cost` does not
+    /// fit in what is left of the client's window.
+    pub fn charge(&self, client: &str, cost: u64) -> Result<u64, GraphQLError> {
+        self.charge_at(client, cost, Instant::now())
+    }
+
+    fn charge_at(&self, client: &str, cost: u64, now: Instant) -> Result<u64, GraphQLError> {
+        let window = Duration::from_secs(self.config.window_secs);
+        let limit = self.config.max_cost_per_window;
+        let mut spent = self.spent.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+        let entries = spent.entry(client.to_string()).or_default();
+
+        while entries
+            .front()
+            .is_some_and(|(at, _)| now.saturating_duration_since(*at) >= window)
+        {
+            entries.pop_front();
+        }
+        let used: u64 = entries.iter().map(|(_, cost)| cost).sum();
+
+        if used.saturating_add(cost) <= limit {
+            entries.push_back((now, cost));
+            // Clients whose last charge has left the window have their full budget back.
+            spent.sweep(|entries| {
+                entries
+                    .back()
+                    .is_some_and(|(at, _)| now.saturating_duration_since(*at) < window)
+            });
+            return Ok(limit - used - cost);
+        }
+
+        let retry_after = if cost > limit {
+            window
+        } else {
+            let mut freed = 0u64;
+            entries
+                .iter()
+                .find_map(|(at, spent)| {
+                    freed += spent;
+                    (used - freed + cost <= limit).then(|| (*at + window).saturating_duration_since(now))
+                })
+                .unwrap_or(window)
+        };
+        if entries.is_empty() {
+            spent.remove(client);
+        }
+        drop(spent);
+
+        Err(GraphQLError::CostBudgetExceeded {
+            cost,
+            remaining: limit.saturating_sub(used),
+            retry_after_secs: retry_after.as_secs().max(1),
+        })
+    }
+}
+
+/// Cost analysis attached to an executor or handler: the analyzer plus its limits.
+#[derive(Debug, Clone)]
+pub struct CostAnalysis {
+    analyzer: Arc<CostAnalyzer>,
+    max_cost: Option<u64>,
+    budget: Option<Arc<CostBudget>>,
+}
+
+impl CostAnalysis {
+    /// Build cost analysis for the schema described by `sdl`.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`CostAnalyzer::from_sdl`].
+    pub fn from_sdl(sdl: &str, config: &CostConfig) -> Result<Self, GraphQLError> {
+        Ok(Self {
+            analyzer: Arc::new(CostAnalyzer::from_sdl(sdl, config)?),
+            max_cost: config.max_cost,
+            budget: config.budget.clone().map(|budget| Arc::new(CostBudget::new(budget))),
+        })
+    }
+
+    /// Price an operation and enforce the per-operation limit and the client's budget.
+    ///
+    /// Returns `None` when the operation cannot be priced (parse errors are left to the
+    /// executor).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GraphQLError::CostLimitExceeded`] when the operation exceeds `max_cost`, or
+    /// [`GraphQLError::CostBudgetExceeded`] when the client's budget is exhausted.
+    pub fn admit(
+        &self,
+        query: &str,
+        operation_name: Option<&str>,
+        variables: Option<&JsonValue>,
+        context: Option<&GraphQLRequestContext>,
+    ) -> Result<Option<CostReport>, GraphQLError> {
+        let Some(cost) = self.analyzer.estimate(query, operation_name, variables) else {
+            return Ok(None);
+        };
+        if let Some(max) = self.max_cost.filter(|max| cost > *max) {
+            return Err(GraphQLError::CostLimitExceeded { cost, max });
+        }
+        let remaining = match &self.budget {
+            Some(budget) => Some(budget.charge(&budget.client_key(context), cost)?),
+            None => None,
+        };
+        Ok(Some(CostReport {
+            requested: cost,
+            maximum: self.max_cost,
+            remaining,
+        }))
+    }
+}
+
+/// The cost reported in a response's `extensions.cost`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CostReport {
+    /// Cost of the executed operation.
+    pub requested: u64,
+    /// Per-operation limit, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maximum: Option<u64>,
+    /// Budget left in the client's current window, if a budget is configured.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remaining: Option<u64>,
+}
+
+impl CostReport {
+    /// Insert this report into a GraphQL response's `extensions` object.
+    pub fn attach(&self, response: &mut JsonValue) {
+        let Some(object) = response.as_object_mut() else {
+            return;
+        };
+        let extensions = object.entry("extensions").or_insert_with(|| json!({}));
+        if let Some(extensions) = extensions.as_object_mut() {
+            extensions.insert("cost".to_string(), json!(self));
+        }
+    }
+}
+
+/// Build the `ProblemDetails` response for a cost rejection.
+///
+/// [`GraphQLError::CostLimitExceeded`] becomes a 400 reporting the computed cost and the limit;
+/// [`GraphQLError::CostBudgetExceeded`] becomes a 429 carrying `Retry-After`. Returns `None` for
+/// every other error.
+#[must_use]
+pub fn cost_problem_response(error: &GraphQLError) -> Option<Response<Body>> {
+    let (problem, retry_after) = match *error {
+        GraphQLError::CostLimitExceeded { cost, max } => {
+            let problem = ProblemDetails::new(
+                TYPE_COST_LIMIT_EXCEEDED,
+                "GraphQL cost limit exceeded",
+                StatusCode::BAD_REQUEST,
+            )
+            .with_detail(format!("Operation cost {cost} exceeds the maximum of {max}"))
+            .with_extension("cost", json!(cost))
+            .with_extension("maximum", json!(max));
+            (problem, None)
+        }
+        GraphQLError::CostBudgetExceeded {
+            cost,
+            remaining,
+            retry_after_secs,
+        } => {
+            let problem = ProblemDetails::new(
+                TYPE_COST_BUDGET_EXCEEDED,
+                "GraphQL cost budget exceeded",
+                StatusCode::TOO_MANY_REQUESTS,
+            )
+            .with_detail(format!(
+                "Operation cost {cost} exceeds the remaining budget of {remaining}; retry after {retry_after_secs} seconds"
+            ))
+            .with_extension("cost", json!(cost))
+            .with_extension("remaining", json!(remaining))
+            .with_extension("retry_after", json!(retry_after_secs));
+            (problem, Some(retry_after_secs))
+        }
+        _ => return None,
+    };
+
+    let mut response = Response::builder()
+        .status(problem.status_code())
+        .header("content-type", spikard_http::CONTENT_TYPE_PROBLEM_JSON);
+    if let Some(retry_after) = retry_after {
+        response = response.header("retry-after", retry_after.to_string());
+    }
+    response.body(Body::from(problem.to_json().unwrap_or_default())).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SDL: &str = r"
+        directive @cost(weight: Int!) on FIELD_DEFINITION | OBJECT
+        type Query {
+            search(term: String!, first: Int): [Hit!]! @cost(weight: 10)
+            me: User
+        }
+        type User { id: ID! name: String! }
+        type Hit @cost(weight: 3) { id: ID! owner: User }
+    ";
+
+    fn analyzer() -> CostAnalyzer {
+        CostAnalyzer::from_sdl(SDL, &CostConfig::default()).expect("analyzer")
+    }
+
+    #[test]
+    fn test_estimate_uses_field_type_and_default_weights() {
+        let analyzer = analyzer();
+
+        assert_eq!(analyzer.estimate("{ me { id name } }", None, None), Some(3));
+        assert_eq!(
+            analyzer.estimate("{ search(term: \"x\") { id } }", None, None),
+            Some(20)
+        );
+        assert_eq!(
+            analyzer.estimate("{ __schema { queryType { name } } }", None, None),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn test_list_fields_multiply_their_selections() {
+        let analyzer = analyzer();
+
+        assert_eq!(
+            analyzer.estimate("{ search(term: \"x\", first: 2) { id } }", None, None),
+            Some(12)
+        );
+        assert_eq!(
+            analyzer.estimate("{ search(term: \"x\") { owner { id name } } }", None, None),
+            Some(40)
+        );
+
+        let query = "query Q($n: Int = 3) { search(term: \"x\", first: $n) { id } }";
+        assert_eq!(analyzer.estimate(query, None, None), Some(13));
+        assert_eq!(analyzer.estimate(query, None, Some(&json!({"n": 5}))), Some(15));
+
+        let config = CostConfig {
+            default_list_size: 1,
+            ..CostConfig::default()
+        };
+        let analyzer = CostAnalyzer::from_sdl(SDL, &config).expect("analyzer");
+        assert_eq!(
+            analyzer.estimate("{ search(term: \"x\") { id } }", None, None),
+            Some(11)
+        );
+    }
+
+    #[test]
+    fn test_estimate_expands_fragments_and_selects_operation() {
+        let analyzer = analyzer();
+        let query = "query A { me { ...U } } query B { me { id } } fragment U on User { id name }";
+
+        assert_eq!(analyzer.estimate(query, Some("A"), None), Some(3));
+        assert_eq!(analyzer.estimate(query, Some("B"), None), Some(2));
+        assert_eq!(analyzer.estimate(query, None, None), None);
+        assert_eq!(analyzer.estimate("{ not valid", None, None), None);
+    }
+
+    #[test]
+    fn test_field_cost_overrides_take_precedence() {
+        let config = CostConfig {
+            field_costs: HashMap::from([("User.name".to_string(), 5)]),
+            ..CostConfig::default()
+        };
+        let analyzer = CostAnalyzer::from_sdl(SDL, &config).expect("analyzer");
+        assert_eq!(analyzer.estimate("{ me { name } }", None, None), Some(6));
+
+        let bad = CostConfig {
+            field_costs: HashMap::from([("nodot".to_string(), 5)]),
+            ..CostConfig::default()
+        };
+        assert!(CostAnalyzer::from_sdl(SDL, &bad).is_err());
+    }
+
+    #[test]
+    fn test_budget_rolls_over_window() {
+        let budget = CostBudget::new(CostBudgetConfig {
+            max_cost_per_window: 10,
+            window_secs: 60,
+            client_key: ClientKeySource::Auto,
+            api_key_header: default_api_key_header(),
+        });
+        let start = Instant::now();
+
+        assert_eq!(budget.charge_at("c", 6, start).unwrap(), 4);
+        assert_eq!(budget.charge_at("c", 3, start + Duration::from_secs(30)).unwrap(), 1);
+
+        match budget.charge_at("c", 5, start + Duration::from_secs(40)) {
+            Err(GraphQLError::CostBudgetExceeded {
+                remaining,
+                retry_after_secs,
+                ..
+            }) => {
+                assert_eq!(remaining, 1);
+                assert_eq!(retry_after_secs, 20);
+            }
+            other => panic!("expected budget rejection, got {other:?}"),
+        }
+
+        assert_eq!(budget.charge_at("other", 10, start).unwrap(), 0);
+        assert_eq!(budget.charge_at("c", 5, start + Duration::from_secs(61)).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_budget_forgets_clients_whose_window_expired() {
+        let budget = CostBudget::new(CostBudgetConfig {
+            max_cost_per_window: 10,
+            window_secs: 60,
+            client_key: ClientKeySource::Auto,
+            api_key_header: default_api_key_header(),
+        });
+        let start = Instant::now();
+
+        assert!(budget.charge_at("too-big", 11, start).is_err());
+        for client in 0..spikard_core::expiring::SWEEP_THRESHOLD - 1 {
+            budget.charge_at(&client.to_string(), 1, start).unwrap();
+        }
+        budget.charge_at("late", 1, start + Duration::from_mins(1)).unwrap();
+
+        let clients = budget.spent.lock().unwrap().keys().cloned().collect::<Vec<_>>();
+        assert_eq!(clients, ["late"]);
+    }
+
+    #[test]
+    fn test_cost_problem_response_is_problem_details() {
+        let error = GraphQLError::CostBudgetExceeded {
+            cost: 5,
+            remaining: 1,
+            retry_after_secs: 20,
+        };
+        let response = cost_problem_response(&error).expect("429 response");
+
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(response.headers()["retry-after"], "20");
+        assert!(cost_problem_response(&GraphQLError::ComplexityLimitExceeded).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_cost_limit_response_reports_the_cost() {
+        let error = GraphQLError::CostLimitExceeded { cost: 40, max: 25 };
+        let response = cost_problem_response(&error).expect("400 response");
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert!(response.headers().get("retry-after").is_none());
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body");
+        let problem: JsonValue = serde_json::from_slice(&body).expect("problem json");
+        assert_eq!(problem["type"], TYPE_COST_LIMIT_EXCEEDED);
+        assert_eq!(problem["cost"], 40);
+        assert_eq!(problem["maximum"], 25);
+    }
+}
@@ -0,0 +1,489 @@
+//! Per-request batching and caching for GraphQL resolvers.
+//!
+//! Resolvers that fetch related entities one at a time cause N+1 round trips. A
+//! [`DataLoaderRegistry`] holds named [`BatchLoader`]s registered while the schema is assembled;
+//! the executor calls [`DataLoaderRegistry::for_request`] once per request and attaches the
+//! resulting [`DataLoaders`] to the resolver context.
+//!
+//! Every `load` made while a batch window is open is coalesced into a single call to the batch
+//! loader. Results are cached by key for the lifetime of the request, so repeated loads of the
+//! same key — in the same batch or later ones — never reach the loader twice. Failed loads are
+//! not cached: a later load of the key retries it. Nothing is shared between requests.
+//!
+//! Keys and values are JSON so that loaders can be implemented either in Rust or by a language
+//! binding, and so that dynamic-SDL schemas can route fields through a loader declaratively (see
+//! [`FieldLoaderSpec`](crate::dynamic::FieldLoaderSpec)).
+//!
+//! ```ignore
+//! let loaders = DataLoaderRegistry::new().register("user", |ids: Vec<Value>| async move {
+//!     Ok(db.users_by_ids(&ids).await)
+//! });
+//! let executor = GraphQLExecutor::new(schema).with_data_loaders(loaders);
+//!
+//! // In a resolver:
+//! let user = ctx.data::<DataLoaders>()?.load("user", json!(self.author_id)).await?;
+//! ```
+
+use futures::FutureExt;
+use futures::future::{BoxFuture, Shared};
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::oneshot;
+
+use crate::error::GraphQLError;
+
+/// Outcome of a batch load: one entry per requested key, in the same order as the keys.
+///
+/// `None` marks a key with no matching value. An `Err` fails every key in the batch.
+pub type BatchLoadResult = Result<Vec<Option<JsonValue>>, String>;
+
+/// Boxed future returned by [`BatchLoader::load`].
+pub type BatchLoadFuture = Pin<Box<dyn Future<Output = BatchLoadResult> + Send>>;
+
+/// A function that loads many keys in a single round trip.
+///
+/// Implemented for any `Fn(Vec<JsonValue>) -> impl Future<Output = BatchLoadResult>`.
+pub trait BatchLoader: Send + Sync {
+    /// Load values for `keys`, returning them in the same order.
+    fn load(&self, keys: Vec<JsonValue>) -> BatchLoadFuture;
+}
+
+impl<F, Fut> BatchLoader for F
+where
+    F: Fn(Vec<JsonValue>) -> Fut + Send + Sync,
+    Fut: Future<Output = BatchLoadResult> + Send + 'static,
+{
+    fn load(&self, keys: Vec<JsonValue>) -> BatchLoadFuture {
+        Box::pin(self(keys))
+    }
+}
+
+/// Batching limits for a single loader.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DataLoaderConfig {
+    /// Maximum number of keys passed to one `load` call; larger batches are split.
+    pub max_batch_size: usize,
+    /// How long to wait for more keys after the first key of a batch arrives, in milliseconds.
+    pub batch_delay_ms: u64,
+}
+
+impl Default for DataLoaderConfig {
+    fn default() -> Self {
+        Self {
+            max_batch_size: 100,
+            batch_delay_ms: 1,
+        }
+    }
+}
+
+#[derive(Clone)]
+struct RegisteredLoader {
+    loader: Arc<dyn BatchLoader>,
+    config: DataLoaderConfig,
+}
+
+/// Named batch loaders registered at schema-build time.
+#[derive(Clone, Default)]
+pub struct DataLoaderRegistry {
+    loaders: HashMap<String, RegisteredLoader>,
+}
+
+impl std::fmt::Debug for DataLoaderRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut names = self.names();
+        names.sort_unstable();
+        f.debug_struct("DataLoaderRegistry").field("loaders", &names).finish()
+    }
+}
+
+impl DataLoaderRegistry {
+    /// Create an empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a loader under `name` with the default batching limits.
+    #[must_use]
+    pub fn register(self, name: impl Into<String>, loader: impl BatchLoader + 'static) -> Self {
+        self.register_with_config(name, loader, DataLoaderConfig::default())
+    }
+
+    /// Register a loader under `name` with explicit batching limits.
+    ///
+    /// Registering the same name twice replaces the earlier loader.
+    #[must_use]
+    pub fn register_with_config(
+        mut self,
+        name: impl Into<String>,
+        loader: impl BatchLoader + 'static,
+        config: DataLoaderConfig,
+    ) -> Self {
+        self.loaders.insert(
+            name.into(),
+            RegisteredLoader {
+                loader: Arc::new(loader),
+                config,
+            },
+        );
+        self
+    }
+
+    /// Register an already shared loader, as produced by language bindings.
+    #[must_use]
+    pub fn register_arc(
+        mut self,
+        name: impl Into<String>,
+        loader: Arc<dyn BatchLoader>,
+        config: DataLoaderConfig,
+    ) -> Self {
+        self.loaders.insert(name.into(), RegisteredLoader { loader, config });
+        self
+    }
+
+    /// Names of all registered loaders.
+    #[must_use]
+    pub fn names(&self) -> Vec<String> {
+        self.loaders.keys().cloned().collect()
+    }
+
+    /// Whether no loaders are registered.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.loaders.is_empty()
+    }
+
+    /// Create a fresh set of loaders with empty caches for a single request.
+    #[must_use]
+    pub fn for_request(&self) -> DataLoaders {
+        let loaders = self
+            .loaders
+            .iter()
+            .map(|(name, registered)| {
+                (
+                    name.clone(),
+                    Arc::new(RequestLoader {
+                        loader: Arc::clone(&registered.loader),
+                        config: registered.config,
+                        state: Mutex::new(LoaderState::default()),
+                    }),
+                )
+            })
+            .collect();
+        DataLoaders {
+            loaders: Arc::new(loaders),
+        }
+    }
+}
+
+/// The loaders available to resolvers during a single request.
+///
+/// Cheap to clone; clones share caches and pending batches.
+#[derive(Clone)]
+pub struct DataLoaders {
+    loaders: Arc<HashMap<String, Arc<RequestLoader>>>,
+}
+
+impl std::fmt::Debug for DataLoaders {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DataLoaders")
+            .field("loaders", &self.loaders.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl DataLoaders {
+    /// Load a single key through the loader registered as `name`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GraphQLError::ExecutionError`] if no loader is registered under `name` or the
+    /// batch containing `key` failed.
+    pub async fn load(&self, name: &str, key: JsonValue) -> Result<Option<JsonValue>, GraphQLError> {
+        let loader = self.loader(name)?;
+        loader.enqueue(key).await.map_err(GraphQLError::ExecutionError)
+    }
+
+    /// Load several keys through the loader registered as `name`, preserving their order.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::load`].
+    pub async fn load_many(&self, name: &str, keys: Vec<JsonValue>) -> Result<Vec<Option<JsonValue>>, GraphQLError> {
+        let loader = self.loader(name)?;
+        let pending: Vec<_> = keys.into_iter().map(|key| loader.enqueue(key)).collect();
+        futures::future::join_all(pending)
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(GraphQLError::ExecutionError)
+    }
+
+    /// Seed the request cache so later loads of `key` skip the loader.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GraphQLError::ExecutionError`] if no loader is registered under `name`.
+    pub fn prime(&self, name: &str, key: &JsonValue, value: Option<JsonValue>) -> Result<(), GraphQLError> {
+        let loader = self.loader(name)?;
+        let ready: SharedLoad = futures::future::ready(Ok(value)).boxed().shared();
+        loader.lock_state().cache.insert(cache_key(key), ready);
+        Ok(())
+    }
+
+    fn loader(&self, name: &str) -> Result<&Arc<RequestLoader>, GraphQLError> {
+        self.loaders
+            .get(name)
+            .ok_or_else(|| GraphQLError::ExecutionError(format!("data loader \"{name}\" is not registered")))
+    }
+}
+
+type LoadOutcome = Result<Option<JsonValue>, String>;
+type SharedLoad = Shared<BoxFuture<'static, LoadOutcome>>;
+
+/// A queued key with the cache entry its result will settle.
+struct PendingLoad {
+    key: JsonValue,
+    cache_key: String,
+    load: SharedLoad,
+    sender: oneshot::Sender<LoadOutcome>,
+}
+
+#[derive(Default)]
+struct LoaderState {
+    cache: HashMap<String, SharedLoad>,
+    pending: Vec<PendingLoad>,
+}
+
+struct RequestLoader {
+    loader: Arc<dyn BatchLoader>,
+    config: DataLoaderConfig,
+    state: Mutex<LoaderState>,
+}
+
+impl RequestLoader {
+    fn lock_state(&self) -> std::sync::MutexGuard<'_, LoaderState> {
+        self.state.lock().unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /// Return the cached load for `key`, or queue it on the current batch.
+    ///
+    /// The first key of a batch schedules a dispatch after `batch_delay_ms`; a batch that reaches
+    /// `max_batch_size` is dispatched immediately.
+    fn enqueue(self: &Arc<Self>, key: JsonValue) -> SharedLoad {
+        let cache_key = cache_key(&key);
+        let mut state = self.lock_state();
+        if let Some(existing) = state.cache.get(&cache_key) {
+            return existing.clone();
+        }
+
+        let (sender, receiver) = oneshot::channel();
+        let load: SharedLoad = async move {
+            receiver
+                .await
+                .unwrap_or_else(|_| Err("data loader batch was dropped".to_string()))
+        }
+        .boxed()
+        .shared();
+        state.cache.insert(cache_key.clone(), load.clone());
+        state.pending.push(PendingLoad {
+            key,
+            cache_key,
+            load: load.clone(),
+            sender,
+        });
+
+        let pending = state.pending.len();
+        let full_batch = (pending >= self.config.max_batch_size.max(1)).then(|| std::mem::take(&mut state.pending));
+        drop(state);
+
+        if let Some(batch) = full_batch {
+            tokio::spawn(Arc::clone(self).run_batch(batch));
+        } else if pending == 1 {
+            let this = Arc::clone(self);
+            let delay = Duration::from_millis(self.config.batch_delay_ms);
+            tokio::spawn(async move {
+                tokio::time::sleep(delay).await;
+                let batch = std::mem::take(&mut this.lock_state().pending);
+                if !batch.is_empty() {
+                    this.run_batch(batch).await;
+                }
+            });
+        }
+
+        load
+    }
+
+    async fn run_batch(self: Arc<Self>, batch: Vec<PendingLoad>) {
+        let keys: Vec<JsonValue> = batch.iter().map(|pending| pending.key.clone()).collect();
+        let expected = keys.len();
+        let outcome = self.loader.load(keys).await;
+
+        let message = match outcome {
+            Ok(values) if values.len() == expected => {
+                for (pending, value) in batch.into_iter().zip(values) {
+                    let _ = pending.sender.send(Ok(value));
+                }
+                return;
+            }
+            Ok(values) => format!("data loader returned {} values for {expected} keys", values.len()),
+            Err(message) => message,
+        };
+
+        // Drop the failed entries first so later loads retry instead of replaying the error;
+        // entries replaced by `prime` in the meantime are kept.
+        let mut state = self.lock_state();
+        for pending in &batch {
+            if state
+                .cache
+                .get(&pending.cache_key)
+                .is_some_and(|cached| cached.ptr_eq(&pending.load))
+            {
+                state.cache.remove(&pending.cache_key);
+            }
+        }
+        drop(state);
+        for pending in batch {
+            let _ = pending.sender.send(Err(message.clone()));
+        }
+    }
+}
+
+fn cache_key(key: &JsonValue) -> String {
+    key.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn counting_registry(calls: Arc<AtomicUsize>, batches: Arc<Mutex<Vec<Vec<JsonValue>>>>) -> DataLoaderRegistry {
+        DataLoaderRegistry::new().register("double", move |keys: Vec<JsonValue>| {
+            calls.fetch_add(1, Ordering::SeqCst);
+            batches.lock().unwrap().push(keys.clone());
+            async move { Ok(keys.iter().map(|key| key.as_i64().map(|n| json!(n * 2))).collect()) }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_loads_are_batched() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let batches = Arc::new(Mutex::new(Vec::new()));
+        let loaders = counting_registry(Arc::clone(&calls), Arc::clone(&batches)).for_request();
+
+        let (a, b, c) = tokio::join!(
+            loaders.load("double", json!(1)),
+            loaders.load("double", json!(2)),
+            loaders.load("double", json!(1)),
+        );
+
+        assert_eq!(a.unwrap(), Some(json!(2)));
+        assert_eq!(b.unwrap(), Some(json!(4)));
+        assert_eq!(c.unwrap(), Some(json!(2)));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(batches.lock().unwrap()[0], vec![json!(1), json!(2)]);
+    }
+
+    #[tokio::test]
+    async fn test_cache_is_scoped_to_request() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let registry = counting_registry(Arc::clone(&calls), Arc::new(Mutex::new(Vec::new())));
+
+        let first = registry.for_request();
+        first.load("double", json!(3)).await.unwrap();
+        first.load("double", json!(3)).await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        registry.for_request().load("double", json!(3)).await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_max_batch_size_splits_batches() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&calls);
+        let loaders = DataLoaderRegistry::new()
+            .register_with_config(
+                "identity",
+                move |keys: Vec<JsonValue>| {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    async move { Ok(keys.into_iter().map(Some).collect()) }
+                },
+                DataLoaderConfig {
+                    max_batch_size: 2,
+                    batch_delay_ms: 1,
+                },
+            )
+            .for_request();
+
+        let values = loaders
+            .load_many("identity", vec![json!("a"), json!("b"), json!("c")])
+            .await
+            .unwrap();
+
+        assert_eq!(values, vec![Some(json!("a")), Some(json!("b")), Some(json!("c"))]);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_loader_errors_and_length_mismatch_fail_every_key() {
+        let loaders = DataLoaderRegistry::new()
+            .register("broken", |_keys: Vec<JsonValue>| async { Err("db down".to_string()) })
+            .register("short", |_keys: Vec<JsonValue>| async { Ok(vec![]) })
+            .for_request();
+
+        let error = loaders.load("broken", json!(1)).await.unwrap_err();
+        assert!(error.to_string().contains("db down"));
+
+        let error = loaders.load("short", json!(1)).await.unwrap_err();
+        assert!(error.to_string().contains("returned 0 values for 1 keys"));
+    }
+
+    #[tokio::test]
+    async fn test_failed_loads_are_retried() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&calls);
+        let loaders = DataLoaderRegistry::new()
+            .register("flaky", move |keys: Vec<JsonValue>| {
+                let attempt = counter.fetch_add(1, Ordering::SeqCst);
+                async move {
+                    if attempt == 0 {
+                        Err("db down".to_string())
+                    } else {
+                        Ok(keys.into_iter().map(Some).collect())
+                    }
+                }
+            })
+            .for_request();
+
+        let (first, second) = tokio::join!(loaders.load("flaky", json!(1)), loaders.load("flaky", json!(1)));
+        assert!(first.unwrap_err().to_string().contains("db down"));
+        assert!(second.unwrap_err().to_string().contains("db down"));
+
+        assert_eq!(loaders.load("flaky", json!(1)).await.unwrap(), Some(json!(1)));
+        assert_eq!(loaders.load("flaky", json!(1)).await.unwrap(), Some(json!(1)));
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_unknown_loader_and_prime() {
+        let loaders = DataLoaderRegistry::new()
+            .register("never", |_keys: Vec<JsonValue>| async {
+                Err("should not be called".to_string())
+            })
+            .for_request();
+
+        assert!(loaders.load("missing", json!(1)).await.is_err());
+
+        loaders.prime("never", &json!(9), Some(json!("primed"))).unwrap();
+        assert_eq!(loaders.load("never", json!(9)).await.unwrap(), Some(json!("primed")));
+    }
+}
@@ -33,6 +33,21 @@
 //! instead of reading `response_data`. This matches the GraphQL spec's partial-data shape:
 //! sibling fields still resolve normally, and the failed field appears as `null` in `data`
 //! alongside a matching entry (with `path`) in `errors`.
+//!
+//! # Batched fields
+//!
+//! A [`FieldLoaderSpec`] routes `Type.field` through a named batch loader instead of reading
+//! `response_data`: the resolver takes the key from the parent JSON node's `key_field` (or, for
+//! root fields, from the argument of that name) and loads it via the request's
+//! [`DataLoaders`](crate::DataLoaders). Array keys load every element and resolve to a list.
+//! Loaders are supplied at handler construction time with
+//! [`DynamicGraphQLHandler::with_data_loaders`].
+//!
+//! # Federation
+//!
+//! With the `federation` feature, SDL carrying `@key` types (or a config with
+//! [`DynamicSchemaConfig::federation`] set) is served as an Apollo Federation v2 subgraph; see
+//! [`crate::federation`].
 
 use async_graphql::dynamic::{
     Field, FieldFuture, FieldValue, InputObject, InputValue, Object, Schema, SchemaError as DynamicSchemaError, TypeRef,
@@ -45,6 +60,9 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 use std::collections::HashMap;
 
+use crate::context::GraphQLRequestContext;
+use crate::cost::{CostAnalysis, CostConfig};
+use crate::dataloader::{DataLoaderRegistry, DataLoaders};
 use crate::error::GraphQLError;
 
 /// A field-level error to inject at a specific response path.
@@ -59,6 +77,31 @@ pub struct FieldErrorSpec {
     pub message: String,
 }
 
+/// Routes a single object field through a named batch loader.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FieldLoaderSpec {
+    /// The object type declaring the field (e.g. `"Post"`).
+    pub type_name: String,
+    /// The field resolved through the loader (e.g. `"author"`).
+    pub field: String,
+    /// Name of the loader registered in the [`DataLoaderRegistry`](crate::DataLoaderRegistry).
+    pub loader: String,
+    /// Parent JSON field (or root-field argument) holding the key or list of keys.
+    pub key_field: String,
+}
+
+/// Resolves references to one federation entity type through a named batch loader.
+///
+/// The loader receives entity representations (`{"__typename": "User", "id": "1"}`) as keys
+/// and returns the entity JSON for each; see [`crate::federation`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EntityResolverSpec {
+    /// The entity type (e.g. `"User"`).
+    pub type_name: String,
+    /// Name of the loader registered in the [`DataLoaderRegistry`](crate::DataLoaderRegistry).
+    pub loader: String,
+}
+
 /// Configuration for building and executing a dynamic-SDL schema.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct DynamicSchemaConfig {
@@ -70,6 +113,20 @@ pub struct DynamicSchemaConfig {
     pub max_depth: Option<usize>,
     /// Field-level errors to inject at specific response paths.
     pub field_errors: Vec<FieldErrorSpec>,
+    /// Fields resolved through batch loaders rather than `response_data`.
+    #[serde(default)]
+    pub field_loaders: Vec<FieldLoaderSpec>,
+    /// Operation cost analysis; applied by handlers built through
+    /// [`DynamicGraphQLHandler::with_cost_analysis`].
+    #[serde(default)]
+    pub cost: Option<CostConfig>,
+    /// Serve the schema as a federation subgraph even if it declares no `@key` types
+    /// (requires the `federation` feature).
+    #[serde(default)]
+    pub federation: bool,
+    /// Reference resolver hooks for federation entity types (`federation` feature).
+    #[serde(default)]
+    pub entity_resolvers: Vec<EntityResolverSpec>,
 }
 
 /// Build an `async-graphql` dynamic schema from an SDL string and a `response_data` JSON tree.
@@ -82,6 +139,8 @@ pub struct DynamicSchemaConfig {
 ///
 /// Returns [`GraphQLError::SchemaBuildError`] if the SDL cannot be parsed, defines no query root,
 /// or otherwise fails `async-graphql`'s dynamic-schema validation (e.g. duplicate field names).
+/// Federation subgraphs also fail to build with introspection disabled, and
+/// [`DynamicSchemaConfig::federation`] is rejected when the `federation` feature is off.
 pub fn build_dynamic_schema(
     sdl: &str,
     response_data: JsonValue,
@@ -92,6 +151,7 @@ pub fn build_dynamic_schema(
 
     let roots = schema_roots(&document);
     let field_errors = index_field_errors(&config.field_errors);
+    let field_loaders = index_field_loaders(&config.field_loaders);
 
     let mut builder = Schema::build(&roots.query, roots.mutation.as_deref(), roots.subscription.as_deref());
 
@@ -104,9 +164,12 @@ pub fn build_dynamic_schema(
             &positioned_type.node,
             roots.subscription.as_deref(),
             &field_errors,
+            &field_loaders,
         )?;
     }
 
+    builder = configure_federation(builder, &document, config)?;
+
     if !config.introspection_enabled {
         builder = builder.disable_introspection();
     }
@@ -125,27 +188,66 @@ pub fn build_dynamic_schema(
     Ok(schema)
 }
 
+/// Enable the federation entry points when the SDL declares entities or the config asks for it.
+#[cfg(feature = "federation")]
+fn configure_federation(
+    builder: async_graphql::dynamic::SchemaBuilder,
+    document: &ServiceDocument,
+    config: &DynamicSchemaConfig,
+) -> Result<async_graphql::dynamic::SchemaBuilder, GraphQLError> {
+    if !config.federation && !crate::federation::has_entities(document) {
+        return Ok(builder);
+    }
+    if !config.introspection_enabled {
+        return Err(GraphQLError::SchemaBuildError(
+            "federation subgraphs require introspection to be enabled".to_string(),
+        ));
+    }
+    Ok(builder
+        .enable_federation()
+        .entity_resolver(crate::federation::entity_resolver(&config.entity_resolvers)))
+}
+
+/// Without the `federation` feature, federation directives are ignored and explicit requests fail.
+#[cfg(not(feature = "federation"))]
+fn configure_federation(
+    builder: async_graphql::dynamic::SchemaBuilder,
+    _document: &ServiceDocument,
+    config: &DynamicSchemaConfig,
+) -> Result<async_graphql::dynamic::SchemaBuilder, GraphQLError> {
+    if config.federation || !config.entity_resolvers.is_empty() {
+        return Err(GraphQLError::SchemaBuildError(
+            "federation support requires the `federation` feature".to_string(),
+        ));
+    }
+    Ok(builder)
+}
+
 /// Root data stored in the schema's context, holding the fixture's `response_data` tree.
-struct RootData(JsonValue);
+pub(crate) struct RootData(pub(crate) JsonValue);
 
 /// The names of a schema's query/mutation/subscription root types, parsed from an
 /// `SDLSchemaDefinition` if present, falling back to the conventional `Query`/`Mutation`/
 /// `Subscription` type names otherwise.
-struct SchemaRoots {
-    query: String,
-    mutation: Option<String>,
-    subscription: Option<String>,
+pub(crate) struct SchemaRoots {
+    pub(crate) query: String,
+    pub(crate) mutation: Option<String>,
+    pub(crate) subscription: Option<String>,
 }
 
 /// Determine the schema's root operation type names.
 ///
 /// Looks for an explicit `schema { query: ... }` definition first; otherwise falls back to the
 /// conventional `Query`/`Mutation`/`Subscription` type names, only including a root if a type
-/// with that name is actually defined in the document.
-fn schema_roots(document: &ServiceDocument) -> SchemaRoots {
+/// with that name is actually defined in the document. Schema definitions naming no root type
+/// (such as a federation `extend schema @link(...)` header) are skipped.
+pub(crate) fn schema_roots(document: &ServiceDocument) -> SchemaRoots {
     for definition in &document.definitions {
         if let TypeSystemDefinition::Schema(positioned) = definition {
             let schema_def = &positioned.node;
+            if schema_def.query.is_none() && schema_def.mutation.is_none() && schema_def.subscription.is_none() {
+                continue;
+            }
             return SchemaRoots {
                 query: schema_def
                     .query
@@ -179,6 +281,14 @@ fn index_field_errors(specs: &[FieldErrorSpec]) -> HashMap<String, String> {
         .collect()
 }
 
+/// Index loader-backed fields by `(type name, field name)`.
+fn index_field_loaders(specs: &[FieldLoaderSpec]) -> HashMap<(String, String), FieldLoaderSpec> {
+    specs
+        .iter()
+        .map(|spec| ((spec.type_name.clone(), spec.field.clone()), spec.clone()))
+        .collect()
+}
+
 /// Register a single SDL type definition (object or input object) on the dynamic schema builder.
 ///
 /// Scalar, enum, interface, and union kinds are intentionally unsupported: the fixture corpus
@@ -190,6 +300,7 @@ fn register_type(
     type_def: &TypeDefinition,
     subscription_root_name: Option<&str>,
     field_errors: &HashMap<String, String>,
+    field_loaders: &HashMap<(String, String), FieldLoaderSpec>,
 ) -> Result<async_graphql::dynamic::SchemaBuilder, GraphQLError> {
     let type_name = type_def.name.node.to_string();
     let is_subscription_root = subscription_root_name == Some(type_name.as_str());
@@ -205,8 +316,16 @@ fn register_type(
         TypeKind::Object(object_type) => {
             let mut object = Object::new(&type_name);
             for field in &object_type.fields {
-                object = object.field(build_field(&type_name, &field.node, field_errors));
+                let loader = field_loaders
+                    .get(&(type_name.clone(), field.node.name.node.to_string()))
+                    .cloned();
+                let built = build_field(&type_name, &field.node, field_errors, loader);
+                #[cfg(feature = "federation")]
+                let built = crate::federation::apply_field_directives(built, &field.node.directives);
+                object = object.field(built);
             }
+            #[cfg(feature = "federation")]
+            let object = crate::federation::apply_object_directives(object, &type_def.directives);
             Ok(builder.register(object))
         }
         TypeKind::InputObject(input_object_type) => {
@@ -262,11 +381,13 @@ fn build_subscription_field(
 ///
 /// The resolver navigates the JSON tree by field name: at the schema roots (Query/Mutation/
 /// Subscription) it reads from the root `response_data`; for nested object fields it reads from
-/// the parent field's own JSON sub-object, threaded through `FieldValue::owned_any`.
+/// the parent field's own JSON sub-object, threaded through `FieldValue::owned_any`. Fields with a
+/// [`FieldLoaderSpec`] load their value through the request's batch loaders instead.
 fn build_field(
     parent_type: &str,
     field_def: &async_graphql_parser::types::FieldDefinition,
     field_errors: &HashMap<String, String>,
+    field_loader: Option<FieldLoaderSpec>,
 ) -> Field {
     let field_name = field_def.name.node.to_string();
     let type_ref = ast_type_to_type_ref(&field_def.ty.node);
@@ -276,6 +397,7 @@ fn build_field(
     let mut field = Field::new(field_name.clone(), type_ref, move |ctx| {
         let field_name = field_name.clone();
         let field_errors = field_errors.clone();
+        let field_loader = field_loader.clone();
         FieldFuture::new(async move {
             let path = resolver_path(&ctx);
             if let Some(message) = field_errors.get(&path) {
@@ -284,6 +406,11 @@ fn build_field(
                 return Ok(None);
             }
 
+            if let Some(spec) = field_loader {
+                let value = load_field_through_loader(&ctx, &spec, is_root).await?;
+                return json_to_field_value(value.as_ref());
+            }
+
             let node = if is_root {
                 let root: &RootData = ctx.ctx.data_unchecked();
                 root.0.get(&field_name)
@@ -307,6 +434,48 @@ fn build_field(
     field
 }
 
+/// Resolve a loader-backed field: read the key (or keys) and load through the request loaders.
+async fn load_field_through_loader(
+    ctx: &async_graphql::dynamic::ResolverContext<'_>,
+    spec: &FieldLoaderSpec,
+    is_root: bool,
+) -> async_graphql::Result<Option<JsonValue>> {
+    let key = if is_root {
+        match ctx.args.get(&spec.key_field) {
+            Some(argument) => Some(argument.as_value().clone().into_json()?),
+            None => None,
+        }
+    } else {
+        ctx.parent_value
+            .downcast_ref::<JsonValue>()
+            .and_then(|parent| parent.get(&spec.key_field))
+            .cloned()
+    };
+
+    let Some(key) = key.filter(|key| !key.is_null()) else {
+        return Ok(None);
+    };
+    let loaders = ctx.ctx.data::<DataLoaders>().map_err(|_| {
+        async_graphql::Error::new(format!(
+            "data loader \"{}\" is not available for this request",
+            spec.loader
+        ))
+    })?;
+
+    match key {
+        JsonValue::Array(keys) => {
+            let values = loaders.load_many(&spec.loader, keys).await?;
+            Ok(Some(JsonValue::Array(
+                values
+                    .into_iter()
+                    .map(|value| value.unwrap_or(JsonValue::Null))
+                    .collect(),
+            )))
+        }
+        key => Ok(loaders.load(&spec.loader, key).await?),
+    }
+}
+
 /// Reconstruct the dot-separated response path of the field currently being resolved.
 fn resolver_path(ctx: &async_graphql::dynamic::ResolverContext<'_>) -> String {
     let mut segments: Vec<String> = ctx
@@ -383,6 +552,36 @@ pub async fn execute_dynamic(
     query: &str,
     variables: Option<&JsonValue>,
     operation_name: Option<&str>,
+) -> Result<JsonValue, GraphQLError> {
+    execute_dynamic_with_context(schema, query, variables, operation_name, None).await
+}
+
+/// Execute a document against a dynamically-built schema with per-request resolver context.
+///
+/// The context's request data, claims and resolved dependencies become available to dynamic
+/// resolvers through `ResolverContext::data` (see [`GraphQLRequestContext`]).
+///
+/// # Errors
+///
+/// Same as [`execute_dynamic`].
+pub async fn execute_dynamic_with_context(
+    schema: &Schema,
+    query: &str,
+    variables: Option<&JsonValue>,
+    operation_name: Option<&str>,
+    context: Option<GraphQLRequestContext>,
+) -> Result<JsonValue, GraphQLError> {
+    execute_dynamic_request(schema, query, variables, operation_name, context, None).await
+}
+
+/// Shared execution path for the public entry points and [`DynamicGraphQLHandler`].
+async fn execute_dynamic_request(
+    schema: &Schema,
+    query: &str,
+    variables: Option<&JsonValue>,
+    operation_name: Option<&str>,
+    context: Option<GraphQLRequestContext>,
+    data_loaders: Option<DataLoaders>,
 ) -> Result<JsonValue, GraphQLError> {
     if query.trim().is_empty() {
         return Err(GraphQLError::ValidationError(
@@ -397,6 +596,12 @@ pub async fn execute_dynamic(
     if let Some(name) = operation_name {
         request = request.operation_name(name);
     }
+    if let Some(data_loaders) = data_loaders {
+        request = request.data(data_loaders);
+    }
+    if let Some(context) = context {
+        request = context.apply(request);
+    }
 
     let response = schema.execute(request).await;
 
@@ -413,6 +618,8 @@ pub async fn execute_dynamic(
 #[derive(Debug, Clone)]
 pub struct DynamicGraphQLHandler {
     schema: std::sync::Arc<Schema>,
+    data_loaders: Option<std::sync::Arc<DataLoaderRegistry>>,
+    cost: Option<CostAnalysis>,
 }
 
 impl DynamicGraphQLHandler {
@@ -421,9 +628,31 @@ impl DynamicGraphQLHandler {
     pub fn new(schema: Schema) -> Self {
         Self {
             schema: std::sync::Arc::new(schema),
+            data_loaders: None,
+            cost: None,
         }
     }
 
+    /// Attach batch loaders used by [`FieldLoaderSpec`] fields and custom dynamic resolvers.
+    ///
+    /// A fresh [`DataLoaders`] set is created for every request.
+    #[must_use]
+    pub fn with_data_loaders(mut self, registry: DataLoaderRegistry) -> Self {
+        self.data_loaders = (!registry.is_empty()).then(|| std::sync::Arc::new(registry));
+        self
+    }
+
+    /// Price operations against `sdl` and enforce `config`'s limits (see [`crate::cost`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GraphQLError::SchemaBuildError`] if the SDL cannot be parsed or a cost override
+    /// key is malformed.
+    pub fn with_cost_analysis(mut self, sdl: &str, config: &CostConfig) -> Result<Self, GraphQLError> {
+        self.cost = Some(CostAnalysis::from_sdl(sdl, config)?);
+        Ok(self)
+    }
+
     /// Parse and execute a GraphQL HTTP request body against the wrapped schema.
     ///
     /// # Errors
@@ -431,24 +660,55 @@ impl DynamicGraphQLHandler {
     /// Returns [`GraphQLError::RequestHandlingError`] if the request body is not valid JSON or
     /// is missing the required `query` field. Errors arising from executing the GraphQL document
     /// itself are reported inside the returned JSON's `errors` array rather than as an `Err`.
+    /// With cost analysis attached, over-limit operations fail with
+    /// [`GraphQLError::CostLimitExceeded`] or [`GraphQLError::CostBudgetExceeded`].
     pub async fn handle(&self, raw_body: &[u8]) -> Result<JsonValue, GraphQLError> {
+        self.handle_with_context(raw_body, None).await
+    }
+
+    /// Parse and execute a GraphQL HTTP request body, exposing `context` to resolvers.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::handle`].
+    pub async fn handle_with_context(
+        &self,
+        raw_body: &[u8],
+        context: Option<GraphQLRequestContext>,
+    ) -> Result<JsonValue, GraphQLError> {
         let payload: crate::handler::GraphQLRequestPayload = serde_json::from_slice(raw_body)
             .map_err(|e| GraphQLError::RequestHandlingError(format!("Failed to parse GraphQL request: {e}")))?;
 
-        execute_dynamic(
+        let cost_report = match &self.cost {
+            Some(cost) => cost.admit(
+                &payload.query,
+                payload.operation_name.as_deref(),
+                payload.variables.as_ref(),
+                context.as_ref(),
+            )?,
+            None => None,
+        };
+
+        let mut response = execute_dynamic_request(
             &self.schema,
             &payload.query,
             payload.variables.as_ref(),
             payload.operation_name.as_deref(),
+            context,
+            self.data_loaders.as_ref().map(|registry| registry.for_request()),
         )
-        .await
+        .await?;
+        if let Some(report) = cost_report {
+            report.attach(&mut response);
+        }
+        Ok(response)
     }
 }
 
 impl spikard_http::handler_trait::Handler for DynamicGraphQLHandler {
     fn call(
         &self,
-        _request: axum::http::Request<axum::body::Body>,
+        request: axum::http::Request<axum::body::Body>,
         request_data: spikard_http::RequestData,
     ) -> std::pin::Pin<Box<dyn std::future::Future<Output = spikard_http::handler_trait::HandlerResult> + Send + '_>>
     {
@@ -457,8 +717,12 @@ impl spikard_http::handler_trait::Handler for DynamicGraphQLHandler {
                 || serde_json::to_vec(&request_data.body).unwrap_or_default(),
                 |raw_body| raw_body.to_vec(),
             );
+            let context = GraphQLRequestContext::from_request(&request, request_data);
 
-            let result = self.handle(&body_bytes).await;
+            let result = self.handle_with_context(&body_bytes, Some(context)).await;
+            if let Some(response) = result.as_ref().err().and_then(crate::cost::cost_problem_response) {
+                return Ok(response);
+            }
             let (status, body) = match result {
                 Ok(graphql_response) => (axum::http::StatusCode::OK, graphql_response),
                 Err(error) => (
@@ -484,11 +748,11 @@ impl spikard_http::handler_trait::Handler for DynamicGraphQLHandler {
     }
 
     fn wants_headers(&self) -> bool {
-        false
+        true
     }
 
     fn wants_cookies(&self) -> bool {
-        false
+        true
     }
 }
 
@@ -707,6 +971,102 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[tokio::test]
+    async fn test_dynamic_resolvers_receive_request_context() {
+        let query = Object::new("Query").field(Field::new("path", TypeRef::named_nn(TypeRef::STRING), |ctx| {
+            FieldFuture::new(async move {
+                let request_data = ctx.data::<spikard_http::RequestData>()?;
+                Ok(Some(GraphQLValue::from(request_data.path.clone())))
+            })
+        }));
+        let schema = Schema::build("Query", None, None)
+            .register(query)
+            .finish()
+            .expect("schema should build");
+        let context = GraphQLRequestContext::new(crate::context::request_data_for_tests("/gql", &HashMap::new()));
+
+        let result = execute_dynamic_with_context(&schema, "{ path }", None, None, Some(context))
+            .await
+            .expect("execute");
+
+        assert_eq!(result, json!({"data": {"path": "/gql"}}));
+    }
+
+    #[tokio::test]
+    async fn test_field_loader_batches_nested_lookups() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let sdl =
+            "type Query { posts: [Post!]! } type Post { title: String! author: User } type User { name: String! }";
+        let response_data = json!({
+            "posts": [
+                {"title": "a", "authorId": "u1"},
+                {"title": "b", "authorId": "u2"},
+                {"title": "c", "authorId": "u1"}
+            ]
+        });
+        let mut cfg = config();
+        cfg.field_loaders.push(FieldLoaderSpec {
+            type_name: "Post".to_string(),
+            field: "author".to_string(),
+            loader: "users".to_string(),
+            key_field: "authorId".to_string(),
+        });
+        let schema = build_dynamic_schema(sdl, response_data, &cfg).expect("schema should build");
+
+        let calls = std::sync::Arc::new(AtomicUsize::new(0));
+        let counter = std::sync::Arc::clone(&calls);
+        let registry = DataLoaderRegistry::new().register("users", move |keys: Vec<JsonValue>| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            async move {
+                Ok(keys
+                    .iter()
+                    .map(|k| Some(json!({"name": format!("name-{}", k.as_str().unwrap_or_default())})))
+                    .collect())
+            }
+        });
+        let handler = DynamicGraphQLHandler::new(schema).with_data_loaders(registry);
+
+        let result = handler
+            .handle(br#"{"query": "{ posts { title author { name } } }"}"#)
+            .await
+            .expect("execute");
+
+        assert_eq!(
+            result,
+            json!({"data": {"posts": [
+                {"title": "a", "author": {"name": "name-u1"}},
+                {"title": "b", "author": {"name": "name-u2"}},
+                {"title": "c", "author": {"name": "name-u1"}}
+            ]}})
+        );
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_field_loader_without_registered_loaders_reports_error() {
+        let mut cfg = config();
+        cfg.field_loaders.push(FieldLoaderSpec {
+            type_name: "Query".to_string(),
+            field: "user".to_string(),
+            loader: "users".to_string(),
+            key_field: "id".to_string(),
+        });
+        let schema = build_dynamic_schema(
+            "type Query { user(id: ID!): User } type User { name: String! }",
+            json!({}),
+            &cfg,
+        )
+        .expect("schema should build");
+
+        let result = execute_dynamic(&schema, "{ user(id: \"u1\") { name } }", None, None)
+            .await
+            .expect("execute");
+
+        assert_eq!(result["data"]["user"], JsonValue::Null);
+        assert!(result["errors"][0]["message"].as_str().unwrap().contains("users"));
+    }
+
     #[tokio::test]
     async fn test_list_field_navigates_each_element() {
         let sdl = "type Query { order(id: ID!): Order } type Order { items: [OrderItem!]! } type OrderItem { sku: String! quantity: Int! }";
@@ -729,4 +1089,27 @@ mod tests {
             json!({"data": {"order": {"items": [{"sku": "WIDGET-A", "quantity": 2}]}}})
         );
     }
+
+    #[tokio::test]
+    async fn test_handler_cost_analysis_reports_and_rejects() {
+        let sdl = "directive @cost(weight: Int!) on FIELD_DEFINITION type Query { search: [String!]! @cost(weight: 10) hello: String! }";
+        let cost = CostConfig {
+            max_cost: Some(5),
+            ..CostConfig::default()
+        };
+        let schema =
+            build_dynamic_schema(sdl, json!({"search": [], "hello": "world"}), &config()).expect("schema should build");
+        let handler = DynamicGraphQLHandler::new(schema)
+            .with_cost_analysis(sdl, &cost)
+            .expect("cost analysis");
+
+        let result = handler.handle(br#"{"query": "{ hello }"}"#).await.expect("execute");
+        assert_eq!(result["extensions"]["cost"], json!({"requested": 1, "maximum": 5}));
+
+        let error = handler
+            .handle(br#"{"query": "{ search }"}"#)
+            .await
+            .expect_err("over max cost");
+        assert!(matches!(error, GraphQLError::CostLimitExceeded { cost: 10, max: 5 }));
+    }
 }
@@ -83,6 +83,30 @@ pub enum GraphQLError {
     #[error("Rate limit exceeded: {0}")]
     RateLimitExceeded(String),
 
+    /// Cost budget exceeded
+    ///
+    /// Occurs when an operation's cost does not fit in the client's remaining cost budget.
+    #[error("Cost budget exceeded: operation cost {cost}, remaining budget {remaining}")]
+    CostBudgetExceeded {
+        /// Cost of the rejected operation
+        cost: u64,
+        /// Budget left in the client's current window
+        remaining: u64,
+        /// Seconds until enough budget is freed to run the operation
+        retry_after_secs: u64,
+    },
+
+    /// Cost limit exceeded
+    ///
+    /// Occurs when an operation's computed cost is above the configured per-operation maximum.
+    #[error("Operation cost {cost} exceeds the maximum of {max}")]
+    CostLimitExceeded {
+        /// Computed cost of the rejected operation
+        cost: u64,
+        /// Configured per-operation maximum
+        max: u64,
+    },
+
     /// Invalid input error with validation details
     ///
     /// Occurs during input validation with detailed error information.
@@ -122,7 +146,8 @@ impl GraphQLError {
     /// Convert error to HTTP status code
     ///
     /// Maps GraphQL error types to appropriate HTTP status codes:
-    /// - 400: Bad Request for request-handling errors (malformed HTTP request body)
+    /// - 400: Bad Request for request-handling errors (malformed HTTP request body) and
+    ///   operations over the cost limit
     /// - 401: Unauthorized for authentication errors
     /// - 403: Forbidden for authorization errors
     /// - 429: Too Many Requests for rate limit errors
@@ -147,7 +172,7 @@ impl GraphQLError {
     #[must_use]
     pub const fn status_code(&self) -> u16 {
         match self {
-            Self::RequestHandlingError(_) => 400,
+            Self::RequestHandlingError(_) | Self::CostLimitExceeded { .. } => 400,
             Self::AuthenticationError(_) => 401,
             Self::AuthorizationError(_) => 403,
             Self::ParseError(_)
@@ -159,7 +184,7 @@ impl GraphQLError {
             | Self::ComplexityLimitExceeded
             | Self::DepthLimitExceeded
             | Self::IntrospectionDisabled => 200,
-            Self::RateLimitExceeded(_) => 429,
+            Self::RateLimitExceeded(_) | Self::CostBudgetExceeded { .. } => 429,
             Self::SchemaBuildError(_) | Self::SerializationError(_) | Self::InternalError(_) => 500,
         }
     }
@@ -255,7 +280,10 @@ impl GraphQLError {
     pub fn to_http_response(&self) -> Value {
         let status = self.status_code();
         let title = match self {
-            Self::ParseError(_) | Self::JsonError(_) | Self::RequestHandlingError(_) => "Bad Request",
+            Self::ParseError(_)
+            | Self::JsonError(_)
+            | Self::RequestHandlingError(_)
+            | Self::CostLimitExceeded { .. } => "Bad Request",
             Self::ValidationError(_)
             | Self::InvalidInput { .. }
             | Self::ComplexityLimitExceeded
@@ -264,7 +292,7 @@ impl GraphQLError {
             Self::AuthenticationError(_) => "Unauthorized",
             Self::AuthorizationError(_) => "Forbidden",
             Self::NotFound(_) => "Not Found",
-            Self::RateLimitExceeded(_) => "Too Many Requests",
+            Self::RateLimitExceeded(_) | Self::CostBudgetExceeded { .. } => "Too Many Requests",
             Self::ExecutionError(_) => "Execution Error",
             Self::SchemaBuildError(_) | Self::SerializationError(_) | Self::InternalError(_) => "Internal Server Error",
         };
@@ -290,7 +318,10 @@ impl GraphQLError {
     pub const fn is_transient(&self) -> bool {
         matches!(
             self,
-            Self::RateLimitExceeded(_) | Self::InternalError(_) | Self::ExecutionError(_)
+            Self::RateLimitExceeded(_)
+                | Self::CostBudgetExceeded { .. }
+                | Self::InternalError(_)
+                | Self::ExecutionError(_)
         )
     }
 
@@ -321,6 +352,8 @@ impl GraphQLError {
             Self::AuthorizationError(_) => "AUTHORIZATION_FAILED",
             Self::NotFound(_) => "NOT_FOUND",
             Self::RateLimitExceeded(_) => "RATE_LIMIT_EXCEEDED",
+            Self::CostBudgetExceeded { .. } => "GRAPHQL_COST_BUDGET_EXCEEDED",
+            Self::CostLimitExceeded { .. } => "GRAPHQL_COST_LIMIT_EXCEEDED",
             Self::InvalidInput { .. } => "VALIDATION_ERROR",
             Self::ComplexityLimitExceeded => "GRAPHQL_COMPLEXITY_LIMIT_EXCEEDED",
             Self::DepthLimitExceeded => "GRAPHQL_DEPTH_LIMIT_EXCEEDED",
@@ -345,6 +378,8 @@ impl GraphQLError {
             Self::AuthorizationError(_) => "https://spikard.dev/errors/authorization-error",
             Self::NotFound(_) => "https://spikard.dev/errors/not-found",
             Self::RateLimitExceeded(_) => "https://spikard.dev/errors/rate-limit-exceeded",
+            Self::CostBudgetExceeded { .. } => "https://spikard.dev/errors/graphql-cost-budget-exceeded",
+            Self::CostLimitExceeded { .. } => "https://spikard.dev/errors/graphql-cost-limit-exceeded",
             Self::InvalidInput { .. } => "https://spikard.dev/errors/validation-error",
             Self::ComplexityLimitExceeded => "https://spikard.dev/errors/complexity-limit-exceeded",
             Self::DepthLimitExceeded => "https://spikard.dev/errors/depth-limit-exceeded",
@@ -471,6 +506,27 @@ mod tests {
         assert_eq!(response["title"], "Too Many Requests");
     }
 
+    #[test]
+    fn test_cost_budget_exceeded_error_status() {
+        let error = GraphQLError::CostBudgetExceeded {
+            cost: 50,
+            remaining: 10,
+            retry_after_secs: 30,
+        };
+        assert_eq!(error.status_code(), 429);
+        assert_eq!(error.error_code(), "GRAPHQL_COST_BUDGET_EXCEEDED");
+        assert!(error.is_transient());
+    }
+
+    #[test]
+    fn test_cost_limit_exceeded_error_status() {
+        let error = GraphQLError::CostLimitExceeded { cost: 120, max: 100 };
+        assert_eq!(error.status_code(), 400);
+        assert_eq!(error.error_code(), "GRAPHQL_COST_LIMIT_EXCEEDED");
+        assert_eq!(error.to_string(), "Operation cost 120 exceeds the maximum of 100");
+        assert!(!error.is_transient());
+    }
+
     #[test]
     fn test_not_found_error_conversion() {
         let error = GraphQLError::NotFound("Product ID 123 not found".to_string());
@@ -557,6 +613,12 @@ mod tests {
             GraphQLError::AuthorizationError(String::new()),
             GraphQLError::NotFound(String::new()),
             GraphQLError::RateLimitExceeded(String::new()),
+            GraphQLError::CostBudgetExceeded {
+                cost: 0,
+                remaining: 0,
+                retry_after_secs: 0,
+            },
+            GraphQLError::CostLimitExceeded { cost: 0, max: 0 },
             GraphQLError::InvalidInput { message: String::new() },
             GraphQLError::InternalError(String::new()),
         ];
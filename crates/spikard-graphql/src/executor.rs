@@ -7,7 +7,11 @@ use async_graphql::{ObjectType, Request, Response, Schema, SubscriptionType, Var
 use serde_json::Value;
 use std::sync::Arc;
 
+use crate::context::GraphQLRequestContext;
+use crate::cost::{CostAnalysis, CostConfig};
+use crate::dataloader::DataLoaderRegistry;
 use crate::error::GraphQLError;
+use crate::schema::SchemaConfig;
 
 /// Generic GraphQL executor that wraps an `async-graphql` schema.
 ///
@@ -26,12 +30,19 @@ pub struct GraphQLExecutor<Query, Mutation, Subscription> {
     /// rejects introspection queries outright when disabled, matching the
     /// project's GraphQL error-response fixtures.
     introspection_enabled: bool,
+    /// Batch loaders instantiated afresh for every executed request.
+    data_loaders: Option<Arc<DataLoaderRegistry>>,
+    /// Operation cost analysis and per-client budgets.
+    cost: Option<CostAnalysis>,
 }
 
 impl<Query, Mutation, Subscription> std::fmt::Debug for GraphQLExecutor<Query, Mutation, Subscription> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("GraphQLExecutor")
             .field("schema", &"<async_graphql::Schema>")
+            .field("introspection_enabled", &self.introspection_enabled)
+            .field("data_loaders", &self.data_loaders)
+            .field("cost", &self.cost)
             .finish()
     }
 }
@@ -48,6 +59,8 @@ where
         Self {
             schema: Arc::new(schema),
             introspection_enabled: true,
+            data_loaders: None,
+            cost: None,
         }
     }
 
@@ -61,9 +74,52 @@ where
         Self {
             schema: Arc::new(schema),
             introspection_enabled,
+            data_loaders: None,
+            cost: None,
         }
     }
 
+    /// Create an executor honouring the executor-level settings of a [`SchemaConfig`]:
+    /// introspection and, when [`SchemaConfig::cost`] is set, operation cost analysis.
+    ///
+    /// Complexity, depth and federation are schema-level settings and must already be
+    /// applied to `schema`'s builder.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GraphQLError::SchemaBuildError`] if a cost override key is malformed.
+    pub fn from_schema_config(
+        schema: Schema<Query, Mutation, Subscription>,
+        config: &SchemaConfig,
+    ) -> Result<Self, GraphQLError> {
+        let executor = Self::with_introspection(schema, config.introspection_enabled);
+        match config.cost {
+            Some(ref cost) => executor.with_cost_config(cost),
+            None => Ok(executor),
+        }
+    }
+
+    /// Attach batch loaders; each executed request gets its own [`DataLoaders`](crate::DataLoaders)
+    /// in the resolver context, with caching scoped to that request.
+    #[must_use]
+    pub fn with_data_loaders(mut self, registry: DataLoaderRegistry) -> Self {
+        self.data_loaders = (!registry.is_empty()).then(|| Arc::new(registry));
+        self
+    }
+
+    /// Price every operation and enforce `config`'s limits (see [`crate::cost`]).
+    ///
+    /// Weights come from `@cost` directives in the schema's SDL and from
+    /// [`CostConfig::field_costs`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GraphQLError::SchemaBuildError`] if a cost override key is malformed.
+    pub fn with_cost_config(mut self, config: &CostConfig) -> Result<Self, GraphQLError> {
+        self.cost = Some(CostAnalysis::from_sdl(&self.schema.sdl(), config)?);
+        Ok(self)
+    }
+
     /// Execute a GraphQL query or mutation and return GraphQL-spec JSON.
     ///
     /// # Errors
@@ -74,12 +130,32 @@ where
     ///   (`__schema`/`__type`) when introspection has been disabled
     /// - `GraphQLError::ComplexityLimitExceeded` when limits are hit
     /// - `GraphQLError::DepthLimitExceeded` when limits are hit
+    /// - `GraphQLError::CostLimitExceeded` when the operation costs more than the configured maximum
+    /// - `GraphQLError::CostBudgetExceeded` when the client's cost budget is exhausted
     /// - `GraphQLError::SerializationError` when response JSON conversion fails
     pub async fn execute(
         &self,
         query: &str,
         variables: Option<&Value>,
         operation_name: Option<&str>,
+    ) -> Result<Value, GraphQLError> {
+        self.execute_with_context(query, variables, operation_name, None).await
+    }
+
+    /// Execute a GraphQL query or mutation with per-request resolver context.
+    ///
+    /// When `context` is provided, its request data, auth claims and resolved dependencies are
+    /// attached as `async-graphql` context data (see [`GraphQLRequestContext`]).
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::execute`].
+    pub async fn execute_with_context(
+        &self,
+        query: &str,
+        variables: Option<&Value>,
+        operation_name: Option<&str>,
+        context: Option<GraphQLRequestContext>,
     ) -> Result<Value, GraphQLError> {
         if query.trim().is_empty() {
             return Err(GraphQLError::ValidationError(
@@ -91,6 +167,11 @@ where
             return Err(GraphQLError::IntrospectionDisabled);
         }
 
+        let cost_report = match &self.cost {
+            Some(cost) => cost.admit(query, operation_name, variables, context.as_ref())?,
+            None => None,
+        };
+
         let mut request = Request::new(query);
         if let Some(vars) = variables {
             request = request.variables(Variables::from_json(vars.clone()));
@@ -98,6 +179,12 @@ where
         if let Some(name) = operation_name {
             request = request.operation_name(name);
         }
+        if let Some(registry) = &self.data_loaders {
+            request = request.data(registry.for_request());
+        }
+        if let Some(context) = context {
+            request = context.apply(request);
+        }
 
         let response = self.schema.execute(request).await;
 
@@ -105,8 +192,13 @@ where
             return Err(limit_error);
         }
 
-        serde_json::to_value(response)
-            .map_err(|error| GraphQLError::SerializationError(format!("Failed to serialize GraphQL response: {error}")))
+        let mut json = serde_json::to_value(response).map_err(|error| {
+            GraphQLError::SerializationError(format!("Failed to serialize GraphQL response: {error}"))
+        })?;
+        if let Some(report) = cost_report {
+            report.attach(&mut json);
+        }
+        Ok(json)
     }
 
     /// Get a reference to the underlying schema.
@@ -241,4 +333,122 @@ mod tests {
 
         assert_eq!(result["data"]["hello"], "world");
     }
+
+    #[derive(Default)]
+    struct ContextQuery;
+
+    #[Object]
+    #[allow(clippy::unused_async)]
+    impl ContextQuery {
+        async fn path(&self, ctx: &async_graphql::Context<'_>) -> async_graphql::Result<String> {
+            Ok(ctx.data::<spikard_http::RequestData>()?.path.clone())
+        }
+
+        async fn subject(&self, ctx: &async_graphql::Context<'_>) -> Option<String> {
+            ctx.data_opt::<spikard_http::Claims>().map(|claims| claims.sub.clone())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_execute_with_context_exposes_request_data_and_claims() {
+        let schema = Schema::build(ContextQuery, EmptyMutation, EmptySubscription).finish();
+        let executor = GraphQLExecutor::new(schema);
+        let request_data = crate::context::request_data_for_tests("/graphql", &std::collections::HashMap::new());
+        let context = GraphQLRequestContext::new(request_data).with_claims(spikard_http::Claims {
+            sub: "user-7".to_string(),
+            exp: 4_102_444_800,
+            iat: None,
+            nbf: None,
+            aud: None,
+            iss: None,
+        });
+
+        let result = executor
+            .execute_with_context("{ path subject }", None, None, Some(context))
+            .await
+            .expect("query should execute");
+
+        assert_eq!(result["data"]["path"], "/graphql");
+        assert_eq!(result["data"]["subject"], "user-7");
+    }
+
+    #[derive(Default)]
+    struct AuthorQuery;
+
+    #[Object]
+    impl AuthorQuery {
+        async fn authors(&self, ctx: &async_graphql::Context<'_>) -> async_graphql::Result<Vec<String>> {
+            let loaders = ctx.data::<crate::DataLoaders>()?;
+            let names = loaders
+                .load_many("author", vec![Value::from(1), Value::from(2), Value::from(1)])
+                .await?;
+            Ok(names
+                .into_iter()
+                .map(|name| name.and_then(|v| v.as_str().map(str::to_string)).unwrap_or_default())
+                .collect())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_data_loaders_batch_within_a_request() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&calls);
+        let registry = DataLoaderRegistry::new().register("author", move |keys: Vec<Value>| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            async move { Ok(keys.iter().map(|k| Some(Value::from(format!("author-{k}")))).collect()) }
+        });
+        let schema = Schema::build(AuthorQuery, EmptyMutation, EmptySubscription).finish();
+        let executor = GraphQLExecutor::new(schema).with_data_loaders(registry);
+
+        let result = executor.execute("{ authors }", None, None).await.expect("execute");
+        assert_eq!(
+            result["data"]["authors"],
+            serde_json::json!(["author-1", "author-2", "author-1"])
+        );
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        executor.execute("{ authors }", None, None).await.expect("execute");
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_execute_without_context_leaves_claims_absent() {
+        let schema = Schema::build(ContextQuery, EmptyMutation, EmptySubscription).finish();
+        let executor = GraphQLExecutor::new(schema);
+
+        let result = executor.execute("{ subject }", None, None).await.expect("execute");
+        assert_eq!(result["data"]["subject"], Value::Null);
+    }
+
+    #[tokio::test]
+    async fn test_cost_config_reports_cost_and_enforces_budget() {
+        use crate::cost::CostBudgetConfig;
+
+        let config = CostConfig {
+            field_costs: std::collections::HashMap::from([("TestQuery.greet".to_string(), 4)]),
+            budget: Some(CostBudgetConfig {
+                max_cost_per_window: 6,
+                window_secs: 60,
+                client_key: crate::cost::ClientKeySource::Auto,
+                api_key_header: "x-api-key".to_string(),
+            }),
+            ..CostConfig::default()
+        };
+        let executor = make_executor().with_cost_config(&config).expect("cost config");
+
+        let result = executor
+            .execute("{ hello greet(name: \"a\") }", None, None)
+            .await
+            .expect("execute");
+        assert_eq!(result["extensions"]["cost"]["requested"], 5);
+        assert_eq!(result["extensions"]["cost"]["remaining"], 1);
+
+        let error = executor
+            .execute("{ greet(name: \"b\") }", None, None)
+            .await
+            .expect_err("budget exhausted");
+        assert!(matches!(error, GraphQLError::CostBudgetExceeded { cost: 4, .. }));
+    }
 }
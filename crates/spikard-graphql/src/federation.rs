@@ -0,0 +1,387 @@
+//! Apollo Federation v2 subgraph support for dynamic-SDL schemas.
+//!
+//! With the `federation` feature enabled, [`build_dynamic_schema`](crate::build_dynamic_schema)
+//! honours the federation directives declared in the SDL and exposes the subgraph entry points a
+//! gateway (router) needs:
+//!
+//! - `_service { sdl }` returns the subgraph SDL, annotated for Federation v2 composition.
+//! - `_entities(representations: [_Any!]!)` resolves entity references for every type carrying a
+//!   resolvable `@key`.
+//!
+//! Supported type directives are `@key(fields:, resolvable:)`, `@shareable`, `@extends`,
+//! `@inaccessible` and `@tag(name:)`; supported field directives are `@external`,
+//! `@requires(fields:)`, `@provides(fields:)`, `@shareable`, `@inaccessible`, `@override(from:)`
+//! and `@tag(name:)`. An `extend schema @link(...)` header is accepted and ignored, since the
+//! exported SDL carries its own `@link`.
+//!
+//! # Entity resolution
+//!
+//! Each entity type can be given a reference resolver hook with an [`EntityResolverSpec`] naming
+//! a loader in the handler's [`DataLoaderRegistry`](crate::DataLoaderRegistry). The loader
+//! receives the representations (`{"__typename": "User", "id": "1"}`) as keys, batched across the
+//! whole `_entities` call, and returns the entity JSON for each.
+//!
+//! Entity types without a hook are looked up in `response_data["_entities"][TypeName]`: the
+//! first entry whose fields match every key field of the representation is returned, merged over
+//! the representation itself. A reference that resolves to nothing yields the bare
+//! representation, so key fields still resolve while any other non-null field reports an error.
+//!
+//! Federation queries are served through the query root's reserved fields, which `async-graphql`
+//! only exposes while introspection is enabled; building a federated schema with
+//! `introspection_enabled: false` is rejected.
+
+use async_graphql::Value as GraphQLValue;
+use async_graphql::dynamic::{Field, FieldFuture, FieldValue, Object, ResolverContext};
+use async_graphql_parser::Positioned;
+use async_graphql_parser::types::{ConstDirective, ServiceDocument, TypeKind, TypeSystemDefinition};
+use serde_json::Value as JsonValue;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::dataloader::DataLoaders;
+use crate::dynamic::{EntityResolverSpec, RootData};
+
+/// Whether any object type in the document declares a `@key`.
+pub(crate) fn has_entities(document: &ServiceDocument) -> bool {
+    document.definitions.iter().any(|definition| {
+        matches!(
+            definition,
+            TypeSystemDefinition::Type(positioned)
+                if matches!(positioned.node.kind, TypeKind::Object(_))
+                    && find_directive(&positioned.node.directives, "key").is_some()
+        )
+    })
+}
+
+/// Apply the object-level federation directives declared in SDL to a dynamic object.
+pub(crate) fn apply_object_directives(mut object: Object, directives: &[Positioned<ConstDirective>]) -> Object {
+    let mut tags = Vec::new();
+    for directive in directives {
+        let directive = &directive.node;
+        match directive.name.node.as_str() {
+            "key" => {
+                let Some(fields) = string_argument(directive, "fields") else {
+                    continue;
+                };
+                let resolvable = !matches!(argument(directive, "resolvable"), Some(GraphQLValue::Boolean(false)));
+                object = if resolvable {
+                    object.key(fields)
+                } else {
+                    object.unresolvable(fields)
+                };
+            }
+            "shareable" => object = object.shareable(),
+            "extends" => object = object.extends(),
+            "inaccessible" => object = object.inaccessible(),
+            "tag" => tags.extend(string_argument(directive, "name")),
+            _ => {}
+        }
+    }
+    if tags.is_empty() { object } else { object.tags(tags) }
+}
+
+/// Apply the field-level federation directives declared in SDL to a dynamic field.
+pub(crate) fn apply_field_directives(mut field: Field, directives: &[Positioned<ConstDirective>]) -> Field {
+    let mut tags = Vec::new();
+    for directive in directives {
+        let directive = &directive.node;
+        match directive.name.node.as_str() {
+            "external" => field = field.external(),
+            "requires" => {
+                if let Some(fields) = string_argument(directive, "fields") {
+                    field = field.requires(fields);
+                }
+            }
+            "provides" => {
+                if let Some(fields) = string_argument(directive, "fields") {
+                    field = field.provides(fields);
+                }
+            }
+            "shareable" => field = field.shareable(),
+            "inaccessible" => field = field.inaccessible(),
+            "override" => {
+                if let Some(from) = string_argument(directive, "from") {
+                    field = field.override_from(from);
+                }
+            }
+            "tag" => tags.extend(string_argument(directive, "name")),
+            _ => {}
+        }
+    }
+    if tags.is_empty() { field } else { field.tags(tags) }
+}
+
+/// Build the `_entities` resolver dispatching each representation by `__typename`.
+pub(crate) fn entity_resolver(
+    specs: &[EntityResolverSpec],
+) -> impl for<'a> Fn(ResolverContext<'a>) -> FieldFuture<'a> + Send + Sync + 'static {
+    let loaders_by_type: Arc<HashMap<String, String>> = Arc::new(
+        specs
+            .iter()
+            .map(|spec| (spec.type_name.clone(), spec.loader.clone()))
+            .collect(),
+    );
+
+    move |ctx| {
+        let loaders_by_type = Arc::clone(&loaders_by_type);
+        FieldFuture::new(async move {
+            let representations = ctx
+                .args
+                .try_get("representations")?
+                .list()?
+                .iter()
+                .map(|item| item.as_value().clone().into_json())
+                .collect::<Result<Vec<JsonValue>, _>>()?;
+
+            let resolutions = representations.into_iter().map(|representation| {
+                let loaders_by_type = Arc::clone(&loaders_by_type);
+                let ctx = &ctx;
+                async move {
+                    let type_name = representation
+                        .get("__typename")
+                        .and_then(JsonValue::as_str)
+                        .ok_or_else(|| async_graphql::Error::new("entity representation is missing __typename"))?
+                        .to_string();
+                    let fallback = representation.clone();
+                    let entity = if let Some(loader) = loaders_by_type.get(&type_name) {
+                        let loaders = ctx.ctx.data::<DataLoaders>().map_err(|_| {
+                            async_graphql::Error::new(format!(
+                                "data loader \"{loader}\" is not available for this request"
+                            ))
+                        })?;
+                        loaders.load(loader, representation).await?
+                    } else {
+                        let root: &RootData = ctx.ctx.data_unchecked();
+                        lookup_entity(&root.0, &type_name, &representation)
+                    };
+                    // Dynamic unions cannot hold a null member, so an unresolved reference
+                    // resolves to its own representation.
+                    let entity = entity.filter(JsonValue::is_object).unwrap_or(fallback);
+                    Ok::<_, async_graphql::Error>(FieldValue::owned_any(entity).with_type(type_name))
+                }
+            });
+
+            let values = futures::future::try_join_all(resolutions).await?;
+            Ok(Some(FieldValue::list(values)))
+        })
+    }
+}
+
+/// Find the entity matching `representation` in `response_data["_entities"][type_name]`.
+fn lookup_entity(response_data: &JsonValue, type_name: &str, representation: &JsonValue) -> Option<JsonValue> {
+    let JsonValue::Object(reference) = representation else {
+        return None;
+    };
+    let candidates = response_data.get("_entities")?.get(type_name)?.as_array()?;
+    let found = candidates.iter().find(|candidate| {
+        reference
+            .iter()
+            .filter(|(name, _)| name.as_str() != "__typename")
+            .all(|(name, value)| candidate.get(name) == Some(value))
+    })?;
+
+    let mut entity = reference.clone();
+    if let JsonValue::Object(fields) = found {
+        entity.extend(fields.iter().map(|(name, value)| (name.clone(), value.clone())));
+    }
+    Some(JsonValue::Object(entity))
+}
+
+fn find_directive<'a>(directives: &'a [Positioned<ConstDirective>], name: &str) -> Option<&'a ConstDirective> {
+    directives
+        .iter()
+        .map(|directive| &directive.node)
+        .find(|directive| directive.name.node.as_str() == name)
+}
+
+fn argument<'a>(directive: &'a ConstDirective, name: &str) -> Option<&'a GraphQLValue> {
+    directive
+        .arguments
+        .iter()
+        .find(|(argument, _)| argument.node.as_str() == name)
+        .map(|(_, value)| &value.node)
+}
+
+fn string_argument(directive: &ConstDirective, name: &str) -> Option<String> {
+    match argument(directive, name)? {
+        GraphQLValue::String(value) => Some(value.clone()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dataloader::DataLoaderRegistry;
+    use crate::dynamic::{DynamicGraphQLHandler, DynamicSchemaConfig, build_dynamic_schema, execute_dynamic};
+    use serde_json::json;
+
+    const ACCOUNTS_SDL: &str = r#"
+        extend schema @link(url: "https://specs.apollo.dev/federation/v2.5", import: ["@key", "@shareable"])
+        type Query { me: User }
+        type User @key(fields: "id") { id: ID! name: String! @shareable }
+    "#;
+
+    const REVIEWS_SDL: &str = r#"
+        extend schema @link(url: "https://specs.apollo.dev/federation/v2.5", import: ["@key"])
+        type Query { topReviews: [Review!]! }
+        type Review { body: String! author: User! }
+        type User @key(fields: "id", resolvable: false) { id: ID! }
+    "#;
+
+    fn config() -> DynamicSchemaConfig {
+        DynamicSchemaConfig {
+            introspection_enabled: true,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_two_subgraphs_resolve_entity_references() {
+        let accounts = build_dynamic_schema(
+            ACCOUNTS_SDL,
+            json!({
+                "me": {"id": "1", "name": "Ada"},
+                "_entities": {"User": [{"id": "1", "name": "Ada"}, {"id": "2", "name": "Grace"}]}
+            }),
+            &config(),
+        )
+        .expect("accounts subgraph");
+        let reviews = build_dynamic_schema(
+            REVIEWS_SDL,
+            json!({"topReviews": [{"body": "Great", "author": {"id": "2"}}]}),
+            &config(),
+        )
+        .expect("reviews subgraph");
+
+        // Step 1 of the query plan: fetch reviews and their author references.
+        let reviews_result = execute_dynamic(&reviews, "{ topReviews { body author { __typename id } } }", None, None)
+            .await
+            .expect("reviews");
+        let author = reviews_result["data"]["topReviews"][0]["author"].clone();
+        assert_eq!(author, json!({"__typename": "User", "id": "2"}));
+
+        // Step 2: resolve the references against the owning subgraph.
+        let entities = execute_dynamic(
+            &accounts,
+            "query($reps: [_Any!]!) { _entities(representations: $reps) { ... on User { id name } } }",
+            Some(&json!({"reps": [author, {"__typename": "User", "id": "404"}]})),
+            None,
+        )
+        .await
+        .expect("entities");
+        assert_eq!(entities["data"]["_entities"][0], json!({"id": "2", "name": "Grace"}));
+        assert_eq!(entities["data"]["_entities"][1]["id"], "404");
+        assert_eq!(entities["errors"][0]["path"], json!(["_entities", 1, "name"]));
+    }
+
+    #[tokio::test]
+    async fn test_service_sdl_exposes_federation_directives() {
+        let accounts = build_dynamic_schema(ACCOUNTS_SDL, json!({}), &config()).expect("accounts subgraph");
+
+        let result = execute_dynamic(&accounts, "{ _service { sdl } }", None, None)
+            .await
+            .expect("service");
+        let sdl = result["data"]["_service"]["sdl"].as_str().expect("sdl string");
+        assert!(sdl.contains("specs.apollo.dev/federation/v2"));
+        assert!(sdl.contains("@key(fields: \"id\")"));
+        assert!(sdl.contains("@shareable"));
+    }
+
+    #[tokio::test]
+    async fn test_entity_resolver_hook_batches_representations() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&calls);
+        let registry = DataLoaderRegistry::new().register("users", move |keys: Vec<JsonValue>| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            async move {
+                Ok(keys
+                    .iter()
+                    .map(|key| {
+                        Some(json!({"id": key["id"], "name": format!("user-{}", key["id"].as_str().unwrap_or(""))}))
+                    })
+                    .collect())
+            }
+        });
+        let mut cfg = config();
+        cfg.entity_resolvers.push(EntityResolverSpec {
+            type_name: "User".to_string(),
+            loader: "users".to_string(),
+        });
+        let schema = build_dynamic_schema(ACCOUNTS_SDL, json!({}), &cfg).expect("schema");
+        let handler = DynamicGraphQLHandler::new(schema).with_data_loaders(registry);
+
+        let body = json!({
+            "query": "{ _entities(representations: [{__typename: \"User\", id: \"a\"}, {__typename: \"User\", id: \"b\"}]) { ... on User { name } } }"
+        });
+        let result = handler
+            .handle(&serde_json::to_vec(&body).unwrap())
+            .await
+            .expect("entities");
+
+        assert_eq!(
+            result["data"]["_entities"],
+            json!([{"name": "user-a"}, {"name": "user-b"}])
+        );
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_federation_requires_introspection() {
+        let cfg = DynamicSchemaConfig::default();
+        assert!(build_dynamic_schema(ACCOUNTS_SDL, json!({}), &cfg).is_err());
+    }
+
+    struct Product {
+        upc: String,
+    }
+
+    #[async_graphql::Object]
+    impl Product {
+        async fn upc(&self) -> &str {
+            &self.upc
+        }
+    }
+
+    struct ProductQuery;
+
+    #[async_graphql::Object]
+    #[allow(clippy::unused_async)]
+    impl ProductQuery {
+        async fn top_product(&self) -> Product {
+            Product { upc: "1".to_string() }
+        }
+
+        #[graphql(entity)]
+        async fn find_product_by_upc(&self, upc: String) -> Product {
+            Product { upc }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_typed_schema_serves_entities() {
+        use async_graphql::{EmptyMutation, EmptySubscription, Schema};
+
+        let schema = Schema::build(ProductQuery, EmptyMutation, EmptySubscription)
+            .enable_federation()
+            .finish();
+        let executor = crate::GraphQLExecutor::new(schema);
+
+        let result = executor
+            .execute(
+                "{ _service { sdl } _entities(representations: [{__typename: \"Product\", upc: \"9\"}]) { ... on Product { upc } } }",
+                None,
+                None,
+            )
+            .await
+            .expect("execute");
+        assert_eq!(result["data"]["_entities"], json!([{"upc": "9"}]));
+        assert!(
+            result["data"]["_service"]["sdl"]
+                .as_str()
+                .is_some_and(|sdl| sdl.contains("@key(fields: \"upc\")"))
+        );
+    }
+}
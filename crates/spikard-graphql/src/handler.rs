@@ -5,6 +5,7 @@
 //! and tower-http middleware stack.
 
 use crate::GraphQLExecutor;
+use crate::context::GraphQLRequestContext;
 use crate::error::GraphQLError;
 use axum::{
     body::Body,
@@ -156,6 +157,17 @@ where
     ///
     /// Returns `GraphQLError` if the request body cannot be parsed or execution fails.
     pub async fn handle_graphql(&self, request_data: &RequestData) -> Result<Value, GraphQLError> {
+        self.handle_graphql_with_context(GraphQLRequestContext::new(request_data.clone()))
+            .await
+    }
+
+    /// Handle a GraphQL request, exposing `context` to resolvers.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GraphQLError` if the request body cannot be parsed or execution fails.
+    pub async fn handle_graphql_with_context(&self, context: GraphQLRequestContext) -> Result<Value, GraphQLError> {
+        let request_data = context.request_data();
         let body_bytes = request_data.raw_body.as_ref().map_or_else(
             || serde_json::to_vec(&request_data.body).unwrap_or_default(),
             |raw_body| raw_body.to_vec(),
@@ -164,10 +176,11 @@ where
         let payload = parse_graphql_request(&body_bytes)?;
 
         self.executor
-            .execute(
+            .execute_with_context(
                 &payload.query,
                 payload.variables.as_ref(),
                 payload.operation_name.as_deref(),
+                Some(context),
             )
             .await
     }
@@ -192,6 +205,9 @@ where
                     })
             }
             Err(e) => {
+                if let Some(response) = crate::cost::cost_problem_response(&e) {
+                    return response;
+                }
                 let status = StatusCode::from_u16(e.status_code()).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
                 let error_response = e.to_graphql_response();
                 let body = serde_json::to_vec(&error_response).unwrap_or_else(|_| {
@@ -230,11 +246,12 @@ where
 {
     fn call(
         &self,
-        _request: Request<Body>,
+        request: Request<Body>,
         request_data: RequestData,
     ) -> Pin<Box<dyn Future<Output = HandlerResult> + Send + '_>> {
+        let context = GraphQLRequestContext::from_request(&request, request_data);
         Box::pin(async move {
-            let result = self.handle_graphql(&request_data).await;
+            let result = self.handle_graphql_with_context(context).await;
             Ok(Self::response_from_result(result))
         })
     }
@@ -244,11 +261,11 @@ where
     }
 
     fn wants_headers(&self) -> bool {
-        false
+        true
     }
 
     fn wants_cookies(&self) -> bool {
-        false
+        true
     }
 }
 
@@ -407,15 +424,15 @@ mod tests {
     }
 
     #[test]
-    fn test_handler_does_not_want_headers() {
+    fn test_handler_wants_headers_for_resolver_context() {
         let handler = make_test_handler();
-        assert!(!handler.wants_headers());
+        assert!(handler.wants_headers());
     }
 
     #[test]
-    fn test_handler_does_not_want_cookies() {
+    fn test_handler_wants_cookies_for_resolver_context() {
         let handler = make_test_handler();
-        assert!(!handler.wants_cookies());
+        assert!(handler.wants_cookies());
     }
 
     #[test]
@@ -7,12 +7,16 @@
 //! - Builder pattern for schema construction
 //! - Introspection control
 //! - Complexity and depth limits
-//! - Federation support (via feature flag)
 //! - Integration with Spikard's HTTP runtime
+//! - Per-request resolver context (request data, auth claims, DI dependencies)
+//! - Request-scoped `DataLoader` batching for typed and dynamic schemas
+//! - Operation cost analysis with per-client cost budgets
+//! - Apollo Federation v2 subgraphs for typed and dynamic-SDL schemas (via feature flag)
 //!
 //! # Features
 //!
-//! - `federation` - Enable Apollo Federation support
+//! - `federation` - Serve typed and dynamic-SDL schemas as Apollo Federation v2 subgraphs
+//! - `di` - Expose DI-resolved dependencies to resolvers
 
 #![forbid(unsafe_code)]
 #![warn(
@@ -23,14 +27,27 @@
     clippy::all
 )]
 
+pub mod context;
+pub mod cost;
+pub mod dataloader;
 pub mod dynamic;
 pub mod error;
 pub mod executor;
+#[cfg(feature = "federation")]
+pub mod federation;
 pub mod handler;
 pub mod routes;
 pub mod schema;
 
-pub use dynamic::{DynamicGraphQLHandler, DynamicSchemaConfig, FieldErrorSpec, build_dynamic_schema, execute_dynamic};
+pub use context::GraphQLRequestContext;
+pub use cost::{ClientKeySource, CostAnalysis, CostAnalyzer, CostBudget, CostBudgetConfig, CostConfig, CostReport};
+pub use dataloader::{
+    BatchLoadFuture, BatchLoadResult, BatchLoader, DataLoaderConfig, DataLoaderRegistry, DataLoaders,
+};
+pub use dynamic::{
+    DynamicGraphQLHandler, DynamicSchemaConfig, EntityResolverSpec, FieldErrorSpec, FieldLoaderSpec,
+    build_dynamic_schema, execute_dynamic, execute_dynamic_with_context,
+};
 pub use error::{GraphQLError, Result as GraphQLResult};
 pub use executor::GraphQLExecutor;
 pub use handler::GraphQLHandler;
@@ -19,6 +19,8 @@ use serde::{Deserialize, Serialize};
 use std::fmt;
 use thiserror::Error;
 
+use crate::cost::CostConfig;
+
 /// Error type for schema building operations
 #[derive(Debug, Error)]
 pub enum SchemaError {
@@ -64,6 +66,12 @@ pub struct SchemaConfig {
     pub complexity_limit: Option<usize>,
     /// Maximum query depth (None = unlimited)
     pub depth_limit: Option<usize>,
+    /// Operation cost analysis, applied by `GraphQLExecutor::from_schema_config` (None = disabled)
+    #[serde(default)]
+    pub cost: Option<CostConfig>,
+    /// Expose the Apollo Federation `_service`/`_entities` fields (requires the `federation` feature)
+    #[serde(default)]
+    pub federation: bool,
 }
 
 impl Default for SchemaConfig {
@@ -72,6 +80,8 @@ impl Default for SchemaConfig {
             introspection_enabled: true,
             complexity_limit: None,
             depth_limit: None,
+            cost: None,
+            federation: false,
         }
     }
 }
@@ -233,6 +243,42 @@ impl<Query, Mutation, Subscription> SchemaBuilder<Query, Mutation, Subscription>
         self
     }
 
+    /// Enable operation cost analysis.
+    ///
+    /// Field weights and per-client budgets are described in [`crate::cost`].
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The cost analysis settings
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining
+    #[must_use]
+    pub fn cost_config(mut self, config: CostConfig) -> Self {
+        self.config.cost = Some(config);
+        self
+    }
+
+    /// Serve the schema as an Apollo Federation v2 subgraph.
+    ///
+    /// Entity types are declared with `#[graphql(entity)]` resolvers on the Query type, as in
+    /// plain `async-graphql`.
+    ///
+    /// # Arguments
+    ///
+    /// * `enable` - Whether to enable federation
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining
+    #[cfg(feature = "federation")]
+    #[must_use]
+    pub const fn enable_federation(mut self, enable: bool) -> Self {
+        self.config.federation = enable;
+        self
+    }
+
     /// Get the current introspection setting.
     ///
     /// # Returns
@@ -282,7 +328,7 @@ impl<Query, Mutation, Subscription> SchemaBuilder<Query, Mutation, Subscription>
     ///
     /// The `SchemaConfig` instance
     #[must_use]
-    pub const fn finish(self) -> SchemaConfig {
+    pub fn finish(self) -> SchemaConfig {
         self.config
     }
 }
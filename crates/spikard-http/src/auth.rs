@@ -13,6 +13,7 @@ use axum::{
 };
 use jsonwebtoken::{Algorithm, DecodingKey, Validation, decode};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::collections::HashSet;
 
 use crate::{ApiKeyConfig, JwtConfig, ProblemDetails};
@@ -175,7 +176,7 @@ fn parse_algorithm(alg: &str) -> Result<Algorithm, String> {
 pub async fn api_key_auth_middleware(
     config: ApiKeyConfig,
     headers: HeaderMap,
-    request: Request<Body>,
+    mut request: Request<Body>,
     next: Next,
 ) -> Result<Response, Response> {
     let valid_keys: HashSet<String> = config.keys.into_iter().collect();
@@ -201,9 +202,31 @@ pub async fn api_key_auth_middleware(
         return Err((StatusCode::UNAUTHORIZED, axum::Json(problem)).into_response());
     }
 
+    let identity = ApiKeyIdentity::new(api_key);
+    request.extensions_mut().insert(identity);
     Ok(next.run(request).await)
 }
 
+/// Identity of the client authenticated by [`api_key_auth_middleware`]
+///
+/// Stored in the request extensions. It is derived from a SHA-256 digest of the key, so
+/// it tells clients apart without keeping the key itself around.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ApiKeyIdentity(String);
+
+impl ApiKeyIdentity {
+    fn new(api_key: &str) -> Self {
+        let digest = Sha256::digest(api_key.as_bytes());
+        Self(digest[..16].iter().map(|byte| format!("{byte:02x}")).collect())
+    }
+
+    /// Hex-encoded identity
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
 /// Extract API key from query parameters
 ///
 /// Checks for common API key parameter names: api_key, apiKey, key
@@ -8,6 +8,7 @@ use tokio::sync::{Semaphore, mpsc};
 use tokio::task::JoinSet;
 use tokio::time::timeout;
 use tokio_util::sync::CancellationToken;
+use tracing::Instrument;
 
 /// Configuration for in-process background task execution.
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
@@ -99,6 +100,17 @@ impl std::error::Error for BackgroundSpawnError {}
 #[derive(Debug)]
 pub struct BackgroundShutdownError;
 
+/// Point-in-time counts of a background task runtime
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct BackgroundMetricsSnapshot {
+    /// Tasks accepted but not yet started
+    pub queued: u64,
+    /// Tasks currently running
+    pub running: u64,
+    /// Tasks that returned an error or could not start
+    pub failed: u64,
+}
+
 #[derive(Default, Debug)]
 struct BackgroundMetrics {
     queued: std::sync::atomic::AtomicU64,
@@ -126,6 +138,14 @@ impl BackgroundMetrics {
     fn inc_failed(&self) {
         self.failed.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
     }
+
+    fn snapshot(&self) -> BackgroundMetricsSnapshot {
+        BackgroundMetricsSnapshot {
+            queued: self.queued.load(std::sync::atomic::Ordering::Relaxed),
+            running: self.running.load(std::sync::atomic::Ordering::Relaxed),
+            failed: self.failed.load(std::sync::atomic::Ordering::Relaxed),
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -152,13 +172,24 @@ impl BackgroundHandle {
     where
         Fut: futures::Future<Output = Result<(), BackgroundJobError>> + Send + 'static,
     {
+        // Parented to the span active at spawn time so the task joins the request's trace
+        let span = tracing::info_span!(
+            "background_task",
+            otel.name = %metadata.name,
+            request_id = metadata.request_id.as_deref()
+        );
         self.metrics.inc_queued();
-        let job = BackgroundJob::new(future, metadata);
+        let job = BackgroundJob::new(future.instrument(span), metadata);
         self.sender.try_send(job).map_err(|_| {
             self.metrics.dec_queued();
             BackgroundSpawnError::QueueFull
         })
     }
+
+    /// Current queue depth, running and failed counts
+    pub fn metrics(&self) -> BackgroundMetricsSnapshot {
+        self.metrics.snapshot()
+    }
 }
 
 pub struct BackgroundRuntime {
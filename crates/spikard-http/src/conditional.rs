@@ -0,0 +1,410 @@
+//! HTTP caching metadata and conditional requests (RFC 9110 section 13).
+//!
+//! Routes with [`CachingConfig`] get two layers:
+//!
+//! - [`caching_middleware`] on the route itself adds `ETag` (hashed from the body
+//!   unless the handler set one), `Cache-Control` and `Vary` to successful
+//!   responses, and answers `GET`/`HEAD` with `304 Not Modified` or
+//!   `412 Precondition Failed` based on the request's validators.
+//! - [`precondition_middleware`] on the path checks `If-Match`, `If-None-Match`
+//!   and `If-Unmodified-Since` on unsafe methods before the handler runs. The
+//!   current validators come from a `GET` of the same path, so optimistic
+//!   concurrency works without the write handler knowing the current `ETag`.
+
+use axum::{
+    body::Body,
+    extract::{Request, State},
+    http::{HeaderMap, HeaderName, HeaderValue, Method, StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    routing::MethodRouter,
+};
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+use std::time::SystemTime;
+use tower::ServiceExt;
+
+use crate::{CONTENT_TYPE_PROBLEM_JSON, CachingConfig, ETagMode, ProblemDetails};
+
+/// Standard type URI for failed preconditions (412)
+pub const TYPE_PRECONDITION_FAILED: &str = "https://spikard.dev/errors/precondition-failed";
+
+/// Headers kept on a `304 Not Modified` response
+const NOT_MODIFIED_HEADERS: [HeaderName; 6] = [
+    header::CACHE_CONTROL,
+    header::CONTENT_LOCATION,
+    header::DATE,
+    header::ETAG,
+    header::EXPIRES,
+    header::VARY,
+];
+
+/// Shared state of the caching middleware for one route
+#[derive(Debug)]
+pub(crate) struct CachingState {
+    etag: ETagMode,
+    cache_control: Option<HeaderValue>,
+    vary: Option<HeaderValue>,
+}
+
+impl CachingState {
+    pub(crate) fn new(config: &CachingConfig) -> Result<Self, String> {
+        let cache_control = config
+            .cache_control
+            .as_deref()
+            .map(|value| HeaderValue::from_str(value).map_err(|_| format!("Invalid Cache-Control value '{}'", value)))
+            .transpose()?;
+        let vary = if config.vary.is_empty() {
+            None
+        } else {
+            for name in &config.vary {
+                name.parse::<HeaderName>()
+                    .map_err(|_| format!("Invalid Vary header name '{}'", name))?;
+            }
+            Some(HeaderValue::from_str(&config.vary.join(", ")).map_err(|e| e.to_string())?)
+        };
+        Ok(Self {
+            etag: config.etag,
+            cache_control,
+            vary,
+        })
+    }
+}
+
+/// Entity tag of a body: the first 128 bits of its SHA-256, hex encoded
+fn entity_tag(body: &[u8], weak: bool) -> HeaderValue {
+    let digest = Sha256::digest(body);
+    let hex: String = digest[..16].iter().map(|byte| format!("{:02x}", byte)).collect();
+    let tag = if weak {
+        format!("W/\"{}\"", hex)
+    } else {
+        format!("\"{}\"", hex)
+    };
+    HeaderValue::from_str(&tag).unwrap_or_else(|_| HeaderValue::from_static("\"\""))
+}
+
+/// A parsed `If-Match` / `If-None-Match` header
+enum EntityTags {
+    Any,
+    List(Vec<String>),
+}
+
+fn parse_entity_tags(headers: &HeaderMap, name: HeaderName) -> Option<EntityTags> {
+    let mut tags = Vec::new();
+    for value in headers.get_all(name).iter() {
+        let value = value.to_str().ok()?;
+        if value.trim() == "*" {
+            return Some(EntityTags::Any);
+        }
+        tags.extend(
+            value
+                .split(',')
+                .map(str::trim)
+                .filter(|tag| !tag.is_empty())
+                .map(str::to_string),
+        );
+    }
+    (!tags.is_empty()).then_some(EntityTags::List(tags))
+}
+
+fn opaque_tag(tag: &str) -> &str {
+    tag.strip_prefix("W/").unwrap_or(tag)
+}
+
+/// Whether `current` is in `tags`, using strong (`If-Match`) or weak (`If-None-Match`) comparison
+fn tag_matches(tags: &[String], current: Option<&str>, strong: bool) -> bool {
+    let Some(current) = current else {
+        return false;
+    };
+    tags.iter().any(|tag| {
+        if strong {
+            !tag.starts_with("W/") && !current.starts_with("W/") && tag == current
+        } else {
+            opaque_tag(tag) == opaque_tag(current)
+        }
+    })
+}
+
+fn http_date(headers: &HeaderMap, name: HeaderName) -> Option<SystemTime> {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| httpdate::parse_http_date(value).ok())
+}
+
+/// Validators of the current representation
+struct Validators {
+    exists: bool,
+    etag: Option<String>,
+    last_modified: Option<SystemTime>,
+}
+
+impl Validators {
+    fn from_response(response: &Response) -> Self {
+        Self {
+            exists: response.status().is_success(),
+            etag: response
+                .headers()
+                .get(header::ETAG)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string),
+            last_modified: http_date(response.headers(), header::LAST_MODIFIED),
+        }
+    }
+}
+
+/// Outcome of evaluating a request's preconditions
+#[derive(Debug, PartialEq, Eq)]
+enum Precondition {
+    Proceed,
+    NotModified,
+    Failed,
+}
+
+/// Evaluate preconditions in the order given by RFC 9110 section 13.2.2
+fn evaluate(method: &Method, request: &HeaderMap, current: &Validators) -> Precondition {
+    let etag = if current.exists { current.etag.as_deref() } else { None };
+    if let Some(tags) = parse_entity_tags(request, header::IF_MATCH) {
+        let satisfied = match tags {
+            EntityTags::Any => current.exists,
+            EntityTags::List(tags) => tag_matches(&tags, etag, true),
+        };
+        if !satisfied {
+            return Precondition::Failed;
+        }
+    } else if let (Some(since), Some(modified)) =
+        (http_date(request, header::IF_UNMODIFIED_SINCE), current.last_modified)
+        && modified > since
+    {
+        return Precondition::Failed;
+    }
+
+    let safe = matches!(*method, Method::GET | Method::HEAD);
+    if let Some(tags) = parse_entity_tags(request, header::IF_NONE_MATCH) {
+        let matched = match tags {
+            EntityTags::Any => current.exists,
+            EntityTags::List(tags) => tag_matches(&tags, etag, false),
+        };
+        if matched {
+            return if safe {
+                Precondition::NotModified
+            } else {
+                Precondition::Failed
+            };
+        }
+    } else if safe
+        && let (Some(since), Some(modified)) = (http_date(request, header::IF_MODIFIED_SINCE), current.last_modified)
+        && modified <= since
+    {
+        return Precondition::NotModified;
+    }
+    Precondition::Proceed
+}
+
+fn precondition_failed() -> Response {
+    let problem = ProblemDetails::new(
+        TYPE_PRECONDITION_FAILED,
+        "Precondition Failed",
+        StatusCode::PRECONDITION_FAILED,
+    )
+    .with_detail("The resource does not match the request's conditional headers");
+    let body = problem.to_json().unwrap_or_else(|_| "{}".to_string());
+    (
+        StatusCode::PRECONDITION_FAILED,
+        [(header::CONTENT_TYPE, CONTENT_TYPE_PROBLEM_JSON)],
+        Body::from(body),
+    )
+        .into_response()
+}
+
+fn not_modified(response: &Response) -> Response {
+    let mut not_modified = StatusCode::NOT_MODIFIED.into_response();
+    for name in NOT_MODIFIED_HEADERS {
+        for value in response.headers().get_all(&name) {
+            not_modified.headers_mut().append(name.clone(), value.clone());
+        }
+    }
+    not_modified
+}
+
+fn is_streaming(response: &Response) -> bool {
+    response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("text/event-stream"))
+}
+
+/// Add caching headers to successful responses and answer conditional `GET`/`HEAD` requests
+pub(crate) async fn caching_middleware(
+    State(state): State<Arc<CachingState>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let method = request.method().clone();
+    let conditions = request.headers().clone();
+    let mut response = next.run(request).await;
+    if !response.status().is_success() {
+        return response;
+    }
+
+    if let Some(ref cache_control) = state.cache_control
+        && !response.headers().contains_key(header::CACHE_CONTROL)
+    {
+        response
+            .headers_mut()
+            .insert(header::CACHE_CONTROL, cache_control.clone());
+    }
+    if let Some(ref vary) = state.vary {
+        response.headers_mut().append(header::VARY, vary.clone());
+    }
+
+    if !response.headers().contains_key(header::ETAG)
+        && state.etag != ETagMode::Handler
+        && response.status() == StatusCode::OK
+        && !is_streaming(&response)
+    {
+        let (mut parts, body) = response.into_parts();
+        let bytes = match axum::body::to_bytes(body, usize::MAX).await {
+            Ok(bytes) => bytes,
+            Err(err) => {
+                tracing::warn!("Failed to buffer response body for ETag: {}", err);
+                return (StatusCode::INTERNAL_SERVER_ERROR, Body::empty()).into_response();
+            }
+        };
+        parts
+            .headers
+            .insert(header::ETAG, entity_tag(&bytes, state.etag == ETagMode::Weak));
+        response = Response::from_parts(parts, Body::from(bytes));
+    }
+
+    if matches!(method, Method::GET | Method::HEAD) {
+        match evaluate(&method, &conditions, &Validators::from_response(&response)) {
+            Precondition::Proceed => {}
+            Precondition::NotModified => return not_modified(&response),
+            Precondition::Failed => return precondition_failed(),
+        }
+    }
+    response
+}
+
+/// Whether an unsafe request carries preconditions that need the current representation
+fn has_preconditions(request: &Request) -> bool {
+    !matches!(*request.method(), Method::GET | Method::HEAD | Method::OPTIONS)
+        && [header::IF_MATCH, header::IF_NONE_MATCH, header::IF_UNMODIFIED_SINCE]
+            .iter()
+            .any(|name| request.headers().contains_key(name))
+}
+
+/// Check `If-Match` / `If-None-Match` / `If-Unmodified-Since` on unsafe methods against
+/// the current representation, obtained by routing a `GET` of the same path through `probe`
+pub(crate) async fn precondition_middleware(
+    State(probe): State<MethodRouter>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if !has_preconditions(&request) {
+        return next.run(request).await;
+    }
+
+    let mut get = Request::new(Body::empty());
+    *get.method_mut() = Method::GET;
+    *get.uri_mut() = request.uri().clone();
+    *get.version_mut() = request.version();
+    *get.extensions_mut() = request.extensions().clone();
+    for (name, value) in request.headers() {
+        let conditional = name.as_str().starts_with("if-");
+        let describes_body = *name == header::CONTENT_TYPE || *name == header::CONTENT_LENGTH;
+        if !conditional && !describes_body {
+            get.headers_mut().append(name.clone(), value.clone());
+        }
+    }
+
+    let current = match probe.oneshot(get).await {
+        Ok(response) => Validators::from_response(&response),
+        Err(never) => match never {},
+    };
+    match evaluate(request.method(), request.headers(), &current) {
+        Precondition::Proceed => next.run(request).await,
+        Precondition::NotModified | Precondition::Failed => precondition_failed(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(pairs: &[(HeaderName, &str)]) -> HeaderMap {
+        let mut map = HeaderMap::new();
+        for (name, value) in pairs {
+            map.append(name.clone(), HeaderValue::from_str(value).expect("header value"));
+        }
+        map
+    }
+
+    fn current(etag: &str) -> Validators {
+        Validators {
+            exists: true,
+            etag: Some(etag.to_string()),
+            last_modified: httpdate::parse_http_date("Wed, 21 Oct 2026 07:28:00 GMT").ok(),
+        }
+    }
+
+    #[test]
+    fn if_none_match_uses_weak_comparison() {
+        let request = headers(&[(header::IF_NONE_MATCH, "\"other\", W/\"abc\"")]);
+        assert_eq!(
+            evaluate(&Method::GET, &request, &current("\"abc\"")),
+            Precondition::NotModified
+        );
+        assert_eq!(
+            evaluate(&Method::PUT, &request, &current("\"abc\"")),
+            Precondition::Failed
+        );
+    }
+
+    #[test]
+    fn if_match_uses_strong_comparison() {
+        let strong = headers(&[(header::IF_MATCH, "\"abc\"")]);
+        assert_eq!(
+            evaluate(&Method::PUT, &strong, &current("\"abc\"")),
+            Precondition::Proceed
+        );
+        assert_eq!(
+            evaluate(&Method::PUT, &strong, &current("W/\"abc\"")),
+            Precondition::Failed
+        );
+
+        let any = headers(&[(header::IF_MATCH, "*")]);
+        let missing = Validators {
+            exists: false,
+            etag: None,
+            last_modified: None,
+        };
+        assert_eq!(evaluate(&Method::PUT, &any, &missing), Precondition::Failed);
+    }
+
+    #[test]
+    fn dates_are_only_checked_without_entity_tags() {
+        let since = headers(&[(header::IF_MODIFIED_SINCE, "Wed, 21 Oct 2026 07:28:00 GMT")]);
+        assert_eq!(
+            evaluate(&Method::GET, &since, &current("\"abc\"")),
+            Precondition::NotModified
+        );
+
+        let both = headers(&[
+            (header::IF_NONE_MATCH, "\"other\""),
+            (header::IF_MODIFIED_SINCE, "Wed, 21 Oct 2026 07:28:00 GMT"),
+        ]);
+        assert_eq!(
+            evaluate(&Method::GET, &both, &current("\"abc\"")),
+            Precondition::Proceed
+        );
+
+        let unmodified = headers(&[(header::IF_UNMODIFIED_SINCE, "Tue, 20 Oct 2026 07:28:00 GMT")]);
+        assert_eq!(
+            evaluate(&Method::PATCH, &unmodified, &current("\"abc\"")),
+            Precondition::Failed
+        );
+    }
+}
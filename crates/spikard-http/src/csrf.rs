@@ -0,0 +1,236 @@
+//! Cross-site request forgery protection.
+//!
+//! Every unsafe request (anything but `GET`, `HEAD`, `OPTIONS` and `TRACE`) must
+//! echo the CSRF token in the configured header. Two ways of keeping the
+//! expected token are supported:
+//!
+//! - [`CsrfMode::DoubleSubmit`] stores it in a cookie readable by JavaScript, so
+//!   the client copies the cookie value into the header.
+//! - [`CsrfMode::Synchronizer`] stores it in the session (requires
+//!   `ServerConfig::session`); handlers hand it to the client, e.g. in a form.
+//!   A client without a session is only issued a token when the request starts
+//!   one, so anonymous traffic does not create a server-side session per visitor.
+//!
+//! The current token is available to Rust handlers as the [`CsrfToken`] request
+//! extension and to binding handlers in the [`INTERNAL_CSRF_TOKEN_HEADER`]
+//! request header.
+
+use axum::{
+    body::Body,
+    extract::{Request, State},
+    http::{HeaderValue, Method, StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use cookie::{Cookie, SameSite};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::Arc;
+
+use crate::session::{Session, parse_same_site, random_token};
+use crate::{CONTENT_TYPE_PROBLEM_JSON, ProblemDetails};
+
+/// Standard type URI for rejected CSRF checks (403)
+pub const TYPE_CSRF_FAILED: &str = "https://spikard.dev/errors/csrf-failed";
+
+/// Internal header exposing the current CSRF token to binding handlers
+pub const INTERNAL_CSRF_TOKEN_HEADER: &str = "x-spikard-csrf-token";
+
+/// Session key holding the synchronizer token
+pub const SESSION_CSRF_KEY: &str = "_csrf_token";
+
+/// Where the expected CSRF token is kept
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CsrfMode {
+    /// Token cookie that the client echoes in a header
+    #[default]
+    DoubleSubmit,
+    /// Token stored in the session
+    Synchronizer,
+}
+
+/// CSRF middleware configuration
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CsrfConfig {
+    /// How the expected token is kept
+    pub mode: CsrfMode,
+    /// Name of the token cookie (double-submit mode)
+    pub cookie_name: String,
+    /// Request header that must carry the token
+    pub header_name: String,
+    /// Path prefixes that skip the check (e.g. webhooks)
+    pub exempt_paths: Vec<String>,
+    /// Only send the token cookie over HTTPS
+    pub secure: bool,
+    /// `SameSite` attribute of the token cookie
+    pub same_site: String,
+    /// `Path` attribute of the token cookie
+    pub path: String,
+}
+
+impl Default for CsrfConfig {
+    fn default() -> Self {
+        Self {
+            mode: CsrfMode::default(),
+            cookie_name: "csrf_token".to_string(),
+            header_name: "x-csrf-token".to_string(),
+            exempt_paths: Vec::new(),
+            secure: true,
+            same_site: "Lax".to_string(),
+            path: "/".to_string(),
+        }
+    }
+}
+
+/// CSRF token expected on the client's next unsafe request
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsrfToken(pub String);
+
+impl CsrfToken {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Shared state of the CSRF middleware
+pub(crate) struct CsrfState {
+    config: CsrfConfig,
+    same_site: SameSite,
+}
+
+impl CsrfState {
+    pub(crate) fn new(config: &CsrfConfig, sessions_enabled: bool) -> Result<Self, String> {
+        if config.mode == CsrfMode::Synchronizer && !sessions_enabled {
+            return Err("CSRF synchronizer tokens require sessions to be configured".to_string());
+        }
+        if config.header_name.parse::<header::HeaderName>().is_err() {
+            return Err(format!("Invalid CSRF header name '{}'", config.header_name));
+        }
+        Ok(Self {
+            same_site: parse_same_site(&config.same_site)?,
+            config: config.clone(),
+        })
+    }
+
+    fn is_exempt(&self, request: &Request) -> bool {
+        matches!(
+            *request.method(),
+            Method::GET | Method::HEAD | Method::OPTIONS | Method::TRACE
+        ) || self
+            .config
+            .exempt_paths
+            .iter()
+            .any(|prefix| request.uri().path().starts_with(prefix.as_str()))
+    }
+
+    fn cookie_token(&self, request: &Request) -> Option<String> {
+        request
+            .headers()
+            .get_all(header::COOKIE)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(Cookie::split_parse)
+            .filter_map(Result::ok)
+            .find(|cookie| cookie.name() == self.config.cookie_name && !cookie.value().is_empty())
+            .map(|cookie| cookie.value().to_string())
+    }
+
+    fn token_cookie(&self, token: &str) -> Option<HeaderValue> {
+        let cookie = Cookie::build((self.config.cookie_name.as_str(), token))
+            .path(self.config.path.as_str())
+            .secure(self.config.secure)
+            .same_site(self.same_site)
+            .build();
+        HeaderValue::from_str(&cookie.to_string()).ok()
+    }
+}
+
+/// Compare tokens without leaking the position of the first difference
+fn tokens_match(expected: &str, provided: &[u8]) -> bool {
+    let expected = expected.as_bytes();
+    expected.len() == provided.len() && expected.iter().zip(provided).fold(0u8, |diff, (a, b)| diff | (a ^ b)) == 0
+}
+
+fn forbidden(detail: &str) -> Response {
+    let problem =
+        ProblemDetails::new(TYPE_CSRF_FAILED, "CSRF validation failed", StatusCode::FORBIDDEN).with_detail(detail);
+    let body = problem.to_json().unwrap_or_else(|_| "{}".to_string());
+    (
+        StatusCode::FORBIDDEN,
+        [(header::CONTENT_TYPE, CONTENT_TYPE_PROBLEM_JSON)],
+        Body::from(body),
+    )
+        .into_response()
+}
+
+/// Verify the CSRF token on unsafe requests and issue one when missing
+pub(crate) async fn csrf_middleware(State(state): State<Arc<CsrfState>>, mut request: Request, next: Next) -> Response {
+    let session = request.extensions().get::<Session>().cloned();
+    let expected = match state.config.mode {
+        CsrfMode::DoubleSubmit => state.cookie_token(&request),
+        CsrfMode::Synchronizer => session
+            .as_ref()
+            .and_then(|session| session.get::<String>(SESSION_CSRF_KEY)),
+    };
+
+    if !state.is_exempt(&request) {
+        let Some(ref expected) = expected else {
+            return forbidden("No CSRF token has been issued to this client");
+        };
+        let provided = request.headers().get(state.config.header_name.as_str());
+        if !provided.is_some_and(|provided| tokens_match(expected, provided.as_bytes())) {
+            return forbidden(&format!(
+                "Missing or invalid CSRF token in the '{}' header",
+                state.config.header_name
+            ));
+        }
+    }
+
+    let issued = expected.is_none();
+    let token = expected.unwrap_or_else(random_token);
+    if issued
+        && state.config.mode == CsrfMode::Synchronizer
+        && let Some(ref session) = session
+    {
+        if session.is_new() {
+            session.insert_if_started(SESSION_CSRF_KEY, Value::String(token.clone()));
+        } else {
+            let _ = session.insert(SESSION_CSRF_KEY, &token);
+        }
+    }
+    request.extensions_mut().insert(CsrfToken(token.clone()));
+
+    let mut response = next.run(request).await;
+    if issued
+        && state.config.mode == CsrfMode::DoubleSubmit
+        && let Some(cookie) = state.token_cookie(&token)
+    {
+        response.headers_mut().append(header::SET_COOKIE, cookie);
+    }
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn token_comparison_requires_exact_match() {
+        assert!(tokens_match("abc123", b"abc123"));
+        assert!(!tokens_match("abc123", b"abc124"));
+        assert!(!tokens_match("abc123", b"abc12"));
+        assert!(!tokens_match("abc123", b""));
+    }
+
+    #[test]
+    fn synchronizer_mode_requires_sessions() {
+        let config = CsrfConfig {
+            mode: CsrfMode::Synchronizer,
+            ..Default::default()
+        };
+        assert!(CsrfState::new(&config, false).is_err());
+        assert!(CsrfState::new(&config, true).is_ok());
+    }
+}
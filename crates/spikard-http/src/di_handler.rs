@@ -44,7 +44,7 @@ use spikard_core::di::{DependencyContainer, DependencyError};
 use std::future::Future;
 use std::pin::Pin;
 use std::sync::Arc;
-use tracing::{debug, info_span, instrument};
+use tracing::{Instrument, debug, info_span, instrument};
 
 /// Handler wrapper that resolves dependencies before calling the inner handler
 ///
@@ -141,8 +141,6 @@ impl Handler for DependencyInjectingHandler {
                 "resolve_dependencies",
                 count = %required_dependencies.len()
             );
-            let _enter = resolution_span.enter();
-
             debug!(
                 "Resolving {} dependencies: {:?}",
                 required_dependencies.len(),
@@ -151,24 +149,7 @@ impl Handler for DependencyInjectingHandler {
 
             let start = std::time::Instant::now();
 
-            let core_request_data = spikard_core::RequestData {
-                path_params: Arc::clone(&request_data.path_params),
-                query_params: Arc::try_unwrap(Arc::clone(&request_data.query_params))
-                    .unwrap_or_else(|arc| (*arc).clone()),
-                validated_params: request_data
-                    .validated_params
-                    .as_ref()
-                    .map(|arc| Arc::try_unwrap(Arc::clone(arc)).unwrap_or_else(|a| (*a).clone())),
-                raw_query_params: Arc::clone(&request_data.raw_query_params),
-                body: Arc::try_unwrap(Arc::clone(&request_data.body)).unwrap_or_else(|arc| (*arc).clone()),
-                raw_body: request_data.raw_body.clone(),
-                headers: Arc::clone(&request_data.headers),
-                cookies: Arc::clone(&request_data.cookies),
-                method: request_data.method.clone(),
-                path: request_data.path.clone(),
-                #[cfg(feature = "di")]
-                dependencies: None,
-            };
+            let core_request_data = core_request_data(&request_data);
 
             let (parts, _body) = request.into_parts();
             let core_request = Request::from_parts(parts.clone(), ());
@@ -177,76 +158,13 @@ impl Handler for DependencyInjectingHandler {
 
             let resolved = match container
                 .resolve_for_handler(&required_dependencies, &core_request, &core_request_data)
+                .instrument(resolution_span)
                 .await
             {
                 Ok(resolved) => resolved,
                 Err(e) => {
                     debug!("DI error: {}", e);
-
-                    let (status, json_body) = match e {
-                        DependencyError::NotFound { ref key } => {
-                            let body = serde_json::json!({
-                                "detail": "Required dependency not found",
-                                "errors": [{
-                                    "dependency_key": key,
-                                    "msg": format!("Dependency '{}' is not registered", key),
-                                    "type": "missing_dependency"
-                                }],
-                                "status": 500,
-                                "title": "Dependency Resolution Failed",
-                                "type": "https://spikard.dev/errors/dependency-error"
-                            });
-                            (StatusCode::INTERNAL_SERVER_ERROR, body)
-                        }
-                        DependencyError::CircularDependency { ref cycle } => {
-                            let body = serde_json::json!({
-                                "detail": "Circular dependency detected",
-                                "errors": [{
-                                    "cycle": cycle,
-                                    "msg": "Circular dependency detected in dependency graph",
-                                    "type": "circular_dependency"
-                                }],
-                                "status": 500,
-                                "title": "Dependency Resolution Failed",
-                                "type": "https://spikard.dev/errors/dependency-error"
-                            });
-                            (StatusCode::INTERNAL_SERVER_ERROR, body)
-                        }
-                        DependencyError::ResolutionFailed { ref message } => {
-                            let body = serde_json::json!({
-                                "detail": "Dependency resolution failed",
-                                "errors": [{
-                                    "msg": message,
-                                    "type": "resolution_failed"
-                                }],
-                                "status": 503,
-                                "title": "Service Unavailable",
-                                "type": "https://spikard.dev/errors/dependency-error"
-                            });
-                            (StatusCode::SERVICE_UNAVAILABLE, body)
-                        }
-                        _ => {
-                            let body = serde_json::json!({
-                                "detail": "Dependency resolution failed",
-                                "errors": [{
-                                    "msg": e.to_string(),
-                                    "type": "unknown"
-                                }],
-                                "status": 500,
-                                "title": "Dependency Resolution Failed",
-                                "type": "https://spikard.dev/errors/dependency-error"
-                            });
-                            (StatusCode::INTERNAL_SERVER_ERROR, body)
-                        }
-                    };
-
-                    let response = axum::http::Response::builder()
-                        .status(status)
-                        .header("Content-Type", "application/json")
-                        .body(Body::from(json_body.to_string()))
-                        .unwrap();
-
-                    return Ok(response);
+                    return Ok(dependency_error_response(&e));
                 }
             };
 
@@ -257,19 +175,14 @@ impl Handler for DependencyInjectingHandler {
                 required_dependencies.len()
             );
 
-            drop(_enter);
-
             let deps = Arc::new(resolved);
             request_data.dependencies = Some(Arc::clone(&deps));
 
             let result = inner.call(request, request_data).await;
 
             if let Ok(deps) = Arc::try_unwrap(deps) {
-                let cleanup_span = info_span!("cleanup_dependencies");
-                let _enter = cleanup_span.enter();
-
                 debug!("Running dependency cleanup tasks");
-                deps.cleanup().await;
+                deps.cleanup().instrument(info_span!("cleanup_dependencies")).await;
             } else {
                 debug!("Skipping cleanup: dependencies still shared");
             }
@@ -279,6 +192,208 @@ impl Handler for DependencyInjectingHandler {
     }
 }
 
+/// Status and RFC 9457 body describing a dependency resolution failure
+pub(crate) fn dependency_error_body(e: &DependencyError) -> (StatusCode, serde_json::Value) {
+    match e {
+        DependencyError::NotFound { key } => {
+            let body = serde_json::json!({
+                "detail": "Required dependency not found",
+                "errors": [{
+                    "dependency_key": key,
+                    "msg": format!("Dependency '{}' is not registered", key),
+                    "type": "missing_dependency"
+                }],
+                "status": 500,
+                "title": "Dependency Resolution Failed",
+                "type": "https://spikard.dev/errors/dependency-error"
+            });
+            (StatusCode::INTERNAL_SERVER_ERROR, body)
+        }
+        DependencyError::CircularDependency { cycle } => {
+            let body = serde_json::json!({
+                "detail": "Circular dependency detected",
+                "errors": [{
+                    "cycle": cycle,
+                    "msg": "Circular dependency detected in dependency graph",
+                    "type": "circular_dependency"
+                }],
+                "status": 500,
+                "title": "Dependency Resolution Failed",
+                "type": "https://spikard.dev/errors/dependency-error"
+            });
+            (StatusCode::INTERNAL_SERVER_ERROR, body)
+        }
+        DependencyError::ResolutionFailed { message } => {
+            let body = serde_json::json!({
+                "detail": "Dependency resolution failed",
+                "errors": [{
+                    "msg": message,
+                    "type": "resolution_failed"
+                }],
+                "status": 503,
+                "title": "Service Unavailable",
+                "type": "https://spikard.dev/errors/dependency-error"
+            });
+            (StatusCode::SERVICE_UNAVAILABLE, body)
+        }
+        _ => {
+            let body = serde_json::json!({
+                "detail": "Dependency resolution failed",
+                "errors": [{
+                    "msg": e.to_string(),
+                    "type": "unknown"
+                }],
+                "status": 500,
+                "title": "Dependency Resolution Failed",
+                "type": "https://spikard.dev/errors/dependency-error"
+            });
+            (StatusCode::INTERNAL_SERVER_ERROR, body)
+        }
+    }
+}
+
+/// Response extension marking a dependency resolution failure
+///
+/// Lets transports that reuse [`DependencyInjectingHandler`] (JSON-RPC) tell the
+/// failure apart from a handler result.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DependencyResolutionFailed;
+
+/// Build the error response sent when dependencies cannot be resolved
+pub(crate) fn dependency_error_response(e: &DependencyError) -> axum::http::Response<Body> {
+    let (status, json_body) = dependency_error_body(e);
+    axum::http::Response::builder()
+        .status(status)
+        .header("Content-Type", "application/json")
+        .extension(DependencyResolutionFailed)
+        .body(Body::from(json_body.to_string()))
+        .unwrap()
+}
+
+/// Dependency keys declared by a WebSocket, SSE, JSON-RPC or gRPC endpoint
+///
+/// HTTP routes are wrapped in [`DependencyInjectingHandler`]; other transports hold
+/// one of these and resolve it per connection (WebSocket, SSE) or per call (gRPC).
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Clone)]
+pub struct DeclaredDependencies {
+    container: Arc<DependencyContainer>,
+    keys: Vec<String>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl DeclaredDependencies {
+    /// Declare `keys` to be resolved from `container`
+    pub fn new(container: Arc<DependencyContainer>, keys: Vec<String>) -> Self {
+        Self { container, keys }
+    }
+
+    /// The declared dependency keys
+    pub fn keys(&self) -> &[String] {
+        &self.keys
+    }
+
+    /// Resolve the declared keys for the request that opened a connection or call
+    ///
+    /// Factories see the request's method, path, query, headers and cookies; there
+    /// is no body.
+    ///
+    /// # Errors
+    ///
+    /// Returns the container's error when a dependency is missing or fails to resolve.
+    pub async fn resolve(
+        &self,
+        parts: &axum::http::request::Parts,
+    ) -> Result<spikard_core::di::ResolvedDependencies, DependencyError> {
+        let data = crate::server::request_extraction::create_request_data_without_body(
+            &parts.uri,
+            &parts.method,
+            &parts.headers,
+            std::collections::HashMap::new(),
+            crate::server::request_extraction::WithoutBodyExtractionOptions {
+                include_raw_query_params: true,
+                include_query_params_json: true,
+                include_headers: true,
+                include_cookies: true,
+            },
+        );
+        let mut request = Request::new(());
+        *request.method_mut() = parts.method.clone();
+        *request.uri_mut() = parts.uri.clone();
+        *request.headers_mut() = parts.headers.clone();
+
+        self.container
+            .resolve_for_handler(&self.keys, &request, &core_request_data(&data))
+            .await
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl std::fmt::Debug for DeclaredDependencies {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DeclaredDependencies")
+            .field("keys", &self.keys)
+            .finish()
+    }
+}
+
+/// Dependencies held by a stream, cleaned up when the stream is dropped
+///
+/// Streams end without a final await when the client disconnects, so cleanup is
+/// spawned onto the current runtime from `Drop`.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) struct DependencyScope(Option<spikard_core::di::ResolvedDependencies>);
+
+#[cfg(not(target_arch = "wasm32"))]
+impl DependencyScope {
+    pub(crate) const fn new(dependencies: Option<spikard_core::di::ResolvedDependencies>) -> Self {
+        Self(dependencies)
+    }
+
+    pub(crate) const fn dependencies(&self) -> Option<&spikard_core::di::ResolvedDependencies> {
+        self.0.as_ref()
+    }
+
+    /// Run the cleanup tasks now instead of spawning them on drop
+    pub(crate) async fn release(mut self) {
+        if let Some(dependencies) = self.0.take() {
+            dependencies.cleanup().await;
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Drop for DependencyScope {
+    fn drop(&mut self) {
+        if let Some(dependencies) = self.0.take()
+            && let Ok(runtime) = tokio::runtime::Handle::try_current()
+        {
+            runtime.spawn(dependencies.cleanup());
+        }
+    }
+}
+
+/// Convert the HTTP request data into the form dependency factories receive
+fn core_request_data(request_data: &RequestData) -> spikard_core::RequestData {
+    spikard_core::RequestData {
+        path_params: Arc::clone(&request_data.path_params),
+        query_params: Arc::try_unwrap(Arc::clone(&request_data.query_params)).unwrap_or_else(|arc| (*arc).clone()),
+        validated_params: request_data
+            .validated_params
+            .as_ref()
+            .map(|arc| Arc::try_unwrap(Arc::clone(arc)).unwrap_or_else(|a| (*a).clone())),
+        raw_query_params: Arc::clone(&request_data.raw_query_params),
+        body: Arc::try_unwrap(Arc::clone(&request_data.body)).unwrap_or_else(|arc| (*arc).clone()),
+        raw_body: request_data.raw_body.clone(),
+        headers: Arc::clone(&request_data.headers),
+        cookies: Arc::clone(&request_data.cookies),
+        method: request_data.method.clone(),
+        path: request_data.path.clone(),
+        #[cfg(feature = "di")]
+        dependencies: None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -359,6 +474,9 @@ mod tests {
             cookies: Arc::new(HashMap::new()),
             method: "GET".to_string(),
             path: "/".to_string(),
+            client_ip: None,
+            scheme: None,
+            host: None,
             #[cfg(feature = "di")]
             dependencies: None,
         }
@@ -1615,6 +1733,9 @@ mod tests {
             cookies: Arc::new(cookies.clone()),
             method: "POST".to_string(),
             path: "/api/users/123".to_string(),
+            client_ip: None,
+            scheme: None,
+            host: None,
             #[cfg(feature = "di")]
             dependencies: None,
         };
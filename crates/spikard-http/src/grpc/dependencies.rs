@@ -0,0 +1,180 @@
+//! Dependency injection for gRPC handlers
+//!
+//! [`DependencyInjectingGrpcHandler`] resolves the declared dependencies for every
+//! call and attaches them to the request before the wrapped handler runs. Factories
+//! see the call as a `POST /{service}/{method}` request carrying the call metadata
+//! as headers.
+
+use super::handler::{GrpcHandler, GrpcHandlerResult, GrpcRequestData, RpcMode};
+use super::streaming::{MessageStream, StreamingRequest};
+use crate::di_handler::{DeclaredDependencies, DependencyScope};
+use futures_util::StreamExt;
+use spikard_core::di::{DependencyError, ResolvedDependencies};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use tonic::metadata::MetadataMap;
+
+/// gRPC handler wrapper that resolves dependencies for each call
+///
+/// Unary and client streaming calls clean their dependencies up once the response
+/// is produced; server and bidirectional streams clean up when the response stream
+/// is dropped.
+///
+/// # Examples
+///
+/// ```ignore
+/// use spikard_http::DeclaredDependencies;
+/// use spikard_http::grpc::DependencyInjectingGrpcHandler;
+///
+/// let handler = DependencyInjectingGrpcHandler::new(
+///     Arc::new(UserService),
+///     DeclaredDependencies::new(container, vec!["db".to_string()]),
+/// );
+/// ```
+pub struct DependencyInjectingGrpcHandler {
+    inner: Arc<dyn GrpcHandler>,
+    dependencies: DeclaredDependencies,
+}
+
+impl DependencyInjectingGrpcHandler {
+    /// Wrap `handler` so each call receives `dependencies`
+    pub fn new(handler: Arc<dyn GrpcHandler>, dependencies: DeclaredDependencies) -> Self {
+        Self {
+            inner: handler,
+            dependencies,
+        }
+    }
+
+    async fn resolve(
+        &self,
+        service_name: &str,
+        method_name: &str,
+        metadata: &MetadataMap,
+    ) -> Result<ResolvedDependencies, tonic::Status> {
+        let (mut parts, ()) = axum::http::Request::post(format!("/{service_name}/{method_name}"))
+            .body(())
+            .map_err(|e| tonic::Status::internal(format!("Invalid gRPC path: {e}")))?
+            .into_parts();
+        parts.headers = metadata.clone().into_headers();
+
+        self.dependencies
+            .resolve(&parts)
+            .await
+            .map_err(|e| dependency_status(&e))
+    }
+}
+
+impl std::fmt::Debug for DependencyInjectingGrpcHandler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DependencyInjectingGrpcHandler")
+            .field("service_name", &self.inner.service_name())
+            .field("dependencies", &self.dependencies)
+            .finish()
+    }
+}
+
+impl GrpcHandler for DependencyInjectingGrpcHandler {
+    fn call(&self, mut request: GrpcRequestData) -> Pin<Box<dyn Future<Output = GrpcHandlerResult> + Send + '_>> {
+        Box::pin(async move {
+            let resolved = self
+                .resolve(&request.service_name, &request.method_name, &request.metadata)
+                .await?;
+            let resolved = Arc::new(resolved);
+            request.dependencies = Some(Arc::clone(&resolved));
+
+            let result = self.inner.call(request).await;
+            release(resolved).await;
+            result
+        })
+    }
+
+    fn service_name(&self) -> &str {
+        self.inner.service_name()
+    }
+
+    fn rpc_mode(&self) -> RpcMode {
+        self.inner.rpc_mode()
+    }
+
+    fn dependency_keys(&self) -> &[String] {
+        self.dependencies.keys()
+    }
+
+    fn call_server_stream(
+        &self,
+        mut request: GrpcRequestData,
+    ) -> Pin<Box<dyn Future<Output = Result<MessageStream, tonic::Status>> + Send + '_>> {
+        Box::pin(async move {
+            let resolved = self
+                .resolve(&request.service_name, &request.method_name, &request.metadata)
+                .await?;
+            request.dependencies = Some(Arc::new(resolved.clone()));
+
+            let stream = self.inner.call_server_stream(request).await;
+            hold_until_dropped(stream, resolved)
+        })
+    }
+
+    fn call_client_stream(
+        &self,
+        mut request: StreamingRequest,
+    ) -> Pin<Box<dyn Future<Output = GrpcHandlerResult> + Send + '_>> {
+        Box::pin(async move {
+            let resolved = self
+                .resolve(&request.service_name, &request.method_name, &request.metadata)
+                .await?;
+            let resolved = Arc::new(resolved);
+            request.dependencies = Some(Arc::clone(&resolved));
+
+            let result = self.inner.call_client_stream(request).await;
+            release(resolved).await;
+            result
+        })
+    }
+
+    fn call_bidi_stream(
+        &self,
+        mut request: StreamingRequest,
+    ) -> Pin<Box<dyn Future<Output = Result<MessageStream, tonic::Status>> + Send + '_>> {
+        Box::pin(async move {
+            let resolved = self
+                .resolve(&request.service_name, &request.method_name, &request.metadata)
+                .await?;
+            request.dependencies = Some(Arc::new(resolved.clone()));
+
+            let stream = self.inner.call_bidi_stream(request).await;
+            hold_until_dropped(stream, resolved)
+        })
+    }
+}
+
+/// Map a resolution failure to the gRPC status the HTTP error response corresponds to
+fn dependency_status(e: &DependencyError) -> tonic::Status {
+    match e {
+        DependencyError::ResolutionFailed { message } => {
+            tonic::Status::unavailable(format!("Dependency resolution failed: {message}"))
+        }
+        _ => tonic::Status::internal(format!("Dependency resolution failed: {e}")),
+    }
+}
+
+/// Run cleanup once the handler has released its reference
+async fn release(resolved: Arc<ResolvedDependencies>) {
+    if let Ok(resolved) = Arc::try_unwrap(resolved) {
+        resolved.cleanup().await;
+    }
+}
+
+/// Keep `resolved` alive for as long as the response stream, cleaning up when it drops
+fn hold_until_dropped(
+    stream: Result<MessageStream, tonic::Status>,
+    resolved: ResolvedDependencies,
+) -> Result<MessageStream, tonic::Status> {
+    let scope = DependencyScope::new(Some(resolved));
+    let stream = stream?;
+    Ok(Box::pin(stream.map(move |message| {
+        let _ = &scope;
+        message
+    })))
+}
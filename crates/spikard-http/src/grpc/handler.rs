@@ -44,6 +44,10 @@ pub struct GrpcRequestData {
     pub payload: Bytes,
     /// gRPC metadata (similar to HTTP headers)
     pub metadata: MetadataMap,
+    /// Dependencies resolved for this call by
+    /// [`DependencyInjectingGrpcHandler`](super::DependencyInjectingGrpcHandler)
+    #[cfg(feature = "di")]
+    pub dependencies: Option<std::sync::Arc<spikard_core::di::ResolvedDependencies>>,
 }
 
 /// gRPC response data returned by handlers
@@ -231,6 +235,14 @@ pub trait GrpcHandler: Send + Sync {
         RpcMode::Unary
     }
 
+    /// Dependency keys resolved for every call
+    ///
+    /// Checked against the dependency container when the router is built. Handlers
+    /// wrapped in `DependencyInjectingGrpcHandler` report their declared keys.
+    fn dependency_keys(&self) -> &[String] {
+        &[]
+    }
+
     /// Handle a server streaming RPC request
     ///
     /// Takes a single request and returns a stream of response messages.
@@ -270,6 +282,8 @@ pub trait GrpcHandler: Send + Sync {
                 method_name,
                 mut message_stream,
                 metadata,
+                #[cfg(feature = "di")]
+                dependencies,
             } = request;
 
             let first_message = match message_stream.next().await {
@@ -298,6 +312,8 @@ pub trait GrpcHandler: Send + Sync {
                 method_name,
                 payload: first_message,
                 metadata,
+                #[cfg(feature = "di")]
+                dependencies,
             })
             .await
         })
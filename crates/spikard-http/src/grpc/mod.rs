@@ -42,11 +42,15 @@
 //! let config = GrpcConfig::default();
 //! ```
 
+#[cfg(feature = "di")]
+mod dependencies;
 pub(crate) mod framing;
 pub(crate) mod handler;
 pub(crate) mod service;
 pub(crate) mod streaming;
 
+#[cfg(feature = "di")]
+pub use dependencies::DependencyInjectingGrpcHandler;
 pub(crate) use handler::RpcMode;
 pub use handler::{GrpcHandler, GrpcHandlerResult, GrpcRequestData, GrpcResponseData};
 pub(crate) use service::{GenericGrpcService, parse_grpc_path};
@@ -262,6 +266,17 @@ impl GrpcRegistry {
     pub fn is_empty(&self) -> bool {
         self.handlers.is_empty()
     }
+
+    /// Dependency keys declared by each registered method, labelled `GRPC service/method`
+    #[cfg(feature = "di")]
+    pub fn dependencies(&self) -> impl Iterator<Item = (String, &[String])> {
+        self.handlers
+            .iter()
+            .filter(|(_, (handler, _))| !handler.dependency_keys().is_empty())
+            .map(|((service_name, method_name), (handler, _))| {
+                (format!("GRPC {service_name}/{method_name}"), handler.dependency_keys())
+            })
+    }
 }
 
 #[cfg(test)]
@@ -64,6 +64,8 @@ impl GenericGrpcService {
             method_name,
             payload,
             metadata,
+            #[cfg(feature = "di")]
+            dependencies: None,
         };
 
         let result: GrpcHandlerResult = self.handler.call(grpc_request).await;
@@ -124,6 +126,8 @@ impl GenericGrpcService {
             method_name,
             payload,
             metadata,
+            #[cfg(feature = "di")]
+            dependencies: None,
         };
 
         let message_stream: MessageStream = self.handler.call_server_stream(grpc_request).await?;
@@ -204,6 +208,8 @@ impl GenericGrpcService {
             method_name,
             message_stream,
             metadata,
+            #[cfg(feature = "di")]
+            dependencies: None,
         };
 
         let response: crate::grpc::handler::GrpcHandlerResult =
@@ -279,6 +285,8 @@ impl GenericGrpcService {
             method_name,
             message_stream,
             metadata,
+            #[cfg(feature = "di")]
+            dependencies: None,
         };
 
         let response_stream: MessageStream = self.handler.call_bidi_stream(streaming_request).await?;
@@ -78,6 +78,10 @@ pub struct StreamingRequest {
     pub message_stream: MessageStream,
     /// Request metadata
     pub metadata: tonic::metadata::MetadataMap,
+    /// Dependencies resolved for this call by
+    /// [`DependencyInjectingGrpcHandler`](super::DependencyInjectingGrpcHandler)
+    #[cfg(feature = "di")]
+    pub dependencies: Option<std::sync::Arc<spikard_core::di::ResolvedDependencies>>,
 }
 
 /// Helper to create a single-message stream
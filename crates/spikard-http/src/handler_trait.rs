@@ -98,6 +98,12 @@ pub struct RequestData {
     pub method: String,
     /// Request path, including any path prefix (e.g. `"/api/users/42"`).
     pub path: String,
+    /// Client IP address, resolved through trusted proxies when configured.
+    pub client_ip: Option<String>,
+    /// Scheme the client used (`"http"` or `"https"`).
+    pub scheme: Option<String>,
+    /// Host the client addressed, including any port (e.g. `"api.example.com"`).
+    pub host: Option<String>,
     /// Resolved dependencies for this request (populated by DependencyInjectingHandler)
     #[cfg(feature = "di")]
     pub dependencies: Option<std::sync::Arc<spikard_core::di::ResolvedDependencies>>,
@@ -110,9 +116,9 @@ impl Serialize for RequestData {
     {
         use serde::ser::SerializeStruct;
         #[cfg(feature = "di")]
-        let field_count = 11;
+        let field_count = 14;
         #[cfg(not(feature = "di"))]
-        let field_count = 10;
+        let field_count = 13;
 
         let mut state = serializer.serialize_struct("RequestData", field_count)?;
         state.serialize_field("path_params", &*self.path_params)?;
@@ -125,6 +131,9 @@ impl Serialize for RequestData {
         state.serialize_field("cookies", &*self.cookies)?;
         state.serialize_field("method", &self.method)?;
         state.serialize_field("path", &self.path)?;
+        state.serialize_field("client_ip", &self.client_ip)?;
+        state.serialize_field("scheme", &self.scheme)?;
+        state.serialize_field("host", &self.host)?;
 
         #[cfg(feature = "di")]
         {
@@ -153,6 +162,9 @@ impl<'de> Deserialize<'de> for RequestData {
             Cookies,
             Method,
             Path,
+            ClientIp,
+            Scheme,
+            Host,
             #[cfg(feature = "di")]
             HasDependencies,
         }
@@ -180,6 +192,9 @@ impl<'de> Deserialize<'de> for RequestData {
                 let mut cookies = None;
                 let mut method = None;
                 let mut path = None;
+                let mut client_ip = None;
+                let mut scheme = None;
+                let mut host = None;
 
                 while let Some(key) = map.next_key()? {
                     match key {
@@ -214,6 +229,15 @@ impl<'de> Deserialize<'de> for RequestData {
                         Field::Path => {
                             path = Some(map.next_value()?);
                         }
+                        Field::ClientIp => {
+                            client_ip = map.next_value()?;
+                        }
+                        Field::Scheme => {
+                            scheme = map.next_value()?;
+                        }
+                        Field::Host => {
+                            host = map.next_value()?;
+                        }
                         #[cfg(feature = "di")]
                         Field::HasDependencies => {
                             let _: bool = map.next_value()?;
@@ -233,6 +257,9 @@ impl<'de> Deserialize<'de> for RequestData {
                     cookies: cookies.ok_or_else(|| serde::de::Error::missing_field("cookies"))?,
                     method: method.ok_or_else(|| serde::de::Error::missing_field("method"))?,
                     path: path.ok_or_else(|| serde::de::Error::missing_field("path"))?,
+                    client_ip,
+                    scheme,
+                    host,
                     #[cfg(feature = "di")]
                     dependencies: None,
                 })
@@ -251,6 +278,9 @@ impl<'de> Deserialize<'de> for RequestData {
             "cookies",
             "method",
             "path",
+            "client_ip",
+            "scheme",
+            "host",
             "has_dependencies",
         ];
 
@@ -266,6 +296,9 @@ impl<'de> Deserialize<'de> for RequestData {
             "cookies",
             "method",
             "path",
+            "client_ip",
+            "scheme",
+            "host",
         ];
 
         deserializer.deserialize_struct("RequestData", FIELDS, RequestDataVisitor)
@@ -339,6 +372,14 @@ pub trait Handler: Send + Sync {
     fn static_response(&self) -> Option<StaticResponse> {
         None
     }
+
+    /// Lifecycle hooks scoped to this route
+    ///
+    /// When `Some`, the server runs these hooks after the global hooks of each phase
+    /// for requests to this route only.
+    fn lifecycle_hooks(&self) -> Option<std::sync::Arc<crate::LifecycleHooks>> {
+        None
+    }
 }
 
 /// A no-op handler that declares a static response.
@@ -418,6 +459,9 @@ mod tests {
             cookies: std::sync::Arc::new(HashMap::new()),
             method: "GET".to_string(),
             path: "/".to_string(),
+            client_ip: None,
+            scheme: None,
+            host: None,
             #[cfg(feature = "di")]
             dependencies: None,
         }
@@ -645,6 +689,9 @@ mod tests {
             cookies: std::sync::Arc::new(cookies),
             method: "POST".to_string(),
             path: "/api/users".to_string(),
+            client_ip: None,
+            scheme: None,
+            host: None,
             #[cfg(feature = "di")]
             dependencies: None,
         };
@@ -787,6 +834,9 @@ mod tests {
             }),
             method: "PUT".to_string(),
             path: "/blog/posts/999".to_string(),
+            client_ip: None,
+            scheme: None,
+            host: None,
             #[cfg(feature = "di")]
             dependencies: None,
         };
@@ -837,6 +887,9 @@ mod tests {
             cookies: std::sync::Arc::new(HashMap::new()),
             method: "GET".to_string(),
             path: "/".to_string(),
+            client_ip: None,
+            scheme: None,
+            host: None,
             #[cfg(feature = "di")]
             dependencies: None,
         };
@@ -108,6 +108,9 @@ mod tests {
             cookies: Arc::new(HashMap::new()),
             method: "POST".to_string(),
             path: "/items/123".to_string(),
+            client_ip: None,
+            scheme: None,
+            host: None,
             #[cfg(feature = "di")]
             dependencies: None,
         };
@@ -140,6 +143,9 @@ mod tests {
             cookies: Arc::new(HashMap::new()),
             method: "GET".to_string(),
             path: "/error".to_string(),
+            client_ip: None,
+            scheme: None,
+            host: None,
 
             #[cfg(feature = "di")]
             dependencies: None,
@@ -179,6 +185,9 @@ mod tests {
             cookies: Arc::new(HashMap::new()),
             method: "GET".to_string(),
             path: "/api/data".to_string(),
+            client_ip: None,
+            scheme: None,
+            host: None,
             #[cfg(feature = "di")]
             dependencies: None,
         };
@@ -203,6 +212,9 @@ mod tests {
             cookies: Arc::new(HashMap::new()),
             method: "GET".to_string(),
             path: "/api/data".to_string(),
+            client_ip: None,
+            scheme: None,
+            host: None,
 
             #[cfg(feature = "di")]
             dependencies: None,
@@ -241,6 +253,9 @@ mod tests {
             cookies: Arc::new(HashMap::new()),
             method: "PUT".to_string(),
             path: "/users/42".to_string(),
+            client_ip: None,
+            scheme: None,
+            host: None,
             #[cfg(feature = "di")]
             dependencies: None,
         };
@@ -267,6 +282,9 @@ mod tests {
             cookies: Arc::new(HashMap::new()),
             method: "GET".to_string(),
             path: "/".to_string(),
+            client_ip: None,
+            scheme: None,
+            host: None,
 
             #[cfg(feature = "di")]
             dependencies: None,
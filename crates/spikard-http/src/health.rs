@@ -0,0 +1,431 @@
+//! Liveness and readiness endpoints.
+//!
+//! The liveness endpoint answers `200` whenever the process can serve requests.
+//! The readiness endpoint runs the registered [`HealthCheck`]s concurrently, each
+//! under its own timeout, and answers `200` with a JSON report when all pass or
+//! `503` otherwise. Once graceful shutdown begins readiness reports
+//! `shutting_down` without running checks, so load balancers stop routing traffic
+//! while in-flight requests drain.
+//!
+//! Both endpoints are mounted after the global middleware stack, so JWT/API key
+//! auth, rate limiting and request timeouts do not apply to them.
+
+use axum::Router as AxumRouter;
+use axum::body::Body;
+use axum::extract::{Request, State};
+use axum::http::{StatusCode, header};
+use axum::response::{IntoResponse, Response};
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+/// Timeout applied to checks registered without an explicit one
+pub const DEFAULT_HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Future returned by [`HealthCheck::check`]
+pub type HealthCheckFuture = Pin<Box<dyn Future<Output = Result<(), String>> + Send>>;
+
+/// Health endpoint configuration
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct HealthConfig {
+    /// Path of the liveness endpoint
+    pub liveness_path: String,
+    /// Path of the readiness endpoint
+    pub readiness_path: String,
+    /// Seconds to keep serving (reporting not ready) after a shutdown signal before
+    /// the listener stops accepting connections
+    pub drain_delay_secs: u64,
+}
+
+impl Default for HealthConfig {
+    fn default() -> Self {
+        Self {
+            liveness_path: "/healthz".to_string(),
+            readiness_path: "/readyz".to_string(),
+            drain_delay_secs: 0,
+        }
+    }
+}
+
+/// Values available to a health check
+#[derive(Clone, Default)]
+pub struct HealthCheckContext {
+    #[cfg(feature = "di")]
+    dependencies: Option<Arc<spikard_core::di::ResolvedDependencies>>,
+}
+
+impl HealthCheckContext {
+    /// Dependency resolved for this check, if it was declared and has type `T`
+    #[cfg(feature = "di")]
+    pub fn get<T: Send + Sync + 'static>(&self, key: &str) -> Option<Arc<T>> {
+        self.dependencies.as_ref()?.get::<T>(key)
+    }
+}
+
+impl std::fmt::Debug for HealthCheckContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HealthCheckContext").finish_non_exhaustive()
+    }
+}
+
+/// An async readiness check
+pub trait HealthCheck: Send + Sync {
+    /// Check name, used as its key in the readiness report
+    fn name(&self) -> &str;
+
+    /// Run the check; an `Err` marks the application as not ready
+    fn check(&self, context: HealthCheckContext) -> HealthCheckFuture;
+}
+
+struct HealthCheckFn<F> {
+    name: String,
+    func: F,
+}
+
+impl<F, Fut> HealthCheck for HealthCheckFn<F>
+where
+    F: Fn(HealthCheckContext) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<(), String>> + Send + 'static,
+{
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn check(&self, context: HealthCheckContext) -> HealthCheckFuture {
+        Box::pin((self.func)(context))
+    }
+}
+
+/// Create a health check from an async function or closure.
+pub fn health_check<F, Fut>(name: impl Into<String>, func: F) -> Arc<dyn HealthCheck>
+where
+    F: Fn(HealthCheckContext) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<(), String>> + Send + 'static,
+{
+    Arc::new(HealthCheckFn {
+        name: name.into(),
+        func,
+    })
+}
+
+/// Outcome of a single check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckStatus {
+    Ok,
+    Error,
+    Timeout,
+}
+
+/// Report entry for a single check
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CheckReport {
+    pub status: CheckStatus,
+    pub duration_ms: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Overall readiness
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReadinessStatus {
+    Ready,
+    NotReady,
+    ShuttingDown,
+}
+
+/// JSON body of the readiness endpoint
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ReadinessReport {
+    pub status: ReadinessStatus,
+    pub checks: BTreeMap<String, CheckReport>,
+}
+
+impl ReadinessReport {
+    /// HTTP status the readiness endpoint answers with
+    pub fn status_code(&self) -> StatusCode {
+        match self.status {
+            ReadinessStatus::Ready => StatusCode::OK,
+            ReadinessStatus::NotReady | ReadinessStatus::ShuttingDown => StatusCode::SERVICE_UNAVAILABLE,
+        }
+    }
+}
+
+#[derive(Clone)]
+struct TimedCheck {
+    check: Arc<dyn HealthCheck>,
+    timeout: Duration,
+    dependencies: Vec<String>,
+}
+
+/// Readiness checks of an application
+#[derive(Clone, Default)]
+pub struct HealthChecks {
+    checks: Vec<TimedCheck>,
+}
+
+impl HealthChecks {
+    /// Create an empty set of checks
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check if any checks are registered
+    pub fn is_empty(&self) -> bool {
+        self.checks.is_empty()
+    }
+
+    /// Register a check with the default timeout
+    pub fn add(&mut self, check: Arc<dyn HealthCheck>) {
+        self.add_with_timeout(check, DEFAULT_HEALTH_CHECK_TIMEOUT);
+    }
+
+    /// Register a check with an explicit timeout
+    pub fn add_with_timeout(&mut self, check: Arc<dyn HealthCheck>, timeout: Duration) {
+        self.add_with_dependencies(check, Vec::new(), timeout);
+    }
+
+    /// Register a check that receives the named dependencies in its context
+    ///
+    /// Dependencies are resolved from the server's `DependencyContainer` on every
+    /// probe; a resolution failure fails the check, as does running it without the
+    /// `di` feature.
+    pub fn add_with_dependencies(&mut self, check: Arc<dyn HealthCheck>, dependencies: Vec<String>, timeout: Duration) {
+        self.checks.push(TimedCheck {
+            check,
+            timeout,
+            dependencies,
+        });
+    }
+
+    /// Dependency keys declared by each check, labelled `HEALTH name`
+    pub fn dependencies(&self) -> impl Iterator<Item = (String, &[String])> {
+        self.checks
+            .iter()
+            .filter(|timed| !timed.dependencies.is_empty())
+            .map(|timed| (format!("HEALTH {}", timed.check.name()), timed.dependencies.as_slice()))
+    }
+
+    /// Run every check concurrently and collect the report
+    ///
+    /// Checks with dependencies fail here; they are resolved only when run by the
+    /// readiness endpoint, which has access to the server's container.
+    pub async fn run(&self) -> ReadinessReport {
+        self.run_with(&axum::http::Request::new(()), None).await
+    }
+
+    async fn run_with(&self, request: &axum::http::Request<()>, state: Option<&HealthState>) -> ReadinessReport {
+        let reports = futures::future::join_all(self.checks.iter().map(|timed| async move {
+            let started = Instant::now();
+            let outcome = tokio::time::timeout(timed.timeout, run_check(timed, request, state)).await;
+            let duration_ms = u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX);
+            let report = match outcome {
+                Ok(Ok(())) => CheckReport {
+                    status: CheckStatus::Ok,
+                    duration_ms,
+                    error: None,
+                },
+                Ok(Err(message)) => CheckReport {
+                    status: CheckStatus::Error,
+                    duration_ms,
+                    error: Some(message),
+                },
+                Err(_) => CheckReport {
+                    status: CheckStatus::Timeout,
+                    duration_ms,
+                    error: Some(format!("timed out after {:?}", timed.timeout)),
+                },
+            };
+            (timed.check.name().to_string(), report)
+        }))
+        .await;
+
+        let status = if reports.iter().all(|(_, report)| report.status == CheckStatus::Ok) {
+            ReadinessStatus::Ready
+        } else {
+            ReadinessStatus::NotReady
+        };
+        ReadinessReport {
+            status,
+            checks: reports.into_iter().collect(),
+        }
+    }
+}
+
+impl std::fmt::Debug for HealthChecks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let names: Vec<&str> = self.checks.iter().map(|timed| timed.check.name()).collect();
+        f.debug_struct("HealthChecks").field("checks", &names).finish()
+    }
+}
+
+#[cfg(feature = "di")]
+async fn run_check(
+    timed: &TimedCheck,
+    request: &axum::http::Request<()>,
+    state: Option<&HealthState>,
+) -> Result<(), String> {
+    if timed.dependencies.is_empty() {
+        return timed.check.check(HealthCheckContext::default()).await;
+    }
+    let container = state
+        .and_then(|state| state.di_container.as_ref())
+        .ok_or_else(|| "no dependency container configured".to_string())?;
+
+    let request_data = spikard_core::RequestData {
+        method: request.method().to_string(),
+        path: request.uri().path().to_string(),
+        ..Default::default()
+    };
+    let resolved = container
+        .resolve_for_handler(&timed.dependencies, request, &request_data)
+        .await
+        .map_err(|err| err.to_string())?;
+
+    let resolved = Arc::new(resolved);
+    let result = timed
+        .check
+        .check(HealthCheckContext {
+            dependencies: Some(Arc::clone(&resolved)),
+        })
+        .await;
+    if let Ok(resolved) = Arc::try_unwrap(resolved) {
+        resolved.cleanup().await;
+    }
+    result
+}
+
+#[cfg(not(feature = "di"))]
+async fn run_check(
+    timed: &TimedCheck,
+    _request: &axum::http::Request<()>,
+    _state: Option<&HealthState>,
+) -> Result<(), String> {
+    if !timed.dependencies.is_empty() {
+        return Err("dependency injection is not enabled".to_string());
+    }
+    timed.check.check(HealthCheckContext::default()).await
+}
+
+/// Marks the server as draining once graceful shutdown begins
+///
+/// Clones share the same flag. [`Server::run_with_config`](crate::Server::run_with_config)
+/// installs one on the router and trips it when the shutdown signal arrives.
+#[derive(Debug, Clone, Default)]
+pub struct DrainSignal {
+    draining: Arc<AtomicBool>,
+}
+
+impl DrainSignal {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start reporting not ready
+    pub fn begin(&self) {
+        self.draining.store(true, Ordering::SeqCst);
+    }
+
+    /// Whether shutdown has begun
+    pub fn is_draining(&self) -> bool {
+        self.draining.load(Ordering::SeqCst)
+    }
+
+    /// Make this signal visible to the readiness endpoint of `router`
+    pub fn attach(&self, router: AxumRouter) -> AxumRouter {
+        router.layer(axum::Extension(self.clone()))
+    }
+}
+
+#[derive(Clone)]
+struct HealthState {
+    checks: Option<Arc<HealthChecks>>,
+    #[cfg(feature = "di")]
+    di_container: Option<Arc<spikard_core::di::DependencyContainer>>,
+}
+
+fn json_response(status: StatusCode, body: &impl serde::Serialize) -> Response {
+    let body = serde_json::to_vec(body).unwrap_or_default();
+    (status, [(header::CONTENT_TYPE, "application/json")], Body::from(body)).into_response()
+}
+
+async fn liveness() -> Response {
+    json_response(StatusCode::OK, &serde_json::json!({"status": "ok"}))
+}
+
+async fn readiness(State(state): State<HealthState>, request: Request) -> Response {
+    let draining = request
+        .extensions()
+        .get::<DrainSignal>()
+        .is_some_and(DrainSignal::is_draining);
+    let (parts, _body) = request.into_parts();
+    let request = axum::http::Request::from_parts(parts, ());
+    let report = if draining {
+        ReadinessReport {
+            status: ReadinessStatus::ShuttingDown,
+            checks: BTreeMap::new(),
+        }
+    } else if let Some(ref checks) = state.checks {
+        checks.run_with(&request, Some(&state)).await
+    } else {
+        ReadinessReport {
+            status: ReadinessStatus::Ready,
+            checks: BTreeMap::new(),
+        }
+    };
+    json_response(report.status_code(), &report)
+}
+
+/// Add the liveness and readiness endpoints to `router`
+pub(crate) fn health_routes(router: AxumRouter, config: &crate::ServerConfig, health: &HealthConfig) -> AxumRouter {
+    let state = HealthState {
+        checks: config.health_checks.clone(),
+        #[cfg(feature = "di")]
+        di_container: config.di_container.clone(),
+    };
+    router
+        .route(&health.liveness_path, axum::routing::get(liveness))
+        .route(&health.readiness_path, axum::routing::get(readiness).with_state(state))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn report_collects_every_check() {
+        let mut checks = HealthChecks::new();
+        checks.add(health_check("db", |_ctx| async { Ok(()) }));
+        checks.add(health_check("cache", |_ctx| async {
+            Err("connection refused".to_string())
+        }));
+        checks.add_with_timeout(
+            health_check("slow", |_ctx| async {
+                tokio::time::sleep(Duration::from_secs(5)).await;
+                Ok(())
+            }),
+            Duration::from_millis(10),
+        );
+
+        let report = checks.run().await;
+
+        assert_eq!(report.status, ReadinessStatus::NotReady);
+        assert_eq!(report.status_code(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(report.checks["db"].status, CheckStatus::Ok);
+        assert_eq!(report.checks["cache"].status, CheckStatus::Error);
+        assert_eq!(report.checks["cache"].error.as_deref(), Some("connection refused"));
+        assert_eq!(report.checks["slow"].status, CheckStatus::Timeout);
+    }
+
+    #[tokio::test]
+    async fn no_checks_means_ready() {
+        let report = HealthChecks::new().run().await;
+        assert_eq!(report.status, ReadinessStatus::Ready);
+        assert!(report.checks.is_empty());
+    }
+}
@@ -184,6 +184,9 @@ fn create_jsonrpc_request_data(headers: &HeaderMap, uri: &axum::http::Uri) -> Re
         cookies: Arc::new(HashMap::new()),
         method: "POST".to_string(),
         path: uri.path().to_string(),
+        client_ip: None,
+        scheme: None,
+        host: None,
         #[cfg(feature = "di")]
         dependencies: None,
     }
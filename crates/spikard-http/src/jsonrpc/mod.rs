@@ -16,6 +16,7 @@ pub(crate) use http_handler::{JsonRpcState, handle_jsonrpc};
 pub(crate) use method_registry::{JsonRpcMethodRegistry, MethodMetadata};
 #[cfg(not(target_arch = "wasm32"))]
 pub(crate) use openrpc::generate_openrpc_spec;
+pub use protocol::{JsonRpcError, error_codes};
 #[cfg(not(target_arch = "wasm32"))]
 pub(crate) use router::JsonRpcRouter;
 
@@ -57,6 +57,9 @@ pub struct JsonRpcRouter {
     enable_batch: bool,
     /// Maximum number of requests allowed in a single batch
     max_batch_size: usize,
+    /// Registry receiving per-method call counts and latencies
+    #[cfg(not(target_arch = "wasm32"))]
+    metrics: Option<Arc<crate::metrics::MetricsRegistry>>,
 }
 
 impl Default for JsonRpcRouter {
@@ -88,9 +91,19 @@ impl JsonRpcRouter {
             registry,
             enable_batch,
             max_batch_size,
+            #[cfg(not(target_arch = "wasm32"))]
+            metrics: None,
         }
     }
 
+    /// Record per-method call counts and latencies into `metrics`
+    #[cfg(not(target_arch = "wasm32"))]
+    #[must_use]
+    pub fn with_metrics(mut self, metrics: Option<Arc<crate::metrics::MetricsRegistry>>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
     /// Routes a single JSON-RPC request to its handler
     ///
     /// Processes a single request by:
@@ -151,9 +164,34 @@ impl JsonRpcRouter {
 
         let _is_notification = request.is_notification();
 
+        #[cfg(not(target_arch = "wasm32"))]
+        let started = std::time::Instant::now();
         let handler_result = handler.call(http_request, request_data.clone()).await;
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(metrics) = &self.metrics {
+            metrics.record_jsonrpc(&request.method, handler_result.is_ok(), started.elapsed());
+        }
 
         match handler_result {
+            #[cfg(feature = "di")]
+            Ok(response)
+                if response
+                    .extensions()
+                    .get::<crate::di_handler::DependencyResolutionFailed>()
+                    .is_some() =>
+            {
+                let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+                    .await
+                    .unwrap_or_default();
+                let error_data = serde_json::from_slice::<Value>(&body_bytes).unwrap_or(Value::Null);
+                let id = request.id.unwrap_or(Value::Null);
+                JsonRpcResponseType::Error(JsonRpcErrorResponse::error_with_data(
+                    error_codes::INTERNAL_ERROR,
+                    "Dependency resolution failed",
+                    error_data,
+                    id,
+                ))
+            }
             Ok(response) => {
                 let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
                     .await
@@ -375,6 +413,9 @@ mod tests {
             cookies: Arc::new(HashMap::new()),
             method: "POST".to_string(),
             path: "/rpc".to_string(),
+            client_ip: None,
+            scheme: None,
+            host: None,
             #[cfg(feature = "di")]
             dependencies: None,
         }
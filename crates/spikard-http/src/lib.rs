@@ -11,24 +11,46 @@ pub mod auth;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod background;
 pub mod bindings;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod conditional;
 pub mod cors;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod csrf;
 #[cfg(feature = "di")]
 pub mod di_handler;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod grpc;
 pub mod handler_response;
 pub mod handler_trait;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod health;
 pub mod jsonrpc;
 pub mod lifecycle;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod lifespan;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod metrics;
 pub(crate) mod middleware;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod negotiation;
 pub mod openapi;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod proxy;
 pub(crate) mod query_parser;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod rate_limit;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod recording;
 pub mod response;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod server;
 #[cfg(not(target_arch = "wasm32"))]
+pub mod session;
+#[cfg(not(target_arch = "wasm32"))]
 pub mod sse;
 #[cfg(not(target_arch = "wasm32"))]
+pub mod telemetry;
+#[cfg(not(target_arch = "wasm32"))]
 pub mod testing;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod websocket;
@@ -44,30 +66,72 @@ pub use asyncapi::{
     AsyncApiConfig, ParseResult, ParsedChannel, ParsedMessage, ParsedOperation, ValidateRequest, ValidationResponse,
     parse_asyncapi_value, validate_message,
 };
-pub use auth::{Claims, api_key_auth_middleware, jwt_auth_middleware};
+pub use auth::{ApiKeyIdentity, Claims, api_key_auth_middleware, jwt_auth_middleware};
 #[cfg(not(target_arch = "wasm32"))]
-pub use background::{BackgroundHandle, BackgroundJobError, BackgroundJobMetadata, BackgroundTaskConfig};
+pub use background::{
+    BackgroundHandle, BackgroundJobError, BackgroundJobMetadata, BackgroundMetricsSnapshot, BackgroundTaskConfig,
+};
+#[cfg(not(target_arch = "wasm32"))]
+pub use csrf::{CsrfConfig, CsrfMode, CsrfToken};
+#[cfg(all(feature = "di", not(target_arch = "wasm32")))]
+pub use di_handler::DeclaredDependencies;
 #[cfg(feature = "di")]
 pub use di_handler::DependencyInjectingHandler;
 #[cfg(not(target_arch = "wasm32"))]
 pub use grpc::GrpcConfig;
 pub use handler_response::HandlerResponse;
 pub use handler_trait::{Handler, HandlerResult, RequestData, StaticResponse, StaticResponseHandler, ValidatedParams};
+#[cfg(not(target_arch = "wasm32"))]
+pub use health::{
+    CheckReport, CheckStatus, DrainSignal, HealthCheck, HealthCheckContext, HealthChecks, HealthConfig,
+    ReadinessReport, ReadinessStatus, health_check,
+};
 pub use jsonrpc::JsonRpcConfig;
-pub use lifecycle::{HookResult, LifecycleHook, LifecycleHooks, LifecycleHooksBuilder, request_hook, response_hook};
+pub use lifecycle::{
+    HookResult, LifecycleHook, LifecycleHooks, LifecycleHooksBuilder, RouteHooksHandler, request_hook, response_hook,
+};
+#[cfg(not(target_arch = "wasm32"))]
+pub use lifespan::{LifespanContext, LifespanError, LifespanHook, LifespanHooks, LifespanPhase, lifespan_hook};
+#[cfg(not(target_arch = "wasm32"))]
+pub use metrics::{Counter, Gauge, Histogram, MetricsConfig, MetricsError, MetricsRegistry};
+pub use middleware::validation::{
+    BodyCodec, BodyCodecRegistry, CborCodec, MessagePackCodec, NdjsonCodec, XmlCodec, XmlMapping,
+};
+#[cfg(not(target_arch = "wasm32"))]
+pub use negotiation::{CsvEncoder, ResponseEncoder, ResponseEncoderRegistry, YamlEncoder};
 pub use openapi::{ContactInfo, LicenseInfo, OpenApiConfig, SecuritySchemeInfo, ServerInfo};
+#[cfg(not(target_arch = "wasm32"))]
+pub use proxy::{ClientInfo, TrustedProxyConfig};
+#[cfg(not(target_arch = "wasm32"))]
+pub use rate_limit::{
+    InMemoryRateLimitStore, RateLimitDecision, RateLimitKeyExtractor, RateLimitKeyRequest, RateLimitQuota,
+    RateLimitStore,
+};
+#[cfg(not(target_arch = "wasm32"))]
+pub use recording::RecordingConfig;
 pub use response::Response;
 #[cfg(not(target_arch = "wasm32"))]
 pub use server::Server;
+#[cfg(not(target_arch = "wasm32"))]
+pub use server::response_cache::{
+    CachedResponse, InMemoryResponseCacheStore, ResponseCache, ResponseCacheFuture, ResponseCacheStore,
+};
+#[cfg(not(target_arch = "wasm32"))]
+pub use session::{InMemorySessionStore, Session, SessionConfig, SessionData, SessionStorage, SessionStore};
 pub use spikard_core::errors::StructuredError;
 pub use spikard_core::parameters::ParameterSource;
 pub use spikard_core::router::JsonRpcMethodInfo;
 pub use spikard_core::{
-    CompressionConfig, CorsConfig, Method, ParameterValidator, ProblemDetails, RateLimitConfig, Route, RouteMetadata,
+    ApiKeyConfig, CachingConfig, CompressionConfig, CorsConfig, ETagMode, JwtConfig, Method, ParameterValidator,
+    ProblemDetails, RateLimitConfig, RateLimitKey, ResponseCacheConfig, Route, RouteAuthConfig, RouteMetadata,
     SchemaRegistry, SchemaValidator, ValidationError, ValidationErrorDetail,
 };
 #[cfg(not(target_arch = "wasm32"))]
 pub use sse::{SseEvent, SseEventProducer, SseState, sse_handler};
+#[cfg(all(feature = "otel", not(target_arch = "wasm32")))]
+pub use telemetry::TelemetryGuard;
+#[cfg(not(target_arch = "wasm32"))]
+pub use telemetry::{TelemetryConfig, TelemetryError};
 #[cfg(not(target_arch = "wasm32"))]
 pub use testing::{ResponseSnapshot, SnapshotError, snapshot_response};
 #[cfg(not(target_arch = "wasm32"))]
@@ -76,41 +140,6 @@ pub use websocket::{WebSocketHandler, WebSocketState, websocket_handler};
 /// Reexport from spikard_core for convenience
 pub use spikard_core::problem::CONTENT_TYPE_PROBLEM_JSON;
 
-/// JWT authentication configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct JwtConfig {
-    /// Secret key for JWT verification
-    pub secret: String,
-    /// Required algorithm (HS256, HS384, HS512, RS256, etc.)
-    #[serde(default = "default_jwt_algorithm")]
-    pub algorithm: String,
-    /// Required audience claim
-    pub audience: Option<Vec<String>>,
-    /// Required issuer claim
-    pub issuer: Option<String>,
-    /// Leeway for expiration checks (seconds)
-    #[serde(default)]
-    pub leeway: u64,
-}
-
-fn default_jwt_algorithm() -> String {
-    "HS256".to_string()
-}
-
-/// API Key authentication configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ApiKeyConfig {
-    /// Valid API keys
-    pub keys: Vec<String>,
-    /// Header name to check (e.g., "X-API-Key")
-    #[serde(default = "default_api_key_header")]
-    pub header_name: String,
-}
-
-fn default_api_key_header() -> String {
-    "X-API-Key".to_string()
-}
-
 /// Static file serving configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StaticFilesConfig {
@@ -146,6 +175,45 @@ pub struct ServerConfig {
     pub compression: Option<CompressionConfig>,
     /// Enable rate limiting
     pub rate_limit: Option<RateLimitConfig>,
+    /// Backend for rate limit buckets, shared across instances (None = in-memory)
+    #[cfg(not(target_arch = "wasm32"))]
+    #[serde(skip)]
+    #[cfg_attr(alef, alef(skip))]
+    pub rate_limit_store: Option<std::sync::Arc<dyn RateLimitStore>>,
+    /// Key extractor for rate limits keyed with `RateLimitKey::Custom`
+    #[cfg(not(target_arch = "wasm32"))]
+    #[serde(skip)]
+    #[cfg_attr(alef, alef(skip))]
+    pub rate_limit_key_extractor: Option<std::sync::Arc<dyn RateLimitKeyExtractor>>,
+    /// Reverse proxies whose forwarding headers determine the client IP, scheme and host
+    #[cfg(not(target_arch = "wasm32"))]
+    pub trusted_proxies: Option<TrustedProxyConfig>,
+    /// Cookie sessions
+    #[cfg(not(target_arch = "wasm32"))]
+    pub session: Option<SessionConfig>,
+    /// Server-side session backend, shared across instances (None = in-memory)
+    #[cfg(not(target_arch = "wasm32"))]
+    #[serde(skip)]
+    #[cfg_attr(alef, alef(skip))]
+    pub session_store: Option<std::sync::Arc<dyn SessionStore>>,
+    /// CSRF protection for unsafe requests
+    #[cfg(not(target_arch = "wasm32"))]
+    pub csrf: Option<CsrfConfig>,
+    /// Backend for cached handler responses, shared across instances (None = in-memory LRU)
+    #[cfg(not(target_arch = "wasm32"))]
+    #[serde(skip)]
+    #[cfg_attr(alef, alef(skip))]
+    pub response_cache_store: Option<std::sync::Arc<dyn ResponseCacheStore>>,
+    /// Codecs for request bodies that are not JSON, form or multipart (None = none accepted)
+    #[cfg(not(target_arch = "wasm32"))]
+    #[serde(skip)]
+    #[cfg_attr(alef, alef(skip))]
+    pub body_codecs: Option<std::sync::Arc<BodyCodecRegistry>>,
+    /// Encoders for JSON responses negotiated through `Accept` (None = JSON only, no negotiation)
+    #[cfg(not(target_arch = "wasm32"))]
+    #[serde(skip)]
+    #[cfg_attr(alef, alef(skip))]
+    pub response_encoders: Option<std::sync::Arc<ResponseEncoderRegistry>>,
     /// JWT authentication configuration
     pub jwt_auth: Option<JwtConfig>,
     /// API Key authentication configuration
@@ -169,17 +237,56 @@ pub struct ServerConfig {
     #[serde(skip)]
     #[cfg_attr(alef, alef(skip))]
     pub lifecycle_hooks: Option<std::sync::Arc<LifecycleHooks>>,
+    /// Startup and shutdown hooks run around the server's lifetime
+    #[cfg(not(target_arch = "wasm32"))]
+    #[serde(skip)]
+    #[cfg_attr(alef, alef(skip))]
+    pub lifespan: Option<std::sync::Arc<LifespanHooks>>,
     /// Background task executor configuration
     #[cfg(not(target_arch = "wasm32"))]
     pub background_tasks: BackgroundTaskConfig,
     /// Enable per-request HTTP tracing (tower-http `TraceLayer`)
     #[cfg(not(target_arch = "wasm32"))]
     pub enable_http_trace: bool,
+    /// Prometheus metrics endpoint configuration (None = disabled)
+    #[cfg(not(target_arch = "wasm32"))]
+    pub metrics: Option<MetricsConfig>,
+    /// Registry shared with binding code for custom metrics (None = created on demand)
+    #[cfg(not(target_arch = "wasm32"))]
+    #[serde(skip)]
+    #[cfg_attr(alef, alef(skip))]
+    pub metrics_registry: Option<std::sync::Arc<MetricsRegistry>>,
+    /// Liveness and readiness endpoints (None = disabled)
+    #[cfg(not(target_arch = "wasm32"))]
+    pub health: Option<HealthConfig>,
+    /// Checks run by the readiness endpoint
+    #[cfg(not(target_arch = "wasm32"))]
+    #[serde(skip)]
+    #[cfg_attr(alef, alef(skip))]
+    pub health_checks: Option<std::sync::Arc<HealthChecks>>,
+    /// Per-request tracing spans and trace context propagation (None = disabled)
+    #[cfg(not(target_arch = "wasm32"))]
+    pub telemetry: Option<TelemetryConfig>,
+    /// Record request/response pairs as e2e fixtures (None = disabled)
+    #[cfg(not(target_arch = "wasm32"))]
+    pub recording: Option<RecordingConfig>,
     /// Dependency injection container (requires 'di' feature)
     #[cfg(feature = "di")]
     #[serde(skip)]
     #[cfg_attr(alef, alef(skip))]
     pub di_container: Option<std::sync::Arc<spikard_core::di::DependencyContainer>>,
+    /// Dependencies declared by WebSocket and SSE endpoints, as `(label, keys)` (requires 'di' feature)
+    ///
+    /// Checked together with route dependencies when the router is built.
+    #[cfg(feature = "di")]
+    #[serde(skip)]
+    #[cfg_attr(alef, alef(skip))]
+    pub endpoint_dependencies: Vec<(String, Vec<String>)>,
+    /// Log unused dependency registrations at debug level instead of warning (requires 'di' feature)
+    ///
+    /// Unknown keys, scope violations and cycles fail router construction either way.
+    #[cfg(feature = "di")]
+    pub lenient_dependencies: bool,
 }
 
 impl Default for ServerConfig {
@@ -193,6 +300,24 @@ impl Default for ServerConfig {
             request_timeout: None,
             compression: None,
             rate_limit: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            rate_limit_store: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            rate_limit_key_extractor: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            trusted_proxies: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            session: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            session_store: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            csrf: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            response_cache_store: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            body_codecs: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            response_encoders: None,
             jwt_auth: None,
             api_key_auth: None,
             static_files: Vec::new(),
@@ -205,11 +330,29 @@ impl Default for ServerConfig {
             grpc: None,
             lifecycle_hooks: None,
             #[cfg(not(target_arch = "wasm32"))]
+            lifespan: None,
+            #[cfg(not(target_arch = "wasm32"))]
             background_tasks: BackgroundTaskConfig::default(),
             #[cfg(not(target_arch = "wasm32"))]
             enable_http_trace: false,
+            #[cfg(not(target_arch = "wasm32"))]
+            metrics: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            metrics_registry: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            health: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            health_checks: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            telemetry: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            recording: None,
             #[cfg(feature = "di")]
             di_container: None,
+            #[cfg(feature = "di")]
+            endpoint_dependencies: Vec::new(),
+            #[cfg(feature = "di")]
+            lenient_dependencies: false,
         }
     }
 }
@@ -304,6 +447,48 @@ impl ServerConfigBuilder {
         self
     }
 
+    /// Enable the Prometheus metrics endpoint
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn metrics(mut self, metrics: MetricsConfig) -> Self {
+        self.config.metrics = Some(metrics);
+        self
+    }
+
+    /// Set the registry the server records into, shared with custom metrics
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn metrics_registry(mut self, registry: Option<std::sync::Arc<MetricsRegistry>>) -> Self {
+        self.config.metrics_registry = registry;
+        self
+    }
+
+    /// Enable the liveness and readiness endpoints
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn health(mut self, health: HealthConfig) -> Self {
+        self.config.health = Some(health);
+        self
+    }
+
+    /// Set the checks run by the readiness endpoint
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn health_checks(mut self, checks: Option<std::sync::Arc<HealthChecks>>) -> Self {
+        self.config.health_checks = checks;
+        self
+    }
+
+    /// Enable per-request tracing spans and trace context propagation
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn telemetry(mut self, telemetry: TelemetryConfig) -> Self {
+        self.config.telemetry = Some(telemetry);
+        self
+    }
+
+    /// Record request/response pairs as e2e fixtures
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn recording(mut self, recording: RecordingConfig) -> Self {
+        self.config.recording = Some(recording);
+        self
+    }
+
     /// Set maximum request body size in bytes (None = unlimited, not recommended)
     pub fn max_body_size(mut self, size: Option<usize>) -> Self {
         self.config.max_body_size = size;
@@ -328,6 +513,69 @@ impl ServerConfigBuilder {
         self
     }
 
+    /// Set the store backing rate limit buckets (defaults to an in-memory store)
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn rate_limit_store(mut self, store: Option<std::sync::Arc<dyn RateLimitStore>>) -> Self {
+        self.config.rate_limit_store = store;
+        self
+    }
+
+    /// Set the key extractor used by rate limits keyed with `RateLimitKey::Custom`
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn rate_limit_key_extractor(mut self, extractor: Option<std::sync::Arc<dyn RateLimitKeyExtractor>>) -> Self {
+        self.config.rate_limit_key_extractor = extractor;
+        self
+    }
+
+    /// Trust forwarding headers from the given reverse proxies
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn trusted_proxies(mut self, trusted_proxies: Option<TrustedProxyConfig>) -> Self {
+        self.config.trusted_proxies = trusted_proxies;
+        self
+    }
+
+    /// Enable cookie sessions
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn session(mut self, session: Option<SessionConfig>) -> Self {
+        self.config.session = session;
+        self
+    }
+
+    /// Set the server-side session backend (defaults to in-memory)
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn session_store(mut self, store: Option<std::sync::Arc<dyn SessionStore>>) -> Self {
+        self.config.session_store = store;
+        self
+    }
+
+    /// Enable CSRF protection
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn csrf(mut self, csrf: Option<CsrfConfig>) -> Self {
+        self.config.csrf = csrf;
+        self
+    }
+
+    /// Set the backend for cached handler responses (defaults to an in-memory LRU)
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn response_cache_store(mut self, store: Option<std::sync::Arc<dyn ResponseCacheStore>>) -> Self {
+        self.config.response_cache_store = store;
+        self
+    }
+
+    /// Accept request bodies in the media types of `codecs`
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn body_codecs(mut self, codecs: Option<std::sync::Arc<BodyCodecRegistry>>) -> Self {
+        self.config.body_codecs = codecs;
+        self
+    }
+
+    /// Negotiate response media types among JSON and `encoders`
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn response_encoders(mut self, encoders: Option<std::sync::Arc<ResponseEncoderRegistry>>) -> Self {
+        self.config.response_encoders = encoders;
+        self
+    }
+
     /// Set JWT authentication configuration
     pub fn jwt_auth(mut self, jwt_auth: Option<JwtConfig>) -> Self {
         self.config.jwt_auth = jwt_auth;
@@ -405,6 +653,13 @@ impl ServerConfigBuilder {
         self
     }
 
+    /// Set startup and shutdown hooks
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn lifespan(mut self, hooks: Option<std::sync::Arc<LifespanHooks>>) -> Self {
+        self.config.lifespan = hooks;
+        self
+    }
+
     /// Set background task executor configuration
     #[cfg(not(target_arch = "wasm32"))]
     pub fn background_tasks(mut self, config: BackgroundTaskConfig) -> Self {
@@ -412,6 +667,16 @@ impl ServerConfigBuilder {
         self
     }
 
+    /// Log unused dependency registrations at debug level instead of warning
+    ///
+    /// Unknown keys, singletons depending on per-request dependencies and cycles
+    /// still fail router construction.
+    #[cfg(feature = "di")]
+    pub fn lenient_dependencies(mut self, lenient: bool) -> Self {
+        self.config.lenient_dependencies = lenient;
+        self
+    }
+
     /// Register a value dependency (like Fastify decorate)
     ///
     /// Value dependencies are static values that are cloned when injected into handlers.
@@ -2,8 +2,12 @@ use axum::{
     body::Body,
     http::{Request, Response},
 };
+use std::future::Future;
+use std::pin::Pin;
 use std::sync::Arc;
 
+use crate::handler_trait::{Handler, HandlerResult, RequestData};
+
 pub mod adapter;
 
 pub use spikard_core::lifecycle::{HookResult, LifecycleHook};
@@ -51,6 +55,67 @@ where
     spikard_core::lifecycle::response_hook::<Request<Body>, Response<Body>, _, _>(name, func)
 }
 
+/// Handler wrapper that scopes lifecycle hooks to a single route
+///
+/// The server runs the wrapped hooks after the global hooks of each phase, and only
+/// for requests to the route the handler is registered for. Route and route group
+/// hooks reach the server through this wrapper.
+pub struct RouteHooksHandler {
+    inner: Arc<dyn Handler>,
+    hooks: Arc<LifecycleHooks>,
+}
+
+impl RouteHooksHandler {
+    /// Wrap `handler` so requests to its route also run `hooks`
+    ///
+    /// Hooks the handler already scopes to its route run before `hooks`.
+    pub fn new(handler: Arc<dyn Handler>, hooks: Arc<LifecycleHooks>) -> Self {
+        let hooks = match handler.lifecycle_hooks() {
+            Some(existing) => {
+                let mut merged = (*existing).clone();
+                merged.extend(&hooks);
+                Arc::new(merged)
+            }
+            None => hooks,
+        };
+        Self { inner: handler, hooks }
+    }
+}
+
+impl Handler for RouteHooksHandler {
+    fn call(
+        &self,
+        request: Request<Body>,
+        request_data: RequestData,
+    ) -> Pin<Box<dyn Future<Output = HandlerResult> + Send + '_>> {
+        self.inner.call(request, request_data)
+    }
+
+    fn prefers_raw_json_body(&self) -> bool {
+        self.inner.prefers_raw_json_body()
+    }
+
+    fn prefers_parameter_extraction(&self) -> bool {
+        self.inner.prefers_parameter_extraction()
+    }
+
+    fn wants_headers(&self) -> bool {
+        self.inner.wants_headers()
+    }
+
+    fn wants_cookies(&self) -> bool {
+        self.inner.wants_cookies()
+    }
+
+    fn wants_request_extensions(&self) -> bool {
+        self.inner.wants_request_extensions()
+    }
+
+    fn lifecycle_hooks(&self) -> Option<Arc<LifecycleHooks>> {
+        Some(Arc::clone(&self.hooks))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
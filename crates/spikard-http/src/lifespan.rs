@@ -0,0 +1,388 @@
+//! Application lifespan: startup and shutdown hooks.
+//!
+//! Startup hooks run in registration order before the listener binds; a failing or
+//! timed-out startup hook aborts boot. Shutdown hooks run in reverse registration
+//! order after the server stops (after graceful shutdown when enabled), so resources
+//! are released in the opposite order they were acquired. Every hook runs under its
+//! own timeout.
+//!
+//! Hooks share a [`LifespanContext`]. Values a startup hook provides through it are
+//! visible to later hooks (including shutdown hooks, to close what startup opened)
+//! and, with the `di` feature, are registered as singleton values in the
+//! `DependencyContainer` by [`crate::server::Server::startup`].
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Timeout applied to hooks registered without an explicit one
+pub const DEFAULT_LIFESPAN_HOOK_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Future returned by [`LifespanHook::run`]
+pub type LifespanFuture = Pin<Box<dyn Future<Output = Result<(), String>> + Send>>;
+
+/// Phase of the application lifespan a hook runs in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LifespanPhase {
+    Startup,
+    Shutdown,
+}
+
+impl std::fmt::Display for LifespanPhase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Startup => f.write_str("startup"),
+            Self::Shutdown => f.write_str("shutdown"),
+        }
+    }
+}
+
+/// An async hook run once at application startup or shutdown
+pub trait LifespanHook: Send + Sync {
+    /// Hook name for logging and error messages
+    fn name(&self) -> &str;
+
+    /// Run the hook; an `Err` aborts startup (or is reported during shutdown)
+    fn run(&self, context: LifespanContext) -> LifespanFuture;
+}
+
+struct LifespanHookFn<F> {
+    name: String,
+    func: F,
+}
+
+impl<F, Fut> LifespanHook for LifespanHookFn<F>
+where
+    F: Fn(LifespanContext) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<(), String>> + Send + 'static,
+{
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn run(&self, context: LifespanContext) -> LifespanFuture {
+        Box::pin((self.func)(context))
+    }
+}
+
+/// Create a lifespan hook from an async function or closure.
+pub fn lifespan_hook<F, Fut>(name: impl Into<String>, func: F) -> Arc<dyn LifespanHook>
+where
+    F: Fn(LifespanContext) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<(), String>> + Send + 'static,
+{
+    Arc::new(LifespanHookFn {
+        name: name.into(),
+        func,
+    })
+}
+
+#[derive(Default)]
+struct LifespanState {
+    values: HashMap<String, Arc<dyn Any + Send + Sync>>,
+    #[cfg(feature = "di")]
+    pending: Vec<(String, Arc<dyn spikard_core::di::Dependency>)>,
+}
+
+/// State shared by the lifespan hooks of one application
+///
+/// Cloning is cheap; clones share the same values.
+#[derive(Clone, Default)]
+pub struct LifespanContext {
+    state: Arc<Mutex<LifespanState>>,
+}
+
+impl LifespanContext {
+    fn lock(&self) -> std::sync::MutexGuard<'_, LifespanState> {
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Make `value` available under `key` to later hooks and, with the `di` feature,
+    /// as a singleton dependency of handlers.
+    pub fn provide<T: Clone + Send + Sync + 'static>(&self, key: impl Into<String>, value: T) {
+        let key = key.into();
+        let mut state = self.lock();
+        #[cfg(feature = "di")]
+        state.pending.push((
+            key.clone(),
+            Arc::new(spikard_core::di::ValueDependency::new(key.clone(), value.clone())),
+        ));
+        state.values.insert(key, Arc::new(value));
+    }
+
+    /// Value provided under `key`, if it exists and has type `T`
+    pub fn get<T: Send + Sync + 'static>(&self, key: &str) -> Option<Arc<T>> {
+        let value = self.lock().values.get(key).cloned()?;
+        value.downcast::<T>().ok()
+    }
+
+    /// Whether a value was provided under `key`
+    pub fn contains(&self, key: &str) -> bool {
+        self.lock().values.contains_key(key)
+    }
+
+    /// Dependencies provided since the last call, in provision order
+    #[cfg(feature = "di")]
+    pub(crate) fn take_dependencies(&self) -> Vec<(String, Arc<dyn spikard_core::di::Dependency>)> {
+        std::mem::take(&mut self.lock().pending)
+    }
+}
+
+impl std::fmt::Debug for LifespanContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut keys: Vec<String> = self.lock().values.keys().cloned().collect();
+        keys.sort();
+        f.debug_struct("LifespanContext").field("keys", &keys).finish()
+    }
+}
+
+/// Error raised when a lifespan hook fails or exceeds its timeout
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LifespanError {
+    /// The hook returned an error
+    Failed {
+        phase: LifespanPhase,
+        hook: String,
+        message: String,
+    },
+    /// The hook did not complete within its timeout
+    TimedOut {
+        phase: LifespanPhase,
+        hook: String,
+        timeout: Duration,
+    },
+    /// Startup values could not be registered with the dependency container
+    Dependencies(String),
+}
+
+impl std::fmt::Display for LifespanError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Failed { phase, hook, message } => write!(f, "{} hook '{}' failed: {}", phase, hook, message),
+            Self::TimedOut { phase, hook, timeout } => {
+                write!(f, "{} hook '{}' timed out after {:?}", phase, hook, timeout)
+            }
+            Self::Dependencies(message) => write!(f, "failed to register startup dependencies: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for LifespanError {}
+
+#[derive(Clone)]
+struct TimedHook {
+    hook: Arc<dyn LifespanHook>,
+    timeout: Duration,
+}
+
+impl TimedHook {
+    async fn run(&self, phase: LifespanPhase, context: &LifespanContext) -> Result<(), LifespanError> {
+        let name = self.hook.name().to_string();
+        tracing::debug!("Running {} hook '{}'", phase, name);
+        match tokio::time::timeout(self.timeout, self.hook.run(context.clone())).await {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err(message)) => Err(LifespanError::Failed {
+                phase,
+                hook: name,
+                message,
+            }),
+            Err(_) => Err(LifespanError::TimedOut {
+                phase,
+                hook: name,
+                timeout: self.timeout,
+            }),
+        }
+    }
+}
+
+/// Ordered startup and shutdown hooks of an application
+#[derive(Default)]
+pub struct LifespanHooks {
+    startup: Vec<TimedHook>,
+    shutdown: Vec<TimedHook>,
+    context: LifespanContext,
+    started: AtomicBool,
+}
+
+impl LifespanHooks {
+    /// Create an empty set of lifespan hooks
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check if any hooks are registered
+    pub fn is_empty(&self) -> bool {
+        self.startup.is_empty() && self.shutdown.is_empty()
+    }
+
+    /// Register a startup hook with the default timeout
+    pub fn add_startup(&mut self, hook: Arc<dyn LifespanHook>) {
+        self.add_startup_with_timeout(hook, DEFAULT_LIFESPAN_HOOK_TIMEOUT);
+    }
+
+    /// Register a startup hook with an explicit timeout
+    pub fn add_startup_with_timeout(&mut self, hook: Arc<dyn LifespanHook>, timeout: Duration) {
+        self.startup.push(TimedHook { hook, timeout });
+    }
+
+    /// Register a shutdown hook with the default timeout
+    pub fn add_shutdown(&mut self, hook: Arc<dyn LifespanHook>) {
+        self.add_shutdown_with_timeout(hook, DEFAULT_LIFESPAN_HOOK_TIMEOUT);
+    }
+
+    /// Register a shutdown hook with an explicit timeout
+    pub fn add_shutdown_with_timeout(&mut self, hook: Arc<dyn LifespanHook>, timeout: Duration) {
+        self.shutdown.push(TimedHook { hook, timeout });
+    }
+
+    /// Context shared by all hooks
+    pub fn context(&self) -> &LifespanContext {
+        &self.context
+    }
+
+    /// Whether the startup hooks have already run
+    pub fn has_started(&self) -> bool {
+        self.started.load(Ordering::SeqCst)
+    }
+
+    /// Run the startup hooks in registration order, stopping at the first failure.
+    ///
+    /// Startup runs at most once; later calls return `Ok(())` immediately.
+    pub async fn run_startup(&self) -> Result<(), LifespanError> {
+        if self.started.swap(true, Ordering::SeqCst) {
+            return Ok(());
+        }
+        for hook in &self.startup {
+            hook.run(LifespanPhase::Startup, &self.context).await?;
+        }
+        Ok(())
+    }
+
+    /// Run the shutdown hooks in reverse registration order.
+    ///
+    /// Every hook runs even if an earlier one fails; the first failure is returned.
+    pub async fn run_shutdown(&self) -> Result<(), LifespanError> {
+        let mut first_error = None;
+        for hook in self.shutdown.iter().rev() {
+            if let Err(err) = hook.run(LifespanPhase::Shutdown, &self.context).await {
+                tracing::error!("{}", err);
+                first_error.get_or_insert(err);
+            }
+        }
+        first_error.map_or(Ok(()), Err)
+    }
+}
+
+/// Clones share the [`LifespanContext`] and copy the hook lists and started flag.
+impl Clone for LifespanHooks {
+    fn clone(&self) -> Self {
+        Self {
+            startup: self.startup.clone(),
+            shutdown: self.shutdown.clone(),
+            context: self.context.clone(),
+            started: AtomicBool::new(self.has_started()),
+        }
+    }
+}
+
+impl std::fmt::Debug for LifespanHooks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LifespanHooks")
+            .field("startup_count", &self.startup.len())
+            .field("shutdown_count", &self.shutdown.len())
+            .field("started", &self.has_started())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recording_hook(name: &'static str, log: Arc<Mutex<Vec<&'static str>>>) -> Arc<dyn LifespanHook> {
+        lifespan_hook(name, move |_ctx| {
+            let log = Arc::clone(&log);
+            async move {
+                log.lock().unwrap().push(name);
+                Ok(())
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_startup_in_order_shutdown_in_reverse() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let mut hooks = LifespanHooks::new();
+        hooks.add_startup(recording_hook("open_db", Arc::clone(&log)));
+        hooks.add_startup(recording_hook("warm_cache", Arc::clone(&log)));
+        hooks.add_shutdown(recording_hook("close_db", Arc::clone(&log)));
+        hooks.add_shutdown(recording_hook("flush_cache", Arc::clone(&log)));
+
+        hooks.run_startup().await.unwrap();
+        hooks.run_startup().await.unwrap();
+        hooks.run_shutdown().await.unwrap();
+
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec!["open_db", "warm_cache", "flush_cache", "close_db"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_failing_startup_hook_stops_boot() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let mut hooks = LifespanHooks::new();
+        hooks.add_startup(lifespan_hook("connect", |_ctx| async {
+            Err("connection refused".to_string())
+        }));
+        hooks.add_startup(recording_hook("after", Arc::clone(&log)));
+
+        let err = hooks.run_startup().await.unwrap_err();
+        assert_eq!(err.to_string(), "startup hook 'connect' failed: connection refused");
+        assert!(log.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_hook_timeout() {
+        let mut hooks = LifespanHooks::new();
+        hooks.add_startup_with_timeout(
+            lifespan_hook("slow", |_ctx| async {
+                tokio::time::sleep(Duration::from_secs(5)).await;
+                Ok(())
+            }),
+            Duration::from_millis(20),
+        );
+
+        let err = hooks.run_startup().await.unwrap_err();
+        assert!(matches!(err, LifespanError::TimedOut { ref hook, .. } if hook == "slow"));
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_hooks_see_startup_values_and_all_run() {
+        let closed = Arc::new(Mutex::new(None));
+        let closed_clone = Arc::clone(&closed);
+        let mut hooks = LifespanHooks::new();
+        hooks.add_startup(lifespan_hook("open", |ctx| async move {
+            ctx.provide("pool", "postgres://db".to_string());
+            Ok(())
+        }));
+        hooks.add_shutdown(lifespan_hook("close", move |ctx| {
+            let closed = Arc::clone(&closed_clone);
+            async move {
+                *closed.lock().unwrap() = ctx.get::<String>("pool").map(|pool| (*pool).clone());
+                Ok(())
+            }
+        }));
+        hooks.add_shutdown(lifespan_hook("broken", |_ctx| async { Err("boom".to_string()) }));
+
+        hooks.run_startup().await.unwrap();
+        let err = hooks.run_shutdown().await.unwrap_err();
+
+        assert!(matches!(err, LifespanError::Failed { ref hook, .. } if hook == "broken"));
+        assert_eq!(closed.lock().unwrap().as_deref(), Some("postgres://db"));
+    }
+}
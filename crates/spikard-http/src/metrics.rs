@@ -0,0 +1,827 @@
+//! Prometheus metrics
+//!
+//! [`MetricsRegistry`] holds counters, gauges and histograms and renders them in the
+//! Prometheus text exposition format. When [`ServerConfig::metrics`] is set the server
+//! records built-in HTTP, gRPC, JSON-RPC, WebSocket/SSE and background task metrics and
+//! serves them at [`MetricsConfig::path`]. Binding code registers its own metrics on the
+//! same registry through [`MetricsRegistry::counter`], [`MetricsRegistry::gauge`] and
+//! [`MetricsRegistry::histogram`].
+
+use crate::ServerConfig;
+use crate::background::{BackgroundHandle, BackgroundMetricsSnapshot};
+use axum::Router as AxumRouter;
+use axum::body::Body;
+use axum::extract::{MatchedPath, Request, State};
+use axum::http::{StatusCode, header};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
+
+/// Content type of the Prometheus text exposition format
+pub const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// Route label for requests that did not match a registered route
+pub const UNMATCHED_ROUTE: &str = "unmatched";
+
+/// Default latency buckets in seconds (the Prometheus client defaults)
+pub const DEFAULT_BUCKETS: [f64; 11] = [0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0];
+
+fn default_path() -> String {
+    "/metrics".to_string()
+}
+
+fn default_buckets() -> Vec<f64> {
+    DEFAULT_BUCKETS.to_vec()
+}
+
+/// Metrics endpoint configuration
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct MetricsConfig {
+    /// Path the Prometheus endpoint is served at
+    pub path: String,
+    /// Upper bounds (seconds) of the built-in latency histograms
+    pub buckets: Vec<f64>,
+}
+
+impl Default for MetricsConfig {
+    fn default() -> Self {
+        Self {
+            path: default_path(),
+            buckets: default_buckets(),
+        }
+    }
+}
+
+/// Error raised when registering or recording a metric
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MetricsError {
+    /// Metric or label name is not a valid Prometheus identifier
+    InvalidName(String),
+    /// A metric with this name exists with a different type or label set
+    Conflict(String),
+    /// Histogram buckets are empty, unsorted or not finite
+    InvalidBuckets(String),
+}
+
+impl std::fmt::Display for MetricsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidName(name) => write!(f, "invalid metric or label name '{}'", name),
+            Self::Conflict(name) => write!(
+                f,
+                "metric '{}' is already registered with a different type or labels",
+                name
+            ),
+            Self::InvalidBuckets(name) => write!(
+                f,
+                "histogram '{}' needs non-empty, finite, strictly increasing buckets",
+                name
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MetricsError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MetricKind {
+    Counter,
+    Gauge,
+    Histogram,
+}
+
+impl MetricKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Counter => "counter",
+            Self::Gauge => "gauge",
+            Self::Histogram => "histogram",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Series {
+    Value(f64),
+    Histogram { counts: Vec<u64>, sum: f64, count: u64 },
+}
+
+#[derive(Debug)]
+struct MetricFamily {
+    name: String,
+    help: String,
+    kind: MetricKind,
+    label_names: Vec<String>,
+    buckets: Vec<f64>,
+    series: Mutex<BTreeMap<Vec<String>, Series>>,
+}
+
+impl MetricFamily {
+    fn with_series<R>(&self, label_values: &[&str], update: impl FnOnce(&mut Series) -> R) -> Option<R> {
+        if label_values.len() != self.label_names.len() {
+            tracing::debug!(
+                metric = %self.name,
+                expected = self.label_names.len(),
+                got = label_values.len(),
+                "ignoring sample with wrong number of label values"
+            );
+            return None;
+        }
+        let key: Vec<String> = label_values.iter().map(|value| (*value).to_string()).collect();
+        let mut series = self.series.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let entry = series.entry(key).or_insert_with(|| match self.kind {
+            MetricKind::Histogram => Series::Histogram {
+                counts: vec![0; self.buckets.len()],
+                sum: 0.0,
+                count: 0,
+            },
+            _ => Series::Value(0.0),
+        });
+        Some(update(entry))
+    }
+
+    fn add(&self, label_values: &[&str], delta: f64) {
+        self.with_series(label_values, |series| {
+            if let Series::Value(value) = series {
+                *value += delta;
+            }
+        });
+    }
+
+    fn set(&self, label_values: &[&str], new_value: f64) {
+        self.with_series(label_values, |series| {
+            if let Series::Value(value) = series {
+                *value = new_value;
+            }
+        });
+    }
+
+    fn observe(&self, label_values: &[&str], sample: f64) {
+        let buckets = &self.buckets;
+        self.with_series(label_values, |series| {
+            if let Series::Histogram { counts, sum, count } = series {
+                if let Some(index) = buckets.iter().position(|bound| sample <= *bound) {
+                    counts[index] += 1;
+                }
+                *sum += sample;
+                *count += 1;
+            }
+        });
+    }
+
+    fn render(&self, out: &mut String) {
+        let series = self.series.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let _ = writeln!(out, "# HELP {} {}", self.name, escape_help(&self.help));
+        let _ = writeln!(out, "# TYPE {} {}", self.name, self.kind.as_str());
+        for (label_values, sample) in series.iter() {
+            let labels = format_labels(&self.label_names, label_values, None);
+            match sample {
+                Series::Value(value) => {
+                    let _ = writeln!(out, "{}{} {}", self.name, labels, format_value(*value));
+                }
+                Series::Histogram { counts, sum, count } => {
+                    let mut cumulative = 0;
+                    for (bound, bucket_count) in self.buckets.iter().zip(counts) {
+                        cumulative += bucket_count;
+                        let labels = format_labels(&self.label_names, label_values, Some(&format_value(*bound)));
+                        let _ = writeln!(out, "{}_bucket{} {}", self.name, labels, cumulative);
+                    }
+                    let labels_inf = format_labels(&self.label_names, label_values, Some("+Inf"));
+                    let _ = writeln!(out, "{}_bucket{} {}", self.name, labels_inf, count);
+                    let _ = writeln!(out, "{}_sum{} {}", self.name, labels, format_value(*sum));
+                    let _ = writeln!(out, "{}_count{} {}", self.name, labels, count);
+                }
+            }
+        }
+    }
+}
+
+fn format_labels(names: &[String], values: &[String], le: Option<&str>) -> String {
+    if names.is_empty() && le.is_none() {
+        return String::new();
+    }
+    let mut pairs: Vec<String> = names
+        .iter()
+        .zip(values)
+        .map(|(name, value)| format!("{}=\"{}\"", name, escape_label_value(value)))
+        .collect();
+    if let Some(le) = le {
+        pairs.push(format!("le=\"{}\"", le));
+    }
+    format!("{{{}}}", pairs.join(","))
+}
+
+fn format_value(value: f64) -> String {
+    if value.is_nan() {
+        "NaN".to_string()
+    } else if value.is_infinite() {
+        if value > 0.0 { "+Inf" } else { "-Inf" }.to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+fn escape_help(help: &str) -> String {
+    help.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn escape_label_value(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+fn is_valid_name(name: &str, allow_colon: bool) -> bool {
+    let mut chars = name.chars();
+    let valid_start = |c: char| c.is_ascii_alphabetic() || c == '_' || (allow_colon && c == ':');
+    match chars.next() {
+        Some(first) if valid_start(first) => chars.all(|c| valid_start(c) || c.is_ascii_digit()),
+        _ => false,
+    }
+}
+
+/// Monotonically increasing counter
+#[derive(Debug, Clone)]
+pub struct Counter {
+    family: Arc<MetricFamily>,
+}
+
+impl Counter {
+    /// Increment the series identified by `label_values` by one
+    pub fn inc(&self, label_values: &[&str]) {
+        self.family.add(label_values, 1.0);
+    }
+
+    /// Increment the series identified by `label_values` by `value` (negative values are ignored)
+    pub fn inc_by(&self, label_values: &[&str], value: f64) {
+        if value >= 0.0 {
+            self.family.add(label_values, value);
+        }
+    }
+}
+
+/// Value that can go up and down
+#[derive(Debug, Clone)]
+pub struct Gauge {
+    family: Arc<MetricFamily>,
+}
+
+impl Gauge {
+    /// Set the series identified by `label_values` to `value`
+    pub fn set(&self, label_values: &[&str], value: f64) {
+        self.family.set(label_values, value);
+    }
+
+    /// Add `delta` (which may be negative) to the series identified by `label_values`
+    pub fn add(&self, label_values: &[&str], delta: f64) {
+        self.family.add(label_values, delta);
+    }
+
+    /// Increment the series identified by `label_values` by one
+    pub fn inc(&self, label_values: &[&str]) {
+        self.add(label_values, 1.0);
+    }
+
+    /// Decrement the series identified by `label_values` by one
+    pub fn dec(&self, label_values: &[&str]) {
+        self.add(label_values, -1.0);
+    }
+}
+
+/// Distribution of observed values over fixed buckets
+#[derive(Debug, Clone)]
+pub struct Histogram {
+    family: Arc<MetricFamily>,
+}
+
+impl Histogram {
+    /// Record `value` in the series identified by `label_values`
+    pub fn observe(&self, label_values: &[&str], value: f64) {
+        self.family.observe(label_values, value);
+    }
+}
+
+/// A tracked background runtime and the failure count last added to the counter
+#[derive(Debug)]
+struct TrackedBackground {
+    handle: BackgroundHandle,
+    failed_seen: u64,
+}
+
+#[derive(Debug)]
+struct BuiltinMetrics {
+    http_requests: Counter,
+    http_duration: Histogram,
+    grpc_requests: Counter,
+    grpc_duration: Histogram,
+    jsonrpc_requests: Counter,
+    jsonrpc_duration: Histogram,
+    websocket_connections: Gauge,
+    sse_connections: Gauge,
+    background_queued: Gauge,
+    background_running: Gauge,
+    background_failed: Counter,
+}
+
+/// Registry of metrics rendered by the `/metrics` endpoint
+///
+/// Built-in metrics are registered on construction with the `spikard_` prefix; custom
+/// metrics may use any other valid Prometheus name. Registering an existing name with the
+/// same type and labels returns a handle to the existing metric.
+#[derive(Debug)]
+pub struct MetricsRegistry {
+    families: RwLock<BTreeMap<String, Arc<MetricFamily>>>,
+    buckets: Vec<f64>,
+    builtin: BuiltinMetrics,
+    background: Mutex<Vec<TrackedBackground>>,
+}
+
+impl Default for MetricsRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MetricsRegistry {
+    /// Create a registry using [`DEFAULT_BUCKETS`] for latency histograms
+    pub fn new() -> Self {
+        Self::with_buckets(default_buckets())
+    }
+
+    /// Create a registry whose built-in latency histograms use `buckets` (seconds)
+    ///
+    /// Invalid buckets fall back to [`DEFAULT_BUCKETS`].
+    pub fn with_buckets(buckets: Vec<f64>) -> Self {
+        let buckets = if validate_buckets(&buckets) {
+            buckets
+        } else {
+            tracing::warn!("invalid metrics buckets {:?}; using defaults", buckets);
+            default_buckets()
+        };
+
+        let families = RwLock::new(BTreeMap::new());
+        let builtin = {
+            let new_family = |name: &str, help: &str, kind: MetricKind, labels: &[&str]| {
+                let family = Arc::new(MetricFamily {
+                    name: name.to_string(),
+                    help: help.to_string(),
+                    kind,
+                    label_names: labels.iter().map(|label| (*label).to_string()).collect(),
+                    buckets: if kind == MetricKind::Histogram {
+                        buckets.clone()
+                    } else {
+                        Vec::new()
+                    },
+                    series: Mutex::new(BTreeMap::new()),
+                });
+                families
+                    .write()
+                    .unwrap_or_else(|poisoned| poisoned.into_inner())
+                    .insert(name.to_string(), Arc::clone(&family));
+                family
+            };
+            let counter = |name, help, labels| Counter {
+                family: new_family(name, help, MetricKind::Counter, labels),
+            };
+            let histogram = |name, help, labels| Histogram {
+                family: new_family(name, help, MetricKind::Histogram, labels),
+            };
+            let gauge = |name, help, labels| Gauge {
+                family: new_family(name, help, MetricKind::Gauge, labels),
+            };
+
+            BuiltinMetrics {
+                http_requests: counter(
+                    "spikard_http_requests_total",
+                    "HTTP requests by route template, method and status",
+                    &["method", "route", "status"],
+                ),
+                http_duration: histogram(
+                    "spikard_http_request_duration_seconds",
+                    "HTTP request latency by route template, method and status",
+                    &["method", "route", "status"],
+                ),
+                grpc_requests: counter(
+                    "spikard_grpc_requests_total",
+                    "gRPC calls by service, method and gRPC status code",
+                    &["service", "method", "code"],
+                ),
+                grpc_duration: histogram(
+                    "spikard_grpc_request_duration_seconds",
+                    "gRPC call latency by service and method",
+                    &["service", "method"],
+                ),
+                jsonrpc_requests: counter(
+                    "spikard_jsonrpc_requests_total",
+                    "JSON-RPC calls by method and outcome",
+                    &["method", "outcome"],
+                ),
+                jsonrpc_duration: histogram(
+                    "spikard_jsonrpc_request_duration_seconds",
+                    "JSON-RPC call latency by method",
+                    &["method"],
+                ),
+                websocket_connections: gauge(
+                    "spikard_websocket_connections_active",
+                    "Open WebSocket connections by route",
+                    &["route"],
+                ),
+                sse_connections: gauge(
+                    "spikard_sse_connections_active",
+                    "Open Server-Sent Events streams by route",
+                    &["route"],
+                ),
+                background_queued: gauge(
+                    "spikard_background_tasks_queued",
+                    "Background tasks waiting to run",
+                    &[],
+                ),
+                background_running: gauge(
+                    "spikard_background_tasks_running",
+                    "Background tasks currently running",
+                    &[],
+                ),
+                background_failed: counter(
+                    "spikard_background_tasks_failed_total",
+                    "Background tasks that failed since startup",
+                    &[],
+                ),
+            }
+        };
+
+        Self {
+            families,
+            buckets,
+            builtin,
+            background: Mutex::new(Vec::new()),
+        }
+    }
+
+    fn register(
+        &self,
+        name: &str,
+        help: &str,
+        kind: MetricKind,
+        label_names: &[&str],
+        buckets: Vec<f64>,
+    ) -> Result<Arc<MetricFamily>, MetricsError> {
+        if !is_valid_name(name, true) {
+            return Err(MetricsError::InvalidName(name.to_string()));
+        }
+        if let Some(label) = label_names
+            .iter()
+            .find(|label| !is_valid_name(label, false) || label.starts_with("__") || **label == "le")
+        {
+            return Err(MetricsError::InvalidName((*label).to_string()));
+        }
+        if kind == MetricKind::Histogram && !validate_buckets(&buckets) {
+            return Err(MetricsError::InvalidBuckets(name.to_string()));
+        }
+
+        let mut families = self.families.write().unwrap_or_else(|poisoned| poisoned.into_inner());
+        if let Some(existing) = families.get(name) {
+            let same_labels = existing.label_names.len() == label_names.len()
+                && existing.label_names.iter().zip(label_names).all(|(a, b)| a == b);
+            return if existing.kind == kind
+                && same_labels
+                && (kind != MetricKind::Histogram || existing.buckets == buckets)
+            {
+                Ok(Arc::clone(existing))
+            } else {
+                Err(MetricsError::Conflict(name.to_string()))
+            };
+        }
+
+        let family = Arc::new(MetricFamily {
+            name: name.to_string(),
+            help: help.to_string(),
+            kind,
+            label_names: label_names.iter().map(|label| (*label).to_string()).collect(),
+            buckets,
+            series: Mutex::new(BTreeMap::new()),
+        });
+        families.insert(name.to_string(), Arc::clone(&family));
+        Ok(family)
+    }
+
+    /// Register (or look up) a counter
+    ///
+    /// # Errors
+    /// Returns an error for invalid names or when `name` is registered with a different type or labels.
+    pub fn counter(&self, name: &str, help: &str, label_names: &[&str]) -> Result<Counter, MetricsError> {
+        self.register(name, help, MetricKind::Counter, label_names, Vec::new())
+            .map(|family| Counter { family })
+    }
+
+    /// Register (or look up) a gauge
+    ///
+    /// # Errors
+    /// Returns an error for invalid names or when `name` is registered with a different type or labels.
+    pub fn gauge(&self, name: &str, help: &str, label_names: &[&str]) -> Result<Gauge, MetricsError> {
+        self.register(name, help, MetricKind::Gauge, label_names, Vec::new())
+            .map(|family| Gauge { family })
+    }
+
+    /// Register (or look up) a histogram; `buckets` defaults to the registry's latency buckets
+    ///
+    /// # Errors
+    /// Returns an error for invalid names or buckets, or when `name` is registered with a
+    /// different type, labels or buckets.
+    pub fn histogram(
+        &self,
+        name: &str,
+        help: &str,
+        label_names: &[&str],
+        buckets: Option<Vec<f64>>,
+    ) -> Result<Histogram, MetricsError> {
+        let buckets = buckets.unwrap_or_else(|| self.buckets.clone());
+        self.register(name, help, MetricKind::Histogram, label_names, buckets)
+            .map(|family| Histogram { family })
+    }
+
+    /// Report the queue depth, running and failed counts of a background task runtime
+    ///
+    /// The server does not own a background runtime, so tracking is opt-in: whoever starts
+    /// a [`BackgroundRuntime`](crate::background::BackgroundRuntime) registers its handle
+    /// here. The `spikard_background_tasks_*` series stay absent until one is tracked.
+    pub fn track_background(&self, handle: BackgroundHandle) {
+        self.background
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .push(TrackedBackground { handle, failed_seen: 0 });
+    }
+
+    /// Render every metric in the Prometheus text exposition format
+    pub fn render(&self) -> String {
+        self.refresh_background();
+        let families = self.families.read().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let mut out = String::new();
+        for family in families.values() {
+            family.render(&mut out);
+        }
+        out
+    }
+
+    fn refresh_background(&self) {
+        let mut tracked = self.background.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        if tracked.is_empty() {
+            return;
+        }
+        let (mut queued, mut running) = (0, 0);
+        for runtime in tracked.iter_mut() {
+            let BackgroundMetricsSnapshot {
+                queued: runtime_queued,
+                running: runtime_running,
+                failed,
+            } = runtime.handle.metrics();
+            queued += runtime_queued;
+            running += runtime_running;
+            // The runtime owns the failure count; add only what it gained since the last scrape.
+            let new_failures = failed.saturating_sub(runtime.failed_seen);
+            runtime.failed_seen = failed;
+            self.builtin.background_failed.inc_by(&[], new_failures as f64);
+        }
+        self.builtin.background_queued.set(&[], queued as f64);
+        self.builtin.background_running.set(&[], running as f64);
+    }
+
+    pub(crate) fn record_http(&self, method: &str, route: &str, status: u16, elapsed: Duration) {
+        let status = status.to_string();
+        let labels = [method, route, status.as_str()];
+        self.builtin.http_requests.inc(&labels);
+        self.builtin.http_duration.observe(&labels, elapsed.as_secs_f64());
+    }
+
+    pub(crate) fn record_grpc(&self, service: &str, method: &str, code: &str, elapsed: Duration) {
+        self.builtin.grpc_requests.inc(&[service, method, code]);
+        self.builtin
+            .grpc_duration
+            .observe(&[service, method], elapsed.as_secs_f64());
+    }
+
+    pub(crate) fn record_jsonrpc(&self, method: &str, success: bool, elapsed: Duration) {
+        let outcome = if success { "success" } else { "error" };
+        self.builtin.jsonrpc_requests.inc(&[method, outcome]);
+        self.builtin.jsonrpc_duration.observe(&[method], elapsed.as_secs_f64());
+    }
+}
+
+fn validate_buckets(buckets: &[f64]) -> bool {
+    !buckets.is_empty()
+        && buckets.iter().all(|bound| bound.is_finite())
+        && buckets.windows(2).all(|pair| pair[0] < pair[1])
+}
+
+/// Registry to use for `config`, creating and storing one when metrics are enabled
+///
+/// Returns `None` when [`ServerConfig::metrics`] is unset.
+pub fn ensure_registry(config: &mut ServerConfig) -> Option<Arc<MetricsRegistry>> {
+    let metrics = config.metrics.as_ref()?;
+    let registry = config
+        .metrics_registry
+        .get_or_insert_with(|| Arc::new(MetricsRegistry::with_buckets(metrics.buckets.clone())));
+    Some(Arc::clone(registry))
+}
+
+/// Kind of long-lived connection counted by the active connection gauges
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionKind {
+    WebSocket,
+    Sse,
+}
+
+/// Per-request handle for counting long-lived connections on the matched route
+///
+/// The HTTP metrics middleware inserts this into request extensions.
+#[derive(Debug, Clone)]
+pub struct ConnectionMetrics {
+    registry: Arc<MetricsRegistry>,
+    route: Arc<str>,
+}
+
+impl ConnectionMetrics {
+    /// Count a connection as active until the returned guard is dropped
+    pub fn track(&self, kind: ConnectionKind) -> ConnectionGuard {
+        let gauge = match kind {
+            ConnectionKind::WebSocket => self.registry.builtin.websocket_connections.clone(),
+            ConnectionKind::Sse => self.registry.builtin.sse_connections.clone(),
+        };
+        gauge.inc(&[&self.route]);
+        ConnectionGuard {
+            gauge,
+            route: Arc::clone(&self.route),
+        }
+    }
+}
+
+/// Decrements an active connection gauge when dropped
+#[derive(Debug)]
+pub struct ConnectionGuard {
+    gauge: Gauge,
+    route: Arc<str>,
+}
+
+impl Drop for ConnectionGuard {
+    fn drop(&mut self) {
+        self.gauge.dec(&[&self.route]);
+    }
+}
+
+pub(crate) fn route_label(request: &Request) -> String {
+    match request.extensions().get::<MatchedPath>() {
+        Some(matched) => {
+            let path = matched.as_str();
+            if path.len() > 1 && path.ends_with('/') {
+                path.trim_end_matches('/').to_string()
+            } else {
+                path.to_string()
+            }
+        }
+        None => UNMATCHED_ROUTE.to_string(),
+    }
+}
+
+/// Record request count and latency by route template, method and status
+///
+/// gRPC calls are skipped here; they are recorded per service method instead.
+pub(crate) async fn http_metrics_middleware(
+    State(registry): State<Arc<MetricsRegistry>>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    if crate::server::grpc_routing::is_grpc_request(&request) {
+        return next.run(request).await;
+    }
+
+    let method = request.method().as_str().to_string();
+    let route = route_label(&request);
+    request.extensions_mut().insert(ConnectionMetrics {
+        registry: Arc::clone(&registry),
+        route: Arc::from(route.as_str()),
+    });
+
+    let started = Instant::now();
+    let response = next.run(request).await;
+    registry.record_http(&method, &route, response.status().as_u16(), started.elapsed());
+    response
+}
+
+async fn render_metrics(State(registry): State<Arc<MetricsRegistry>>) -> Response {
+    (
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, PROMETHEUS_CONTENT_TYPE)],
+        Body::from(registry.render()),
+    )
+        .into_response()
+}
+
+/// Record HTTP metrics for every route of `router` into `registry`
+///
+/// The server applies this to the routers it builds; use it for routers merged in
+/// afterwards (for example WebSocket and SSE routes) so they share the same registry.
+pub fn instrument_router(router: AxumRouter, registry: Arc<MetricsRegistry>) -> AxumRouter {
+    router.layer(axum::middleware::from_fn_with_state(registry, http_metrics_middleware))
+}
+
+/// Add the Prometheus endpoint at `path`
+pub(crate) fn metrics_route(router: AxumRouter, path: &str, registry: Arc<MetricsRegistry>) -> AxumRouter {
+    router.route(path, axum::routing::get(render_metrics).with_state(registry))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_counters_and_histograms() {
+        let registry = MetricsRegistry::new();
+        let counter = registry
+            .counter("app_jobs_total", "Jobs processed", &["queue"])
+            .expect("counter");
+        counter.inc(&["emails"]);
+        counter.inc_by(&["emails"], 2.0);
+        let histogram = registry
+            .histogram("app_job_seconds", "Job time", &[], Some(vec![0.1, 1.0]))
+            .expect("histogram");
+        histogram.observe(&[], 0.0625);
+        histogram.observe(&[], 0.5);
+        histogram.observe(&[], 5.0);
+
+        let text = registry.render();
+        assert!(text.contains("# TYPE app_jobs_total counter\n"));
+        assert!(text.contains("app_jobs_total{queue=\"emails\"} 3\n"));
+        assert!(text.contains("app_job_seconds_bucket{le=\"0.1\"} 1\n"));
+        assert!(text.contains("app_job_seconds_bucket{le=\"1\"} 2\n"));
+        assert!(text.contains("app_job_seconds_bucket{le=\"+Inf\"} 3\n"));
+        assert!(text.contains("app_job_seconds_sum 5.5625\n"));
+        assert!(text.contains("app_job_seconds_count 3\n"));
+    }
+
+    #[test]
+    fn registration_is_idempotent_and_rejects_conflicts() {
+        let registry = MetricsRegistry::new();
+        let first = registry.counter("app_hits_total", "Hits", &["page"]).expect("counter");
+        let second = registry
+            .counter("app_hits_total", "Hits", &["page"])
+            .expect("same counter");
+        first.inc(&["home"]);
+        second.inc(&["home"]);
+        assert!(registry.render().contains("app_hits_total{page=\"home\"} 2\n"));
+
+        assert_eq!(
+            registry.gauge("app_hits_total", "Hits", &["page"]).unwrap_err(),
+            MetricsError::Conflict("app_hits_total".to_string())
+        );
+        assert!(matches!(
+            registry.counter("spikard_http_requests_total", "", &[]),
+            Err(MetricsError::Conflict(_))
+        ));
+        assert!(matches!(
+            registry.counter("9lives", "", &[]),
+            Err(MetricsError::InvalidName(_))
+        ));
+        assert!(matches!(
+            registry.histogram("app_latency", "", &["le"], None),
+            Err(MetricsError::InvalidName(_))
+        ));
+        assert!(matches!(
+            registry.histogram("app_latency", "", &[], Some(vec![1.0, 0.5])),
+            Err(MetricsError::InvalidBuckets(_))
+        ));
+    }
+
+    #[test]
+    fn label_values_are_escaped_and_arity_checked() {
+        let registry = MetricsRegistry::new();
+        let gauge = registry.gauge("app_temp", "Temp", &["room"]).expect("gauge");
+        gauge.set(&["a\"b\\c\nd"], 1.5);
+        gauge.set(&[], 3.0);
+        let text = registry.render();
+        assert!(text.contains("app_temp{room=\"a\\\"b\\\\c\\nd\"} 1.5\n"));
+        assert!(!text.contains("app_temp 3"));
+    }
+
+    #[test]
+    fn connection_guard_tracks_active_connections() {
+        let registry = Arc::new(MetricsRegistry::new());
+        let connections = ConnectionMetrics {
+            registry: Arc::clone(&registry),
+            route: Arc::from("/ws"),
+        };
+        let first = connections.track(ConnectionKind::WebSocket);
+        let _second = connections.track(ConnectionKind::WebSocket);
+        assert!(
+            registry
+                .render()
+                .contains("spikard_websocket_connections_active{route=\"/ws\"} 2\n")
+        );
+        drop(first);
+        assert!(
+            registry
+                .render()
+                .contains("spikard_websocket_connections_active{route=\"/ws\"} 1\n")
+        );
+    }
+}
@@ -19,6 +19,7 @@ use serde_json::json;
 use spikard_core::ProblemDetails;
 use std::cell::RefCell;
 use std::num::NonZeroUsize;
+use std::sync::Arc;
 use std::time::Duration;
 use tokio::time::timeout;
 
@@ -32,6 +33,8 @@ thread_local! {
 pub(crate) struct RouteInfo {
     /// Whether this route expects a JSON request body
     pub expects_json_body: bool,
+    /// Codecs for request bodies in other media types
+    pub body_codecs: Option<Arc<validation::BodyCodecRegistry>>,
 }
 
 /// Extract a multipart boundary token from the first `--<boundary>` line of a
@@ -76,6 +79,40 @@ pub(crate) struct PreReadBody(pub bytes::Bytes);
 #[derive(Debug, Clone)]
 pub(crate) struct PreParsedJson(pub serde_json::Value);
 
+/// Read a request body of the size declared by `Content-Length`, if any
+#[allow(clippy::result_large_err)]
+async fn read_declared_body(headers: &axum::http::HeaderMap, body: Body) -> Result<bytes::Bytes, Response> {
+    let body_limit = headers
+        .get(axum::http::header::CONTENT_LENGTH)
+        .and_then(|value| value.to_str().ok())
+        .map_or(usize::MAX, |value| value.parse::<usize>().unwrap_or(usize::MAX));
+
+    let body_bytes = match timeout(Duration::from_secs(1), axum::body::to_bytes(body, body_limit)).await {
+        Ok(Ok(bytes)) => bytes,
+        Ok(Err(_)) => {
+            let error_body = json!({
+                "error": "Failed to read request body"
+            });
+            return Err((StatusCode::BAD_REQUEST, axum::Json(error_body)).into_response());
+        }
+        Err(_) => {
+            let problem = ProblemDetails::new(
+                "https://spikard.dev/errors/content-length-mismatch",
+                "Content-Length header mismatch",
+                StatusCode::BAD_REQUEST,
+            )
+            .with_detail("Content-Length header does not match actual body size");
+            let body = serde_json::to_string(&problem).unwrap_or_else(|_| "{}".to_string());
+            return Err((StatusCode::BAD_REQUEST, body).into_response());
+        }
+    };
+
+    if headers.get(axum::http::header::CONTENT_LENGTH).is_some() {
+        validation::validate_content_length(headers, body_bytes.len())?;
+    }
+    Ok(body_bytes)
+}
+
 /// Middleware to validate Content-Type headers and related requirements
 ///
 /// This middleware performs comprehensive request body validation and transformation:
@@ -92,6 +129,9 @@ pub(crate) struct PreParsedJson(pub serde_json::Value);
 /// - **JSON Validation:** Validates JSON request bodies for well-formedness (when the
 ///   Content-Type is `application/json`).
 ///
+/// - **Body Codecs:** Decodes bodies in media types registered in a
+///   [`validation::BodyCodecRegistry`] (MessagePack, CBOR, XML, NDJSON, ...) into JSON.
+///
 /// - **Content-Length:** Validates that the Content-Length header is present and
 ///   reasonable for POST, PUT, and PATCH requests.
 ///
@@ -140,6 +180,49 @@ pub async fn validate_content_type_middleware(
 
     let method = &parts.method;
     if method == axum::http::Method::POST || method == axum::http::Method::PUT || method == axum::http::Method::PATCH {
+        let codec = route_info.body_codecs.as_deref().and_then(|codecs| {
+            let content_type = headers.get(axum::http::header::CONTENT_TYPE)?.to_str().ok()?;
+            codecs
+                .for_content_type(content_type)
+                .map(|(media_type, codec)| (media_type.to_string(), Arc::clone(codec)))
+        });
+        if let Some((media_type, codec)) = codec {
+            let body_bytes = read_declared_body(headers, body).await?;
+            let json_body = codec.decode(&body_bytes).map_err(|e| {
+                let problem = ProblemDetails::bad_request(format!("Invalid {} request body: {}", media_type, e));
+                let body = problem.to_json().unwrap_or_else(|_| "{}".to_string());
+                (
+                    StatusCode::BAD_REQUEST,
+                    [(
+                        axum::http::header::CONTENT_TYPE,
+                        spikard_core::problem::CONTENT_TYPE_PROBLEM_JSON,
+                    )],
+                    body,
+                )
+                    .into_response()
+            })?;
+            let json_bytes = match serde_json::to_vec(&json_body) {
+                Ok(bytes) => bytes,
+                Err(e) => {
+                    let error_body = json!({
+                        "error": format!("Failed to serialize {} body to JSON: {}", media_type, e)
+                    });
+                    return Err((StatusCode::INTERNAL_SERVER_ERROR, axum::Json(error_body)).into_response());
+                }
+            };
+
+            parts.headers.insert(
+                axum::http::header::CONTENT_TYPE,
+                axum::http::HeaderValue::from_static("application/json"),
+            );
+            if let Ok(value) = axum::http::HeaderValue::from_str(&json_bytes.len().to_string()) {
+                parts.headers.insert(axum::http::header::CONTENT_LENGTH, value);
+            }
+            parts.extensions.insert(PreReadBody(bytes::Bytes::from(json_bytes)));
+            parts.extensions.insert(PreParsedJson(json_body));
+            return Ok(next.run(HttpRequest::from_parts(parts, Body::empty())).await);
+        }
+
         if route_info.expects_json_body {
             validation::validate_json_content_type(headers)?;
         }
@@ -510,6 +593,7 @@ mod tests {
     fn test_route_info_creation() {
         let info = RouteInfo {
             expects_json_body: true,
+            body_codecs: None,
         };
         assert!(info.expects_json_body);
     }
@@ -518,6 +602,7 @@ mod tests {
     fn test_route_info_expects_json_body_true() {
         let info = RouteInfo {
             expects_json_body: true,
+            body_codecs: None,
         };
         assert_eq!(info.expects_json_body, true);
     }
@@ -526,6 +611,7 @@ mod tests {
     fn test_route_info_expects_json_body_false() {
         let info = RouteInfo {
             expects_json_body: false,
+            body_codecs: None,
         };
         assert_eq!(info.expects_json_body, false);
     }
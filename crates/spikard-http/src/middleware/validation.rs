@@ -3,8 +3,11 @@
 use axum::http::HeaderValue;
 use axum::http::{HeaderMap, StatusCode};
 use axum::response::{IntoResponse, Response};
-use serde_json::json;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
 use spikard_core::problem::{CONTENT_TYPE_PROBLEM_JSON, ProblemDetails};
+use std::collections::BTreeMap;
+use std::sync::Arc;
 
 fn trim_ascii_whitespace(bytes: &[u8]) -> &[u8] {
     let mut start = 0usize;
@@ -342,6 +345,241 @@ pub fn validate_content_type_headers_and_classify(
     Ok(Some(kind))
 }
 
+/// Decodes request bodies of one media type into the JSON value checked by `SchemaValidator`
+pub trait BodyCodec: Send + Sync {
+    /// Decode a complete request body
+    fn decode(&self, body: &[u8]) -> Result<Value, String>;
+}
+
+impl std::fmt::Debug for dyn BodyCodec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("BodyCodec")
+    }
+}
+
+/// `application/msgpack` bodies
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MessagePackCodec;
+
+impl BodyCodec for MessagePackCodec {
+    fn decode(&self, body: &[u8]) -> Result<Value, String> {
+        rmp_serde::from_slice(body).map_err(|e| e.to_string())
+    }
+}
+
+/// `application/cbor` bodies
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CborCodec;
+
+impl BodyCodec for CborCodec {
+    fn decode(&self, body: &[u8]) -> Result<Value, String> {
+        ciborium::from_reader(body).map_err(|e| e.to_string())
+    }
+}
+
+/// `application/x-ndjson` bodies, decoded into an array with one item per non-empty line
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NdjsonCodec;
+
+impl BodyCodec for NdjsonCodec {
+    fn decode(&self, body: &[u8]) -> Result<Value, String> {
+        body.split(|&b| b == b'\n')
+            .enumerate()
+            .filter(|(_, line)| !line.trim_ascii().is_empty())
+            .map(|(idx, line)| serde_json::from_slice(line).map_err(|e| format!("line {}: {}", idx + 1, e)))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Value::Array)
+    }
+}
+
+/// How XML documents map onto JSON values
+///
+/// Elements become objects keyed by child element name, repeated children become
+/// arrays and elements holding only text become scalars. Namespace prefixes are dropped.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct XmlMapping {
+    /// Prefix for keys holding attributes
+    pub attribute_prefix: String,
+    /// Key holding the text of elements that also have attributes or children
+    pub text_key: String,
+    /// Element names that always decode to arrays, even with a single occurrence
+    pub array_elements: Vec<String>,
+    /// Decode `true`/`false` and numeric text as JSON booleans and numbers
+    pub coerce_scalars: bool,
+    /// Wrap the result in an object keyed by the root element name
+    pub include_root: bool,
+}
+
+impl Default for XmlMapping {
+    fn default() -> Self {
+        Self {
+            attribute_prefix: "@".to_string(),
+            text_key: "#text".to_string(),
+            array_elements: Vec::new(),
+            coerce_scalars: true,
+            include_root: false,
+        }
+    }
+}
+
+/// `application/xml` bodies, decoded according to an [`XmlMapping`]
+#[derive(Debug, Clone, Default)]
+pub struct XmlCodec {
+    mapping: XmlMapping,
+}
+
+impl XmlCodec {
+    pub fn new(mapping: XmlMapping) -> Self {
+        Self { mapping }
+    }
+
+    fn scalar(&self, text: &str) -> Value {
+        if self.mapping.coerce_scalars {
+            match text {
+                "true" => return Value::Bool(true),
+                "false" => return Value::Bool(false),
+                _ => {}
+            }
+            if let Ok(int) = text.parse::<i64>() {
+                return Value::from(int);
+            }
+            if text
+                .bytes()
+                .all(|b| b.is_ascii_digit() || matches!(b, b'-' | b'+' | b'.' | b'e' | b'E'))
+                && let Some(number) = text.parse::<f64>().ok().and_then(serde_json::Number::from_f64)
+            {
+                return Value::Number(number);
+            }
+        }
+        Value::String(text.to_string())
+    }
+
+    fn element(&self, node: roxmltree::Node<'_, '_>) -> Value {
+        let mut object = serde_json::Map::new();
+        for attribute in node.attributes() {
+            object.insert(
+                format!("{}{}", self.mapping.attribute_prefix, attribute.name()),
+                self.scalar(attribute.value()),
+            );
+        }
+
+        let mut text = String::new();
+        let mut has_children = false;
+        for child in node.children() {
+            if child.is_element() {
+                has_children = true;
+                let name = child.tag_name().name();
+                let value = self.element(child);
+                match object.get_mut(name) {
+                    Some(Value::Array(items)) => items.push(value),
+                    Some(existing) => {
+                        let first = existing.take();
+                        *existing = Value::Array(vec![first, value]);
+                    }
+                    None if self.mapping.array_elements.iter().any(|element| element == name) => {
+                        object.insert(name.to_string(), Value::Array(vec![value]));
+                    }
+                    None => {
+                        object.insert(name.to_string(), value);
+                    }
+                }
+            } else if let Some(chunk) = child.text() {
+                text.push_str(chunk);
+            }
+        }
+
+        let text = text.trim();
+        if object.is_empty() && !has_children {
+            return self.scalar(text);
+        }
+        if !text.is_empty() {
+            object.insert(self.mapping.text_key.clone(), self.scalar(text));
+        }
+        Value::Object(object)
+    }
+}
+
+impl BodyCodec for XmlCodec {
+    fn decode(&self, body: &[u8]) -> Result<Value, String> {
+        let text = std::str::from_utf8(body).map_err(|e| e.to_string())?;
+        let document = roxmltree::Document::parse(text).map_err(|e| e.to_string())?;
+        let root = document.root_element();
+        let value = self.element(root);
+        if self.mapping.include_root {
+            Ok(json!({ root.tag_name().name(): value }))
+        } else {
+            Ok(value)
+        }
+    }
+}
+
+/// Body codecs keyed by media type
+///
+/// Routes that take a request body accept every registered media type in addition to
+/// JSON, form and multipart bodies. Decoded bodies are handed on as JSON, so schema
+/// validation and handlers see the same value whatever the wire format.
+#[derive(Clone, Default)]
+pub struct BodyCodecRegistry {
+    codecs: BTreeMap<String, Arc<dyn BodyCodec>>,
+}
+
+impl std::fmt::Debug for BodyCodecRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.codecs.keys()).finish()
+    }
+}
+
+impl BodyCodecRegistry {
+    /// Create a registry without codecs
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a registry with the MessagePack, CBOR, XML and NDJSON codecs
+    pub fn with_builtin_codecs() -> Self {
+        let mut registry = Self::new();
+        registry.register("application/msgpack", Arc::new(MessagePackCodec));
+        registry.register("application/x-msgpack", Arc::new(MessagePackCodec));
+        registry.register("application/cbor", Arc::new(CborCodec));
+        registry.register("application/xml", Arc::new(XmlCodec::default()));
+        registry.register("text/xml", Arc::new(XmlCodec::default()));
+        registry.register("application/x-ndjson", Arc::new(NdjsonCodec));
+        registry
+    }
+
+    /// Register `codec` for `media_type`, replacing any codec registered for it
+    pub fn register(&mut self, media_type: impl Into<String>, codec: Arc<dyn BodyCodec>) {
+        self.codecs.insert(media_type.into().to_ascii_lowercase(), codec);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.codecs.is_empty()
+    }
+
+    /// Registered media types, in sorted order
+    pub fn media_types(&self) -> impl Iterator<Item = &str> {
+        self.codecs.keys().map(String::as_str)
+    }
+
+    /// Codec for a `Content-Type` value
+    ///
+    /// Structured syntax suffixes fall back to the base type, so
+    /// `application/vnd.acme+cbor` uses the `application/cbor` codec.
+    pub fn for_content_type(&self, content_type: &str) -> Option<(&str, &Arc<dyn BodyCodec>)> {
+        let token = std::str::from_utf8(token_before_semicolon(content_type.as_bytes()))
+            .ok()?
+            .to_ascii_lowercase();
+        if let Some((media_type, codec)) = self.codecs.get_key_value(&token) {
+            return Some((media_type, codec));
+        }
+        let (_, suffix) = token.rsplit_once('+')?;
+        self.codecs
+            .get_key_value(&format!("application/{}", suffix))
+            .map(|(media_type, codec)| (media_type.as_str(), codec))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -887,4 +1125,44 @@ mod tests {
         assert!(result.is_err(), "XML must still be rejected on JSON routes");
         assert_eq!(result.unwrap_err().status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
     }
+
+    #[test]
+    fn body_codec_registry_matches_structured_suffixes() {
+        let registry = BodyCodecRegistry::with_builtin_codecs();
+        let (media_type, _) = registry
+            .for_content_type("Application/Vnd.Acme.Order+XML; charset=utf-8")
+            .expect("xml suffix");
+        assert_eq!(media_type, "application/xml");
+        assert!(registry.for_content_type("application/json").is_none());
+        assert!(BodyCodecRegistry::new().for_content_type("application/cbor").is_none());
+    }
+
+    #[test]
+    fn xml_codec_maps_repeated_elements_attributes_and_text() {
+        let codec = XmlCodec::default();
+        let value = codec
+            .decode(br#"<order><line sku="a">2</line><line sku="b">1</line><note lang="en">fragile</note><zip>007</zip></order>"#)
+            .unwrap();
+        assert_eq!(
+            value,
+            json!({
+                "line": [{"@sku": "a", "#text": 2}, {"@sku": "b", "#text": 1}],
+                "note": {"@lang": "en", "#text": "fragile"},
+                "zip": 7
+            })
+        );
+
+        let verbatim = XmlCodec::new(XmlMapping {
+            coerce_scalars: false,
+            include_root: true,
+            ..Default::default()
+        });
+        assert_eq!(verbatim.decode(b"<zip>007</zip>").unwrap(), json!({"zip": "007"}));
+    }
+
+    #[test]
+    fn ndjson_codec_reports_offending_line() {
+        let err = NdjsonCodec.decode(b"{\"a\": 1}\n{oops}\n").unwrap_err();
+        assert!(err.starts_with("line 2:"), "{err}");
+    }
 }
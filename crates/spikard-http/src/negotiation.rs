@@ -0,0 +1,369 @@
+//! Response content negotiation driven by `Accept` (RFC 9110 section 12.5.1).
+//!
+//! Handlers produce JSON. When a [`ResponseEncoderRegistry`] is configured,
+//! [`negotiation_middleware`] re-encodes `application/json` responses into the
+//! media type the client prefers, weighing `Accept` q-values against JSON and the
+//! registered encoders. Requests that accept none of them get `406 Not Acceptable`,
+//! and negotiated responses carry `Vary: Accept`.
+
+use axum::{
+    body::Body,
+    extract::{Request, State},
+    http::{HeaderMap, HeaderValue, StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use crate::{CONTENT_TYPE_PROBLEM_JSON, CborCodec, MessagePackCodec, ProblemDetails};
+
+/// Standard type URI for unacceptable `Accept` headers (406)
+pub const TYPE_NOT_ACCEPTABLE: &str = "https://spikard.dev/errors/not-acceptable";
+
+const JSON: &str = "application/json";
+
+/// Encodes JSON response values into another media type
+pub trait ResponseEncoder: Send + Sync {
+    /// Encode a complete response body; errors mean the value has no representation
+    fn encode(&self, value: &Value) -> Result<Vec<u8>, String>;
+}
+
+impl std::fmt::Debug for dyn ResponseEncoder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("ResponseEncoder")
+    }
+}
+
+impl ResponseEncoder for MessagePackCodec {
+    fn encode(&self, value: &Value) -> Result<Vec<u8>, String> {
+        rmp_serde::to_vec_named(value).map_err(|e| e.to_string())
+    }
+}
+
+impl ResponseEncoder for CborCodec {
+    fn encode(&self, value: &Value) -> Result<Vec<u8>, String> {
+        let mut out = Vec::new();
+        ciborium::into_writer(value, &mut out).map_err(|e| e.to_string())?;
+        Ok(out)
+    }
+}
+
+/// `application/yaml` responses
+#[derive(Debug, Clone, Copy, Default)]
+pub struct YamlEncoder;
+
+impl ResponseEncoder for YamlEncoder {
+    fn encode(&self, value: &Value) -> Result<Vec<u8>, String> {
+        serde_norway::to_string(value)
+            .map(String::into_bytes)
+            .map_err(|e| e.to_string())
+    }
+}
+
+/// `text/csv` responses for objects and arrays of objects
+///
+/// Columns are the object keys in order of first appearance; nested values are
+/// written as JSON text.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CsvEncoder;
+
+impl CsvEncoder {
+    fn field(value: Option<&Value>) -> String {
+        let text = match value {
+            None | Some(Value::Null) => String::new(),
+            Some(Value::String(s)) => s.clone(),
+            Some(other) => other.to_string(),
+        };
+        if text.contains([',', '"', '\r', '\n']) {
+            format!("\"{}\"", text.replace('"', "\"\""))
+        } else {
+            text
+        }
+    }
+}
+
+impl ResponseEncoder for CsvEncoder {
+    fn encode(&self, value: &Value) -> Result<Vec<u8>, String> {
+        let rows: Vec<&serde_json::Map<String, Value>> = match value {
+            Value::Object(row) => vec![row],
+            Value::Array(items) => items
+                .iter()
+                .map(|item| item.as_object().ok_or("CSV rows must be objects"))
+                .collect::<Result<_, _>>()?,
+            _ => return Err("CSV needs an object or an array of objects".to_string()),
+        };
+
+        let mut columns: Vec<&str> = Vec::new();
+        for row in &rows {
+            for key in row.keys() {
+                if !columns.contains(&key.as_str()) {
+                    columns.push(key);
+                }
+            }
+        }
+
+        let mut out = String::new();
+        let header: Vec<String> = columns
+            .iter()
+            .map(|column| Self::field(Some(&Value::String((*column).to_string()))))
+            .collect();
+        out.push_str(&header.join(","));
+        out.push_str("\r\n");
+        for row in rows {
+            let fields: Vec<String> = columns.iter().map(|column| Self::field(row.get(*column))).collect();
+            out.push_str(&fields.join(","));
+            out.push_str("\r\n");
+        }
+        Ok(out.into_bytes())
+    }
+}
+
+/// Response encoders keyed by media type
+///
+/// `application/json` is always available and preferred on ties.
+#[derive(Clone, Default)]
+pub struct ResponseEncoderRegistry {
+    encoders: BTreeMap<String, Arc<dyn ResponseEncoder>>,
+}
+
+impl std::fmt::Debug for ResponseEncoderRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.encoders.keys()).finish()
+    }
+}
+
+impl ResponseEncoderRegistry {
+    /// Create a registry without encoders
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a registry with the MessagePack, CBOR, YAML and CSV encoders
+    pub fn with_builtin_encoders() -> Self {
+        let mut registry = Self::new();
+        registry.register("application/msgpack", Arc::new(MessagePackCodec));
+        registry.register("application/cbor", Arc::new(CborCodec));
+        registry.register("application/yaml", Arc::new(YamlEncoder));
+        registry.register("text/csv", Arc::new(CsvEncoder));
+        registry
+    }
+
+    /// Register `encoder` for `media_type`, replacing any encoder registered for it
+    pub fn register(&mut self, media_type: impl Into<String>, encoder: Arc<dyn ResponseEncoder>) {
+        self.encoders.insert(media_type.into().to_ascii_lowercase(), encoder);
+    }
+
+    /// Registered media types, in sorted order
+    pub fn media_types(&self) -> impl Iterator<Item = &str> {
+        self.encoders.keys().map(String::as_str)
+    }
+
+    /// Media types the client accepts, best first, or `None` without an `Accept` header
+    fn preferred(&self, headers: &HeaderMap) -> Option<Vec<&str>> {
+        let ranges = accepted_ranges(headers)?;
+        let mut offers: Vec<(&str, f32)> = std::iter::once(JSON)
+            .chain(self.media_types())
+            .filter_map(|offer| quality(&ranges, offer).map(|q| (offer, q)))
+            .filter(|(_, q)| *q > 0.0)
+            .collect();
+        offers.sort_by(|a, b| b.1.total_cmp(&a.1));
+        Some(offers.into_iter().map(|(offer, _)| offer).collect())
+    }
+}
+
+/// One `Accept` media range
+#[derive(Debug, PartialEq)]
+struct MediaRange {
+    kind: String,
+    subtype: String,
+    q: f32,
+}
+
+fn accepted_ranges(headers: &HeaderMap) -> Option<Vec<MediaRange>> {
+    let mut values = headers.get_all(header::ACCEPT).iter().peekable();
+    values.peek()?;
+    let ranges = values
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .filter_map(|range| {
+            let mut params = range.split(';');
+            let media_type = params.next()?.trim().to_ascii_lowercase();
+            let (kind, subtype) = media_type.split_once('/')?;
+            let q = params
+                .filter_map(|param| param.split_once('='))
+                .find(|(name, _)| name.trim().eq_ignore_ascii_case("q"))
+                .map_or(1.0, |(_, q)| q.trim().parse::<f32>().unwrap_or(0.0).clamp(0.0, 1.0));
+            Some(MediaRange {
+                kind: kind.to_string(),
+                subtype: subtype.to_string(),
+                q,
+            })
+        })
+        .collect();
+    Some(ranges)
+}
+
+/// Weight of `offer` under the most specific matching range
+fn quality(ranges: &[MediaRange], offer: &str) -> Option<f32> {
+    let (kind, subtype) = offer.split_once('/')?;
+    ranges
+        .iter()
+        .filter_map(|range| {
+            let specificity = match (range.kind.as_str(), range.subtype.as_str()) {
+                ("*", "*") => 0,
+                (k, "*") if k == kind => 1,
+                (k, s) if k == kind && s == subtype => 2,
+                _ => return None,
+            };
+            Some((specificity, range.q))
+        })
+        .max_by_key(|(specificity, _)| *specificity)
+        .map(|(_, q)| q)
+}
+
+fn not_acceptable(registry: &ResponseEncoderRegistry) -> Response {
+    let available: Vec<&str> = std::iter::once(JSON).chain(registry.media_types()).collect();
+    let problem = ProblemDetails::new(TYPE_NOT_ACCEPTABLE, "Not Acceptable", StatusCode::NOT_ACCEPTABLE)
+        .with_detail(format!(
+            "None of the accepted media types can be produced; available: {}",
+            available.join(", ")
+        ))
+        .with_extension("available", serde_json::json!(available));
+    let body = problem.to_json().unwrap_or_else(|_| "{}".to_string());
+    (
+        StatusCode::NOT_ACCEPTABLE,
+        [(header::CONTENT_TYPE, CONTENT_TYPE_PROBLEM_JSON)],
+        Body::from(body),
+    )
+        .into_response()
+}
+
+fn add_vary_accept(headers: &mut HeaderMap) {
+    let listed = headers
+        .get_all(header::VARY)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|name| matches!(name.trim(), "*") || name.trim().eq_ignore_ascii_case("accept"));
+    if !listed {
+        headers.append(header::VARY, HeaderValue::from_static("accept"));
+    }
+}
+
+/// Encode JSON responses in the media type preferred by the request's `Accept` header
+pub(crate) async fn negotiation_middleware(
+    State(registry): State<Arc<ResponseEncoderRegistry>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let preferred = registry.preferred(request.headers());
+    let mut response = next.run(request).await;
+
+    let is_json = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.split(';').next())
+        .is_some_and(|media_type| media_type.trim().eq_ignore_ascii_case(JSON));
+    if !is_json {
+        return response;
+    }
+    add_vary_accept(response.headers_mut());
+
+    let Some(preferred) = preferred else {
+        return response;
+    };
+    match preferred.first() {
+        None => return not_acceptable(&registry),
+        Some(&JSON) => return response,
+        Some(_) => {}
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let bytes = match axum::body::to_bytes(body, usize::MAX).await {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            tracing::warn!("Failed to buffer response body for encoding: {}", err);
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+    let Ok(value) = serde_json::from_slice::<Value>(&bytes) else {
+        return Response::from_parts(parts, Body::from(bytes));
+    };
+
+    for media_type in preferred {
+        if media_type == JSON {
+            return Response::from_parts(parts, Body::from(bytes));
+        }
+        let Some(encoder) = registry.encoders.get(media_type) else {
+            continue;
+        };
+        match encoder.encode(&value) {
+            Ok(encoded) => {
+                parts.headers.insert(
+                    header::CONTENT_TYPE,
+                    HeaderValue::from_str(media_type).unwrap_or_else(|_| HeaderValue::from_static(JSON)),
+                );
+                parts.headers.remove(header::CONTENT_LENGTH);
+                // Each representation needs its own validator
+                if let Some(etag) = parts.headers.get(header::ETAG).and_then(|value| value.to_str().ok())
+                    && let Some(opaque) = etag.strip_suffix('"')
+                    && let Ok(tagged) = HeaderValue::from_str(&format!(
+                        "{}-{}\"",
+                        opaque,
+                        media_type.rsplit('/').next().unwrap_or(media_type)
+                    ))
+                {
+                    parts.headers.insert(header::ETAG, tagged);
+                }
+                return Response::from_parts(parts, Body::from(encoded));
+            }
+            Err(err) => tracing::debug!("Cannot encode response as {}: {}", media_type, err),
+        }
+    }
+    not_acceptable(&registry)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn accept(value: &'static str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::ACCEPT, HeaderValue::from_static(value));
+        headers
+    }
+
+    #[test]
+    fn preference_follows_q_values_and_specificity() {
+        let registry = ResponseEncoderRegistry::with_builtin_encoders();
+        assert_eq!(registry.preferred(&HeaderMap::new()), None);
+        assert_eq!(
+            registry
+                .preferred(&accept("application/json;q=0.5, application/msgpack"))
+                .unwrap()
+                .first(),
+            Some(&"application/msgpack")
+        );
+        assert_eq!(
+            registry.preferred(&accept("*/*")).unwrap().first(),
+            Some(&JSON),
+            "JSON wins ties"
+        );
+        assert_eq!(
+            registry.preferred(&accept("text/*, text/csv;q=0")).unwrap(),
+            Vec::<&str>::new()
+        );
+        assert!(registry.preferred(&accept("text/html")).unwrap().is_empty());
+    }
+
+    #[test]
+    fn csv_encoder_writes_header_from_all_rows() {
+        let value = serde_json::json!([{"id": 1, "name": "a, b"}, {"id": 2, "note": "say \"hi\""}]);
+        let csv = String::from_utf8(CsvEncoder.encode(&value).unwrap()).unwrap();
+        assert_eq!(csv, "id,name,note\r\n1,\"a, b\",\r\n2,,\"say \"\"hi\"\"\"\r\n");
+        assert!(CsvEncoder.encode(&serde_json::json!([1, 2])).is_err());
+    }
+}
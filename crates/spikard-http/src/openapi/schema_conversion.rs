@@ -34,35 +34,32 @@ pub fn json_value_to_schema(value: &serde_json::Value) -> Result<RefOr<Schema>,
                     let items_schema = json_value_to_schema(items)?;
                     array_schema = array_schema.items(items_schema);
                 }
+                array_schema = array_schema
+                    .min_items(usize_keyword(value, "minItems"))
+                    .max_items(usize_keyword(value, "maxItems"));
 
                 Ok(RefOr::T(Schema::Array(array_schema.build())))
             }
-            "string" => {
-                let mut schema_type = utoipa::openapi::schema::Type::String;
-
-                if let Some(format) = value.get("format").and_then(|f| f.as_str()) {
-                    match format {
-                        "date-time" => schema_type = utoipa::openapi::schema::Type::String,
-                        "date" => schema_type = utoipa::openapi::schema::Type::String,
-                        "email" => schema_type = utoipa::openapi::schema::Type::String,
-                        "uri" => schema_type = utoipa::openapi::schema::Type::String,
-                        _ => {}
-                    }
-                }
-
-                Ok(RefOr::T(Schema::Object(
-                    utoipa::openapi::ObjectBuilder::new().schema_type(schema_type).build(),
-                )))
-            }
+            "string" => Ok(RefOr::T(Schema::Object(
+                with_constraints(
+                    utoipa::openapi::ObjectBuilder::new().schema_type(utoipa::openapi::schema::Type::String),
+                    value,
+                )
+                .build(),
+            ))),
             "integer" => Ok(RefOr::T(Schema::Object(
-                utoipa::openapi::ObjectBuilder::new()
-                    .schema_type(utoipa::openapi::schema::Type::Integer)
-                    .build(),
+                with_constraints(
+                    utoipa::openapi::ObjectBuilder::new().schema_type(utoipa::openapi::schema::Type::Integer),
+                    value,
+                )
+                .build(),
             ))),
             "number" => Ok(RefOr::T(Schema::Object(
-                utoipa::openapi::ObjectBuilder::new()
-                    .schema_type(utoipa::openapi::schema::Type::Number)
-                    .build(),
+                with_constraints(
+                    utoipa::openapi::ObjectBuilder::new().schema_type(utoipa::openapi::schema::Type::Number),
+                    value,
+                )
+                .build(),
             ))),
             "boolean" => Ok(RefOr::T(Schema::Object(
                 utoipa::openapi::ObjectBuilder::new()
@@ -76,6 +73,45 @@ pub fn json_value_to_schema(value: &serde_json::Value) -> Result<RefOr<Schema>,
     }
 }
 
+/// Carry the validation keywords of a scalar schema over, so the document describes what
+/// the request validator enforces.
+fn with_constraints(
+    builder: utoipa::openapi::ObjectBuilder,
+    value: &serde_json::Value,
+) -> utoipa::openapi::ObjectBuilder {
+    builder
+        .format(
+            value
+                .get("format")
+                .and_then(|f| f.as_str())
+                .map(|format| utoipa::openapi::SchemaFormat::Custom(format.to_string())),
+        )
+        .min_length(usize_keyword(value, "minLength"))
+        .max_length(usize_keyword(value, "maxLength"))
+        .pattern(value.get("pattern").and_then(|p| p.as_str()))
+        .minimum(number_keyword(value, "minimum"))
+        .maximum(number_keyword(value, "maximum"))
+        .exclusive_minimum(number_keyword(value, "exclusiveMinimum"))
+        .exclusive_maximum(number_keyword(value, "exclusiveMaximum"))
+        .enum_values(value.get("enum").and_then(|e| e.as_array()).cloned())
+}
+
+fn usize_keyword(value: &serde_json::Value, keyword: &str) -> Option<usize> {
+    value
+        .get(keyword)
+        .and_then(|v| v.as_u64())
+        .and_then(|v| usize::try_from(v).ok())
+}
+
+fn number_keyword(value: &serde_json::Value, keyword: &str) -> Option<utoipa::Number> {
+    let number = value.get(keyword)?;
+    number
+        .as_i64()
+        .and_then(|n| isize::try_from(n).ok())
+        .map(utoipa::Number::Int)
+        .or_else(|| number.as_f64().map(utoipa::Number::Float))
+}
+
 /// Convert JSON Schema to OpenAPI RequestBody
 pub fn json_schema_to_request_body(
     schema: &serde_json::Value,
@@ -1307,6 +1343,34 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_validation_keywords_are_preserved() {
+        let schema_json = serde_json::json!({
+            "type": "object",
+            "properties": {
+                "code": {"type": "string", "minLength": 3, "maxLength": 8, "pattern": "^[A-Z]+$"},
+                "email": {"type": "string", "format": "email"},
+                "level": {"type": "integer", "minimum": 1, "exclusiveMaximum": 10},
+                "ratio": {"type": "number", "maximum": 0.5},
+                "kind": {"type": "string", "enum": ["a", "b"]},
+                "tags": {"type": "array", "items": {"type": "string"}, "minItems": 1, "maxItems": 4}
+            }
+        });
+
+        let converted = serde_json::to_value(json_value_to_schema(&schema_json).unwrap()).unwrap();
+        let properties = &converted["properties"];
+        assert_eq!(properties["code"]["minLength"], 3);
+        assert_eq!(properties["code"]["maxLength"], 8);
+        assert_eq!(properties["code"]["pattern"], "^[A-Z]+$");
+        assert_eq!(properties["email"]["format"], "email");
+        assert_eq!(properties["level"]["minimum"], 1);
+        assert_eq!(properties["level"]["exclusiveMaximum"], 10);
+        assert_eq!(properties["ratio"]["maximum"], 0.5);
+        assert_eq!(properties["kind"]["enum"], serde_json::json!(["a", "b"]));
+        assert_eq!(properties["tags"]["minItems"], 1);
+        assert_eq!(properties["tags"]["maxItems"], 4);
+    }
+
     #[test]
     fn test_object_with_pattern_properties() {
         let schema_json = serde_json::json!({
@@ -1,13 +1,14 @@
 //! OpenAPI specification generation and assembly
 
 use crate::RouteMetadata;
+use utoipa::openapi::Content;
 use utoipa::openapi::HttpMethod;
 use utoipa::openapi::security::SecurityScheme;
 use utoipa::openapi::{Components, Info, OpenApi, OpenApiBuilder, PathItem, Paths, RefOr, Response, Responses};
 
 /// Convert route to OpenAPI PathItem
-fn route_to_path_item(route: &RouteMetadata) -> Result<PathItem, String> {
-    let operation = route_to_operation(route)?;
+fn route_to_path_item(route: &RouteMetadata, media_types: &MediaTypes<'_>) -> Result<PathItem, String> {
+    let operation = route_to_operation(route, media_types)?;
 
     let http_method = match route.method.to_uppercase().as_str() {
         "GET" => HttpMethod::Get,
@@ -25,8 +26,30 @@ fn route_to_path_item(route: &RouteMetadata) -> Result<PathItem, String> {
     Ok(path_item)
 }
 
+/// Media types offered besides JSON
+#[derive(Debug, Default)]
+struct MediaTypes<'a> {
+    /// Request bodies accepted through body codecs
+    request: Vec<&'a str>,
+    /// Responses produced through `Accept` negotiation
+    response: Vec<&'a str>,
+}
+
+/// Entries repeating the `application/json` content under each of `media_types`
+fn alternatives(json_content: Option<&Content>, media_types: &[&str]) -> Vec<(String, Content)> {
+    json_content.map_or_else(Vec::new, |content| {
+        media_types
+            .iter()
+            .map(|media_type| ((*media_type).to_string(), content.clone()))
+            .collect()
+    })
+}
+
 /// Convert route to OpenAPI Operation
-fn route_to_operation(route: &RouteMetadata) -> Result<utoipa::openapi::path::Operation, String> {
+fn route_to_operation(
+    route: &RouteMetadata,
+    media_types: &MediaTypes<'_>,
+) -> Result<utoipa::openapi::path::Operation, String> {
     let mut operation = utoipa::openapi::path::Operation::new();
 
     if let Some(param_schema) = &route.parameter_schema {
@@ -42,13 +65,17 @@ fn route_to_operation(route: &RouteMetadata) -> Result<utoipa::openapi::path::Op
     }
 
     if let Some(request_schema) = &route.request_schema {
-        let request_body = crate::openapi::schema_conversion::json_schema_to_request_body(request_schema)?;
+        let mut request_body = crate::openapi::schema_conversion::json_schema_to_request_body(request_schema)?;
+        let extra = alternatives(request_body.content.get("application/json"), &media_types.request);
+        request_body.content.extend(extra);
         operation.request_body = Some(request_body);
     }
 
     let mut responses = Responses::new();
     if let Some(response_schema) = &route.response_schema {
-        let response = crate::openapi::schema_conversion::json_schema_to_response(response_schema)?;
+        let mut response = crate::openapi::schema_conversion::json_schema_to_response(response_schema)?;
+        let extra = alternatives(response.content.get("application/json"), &media_types.response);
+        response.content.extend(extra);
         responses.responses.insert("200".to_string(), RefOr::T(response));
     } else {
         responses
@@ -57,9 +84,49 @@ fn route_to_operation(route: &RouteMetadata) -> Result<utoipa::openapi::path::Op
     }
     operation.responses = responses;
 
+    if !route.tags.is_empty() {
+        operation.tags = Some(route.tags.clone());
+    }
+
+    if let Some(auth) = &route.auth {
+        let mut names = Vec::new();
+        if auth.jwt.is_some() {
+            names.push(BEARER_AUTH);
+        }
+        if auth.api_key.is_some() {
+            names.push(API_KEY_AUTH);
+        }
+        if let Some((first, rest)) = names.split_first() {
+            let requirement = rest.iter().fold(
+                utoipa::openapi::security::SecurityRequirement::new(*first, Vec::<String>::new()),
+                |requirement, name| requirement.add(*name, Vec::<String>::new()),
+            );
+            operation.security = Some(vec![requirement]);
+        }
+    }
+
     Ok(operation)
 }
 
+/// Security scheme name used for JWT bearer authentication
+const BEARER_AUTH: &str = "bearerAuth";
+/// Security scheme name used for API key authentication
+const API_KEY_AUTH: &str = "apiKeyAuth";
+
+fn jwt_security_scheme() -> SecurityScheme {
+    SecurityScheme::Http(
+        utoipa::openapi::security::HttpBuilder::new()
+            .scheme(utoipa::openapi::security::HttpAuthScheme::Bearer)
+            .bearer_format("JWT")
+            .build(),
+    )
+}
+
+fn api_key_security_scheme(header_name: &str) -> SecurityScheme {
+    use utoipa::openapi::security::ApiKey;
+    SecurityScheme::ApiKey(ApiKey::Header(utoipa::openapi::security::ApiKeyValue::new(header_name)))
+}
+
 /// Assemble OpenAPI specification from routes with auto-detection of security schemes
 pub fn assemble_openapi_spec(
     routes: &[RouteMetadata],
@@ -109,9 +176,20 @@ pub fn assemble_openapi_spec(
         )
     };
 
+    let media_types = MediaTypes {
+        request: server_config
+            .and_then(|cfg| cfg.body_codecs.as_deref())
+            .map(|codecs| codecs.media_types().collect())
+            .unwrap_or_default(),
+        response: server_config
+            .and_then(|cfg| cfg.response_encoders.as_deref())
+            .map(|encoders| encoders.media_types().collect())
+            .unwrap_or_default(),
+    };
+
     let mut paths = Paths::new();
     for route in routes {
-        let path_item = route_to_path_item(route)?;
+        let path_item = route_to_path_item(route, &media_types)?;
         paths.paths.insert(route.path.clone(), path_item);
     }
 
@@ -120,30 +198,32 @@ pub fn assemble_openapi_spec(
 
     if let Some(server_cfg) = server_config {
         if let Some(_jwt_cfg) = &server_cfg.jwt_auth {
-            let jwt_scheme = SecurityScheme::Http(
-                utoipa::openapi::security::HttpBuilder::new()
-                    .scheme(utoipa::openapi::security::HttpAuthScheme::Bearer)
-                    .bearer_format("JWT")
-                    .build(),
-            );
-            components.add_security_scheme("bearerAuth", jwt_scheme);
+            components.add_security_scheme(BEARER_AUTH, jwt_security_scheme());
 
-            let security_req = utoipa::openapi::security::SecurityRequirement::new("bearerAuth", Vec::<String>::new());
+            let security_req = utoipa::openapi::security::SecurityRequirement::new(BEARER_AUTH, Vec::<String>::new());
             global_security.push(security_req);
         }
 
         if let Some(api_key_cfg) = &server_cfg.api_key_auth {
-            use utoipa::openapi::security::ApiKey;
-            let api_key_scheme = SecurityScheme::ApiKey(ApiKey::Header(utoipa::openapi::security::ApiKeyValue::new(
-                &api_key_cfg.header_name,
-            )));
-            components.add_security_scheme("apiKeyAuth", api_key_scheme);
+            components.add_security_scheme(API_KEY_AUTH, api_key_security_scheme(&api_key_cfg.header_name));
 
-            let security_req = utoipa::openapi::security::SecurityRequirement::new("apiKeyAuth", Vec::<String>::new());
+            let security_req = utoipa::openapi::security::SecurityRequirement::new(API_KEY_AUTH, Vec::<String>::new());
             global_security.push(security_req);
         }
     }
 
+    // Routes that carry their own authentication reference the same schemes
+    for auth in routes.iter().filter_map(|route| route.auth.as_ref()) {
+        if auth.jwt.is_some() && !components.security_schemes.contains_key(BEARER_AUTH) {
+            components.add_security_scheme(BEARER_AUTH, jwt_security_scheme());
+        }
+        if let Some(api_key_cfg) = &auth.api_key
+            && !components.security_schemes.contains_key(API_KEY_AUTH)
+        {
+            components.add_security_scheme(API_KEY_AUTH, api_key_security_scheme(&api_key_cfg.header_name));
+        }
+    }
+
     if !config.security_schemes.is_empty() {
         for (name, scheme_info) in &config.security_schemes {
             let scheme = crate::openapi::security_scheme_info_to_openapi(scheme_info);
@@ -192,6 +272,11 @@ mod tests {
             static_response: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            tags: Vec::new(),
+            auth: None,
         }
     }
 
@@ -221,49 +306,49 @@ mod tests {
     #[test]
     fn test_route_to_path_item_get() {
         let route = make_route("GET", "/users");
-        let result = route_to_path_item(&route);
+        let result = route_to_path_item(&route, &MediaTypes::default());
         assert!(result.is_ok());
     }
 
     #[test]
     fn test_route_to_path_item_post() {
         let route = make_route("POST", "/users");
-        let result = route_to_path_item(&route);
+        let result = route_to_path_item(&route, &MediaTypes::default());
         assert!(result.is_ok());
     }
 
     #[test]
     fn test_route_to_path_item_put() {
         let route = make_route("PUT", "/users/123");
-        let result = route_to_path_item(&route);
+        let result = route_to_path_item(&route, &MediaTypes::default());
         assert!(result.is_ok());
     }
 
     #[test]
     fn test_route_to_path_item_patch() {
         let route = make_route("PATCH", "/users/123");
-        let result = route_to_path_item(&route);
+        let result = route_to_path_item(&route, &MediaTypes::default());
         assert!(result.is_ok());
     }
 
     #[test]
     fn test_route_to_path_item_delete() {
         let route = make_route("DELETE", "/users/123");
-        let result = route_to_path_item(&route);
+        let result = route_to_path_item(&route, &MediaTypes::default());
         assert!(result.is_ok());
     }
 
     #[test]
     fn test_route_to_path_item_head() {
         let route = make_route("HEAD", "/users");
-        let result = route_to_path_item(&route);
+        let result = route_to_path_item(&route, &MediaTypes::default());
         assert!(result.is_ok());
     }
 
     #[test]
     fn test_route_to_path_item_options() {
         let route = make_route("OPTIONS", "/users");
-        let result = route_to_path_item(&route);
+        let result = route_to_path_item(&route, &MediaTypes::default());
         assert!(result.is_ok());
     }
 
@@ -272,14 +357,14 @@ mod tests {
         let route_lower = make_route("get", "/users");
         let route_mixed = make_route("GeT", "/users");
 
-        assert!(route_to_path_item(&route_lower).is_ok());
-        assert!(route_to_path_item(&route_mixed).is_ok());
+        assert!(route_to_path_item(&route_lower, &MediaTypes::default()).is_ok());
+        assert!(route_to_path_item(&route_mixed, &MediaTypes::default()).is_ok());
     }
 
     #[test]
     fn test_route_to_path_item_unsupported_method() {
         let route = make_route("CONNECT", "/users");
-        let result = route_to_path_item(&route);
+        let result = route_to_path_item(&route, &MediaTypes::default());
         assert!(result.is_err());
         if let Err(err) = result {
             assert!(err.contains("Unsupported HTTP method"));
@@ -525,7 +610,7 @@ mod tests {
     #[test]
     fn test_route_to_operation_default_response() {
         let route = make_route("GET", "/health");
-        let result = route_to_operation(&route);
+        let result = route_to_operation(&route, &MediaTypes::default());
 
         assert!(result.is_ok());
         let operation = result.unwrap();
@@ -589,14 +674,14 @@ mod tests {
     #[test]
     fn test_route_to_path_item_lowercase_method() {
         let route = make_route("post", "/items");
-        let result = route_to_path_item(&route);
+        let result = route_to_path_item(&route, &MediaTypes::default());
         assert!(result.is_ok());
     }
 
     #[test]
     fn test_route_to_path_item_mixed_case_method() {
         let route = make_route("PoSt", "/items");
-        let result = route_to_path_item(&route);
+        let result = route_to_path_item(&route, &MediaTypes::default());
         assert!(result.is_ok());
     }
 
@@ -662,9 +747,14 @@ mod tests {
             static_response: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            tags: Vec::new(),
+            auth: None,
         };
 
-        let result = route_to_operation(&route);
+        let result = route_to_operation(&route, &MediaTypes::default());
         assert!(result.is_ok());
         let operation = result.unwrap();
         assert!(operation.request_body.is_none());
@@ -0,0 +1,402 @@
+//! Trusted reverse proxies and the real client address
+//!
+//! Behind a load balancer the socket peer is the proxy, not the client. When the peer
+//! is a trusted proxy, the client IP, scheme and host are taken from the `Forwarded`
+//! header (RFC 7239), or from `X-Forwarded-For`, `X-Forwarded-Proto` and
+//! `X-Forwarded-Host` when `Forwarded` is absent. Forwarding headers sent by untrusted
+//! peers are ignored, so clients cannot spoof their address.
+//!
+//! The resolved [`ClientInfo`] is stored in the request extensions for every route and
+//! copied into [`RequestData`](crate::RequestData) for handlers.
+
+use axum::{
+    extract::{ConnectInfo, Request, State},
+    http::{HeaderMap, Uri, header},
+    middleware::Next,
+    response::Response,
+};
+use serde::{Deserialize, Serialize};
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+
+const X_FORWARDED_FOR: &str = "x-forwarded-for";
+const X_FORWARDED_PROTO: &str = "x-forwarded-proto";
+const X_FORWARDED_HOST: &str = "x-forwarded-host";
+
+/// Which peers may set forwarding headers
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TrustedProxyConfig {
+    /// Proxy addresses or CIDR ranges (e.g. `"10.0.0.0/8"`, `"::1"`)
+    ///
+    /// Must be non-empty unless `trust_all` is set.
+    pub proxies: Vec<String>,
+    /// Maximum number of proxies in front of the server (None = walk every trusted hop)
+    pub hops: Option<usize>,
+    /// Treat every peer as a proxy, so only `hops` bounds the walk
+    ///
+    /// Any client can then set its own address; only use this when the server is reachable
+    /// solely through the proxies, and set `hops` to their number.
+    pub trust_all: bool,
+}
+
+/// Client as seen by the outermost trusted proxy
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientInfo {
+    /// Client IP address (None when the transport does not expose one)
+    pub ip: Option<IpAddr>,
+    /// Scheme the client used (`"http"` or `"https"`)
+    pub scheme: String,
+    /// Host the client addressed, including any port
+    pub host: Option<String>,
+}
+
+impl ClientInfo {
+    /// Origin the client addressed (e.g. `"https://api.example.com"`)
+    pub fn origin(&self) -> Option<String> {
+        self.host.as_ref().map(|host| format!("{}://{}", self.scheme, host))
+    }
+
+    /// Copy the client fields into the handler's request data
+    pub(crate) fn apply_to(&self, request_data: &mut crate::RequestData) {
+        request_data.client_ip = self.ip.map(|ip| ip.to_string());
+        request_data.scheme = Some(self.scheme.clone());
+        request_data.host = self.host.clone();
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct IpNetwork {
+    addr: IpAddr,
+    prefix: u8,
+}
+
+impl IpNetwork {
+    fn parse(value: &str) -> Result<Self, String> {
+        let invalid = || {
+            format!(
+                "Invalid trusted proxy '{}': expected an IP address or CIDR range",
+                value
+            )
+        };
+        let (addr, prefix) = match value.trim().split_once('/') {
+            Some((addr, prefix)) => (addr, Some(prefix)),
+            None => (value.trim(), None),
+        };
+        let addr: IpAddr = addr.parse().map_err(|_| invalid())?;
+        let max = if addr.is_ipv4() { 32 } else { 128 };
+        let prefix = match prefix {
+            Some(prefix) => prefix.parse::<u8>().ok().filter(|p| *p <= max).ok_or_else(invalid)?,
+            None => max,
+        };
+        Ok(Self {
+            addr: addr.to_canonical(),
+            prefix,
+        })
+    }
+
+    fn contains(&self, ip: IpAddr) -> bool {
+        match (self.addr, ip.to_canonical()) {
+            (IpAddr::V4(net), IpAddr::V4(ip)) => {
+                let mask = u32::MAX.checked_shl(32 - u32::from(self.prefix)).unwrap_or(0);
+                u32::from(net) & mask == u32::from(ip) & mask
+            }
+            (IpAddr::V6(net), IpAddr::V6(ip)) => {
+                let mask = u128::MAX.checked_shl(128 - u32::from(self.prefix)).unwrap_or(0);
+                u128::from(net) & mask == u128::from(ip) & mask
+            }
+            _ => false,
+        }
+    }
+}
+
+/// One forwarding hop: the address a proxy received the request from
+#[derive(Debug, Default)]
+struct Hop {
+    address: Option<IpAddr>,
+    proto: Option<String>,
+    host: Option<String>,
+}
+
+/// Resolves [`ClientInfo`] from the socket peer and forwarding headers
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ClientResolver {
+    proxies: Vec<IpNetwork>,
+    hops: Option<usize>,
+    trust_all: bool,
+    enabled: bool,
+}
+
+impl ClientResolver {
+    pub(crate) fn new(config: Option<&TrustedProxyConfig>) -> Result<Self, String> {
+        let Some(config) = config else {
+            return Ok(Self::default());
+        };
+        if config.trust_all && !config.proxies.is_empty() {
+            return Err("Trusted proxies: set either `proxies` or `trust_all`, not both".to_string());
+        }
+        if !config.trust_all && config.proxies.is_empty() {
+            return Err(
+                "Trusted proxies: `proxies` is empty; list the proxy addresses or set `trust_all` to trust every peer"
+                    .to_string(),
+            );
+        }
+        let proxies = config
+            .proxies
+            .iter()
+            .map(|proxy| IpNetwork::parse(proxy))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self {
+            enabled: config.hops != Some(0),
+            proxies,
+            hops: config.hops,
+            trust_all: config.trust_all,
+        })
+    }
+
+    fn trusts(&self, ip: IpAddr) -> bool {
+        self.trust_all || self.proxies.iter().any(|proxy| proxy.contains(ip))
+    }
+
+    pub(crate) fn resolve(&self, peer: Option<IpAddr>, uri: &Uri, headers: &HeaderMap) -> ClientInfo {
+        let direct = ClientInfo {
+            ip: peer,
+            scheme: uri.scheme_str().unwrap_or("http").to_ascii_lowercase(),
+            host: headers
+                .get(header::HOST)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string)
+                .or_else(|| uri.authority().map(|authority| authority.to_string())),
+        };
+        if !self.enabled || !peer.is_some_and(|peer| self.trusts(peer)) {
+            return direct;
+        }
+
+        let hops = forwarded_hops(headers);
+        let mut client = None;
+        for hop in hops.iter().rev().take(self.hops.unwrap_or(usize::MAX)) {
+            client = Some(hop);
+            match hop.address {
+                Some(address) if self.trusts(address) => {}
+                _ => break,
+            }
+        }
+
+        match client {
+            Some(hop) => ClientInfo {
+                ip: hop.address,
+                scheme: hop.proto.clone().unwrap_or(direct.scheme),
+                host: hop.host.clone().or(direct.host),
+            },
+            None => direct,
+        }
+    }
+}
+
+fn header_values(headers: &HeaderMap, name: &str) -> Vec<String> {
+    headers
+        .get_all(name)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(|item| item.trim().to_string())
+        .filter(|item| !item.is_empty())
+        .collect()
+}
+
+/// Parse a node from `X-Forwarded-For` or a `Forwarded` `for=` parameter.
+///
+/// Accepts `1.2.3.4`, `1.2.3.4:80`, `[::1]` and `[::1]:80`; obfuscated and `unknown`
+/// nodes yield `None`.
+fn parse_node(node: &str) -> Option<IpAddr> {
+    let node = node.trim().trim_matches('"');
+    if let Some(rest) = node.strip_prefix('[') {
+        return rest.split_once(']').and_then(|(addr, _)| addr.parse().ok());
+    }
+    node.parse()
+        .ok()
+        .or_else(|| node.parse::<SocketAddr>().ok().map(|addr| addr.ip()))
+}
+
+/// Forwarding hops ordered from the client towards the server
+fn forwarded_hops(headers: &HeaderMap) -> Vec<Hop> {
+    let forwarded = header_values(headers, header::FORWARDED.as_str());
+    if !forwarded.is_empty() {
+        return forwarded
+            .iter()
+            .map(|element| {
+                let mut hop = Hop::default();
+                for pair in element.split(';') {
+                    let Some((key, value)) = pair.split_once('=') else {
+                        continue;
+                    };
+                    let value = value.trim().trim_matches('"');
+                    match key.trim().to_ascii_lowercase().as_str() {
+                        "for" => hop.address = parse_node(value),
+                        "proto" => hop.proto = Some(value.to_ascii_lowercase()),
+                        "host" => hop.host = Some(value.to_string()),
+                        _ => {}
+                    }
+                }
+                hop
+            })
+            .collect();
+    }
+
+    let addresses = header_values(headers, X_FORWARDED_FOR);
+    let protos = header_values(headers, X_FORWARDED_PROTO);
+    let hosts = header_values(headers, X_FORWARDED_HOST);
+    // Proxies either append to these lists in step with X-Forwarded-For or overwrite them;
+    // in the latter case the value was set by the proxy closest to the server.
+    let aligned = |values: &[String], index: usize| {
+        if values.len() == addresses.len() {
+            values.get(index).cloned()
+        } else {
+            values.last().cloned()
+        }
+    };
+    addresses
+        .iter()
+        .enumerate()
+        .map(|(index, address)| Hop {
+            address: parse_node(address),
+            proto: aligned(&protos, index).map(|proto| proto.to_ascii_lowercase()),
+            host: aligned(&hosts, index),
+        })
+        .collect()
+}
+
+/// Store the resolved [`ClientInfo`] in the request extensions
+pub(crate) async fn client_info_middleware(
+    State(resolver): State<Arc<ClientResolver>>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let peer = request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|info| info.0.ip());
+    let client = resolver.resolve(peer, request.uri(), request.headers());
+    request.extensions_mut().insert(client);
+    next.run(request).await
+}
+
+/// Resolve the client of every request served by `router`
+///
+/// The server applies this to the routers it builds; use it for routers merged in
+/// afterwards (for example WebSocket and SSE routes).
+pub fn client_info_router(router: axum::Router, config: Option<&TrustedProxyConfig>) -> Result<axum::Router, String> {
+    let resolver = Arc::new(ClientResolver::new(config)?);
+    Ok(router.layer(axum::middleware::from_fn_with_state(resolver, client_info_middleware)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(pairs: &[(&str, &str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.append(
+                axum::http::HeaderName::from_bytes(name.as_bytes()).expect("name"),
+                value.parse().expect("value"),
+            );
+        }
+        headers
+    }
+
+    fn resolver(proxies: &[&str], hops: Option<usize>) -> ClientResolver {
+        ClientResolver::new(Some(&TrustedProxyConfig {
+            proxies: proxies.iter().map(|proxy| proxy.to_string()).collect(),
+            hops,
+            trust_all: proxies.is_empty(),
+        }))
+        .expect("resolver")
+    }
+
+    fn ip(value: &str) -> Option<IpAddr> {
+        Some(value.parse().expect("ip"))
+    }
+
+    #[test]
+    fn networks_match_by_prefix() {
+        let network = IpNetwork::parse("10.1.0.0/16").expect("cidr");
+        assert!(network.contains("10.1.200.3".parse().unwrap()));
+        assert!(!network.contains("10.2.0.1".parse().unwrap()));
+        assert!(network.contains("::ffff:10.1.0.9".parse().unwrap()));
+        assert!(IpNetwork::parse("::1").expect("v6").contains("::1".parse().unwrap()));
+        assert!(
+            IpNetwork::parse("0.0.0.0/0")
+                .expect("any")
+                .contains("8.8.8.8".parse().unwrap())
+        );
+        assert!(IpNetwork::parse("10.0.0.0/33").is_err());
+        assert!(IpNetwork::parse("proxy.internal").is_err());
+    }
+
+    #[test]
+    fn walks_x_forwarded_for_until_the_first_untrusted_hop() {
+        let resolver = resolver(&["10.0.0.0/8"], None);
+        let headers = headers(&[
+            ("x-forwarded-for", "198.51.100.4, 203.0.113.7, 10.0.0.5"),
+            ("x-forwarded-proto", "https"),
+            ("x-forwarded-host", "api.example.com"),
+            ("host", "backend:8000"),
+        ]);
+        let client = resolver.resolve(ip("10.0.0.2"), &Uri::from_static("/"), &headers);
+        assert_eq!(client.ip, ip("203.0.113.7"));
+        assert_eq!(client.scheme, "https");
+        assert_eq!(client.origin().as_deref(), Some("https://api.example.com"));
+    }
+
+    #[test]
+    fn forwarded_header_takes_precedence() {
+        let resolver = resolver(&["10.0.0.0/8"], None);
+        let headers = headers(&[
+            ("forwarded", r#"for="[2001:db8::1]:4711";proto=https;host=shop.example"#),
+            ("x-forwarded-for", "192.0.2.1"),
+        ]);
+        let client = resolver.resolve(ip("10.0.0.2"), &Uri::from_static("/"), &headers);
+        assert_eq!(client.ip, ip("2001:db8::1"));
+        assert_eq!(client.scheme, "https");
+        assert_eq!(client.host.as_deref(), Some("shop.example"));
+    }
+
+    #[test]
+    fn hop_count_limits_how_far_back_the_walk_goes() {
+        let resolver = resolver(&[], Some(1));
+        let headers = headers(&[("x-forwarded-for", "192.0.2.9, 198.51.100.4")]);
+        let client = resolver.resolve(ip("203.0.113.1"), &Uri::from_static("/"), &headers);
+        assert_eq!(client.ip, ip("198.51.100.4"));
+    }
+
+    #[test]
+    fn untrusted_peers_cannot_spoof_their_address() {
+        let resolver = resolver(&["10.0.0.0/8"], None);
+        let headers = headers(&[("x-forwarded-for", "192.0.2.1"), ("x-forwarded-proto", "https")]);
+        let client = resolver.resolve(ip("203.0.113.1"), &Uri::from_static("/"), &headers);
+        assert_eq!(client.ip, ip("203.0.113.1"));
+        assert_eq!(client.scheme, "http");
+
+        let unconfigured = ClientResolver::new(None).expect("resolver");
+        let client = unconfigured.resolve(ip("10.0.0.2"), &Uri::from_static("/"), &headers);
+        assert_eq!(client.ip, ip("10.0.0.2"));
+    }
+
+    #[test]
+    fn an_empty_allowlist_requires_trust_all() {
+        let ambiguous = TrustedProxyConfig {
+            hops: Some(1),
+            ..Default::default()
+        };
+        let error = ClientResolver::new(Some(&ambiguous)).expect_err("empty allowlist");
+        assert!(error.contains("trust_all"), "{error}");
+
+        let both = TrustedProxyConfig {
+            proxies: vec!["10.0.0.0/8".to_string()],
+            trust_all: true,
+            ..Default::default()
+        };
+        assert!(ClientResolver::new(Some(&both)).is_err());
+    }
+}
@@ -0,0 +1,517 @@
+//! Rate limiting middleware with pluggable keys and storage.
+//!
+//! Limits are enforced with GCRA (a token bucket expressed as a single
+//! "theoretical arrival time" per key), so a bucket is one timestamp regardless
+//! of the configured burst. Buckets live in a [`RateLimitStore`]; the default
+//! [`InMemoryRateLimitStore`] is per-process, while a shared backend (Redis, a
+//! database, ...) can be plugged in through `ServerConfig::rate_limit_store` to
+//! enforce one limit across instances.
+//!
+//! Every limited response carries the IETF `RateLimit-Limit`,
+//! `RateLimit-Remaining` and `RateLimit-Reset` headers. Rejected requests get a
+//! 429 RFC 9457 Problem Details body with `Retry-After`.
+
+use axum::{
+    body::Body,
+    extract::{ConnectInfo, Request, State},
+    http::{HeaderMap, HeaderName, HeaderValue, StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+use spikard_core::ExpiringMap;
+use std::collections::HashMap;
+use std::future::Future;
+use std::net::{IpAddr, SocketAddr};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::{CONTENT_TYPE_PROBLEM_JSON, ProblemDetails, RateLimitConfig, RateLimitKey};
+
+/// Standard type URI for rate limit errors (429)
+pub const TYPE_RATE_LIMITED: &str = "https://spikard.dev/errors/rate-limited";
+
+/// Number of requests allowed in a burst
+pub const HEADER_RATELIMIT_LIMIT: HeaderName = HeaderName::from_static("ratelimit-limit");
+/// Requests left before the client is throttled
+pub const HEADER_RATELIMIT_REMAINING: HeaderName = HeaderName::from_static("ratelimit-remaining");
+/// Seconds until the bucket is full again
+pub const HEADER_RATELIMIT_RESET: HeaderName = HeaderName::from_static("ratelimit-reset");
+
+/// Key used when the client address is unknown (e.g. in-process test servers)
+const UNKNOWN_CLIENT: &str = "unknown";
+
+/// Sustained rate and burst size of a single bucket
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitQuota {
+    pub per_second: u64,
+    pub burst: u32,
+}
+
+impl RateLimitQuota {
+    /// Quota described by a [`RateLimitConfig`]; zero values are clamped to one
+    pub fn from_config(config: &RateLimitConfig) -> Self {
+        Self {
+            per_second: config.per_second.max(1),
+            burst: config.burst.max(1),
+        }
+    }
+
+    /// Time it takes to earn back one request
+    pub fn emission_interval(&self) -> Duration {
+        Duration::from_nanos((1_000_000_000 / self.per_second.max(1)).max(1))
+    }
+
+    /// Evaluate a request arriving at `now` against a bucket whose theoretical arrival
+    /// time is `tat`, returning the decision and the bucket's new arrival time.
+    ///
+    /// Exposed so that shared stores can run the same algorithm against their own clock.
+    pub fn evaluate(&self, tat: Option<Instant>, now: Instant) -> (RateLimitDecision, Instant) {
+        let interval = self.emission_interval();
+        let tolerance = interval * self.burst.max(1);
+        let tat = tat.filter(|tat| *tat > now).unwrap_or(now);
+        let new_tat = tat + interval;
+        let backlog = new_tat - now;
+
+        if backlog > tolerance {
+            let decision = RateLimitDecision {
+                allowed: false,
+                limit: self.burst,
+                remaining: 0,
+                reset_after: tat - now,
+                retry_after: Some(backlog - tolerance),
+            };
+            return (decision, tat);
+        }
+
+        let remaining = u32::try_from((tolerance - backlog).as_nanos() / interval.as_nanos()).unwrap_or(u32::MAX);
+        let decision = RateLimitDecision {
+            allowed: true,
+            limit: self.burst,
+            remaining,
+            reset_after: backlog,
+            retry_after: None,
+        };
+        (decision, new_tat)
+    }
+}
+
+/// Outcome of charging one request against a bucket
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitDecision {
+    pub allowed: bool,
+    /// Burst size of the bucket
+    pub limit: u32,
+    /// Requests left before the client is throttled
+    pub remaining: u32,
+    /// Time until the bucket is full again
+    pub reset_after: Duration,
+    /// Time until the next request would be admitted (only set when rejected)
+    pub retry_after: Option<Duration>,
+}
+
+/// Future returned by [`RateLimitStore::check`]
+pub type RateLimitFuture<'a> = Pin<Box<dyn Future<Output = Result<RateLimitDecision, String>> + Send + 'a>>;
+
+/// Storage backend for rate limit buckets
+///
+/// Implementations must charge the request atomically: a rejected request must not
+/// consume quota. Errors fail open (the request is let through and a warning is logged)
+/// so an unavailable backend cannot take the service down.
+pub trait RateLimitStore: Send + Sync {
+    /// Charge one request against the bucket identified by `key`
+    fn check<'a>(&'a self, key: &'a str, quota: RateLimitQuota) -> RateLimitFuture<'a>;
+}
+
+impl std::fmt::Debug for dyn RateLimitStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("RateLimitStore")
+    }
+}
+
+/// Computes the bucket key for requests limited with [`RateLimitKey::Custom`]
+///
+/// Returning `None` falls back to the client IP.
+pub trait RateLimitKeyExtractor: Send + Sync {
+    fn extract_key(&self, request: &Request) -> Option<String>;
+}
+
+impl<F> RateLimitKeyExtractor for F
+where
+    F: Fn(&Request) -> Option<String> + Send + Sync,
+{
+    fn extract_key(&self, request: &Request) -> Option<String> {
+        self(request)
+    }
+}
+
+impl std::fmt::Debug for dyn RateLimitKeyExtractor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("RateLimitKeyExtractor")
+    }
+}
+
+/// The parts of a request a host-language [`RateLimitKeyExtractor`] sees
+///
+/// Language bindings serialize this to hand the request to their key callback.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RateLimitKeyRequest {
+    /// HTTP method (e.g. `"GET"`)
+    pub method: String,
+    /// Request path without the query string
+    pub path: String,
+    /// Raw query string, if any
+    pub query: Option<String>,
+    /// Request headers with lowercase names; non-UTF-8 values are skipped
+    pub headers: HashMap<String, String>,
+    /// Client IP address, resolved through trusted proxies when configured
+    pub client_ip: Option<String>,
+}
+
+impl RateLimitKeyRequest {
+    /// Capture the key-relevant parts of `request`
+    pub fn from_request(request: &Request) -> Self {
+        Self {
+            method: request.method().to_string(),
+            path: request.uri().path().to_string(),
+            query: request.uri().query().map(str::to_string),
+            headers: request
+                .headers()
+                .iter()
+                .filter_map(|(name, value)| Some((name.as_str().to_string(), value.to_str().ok()?.to_string())))
+                .collect(),
+            client_ip: client_ip(request).map(|ip| ip.to_string()),
+        }
+    }
+}
+
+/// Per-process [`RateLimitStore`], used when no shared store is configured
+#[derive(Debug)]
+pub struct InMemoryRateLimitStore {
+    buckets: Mutex<ExpiringMap<Instant>>,
+}
+
+impl InMemoryRateLimitStore {
+    pub fn new() -> Self {
+        Self {
+            buckets: Mutex::new(ExpiringMap::new()),
+        }
+    }
+
+    fn check_at(&self, key: &str, quota: RateLimitQuota, now: Instant) -> RateLimitDecision {
+        let mut buckets = self.buckets.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let (decision, tat) = quota.evaluate(buckets.get(key).copied(), now);
+        if decision.allowed {
+            buckets.insert(key.to_string(), tat);
+            // Buckets whose arrival time has passed are full again and carry no state.
+            buckets.sweep(|tat| *tat > now);
+        }
+        decision
+    }
+}
+
+impl Default for InMemoryRateLimitStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RateLimitStore for InMemoryRateLimitStore {
+    fn check<'a>(&'a self, key: &'a str, quota: RateLimitQuota) -> RateLimitFuture<'a> {
+        let decision = self.check_at(key, quota, Instant::now());
+        Box::pin(async move { Ok(decision) })
+    }
+}
+
+/// Store, key extractor and defaults shared by every rate limit layer of a router
+#[derive(Clone)]
+pub(crate) struct RateLimiting {
+    store: Arc<dyn RateLimitStore>,
+    key_extractor: Option<Arc<dyn RateLimitKeyExtractor>>,
+    api_key_header: String,
+}
+
+impl RateLimiting {
+    pub(crate) fn from_config(config: &crate::ServerConfig) -> Self {
+        Self {
+            store: config
+                .rate_limit_store
+                .clone()
+                .unwrap_or_else(|| Arc::new(InMemoryRateLimitStore::new())),
+            key_extractor: config.rate_limit_key_extractor.clone(),
+            api_key_header: config
+                .api_key_auth
+                .as_ref()
+                .map_or_else(|| "X-API-Key".to_string(), |auth| auth.header_name.clone()),
+        }
+    }
+
+    /// State for a layer enforcing `config` under `scope`
+    pub(crate) fn layer_state(
+        &self,
+        scope: impl Into<Arc<str>>,
+        config: &RateLimitConfig,
+    ) -> Result<RateLimitState, String> {
+        RateLimitState::new(
+            scope,
+            config,
+            &self.api_key_header,
+            Arc::clone(&self.store),
+            self.key_extractor.clone(),
+        )
+    }
+}
+
+impl Default for RateLimiting {
+    fn default() -> Self {
+        Self::from_config(&crate::ServerConfig::default())
+    }
+}
+
+/// Shared state of one rate limit layer (server-wide or a single route)
+#[derive(Clone)]
+pub(crate) struct RateLimitState {
+    scope: Arc<str>,
+    key: RateLimitKey,
+    quota: RateLimitQuota,
+    api_key_header: Arc<str>,
+    store: Arc<dyn RateLimitStore>,
+    key_extractor: Option<Arc<dyn RateLimitKeyExtractor>>,
+}
+
+impl RateLimitState {
+    /// Build the state for a layer, validating that custom keys have an extractor.
+    fn new(
+        scope: impl Into<Arc<str>>,
+        config: &RateLimitConfig,
+        api_key_header: &str,
+        store: Arc<dyn RateLimitStore>,
+        key_extractor: Option<Arc<dyn RateLimitKeyExtractor>>,
+    ) -> Result<Self, String> {
+        let scope = scope.into();
+        let key = config.effective_key();
+        if key == RateLimitKey::Custom && key_extractor.is_none() {
+            return Err(format!(
+                "Rate limit for {} uses a custom key but no rate limit key extractor is configured",
+                scope
+            ));
+        }
+        Ok(Self {
+            scope,
+            key,
+            quota: RateLimitQuota::from_config(config),
+            api_key_header: Arc::from(api_key_header),
+            store,
+            key_extractor,
+        })
+    }
+
+    fn bucket_key(&self, request: &Request) -> String {
+        let derived = match &self.key {
+            RateLimitKey::Ip => None,
+            RateLimitKey::Global => Some("global".to_string()),
+            RateLimitKey::JwtSubject => request
+                .extensions()
+                .get::<crate::Claims>()
+                .map(|claims| format!("sub:{}", claims.sub)),
+            RateLimitKey::ApiKey { header } => {
+                header_value(request.headers(), header.as_deref().unwrap_or(&self.api_key_header))
+                    .map(|key| format!("api_key:{}", key))
+            }
+            RateLimitKey::Header { name } => header_value(request.headers(), name).map(|v| format!("header:{}", v)),
+            RateLimitKey::Custom => self
+                .key_extractor
+                .as_ref()
+                .and_then(|extractor| extractor.extract_key(request))
+                .map(|key| format!("custom:{}", key)),
+        };
+        let key = derived.unwrap_or_else(|| {
+            format!(
+                "ip:{}",
+                client_ip(request).map_or_else(|| UNKNOWN_CLIENT.to_string(), |ip| ip.to_string())
+            )
+        });
+        format!("{}|{}", self.scope, key)
+    }
+}
+
+fn client_ip(request: &Request) -> Option<IpAddr> {
+    match request.extensions().get::<crate::proxy::ClientInfo>() {
+        Some(client) => client.ip,
+        None => request
+            .extensions()
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|info| info.0.ip()),
+    }
+}
+
+fn header_value<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .filter(|value| !value.is_empty())
+}
+
+fn ceil_secs(duration: Duration) -> u64 {
+    duration.as_secs() + u64::from(duration.subsec_nanos() > 0)
+}
+
+/// Set the `RateLimit-*` headers unless a stricter limit already set them.
+fn apply_headers(headers: &mut HeaderMap, decision: &RateLimitDecision) {
+    let stricter_present = headers
+        .get(&HEADER_RATELIMIT_REMAINING)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<u32>().ok())
+        .is_some_and(|remaining| remaining <= decision.remaining);
+    if stricter_present {
+        return;
+    }
+    headers.insert(HEADER_RATELIMIT_LIMIT, HeaderValue::from(decision.limit));
+    headers.insert(HEADER_RATELIMIT_REMAINING, HeaderValue::from(decision.remaining));
+    headers.insert(
+        HEADER_RATELIMIT_RESET,
+        HeaderValue::from(ceil_secs(decision.reset_after)),
+    );
+}
+
+fn too_many_requests(decision: &RateLimitDecision) -> Response {
+    let retry_after = ceil_secs(decision.retry_after.unwrap_or_default()).max(1);
+    let problem = ProblemDetails::new(TYPE_RATE_LIMITED, "Too Many Requests", StatusCode::TOO_MANY_REQUESTS)
+        .with_detail(format!("Rate limit exceeded, retry in {} second(s)", retry_after))
+        .with_extension("retry_after", serde_json::json!(retry_after));
+    let body = problem.to_json().unwrap_or_else(|_| "{}".to_string());
+    let mut response = (
+        StatusCode::TOO_MANY_REQUESTS,
+        [(header::CONTENT_TYPE, CONTENT_TYPE_PROBLEM_JSON)],
+        Body::from(body),
+    )
+        .into_response();
+    apply_headers(response.headers_mut(), decision);
+    response
+        .headers_mut()
+        .insert(header::RETRY_AFTER, HeaderValue::from(retry_after));
+    response
+}
+
+/// Rate limiting middleware
+///
+/// Charges the request against its bucket and either rejects it with 429 Problem Details
+/// or forwards it and annotates the response with `RateLimit-*` headers.
+pub(crate) async fn rate_limit_middleware(
+    State(state): State<RateLimitState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let key = state.bucket_key(&request);
+    let decision = match state.store.check(&key, state.quota).await {
+        Ok(decision) => decision,
+        Err(err) => {
+            tracing::warn!("Rate limit store failed for {}, allowing request: {}", state.scope, err);
+            return next.run(request).await;
+        }
+    };
+
+    if !decision.allowed {
+        return too_many_requests(&decision);
+    }
+
+    let mut response = next.run(request).await;
+    apply_headers(response.headers_mut(), &decision);
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_burst_then_reject_then_recover() {
+        let store = InMemoryRateLimitStore::new();
+        let quota = RateLimitQuota {
+            per_second: 2,
+            burst: 3,
+        };
+        let start = Instant::now();
+
+        let remaining: Vec<u32> = (0..3)
+            .map(|_| {
+                let decision = store.check_at("k", quota, start);
+                assert!(decision.allowed);
+                decision.remaining
+            })
+            .collect();
+        assert_eq!(remaining, vec![2, 1, 0]);
+
+        let rejected = store.check_at("k", quota, start);
+        assert!(!rejected.allowed);
+        assert_eq!(rejected.retry_after, Some(Duration::from_millis(500)));
+
+        let other = store.check_at("other", quota, start);
+        assert!(other.allowed, "buckets are independent per key");
+
+        let later = store.check_at("k", quota, start + Duration::from_millis(500));
+        assert!(later.allowed);
+        assert_eq!(later.remaining, 0);
+    }
+
+    #[test]
+    fn test_rejected_requests_do_not_consume_quota() {
+        let store = InMemoryRateLimitStore::new();
+        let quota = RateLimitQuota {
+            per_second: 1,
+            burst: 1,
+        };
+        let start = Instant::now();
+        assert!(store.check_at("k", quota, start).allowed);
+        for _ in 0..5 {
+            assert!(!store.check_at("k", quota, start).allowed);
+        }
+        assert!(store.check_at("k", quota, start + Duration::from_secs(1)).allowed);
+    }
+
+    #[test]
+    fn test_stricter_headers_are_kept() {
+        let mut headers = HeaderMap::new();
+        let strict = RateLimitDecision {
+            allowed: true,
+            limit: 5,
+            remaining: 1,
+            reset_after: Duration::from_millis(1500),
+            retry_after: None,
+        };
+        apply_headers(&mut headers, &strict);
+        apply_headers(
+            &mut headers,
+            &RateLimitDecision {
+                limit: 100,
+                remaining: 99,
+                ..strict
+            },
+        );
+        assert_eq!(headers[&HEADER_RATELIMIT_LIMIT], "5");
+        assert_eq!(headers[&HEADER_RATELIMIT_REMAINING], "1");
+        assert_eq!(headers[&HEADER_RATELIMIT_RESET], "2");
+    }
+
+    #[test]
+    fn test_key_request_captures_request_parts() {
+        let mut request = Request::builder()
+            .method("POST")
+            .uri("/orders?page=2")
+            .header("x-tenant", "acme")
+            .body(Body::empty())
+            .unwrap();
+        request
+            .extensions_mut()
+            .insert(ConnectInfo(SocketAddr::from(([10, 0, 0, 7], 4000))));
+
+        let key_request = RateLimitKeyRequest::from_request(&request);
+
+        assert_eq!(key_request.method, "POST");
+        assert_eq!(key_request.path, "/orders");
+        assert_eq!(key_request.query.as_deref(), Some("page=2"));
+        assert_eq!(key_request.headers.get("x-tenant").map(String::as_str), Some("acme"));
+        assert_eq!(key_request.client_ip.as_deref(), Some("10.0.0.7"));
+    }
+}
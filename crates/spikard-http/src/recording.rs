@@ -0,0 +1,538 @@
+//! Record live traffic as e2e fixtures
+//!
+//! With [`RecordingConfig`] set on the server, every completed request/response pair is
+//! written to `output_dir` as one JSON file in the shared [`spikard_fixtures::Fixture`]
+//! format read by the e2e suite and by `spikard_codegen::openapi::load_fixtures_from_dir`.
+//! Configured headers, cookies, query parameters and body fields are replaced with
+//! [`REDACTED`] before anything is written; [`replay_fixture`](crate::testing::replay_fixture) treats that placeholder as
+//! a wildcard.
+//!
+//! Streaming responses (SSE, bodies without a known length), WebSocket upgrades, gRPC calls
+//! and binary payloads pass through without being recorded.
+
+use axum::body::{Body, Bytes};
+use axum::extract::{Request, State};
+use axum::http::HeaderMap;
+use axum::middleware::Next;
+use axum::response::Response;
+use http_body::Body as _;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use spikard_fixtures::{Fixture, FixtureExpectedResponse, FixtureHandler, FixtureRequest};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// Value written in place of redacted headers, cookies, query parameters and body fields
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Fixture recording configuration
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RecordingConfig {
+    /// Directory fixtures are written to (created on first write)
+    pub output_dir: String,
+    /// `category` of recorded fixtures, also used as file name prefix
+    pub category: Option<String>,
+    /// Only record requests whose path starts with one of these prefixes (empty = all)
+    pub include_paths: Vec<String>,
+    /// Headers whose values are redacted in requests and responses; `cookie` redacts every cookie
+    pub redact_headers: Vec<String>,
+    /// Query parameters whose values are redacted (matched case-insensitively)
+    pub redact_query_params: Vec<String>,
+    /// JSON pointers into request and response bodies to redact; a `*` segment matches any key or index
+    pub redact_body_fields: Vec<String>,
+    /// Response headers kept in `expected_response.headers`
+    pub response_headers: Vec<String>,
+    /// Requests and responses with larger bodies are not recorded
+    pub max_body_size: usize,
+}
+
+impl Default for RecordingConfig {
+    fn default() -> Self {
+        Self {
+            output_dir: "fixtures/recorded".to_string(),
+            category: None,
+            include_paths: Vec::new(),
+            redact_headers: [
+                "authorization",
+                "proxy-authorization",
+                "cookie",
+                "set-cookie",
+                "x-api-key",
+            ]
+            .into_iter()
+            .map(String::from)
+            .collect(),
+            redact_query_params: ["api_key", "apikey", "access_token", "token", "key"]
+                .into_iter()
+                .map(String::from)
+                .collect(),
+            redact_body_fields: Vec::new(),
+            response_headers: vec!["content-type".to_string()],
+            max_body_size: 1024 * 1024,
+        }
+    }
+}
+
+/// Request headers that describe the connection or body framing rather than the request
+const SKIPPED_REQUEST_HEADERS: &[&str] = &[
+    "host",
+    "content-length",
+    "content-type",
+    "cookie",
+    "connection",
+    "transfer-encoding",
+    "accept-encoding",
+];
+
+/// Recording state shared by the middleware
+#[derive(Debug)]
+pub(crate) struct Recorder {
+    config: RecordingConfig,
+    output_dir: PathBuf,
+}
+
+impl Recorder {
+    pub(crate) fn new(config: &RecordingConfig) -> Self {
+        Self {
+            output_dir: PathBuf::from(&config.output_dir),
+            config: RecordingConfig {
+                redact_headers: config
+                    .redact_headers
+                    .iter()
+                    .map(|name| name.to_ascii_lowercase())
+                    .collect(),
+                redact_query_params: config
+                    .redact_query_params
+                    .iter()
+                    .map(|name| name.to_ascii_lowercase())
+                    .collect(),
+                response_headers: config
+                    .response_headers
+                    .iter()
+                    .map(|name| name.to_ascii_lowercase())
+                    .collect(),
+                ..config.clone()
+            },
+        }
+    }
+
+    fn records(&self, request: &Request) -> bool {
+        let path = request.uri().path();
+        (self.config.include_paths.is_empty()
+            || self
+                .config
+                .include_paths
+                .iter()
+                .any(|prefix| path.starts_with(prefix.as_str())))
+            && !request.headers().contains_key(axum::http::header::UPGRADE)
+            && !crate::server::grpc_routing::is_grpc_request(request)
+    }
+
+    /// Whether a body is small enough to buffer; bodies of unknown length are never buffered.
+    fn fits(&self, body: &Body) -> bool {
+        body.size_hint()
+            .upper()
+            .is_some_and(|size| size <= self.config.max_body_size as u64)
+    }
+
+    fn is_redacted_header(&self, name: &str) -> bool {
+        self.config.redact_headers.iter().any(|redacted| redacted == name)
+    }
+
+    fn is_redacted_query_param(&self, name: &str) -> bool {
+        self.config
+            .redact_query_params
+            .iter()
+            .any(|redacted| redacted.eq_ignore_ascii_case(name))
+    }
+
+    fn redact_body(&self, body: &mut Value) {
+        for pointer in &self.config.redact_body_fields {
+            redact_json_pointer(body, pointer);
+        }
+    }
+
+    fn record_request(&self, request: &Request, body: &Bytes) -> Option<FixtureRequest> {
+        let headers = request.headers();
+        let content_type = header_str(headers, "content-type");
+
+        let mut recorded = FixtureRequest {
+            method: request.method().to_string(),
+            path: request.uri().path().to_string(),
+            content_type: content_type.clone(),
+            ..FixtureRequest::default()
+        };
+
+        if let Some(query) = request.uri().query() {
+            let mut params: HashMap<String, Value> = HashMap::new();
+            for (key, value) in url::form_urlencoded::parse(query.as_bytes()) {
+                let value = if self.is_redacted_query_param(&key) {
+                    Value::String(REDACTED.to_string())
+                } else {
+                    Value::String(value.into_owned())
+                };
+                match params.get_mut(key.as_ref()) {
+                    Some(Value::Array(values)) => values.push(value),
+                    Some(existing) => *existing = Value::Array(vec![existing.take(), value]),
+                    None => {
+                        params.insert(key.into_owned(), value);
+                    }
+                }
+            }
+            recorded.query_params = non_empty(params);
+        }
+
+        let recorded_headers = headers
+            .iter()
+            .filter(|(name, _)| !SKIPPED_REQUEST_HEADERS.contains(&name.as_str()))
+            .filter_map(|(name, value)| {
+                let value = if self.is_redacted_header(name.as_str()) {
+                    REDACTED.to_string()
+                } else {
+                    value.to_str().ok()?.to_string()
+                };
+                Some((name.as_str().to_string(), value))
+            })
+            .collect();
+        recorded.headers = non_empty(recorded_headers);
+
+        let redact_cookies = self.is_redacted_header("cookie");
+        let cookies = headers
+            .get_all(axum::http::header::COOKIE)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| cookie::Cookie::split_parse(value.to_string()).filter_map(Result::ok))
+            .map(|cookie| {
+                let value = if redact_cookies {
+                    REDACTED.to_string()
+                } else {
+                    cookie.value().to_string()
+                };
+                (cookie.name().to_string(), value)
+            })
+            .collect();
+        recorded.cookies = non_empty(cookies);
+
+        if body.is_empty() {
+            return Some(recorded);
+        }
+        let text = std::str::from_utf8(body).ok()?;
+        match content_type.as_deref().map(media_type) {
+            Some("application/x-www-form-urlencoded") => {
+                let mut form = Value::Object(
+                    url::form_urlencoded::parse(body)
+                        .map(|(key, value)| (key.into_owned(), Value::String(value.into_owned())))
+                        .collect(),
+                );
+                self.redact_body(&mut form);
+                recorded.form_data = match form {
+                    Value::Object(fields) => non_empty(fields.into_iter().collect()),
+                    _ => None,
+                };
+            }
+            _ => recorded.body = Some(self.text_body(text, content_type.as_deref())),
+        }
+        Some(recorded)
+    }
+
+    fn record_response(&self, response: &Response, body: &Bytes) -> Option<FixtureExpectedResponse> {
+        let headers = response.headers();
+        let recorded_headers = self
+            .config
+            .response_headers
+            .iter()
+            .filter_map(|name| {
+                let value = if self.is_redacted_header(name) {
+                    headers.get(name.as_str()).map(|_| REDACTED.to_string())
+                } else {
+                    header_str(headers, name)
+                };
+                value.map(|value| (name.clone(), value))
+            })
+            .collect();
+
+        let body = if body.is_empty() {
+            None
+        } else {
+            let text = std::str::from_utf8(body).ok()?;
+            Some(self.text_body(text, header_str(headers, "content-type").as_deref()))
+        };
+
+        Some(FixtureExpectedResponse {
+            status_code: response.status().as_u16(),
+            body,
+            headers: non_empty(recorded_headers),
+            ..FixtureExpectedResponse::default()
+        })
+    }
+
+    /// Parse JSON bodies and redact them; other text is kept verbatim.
+    fn text_body(&self, text: &str, content_type: Option<&str>) -> Value {
+        let is_json = content_type.is_none_or(|content_type| {
+            let media_type = media_type(content_type);
+            media_type == "application/json" || media_type.ends_with("+json")
+        });
+        match serde_json::from_str::<Value>(text) {
+            Ok(mut value) if is_json => {
+                self.redact_body(&mut value);
+                value
+            }
+            _ => Value::String(text.to_string()),
+        }
+    }
+
+    fn fixture(&self, route: Option<String>, request: FixtureRequest, response: FixtureExpectedResponse) -> Fixture {
+        Fixture {
+            name: String::new(),
+            description: format!(
+                "Recorded {} {} -> {}",
+                request.method, request.path, response.status_code
+            ),
+            category: self.config.category.clone(),
+            handler: route.map(|route| FixtureHandler {
+                route,
+                method: request.method.clone(),
+                ..FixtureHandler::default()
+            }),
+            streaming: None,
+            background: None,
+            request,
+            expected_response: response,
+            tags: Some(vec!["recorded".to_string()]),
+        }
+    }
+
+    /// Write the fixture under a file name derived from method, route and status.
+    ///
+    /// Existing files are never overwritten; repeated requests get a numeric suffix.
+    async fn write(&self, mut fixture: Fixture) -> std::io::Result<PathBuf> {
+        use tokio::io::AsyncWriteExt;
+
+        tokio::fs::create_dir_all(&self.output_dir).await?;
+        let route = fixture
+            .handler
+            .as_ref()
+            .map_or(fixture.request.path.as_str(), |handler| handler.route.as_str());
+        let mut stem = format!(
+            "{}_{}_{}",
+            fixture.request.method.to_ascii_lowercase(),
+            sanitize(route),
+            fixture.expected_response.status_code
+        );
+        if let Some(ref category) = self.config.category {
+            stem = format!("{}_{stem}", sanitize(category));
+        }
+
+        for attempt in 1u32.. {
+            let name = if attempt == 1 {
+                stem.clone()
+            } else {
+                format!("{stem}_{attempt}")
+            };
+            let path = self.output_dir.join(format!("{name}.json"));
+            let file = tokio::fs::OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(&path)
+                .await;
+            let mut file = match file {
+                Ok(file) => file,
+                Err(err) if err.kind() == std::io::ErrorKind::AlreadyExists => continue,
+                Err(err) => return Err(err),
+            };
+            fixture.name = name;
+            let mut json = serde_json::to_vec_pretty(&fixture).map_err(std::io::Error::other)?;
+            json.push(b'\n');
+            file.write_all(&json).await?;
+            file.flush().await?;
+            return Ok(path);
+        }
+        unreachable!("fixture file names are unbounded")
+    }
+}
+
+/// Record the request and response as a fixture, leaving both unchanged
+pub(crate) async fn recording_middleware(
+    State(recorder): State<Arc<Recorder>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if !recorder.records(&request) || !recorder.fits(request.body()) {
+        return next.run(request).await;
+    }
+
+    let route = Some(crate::metrics::route_label(&request)).filter(|route| route != crate::metrics::UNMATCHED_ROUTE);
+    let (parts, body) = request.into_parts();
+    let Ok(request_body) = axum::body::to_bytes(body, recorder.config.max_body_size).await else {
+        return Response::builder()
+            .status(axum::http::StatusCode::BAD_REQUEST)
+            .body(Body::from("Failed to read request body"))
+            .unwrap_or_default();
+    };
+    let request = Request::from_parts(parts, Body::from(request_body.clone()));
+    let recorded_request = recorder.record_request(&request, &request_body);
+
+    let response = next.run(request).await;
+    let is_stream = header_str(response.headers(), "content-type")
+        .is_some_and(|content_type| media_type(&content_type) == "text/event-stream");
+    let Some(recorded_request) = recorded_request else {
+        return response;
+    };
+    if is_stream || !recorder.fits(response.body()) {
+        return response;
+    }
+
+    let (parts, body) = response.into_parts();
+    let response_body = match axum::body::to_bytes(body, usize::MAX).await {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            tracing::warn!("Failed to buffer response for recording: {}", err);
+            return Response::from_parts(parts, Body::empty());
+        }
+    };
+    let response = Response::from_parts(parts, Body::from(response_body.clone()));
+
+    if let Some(recorded_response) = recorder.record_response(&response, &response_body) {
+        let fixture = recorder.fixture(route, recorded_request, recorded_response);
+        if let Err(err) = recorder.write(fixture).await {
+            tracing::warn!("Failed to write recorded fixture: {}", err);
+        }
+    }
+    response
+}
+
+/// Record every route of `router` into fixtures
+pub fn record_router(router: axum::Router, config: &RecordingConfig) -> axum::Router {
+    router.layer(axum::middleware::from_fn_with_state(
+        Arc::new(Recorder::new(config)),
+        recording_middleware,
+    ))
+}
+
+/// Replace the values addressed by `pointer` with [`REDACTED`]; a `*` segment matches any key or index.
+pub(crate) fn redact_json_pointer(value: &mut Value, pointer: &str) {
+    let segments: Vec<String> = pointer
+        .split('/')
+        .skip(1)
+        .map(|segment| segment.replace("~1", "/").replace("~0", "~"))
+        .collect();
+    redact_pointer(value, &segments);
+}
+
+fn redact_pointer(value: &mut Value, segments: &[String]) {
+    let Some((segment, rest)) = segments.split_first() else {
+        *value = Value::String(REDACTED.to_string());
+        return;
+    };
+    match value {
+        Value::Object(fields) if segment == "*" => fields.values_mut().for_each(|field| redact_pointer(field, rest)),
+        Value::Object(fields) => {
+            if let Some(field) = fields.get_mut(segment) {
+                redact_pointer(field, rest);
+            }
+        }
+        Value::Array(items) if segment == "*" => items.iter_mut().for_each(|item| redact_pointer(item, rest)),
+        Value::Array(items) => {
+            if let Some(item) = segment.parse::<usize>().ok().and_then(|index| items.get_mut(index)) {
+                redact_pointer(item, rest);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn header_str(headers: &HeaderMap, name: &str) -> Option<String> {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string)
+}
+
+fn media_type(content_type: &str) -> &str {
+    content_type.split(';').next().unwrap_or_default().trim()
+}
+
+fn non_empty<V>(map: HashMap<String, V>) -> Option<HashMap<String, V>> {
+    (!map.is_empty()).then_some(map)
+}
+
+fn sanitize(value: &str) -> String {
+    let sanitized = value
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("_");
+    if sanitized.is_empty() {
+        "root".to_string()
+    } else {
+        sanitized
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn pointers_redact_nested_and_wildcard_fields() {
+        let recorder = Recorder::new(&RecordingConfig {
+            redact_body_fields: vec![
+                "/token".to_string(),
+                "/users/*/email".to_string(),
+                "/a~1b".to_string(),
+                "/missing/field".to_string(),
+            ],
+            ..RecordingConfig::default()
+        });
+        let mut body = json!({
+            "token": {"value": "secret"},
+            "users": [{"id": 1, "email": "a@example.com"}, {"id": 2, "email": "b@example.com"}],
+            "a/b": 3,
+            "kept": "value"
+        });
+        recorder.redact_body(&mut body);
+        assert_eq!(
+            body,
+            json!({
+                "token": REDACTED,
+                "users": [{"id": 1, "email": REDACTED}, {"id": 2, "email": REDACTED}],
+                "a/b": REDACTED,
+                "kept": "value"
+            })
+        );
+    }
+
+    #[test]
+    fn query_params_are_redacted() {
+        let recorder = Recorder::new(&RecordingConfig {
+            redact_query_params: vec!["Session".to_string(), "api_key".to_string()],
+            ..RecordingConfig::default()
+        });
+        let request = Request::builder()
+            .uri("/items?api_key=secret&session=a&SESSION=b&page=2")
+            .body(Body::empty())
+            .expect("request");
+
+        let recorded = recorder.record_request(&request, &Bytes::new()).expect("recorded");
+        let fixture = serde_json::to_string(&recorded).expect("json");
+        assert!(!fixture.contains("secret"));
+        assert_eq!(
+            recorded.query_params,
+            Some(HashMap::from([
+                ("api_key".to_string(), json!(REDACTED)),
+                ("session".to_string(), json!(REDACTED)),
+                ("SESSION".to_string(), json!(REDACTED)),
+                ("page".to_string(), json!("2")),
+            ]))
+        );
+    }
+
+    #[test]
+    fn file_stems_are_filesystem_safe() {
+        assert_eq!(sanitize("/users/{id}"), "users_id");
+        assert_eq!(sanitize("/"), "root");
+    }
+}
@@ -35,6 +35,28 @@ fn grpc_status_to_http(code: tonic::Code) -> StatusCode {
     }
 }
 
+/// Convert an HTTP error status back to the gRPC status code it most likely came from
+///
+/// Inverse of [`grpc_status_to_http`]; where several codes share an HTTP status, the
+/// most common one is returned.
+pub(crate) fn http_status_to_grpc(status: StatusCode) -> tonic::Code {
+    match status.as_u16() {
+        200..=299 => tonic::Code::Ok,
+        400 => tonic::Code::InvalidArgument,
+        401 => tonic::Code::Unauthenticated,
+        403 => tonic::Code::PermissionDenied,
+        404 => tonic::Code::NotFound,
+        409 => tonic::Code::AlreadyExists,
+        413 | 429 => tonic::Code::ResourceExhausted,
+        499 => tonic::Code::Cancelled,
+        501 => tonic::Code::Unimplemented,
+        503 => tonic::Code::Unavailable,
+        504 => tonic::Code::DeadlineExceeded,
+        500 => tonic::Code::Internal,
+        _ => tonic::Code::Unknown,
+    }
+}
+
 /// Route a gRPC request to the appropriate handler
 ///
 /// Parses the request path to extract service and method names,
@@ -180,6 +202,8 @@ async fn handle_unary_request(
         }
     }
 
+    crate::telemetry::inject_grpc_metadata(tonic_request.metadata_mut());
+
     let tonic_response = match service.handle_unary(service_name, method_name, tonic_request).await {
         Ok(resp) => resp,
         Err(status) => {
@@ -269,6 +293,8 @@ async fn handle_server_streaming_request(
         }
     }
 
+    crate::telemetry::inject_grpc_metadata(tonic_request.metadata_mut());
+
     let tonic_response = match service
         .handle_server_stream(service_name, method_name, tonic_request, max_stream_response_bytes)
         .await
@@ -319,6 +345,8 @@ async fn handle_client_streaming_request(
         }
     }
 
+    crate::telemetry::inject_grpc_metadata(tonic_request.metadata_mut());
+
     let tonic_response = match service
         .handle_client_stream(
             service_name,
@@ -416,6 +444,8 @@ async fn handle_bidirectional_streaming_request(
         }
     }
 
+    crate::telemetry::inject_grpc_metadata(tonic_request.metadata_mut());
+
     let tonic_response = match service
         .handle_bidi_stream(
             service_name,
@@ -696,6 +726,27 @@ mod tests {
         assert!(!is_grpc_request(&request));
     }
 
+    #[test]
+    fn test_http_status_to_grpc_inverts_unambiguous_mappings() {
+        for code in [
+            tonic::Code::Ok,
+            tonic::Code::Cancelled,
+            tonic::Code::InvalidArgument,
+            tonic::Code::DeadlineExceeded,
+            tonic::Code::NotFound,
+            tonic::Code::AlreadyExists,
+            tonic::Code::PermissionDenied,
+            tonic::Code::ResourceExhausted,
+            tonic::Code::Unimplemented,
+            tonic::Code::Internal,
+            tonic::Code::Unavailable,
+            tonic::Code::Unauthenticated,
+        ] {
+            assert_eq!(http_status_to_grpc(grpc_status_to_http(code)), code);
+        }
+        assert_eq!(http_status_to_grpc(StatusCode::IM_A_TEAPOT), tonic::Code::Unknown);
+    }
+
     #[test]
     fn test_grpc_status_to_http_mappings() {
         assert_eq!(grpc_status_to_http(tonic::Code::Ok), StatusCode::OK);
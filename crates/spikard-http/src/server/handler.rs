@@ -10,6 +10,7 @@ use std::future::Future;
 use std::panic::AssertUnwindSafe;
 use std::pin::Pin;
 use std::sync::Arc;
+use tracing::Instrument;
 
 /// Default content type for file parts that declare none.
 const DEFAULT_FILE_CONTENT_TYPE: &str = "application/octet-stream";
@@ -221,94 +222,100 @@ impl Handler for ValidatingHandler {
         let parameter_validator = &self.parameter_validator;
 
         Box::pin(async move {
-            let content_type = request_data.headers.get("content-type").map(String::as_str);
-            let is_grpc = content_type.is_some_and(crate::middleware::validation::is_grpc_str);
-
-            if !is_grpc
-                && request_data.body.is_null()
-                && let Some(raw_bytes) = request_data.raw_body.as_ref()
-            {
-                let header_is_multipart = content_type.is_some_and(crate::middleware::validation::is_multipart_str);
-                let header_boundary_present =
-                    header_is_multipart && extract_boundary(content_type.unwrap_or("")).is_some();
-                let sniffed_boundary = if !header_boundary_present {
-                    looks_like_multipart(raw_bytes)
-                        .then(|| extract_boundary_from_body(raw_bytes))
-                        .flatten()
-                } else {
-                    None
-                };
-                let synth_ct = sniffed_boundary.map(|b| format!("multipart/form-data; boundary={}", b));
-
-                let is_form_urlencoded =
-                    content_type.is_some_and(crate::middleware::validation::is_form_urlencoded_str);
-                let is_json_like = content_type.is_some_and(crate::middleware::validation::is_json_like_str);
-                let has_multipart_boundary = header_boundary_present || synth_ct.is_some();
-
-                let parsed = if has_multipart_boundary {
-                    let ct = if header_boundary_present {
-                        content_type.unwrap_or("").to_owned()
+            let request_data = async move {
+                let content_type = request_data.headers.get("content-type").map(String::as_str);
+                let is_grpc = content_type.is_some_and(crate::middleware::validation::is_grpc_str);
+
+                if !is_grpc
+                    && request_data.body.is_null()
+                    && let Some(raw_bytes) = request_data.raw_body.as_ref()
+                {
+                    let header_is_multipart = content_type.is_some_and(crate::middleware::validation::is_multipart_str);
+                    let header_boundary_present =
+                        header_is_multipart && extract_boundary(content_type.unwrap_or("")).is_some();
+                    let sniffed_boundary = if !header_boundary_present {
+                        looks_like_multipart(raw_bytes)
+                            .then(|| extract_boundary_from_body(raw_bytes))
+                            .flatten()
                     } else {
-                        synth_ct.unwrap()
+                        None
                     };
-                    Some(parse_multipart_body(raw_bytes, &ct).await?)
-                } else if is_form_urlencoded {
-                    Some(
-                        serde_qs::from_bytes::<Value>(raw_bytes)
-                            .map_err(|e| (axum::http::StatusCode::BAD_REQUEST, format!("Invalid form body: {}", e)))?,
-                    )
-                } else if is_json_like && (request_validator.is_some() || !inner.prefers_raw_json_body()) {
-                    Some(serde_json::from_slice::<Value>(raw_bytes).map_err(|_| {
-                        let problem = ProblemDetails::bad_request("Invalid JSON in request body");
-                        let body = problem.to_json().unwrap_or_else(|_| "{}".to_string());
-                        (axum::http::StatusCode::BAD_REQUEST, body)
-                    })?)
-                } else if request_validator.is_some() {
-                    Some(serde_json::from_slice::<Value>(raw_bytes).map_err(|_| {
-                        let problem = ProblemDetails::bad_request("Invalid JSON in request body");
-                        let body = problem.to_json().unwrap_or_else(|_| "{}".to_string());
-                        (axum::http::StatusCode::BAD_REQUEST, body)
-                    })?)
-                } else {
-                    None
-                };
-
-                if let Some(parsed) = parsed {
-                    request_data.body = Arc::new(parsed);
+                    let synth_ct = sniffed_boundary.map(|b| format!("multipart/form-data; boundary={}", b));
+
+                    let is_form_urlencoded =
+                        content_type.is_some_and(crate::middleware::validation::is_form_urlencoded_str);
+                    let is_json_like = content_type.is_some_and(crate::middleware::validation::is_json_like_str);
+                    let has_multipart_boundary = header_boundary_present || synth_ct.is_some();
+
+                    let parsed =
+                        if has_multipart_boundary {
+                            let ct = if header_boundary_present {
+                                content_type.unwrap_or("").to_owned()
+                            } else {
+                                synth_ct.unwrap()
+                            };
+                            Some(parse_multipart_body(raw_bytes, &ct).await?)
+                        } else if is_form_urlencoded {
+                            Some(serde_qs::from_bytes::<Value>(raw_bytes).map_err(|e| {
+                                (axum::http::StatusCode::BAD_REQUEST, format!("Invalid form body: {}", e))
+                            })?)
+                        } else if is_json_like && (request_validator.is_some() || !inner.prefers_raw_json_body()) {
+                            Some(serde_json::from_slice::<Value>(raw_bytes).map_err(|_| {
+                                let problem = ProblemDetails::bad_request("Invalid JSON in request body");
+                                let body = problem.to_json().unwrap_or_else(|_| "{}".to_string());
+                                (axum::http::StatusCode::BAD_REQUEST, body)
+                            })?)
+                        } else if request_validator.is_some() {
+                            Some(serde_json::from_slice::<Value>(raw_bytes).map_err(|_| {
+                                let problem = ProblemDetails::bad_request("Invalid JSON in request body");
+                                let body = problem.to_json().unwrap_or_else(|_| "{}".to_string());
+                                (axum::http::StatusCode::BAD_REQUEST, body)
+                            })?)
+                        } else {
+                            None
+                        };
+
+                    if let Some(parsed) = parsed {
+                        request_data.body = Arc::new(parsed);
+                    }
                 }
-            }
 
-            if let Some(validator) = request_validator
-                && !is_grpc
-                && let Err(errors) = validator.validate(&request_data.body)
-            {
-                let problem = ProblemDetails::from_validation_error(&errors);
-                let body = problem.to_json().unwrap_or_else(|_| "{}".to_string());
-                return Err((problem.status_code(), body));
-            }
+                if let Some(validator) = request_validator
+                    && !is_grpc
+                    && let Err(errors) = validator.validate(&request_data.body)
+                {
+                    let problem = ProblemDetails::from_validation_error(&errors);
+                    let body = problem.to_json().unwrap_or_else(|_| "{}".to_string());
+                    return Err((problem.status_code(), body));
+                }
 
-            if let Some(validator) = parameter_validator
-                && !inner.prefers_parameter_extraction()
-            {
-                match validator.validate_and_extract(
-                    &request_data.query_params,
-                    &request_data.raw_query_params,
-                    &request_data.path_params,
-                    &request_data.headers,
-                    &request_data.cookies,
-                ) {
-                    Ok(validated) => {
-                        request_data.validated_params = Some(Arc::new(validated));
-                    }
-                    Err(errors) => {
-                        let problem = ProblemDetails::from_validation_error(&errors);
-                        let body = problem.to_json().unwrap_or_else(|_| "{}".to_string());
-                        return Err((problem.status_code(), body));
+                if let Some(validator) = parameter_validator
+                    && !inner.prefers_parameter_extraction()
+                {
+                    match validator.validate_and_extract(
+                        &request_data.query_params,
+                        &request_data.raw_query_params,
+                        &request_data.path_params,
+                        &request_data.headers,
+                        &request_data.cookies,
+                    ) {
+                        Ok(validated) => {
+                            request_data.validated_params = Some(Arc::new(validated));
+                        }
+                        Err(errors) => {
+                            let problem = ProblemDetails::from_validation_error(&errors);
+                            let body = problem.to_json().unwrap_or_else(|_| "{}".to_string());
+                            return Err((problem.status_code(), body));
+                        }
                     }
                 }
+
+                Ok::<_, (axum::http::StatusCode, String)>(request_data)
             }
+            .instrument(tracing::info_span!("validation"))
+            .await?;
 
-            match AssertUnwindSafe(async { inner.call(req, request_data).await })
+            match AssertUnwindSafe(inner.call(req, request_data).instrument(tracing::info_span!("handler")))
                 .catch_unwind()
                 .await
             {
@@ -345,6 +352,9 @@ mod tests {
             cookies: Arc::new(HashMap::new()),
             method: "POST".to_string(),
             path: "/test".to_string(),
+            client_ip: None,
+            scheme: None,
+            host: None,
             #[cfg(feature = "di")]
             dependencies: None,
         }
@@ -363,6 +373,9 @@ mod tests {
             cookies: Arc::new(HashMap::new()),
             method: "POST".to_string(),
             path: "/test".to_string(),
+            client_ip: None,
+            scheme: None,
+            host: None,
             #[cfg(feature = "di")]
             dependencies: None,
         }
@@ -423,6 +436,10 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            auth: None,
         };
 
         let inner = Arc::new(SuccessEchoHandler);
@@ -463,6 +480,10 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            auth: None,
         };
 
         let inner = Arc::new(SuccessEchoHandler);
@@ -488,6 +509,9 @@ mod tests {
             cookies: Arc::new(HashMap::new()),
             method: "POST".to_string(),
             path: "/test".to_string(),
+            client_ip: None,
+            scheme: None,
+            host: None,
             #[cfg(feature = "di")]
             dependencies: None,
         };
@@ -534,6 +558,10 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            auth: None,
         };
 
         let inner = Arc::new(SuccessEchoHandler);
@@ -584,6 +612,10 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            auth: None,
         };
 
         let inner = Arc::new(SuccessEchoHandler);
@@ -647,6 +679,10 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            auth: None,
         };
 
         let inner = Arc::new(SuccessEchoHandler);
@@ -691,6 +727,10 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            auth: None,
         };
 
         let inner = Arc::new(PanicHandlerImpl);
@@ -745,6 +785,10 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            auth: None,
         };
 
         let inner = Arc::new(SuccessEchoHandler);
@@ -798,6 +842,10 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            auth: None,
         };
 
         let inner = Arc::new(SuccessEchoHandler);
@@ -856,6 +904,10 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            auth: None,
         };
 
         let inner = Arc::new(SuccessEchoHandler);
@@ -910,6 +962,10 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            auth: None,
         };
 
         let inner = Arc::new(SuccessEchoHandler);
@@ -950,6 +1006,10 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            auth: None,
         };
 
         let inner = Arc::new(SuccessEchoHandler);
@@ -997,6 +1057,10 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            auth: None,
         };
 
         let inner = Arc::new(SuccessEchoHandler);
@@ -1019,6 +1083,9 @@ mod tests {
             cookies: Arc::new(HashMap::new()),
             method: "POST".to_string(),
             path: "/test".to_string(),
+            client_ip: None,
+            scheme: None,
+            host: None,
             #[cfg(feature = "di")]
             dependencies: None,
         };
@@ -1048,6 +1115,10 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            auth: None,
         };
 
         let inner = Arc::new(PanicHandlerImpl);
@@ -1093,6 +1164,10 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            auth: None,
         };
 
         let inner = Arc::new(SuccessEchoHandler);
@@ -1144,6 +1219,10 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            auth: None,
         };
 
         let inner = Arc::new(SuccessEchoHandler);
@@ -1166,6 +1245,9 @@ mod tests {
             cookies: Arc::new(HashMap::new()),
             method: "POST".to_string(),
             path: "/test".to_string(),
+            client_ip: None,
+            scheme: None,
+            host: None,
             #[cfg(feature = "di")]
             dependencies: None,
         };
@@ -1207,6 +1289,10 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            auth: None,
         };
 
         let inner = Arc::new(SuccessEchoHandler);
@@ -1250,6 +1336,10 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            auth: None,
         };
 
         let inner = Arc::new(SuccessEchoHandler);
@@ -1290,6 +1380,10 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            auth: None,
         };
 
         let inner = Arc::new(SuccessEchoHandler);
@@ -1331,6 +1425,10 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            auth: None,
         };
 
         let inner = Arc::new(PanicHandlerImpl);
@@ -1385,6 +1483,10 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            auth: None,
         };
 
         let inner = Arc::new(SuccessEchoHandler);
@@ -1427,6 +1529,10 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            auth: None,
         };
 
         let inner = Arc::new(SuccessEchoHandler);
@@ -1467,6 +1573,10 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            auth: None,
         };
 
         let inner = Arc::new(SuccessEchoHandler);
@@ -1520,6 +1630,10 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            auth: None,
         };
 
         let inner = Arc::new(PanicHandlerImpl);
@@ -1570,6 +1684,10 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            auth: None,
         };
 
         let inner = Arc::new(SuccessEchoHandler);
@@ -1616,6 +1734,10 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            auth: None,
         };
 
         let inner = Arc::new(SuccessEchoHandler);
@@ -1659,6 +1781,10 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            auth: None,
         };
 
         let inner = Arc::new(SuccessEchoHandler);
@@ -1719,6 +1845,10 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            auth: None,
         };
 
         let inner = Arc::new(SuccessEchoHandler);
@@ -1775,6 +1905,10 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            auth: None,
         };
 
         let inner = Arc::new(SuccessEchoHandler);
@@ -1826,6 +1960,10 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            auth: None,
         };
 
         let inner = Arc::new(SuccessEchoHandler);
@@ -1866,6 +2004,10 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            auth: None,
         };
 
         let inner = Arc::new(PanicHandlerImpl);
@@ -1937,6 +2079,10 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            auth: None,
         };
 
         let boundary = "TestBoundary1234";
@@ -1963,6 +2109,9 @@ mod tests {
             cookies: Arc::new(HashMap::new()),
             method: "POST".to_string(),
             path: "/upload".to_string(),
+            client_ip: None,
+            scheme: None,
+            host: None,
             #[cfg(feature = "di")]
             dependencies: None,
         };
@@ -2018,6 +2167,10 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            auth: None,
         };
 
         let boundary = "TestBoundary1234";
@@ -2043,6 +2196,9 @@ mod tests {
             cookies: Arc::new(HashMap::new()),
             method: "POST".to_string(),
             path: "/upload".to_string(),
+            client_ip: None,
+            scheme: None,
+            host: None,
             #[cfg(feature = "di")]
             dependencies: None,
         };
@@ -2090,6 +2246,10 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            auth: None,
         };
 
         let body = "--alef-boundary\r\nContent-Disposition: form-data; name=\"file\"; filename=\"optional.txt\"\r\nContent-Type: text/plain\r\n\r\noptional file content here\r\n--alef-boundary--\r\n";
@@ -2108,6 +2268,9 @@ mod tests {
             cookies: Arc::new(HashMap::new()),
             method: "POST".to_string(),
             path: "/upload".to_string(),
+            client_ip: None,
+            scheme: None,
+            host: None,
             #[cfg(feature = "di")]
             dependencies: None,
         };
@@ -2155,6 +2318,10 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            auth: None,
         };
 
         let mut headers = HashMap::new();
@@ -2173,6 +2340,9 @@ mod tests {
             cookies: Arc::new(HashMap::new()),
             method: "POST".to_string(),
             path: "/upload".to_string(),
+            client_ip: None,
+            scheme: None,
+            host: None,
             #[cfg(feature = "di")]
             dependencies: None,
         };
@@ -2222,6 +2392,10 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            auth: None,
         };
 
         let mut headers = HashMap::new();
@@ -2241,6 +2415,9 @@ mod tests {
             cookies: Arc::new(HashMap::new()),
             method: "POST".to_string(),
             path: "/upload".to_string(),
+            client_ip: None,
+            scheme: None,
+            host: None,
             #[cfg(feature = "di")]
             dependencies: None,
         };
@@ -2289,6 +2466,10 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            auth: None,
         };
 
         let inner = Arc::new(SuccessEchoHandler);
@@ -2314,6 +2495,9 @@ mod tests {
             cookies: Arc::new(HashMap::new()),
             method: "GET".to_string(),
             path: "/type-syntax/items/not-a-uuid".to_string(),
+            client_ip: None,
+            scheme: None,
+            host: None,
             #[cfg(feature = "di")]
             dependencies: None,
         };
@@ -2361,6 +2545,10 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            auth: None,
         };
 
         let inner = Arc::new(SuccessEchoHandler);
@@ -2386,6 +2574,9 @@ mod tests {
             cookies: Arc::new(HashMap::new()),
             method: "POST".to_string(),
             path: "/parse".to_string(),
+            client_ip: None,
+            scheme: None,
+            host: None,
             #[cfg(feature = "di")]
             dependencies: None,
         };
@@ -2424,6 +2615,10 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            auth: None,
         };
 
         let inner = Arc::new(SuccessEchoHandler);
@@ -2449,6 +2644,9 @@ mod tests {
             cookies: Arc::new(HashMap::new()),
             method: "POST".to_string(),
             path: "/parse".to_string(),
+            client_ip: None,
+            scheme: None,
+            host: None,
             #[cfg(feature = "di")]
             dependencies: None,
         };
@@ -2495,6 +2693,10 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            auth: None,
         };
 
         let inner = Arc::new(SuccessEchoHandler);
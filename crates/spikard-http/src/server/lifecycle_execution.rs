@@ -4,6 +4,7 @@ use crate::handler_trait::Handler;
 use axum::body::Body;
 use axum::http::StatusCode;
 use std::sync::Arc;
+use tracing::Instrument;
 
 /// Execute a handler with lifecycle hooks
 ///
@@ -29,7 +30,11 @@ pub async fn execute_with_lifecycle_hooks(
         return handler.call(req, request_data).await;
     }
 
-    let req = match hooks.execute_on_request(req).await {
+    let req = match hooks
+        .execute_on_request(req)
+        .instrument(tracing::info_span!("lifecycle.on_request"))
+        .await
+    {
         Ok(HookResult::Continue(r)) => r,
         Ok(HookResult::ShortCircuit(response)) => return Ok(response),
         Err(e) => {
@@ -38,7 +43,11 @@ pub async fn execute_with_lifecycle_hooks(
                 .body(Body::from(format!("{{\"error\":\"onRequest hook failed: {}\"}}", e)))
                 .unwrap();
 
-            return match hooks.execute_on_error(error_response).await {
+            return match hooks
+                .execute_on_error(error_response)
+                .instrument(tracing::info_span!("lifecycle.on_error"))
+                .await
+            {
                 Ok(resp) => Ok(resp),
                 Err(_) => Ok(axum::http::Response::builder()
                     .status(StatusCode::INTERNAL_SERVER_ERROR)
@@ -48,7 +57,11 @@ pub async fn execute_with_lifecycle_hooks(
         }
     };
 
-    let req = match hooks.execute_pre_validation(req).await {
+    let req = match hooks
+        .execute_pre_validation(req)
+        .instrument(tracing::info_span!("lifecycle.pre_validation"))
+        .await
+    {
         Ok(HookResult::Continue(r)) => r,
         Ok(HookResult::ShortCircuit(response)) => return Ok(response),
         Err(e) => {
@@ -60,7 +73,11 @@ pub async fn execute_with_lifecycle_hooks(
                 )))
                 .unwrap();
 
-            return match hooks.execute_on_error(error_response).await {
+            return match hooks
+                .execute_on_error(error_response)
+                .instrument(tracing::info_span!("lifecycle.on_error"))
+                .await
+            {
                 Ok(resp) => Ok(resp),
                 Err(_) => Ok(axum::http::Response::builder()
                     .status(StatusCode::INTERNAL_SERVER_ERROR)
@@ -70,7 +87,11 @@ pub async fn execute_with_lifecycle_hooks(
         }
     };
 
-    let req = match hooks.execute_pre_handler(req).await {
+    let req = match hooks
+        .execute_pre_handler(req)
+        .instrument(tracing::info_span!("lifecycle.pre_handler"))
+        .await
+    {
         Ok(HookResult::Continue(r)) => r,
         Ok(HookResult::ShortCircuit(response)) => return Ok(response),
         Err(e) => {
@@ -79,7 +100,11 @@ pub async fn execute_with_lifecycle_hooks(
                 .body(Body::from(format!("{{\"error\":\"preHandler hook failed: {}\"}}", e)))
                 .unwrap();
 
-            return match hooks.execute_on_error(error_response).await {
+            return match hooks
+                .execute_on_error(error_response)
+                .instrument(tracing::info_span!("lifecycle.on_error"))
+                .await
+            {
                 Ok(resp) => Ok(resp),
                 Err(_) => Ok(axum::http::Response::builder()
                     .status(StatusCode::INTERNAL_SERVER_ERROR)
@@ -97,7 +122,11 @@ pub async fn execute_with_lifecycle_hooks(
                 .body(Body::from(message))
                 .unwrap();
 
-            return match hooks.execute_on_error(error_response).await {
+            return match hooks
+                .execute_on_error(error_response)
+                .instrument(tracing::info_span!("lifecycle.on_error"))
+                .await
+            {
                 Ok(resp) => Ok(resp),
                 Err(e) => Ok(axum::http::Response::builder()
                     .status(StatusCode::INTERNAL_SERVER_ERROR)
@@ -107,7 +136,11 @@ pub async fn execute_with_lifecycle_hooks(
         }
     };
 
-    match hooks.execute_on_response(response).await {
+    match hooks
+        .execute_on_response(response)
+        .instrument(tracing::info_span!("lifecycle.on_response"))
+        .await
+    {
         Ok(resp) => Ok(resp),
         Err(e) => Ok(axum::http::Response::builder()
             .status(StatusCode::INTERNAL_SERVER_ERROR)
@@ -168,6 +201,9 @@ mod tests {
             cookies: std::sync::Arc::new(HashMap::new()),
             method: "GET".to_string(),
             path: "/".to_string(),
+            client_ip: None,
+            scheme: None,
+            host: None,
             #[cfg(feature = "di")]
             dependencies: None,
         }
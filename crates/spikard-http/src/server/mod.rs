@@ -8,22 +8,23 @@ pub mod grpc_routing;
 pub(crate) mod handler;
 pub(crate) mod lifecycle_execution;
 pub(crate) mod request_extraction;
+pub mod response_cache;
 
 use crate::handler_trait::{Handler, HandlerResult, RequestData};
+use crate::rate_limit::RateLimiting;
 use crate::{CorsConfig, ServerConfig};
 use axum::Router as AxumRouter;
 use axum::body::Body;
 use axum::extract::{DefaultBodyLimit, Path};
 use axum::http::StatusCode;
 use axum::routing::{MethodRouter, get, post};
+use response_cache::ResponseCache;
 use spikard_core::type_hints;
 use std::collections::HashMap;
 use std::net::SocketAddr;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::net::TcpListener;
-use tower_governor::governor::GovernorConfigBuilder;
-use tower_governor::key_extractor::GlobalKeyExtractor;
 use tower_http::compression::CompressionLayer;
 use tower_http::compression::predicate::{NotForContentType, Predicate, SizeAbove};
 use tower_http::request_id::{MakeRequestId, PropagateRequestIdLayer, RequestId, SetRequestIdLayer};
@@ -42,6 +43,7 @@ type RouteHandlerPair = (crate::Route, Arc<dyn Handler>);
 struct GrpcMiddlewareState {
     registry: Arc<crate::grpc::GrpcRegistry>,
     config: crate::grpc::GrpcConfig,
+    metrics: Option<Arc<crate::metrics::MetricsRegistry>>,
 }
 
 /// Extract required dependencies from route metadata
@@ -52,6 +54,90 @@ fn extract_handler_dependencies(route: &crate::Route) -> Vec<String> {
     route.handler_dependencies.clone()
 }
 
+/// Every dependency consumer of the server, as `(label, keys)`
+///
+/// HTTP routes are labelled `METHOD /path`; WebSocket and SSE endpoints, gRPC methods
+/// and readiness checks carry the labels they were declared with.
+#[cfg(feature = "di")]
+fn dependency_consumers<'a>(
+    routes: &'a [RouteHandlerPair],
+    config: &'a ServerConfig,
+    grpc_registry: Option<&'a crate::grpc::GrpcRegistry>,
+) -> Vec<(String, &'a [String])> {
+    let mut consumers: Vec<(String, &[String])> = routes
+        .iter()
+        .filter(|(route, _)| !route.handler_dependencies.is_empty())
+        .map(|(route, _)| {
+            (
+                format!("{} {}", route.method.as_str(), route.path),
+                route.handler_dependencies.as_slice(),
+            )
+        })
+        .collect();
+    consumers.extend(
+        config
+            .endpoint_dependencies
+            .iter()
+            .map(|(label, keys)| (label.clone(), keys.as_slice())),
+    );
+    if let Some(checks) = config.health_checks.as_deref() {
+        consumers.extend(checks.dependencies());
+    }
+    if let Some(registry) = grpc_registry {
+        let mut grpc: Vec<_> = registry.dependencies().collect();
+        grpc.sort_by(|a, b| a.0.cmp(&b.0));
+        consumers.extend(grpc);
+    }
+    consumers
+}
+
+/// Validate the dependency wiring of routes, endpoints and readiness checks
+///
+/// The report covers the same consumers the router checks when it is built.
+#[cfg(feature = "di")]
+pub fn dependency_report(
+    routes: &[(crate::Route, Arc<dyn Handler>)],
+    config: &ServerConfig,
+) -> spikard_core::di::DependencyReport {
+    validate_consumers(&dependency_consumers(routes, config, None), config)
+}
+
+#[cfg(feature = "di")]
+fn validate_consumers(consumers: &[(String, &[String])], config: &ServerConfig) -> spikard_core::di::DependencyReport {
+    let empty = spikard_core::di::DependencyContainer::new();
+    let container = config.di_container.as_deref().unwrap_or(&empty);
+    container.validate(consumers.iter().map(|(label, keys)| (label.as_str(), *keys)))
+}
+
+/// Check declared dependencies against the container before any request resolves them
+///
+/// Unknown keys, scope violations and cycles fail the build with the report. Unused
+/// registrations are logged as warnings, or at debug level under `lenient_dependencies`.
+#[cfg(feature = "di")]
+fn check_dependency_wiring(
+    routes: &[RouteHandlerPair],
+    config: &ServerConfig,
+    grpc_registry: Option<&crate::grpc::GrpcRegistry>,
+) -> Result<(), String> {
+    let consumers = dependency_consumers(routes, config, grpc_registry);
+    if consumers.is_empty() && config.di_container.is_none() {
+        return Ok(());
+    }
+
+    let report = validate_consumers(&consumers, config);
+    if !report.is_valid() {
+        return Err(report.to_string());
+    }
+    if !report.is_clean() {
+        if config.lenient_dependencies {
+            tracing::debug!("{report}");
+        } else {
+            tracing::warn!("{report}");
+        }
+    }
+    Ok(())
+}
+
 /// Determines if a method typically has a request body
 fn method_expects_body(method: &crate::Method) -> bool {
     matches!(method, crate::Method::Post | crate::Method::Put | crate::Method::Patch)
@@ -94,6 +180,11 @@ fn route_to_metadata(route: &crate::Route) -> crate::RouteMetadata {
             compression: route.compression.clone(),
             body_limit: route.body_limit,
             request_timeout_secs: route.request_timeout_secs,
+            rate_limit: route.rate_limit.clone(),
+            caching: route.caching.clone(),
+            response_cache: route.response_cache.clone(),
+            tags: Vec::new(),
+            auth: route.auth.clone(),
         }
     }
     #[cfg(not(feature = "di"))]
@@ -126,6 +217,11 @@ fn route_to_metadata(route: &crate::Route) -> crate::RouteMetadata {
             compression: route.compression.clone(),
             body_limit: route.body_limit,
             request_timeout_secs: route.request_timeout_secs,
+            rate_limit: route.rate_limit.clone(),
+            caching: route.caching.clone(),
+            response_cache: route.response_cache.clone(),
+            tags: Vec::new(),
+            auth: route.auth.clone(),
         }
     }
 }
@@ -163,15 +259,49 @@ async fn grpc_routing_middleware(
     next: axum::middleware::Next,
 ) -> axum::response::Response {
     if grpc_routing::is_grpc_request(&request) {
-        return match grpc_routing::route_grpc_request(Arc::clone(&state.registry), &state.config, request).await {
+        let call = state.metrics.as_ref().and_then(|metrics| {
+            let (service, method) = crate::grpc::parse_grpc_path(request.uri().path()).ok()?;
+            Some((Arc::clone(metrics), service, method))
+        });
+        let started = std::time::Instant::now();
+        let response = match grpc_routing::route_grpc_request(Arc::clone(&state.registry), &state.config, request).await
+        {
             Ok(response) => response,
             Err((status, body)) => error_to_response(status, body),
         };
+        if let Some((metrics, service, method)) = call {
+            let code = grpc_code_label(&response);
+            if state.registry.get(&service, &method).is_some() {
+                metrics.record_grpc(&service, &method, &code, started.elapsed());
+            } else {
+                // Keep label cardinality bounded for arbitrary client paths.
+                metrics.record_grpc("unknown", "unknown", &code, started.elapsed());
+            }
+        }
+        return response;
     }
 
     next.run(request).await
 }
 
+/// gRPC status code for the metrics `code` label
+///
+/// Streaming calls report their final status in trailers, after the response has been
+/// recorded, so they are labeled `streaming`.
+fn grpc_code_label(response: &axum::response::Response) -> String {
+    if let Some(code) = response.headers().get("grpc-status").and_then(|v| v.to_str().ok()) {
+        return code.to_string();
+    }
+    let code = match response.status() {
+        status if status.is_success() => return "streaming".to_string(),
+        StatusCode::NOT_FOUND => tonic::Code::Unimplemented,
+        StatusCode::BAD_REQUEST => tonic::Code::InvalidArgument,
+        StatusCode::PAYLOAD_TOO_LARGE => tonic::Code::ResourceExhausted,
+        _ => tonic::Code::Internal,
+    };
+    (code as i32).to_string()
+}
+
 #[inline]
 async fn call_with_optional_hooks(
     req: axum::http::Request<Body>,
@@ -179,7 +309,7 @@ async fn call_with_optional_hooks(
     handler: Arc<dyn Handler>,
     hooks: Option<Arc<crate::LifecycleHooks>>,
 ) -> HandlerResult {
-    let request_data = if let Some(claims) = req.extensions().get::<crate::auth::Claims>() {
+    let mut request_data = if let Some(claims) = req.extensions().get::<crate::auth::Claims>() {
         let mut request_data = request_data;
         if let Ok(serialized_claims) = serde_json::to_string(claims) {
             let mut headers = (*request_data.headers).clone();
@@ -190,6 +320,26 @@ async fn call_with_optional_hooks(
     } else {
         request_data
     };
+    if let Some(client) = req.extensions().get::<crate::proxy::ClientInfo>() {
+        client.apply_to(&mut request_data);
+    }
+    let session = req.extensions().get::<crate::session::Session>();
+    let csrf_token = req.extensions().get::<crate::csrf::CsrfToken>();
+    if session.is_some() || csrf_token.is_some() {
+        let mut headers = (*request_data.headers).clone();
+        if let Some(session) = session
+            && let Ok(serialized) = serde_json::to_string(&session.data())
+        {
+            headers.insert(crate::session::INTERNAL_SESSION_HEADER.to_string(), serialized);
+        }
+        if let Some(token) = csrf_token {
+            headers.insert(
+                crate::csrf::INTERNAL_CSRF_TOKEN_HEADER.to_string(),
+                token.as_str().to_string(),
+            );
+        }
+        request_data.headers = Arc::new(headers);
+    }
 
     if hooks.as_ref().is_some_and(|h| !h.is_empty()) {
         lifecycle_execution::execute_with_lifecycle_hooks(req, request_data, handler, hooks).await
@@ -570,7 +720,15 @@ pub fn build_router_with_handlers(
     routes: Vec<(crate::Route, Arc<dyn Handler>)>,
     hooks: Option<Arc<crate::LifecycleHooks>>,
 ) -> Result<AxumRouter, String> {
-    build_router_with_handlers_inner(routes, hooks, None, true)
+    build_router_with_handlers_inner(
+        routes,
+        hooks,
+        None,
+        true,
+        &RateLimiting::default(),
+        &ResponseCache::default(),
+        None,
+    )
 }
 
 /// Build an Axum router from routes and foreign handlers with optional DI container
@@ -580,7 +738,31 @@ pub fn build_router_with_handlers(
     hooks: Option<Arc<crate::LifecycleHooks>>,
     di_container: Option<Arc<spikard_core::di::DependencyContainer>>,
 ) -> Result<AxumRouter, String> {
-    build_router_with_handlers_inner(routes, hooks, di_container, true)
+    build_router_with_handlers_inner(
+        routes,
+        hooks,
+        di_container,
+        true,
+        &RateLimiting::default(),
+        &ResponseCache::default(),
+        None,
+    )
+}
+
+/// Hooks to run for one route: the global hooks followed by the route's own hooks
+fn route_lifecycle_hooks(
+    global: Option<&Arc<crate::LifecycleHooks>>,
+    route: Option<Arc<crate::LifecycleHooks>>,
+) -> Option<Arc<crate::LifecycleHooks>> {
+    match (global, route) {
+        (Some(global), Some(route)) if !route.is_empty() => {
+            let mut merged = (**global).clone();
+            merged.extend(&route);
+            Some(Arc::new(merged))
+        }
+        (None, Some(route)) if !route.is_empty() => Some(route),
+        (global, _) => global.cloned(),
+    }
 }
 
 fn build_router_with_handlers_inner(
@@ -589,6 +771,9 @@ fn build_router_with_handlers_inner(
     #[cfg(feature = "di")] di_container: Option<Arc<spikard_core::di::DependencyContainer>>,
     #[cfg(not(feature = "di"))] _di_container: Option<()>,
     enable_http_trace: bool,
+    rate_limiting: &RateLimiting,
+    response_cache: &ResponseCache,
+    body_codecs: Option<&Arc<crate::BodyCodecRegistry>>,
 ) -> Result<AxumRouter, String> {
     let mut app = AxumRouter::new();
     let mut fast_router = fast_router::FastRouter::new();
@@ -609,9 +794,16 @@ fn build_router_with_handlers_inner(
             .remove(&path)
             .ok_or_else(|| format!("Missing handlers for path '{}'", path))?;
 
-        type RouteEntry = (crate::Route, Arc<dyn Handler>, Option<crate::StaticResponse>);
+        type RouteEntry = (
+            crate::Route,
+            Arc<dyn Handler>,
+            Option<crate::StaticResponse>,
+            Option<Arc<crate::LifecycleHooks>>,
+        );
         let mut handlers_by_method: HashMap<crate::Method, RouteEntry> = HashMap::new();
         for (route, handler) in route_handlers {
+            let route_hooks = route_lifecycle_hooks(hooks.as_ref(), handler.lifecycle_hooks());
+
             #[cfg(feature = "di")]
             let handler = if let Some(ref container) = di_container {
                 let required_deps = extract_handler_dependencies(&route);
@@ -630,20 +822,25 @@ fn build_router_with_handlers_inner(
 
             let static_resp = handler.static_response();
             let validating_handler = Arc::new(handler::ValidatingHandler::new(handler, &route));
-            handlers_by_method.insert(route.method.clone(), (route, validating_handler, static_resp));
+            handlers_by_method.insert(
+                route.method.clone(),
+                (route, validating_handler, static_resp, route_hooks),
+            );
         }
 
         let cors_config: Option<CorsConfig> = handlers_by_method
             .values()
-            .find_map(|(route, _, _)| route.cors.as_ref())
+            .find_map(|(route, _, _, _)| route.cors.as_ref())
             .cloned();
 
         let has_options_handler = handlers_by_method.keys().any(|m| m.as_str() == "OPTIONS");
 
         let mut combined_router: Option<MethodRouter> = None;
         let has_path_params = path.contains('{');
+        let mut has_caching = false;
+        let mut has_get = false;
 
-        for (_method, (route, handler, static_resp_opt)) in handlers_by_method {
+        for (_method, (route, handler, static_resp_opt, route_hooks)) in handlers_by_method {
             let method = route.method.clone();
 
             // NOTE: static routes also bypass CORS handling, content-type
@@ -707,7 +904,7 @@ fn build_router_with_handlers_inner(
                             method,
                             has_path_params,
                             handler,
-                            hooks.clone(),
+                            route_hooks.clone(),
                             include_raw_query_params,
                             include_query_params_json,
                         )
@@ -720,7 +917,7 @@ fn build_router_with_handlers_inner(
                         method,
                         has_path_params,
                         handler,
-                        hooks.clone(),
+                        route_hooks,
                         include_raw_query_params,
                         include_query_params_json,
                     )
@@ -735,6 +932,7 @@ fn build_router_with_handlers_inner(
                 method_router.layer(axum::middleware::from_fn_with_state(
                     crate::middleware::RouteInfo {
                         expects_json_body: route.expects_json_body,
+                        body_codecs: body_codecs.cloned(),
                     },
                     crate::middleware::validate_content_type_middleware,
                 ))
@@ -760,6 +958,55 @@ fn build_router_with_handlers_inner(
                 method_router
             };
 
+            let method_router = if let Some(ref cache_config) = route.response_cache {
+                method_router.layer(axum::middleware::from_fn_with_state(
+                    Arc::new(response_cache::RouteCache::new(
+                        &route.method,
+                        &path,
+                        cache_config,
+                        response_cache,
+                    )?),
+                    response_cache::response_cache_middleware,
+                ))
+            } else {
+                method_router
+            };
+
+            let method_router = if let Some(ref rate_limit) = route.rate_limit {
+                let scope = format!("{} {}", route.method.as_str(), path);
+                method_router.layer(axum::middleware::from_fn_with_state(
+                    rate_limiting.layer_state(scope, rate_limit)?,
+                    crate::rate_limit::rate_limit_middleware,
+                ))
+            } else {
+                method_router
+            };
+
+            let method_router = if let Some(ref caching) = route.caching {
+                has_caching = true;
+                method_router.layer(axum::middleware::from_fn_with_state(
+                    Arc::new(crate::conditional::CachingState::new(caching)?),
+                    crate::conditional::caching_middleware,
+                ))
+            } else {
+                method_router
+            };
+
+            let method_router = match route.auth.as_ref().and_then(|auth| auth.jwt.clone()) {
+                Some(jwt_config) => method_router.layer(axum::middleware::from_fn(move |headers, req, next| {
+                    crate::auth::jwt_auth_middleware(jwt_config.clone(), headers, req, next)
+                })),
+                None => method_router,
+            };
+
+            let method_router = match route.auth.as_ref().and_then(|auth| auth.api_key.clone()) {
+                Some(api_key_config) => method_router.layer(axum::middleware::from_fn(move |headers, req, next| {
+                    crate::auth::api_key_auth_middleware(api_key_config.clone(), headers, req, next)
+                })),
+                None => method_router,
+            };
+            has_get |= route.method == crate::Method::Get;
+
             combined_router = Some(match combined_router {
                 None => method_router,
                 Some(existing) => existing.merge(method_router),
@@ -784,6 +1031,15 @@ fn build_router_with_handlers_inner(
             tracing::info!("Auto-generated OPTIONS handler for CORS preflight: {}", path);
         }
 
+        // Preconditions on writes are checked against the path's GET representation
+        let combined_router = match combined_router {
+            Some(router) if has_caching && has_get => Some(router.clone().layer(axum::middleware::from_fn_with_state(
+                router,
+                crate::conditional::precondition_middleware,
+            ))),
+            router => router,
+        };
+
         if let Some(router) = combined_router {
             let mut axum_path = type_hints::strip_type_hints(&path);
             if !axum_path.starts_with('/') {
@@ -830,7 +1086,7 @@ pub fn build_router_with_handlers_and_config(
 /// Build router with handlers, config, and an optional gRPC service registry.
 pub(crate) fn build_router_with_handlers_and_config_and_grpc(
     routes: Vec<RouteHandlerPair>,
-    config: ServerConfig,
+    mut config: ServerConfig,
     route_metadata: Vec<crate::RouteMetadata>,
     grpc_registry: Option<Arc<crate::grpc::GrpcRegistry>>,
 ) -> Result<AxumRouter, String> {
@@ -843,7 +1099,10 @@ pub(crate) fn build_router_with_handlers_and_config_and_grpc(
     } else {
         eprintln!("[spikard-di] build_router: di_container is None");
     }
+    #[cfg(feature = "di")]
+    check_dependency_wiring(&routes, &config, grpc_registry.as_deref())?;
     let hooks = config.lifecycle_hooks.clone();
+    let metrics_registry = crate::metrics::ensure_registry(&mut config);
 
     let jsonrpc_registry = if let Some(ref jsonrpc_config) = config.jsonrpc {
         if jsonrpc_config.enabled {
@@ -853,6 +1112,18 @@ pub(crate) fn build_router_with_handlers_and_config_and_grpc(
                 if let Some(ref jsonrpc_info) = route.jsonrpc_method {
                     let method_name = jsonrpc_info.method_name.clone();
 
+                    #[cfg(feature = "di")]
+                    let handler = &match config.di_container {
+                        Some(ref container) if !route.handler_dependencies.is_empty() => {
+                            Arc::new(crate::di_handler::DependencyInjectingHandler::new(
+                                Arc::clone(handler),
+                                Arc::clone(container),
+                                route.handler_dependencies.clone(),
+                            )) as Arc<dyn Handler>
+                        }
+                        _ => Arc::clone(handler),
+                    };
+
                     let metadata = crate::jsonrpc::MethodMetadata::new(&method_name)
                         .with_params_schema(jsonrpc_info.params_schema.clone().unwrap_or(serde_json::json!({})))
                         .with_result_schema(jsonrpc_info.result_schema.clone().unwrap_or(serde_json::json!({})));
@@ -901,11 +1172,30 @@ pub(crate) fn build_router_with_handlers_and_config_and_grpc(
         None
     };
 
+    let rate_limiting = RateLimiting::from_config(&config);
+    let response_cache = ResponseCache::from_config(&config);
+    let caches_responses = routes.iter().any(|(route, _)| route.response_cache.is_some());
+
     #[cfg(feature = "di")]
-    let mut app =
-        build_router_with_handlers_inner(routes, hooks, config.di_container.clone(), config.enable_http_trace)?;
+    let mut app = build_router_with_handlers_inner(
+        routes,
+        hooks,
+        config.di_container.clone(),
+        config.enable_http_trace,
+        &rate_limiting,
+        &response_cache,
+        config.body_codecs.as_ref(),
+    )?;
     #[cfg(not(feature = "di"))]
-    let mut app = build_router_with_handlers_inner(routes, hooks, None, config.enable_http_trace)?;
+    let mut app = build_router_with_handlers_inner(
+        routes,
+        hooks,
+        None,
+        config.enable_http_trace,
+        &rate_limiting,
+        &response_cache,
+        config.body_codecs.as_ref(),
+    )?;
 
     if let (Some(grpc_config), Some(registry)) = (config.grpc.clone(), grpc_registry)
         && !registry.is_empty()
@@ -913,6 +1203,7 @@ pub(crate) fn build_router_with_handlers_and_config_and_grpc(
         let state = GrpcMiddlewareState {
             registry,
             config: grpc_config,
+            metrics: metrics_registry.clone(),
         };
         app = app.layer(axum::middleware::from_fn_with_state(state, grpc_routing_middleware));
     }
@@ -924,6 +1215,18 @@ pub(crate) fn build_router_with_handlers_and_config_and_grpc(
         ]));
     }
 
+    if let Some(ref encoders) = config.response_encoders {
+        app = app.layer(axum::middleware::from_fn_with_state(
+            Arc::clone(encoders),
+            crate::negotiation::negotiation_middleware,
+        ));
+    }
+
+    if let Some(ref recording) = config.recording {
+        app = crate::recording::record_router(app, recording);
+        tracing::info!("Recording fixtures to {}", recording.output_dir);
+    }
+
     if let Some(ref compression) = config.compression {
         let mut compression_layer = CompressionLayer::new();
         if !compression.gzip {
@@ -943,27 +1246,34 @@ pub(crate) fn build_router_with_handlers_and_config_and_grpc(
         app = app.layer(compression_layer);
     }
 
+    if caches_responses {
+        app = app.layer(axum::middleware::from_fn_with_state(
+            response_cache,
+            response_cache::cache_invalidation_middleware,
+        ));
+    }
+
+    if let Some(ref csrf) = config.csrf {
+        let state = crate::csrf::CsrfState::new(csrf, config.session.is_some())?;
+        app = app.layer(axum::middleware::from_fn_with_state(
+            Arc::new(state),
+            crate::csrf::csrf_middleware,
+        ));
+    }
+
+    if let Some(ref session) = config.session {
+        let manager = crate::session::SessionManager::new(session, config.session_store.clone())?;
+        app = app.layer(axum::middleware::from_fn_with_state(
+            Arc::new(manager),
+            crate::session::session_middleware,
+        ));
+    }
+
     if let Some(ref rate_limit) = config.rate_limit {
-        if rate_limit.ip_based {
-            let governor_conf = Arc::new(
-                GovernorConfigBuilder::default()
-                    .per_second(rate_limit.per_second)
-                    .burst_size(rate_limit.burst)
-                    .finish()
-                    .ok_or_else(|| "Failed to create rate limiter".to_string())?,
-            );
-            app = app.layer(tower_governor::GovernorLayer::new(governor_conf));
-        } else {
-            let governor_conf = Arc::new(
-                GovernorConfigBuilder::default()
-                    .per_second(rate_limit.per_second)
-                    .burst_size(rate_limit.burst)
-                    .key_extractor(GlobalKeyExtractor)
-                    .finish()
-                    .ok_or_else(|| "Failed to create rate limiter".to_string())?,
-            );
-            app = app.layer(tower_governor::GovernorLayer::new(governor_conf));
-        }
+        app = app.layer(axum::middleware::from_fn_with_state(
+            rate_limiting.layer_state("global", rate_limit)?,
+            crate::rate_limit::rate_limit_middleware,
+        ));
     }
 
     if let Some(ref jwt_config) = config.jwt_auth {
@@ -1038,7 +1348,23 @@ pub(crate) fn build_router_with_handlers_and_config_and_grpc(
             .map_err(|e| format!("Failed to parse OpenAPI spec: {}", e))?;
 
         let openapi_json_path = openapi_config.openapi_json_path.clone();
-        app = app.route(&openapi_json_path, get(move || async move { Json(spec_value) }));
+        let infer_servers = openapi_config.servers.is_empty();
+        app = app.route(
+            &openapi_json_path,
+            get(
+                move |client: Option<axum::Extension<crate::proxy::ClientInfo>>| async move {
+                    // Without configured servers, advertise the origin the client reached us on.
+                    match client.and_then(|axum::Extension(client)| client.origin()) {
+                        Some(origin) if infer_servers => {
+                            let mut spec = spec_value;
+                            spec["servers"] = serde_json::json!([{ "url": origin }]);
+                            Json(spec)
+                        }
+                        _ => Json(spec_value),
+                    }
+                },
+            ),
+        );
 
         let swagger_html = format!(
             r#"<!DOCTYPE html>
@@ -1104,11 +1430,14 @@ pub(crate) fn build_router_with_handlers_and_config_and_grpc(
     {
         use axum::response::Json;
 
-        let jsonrpc_router = Arc::new(crate::jsonrpc::JsonRpcRouter::new(
-            Arc::clone(&registry),
-            jsonrpc_config.enable_batch,
-            jsonrpc_config.max_batch_size,
-        ));
+        let jsonrpc_router = Arc::new(
+            crate::jsonrpc::JsonRpcRouter::new(
+                Arc::clone(&registry),
+                jsonrpc_config.enable_batch,
+                jsonrpc_config.max_batch_size,
+            )
+            .with_metrics(metrics_registry.clone()),
+        );
 
         let state = Arc::new(crate::jsonrpc::JsonRpcState { router: jsonrpc_router });
 
@@ -1121,6 +1450,32 @@ pub(crate) fn build_router_with_handlers_and_config_and_grpc(
         tracing::info!("OpenRPC documentation enabled at /openrpc.json");
     }
 
+    if let Some(ref registry) = metrics_registry {
+        app = crate::metrics::instrument_router(app, Arc::clone(registry));
+    }
+
+    if let Some(ref telemetry) = config.telemetry {
+        app = crate::telemetry::trace_router(app, Arc::new(telemetry.clone()));
+    }
+
+    if let (Some(metrics_config), Some(registry)) = (config.metrics.as_ref(), metrics_registry) {
+        app = crate::metrics::metrics_route(app, &metrics_config.path, registry);
+
+        tracing::info!("Prometheus metrics enabled at {}", metrics_config.path);
+    }
+
+    if let Some(ref health) = config.health {
+        app = crate::health::health_routes(app, &config, health);
+
+        tracing::info!(
+            "Health endpoints enabled at {} (liveness) and {} (readiness)",
+            health.liveness_path,
+            health.readiness_path
+        );
+    }
+
+    app = crate::proxy::client_info_router(app, config.trusted_proxies.as_ref())?;
+
     Ok(app)
 }
 
@@ -1151,11 +1506,79 @@ impl Server {
         build_router_with_handlers_and_config(routes, config, metadata)
     }
 
+    /// Run the configured startup hooks and register the values they provide as
+    /// singleton dependencies.
+    ///
+    /// Call this before building the router: handlers capture the dependency container
+    /// when the router is built, so values registered afterwards are not visible to them.
+    ///
+    /// # Errors
+    /// Returns the first startup hook failure or timeout, or a registration error when
+    /// the dependency container is already shared with a built router.
+    pub async fn startup(config: &mut ServerConfig) -> Result<(), crate::LifespanError> {
+        let Some(lifespan) = config.lifespan.clone() else {
+            return Ok(());
+        };
+        lifespan.run_startup().await?;
+
+        #[cfg(feature = "di")]
+        {
+            let provided = lifespan.context().take_dependencies();
+            if !provided.is_empty() {
+                let mut container = match config.di_container.take() {
+                    Some(container) => Arc::try_unwrap(container).map_err(|shared| {
+                        config.di_container = Some(shared);
+                        crate::LifespanError::Dependencies(
+                            "dependency container is already in use by a built router".to_string(),
+                        )
+                    })?,
+                    None => spikard_core::di::DependencyContainer::new(),
+                };
+                for (key, dependency) in provided {
+                    container
+                        .register(key, dependency)
+                        .map_err(|err| crate::LifespanError::Dependencies(err.to_string()))?;
+                }
+                config.di_container = Some(Arc::new(container));
+            }
+        }
+
+        Ok(())
+    }
+
     /// Run the server with the Axum router and config
     ///
+    /// Startup hooks that have not run yet (see [`Server::startup`]) run before the
+    /// listener binds; shutdown hooks run after the server stops.
+    ///
     /// Coverage: Production-only, tested via integration tests
     #[cfg(not(tarpaulin_include))]
     pub async fn run_with_config(app: AxumRouter, config: ServerConfig) -> Result<(), Box<dyn std::error::Error>> {
+        #[cfg(feature = "otel")]
+        let _telemetry = match config.telemetry {
+            Some(ref telemetry) => match Self::init_telemetry(telemetry) {
+                Ok(guard) => Some(guard),
+                Err(crate::TelemetryError::SubscriberInstalled) => {
+                    tracing::warn!("Tracing subscriber already installed; spans will not be exported over OTLP");
+                    None
+                }
+                Err(err) => return Err(err.into()),
+            },
+            None => None,
+        };
+
+        if let Some(ref lifespan) = config.lifespan
+            && !lifespan.has_started()
+        {
+            lifespan.run_startup().await?;
+            #[cfg(feature = "di")]
+            if !lifespan.context().take_dependencies().is_empty() {
+                tracing::warn!(
+                    "Values provided by startup hooks are not injectable: call Server::startup before building the router"
+                );
+            }
+        }
+
         // ~keep Honor SPIKARD_SERVER_PORT when set so e2e harnesses (and any deployment
         // ~keep that injects a port) can bind a runtime-chosen port without an explicit
         // ~keep config call. Falls back to the configured port (default 8000).
@@ -1169,15 +1592,31 @@ impl Server {
 
         tracing::info!("Listening on http://{}", socket_addr);
 
-        if config.graceful_shutdown {
+        let served = if config.graceful_shutdown {
+            let drain = crate::health::DrainSignal::new();
+            let app = drain.attach(app);
+            let drain_delay = Duration::from_secs(config.health.as_ref().map_or(0, |health| health.drain_delay_secs));
             axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>())
-                .with_graceful_shutdown(shutdown_signal())
-                .await?;
+                .with_graceful_shutdown(async move {
+                    shutdown_signal().await;
+                    drain.begin();
+                    if !drain_delay.is_zero() {
+                        tracing::info!("Reporting not ready; draining for {:?} before shutdown", drain_delay);
+                        tokio::time::sleep(drain_delay).await;
+                    }
+                })
+                .await
         } else {
-            axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>()).await?;
-        }
+            axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>()).await
+        };
 
-        Ok(())
+        // Shutdown hooks log their own failures; an error from serving takes precedence.
+        let shut_down = match config.lifespan {
+            Some(ref lifespan) => lifespan.run_shutdown().await,
+            None => Ok(()),
+        };
+        served?;
+        Ok(shut_down?)
     }
 
     /// Initialize logging
@@ -1195,6 +1634,31 @@ impl Server {
             .with(tracing_subscriber::fmt::layer())
             .try_init();
     }
+
+    /// Initialize logging together with OTLP span export
+    ///
+    /// Installs the same subscriber as [`Server::init_logging`] plus an OpenTelemetry
+    /// layer. Spans are flushed when the returned guard is dropped. Fails with
+    /// [`TelemetryError::SubscriberInstalled`](crate::TelemetryError::SubscriberInstalled)
+    /// when a global subscriber is already set.
+    #[cfg(feature = "otel")]
+    pub fn init_telemetry(config: &crate::TelemetryConfig) -> Result<crate::TelemetryGuard, crate::TelemetryError> {
+        use opentelemetry::trace::TracerProvider as _;
+
+        let provider = crate::telemetry::tracer_provider(config)?;
+        let tracer = provider.tracer("spikard");
+        tracing_subscriber::registry()
+            .with(
+                tracing_subscriber::EnvFilter::try_from_default_env()
+                    .unwrap_or_else(|_| "spikard=info,tower_http=info".into()),
+            )
+            .with(tracing_subscriber::fmt::layer())
+            .with(tracing_opentelemetry::layer().with_tracer(tracer))
+            .try_init()
+            .map_err(|_| crate::TelemetryError::SubscriberInstalled)?;
+        opentelemetry::global::set_tracer_provider(provider.clone());
+        Ok(crate::telemetry::guard(provider))
+    }
 }
 
 #[cfg(test)]
@@ -1232,6 +1696,10 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            auth: None,
             #[cfg(feature = "di")]
             handler_dependencies: vec![],
         }
@@ -1260,6 +1728,10 @@ mod tests {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            auth: None,
             #[cfg(feature = "di")]
             handler_dependencies: vec![],
         }
@@ -1740,4 +2212,183 @@ mod tests {
         let result = build_router_for_tests(routes, None);
         assert!(result.is_ok());
     }
+
+    #[tokio::test]
+    async fn test_grpc_calls_are_recorded_per_method() {
+        use crate::grpc::{GrpcHandler, GrpcHandlerResult, GrpcRequestData, GrpcResponseData, RpcMode};
+        use tower::ServiceExt;
+
+        struct EchoGrpc;
+
+        impl GrpcHandler for EchoGrpc {
+            fn call(
+                &self,
+                request: GrpcRequestData,
+            ) -> Pin<Box<dyn std::future::Future<Output = GrpcHandlerResult> + Send>> {
+                Box::pin(async move {
+                    Ok(GrpcResponseData {
+                        payload: request.payload,
+                        metadata: tonic::metadata::MetadataMap::new(),
+                    })
+                })
+            }
+
+            fn service_name(&self) -> &str {
+                "test.Echo"
+            }
+        }
+
+        let mut grpc = crate::grpc::GrpcRegistry::new();
+        grpc.register("test.Echo", "Say", Arc::new(EchoGrpc), RpcMode::Unary);
+        let config = ServerConfig {
+            grpc: Some(crate::grpc::GrpcConfig::default()),
+            metrics: Some(crate::MetricsConfig::default()),
+            ..Default::default()
+        };
+        let app = build_router_with_handlers_and_config_and_grpc(Vec::new(), config, Vec::new(), Some(Arc::new(grpc)))
+            .expect("router");
+
+        for path in ["/test.Echo/Say", "/test.Echo/Missing"] {
+            let payload = crate::grpc::framing::encode_grpc_message(bytes::Bytes::from_static(b"hi")).expect("frame");
+            let request = axum::http::Request::builder()
+                .method("POST")
+                .uri(path)
+                .header("content-type", "application/grpc")
+                .body(Body::from(payload))
+                .unwrap();
+            app.clone().oneshot(request).await.expect("grpc response");
+        }
+
+        let response = app
+            .oneshot(axum::http::Request::get("/metrics").body(Body::empty()).unwrap())
+            .await
+            .expect("metrics response");
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let text = String::from_utf8(body.to_vec()).unwrap();
+        assert!(text.contains("spikard_grpc_requests_total{service=\"test.Echo\",method=\"Say\",code=\"0\"} 1\n"));
+        assert!(text.contains("spikard_grpc_requests_total{service=\"unknown\",method=\"unknown\",code=\"12\"} 1\n"));
+        assert!(text.contains("spikard_grpc_request_duration_seconds_count{service=\"test.Echo\",method=\"Say\"} 1\n"));
+        assert!(!text.contains("spikard_http_requests_total{"));
+    }
+
+    #[cfg(feature = "di")]
+    #[test]
+    fn test_grpc_dependencies_are_validated_with_the_router() {
+        use crate::grpc::{DependencyInjectingGrpcHandler, GrpcHandler, GrpcHandlerResult, GrpcRequestData, RpcMode};
+
+        struct NoopGrpc;
+
+        impl GrpcHandler for NoopGrpc {
+            fn call(
+                &self,
+                _request: GrpcRequestData,
+            ) -> Pin<Box<dyn std::future::Future<Output = GrpcHandlerResult> + Send>> {
+                Box::pin(async { Err(tonic::Status::unimplemented("noop")) })
+            }
+
+            fn service_name(&self) -> &str {
+                "test.Users"
+            }
+        }
+
+        let container = Arc::new(spikard_core::di::DependencyContainer::new());
+        let handler = DependencyInjectingGrpcHandler::new(
+            Arc::new(NoopGrpc),
+            crate::DeclaredDependencies::new(Arc::clone(&container), vec!["db".to_string()]),
+        );
+        let mut grpc = crate::grpc::GrpcRegistry::new();
+        grpc.register("test.Users", "Get", Arc::new(handler), RpcMode::Unary);
+        let config = ServerConfig {
+            grpc: Some(crate::grpc::GrpcConfig::default()),
+            di_container: Some(container),
+            ..Default::default()
+        };
+
+        let error =
+            build_router_with_handlers_and_config_and_grpc(Vec::new(), config, Vec::new(), Some(Arc::new(grpc)))
+                .expect_err("unknown gRPC dependency must fail the build");
+        assert!(
+            error.contains("unknown dependency 'db' required by route GRPC test.Users/Get"),
+            "{error}"
+        );
+    }
+
+    #[cfg(feature = "otel")]
+    #[tokio::test]
+    async fn test_grpc_metadata_carries_server_span_context() {
+        use crate::grpc::{GrpcHandler, GrpcHandlerResult, GrpcRequestData, GrpcResponseData, RpcMode};
+        use opentelemetry::trace::TracerProvider as _;
+        use tower::ServiceExt;
+        use tracing_subscriber::layer::SubscriberExt;
+
+        struct TraceparentEcho;
+
+        impl GrpcHandler for TraceparentEcho {
+            fn call(
+                &self,
+                request: GrpcRequestData,
+            ) -> Pin<Box<dyn std::future::Future<Output = GrpcHandlerResult> + Send>> {
+                let traceparent = request
+                    .metadata
+                    .get("traceparent")
+                    .and_then(|value| value.to_str().ok())
+                    .unwrap_or_default()
+                    .to_string();
+                Box::pin(async move {
+                    Ok(GrpcResponseData {
+                        payload: bytes::Bytes::from(traceparent),
+                        metadata: tonic::metadata::MetadataMap::new(),
+                    })
+                })
+            }
+
+            fn service_name(&self) -> &str {
+                "test.Trace"
+            }
+        }
+
+        let exporter = opentelemetry_sdk::trace::InMemorySpanExporter::default();
+        let provider = opentelemetry_sdk::trace::SdkTracerProvider::builder()
+            .with_simple_exporter(exporter.clone())
+            .build();
+        let _guard = tracing::subscriber::set_default(
+            tracing_subscriber::registry().with(tracing_opentelemetry::layer().with_tracer(provider.tracer("test"))),
+        );
+
+        let mut grpc = crate::grpc::GrpcRegistry::new();
+        grpc.register("test.Trace", "Echo", Arc::new(TraceparentEcho), RpcMode::Unary);
+        let config = ServerConfig {
+            grpc: Some(crate::grpc::GrpcConfig::default()),
+            telemetry: Some(crate::TelemetryConfig::default()),
+            ..Default::default()
+        };
+        let app = build_router_with_handlers_and_config_and_grpc(Vec::new(), config, Vec::new(), Some(Arc::new(grpc)))
+            .expect("router");
+
+        let payload = crate::grpc::framing::encode_grpc_message(bytes::Bytes::new()).expect("frame");
+        let request = axum::http::Request::builder()
+            .method("POST")
+            .uri("/test.Trace/Echo")
+            .header("content-type", "application/grpc")
+            .header("traceparent", "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01")
+            .body(Body::from(payload))
+            .unwrap();
+        let response = app.oneshot(request).await.expect("grpc response");
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let echoed = String::from_utf8_lossy(&body[crate::grpc::framing::GRPC_MESSAGE_HEADER_LEN..]).to_string();
+
+        provider.force_flush().expect("flush");
+        let spans = exporter.get_finished_spans().expect("spans");
+        let server_span = spans
+            .iter()
+            .find(|span| span.name == "test.Trace/Echo")
+            .expect("server span");
+        assert_eq!(
+            echoed,
+            format!(
+                "00-4bf92f3577b34da6a3ce929d0e0e4736-{}-01",
+                server_span.span_context.span_id()
+            )
+        );
+    }
 }
@@ -183,6 +183,9 @@ pub fn create_request_data_without_body(
         raw_body: None,
         method: method.as_str().to_string(),
         path: uri.path().to_string(),
+        client_ip: None,
+        scheme: None,
+        host: None,
         #[cfg(feature = "di")]
         dependencies: None,
     }
@@ -265,6 +268,9 @@ pub async fn create_request_data_with_body(
         raw_body: if body_bytes.is_empty() { None } else { Some(body_bytes) },
         method: parts.method.as_str().to_string(),
         path: parts.uri.path().to_string(),
+        client_ip: None,
+        scheme: None,
+        host: None,
         #[cfg(feature = "di")]
         dependencies: None,
     })
@@ -0,0 +1,615 @@
+//! Server-side cache for whole handler responses.
+//!
+//! Where [`FastRouter`](super::fast_router::FastRouter) serves responses fixed at
+//! registration time, this cache stores responses produced by handlers of `GET`
+//! routes with a [`ResponseCacheConfig`] and replays them until their TTL expires.
+//! Within `stale_while_revalidate_secs` after expiry the stale response is still
+//! served while a single background call refreshes it, and concurrent misses for
+//! the same key wait for one in-flight handler call instead of each calling it.
+//!
+//! Stored responses carry tags. Rust handlers invalidate tags through the
+//! [`ResponseCache`] request extension; handlers in language bindings list tags
+//! to invalidate in the [`INTERNAL_CACHE_INVALIDATE_HEADER`] response header and
+//! may add tags to a cacheable response with [`INTERNAL_CACHE_TAGS_HEADER`]. Both
+//! headers are stripped before the response leaves the server.
+//!
+//! Responses larger than `max_entry_bytes`, or whose length is not known up front
+//! (streams), are passed through uncached instead of being buffered.
+
+use axum::{
+    body::Body,
+    extract::{FromRequestParts, RawPathParams, Request, State},
+    http::{HeaderMap, HeaderName, HeaderValue, Method, StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use bytes::Bytes;
+use http_body::Body as _;
+use lru::LruCache;
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::num::NonZeroUsize;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, SystemTime};
+use tokio::sync::watch;
+
+use crate::ResponseCacheConfig;
+
+/// Response header listing tags to invalidate (comma separated)
+pub const INTERNAL_CACHE_INVALIDATE_HEADER: &str = "x-spikard-cache-invalidate";
+
+/// Response header listing extra tags for a cacheable response (comma separated)
+pub const INTERNAL_CACHE_TAGS_HEADER: &str = "x-spikard-cache-tags";
+
+/// Default number of responses kept by [`InMemoryResponseCacheStore`]
+const DEFAULT_CAPACITY: usize = 1024;
+
+/// A stored response
+#[derive(Debug, Clone)]
+pub struct CachedResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Bytes,
+    pub tags: Vec<String>,
+    pub stored_at: SystemTime,
+    /// Served without calling the handler until this instant
+    pub fresh_until: SystemTime,
+    /// Served while refreshing in the background until this instant
+    pub stale_until: SystemTime,
+}
+
+impl CachedResponse {
+    fn to_response(&self, now: SystemTime) -> Response {
+        let mut response = Response::new(Body::from(self.body.clone()));
+        *response.status_mut() = StatusCode::from_u16(self.status).unwrap_or(StatusCode::OK);
+        let headers = response.headers_mut();
+        for (name, value) in &self.headers {
+            if let (Ok(name), Ok(value)) = (HeaderName::try_from(name.as_str()), HeaderValue::from_str(value)) {
+                headers.append(name, value);
+            }
+        }
+        let age = now.duration_since(self.stored_at).unwrap_or_default().as_secs();
+        headers.insert(header::AGE, HeaderValue::from(age));
+        response
+    }
+}
+
+/// Future returned by [`ResponseCacheStore`] operations
+pub type ResponseCacheFuture<'a, T> = Pin<Box<dyn Future<Output = Result<T, String>> + Send + 'a>>;
+
+/// Storage for cached responses
+///
+/// Implementations may drop entries at any time; errors are logged and treated as misses.
+pub trait ResponseCacheStore: Send + Sync {
+    /// Entry stored under `key`, if it has not passed `stale_until`
+    fn get<'a>(&'a self, key: &'a str) -> ResponseCacheFuture<'a, Option<Arc<CachedResponse>>>;
+    /// Store `entry` under `key`, replacing any previous entry
+    fn put<'a>(&'a self, key: &'a str, entry: Arc<CachedResponse>) -> ResponseCacheFuture<'a, ()>;
+    /// Remove every entry tagged with `tag`
+    fn invalidate_tag<'a>(&'a self, tag: &'a str) -> ResponseCacheFuture<'a, ()>;
+}
+
+impl std::fmt::Debug for dyn ResponseCacheStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("ResponseCacheStore")
+    }
+}
+
+struct CachedEntries {
+    entries: LruCache<String, Arc<CachedResponse>>,
+    tagged: HashMap<String, HashSet<String>>,
+}
+
+impl CachedEntries {
+    fn untag(&mut self, key: &str, entry: &CachedResponse) {
+        for tag in &entry.tags {
+            if let Some(keys) = self.tagged.get_mut(tag) {
+                keys.remove(key);
+                if keys.is_empty() {
+                    self.tagged.remove(tag);
+                }
+            }
+        }
+    }
+}
+
+/// Per-process least-recently-used [`ResponseCacheStore`]
+pub struct InMemoryResponseCacheStore {
+    inner: Mutex<CachedEntries>,
+}
+
+impl InMemoryResponseCacheStore {
+    pub fn new() -> Self {
+        Self::with_capacity(NonZeroUsize::new(DEFAULT_CAPACITY).expect("non-zero capacity"))
+    }
+
+    /// Store holding at most `capacity` responses
+    pub fn with_capacity(capacity: NonZeroUsize) -> Self {
+        Self {
+            inner: Mutex::new(CachedEntries {
+                entries: LruCache::new(capacity),
+                tagged: HashMap::new(),
+            }),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, CachedEntries> {
+        self.inner.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Default for InMemoryResponseCacheStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for InMemoryResponseCacheStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InMemoryResponseCacheStore")
+            .field("len", &self.lock().entries.len())
+            .finish()
+    }
+}
+
+impl ResponseCacheStore for InMemoryResponseCacheStore {
+    fn get<'a>(&'a self, key: &'a str) -> ResponseCacheFuture<'a, Option<Arc<CachedResponse>>> {
+        let mut inner = self.lock();
+        let entry = match inner.entries.get(key) {
+            Some(entry) if entry.stale_until > SystemTime::now() => Some(Arc::clone(entry)),
+            Some(_) => {
+                if let Some(expired) = inner.entries.pop(key) {
+                    inner.untag(key, &expired);
+                }
+                None
+            }
+            None => None,
+        };
+        Box::pin(async move { Ok(entry) })
+    }
+
+    fn put<'a>(&'a self, key: &'a str, entry: Arc<CachedResponse>) -> ResponseCacheFuture<'a, ()> {
+        let mut inner = self.lock();
+        for tag in &entry.tags {
+            inner.tagged.entry(tag.clone()).or_default().insert(key.to_string());
+        }
+        if let Some((removed_key, removed)) = inner.entries.push(key.to_string(), entry) {
+            inner.untag(&removed_key, &removed);
+            if removed_key == key
+                && let Some(current) = inner.entries.peek(key).cloned()
+            {
+                for tag in &current.tags {
+                    inner.tagged.entry(tag.clone()).or_default().insert(key.to_string());
+                }
+            }
+        }
+        Box::pin(async { Ok(()) })
+    }
+
+    fn invalidate_tag<'a>(&'a self, tag: &'a str) -> ResponseCacheFuture<'a, ()> {
+        let mut inner = self.lock();
+        for key in inner.tagged.remove(tag).unwrap_or_default() {
+            if let Some(entry) = inner.entries.pop(&key) {
+                inner.untag(&key, &entry);
+            }
+        }
+        Box::pin(async { Ok(()) })
+    }
+}
+
+type InFlight = Arc<Mutex<HashMap<String, watch::Receiver<Option<Arc<CachedResponse>>>>>>;
+
+/// Handle to the server's response cache
+///
+/// Available to handlers as a request extension when any route caches responses.
+#[derive(Clone)]
+pub struct ResponseCache {
+    store: Arc<dyn ResponseCacheStore>,
+    in_flight: InFlight,
+}
+
+impl std::fmt::Debug for ResponseCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ResponseCache").field("store", &self.store).finish()
+    }
+}
+
+impl Default for ResponseCache {
+    fn default() -> Self {
+        Self::new(Arc::new(InMemoryResponseCacheStore::new()))
+    }
+}
+
+impl ResponseCache {
+    /// Cache backed by `store`
+    pub fn new(store: Arc<dyn ResponseCacheStore>) -> Self {
+        Self {
+            store,
+            in_flight: Arc::default(),
+        }
+    }
+
+    pub(crate) fn from_config(config: &crate::ServerConfig) -> Self {
+        config.response_cache_store.clone().map(Self::new).unwrap_or_default()
+    }
+
+    /// Drop every cached response tagged with `tag`
+    pub async fn invalidate_tag(&self, tag: &str) -> Result<(), String> {
+        self.store.invalidate_tag(tag).await
+    }
+
+    async fn invalidate_listed(&self, tags: &HeaderValue) {
+        let Ok(tags) = tags.to_str() else {
+            return;
+        };
+        for tag in split_list(tags) {
+            if let Err(err) = self.invalidate_tag(tag).await {
+                tracing::warn!("Failed to invalidate cache tag '{}': {}", tag, err);
+            }
+        }
+    }
+
+    /// Join the in-flight call for `key`, or become the caller that others wait for
+    fn join(&self, key: &str) -> Flight {
+        let mut in_flight = self.in_flight.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        if let Some(receiver) = in_flight.get(key) {
+            return Flight::Waiting(receiver.clone());
+        }
+        let (sender, receiver) = watch::channel(None);
+        in_flight.insert(key.to_string(), receiver);
+        Flight::Leading(Leader {
+            key: key.to_string(),
+            sender,
+            in_flight: Arc::clone(&self.in_flight),
+        })
+    }
+
+    /// Claim the refresh of `key` unless one is already running
+    fn claim(&self, key: &str) -> Option<Leader> {
+        match self.join(key) {
+            Flight::Leading(leader) => Some(leader),
+            Flight::Waiting(_) => None,
+        }
+    }
+}
+
+enum Flight {
+    Leading(Leader),
+    Waiting(watch::Receiver<Option<Arc<CachedResponse>>>),
+}
+
+/// The one handler call in flight for a key; waiters are released when it is dropped
+struct Leader {
+    key: String,
+    sender: watch::Sender<Option<Arc<CachedResponse>>>,
+    in_flight: InFlight,
+}
+
+impl Leader {
+    fn publish(&self, entry: Arc<CachedResponse>) {
+        let _ = self.sender.send(Some(entry));
+    }
+}
+
+impl Drop for Leader {
+    fn drop(&mut self) {
+        self.in_flight
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .remove(&self.key);
+    }
+}
+
+fn split_list(value: &str) -> impl Iterator<Item = &str> {
+    value.split(',').map(str::trim).filter(|item| !item.is_empty())
+}
+
+/// Expose the cache to handlers and apply invalidations requested by their responses
+pub(crate) async fn cache_invalidation_middleware(
+    State(cache): State<ResponseCache>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    request.extensions_mut().insert(cache.clone());
+    let mut response = next.run(request).await;
+    response.headers_mut().remove(INTERNAL_CACHE_TAGS_HEADER);
+    if let Some(tags) = response.headers_mut().remove(INTERNAL_CACHE_INVALIDATE_HEADER) {
+        cache.invalidate_listed(&tags).await;
+    }
+    response
+}
+
+/// Response cache settings of one route
+pub(crate) struct RouteCache {
+    scope: String,
+    ttl: Duration,
+    stale_while_revalidate: Duration,
+    query_params: Vec<String>,
+    headers: Vec<HeaderName>,
+    tags: Vec<String>,
+    max_entry_bytes: usize,
+    cache: ResponseCache,
+}
+
+impl RouteCache {
+    pub(crate) fn new(
+        method: &crate::Method,
+        path: &str,
+        config: &ResponseCacheConfig,
+        cache: &ResponseCache,
+    ) -> Result<Self, String> {
+        if *method != crate::Method::Get {
+            return Err(format!(
+                "Response caching is only supported on GET routes, not {} {}",
+                method.as_str(),
+                path
+            ));
+        }
+        let headers = config
+            .headers
+            .iter()
+            .map(|name| {
+                name.parse::<HeaderName>()
+                    .map_err(|_| format!("Invalid response cache header name '{}' on GET {}", name, path))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut query_params = config.query_params.clone();
+        query_params.sort();
+        Ok(Self {
+            scope: format!("GET {}", path),
+            ttl: Duration::from_secs(config.ttl_secs),
+            stale_while_revalidate: Duration::from_secs(config.stale_while_revalidate_secs),
+            query_params,
+            headers,
+            tags: config.tags.clone(),
+            max_entry_bytes: config.max_entry_bytes,
+            cache: cache.clone(),
+        })
+    }
+
+    fn key(&self, request: &Request) -> String {
+        let mut key = format!("{}|{}", self.scope, request.uri().path());
+        if !self.query_params.is_empty() {
+            let pairs: Vec<(String, String)> = request
+                .uri()
+                .query()
+                .map(|query| url::form_urlencoded::parse(query.as_bytes()).into_owned().collect())
+                .unwrap_or_default();
+            for name in &self.query_params {
+                key.push_str(&format!("|?{}=", name));
+                let values: Vec<&str> = pairs
+                    .iter()
+                    .filter(|(param, _)| param == name)
+                    .map(|(_, value)| value.as_str())
+                    .collect();
+                key.push_str(&serde_json::to_string(&values).unwrap_or_default());
+            }
+        }
+        for name in &self.headers {
+            let values: Vec<&str> = request
+                .headers()
+                .get_all(name)
+                .iter()
+                .filter_map(|value| value.to_str().ok())
+                .collect();
+            key.push_str(&format!("|{}:", name));
+            key.push_str(&serde_json::to_string(&values).unwrap_or_default());
+        }
+        if let Some(claims) = request.extensions().get::<crate::Claims>() {
+            key.push_str("|sub:");
+            key.push_str(&claims.sub);
+        }
+        if let Some(identity) = request.extensions().get::<crate::ApiKeyIdentity>() {
+            key.push_str("|key:");
+            key.push_str(identity.as_str());
+        }
+        key
+    }
+
+    /// Route tags with `{name}` replaced by path parameters
+    fn tags_for(&self, params: &[(String, String)]) -> Vec<String> {
+        self.tags
+            .iter()
+            .map(|tag| {
+                params.iter().fold(tag.clone(), |tag, (name, value)| {
+                    tag.replace(&format!("{{{}}}", name), value)
+                })
+            })
+            .collect()
+    }
+
+    /// Store `response` if it is cacheable, returning the response to send
+    async fn store(&self, key: &str, tags: Vec<String>, response: Response) -> (Response, Option<Arc<CachedResponse>>) {
+        let (mut parts, body) = response.into_parts();
+        let extra_tags = parts.headers.remove(INTERNAL_CACHE_TAGS_HEADER);
+        if !is_cacheable(parts.status, &parts.headers) {
+            return (Response::from_parts(parts, body), None);
+        }
+        let fits = body
+            .size_hint()
+            .upper()
+            .is_some_and(|upper| upper <= self.max_entry_bytes as u64);
+        if !fits {
+            return (Response::from_parts(parts, body), None);
+        }
+        let body = match axum::body::to_bytes(body, self.max_entry_bytes).await {
+            Ok(body) => body,
+            Err(err) => {
+                tracing::warn!("Failed to buffer response body for caching: {}", err);
+                return (StatusCode::INTERNAL_SERVER_ERROR.into_response(), None);
+            }
+        };
+
+        let mut tags = tags;
+        if let Some(extra) = extra_tags.as_ref().and_then(|value| value.to_str().ok()) {
+            tags.extend(split_list(extra).map(str::to_string));
+        }
+        let now = SystemTime::now();
+        let entry = Arc::new(CachedResponse {
+            status: parts.status.as_u16(),
+            headers: parts
+                .headers
+                .iter()
+                .filter(|(name, _)| **name != header::SET_COOKIE && **name != INTERNAL_CACHE_INVALIDATE_HEADER)
+                .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+                .collect(),
+            body: body.clone(),
+            tags,
+            stored_at: now,
+            fresh_until: now + self.ttl,
+            stale_until: now + self.ttl + self.stale_while_revalidate,
+        });
+        if let Err(err) = self.cache.store.put(key, Arc::clone(&entry)).await {
+            tracing::warn!("Failed to store cached response for {}: {}", self.scope, err);
+        }
+        (Response::from_parts(parts, Body::from(body)), Some(entry))
+    }
+}
+
+/// Only complete, successful, shareable responses are cached
+fn is_cacheable(status: StatusCode, headers: &HeaderMap) -> bool {
+    let forbidden_by_handler = headers
+        .get_all(header::CACHE_CONTROL)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(split_list)
+        .any(|directive| directive.eq_ignore_ascii_case("no-store") || directive.eq_ignore_ascii_case("private"));
+    let streaming = headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("text/event-stream"));
+    status == StatusCode::OK && !headers.contains_key(header::SET_COOKIE) && !forbidden_by_handler && !streaming
+}
+
+/// Serve `GET`/`HEAD` requests from the response cache, calling the handler on misses
+pub(crate) async fn response_cache_middleware(
+    State(route): State<Arc<RouteCache>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if !matches!(*request.method(), Method::GET | Method::HEAD) {
+        return next.run(request).await;
+    }
+    let key = route.key(&request);
+    let (mut parts, body) = request.into_parts();
+    let params: Vec<(String, String)> = RawPathParams::from_request_parts(&mut parts, &())
+        .await
+        .map(|params| {
+            params
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect()
+        })
+        .unwrap_or_default();
+    let request = Request::from_parts(parts, body);
+    let tags = route.tags_for(&params);
+
+    let now = SystemTime::now();
+    match route.cache.store.get(&key).await {
+        Ok(Some(entry)) if entry.fresh_until > now => return entry.to_response(now),
+        Ok(Some(entry)) if entry.stale_until > now => {
+            if let Some(leader) = route.cache.claim(&key) {
+                let route = Arc::clone(&route);
+                tokio::spawn(async move {
+                    let response = next.run(request).await;
+                    if let (_, Some(entry)) = route.store(&key, tags, response).await {
+                        leader.publish(entry);
+                    }
+                });
+            }
+            return entry.to_response(now);
+        }
+        Ok(_) => {}
+        Err(err) => tracing::warn!("Response cache lookup failed for {}: {}", route.scope, err),
+    }
+
+    match route.cache.join(&key) {
+        Flight::Leading(leader) => {
+            let response = next.run(request).await;
+            let (response, entry) = route.store(&key, tags, response).await;
+            if let Some(entry) = entry {
+                leader.publish(entry);
+            }
+            response
+        }
+        Flight::Waiting(mut receiver) => {
+            let shared = receiver
+                .wait_for(Option::is_some)
+                .await
+                .ok()
+                .and_then(|entry| entry.clone());
+            match shared {
+                Some(entry) => entry.to_response(SystemTime::now()),
+                None => next.run(request).await,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(tags: &[&str], ttl: Duration) -> Arc<CachedResponse> {
+        let now = SystemTime::now();
+        Arc::new(CachedResponse {
+            status: 200,
+            headers: Vec::new(),
+            body: Bytes::from_static(b"ok"),
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+            stored_at: now,
+            fresh_until: now + ttl,
+            stale_until: now + ttl,
+        })
+    }
+
+    #[tokio::test]
+    async fn in_memory_store_invalidates_by_tag() {
+        let store = InMemoryResponseCacheStore::new();
+        store
+            .put("a", entry(&["users", "user:1"], Duration::from_secs(60)))
+            .await
+            .unwrap();
+        store
+            .put("b", entry(&["users"], Duration::from_secs(60)))
+            .await
+            .unwrap();
+        store
+            .put("c", entry(&["posts"], Duration::from_secs(60)))
+            .await
+            .unwrap();
+
+        store.invalidate_tag("user:1").await.unwrap();
+        assert!(store.get("a").await.unwrap().is_none());
+        assert!(store.get("b").await.unwrap().is_some());
+
+        store.invalidate_tag("users").await.unwrap();
+        assert!(store.get("b").await.unwrap().is_none());
+        assert!(store.get("c").await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn in_memory_store_evicts_least_recently_used_and_expired() {
+        let store = InMemoryResponseCacheStore::with_capacity(NonZeroUsize::new(2).unwrap());
+        store.put("a", entry(&["t"], Duration::from_secs(60))).await.unwrap();
+        store.put("b", entry(&["t"], Duration::from_secs(60))).await.unwrap();
+        store.get("a").await.unwrap();
+        store.put("c", entry(&["t"], Duration::from_secs(60))).await.unwrap();
+        assert!(store.get("b").await.unwrap().is_none());
+        assert!(store.get("a").await.unwrap().is_some());
+
+        store.put("expired", entry(&[], Duration::ZERO)).await.unwrap();
+        assert!(store.get("expired").await.unwrap().is_none());
+        assert_eq!(store.lock().tagged["t"].len(), 1);
+    }
+
+    #[test]
+    fn cacheability_respects_handler_directives() {
+        let mut headers = HeaderMap::new();
+        assert!(is_cacheable(StatusCode::OK, &headers));
+        assert!(!is_cacheable(StatusCode::CREATED, &headers));
+        headers.insert(header::CACHE_CONTROL, HeaderValue::from_static("max-age=0, no-store"));
+        assert!(!is_cacheable(StatusCode::OK, &headers));
+    }
+}
@@ -0,0 +1,690 @@
+//! Cookie sessions with signed or encrypted cookies and pluggable storage.
+//!
+//! The session cookie is signed (HMAC-SHA256) or, with `encrypt`, encrypted
+//! (AES-256-GCM) with a key derived from `SessionConfig::secret`. Secrets listed
+//! in `previous_secrets` are still accepted and cookies signed with them are
+//! re-issued with the current secret, so secrets can be rotated without logging
+//! users out.
+//!
+//! With [`SessionStorage::Server`] (the default) the cookie only carries a random
+//! session id and the data lives in a [`SessionStore`]; the default
+//! [`InMemorySessionStore`] is per-process, while a shared backend can be plugged
+//! in through `ServerConfig::session_store`. With [`SessionStorage::Cookie`] the
+//! data travels in the cookie itself.
+//!
+//! Sessions expire `ttl_secs` after the last request that used them; with
+//! `rolling` turned off they expire `ttl_secs` after their last change instead.
+//!
+//! Rust handlers read and write the [`Session`] from the request extensions.
+//! Handlers in language bindings receive the session data as JSON in the
+//! [`INTERNAL_SESSION_HEADER`] request header and replace it by setting the same
+//! header on their response (`null` destroys the session), or ask for a new
+//! session id with [`INTERNAL_SESSION_REGENERATE_HEADER`]. Bindings rebuild a
+//! [`Session`] with [`Session::from_data`] and get those response headers from
+//! [`Session::binding_headers`]. The headers are stripped before the response
+//! leaves the server.
+
+use axum::{
+    extract::{Request, State},
+    http::{HeaderMap, HeaderValue, header},
+    middleware::Next,
+    response::Response,
+};
+use cookie::{Cookie, CookieJar, Key, SameSite};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use spikard_core::ExpiringMap;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Internal header carrying session data between the server and binding handlers
+pub const INTERNAL_SESSION_HEADER: &str = "x-spikard-session";
+
+/// Internal response header a binding handler sets to issue a new session id
+pub const INTERNAL_SESSION_REGENERATE_HEADER: &str = "x-spikard-session-regenerate";
+
+/// Minimum secret length accepted for deriving cookie keys
+const MIN_SECRET_LEN: usize = 32;
+
+/// Browsers drop cookies larger than this
+const MAX_COOKIE_SIZE: usize = 4096;
+
+/// Key/value data stored in a session
+pub type SessionData = Map<String, Value>;
+
+/// Where session data is kept
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SessionStorage {
+    /// The cookie holds a session id; data lives in the session store
+    #[default]
+    Server,
+    /// The cookie holds the data itself (limited to about 4 KB)
+    Cookie,
+}
+
+/// Session middleware configuration
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SessionConfig {
+    /// Secret the cookie keys are derived from (at least 32 bytes)
+    pub secret: String,
+    /// Older secrets still accepted while rotating
+    pub previous_secrets: Vec<String>,
+    /// Name of the session cookie
+    pub cookie_name: String,
+    /// Where session data is kept
+    pub storage: SessionStorage,
+    /// Encrypt the cookie instead of only signing it
+    pub encrypt: bool,
+    /// Lifetime of a session, in seconds
+    pub ttl_secs: u64,
+    /// Restart the lifetime on every request instead of only when the session changes
+    ///
+    /// Each request with a session then rewrites it in the store and re-sends the cookie.
+    pub rolling: bool,
+    /// Only send the cookie over HTTPS
+    pub secure: bool,
+    /// Hide the cookie from JavaScript
+    pub http_only: bool,
+    /// `SameSite` attribute (`"Strict"`, `"Lax"` or `"None"`)
+    pub same_site: String,
+    /// `Path` attribute
+    pub path: String,
+    /// `Domain` attribute
+    pub domain: Option<String>,
+}
+
+impl Default for SessionConfig {
+    fn default() -> Self {
+        Self {
+            secret: String::new(),
+            previous_secrets: Vec::new(),
+            cookie_name: "spikard_session".to_string(),
+            storage: SessionStorage::default(),
+            encrypt: false,
+            ttl_secs: 24 * 60 * 60,
+            rolling: true,
+            secure: true,
+            http_only: true,
+            same_site: "Lax".to_string(),
+            path: "/".to_string(),
+            domain: None,
+        }
+    }
+}
+
+impl std::fmt::Debug for SessionConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SessionConfig")
+            .field("secret", &"<redacted>")
+            .field("previous_secrets", &self.previous_secrets.len())
+            .field("cookie_name", &self.cookie_name)
+            .field("storage", &self.storage)
+            .field("encrypt", &self.encrypt)
+            .field("ttl_secs", &self.ttl_secs)
+            .field("rolling", &self.rolling)
+            .field("secure", &self.secure)
+            .field("http_only", &self.http_only)
+            .field("same_site", &self.same_site)
+            .field("path", &self.path)
+            .field("domain", &self.domain)
+            .finish()
+    }
+}
+
+/// Future returned by [`SessionStore`] operations
+pub type SessionFuture<'a, T> = Pin<Box<dyn Future<Output = Result<T, String>> + Send + 'a>>;
+
+/// Server-side storage for session data
+///
+/// Errors are logged; a failed load starts a fresh session and a failed save
+/// leaves the client's cookie untouched.
+pub trait SessionStore: Send + Sync {
+    /// Data of a live session, or `None` if it is unknown or expired
+    fn load<'a>(&'a self, id: &'a str) -> SessionFuture<'a, Option<SessionData>>;
+    /// Store `data` under `id`, expiring after `ttl`
+    fn save<'a>(&'a self, id: &'a str, data: &'a SessionData, ttl: Duration) -> SessionFuture<'a, ()>;
+    /// Forget the session `id`
+    fn delete<'a>(&'a self, id: &'a str) -> SessionFuture<'a, ()>;
+}
+
+impl std::fmt::Debug for dyn SessionStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SessionStore")
+    }
+}
+
+/// Per-process [`SessionStore`], used when no shared store is configured
+#[derive(Debug)]
+pub struct InMemorySessionStore {
+    sessions: Mutex<ExpiringMap<(SessionData, Instant)>>,
+}
+
+impl InMemorySessionStore {
+    pub fn new() -> Self {
+        Self {
+            sessions: Mutex::new(ExpiringMap::new()),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, ExpiringMap<(SessionData, Instant)>> {
+        self.sessions.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Default for InMemorySessionStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SessionStore for InMemorySessionStore {
+    fn load<'a>(&'a self, id: &'a str) -> SessionFuture<'a, Option<SessionData>> {
+        let now = Instant::now();
+        let mut sessions = self.lock();
+        let data = match sessions.get(id) {
+            Some((data, expires)) if *expires > now => Some(data.clone()),
+            Some(_) => {
+                sessions.remove(id);
+                None
+            }
+            None => None,
+        };
+        Box::pin(async move { Ok(data) })
+    }
+
+    fn save<'a>(&'a self, id: &'a str, data: &'a SessionData, ttl: Duration) -> SessionFuture<'a, ()> {
+        let now = Instant::now();
+        let mut sessions = self.lock();
+        sessions.insert(id.to_string(), (data.clone(), now + ttl));
+        sessions.sweep(|(_, expires)| *expires > now);
+        Box::pin(async { Ok(()) })
+    }
+
+    fn delete<'a>(&'a self, id: &'a str) -> SessionFuture<'a, ()> {
+        self.lock().remove(id);
+        Box::pin(async { Ok(()) })
+    }
+}
+
+#[derive(Debug, Default)]
+struct SessionState {
+    data: SessionData,
+    changed: bool,
+    regenerate: bool,
+    destroyed: bool,
+    /// Values stored only if the request writes to the session anyway
+    pending: Vec<(String, Value)>,
+}
+
+/// Session of the current request
+///
+/// Cloning is cheap; all clones share the same data. Changes are persisted when
+/// the response is sent.
+#[derive(Debug, Clone, Default)]
+pub struct Session {
+    state: Arc<Mutex<SessionState>>,
+    is_new: bool,
+}
+
+impl Session {
+    fn new(data: SessionData, is_new: bool) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(SessionState {
+                data,
+                ..Default::default()
+            })),
+            is_new,
+        }
+    }
+
+    /// Session holding `data`, detached from any request
+    ///
+    /// Bindings use this to rebuild the request's session from the data handed
+    /// over in [`INTERNAL_SESSION_HEADER`].
+    pub fn from_data(data: SessionData) -> Self {
+        Self::new(data, false)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, SessionState> {
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Whether the request arrived without a valid session
+    pub fn is_new(&self) -> bool {
+        self.is_new
+    }
+
+    /// Deserialize the value stored under `key`
+    pub fn get<T: DeserializeOwned>(&self, key: &str) -> Option<T> {
+        self.get_value(key).and_then(|value| serde_json::from_value(value).ok())
+    }
+
+    /// Raw JSON value stored under `key`
+    pub fn get_value(&self, key: &str) -> Option<Value> {
+        self.lock().data.get(key).cloned()
+    }
+
+    /// Store `value` under `key`
+    pub fn insert(&self, key: impl Into<String>, value: impl Serialize) -> Result<(), serde_json::Error> {
+        let value = serde_json::to_value(value)?;
+        let mut state = self.lock();
+        state.data.insert(key.into(), value);
+        state.changed = true;
+        Ok(())
+    }
+
+    /// Remove and return the value stored under `key`
+    pub fn remove(&self, key: &str) -> Option<Value> {
+        let mut state = self.lock();
+        let removed = state.data.remove(key);
+        state.changed |= removed.is_some();
+        removed
+    }
+
+    /// Remove every value, keeping the session itself
+    pub fn clear(&self) {
+        let mut state = self.lock();
+        state.changed |= !state.data.is_empty();
+        state.data.clear();
+    }
+
+    /// Replace all session data
+    pub fn replace(&self, data: SessionData) {
+        let mut state = self.lock();
+        state.data = data;
+        state.changed = true;
+    }
+
+    /// Snapshot of the session data
+    pub fn data(&self) -> SessionData {
+        self.lock().data.clone()
+    }
+
+    /// Issue a new session id, keeping the data
+    ///
+    /// Call this when the privilege level changes (for example after login) to
+    /// prevent session fixation.
+    pub fn regenerate(&self) {
+        let mut state = self.lock();
+        state.regenerate = true;
+        state.changed = true;
+    }
+
+    /// Store `value` under `key` once the request writes to the session anyway
+    ///
+    /// Lets middleware attach data to sessions that handlers start without
+    /// creating a session for every visitor.
+    pub(crate) fn insert_if_started(&self, key: impl Into<String>, value: Value) {
+        self.lock().pending.push((key.into(), value));
+    }
+
+    /// Move pending values into the session if it is being written
+    fn settle_pending(&self) {
+        let mut state = self.lock();
+        let pending = std::mem::take(&mut state.pending);
+        if state.changed && !state.destroyed {
+            for (key, value) in pending {
+                state.data.entry(key).or_insert(value);
+            }
+        }
+    }
+
+    /// Delete the session and expire its cookie
+    pub fn destroy(&self) {
+        let mut state = self.lock();
+        state.data.clear();
+        state.destroyed = true;
+    }
+
+    /// Internal response headers that hand this session's changes back to the server
+    ///
+    /// Empty when the session is unchanged.
+    pub fn binding_headers(&self) -> HashMap<String, String> {
+        let state = self.lock();
+        let mut headers = HashMap::new();
+        if state.destroyed {
+            headers.insert(INTERNAL_SESSION_HEADER.to_string(), "null".to_string());
+            return headers;
+        }
+        if state.changed
+            && let Ok(serialized) = serde_json::to_string(&state.data)
+        {
+            headers.insert(INTERNAL_SESSION_HEADER.to_string(), serialized);
+        }
+        if state.regenerate {
+            headers.insert(INTERNAL_SESSION_REGENERATE_HEADER.to_string(), "1".to_string());
+        }
+        headers
+    }
+}
+
+/// Random token suitable for session ids and CSRF tokens
+pub(crate) fn random_token() -> String {
+    format!("{}{}", uuid::Uuid::new_v4().simple(), uuid::Uuid::new_v4().simple())
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+/// Payload of a [`SessionStorage::Cookie`] session cookie
+#[derive(Serialize, Deserialize)]
+struct CookiePayload {
+    #[serde(rename = "d")]
+    data: SessionData,
+    #[serde(rename = "e")]
+    expires: u64,
+}
+
+/// Session cookie found on a request
+struct Incoming {
+    value: String,
+    signed_with_current_key: bool,
+}
+
+/// Shared state of the session middleware
+pub(crate) struct SessionManager {
+    config: SessionConfig,
+    keys: Vec<Key>,
+    same_site: SameSite,
+    store: Arc<dyn SessionStore>,
+}
+
+impl SessionManager {
+    pub(crate) fn new(config: &SessionConfig, store: Option<Arc<dyn SessionStore>>) -> Result<Self, String> {
+        let keys = std::iter::once(&config.secret)
+            .chain(&config.previous_secrets)
+            .map(|secret| {
+                if secret.len() < MIN_SECRET_LEN {
+                    return Err(format!(
+                        "Session secrets must be at least {} bytes long",
+                        MIN_SECRET_LEN
+                    ));
+                }
+                Ok(Key::derive_from(secret.as_bytes()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self {
+            same_site: parse_same_site(&config.same_site)?,
+            keys,
+            store: store.unwrap_or_else(|| Arc::new(InMemorySessionStore::new())),
+            config: config.clone(),
+        })
+    }
+
+    fn ttl(&self) -> Duration {
+        Duration::from_secs(self.config.ttl_secs)
+    }
+
+    fn incoming(&self, headers: &HeaderMap) -> Option<Incoming> {
+        let cookie = headers
+            .get_all(header::COOKIE)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(Cookie::split_parse)
+            .filter_map(Result::ok)
+            .find(|cookie| cookie.name() == self.config.cookie_name)?
+            .into_owned();
+        let jar = CookieJar::new();
+        self.keys.iter().enumerate().find_map(|(index, key)| {
+            let verified = if self.config.encrypt {
+                jar.private(key).decrypt(cookie.clone())
+            } else {
+                jar.signed(key).verify(cookie.clone())
+            };
+            verified.map(|cookie| Incoming {
+                value: cookie.value().to_string(),
+                signed_with_current_key: index == 0,
+            })
+        })
+    }
+
+    fn outgoing(&self, value: String) -> HeaderValue {
+        let mut cookie = Cookie::build((self.config.cookie_name.clone(), value))
+            .path(self.config.path.clone())
+            .secure(self.config.secure)
+            .http_only(self.config.http_only)
+            .same_site(self.same_site)
+            .max_age(cookie::time::Duration::seconds(
+                i64::try_from(self.config.ttl_secs).unwrap_or(i64::MAX),
+            ))
+            .build();
+        if let Some(ref domain) = self.config.domain {
+            cookie.set_domain(domain.clone());
+        }
+        let mut jar = CookieJar::new();
+        if self.config.encrypt {
+            jar.private_mut(&self.keys[0]).add(cookie);
+        } else {
+            jar.signed_mut(&self.keys[0]).add(cookie);
+        }
+        let sealed = jar.delta().next().map(ToString::to_string).unwrap_or_default();
+        HeaderValue::from_str(&sealed).unwrap_or_else(|_| HeaderValue::from_static(""))
+    }
+
+    fn removal(&self) -> HeaderValue {
+        let mut cookie = Cookie::build((self.config.cookie_name.clone(), ""))
+            .path(self.config.path.clone())
+            .secure(self.config.secure)
+            .http_only(self.config.http_only)
+            .same_site(self.same_site)
+            .build();
+        if let Some(ref domain) = self.config.domain {
+            cookie.set_domain(domain.clone());
+        }
+        cookie.make_removal();
+        HeaderValue::from_str(&cookie.to_string()).unwrap_or_else(|_| HeaderValue::from_static(""))
+    }
+
+    /// Session for a request, plus the id it was stored under
+    async fn load(&self, headers: &HeaderMap) -> (Session, Option<String>, bool) {
+        let Some(incoming) = self.incoming(headers) else {
+            return (Session::new(SessionData::new(), true), None, false);
+        };
+        let reissue = !incoming.signed_with_current_key;
+        match self.config.storage {
+            SessionStorage::Server => match self.store.load(&incoming.value).await {
+                Ok(Some(data)) => (Session::new(data, false), Some(incoming.value), reissue),
+                Ok(None) => (Session::new(SessionData::new(), true), None, false),
+                Err(err) => {
+                    tracing::warn!("Session store failed to load a session: {}", err);
+                    (Session::new(SessionData::new(), true), None, false)
+                }
+            },
+            SessionStorage::Cookie => match serde_json::from_str::<CookiePayload>(&incoming.value) {
+                Ok(payload) if payload.expires > unix_now() => (Session::new(payload.data, false), None, reissue),
+                _ => (Session::new(SessionData::new(), true), None, false),
+            },
+        }
+    }
+
+    /// Persist the session and set or expire its cookie
+    async fn commit(&self, session: &Session, id: Option<String>, reissue: bool, response: &mut Response) {
+        let (data, changed, regenerate, destroyed) = {
+            let state = session.lock();
+            (state.data.clone(), state.changed, state.regenerate, state.destroyed)
+        };
+        let had_cookie = !session.is_new();
+        let refresh = self.config.rolling && had_cookie;
+
+        if destroyed {
+            if let Some(ref id) = id
+                && let Err(err) = self.store.delete(id).await
+            {
+                tracing::warn!("Session store failed to delete a session: {}", err);
+            }
+            if had_cookie {
+                response.headers_mut().append(header::SET_COOKIE, self.removal());
+            }
+            return;
+        }
+        if !changed && !reissue && !refresh {
+            return;
+        }
+
+        let value = match self.config.storage {
+            SessionStorage::Server => {
+                if !changed {
+                    if refresh
+                        && let Some(ref id) = id
+                        && let Err(err) = self.store.save(id, &data, self.ttl()).await
+                    {
+                        tracing::error!("Session store failed to refresh a session: {}", err);
+                        return;
+                    }
+                    id
+                } else if data.is_empty() && session.is_new() {
+                    return;
+                } else {
+                    let new_id = match id {
+                        Some(id) if !regenerate => id,
+                        previous => {
+                            if let Some(ref previous) = previous
+                                && let Err(err) = self.store.delete(previous).await
+                            {
+                                tracing::warn!("Session store failed to delete a session: {}", err);
+                            }
+                            random_token()
+                        }
+                    };
+                    if let Err(err) = self.store.save(&new_id, &data, self.ttl()).await {
+                        tracing::error!("Session store failed to save a session: {}", err);
+                        return;
+                    }
+                    Some(new_id)
+                }
+            }
+            SessionStorage::Cookie => {
+                if data.is_empty() {
+                    if had_cookie {
+                        response.headers_mut().append(header::SET_COOKIE, self.removal());
+                    }
+                    return;
+                }
+                let payload = CookiePayload {
+                    data,
+                    expires: unix_now().saturating_add(self.config.ttl_secs),
+                };
+                serde_json::to_string(&payload).ok()
+            }
+        };
+
+        if let Some(value) = value {
+            let cookie = self.outgoing(value);
+            if cookie.len() > MAX_COOKIE_SIZE {
+                tracing::warn!(
+                    "Session cookie is {} bytes; browsers may drop cookies over {} bytes",
+                    cookie.len(),
+                    MAX_COOKIE_SIZE
+                );
+            }
+            response.headers_mut().append(header::SET_COOKIE, cookie);
+        }
+    }
+}
+
+pub(crate) fn parse_same_site(value: &str) -> Result<SameSite, String> {
+    match value.to_ascii_lowercase().as_str() {
+        "strict" => Ok(SameSite::Strict),
+        "lax" => Ok(SameSite::Lax),
+        "none" => Ok(SameSite::None),
+        _ => Err(format!(
+            "Invalid SameSite value '{}': expected Strict, Lax or None",
+            value
+        )),
+    }
+}
+
+/// Apply session changes written by a binding handler through the internal headers
+fn apply_binding_update(session: &Session, response: &mut Response) {
+    if response
+        .headers_mut()
+        .remove(INTERNAL_SESSION_REGENERATE_HEADER)
+        .is_some()
+    {
+        session.regenerate();
+    }
+    let Some(raw) = response.headers_mut().remove(INTERNAL_SESSION_HEADER) else {
+        return;
+    };
+    match serde_json::from_slice::<Option<SessionData>>(raw.as_bytes()) {
+        Ok(Some(data)) => session.replace(data),
+        Ok(None) => session.destroy(),
+        Err(err) => tracing::warn!(
+            "Ignoring malformed {} response header: {}",
+            INTERNAL_SESSION_HEADER,
+            err
+        ),
+    }
+}
+
+/// Load the session before the handler runs and persist it afterwards
+pub(crate) async fn session_middleware(
+    State(manager): State<Arc<SessionManager>>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let (session, id, reissue) = manager.load(request.headers()).await;
+    request.extensions_mut().insert(session.clone());
+
+    let mut response = next.run(request).await;
+    apply_binding_update(&session, &mut response);
+    session.settle_pending();
+    manager.commit(&session, id, reissue, &mut response).await;
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &str = "0123456789abcdef0123456789abcdef";
+
+    #[test]
+    fn short_secrets_and_bad_same_site_are_rejected() {
+        let short = SessionConfig {
+            secret: "too short".to_string(),
+            ..Default::default()
+        };
+        assert!(SessionManager::new(&short, None).is_err());
+
+        let bad_same_site = SessionConfig {
+            secret: SECRET.to_string(),
+            same_site: "sometimes".to_string(),
+            ..Default::default()
+        };
+        assert!(SessionManager::new(&bad_same_site, None).is_err());
+    }
+
+    #[test]
+    fn debug_output_redacts_secrets() {
+        let config = SessionConfig {
+            secret: SECRET.to_string(),
+            ..Default::default()
+        };
+        assert!(!format!("{:?}", config).contains(SECRET));
+    }
+
+    #[tokio::test]
+    async fn in_memory_store_expires_sessions() {
+        let store = InMemorySessionStore::new();
+        let mut data = SessionData::new();
+        data.insert("user".to_string(), Value::from(7));
+
+        store.save("live", &data, Duration::from_secs(60)).await.expect("save");
+        store.save("stale", &data, Duration::ZERO).await.expect("save");
+
+        assert_eq!(store.load("live").await.expect("load"), Some(data));
+        assert_eq!(store.load("stale").await.expect("load"), None);
+        store.delete("live").await.expect("delete");
+        assert_eq!(store.load("live").await.expect("load"), None);
+    }
+}
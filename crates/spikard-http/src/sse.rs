@@ -2,17 +2,25 @@
 //!
 //! Provides SSE streaming with event generation and lifecycle management.
 
+use crate::metrics::{ConnectionKind, ConnectionMetrics};
 use axum::{
+    Extension,
     extract::State,
     response::{
         IntoResponse,
         sse::{Event, KeepAlive, Sse},
     },
 };
-use futures_util::stream;
+use futures_util::{StreamExt, stream};
 use std::{convert::Infallible, sync::Arc, time::Duration};
 use tracing::{debug, error, info};
 
+/// Dependencies resolved when a stream opens, cleaned up when it is dropped
+#[cfg(feature = "di")]
+type StreamDependencies = crate::di_handler::DependencyScope;
+#[cfg(not(feature = "di"))]
+type StreamDependencies = ();
+
 /// SSE event producer trait
 ///
 /// Implement this trait to create custom Server-Sent Event (SSE) producers for your application.
@@ -74,6 +82,21 @@ pub trait SseEventProducer: Send + Sync {
     /// * `None` - Stream complete, connection will close
     fn next_event(&self) -> impl std::future::Future<Output = Option<SseEvent>> + Send;
 
+    /// Generate the next event with the connection's dependencies
+    ///
+    /// Called instead of `next_event` when the endpoint declares dependencies (see
+    /// [`SseState::with_dependencies`]). The dependencies are resolved once when the
+    /// client connects and cleaned up when the stream is dropped. The default
+    /// implementation ignores them.
+    #[cfg(feature = "di")]
+    fn next_event_with_dependencies(
+        &self,
+        dependencies: &spikard_core::di::ResolvedDependencies,
+    ) -> impl std::future::Future<Output = Option<SseEvent>> + Send {
+        let _ = dependencies;
+        self.next_event()
+    }
+
     /// Called when a client connects to the SSE endpoint
     ///
     /// Optional lifecycle hook invoked when a new SSE connection is established.
@@ -258,6 +281,9 @@ pub struct SseState<P: SseEventProducer> {
     producer: Arc<P>,
     /// Optional JSON Schema for validating outgoing events
     event_schema: Option<Arc<jsonschema::Validator>>,
+    /// Dependencies resolved for each connection
+    #[cfg(feature = "di")]
+    dependencies: Option<crate::di_handler::DeclaredDependencies>,
 }
 
 impl<P: SseEventProducer> Clone for SseState<P> {
@@ -265,6 +291,8 @@ impl<P: SseEventProducer> Clone for SseState<P> {
         Self {
             producer: Arc::clone(&self.producer),
             event_schema: self.event_schema.clone(),
+            #[cfg(feature = "di")]
+            dependencies: self.dependencies.clone(),
         }
     }
 }
@@ -287,6 +315,8 @@ impl<P: SseEventProducer + 'static> SseState<P> {
         Self {
             producer: Arc::new(producer),
             event_schema: None,
+            #[cfg(feature = "di")]
+            dependencies: None,
         }
     }
 
@@ -329,8 +359,22 @@ impl<P: SseEventProducer + 'static> SseState<P> {
         Ok(Self {
             producer: Arc::new(producer),
             event_schema: event_validator,
+            #[cfg(feature = "di")]
+            dependencies: None,
         })
     }
+
+    /// Resolve `dependencies` when each client connects
+    ///
+    /// Events are then produced by [`SseEventProducer::next_event_with_dependencies`].
+    /// If resolution fails the connection is refused with the same error response
+    /// HTTP handlers produce.
+    #[cfg(feature = "di")]
+    #[must_use]
+    pub fn with_dependencies(mut self, dependencies: crate::di_handler::DeclaredDependencies) -> Self {
+        self.dependencies = Some(dependencies);
+        self
+    }
 }
 
 /// SSE endpoint handler
@@ -361,15 +405,35 @@ impl<P: SseEventProducer + 'static> SseState<P> {
 /// // const eventSource = new EventSource('/events');
 /// // eventSource.onmessage = (e) => console.log(e.data);
 /// ```
-pub async fn sse_handler<P: SseEventProducer + 'static>(State(state): State<SseState<P>>) -> impl IntoResponse {
+pub async fn sse_handler<P: SseEventProducer + 'static>(
+    #[cfg(feature = "di")] parts: axum::http::request::Parts,
+    State(state): State<SseState<P>>,
+    connection_metrics: Option<Extension<ConnectionMetrics>>,
+) -> axum::response::Response {
+    #[cfg(feature = "di")]
+    let dependencies = match &state.dependencies {
+        Some(declared) => match declared.resolve(&parts).await {
+            Ok(resolved) => crate::di_handler::DependencyScope::new(Some(resolved)),
+            Err(e) => {
+                error!("Failed to resolve SSE dependencies: {}", e);
+                return crate::di_handler::dependency_error_response(&e);
+            }
+        },
+        None => crate::di_handler::DependencyScope::new(None),
+    };
+    #[cfg(not(feature = "di"))]
+    let dependencies = ();
+
     info!("SSE client connected");
+    let active = connection_metrics.map(|Extension(metrics)| metrics.track(ConnectionKind::Sse));
 
     state.producer.on_connect().await;
 
     let producer = Arc::clone(&state.producer);
     let event_schema = state.event_schema.clone();
-    let stream = stream::unfold((producer, event_schema), |(producer, event_schema)| async move {
-        match producer.next_event().await {
+    let initial = (producer, event_schema, dependencies);
+    let stream = stream::unfold(initial, |(producer, event_schema, dependencies)| async move {
+        match produce_event(producer.as_ref(), &dependencies).await {
             Some(sse_event) => {
                 debug!("Sending SSE event: {:?}", sse_event.event_type);
 
@@ -379,12 +443,12 @@ pub async fn sse_handler<P: SseEventProducer + 'static>(State(state): State<SseS
                     error!("SSE event validation failed");
                     return Some((
                         Ok::<_, Infallible>(Event::default().data("validation_error")),
-                        (producer, event_schema),
+                        (producer, event_schema, dependencies),
                     ));
                 }
 
                 let event = sse_event.into_axum_event();
-                Some((Ok::<_, Infallible>(event), (producer, event_schema)))
+                Some((Ok::<_, Infallible>(event), (producer, event_schema, dependencies)))
             }
             None => {
                 info!("SSE stream ended");
@@ -393,6 +457,11 @@ pub async fn sse_handler<P: SseEventProducer + 'static>(State(state): State<SseS
             }
         }
     });
+    // The guard lives as long as the stream, so the gauge drops when the client disconnects.
+    let stream = stream.map(move |event| {
+        let _ = &active;
+        event
+    });
 
     let sse_response =
         Sse::new(stream).keep_alive(KeepAlive::new().interval(Duration::from_secs(15)).text("keep-alive"));
@@ -400,6 +469,17 @@ pub async fn sse_handler<P: SseEventProducer + 'static>(State(state): State<SseS
     sse_response.into_response()
 }
 
+/// Produce the next event, with the connection's dependencies if it has any
+async fn produce_event<P: SseEventProducer>(producer: &P, dependencies: &StreamDependencies) -> Option<SseEvent> {
+    #[cfg(feature = "di")]
+    if let Some(dependencies) = dependencies.dependencies() {
+        return producer.next_event_with_dependencies(dependencies).await;
+    }
+    #[cfg(not(feature = "di"))]
+    let () = dependencies;
+    producer.next_event().await
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
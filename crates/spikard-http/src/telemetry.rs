@@ -0,0 +1,320 @@
+//! Request tracing with W3C trace context propagation
+//!
+//! Every request runs inside a `request` span named after its route template
+//! (`GET /users/{id}`) or gRPC method (`package.Service/Method`). Validation,
+//! dependency resolution, lifecycle hooks and handler execution open child spans
+//! beneath it, and background tasks spawned while it is active inherit it.
+//!
+//! With the `otel` feature the spans are bridged to OpenTelemetry: incoming
+//! `traceparent`/`tracestate` headers become the span's remote parent, the server
+//! span's context is written back to responses and into gRPC request metadata,
+//! and [`Server::init_telemetry`](crate::Server::init_telemetry) exports spans over OTLP.
+
+use axum::extract::{Request, State};
+use axum::http::HeaderMap;
+use axum::middleware::Next;
+use axum::response::Response;
+use std::sync::Arc;
+use tracing::field::Empty;
+use tracing::{Instrument, Span};
+
+fn default_service_name() -> String {
+    "spikard".to_string()
+}
+
+/// Request tracing configuration
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct TelemetryConfig {
+    /// `service.name` resource attribute reported with exported spans
+    pub service_name: String,
+    /// OTLP gRPC collector endpoint (None = `OTEL_EXPORTER_OTLP_ENDPOINT` or `http://localhost:4317`)
+    pub otlp_endpoint: Option<String>,
+    /// Fraction of new traces that are sampled; requests with a sampled parent are always kept
+    pub sample_ratio: f64,
+    /// Write the server span's `traceparent`/`tracestate` to responses
+    pub propagate_response_headers: bool,
+}
+
+impl Default for TelemetryConfig {
+    fn default() -> Self {
+        Self {
+            service_name: default_service_name(),
+            otlp_endpoint: None,
+            sample_ratio: 1.0,
+            propagate_response_headers: true,
+        }
+    }
+}
+
+/// Error raised while installing the OTLP exporter
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TelemetryError {
+    /// The OTLP exporter could not be built
+    Exporter(String),
+    /// A global `tracing` subscriber was installed before telemetry was initialized
+    SubscriberInstalled,
+}
+
+impl std::fmt::Display for TelemetryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Exporter(message) => write!(f, "failed to build OTLP exporter: {message}"),
+            Self::SubscriberInstalled => write!(f, "a global tracing subscriber is already installed"),
+        }
+    }
+}
+
+impl std::error::Error for TelemetryError {}
+
+/// Flushes and shuts down the tracer provider when dropped
+#[cfg(feature = "otel")]
+#[derive(Debug)]
+pub struct TelemetryGuard {
+    provider: opentelemetry_sdk::trace::SdkTracerProvider,
+}
+
+#[cfg(feature = "otel")]
+impl TelemetryGuard {
+    /// The provider spans are exported through
+    pub fn provider(&self) -> &opentelemetry_sdk::trace::SdkTracerProvider {
+        &self.provider
+    }
+}
+
+#[cfg(feature = "otel")]
+impl Drop for TelemetryGuard {
+    fn drop(&mut self) {
+        if let Err(err) = self.provider.shutdown() {
+            tracing::warn!("Failed to flush spans on shutdown: {}", err);
+        }
+    }
+}
+
+/// Build a tracer provider exporting to the configured OTLP collector
+///
+/// Must be called from within a Tokio runtime; the gRPC channel is driven by it.
+#[cfg(feature = "otel")]
+pub fn tracer_provider(
+    config: &TelemetryConfig,
+) -> Result<opentelemetry_sdk::trace::SdkTracerProvider, TelemetryError> {
+    use opentelemetry_otlp::WithExportConfig;
+    use opentelemetry_sdk::trace::{Sampler, SdkTracerProvider};
+
+    let mut builder = opentelemetry_otlp::SpanExporter::builder().with_tonic();
+    if let Some(ref endpoint) = config.otlp_endpoint {
+        builder = builder.with_endpoint(endpoint.clone());
+    }
+    let exporter = builder
+        .build()
+        .map_err(|err| TelemetryError::Exporter(err.to_string()))?;
+
+    Ok(SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        .with_sampler(Sampler::ParentBased(Box::new(Sampler::TraceIdRatioBased(
+            config.sample_ratio,
+        ))))
+        .with_resource(
+            opentelemetry_sdk::Resource::builder()
+                .with_service_name(config.service_name.clone())
+                .build(),
+        )
+        .build())
+}
+
+/// Wrap `provider` in a guard that shuts it down on drop
+#[cfg(feature = "otel")]
+pub(crate) fn guard(provider: opentelemetry_sdk::trace::SdkTracerProvider) -> TelemetryGuard {
+    TelemetryGuard { provider }
+}
+
+#[cfg(feature = "otel")]
+mod propagation {
+    use axum::http::{HeaderMap, HeaderName, HeaderValue};
+    use opentelemetry::Context;
+    use opentelemetry::propagation::{Extractor, Injector, TextMapPropagator};
+    use opentelemetry::trace::TraceContextExt;
+    use opentelemetry_sdk::propagation::TraceContextPropagator;
+    use tracing::Span;
+    use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+    struct HeaderExtractor<'a>(&'a HeaderMap);
+
+    impl Extractor for HeaderExtractor<'_> {
+        fn get(&self, key: &str) -> Option<&str> {
+            self.0.get(key).and_then(|value| value.to_str().ok())
+        }
+
+        fn keys(&self) -> Vec<&str> {
+            self.0.keys().map(HeaderName::as_str).collect()
+        }
+    }
+
+    struct HeaderInjector<'a>(&'a mut HeaderMap);
+
+    impl Injector for HeaderInjector<'_> {
+        fn set(&mut self, key: &str, value: String) {
+            if let (Ok(name), Ok(value)) = (HeaderName::from_bytes(key.as_bytes()), HeaderValue::from_str(&value)) {
+                self.0.insert(name, value);
+            }
+        }
+    }
+
+    struct MetadataInjector<'a>(&'a mut tonic::metadata::MetadataMap);
+
+    impl Injector for MetadataInjector<'_> {
+        fn set(&mut self, key: &str, value: String) {
+            if let (Ok(key), Ok(value)) = (
+                tonic::metadata::MetadataKey::from_bytes(key.as_bytes()),
+                value.parse::<tonic::metadata::MetadataValue<tonic::metadata::Ascii>>(),
+            ) {
+                self.0.insert(key, value);
+            }
+        }
+    }
+
+    fn span_context(span: &Span) -> Option<Context> {
+        let cx = span.context();
+        cx.span().span_context().is_valid().then_some(cx)
+    }
+
+    pub(super) fn extract_parent(span: &Span, headers: &HeaderMap) {
+        let cx = TraceContextPropagator::new().extract(&HeaderExtractor(headers));
+        if cx.span().span_context().is_valid() {
+            let _ = span.set_parent(cx);
+        }
+    }
+
+    pub(super) fn inject_headers(span: &Span, headers: &mut HeaderMap) {
+        if let Some(cx) = span_context(span) {
+            TraceContextPropagator::new().inject_context(&cx, &mut HeaderInjector(headers));
+        }
+    }
+
+    pub(super) fn inject_metadata(span: &Span, metadata: &mut tonic::metadata::MetadataMap) {
+        if let Some(cx) = span_context(span) {
+            TraceContextPropagator::new().inject_context(&cx, &mut MetadataInjector(metadata));
+        }
+    }
+}
+
+#[cfg(feature = "otel")]
+fn extract_parent(span: &Span, headers: &HeaderMap) {
+    propagation::extract_parent(span, headers);
+}
+
+#[cfg(not(feature = "otel"))]
+fn extract_parent(_span: &Span, _headers: &HeaderMap) {}
+
+#[cfg(feature = "otel")]
+fn inject_headers(span: &Span, headers: &mut HeaderMap) {
+    propagation::inject_headers(span, headers);
+}
+
+#[cfg(not(feature = "otel"))]
+fn inject_headers(_span: &Span, _headers: &mut HeaderMap) {}
+
+/// Write the current span's trace context into gRPC request metadata
+///
+/// Replaces any incoming `traceparent` so handlers calling other services continue
+/// the trace from the server span rather than from the remote caller.
+#[cfg(feature = "otel")]
+pub(crate) fn inject_grpc_metadata(metadata: &mut tonic::metadata::MetadataMap) {
+    propagation::inject_metadata(&Span::current(), metadata);
+}
+
+#[cfg(not(feature = "otel"))]
+pub(crate) fn inject_grpc_metadata(_metadata: &mut tonic::metadata::MetadataMap) {}
+
+fn request_span(request: &Request) -> Span {
+    let method = request.method().as_str();
+    let path = request.uri().path();
+    let span = tracing::info_span!(
+        "request",
+        otel.name = Empty,
+        otel.kind = "server",
+        otel.status_code = Empty,
+        http.request.method = %method,
+        http.route = Empty,
+        url.path = %path,
+        client.address = Empty,
+        http.response.status_code = Empty,
+        rpc.system = Empty,
+    );
+
+    if let Some(ip) = request
+        .extensions()
+        .get::<crate::proxy::ClientInfo>()
+        .and_then(|client| client.ip)
+    {
+        span.record("client.address", ip.to_string());
+    }
+
+    if crate::server::grpc_routing::is_grpc_request(request) {
+        span.record("otel.name", path.trim_start_matches('/'));
+        span.record("rpc.system", "grpc");
+    } else {
+        let route = crate::metrics::route_label(request);
+        if route == crate::metrics::UNMATCHED_ROUTE {
+            span.record("otel.name", method);
+        } else {
+            span.record("otel.name", format!("{method} {route}"));
+            span.record("http.route", route);
+        }
+    }
+    span
+}
+
+fn is_error(response: &Response) -> bool {
+    response.status().is_server_error()
+        || response
+            .headers()
+            .get("grpc-status")
+            .is_some_and(|status| status.as_bytes() != b"0")
+}
+
+/// Run each request inside a server span named by its route template
+pub(crate) async fn request_span_middleware(
+    State(config): State<Arc<TelemetryConfig>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let span = request_span(&request);
+    extract_parent(&span, request.headers());
+
+    let mut response = next.run(request).instrument(span.clone()).await;
+
+    span.record("http.response.status_code", response.status().as_u16());
+    if is_error(&response) {
+        span.record("otel.status_code", "ERROR");
+    }
+    if config.propagate_response_headers {
+        inject_headers(&span, response.headers_mut());
+    }
+    response
+}
+
+/// Trace every route of `router` with the server's request spans
+///
+/// The server applies this to the routers it builds; use it for routers merged in
+/// afterwards (for example WebSocket and SSE routes).
+pub fn trace_router(router: axum::Router, config: Arc<TelemetryConfig>) -> axum::Router {
+    router.layer(axum::middleware::from_fn_with_state(config, request_span_middleware))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn config_defaults_apply_to_partial_input() {
+        let config: TelemetryConfig = serde_json::from_value(serde_json::json!({
+            "service_name": "orders"
+        }))
+        .expect("config");
+        assert_eq!(config.service_name, "orders");
+        assert_eq!(config.sample_ratio, 1.0);
+        assert!(config.propagate_response_headers);
+        assert!(config.otlp_endpoint.is_none());
+    }
+}
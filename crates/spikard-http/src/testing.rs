@@ -10,6 +10,27 @@ pub mod form;
 pub mod test_client;
 pub use test_client::{GraphQLSubscriptionSnapshot, TestClient};
 
+pub mod stream;
+pub use stream::{SseReader, StreamError, StreamingResponse};
+
+pub mod grpc;
+pub use grpc::GrpcResponse;
+
+pub mod contract;
+pub use contract::{
+    CaseKind, ContractCase, ContractFailure, ContractParam, ContractReport, ContractRoute, ContractTransport,
+    ParamLocation, generate_cases, run_contract,
+};
+
+pub mod replay;
+pub use replay::{ReplayOutcome, load_recorded_fixtures, replay_fixture};
+pub use spikard_fixtures::{Fixture, FixtureExpectedResponse, FixtureHandler, FixtureRequest};
+
+pub mod snapshot;
+pub use snapshot::{
+    SnapshotOptions, SnapshotOutcome, SnapshotUpdate, assert_snapshot_file, check_snapshot, snapshot_path,
+};
+
 use brotli::Decompressor;
 use flate2::read::GzDecoder;
 pub use form::encode_urlencoded_body;
@@ -423,7 +444,8 @@ pub async fn connect_websocket(server: &TestServer, path: &str) -> WebSocketConn
 
 /// Server-Sent Events (SSE) stream for testing.
 ///
-/// Wraps a response body and provides methods to parse SSE events.
+/// Wraps a complete response body and provides methods to parse SSE events.
+/// Use [`TestClient::sse`] to read events as they arrive instead.
 #[derive(Debug)]
 pub struct SseStream {
     body: String,
@@ -443,23 +465,9 @@ impl SseStream {
     }
 
     fn parse_events(body: &str) -> Vec<SseEvent> {
-        let mut events = Vec::new();
-        let lines: Vec<&str> = body.lines().collect();
-        let mut i = 0;
-
-        while i < lines.len() {
-            if lines[i].starts_with("data:") {
-                let data = lines[i].trim_start_matches("data:").trim().to_string();
-                events.push(SseEvent { data });
-            } else if lines[i].starts_with("data") {
-                let data = lines[i].trim_start_matches("data").trim().to_string();
-                if !data.is_empty() || lines[i].len() == 4 {
-                    events.push(SseEvent { data });
-                }
-            }
-            i += 1;
-        }
-
+        let mut parser = stream::SseParser::default();
+        let mut events = parser.feed(body.as_bytes());
+        events.extend(parser.finish());
         events
     }
 
@@ -483,10 +491,16 @@ impl SseStream {
 }
 
 /// A single Server-Sent Event.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct SseEvent {
-    /// The data field of the event.
+    /// The data of the event; multiple `data` lines are joined with `\n`.
     pub data: String,
+    /// The `event` type, if the server set one.
+    pub event: Option<String>,
+    /// The `id` field, if the server set one.
+    pub id: Option<String>,
+    /// The `retry` reconnection time in milliseconds, if the server set one.
+    pub retry: Option<u64>,
 }
 
 impl SseEvent {
@@ -525,6 +539,7 @@ mod tests {
     fn sse_event_reports_invalid_json() {
         let event = SseEvent {
             data: "not-json".to_string(),
+            ..SseEvent::default()
         };
         assert!(event.as_json().is_err());
     }
@@ -0,0 +1,1445 @@
+//! Contract testing of routes against their JSON Schemas
+//!
+//! Requests are generated from each route's `parameter_schema` and `request_schema`:
+//! valid requests, boundary values (length, range and item-count limits, enum members)
+//! and invalid requests that break exactly one constraint. Every case is sent through a
+//! [`ContractTransport`], usually a [`TestClient`], and its response is checked:
+//!
+//! - invalid requests must be rejected with a 4xx JSON `ProblemDetails` body;
+//! - valid and boundary requests must not be rejected or fail with a 5xx, and JSON
+//!   bodies of successful responses must match the route's `response_schema`.
+//!
+//! Failing cases are shrunk before they are reported: optional fields are dropped and
+//! strings, arrays and numbers simplified for as long as the failure reproduces.
+
+use super::{ResponseSnapshot, SnapshotError, TestClient};
+use crate::RouteMetadata;
+use axum::http::Method;
+use bytes::Bytes;
+use serde::Serialize;
+use serde_json::{Map, Value, json};
+use spikard_core::validation::SchemaValidator;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use urlencoding::encode;
+
+/// Requests spent at most on shrinking one failing case.
+const MAX_SHRINK_ATTEMPTS: usize = 64;
+/// Body fields nested deeper than this are not mutated one by one.
+const MAX_FIELD_DEPTH: usize = 4;
+/// Longest string or array generated for a boundary or invalid value.
+const MAX_GENERATED_LENGTH: usize = 4096;
+/// HTTP methods read from `OpenAPI` path items.
+const OPENAPI_METHODS: [&str; 8] = ["get", "put", "post", "delete", "options", "head", "patch", "trace"];
+
+/// Schemas of one route, the input of contract testing.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ContractRoute {
+    /// HTTP method, e.g. `POST`.
+    pub method: String,
+    /// Route path template, e.g. `/items/{id:int}`.
+    pub path: String,
+    /// Parameter schema with a `source` on every property.
+    pub parameter_schema: Option<Value>,
+    /// JSON body schema.
+    pub request_schema: Option<Value>,
+    /// JSON schema of successful response bodies.
+    pub response_schema: Option<Value>,
+}
+
+impl ContractRoute {
+    /// Take the schemas of a registered route, including those implied by path type hints.
+    pub fn from_metadata(metadata: &RouteMetadata) -> Self {
+        let explicit = metadata
+            .parameter_schema
+            .clone()
+            .filter(|schema| !is_empty_schema(schema));
+        let parameter_schema = match (
+            spikard_core::type_hints::auto_generate_parameter_schema(&metadata.path),
+            explicit,
+        ) {
+            (Some(auto), Some(explicit)) => Some(spikard_core::type_hints::merge_parameter_schemas(&auto, &explicit)),
+            (auto, explicit) => explicit.or(auto),
+        };
+        Self {
+            method: metadata.method.to_ascii_uppercase(),
+            path: metadata.path.clone(),
+            parameter_schema,
+            request_schema: metadata
+                .request_schema
+                .clone()
+                .filter(|schema| !is_empty_schema(schema)),
+            response_schema: metadata
+                .response_schema
+                .clone()
+                .filter(|schema| !is_empty_schema(schema)),
+        }
+    }
+
+    /// Read the operations of an `OpenAPI` document, resolving local `$ref`s.
+    ///
+    /// Parameters become the parameter schema, the `application/json` request body the request
+    /// schema and the first `2xx` `application/json` response the response schema.
+    pub fn from_openapi(spec: &Value) -> Vec<Self> {
+        let Some(paths) = spec.get("paths").and_then(Value::as_object) else {
+            return Vec::new();
+        };
+
+        let mut routes = Vec::new();
+        for (path, item) in paths {
+            let item = resolve_refs(item, spec, 0);
+            let shared_parameters = item
+                .get("parameters")
+                .and_then(Value::as_array)
+                .cloned()
+                .unwrap_or_default();
+            for method in OPENAPI_METHODS {
+                let Some(operation) = item.get(method) else {
+                    continue;
+                };
+                let mut parameters = shared_parameters.clone();
+                for parameter in operation
+                    .get("parameters")
+                    .and_then(Value::as_array)
+                    .into_iter()
+                    .flatten()
+                {
+                    parameters.retain(|existing| {
+                        existing.get("name") != parameter.get("name") || existing.get("in") != parameter.get("in")
+                    });
+                    parameters.push(parameter.clone());
+                }
+
+                routes.push(Self {
+                    method: method.to_ascii_uppercase(),
+                    path: path.clone(),
+                    parameter_schema: openapi_parameter_schema(&parameters),
+                    request_schema: operation
+                        .pointer("/requestBody/content/application~1json/schema")
+                        .cloned(),
+                    response_schema: openapi_response_schema(operation),
+                });
+            }
+        }
+        routes
+    }
+
+    fn label(&self) -> String {
+        format!("{} {}", self.method, self.path)
+    }
+}
+
+/// What a generated request is expected to do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CaseKind {
+    /// Satisfies every schema with typical values.
+    Valid,
+    /// Satisfies every schema with a value at the edge of a constraint.
+    Boundary,
+    /// Breaks one constraint and must be rejected.
+    Invalid,
+}
+
+impl fmt::Display for CaseKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Valid => "valid",
+            Self::Boundary => "boundary",
+            Self::Invalid => "invalid",
+        })
+    }
+}
+
+/// Where a request parameter is sent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ParamLocation {
+    /// Substituted into the path template.
+    Path,
+    /// Appended to the query string.
+    Query,
+    /// Sent as a header, with underscores in the name turned into dashes.
+    Header,
+    /// Sent in the `cookie` header.
+    Cookie,
+}
+
+/// One parameter of a generated request.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ContractParam {
+    /// Parameter name as declared in the schema.
+    pub name: String,
+    /// Where the parameter is sent.
+    pub location: ParamLocation,
+    /// Typed value; strings are sent as-is and other values as JSON text.
+    pub value: Value,
+}
+
+/// Field a case is about, left untouched while shrinking.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Field {
+    Param(String),
+    Body(String),
+}
+
+/// A generated request.
+#[derive(Debug, Clone, Serialize)]
+pub struct ContractCase {
+    /// Expected outcome.
+    pub kind: CaseKind,
+    /// What the case exercises, e.g. ``body `/name` longer than maxLength 20``.
+    pub description: String,
+    /// HTTP method.
+    pub method: String,
+    /// Route path template the case was generated for.
+    pub path: String,
+    /// Path, query, header and cookie parameters.
+    pub params: Vec<ContractParam>,
+    /// JSON body, sent with `content-type: application/json`.
+    pub body: Option<Value>,
+    #[serde(skip)]
+    field: Option<Field>,
+}
+
+impl ContractCase {
+    /// Request URI with path parameters substituted and the query string appended.
+    pub fn uri(&self) -> String {
+        let mut uri = String::new();
+        let mut rest = self.path.as_str();
+        while let Some(start) = rest.find('{') {
+            let Some(len) = rest[start..].find('}') else {
+                break;
+            };
+            uri.push_str(&rest[..start]);
+            let name = placeholder_name(&rest[start + 1..start + len]);
+            let value = self
+                .params
+                .iter()
+                .find(|param| param.location == ParamLocation::Path && param.name == name)
+                .map(|param| param_text(&param.value))
+                .unwrap_or_default();
+            uri.push_str(&encode(&value));
+            rest = &rest[start + len + 1..];
+        }
+        uri.push_str(rest);
+
+        let mut query = Vec::new();
+        for param in self
+            .params
+            .iter()
+            .filter(|param| param.location == ParamLocation::Query)
+        {
+            match &param.value {
+                Value::Array(items) => {
+                    for item in items {
+                        query.push(format!("{}={}", encode(&param.name), encode(&param_text(item))));
+                    }
+                }
+                value => query.push(format!("{}={}", encode(&param.name), encode(&param_text(value)))),
+            }
+        }
+        if !query.is_empty() {
+            uri.push('?');
+            uri.push_str(&query.join("&"));
+        }
+        uri
+    }
+
+    /// Request headers, including `cookie` and the body content type.
+    pub fn headers(&self) -> Vec<(String, String)> {
+        let mut headers: Vec<(String, String)> = self
+            .params
+            .iter()
+            .filter(|param| param.location == ParamLocation::Header)
+            .map(|param| {
+                (
+                    param.name.replace('_', "-").to_ascii_lowercase(),
+                    param_text(&param.value),
+                )
+            })
+            .collect();
+        let cookies: Vec<String> = self
+            .params
+            .iter()
+            .filter(|param| param.location == ParamLocation::Cookie)
+            .map(|param| format!("{}={}", param.name, param_text(&param.value)))
+            .collect();
+        if !cookies.is_empty() {
+            headers.push(("cookie".to_string(), cookies.join("; ")));
+        }
+        if self.body.is_some() {
+            headers.push(("content-type".to_string(), "application/json".to_string()));
+        }
+        headers
+    }
+
+    /// Serialized request body, empty when the case has none.
+    pub fn body_bytes(&self) -> Bytes {
+        self.body
+            .as_ref()
+            .map(|body| Bytes::from(body.to_string()))
+            .unwrap_or_default()
+    }
+
+    /// Parameters as one JSON object, the shape the parameter schema validates.
+    fn params_object(&self) -> Value {
+        Value::Object(
+            self.params
+                .iter()
+                .map(|param| (param.name.clone(), param.value.clone()))
+                .collect(),
+        )
+    }
+}
+
+impl fmt::Display for ContractCase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.method, self.uri())?;
+        for (name, value) in self.headers() {
+            if name != "content-type" {
+                write!(f, " [{name}: {value}]")?;
+            }
+        }
+        if let Some(body) = &self.body {
+            write!(f, " {body}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A case whose response broke the contract.
+#[derive(Debug, Clone, Serialize)]
+pub struct ContractFailure {
+    /// Route the case was generated for, e.g. `POST /items`.
+    pub route: String,
+    /// Smallest request found that still fails.
+    pub case: ContractCase,
+    /// Why the response broke the contract.
+    pub reason: String,
+    /// Response status of the shrunk case.
+    pub status: u16,
+    /// Number of simplifications applied to the generated case.
+    pub shrink_steps: usize,
+}
+
+/// Outcome of [`run_contract`].
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ContractReport {
+    /// Routes tested.
+    pub routes: usize,
+    /// Cases sent.
+    pub cases: usize,
+    /// Cases whose response honoured the contract.
+    pub passed: usize,
+    /// Generated cases dropped because they did not meet their own expectation, e.g. a
+    /// sample that cannot satisfy a `pattern`.
+    pub skipped: usize,
+    /// Cases that broke the contract, after shrinking.
+    pub failures: Vec<ContractFailure>,
+}
+
+impl ContractReport {
+    /// Whether every case honoured the contract.
+    pub fn is_success(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// Sends contract cases to the application under test.
+pub trait ContractTransport {
+    /// Send one case and return the buffered response.
+    fn send<'a>(
+        &'a self,
+        case: &'a ContractCase,
+    ) -> Pin<Box<dyn Future<Output = Result<ResponseSnapshot, SnapshotError>> + Send + 'a>>;
+}
+
+impl ContractTransport for TestClient {
+    fn send<'a>(
+        &'a self,
+        case: &'a ContractCase,
+    ) -> Pin<Box<dyn Future<Output = Result<ResponseSnapshot, SnapshotError>> + Send + 'a>> {
+        Box::pin(async move {
+            let method = Method::from_bytes(case.method.as_bytes())
+                .map_err(|e| SnapshotError::Decompression(format!("Invalid method {}: {}", case.method, e)))?;
+            self.request_raw(method, &case.uri(), case.body_bytes(), None, Some(case.headers()))
+                .await
+        })
+    }
+}
+
+/// Generate the cases of one route, keeping only those that meet their own expectation.
+pub fn generate_cases(route: &ContractRoute) -> Result<Vec<ContractCase>, SnapshotError> {
+    let compiled = CompiledRoute::new(route)?;
+    Ok(candidate_cases(route)
+        .into_iter()
+        .filter(|case| compiled.accepts(case) == (case.kind != CaseKind::Invalid))
+        .collect())
+}
+
+/// Run every generated case of `routes` and report the ones that break the contract.
+pub async fn run_contract<T: ContractTransport + ?Sized>(
+    transport: &T,
+    routes: &[ContractRoute],
+) -> Result<ContractReport, SnapshotError> {
+    let mut report = ContractReport::default();
+    for route in routes {
+        let compiled = CompiledRoute::new(route)?;
+        report.routes += 1;
+        for case in candidate_cases(route) {
+            if compiled.accepts(&case) != (case.kind != CaseKind::Invalid) {
+                report.skipped += 1;
+                continue;
+            }
+            report.cases += 1;
+            let response = transport.send(&case).await?;
+            match compiled.check(case.kind, &response) {
+                Ok(()) => report.passed += 1,
+                Err(reason) => {
+                    let failure = shrink(transport, &compiled, case, reason, response.status).await?;
+                    report.failures.push(ContractFailure {
+                        route: route.label(),
+                        ..failure
+                    });
+                }
+            }
+        }
+    }
+    Ok(report)
+}
+
+/// Compiled schemas of a route.
+struct CompiledRoute {
+    params: Option<SchemaValidator>,
+    request: Option<SchemaValidator>,
+    response: Option<SchemaValidator>,
+}
+
+impl CompiledRoute {
+    fn new(route: &ContractRoute) -> Result<Self, SnapshotError> {
+        let compile = |kind: &str, schema: Option<Value>| {
+            schema
+                .map(|schema| {
+                    SchemaValidator::new(schema).map_err(|e| {
+                        SnapshotError::Decompression(format!("Invalid {} schema for {}: {}", kind, route.label(), e))
+                    })
+                })
+                .transpose()
+        };
+        Ok(Self {
+            params: compile(
+                "parameter",
+                route.parameter_schema.as_ref().map(params_validation_schema),
+            )?,
+            request: compile("request", route.request_schema.clone())?,
+            response: compile("response", route.response_schema.clone())?,
+        })
+    }
+
+    /// Whether the case satisfies the parameter and request schemas.
+    fn accepts(&self, case: &ContractCase) -> bool {
+        let params_ok = self
+            .params
+            .as_ref()
+            .is_none_or(|validator| validator.validate(&case.params_object()).is_ok());
+        let body_ok = match (&self.request, &case.body) {
+            (Some(validator), Some(body)) => validator.validate(body).is_ok(),
+            (Some(_), None) => false,
+            (None, _) => true,
+        };
+        params_ok && body_ok
+    }
+
+    /// Check a response against the expectation of its case.
+    fn check(&self, kind: CaseKind, response: &ResponseSnapshot) -> Result<(), String> {
+        let status = response.status;
+        if kind == CaseKind::Invalid {
+            if !(400..500).contains(&status) {
+                return Err(format!("invalid request was answered with {status} instead of a 4xx"));
+            }
+            let content_type = response.header("content-type").unwrap_or_default();
+            if !content_type.starts_with("application/problem+json") && !content_type.starts_with("application/json") {
+                return Err(format!("rejection has content-type `{content_type}` instead of JSON"));
+            }
+            let problem = response.json().unwrap_or(Value::Null);
+            if problem.get("status").and_then(Value::as_u64) != Some(u64::from(status))
+                || !problem.get("title").is_some_and(Value::is_string)
+            {
+                return Err("rejection body is not a ProblemDetails document".to_string());
+            }
+            return Ok(());
+        }
+
+        if status >= 500 {
+            return Err(format!("request failed with {status}"));
+        }
+        if status == 400 || status == 422 {
+            return Err(format!("request satisfying the schemas was rejected with {status}"));
+        }
+        if let Some(validator) = &self.response
+            && (200..300).contains(&status)
+            && !response.body.is_empty()
+        {
+            let body = response.json().map_err(|e| format!("response body is not JSON: {e}"))?;
+            if let Err(error) = validator.validate(&body) {
+                let detail = error
+                    .errors
+                    .first()
+                    .map(|detail| format!("{}: {}", detail.loc.join("."), detail.msg))
+                    .unwrap_or_default();
+                return Err(format!("response does not match response_schema ({detail})"));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Simplify a failing case for as long as the failure reproduces.
+async fn shrink<T: ContractTransport + ?Sized>(
+    transport: &T,
+    compiled: &CompiledRoute,
+    case: ContractCase,
+    reason: String,
+    status: u16,
+) -> Result<ContractFailure, SnapshotError> {
+    let mut failure = ContractFailure {
+        route: String::new(),
+        case,
+        reason,
+        status,
+        shrink_steps: 0,
+    };
+    let expect_valid = failure.case.kind != CaseKind::Invalid;
+    let mut attempts = 0;
+
+    'shrinking: loop {
+        for candidate in simplifications(&failure.case) {
+            if attempts == MAX_SHRINK_ATTEMPTS {
+                break 'shrinking;
+            }
+            if compiled.accepts(&candidate) != expect_valid {
+                continue;
+            }
+            attempts += 1;
+            let response = transport.send(&candidate).await?;
+            if let Err(reason) = compiled.check(candidate.kind, &response) {
+                failure.case = candidate;
+                failure.reason = reason;
+                failure.status = response.status;
+                failure.shrink_steps += 1;
+                continue 'shrinking;
+            }
+        }
+        break;
+    }
+    Ok(failure)
+}
+
+/// Smaller variants of a case: fewer parameters and fields first, then shorter values.
+fn simplifications(case: &ContractCase) -> Vec<ContractCase> {
+    let keeps_param = |name: &str| case.field.as_ref() == Some(&Field::Param(name.to_string()));
+    let target = match &case.field {
+        Some(Field::Body(pointer)) => Some(pointer.as_str()),
+        _ => None,
+    };
+    let touches_target =
+        |pointer: &str| target.is_some_and(|target| is_prefix(pointer, target) || is_prefix(target, pointer));
+    let mut candidates = Vec::new();
+
+    for (index, param) in case.params.iter().enumerate() {
+        if !keeps_param(&param.name) {
+            let mut candidate = case.clone();
+            candidate.params.remove(index);
+            candidates.push(candidate);
+        }
+    }
+
+    let mut nodes = Vec::new();
+    if let Some(body) = &case.body {
+        collect_nodes(body, String::new(), &mut nodes);
+    }
+    for (pointer, _) in nodes.iter().filter(|(pointer, _)| !pointer.is_empty()) {
+        if !touches_target(pointer)
+            && let Some(body) = case.body.as_ref().and_then(|body| without(body, pointer))
+        {
+            candidates.push(ContractCase {
+                body: Some(body),
+                ..case.clone()
+            });
+        }
+    }
+
+    for (index, param) in case.params.iter().enumerate() {
+        if keeps_param(&param.name) {
+            continue;
+        }
+        if let Some(value) = simpler_value(&param.value) {
+            let mut candidate = case.clone();
+            candidate.params[index].value = value;
+            candidates.push(candidate);
+        }
+    }
+    for (pointer, value) in &nodes {
+        if touches_target(pointer) {
+            continue;
+        }
+        if let Some(body) =
+            simpler_value(value).and_then(|value| case.body.as_ref().and_then(|body| with_value(body, pointer, value)))
+        {
+            candidates.push(ContractCase {
+                body: Some(body),
+                ..case.clone()
+            });
+        }
+    }
+    candidates
+}
+
+fn simpler_value(value: &Value) -> Option<Value> {
+    match value {
+        Value::String(text) if !text.is_empty() => {
+            Some(Value::String(text.chars().take(text.chars().count() / 2).collect()))
+        }
+        Value::Array(items) if !items.is_empty() => Some(Value::Array(items[..items.len() - 1].to_vec())),
+        Value::Number(number) if number.as_f64() != Some(0.0) => Some(json!(0)),
+        Value::Bool(true) => Some(Value::Bool(false)),
+        _ => None,
+    }
+}
+
+fn collect_nodes(value: &Value, pointer: String, out: &mut Vec<(String, Value)>) {
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map {
+                collect_nodes(child, format!("{}/{}", pointer, escape_pointer(key)), out);
+            }
+        }
+        Value::Array(items) => {
+            for (index, child) in items.iter().enumerate() {
+                collect_nodes(child, format!("{pointer}/{index}"), out);
+            }
+        }
+        _ => {}
+    }
+    out.push((pointer, value.clone()));
+}
+
+/// A parameter of the route, with its schema.
+struct ParamSpec {
+    name: String,
+    location: ParamLocation,
+    schema: Value,
+    required: bool,
+}
+
+fn param_specs(route: &ContractRoute) -> Vec<ParamSpec> {
+    let schema = route.parameter_schema.as_ref();
+    let required: Vec<&str> = schema
+        .and_then(|schema| schema.get("required"))
+        .and_then(Value::as_array)
+        .map(|names| names.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default();
+
+    let mut specs: Vec<ParamSpec> = schema
+        .and_then(|schema| schema.get("properties"))
+        .and_then(Value::as_object)
+        .into_iter()
+        .flatten()
+        .filter_map(|(name, property)| {
+            let location = match property.get("source").and_then(Value::as_str)? {
+                "path" => ParamLocation::Path,
+                "query" => ParamLocation::Query,
+                "header" => ParamLocation::Header,
+                "cookie" => ParamLocation::Cookie,
+                _ => return None,
+            };
+            let optional = property.get("optional").and_then(Value::as_bool).unwrap_or(false);
+            Some(ParamSpec {
+                name: name.clone(),
+                location,
+                schema: property.clone(),
+                required: location == ParamLocation::Path || (required.contains(&name.as_str()) && !optional),
+            })
+        })
+        .collect();
+
+    for name in path_placeholders(&route.path) {
+        if !specs.iter().any(|spec| spec.name == name) {
+            specs.push(ParamSpec {
+                name,
+                location: ParamLocation::Path,
+                schema: json!({"type": "string"}),
+                required: true,
+            });
+        }
+    }
+    specs
+}
+
+/// Every case for a route, before filtering against the route's own schemas.
+fn candidate_cases(route: &ContractRoute) -> Vec<ContractCase> {
+    let specs = param_specs(route);
+    let params = |required_only: bool| -> Vec<ContractParam> {
+        specs
+            .iter()
+            .filter(|spec| spec.required || !required_only)
+            .map(|spec| ContractParam {
+                name: spec.name.clone(),
+                location: spec.location,
+                value: sample(&spec.schema, required_only, 0),
+            })
+            .collect()
+    };
+    let base = ContractCase {
+        kind: CaseKind::Valid,
+        description: "every parameter and field with typical values".to_string(),
+        method: route.method.clone(),
+        path: route.path.clone(),
+        params: params(false),
+        body: route.request_schema.as_ref().map(|schema| sample(schema, false, 0)),
+        field: None,
+    };
+
+    let mut cases = vec![base.clone()];
+    let minimal = ContractCase {
+        description: "required parameters and fields only".to_string(),
+        params: params(true),
+        body: route.request_schema.as_ref().map(|schema| sample(schema, true, 0)),
+        ..base.clone()
+    };
+    if minimal.params != base.params || minimal.body != base.body {
+        cases.push(minimal);
+    }
+
+    for (index, spec) in specs.iter().enumerate() {
+        let label = format!("{} parameter `{}`", location_name(spec.location), spec.name);
+        let with_param = |kind: CaseKind, description: String, value: Value| {
+            let mut case = ContractCase {
+                kind,
+                description: format!("{label} {description}"),
+                field: Some(Field::Param(spec.name.clone())),
+                ..base.clone()
+            };
+            case.params[index].value = value;
+            case
+        };
+        for (description, value) in boundary_values(&spec.schema) {
+            if value != base.params[index].value {
+                cases.push(with_param(CaseKind::Boundary, description, value));
+            }
+        }
+        for (description, value) in invalid_values(&spec.schema, true) {
+            cases.push(with_param(CaseKind::Invalid, description, value));
+        }
+        if spec.required && spec.location != ParamLocation::Path {
+            let mut case = ContractCase {
+                kind: CaseKind::Invalid,
+                description: format!("{label} missing"),
+                field: Some(Field::Param(spec.name.clone())),
+                ..base.clone()
+            };
+            case.params.remove(index);
+            cases.push(case);
+        }
+    }
+
+    if let (Some(schema), Some(body)) = (&route.request_schema, &base.body) {
+        let mut fields = Vec::new();
+        body_fields(schema, String::new(), 0, &mut fields);
+        for (pointer, schema) in fields {
+            let Some(current) = body.pointer(&pointer) else {
+                continue;
+            };
+            let label = if pointer.is_empty() {
+                "body".to_string()
+            } else {
+                format!("body `{pointer}`")
+            };
+            let mut push = |kind: CaseKind, description: String, body: Option<Value>| {
+                if let Some(body) = body {
+                    cases.push(ContractCase {
+                        kind,
+                        description: format!("{label} {description}"),
+                        body: Some(body),
+                        field: Some(Field::Body(pointer.clone())),
+                        ..base.clone()
+                    });
+                }
+            };
+            for (description, value) in boundary_values(&schema) {
+                if &value != current {
+                    push(CaseKind::Boundary, description, with_value(body, &pointer, value));
+                }
+            }
+            for (description, value) in invalid_values(&schema, false) {
+                push(CaseKind::Invalid, description, with_value(body, &pointer, value));
+            }
+
+            let schema = effective(&schema);
+            for name in required_names(&schema) {
+                let field = format!("{}/{}", pointer, escape_pointer(&name));
+                push(
+                    CaseKind::Invalid,
+                    format!("without required field `{name}`"),
+                    without(body, &field),
+                );
+            }
+            if schema.get("additionalProperties") == Some(&Value::Bool(false)) && current.is_object() {
+                let mut extended = current.clone();
+                if let Some(map) = extended.as_object_mut() {
+                    map.insert("unexpected_field".to_string(), json!("unexpected"));
+                }
+                push(
+                    CaseKind::Invalid,
+                    "with an undeclared field".to_string(),
+                    with_value(body, &pointer, extended),
+                );
+            }
+        }
+    }
+    cases
+}
+
+/// Schema nodes of a body reachable through properties and array items.
+fn body_fields(schema: &Value, pointer: String, depth: usize, out: &mut Vec<(String, Value)>) {
+    let resolved = effective(schema);
+    if depth < MAX_FIELD_DEPTH {
+        if let Some(properties) = resolved.get("properties").and_then(Value::as_object) {
+            for (name, property) in properties {
+                body_fields(
+                    property,
+                    format!("{}/{}", pointer, escape_pointer(name)),
+                    depth + 1,
+                    out,
+                );
+            }
+        }
+        if let Some(items) = resolved.get("items").filter(|items| items.is_object()) {
+            body_fields(items, format!("{pointer}/0"), depth + 1, out);
+        }
+    }
+    out.push((pointer, resolved));
+}
+
+/// A value satisfying `schema`, preferring declared constants, enums and examples.
+fn sample(schema: &Value, required_only: bool, depth: usize) -> Value {
+    let schema = effective(schema);
+    if let Some(value) = schema.get("const") {
+        return value.clone();
+    }
+    if let Some(value) = schema
+        .get("enum")
+        .and_then(Value::as_array)
+        .and_then(|values| values.first())
+    {
+        return value.clone();
+    }
+    if let Some(value) = schema
+        .get("examples")
+        .and_then(Value::as_array)
+        .and_then(|values| values.first())
+        .or_else(|| schema.get("example"))
+        .or_else(|| schema.get("default"))
+    {
+        return value.clone();
+    }
+
+    match schema_type(&schema) {
+        Some("string") => {
+            if let Some(value) = schema.get("format").and_then(Value::as_str).and_then(format_sample) {
+                return json!(value);
+            }
+            let (min, max) = length_bounds(&schema, "minLength", "maxLength");
+            let len = min.unwrap_or(0).max(1).min(max.unwrap_or(usize::MAX));
+            json!("a".repeat(len))
+        }
+        Some("integer") => {
+            let (min, max) = integer_bounds(&schema);
+            let mut value = 1;
+            if let Some(min) = min {
+                value = value.max(min);
+            }
+            if let Some(max) = max {
+                value = value.min(max);
+            }
+            json!(value)
+        }
+        Some("number") => {
+            let (min, max) = number_bounds(&schema);
+            let value = 1.0_f64.max(min.unwrap_or(f64::MIN)).min(max.unwrap_or(f64::MAX));
+            json!(value)
+        }
+        Some("boolean") => json!(true),
+        Some("null") => Value::Null,
+        Some("array") => {
+            let (min, max) = length_bounds(&schema, "minItems", "maxItems");
+            let len = min.unwrap_or(0).max(1).min(max.unwrap_or(usize::MAX));
+            let item = schema
+                .get("items")
+                .filter(|_| depth < MAX_FIELD_DEPTH * 2)
+                .map_or_else(|| json!("sample"), |items| sample(items, required_only, depth + 1));
+            Value::Array(vec![item; len])
+        }
+        Some("object") => {
+            let required = required_names(&schema);
+            let mut object = Map::new();
+            if depth < MAX_FIELD_DEPTH * 2 {
+                for (name, property) in schema
+                    .get("properties")
+                    .and_then(Value::as_object)
+                    .into_iter()
+                    .flatten()
+                {
+                    if !required_only || required.contains(name) {
+                        object.insert(name.clone(), sample(property, required_only, depth + 1));
+                    }
+                }
+            }
+            Value::Object(object)
+        }
+        _ => json!("sample"),
+    }
+}
+
+/// Values on the edges of the constraints of `schema`.
+fn boundary_values(schema: &Value) -> Vec<(String, Value)> {
+    let schema = effective(schema);
+    let mut values = Vec::new();
+    if let Some(members) = schema.get("enum").and_then(Value::as_array) {
+        for member in members.iter().skip(1) {
+            values.push((format!("set to enum member {member}"), member.clone()));
+        }
+        return values;
+    }
+
+    match schema_type(&schema) {
+        Some("string") if schema.get("format").is_none() && schema.get("pattern").is_none() => {
+            let (min, max) = length_bounds(&schema, "minLength", "maxLength");
+            let min = min.unwrap_or(0);
+            values.push((format!("at minLength {min}"), json!("a".repeat(min))));
+            if let Some(max) = max.filter(|&max| max <= MAX_GENERATED_LENGTH) {
+                values.push((format!("at maxLength {max}"), json!("a".repeat(max))));
+            }
+        }
+        Some("integer") => {
+            let (min, max) = integer_bounds(&schema);
+            if let Some(min) = min {
+                values.push((format!("at minimum {min}"), json!(min)));
+            }
+            if let Some(max) = max {
+                values.push((format!("at maximum {max}"), json!(max)));
+            }
+        }
+        Some("number") => {
+            if let Some(min) = schema.get("minimum").and_then(Value::as_f64) {
+                values.push((format!("at minimum {min}"), json!(min)));
+            }
+            if let Some(max) = schema.get("maximum").and_then(Value::as_f64) {
+                values.push((format!("at maximum {max}"), json!(max)));
+            }
+        }
+        Some("array") => {
+            let (min, max) = length_bounds(&schema, "minItems", "maxItems");
+            let item = schema
+                .get("items")
+                .map_or_else(|| json!("sample"), |items| sample(items, false, 0));
+            let min = min.unwrap_or(0);
+            values.push((format!("with minItems {min}"), Value::Array(vec![item.clone(); min])));
+            if let Some(max) = max.filter(|&max| max <= MAX_GENERATED_LENGTH) {
+                values.push((format!("with maxItems {max}"), Value::Array(vec![item; max])));
+            }
+        }
+        _ => {}
+    }
+    values
+}
+
+/// Values breaking one constraint of `schema`. Parameters travel as text, so only their
+/// non-string types can be broken by a type mismatch.
+fn invalid_values(schema: &Value, as_param: bool) -> Vec<(String, Value)> {
+    let schema = effective(schema);
+    let mut values = Vec::new();
+    let kind = schema_type(&schema);
+
+    let mismatch = match kind {
+        Some("integer" | "number") => Some(json!("not-a-number")),
+        Some("boolean") => Some(json!("not-a-boolean")),
+        Some("string") if !as_param => Some(json!(12345)),
+        Some("array") if !as_param => Some(json!("not-an-array")),
+        Some("object") if !as_param => Some(json!("not-an-object")),
+        _ => None,
+    };
+    if let Some(value) = mismatch {
+        values.push((
+            format!("of the wrong type (expected {})", kind.unwrap_or_default()),
+            value,
+        ));
+    }
+    if schema.get("enum").is_some() {
+        values.push(("outside the enum".to_string(), json!("not-an-enum-member")));
+    }
+
+    match kind {
+        Some("string") => {
+            let (min, max) = length_bounds(&schema, "minLength", "maxLength");
+            if let Some(min) = min.filter(|&min| min > 0 && min <= MAX_GENERATED_LENGTH) {
+                values.push((format!("shorter than minLength {min}"), json!("a".repeat(min - 1))));
+            }
+            if let Some(max) = max.filter(|&max| max < MAX_GENERATED_LENGTH) {
+                values.push((format!("longer than maxLength {max}"), json!("a".repeat(max + 1))));
+            }
+            if let Some(format) = schema.get("format").and_then(Value::as_str)
+                && format_sample(format).is_some()
+            {
+                values.push((format!("not a valid {format}"), json!(format!("not-a-{format}"))));
+            }
+        }
+        Some("integer") => {
+            let (min, max) = integer_bounds(&schema);
+            if let Some(min) = min.and_then(|min| min.checked_sub(1)) {
+                values.push((format!("below minimum ({min})"), json!(min)));
+            }
+            if let Some(max) = max.and_then(|max| max.checked_add(1)) {
+                values.push((format!("above maximum ({max})"), json!(max)));
+            }
+        }
+        Some("number") => {
+            if let Some(min) = schema.get("minimum").and_then(Value::as_f64) {
+                values.push((format!("below minimum ({})", min - 1.0), json!(min - 1.0)));
+            }
+            if let Some(min) = schema.get("exclusiveMinimum").and_then(Value::as_f64) {
+                values.push((format!("at exclusiveMinimum {min}"), json!(min)));
+            }
+            if let Some(max) = schema.get("maximum").and_then(Value::as_f64) {
+                values.push((format!("above maximum ({})", max + 1.0), json!(max + 1.0)));
+            }
+            if let Some(max) = schema.get("exclusiveMaximum").and_then(Value::as_f64) {
+                values.push((format!("at exclusiveMaximum {max}"), json!(max)));
+            }
+        }
+        Some("array") => {
+            let (min, max) = length_bounds(&schema, "minItems", "maxItems");
+            let item = schema
+                .get("items")
+                .map_or_else(|| json!("sample"), |items| sample(items, false, 0));
+            if let Some(min) = min.filter(|&min| min > 0 && min <= MAX_GENERATED_LENGTH) {
+                values.push((
+                    format!("with fewer than minItems {min}"),
+                    Value::Array(vec![item.clone(); min - 1]),
+                ));
+            }
+            if let Some(max) = max.filter(|&max| max < MAX_GENERATED_LENGTH) {
+                values.push((
+                    format!("with more than maxItems {max}"),
+                    Value::Array(vec![item; max + 1]),
+                ));
+            }
+        }
+        _ => {}
+    }
+    values
+}
+
+/// Collapse `allOf` into one schema and pick the first branch of `oneOf`/`anyOf`.
+fn effective(schema: &Value) -> Value {
+    if let Some(parts) = schema.get("allOf").and_then(Value::as_array) {
+        let mut merged = schema.as_object().cloned().unwrap_or_default();
+        merged.remove("allOf");
+        for part in parts.iter().map(effective) {
+            for (key, value) in part.as_object().into_iter().flatten() {
+                match (merged.get_mut(key), value) {
+                    (Some(Value::Object(existing)), Value::Object(extra)) if key == "properties" => {
+                        existing.extend(extra.clone());
+                    }
+                    (Some(Value::Array(existing)), Value::Array(extra)) if key == "required" => {
+                        existing.extend(extra.iter().cloned());
+                    }
+                    (None, _) => {
+                        merged.insert(key.clone(), value.clone());
+                    }
+                    _ => {}
+                }
+            }
+        }
+        return Value::Object(merged);
+    }
+    for key in ["oneOf", "anyOf"] {
+        if let Some(first) = schema
+            .get(key)
+            .and_then(Value::as_array)
+            .and_then(|branches| branches.first())
+        {
+            return effective(first);
+        }
+    }
+    schema.clone()
+}
+
+fn schema_type(schema: &Value) -> Option<&str> {
+    match schema.get("type") {
+        Some(Value::String(kind)) => Some(kind),
+        Some(Value::Array(kinds)) => kinds
+            .iter()
+            .filter_map(Value::as_str)
+            .find(|kind| *kind != "null")
+            .or(Some("null")),
+        _ if schema.get("properties").is_some() => Some("object"),
+        _ if schema.get("items").is_some() => Some("array"),
+        _ => None,
+    }
+}
+
+fn required_names(schema: &Value) -> Vec<String> {
+    schema
+        .get("required")
+        .and_then(Value::as_array)
+        .map(|names| names.iter().filter_map(Value::as_str).map(str::to_string).collect())
+        .unwrap_or_default()
+}
+
+fn length_bounds(schema: &Value, min_key: &str, max_key: &str) -> (Option<usize>, Option<usize>) {
+    let bound = |key: &str| {
+        schema
+            .get(key)
+            .and_then(Value::as_u64)
+            .and_then(|bound| usize::try_from(bound).ok())
+    };
+    (bound(min_key), bound(max_key))
+}
+
+fn integer_bounds(schema: &Value) -> (Option<i64>, Option<i64>) {
+    let get = |key: &str| schema.get(key).and_then(Value::as_i64);
+    let min = match (
+        get("minimum"),
+        get("exclusiveMinimum").and_then(|min| min.checked_add(1)),
+    ) {
+        (Some(a), Some(b)) => Some(a.max(b)),
+        (a, b) => a.or(b),
+    };
+    let max = match (
+        get("maximum"),
+        get("exclusiveMaximum").and_then(|max| max.checked_sub(1)),
+    ) {
+        (Some(a), Some(b)) => Some(a.min(b)),
+        (a, b) => a.or(b),
+    };
+    (min, max)
+}
+
+fn number_bounds(schema: &Value) -> (Option<f64>, Option<f64>) {
+    let get = |key: &str| schema.get(key).and_then(Value::as_f64);
+    (
+        get("minimum").or(get("exclusiveMinimum")),
+        get("maximum").or(get("exclusiveMaximum")),
+    )
+}
+
+fn format_sample(format: &str) -> Option<&'static str> {
+    Some(match format {
+        "uuid" => "3fa85f64-5717-4562-b3fc-2c963f66afa6",
+        "email" => "user@example.com",
+        "date" => "2024-01-15",
+        "date-time" => "2024-01-15T10:30:00Z",
+        "time" => "10:30:00Z",
+        "duration" => "P1D",
+        "uri" => "https://example.com/",
+        "ipv4" => "192.0.2.1",
+        "ipv6" => "2001:db8::1",
+        _ => return None,
+    })
+}
+
+fn location_name(location: ParamLocation) -> &'static str {
+    match location {
+        ParamLocation::Path => "path",
+        ParamLocation::Query => "query",
+        ParamLocation::Header => "header",
+        ParamLocation::Cookie => "cookie",
+    }
+}
+
+/// Parameter schema as validated against typed values: without requirements marked `optional`.
+fn params_validation_schema(schema: &Value) -> Value {
+    let mut schema = schema.clone();
+    let optional: Vec<String> = schema
+        .get("properties")
+        .and_then(Value::as_object)
+        .into_iter()
+        .flatten()
+        .filter(|(_, property)| property.get("optional").and_then(Value::as_bool) == Some(true))
+        .map(|(name, _)| name.clone())
+        .collect();
+    if let Some(required) = schema.get_mut("required").and_then(Value::as_array_mut) {
+        required.retain(|name| name.as_str().is_none_or(|name| !optional.iter().any(|o| o == name)));
+    }
+    schema
+}
+
+fn is_empty_schema(schema: &Value) -> bool {
+    matches!(schema, Value::Object(map) if map.is_empty())
+}
+
+fn param_text(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        Value::Null => String::new(),
+        Value::Array(items) => items.iter().map(param_text).collect::<Vec<_>>().join(","),
+        other => other.to_string(),
+    }
+}
+
+/// Parameter name of a `{name}`, `{name:hint}` or `{*name}` placeholder.
+fn placeholder_name(placeholder: &str) -> &str {
+    let name = placeholder.split(':').next().unwrap_or(placeholder);
+    name.trim_start_matches('*')
+}
+
+fn path_placeholders(path: &str) -> Vec<String> {
+    path.split('{')
+        .skip(1)
+        .filter_map(|rest| rest.split_once('}'))
+        .map(|(placeholder, _)| placeholder_name(placeholder).to_string())
+        .collect()
+}
+
+fn escape_pointer(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+fn unescape_pointer(token: &str) -> String {
+    token.replace("~1", "/").replace("~0", "~")
+}
+
+/// Whether `prefix` is `pointer` or one of its ancestors.
+fn is_prefix(prefix: &str, pointer: &str) -> bool {
+    pointer == prefix || pointer.starts_with(&format!("{prefix}/"))
+}
+
+fn with_value(body: &Value, pointer: &str, value: Value) -> Option<Value> {
+    let mut body = body.clone();
+    *body.pointer_mut(pointer)? = value;
+    Some(body)
+}
+
+fn without(body: &Value, pointer: &str) -> Option<Value> {
+    let (parent, token) = pointer.rsplit_once('/')?;
+    let token = unescape_pointer(token);
+    let mut body = body.clone();
+    match body.pointer_mut(parent)? {
+        Value::Object(map) => {
+            map.remove(&token)?;
+        }
+        Value::Array(items) => {
+            let index = token.parse::<usize>().ok().filter(|&index| index < items.len())?;
+            items.remove(index);
+        }
+        _ => return None,
+    }
+    Some(body)
+}
+
+/// Inline `#/...` references, replacing reference cycles with an empty schema.
+fn resolve_refs(value: &Value, spec: &Value, depth: usize) -> Value {
+    if depth > 32 {
+        return json!({});
+    }
+    match value {
+        Value::Object(map) => {
+            if let Some(target) = map
+                .get("$ref")
+                .and_then(Value::as_str)
+                .and_then(|reference| reference.strip_prefix('#'))
+            {
+                return spec
+                    .pointer(target)
+                    .map_or_else(|| json!({}), |target| resolve_refs(target, spec, depth + 1));
+            }
+            Value::Object(
+                map.iter()
+                    .map(|(key, value)| (key.clone(), resolve_refs(value, spec, depth)))
+                    .collect(),
+            )
+        }
+        Value::Array(items) => Value::Array(items.iter().map(|item| resolve_refs(item, spec, depth)).collect()),
+        other => other.clone(),
+    }
+}
+
+fn openapi_parameter_schema(parameters: &[Value]) -> Option<Value> {
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+    for parameter in parameters {
+        let (Some(name), Some(location)) = (
+            parameter.get("name").and_then(Value::as_str),
+            parameter.get("in").and_then(Value::as_str),
+        ) else {
+            continue;
+        };
+        let mut schema = parameter.get("schema").cloned().unwrap_or_else(|| json!({}));
+        if let Some(schema) = schema.as_object_mut() {
+            schema.insert("source".to_string(), json!(location));
+        }
+        properties.insert(name.to_string(), schema);
+        if location == "path" || parameter.get("required").and_then(Value::as_bool) == Some(true) {
+            required.push(json!(name));
+        }
+    }
+    (!properties.is_empty()).then(|| json!({"type": "object", "properties": properties, "required": required}))
+}
+
+fn openapi_response_schema(operation: &Value) -> Option<Value> {
+    let responses = operation.get("responses").and_then(Value::as_object)?;
+    let mut statuses: Vec<&String> = responses.keys().filter(|status| status.starts_with('2')).collect();
+    statuses.sort();
+    statuses
+        .into_iter()
+        .find_map(|status| responses[status].pointer("/content/application~1json/schema"))
+        .cloned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn route(request_schema: Value) -> ContractRoute {
+        ContractRoute {
+            method: "POST".to_string(),
+            path: "/items/{id:int}".to_string(),
+            parameter_schema: Some(json!({
+                "type": "object",
+                "properties": {
+                    "id": {"type": "integer", "source": "path", "minimum": 1},
+                    "limit": {"type": "integer", "source": "query", "minimum": 1, "maximum": 50},
+                    "x_tenant": {"type": "string", "source": "header"}
+                },
+                "required": ["id"]
+            })),
+            request_schema: Some(request_schema),
+            response_schema: None,
+        }
+    }
+
+    #[test]
+    fn cases_cover_boundaries_and_single_violations() {
+        let cases = generate_cases(&route(json!({
+            "type": "object",
+            "properties": {
+                "name": {"type": "string", "minLength": 2, "maxLength": 5},
+                "tags": {"type": "array", "items": {"type": "string"}, "maxItems": 2}
+            },
+            "required": ["name"],
+            "additionalProperties": false
+        })))
+        .expect("cases");
+
+        let described = |text: &str| {
+            cases
+                .iter()
+                .find(|case| case.description == text)
+                .unwrap_or_else(|| panic!("no case {text:?}"))
+        };
+        assert_eq!(
+            described("body `/name` at maxLength 5").body,
+            Some(json!({"name": "aaaaa", "tags": ["a"]}))
+        );
+        assert_eq!(
+            described("query parameter `limit` above maximum (51)").kind,
+            CaseKind::Invalid
+        );
+        assert_eq!(
+            described("body without required field `name`").body,
+            Some(json!({"tags": ["a"]}))
+        );
+        assert_eq!(described("required parameters and fields only").uri(), "/items/1");
+        assert_eq!(
+            described("every parameter and field with typical values").headers(),
+            vec![
+                ("x-tenant".to_string(), "a".to_string()),
+                ("content-type".to_string(), "application/json".to_string()),
+            ]
+        );
+        assert!(
+            cases
+                .iter()
+                .any(|case| case.description == "body with an undeclared field")
+        );
+        assert!(!cases.iter().any(|case| {
+            case.description
+                .starts_with("header parameter `x_tenant` of the wrong type")
+        }));
+    }
+
+    #[test]
+    fn cases_that_miss_their_expectation_are_dropped() {
+        let cases = generate_cases(&route(json!({
+            "type": "object",
+            "properties": {"code": {"type": "string", "pattern": "^[0-9]{3}$"}},
+            "required": ["code"]
+        })))
+        .expect("cases");
+        assert!(cases.iter().all(|case| case.kind == CaseKind::Invalid));
+    }
+
+    #[test]
+    fn simplifications_keep_the_field_under_test() {
+        let case = ContractCase {
+            kind: CaseKind::Invalid,
+            description: "body `/a/b` of the wrong type".to_string(),
+            method: "POST".to_string(),
+            path: "/".to_string(),
+            params: Vec::new(),
+            body: Some(json!({"a": {"b": 1, "c": "xy"}, "d": [1, 2]})),
+            field: Some(Field::Body("/a/b".to_string())),
+        };
+        let bodies: Vec<Value> = simplifications(&case)
+            .into_iter()
+            .filter_map(|candidate| candidate.body)
+            .collect();
+        assert!(bodies.contains(&json!({"a": {"b": 1, "c": "xy"}})));
+        assert!(bodies.contains(&json!({"a": {"b": 1}, "d": [1, 2]})));
+        assert!(bodies.contains(&json!({"a": {"b": 1, "c": "x"}, "d": [1, 2]})));
+        assert!(bodies.iter().all(|body| body.pointer("/a/b") == Some(&json!(1))));
+    }
+
+    #[test]
+    fn openapi_operations_become_routes() {
+        let spec = json!({
+            "openapi": "3.1.0",
+            "paths": {
+                "/users/{id}": {
+                    "parameters": [{"name": "id", "in": "path", "required": true, "schema": {"type": "integer"}}],
+                    "put": {
+                        "parameters": [{"name": "dry_run", "in": "query", "schema": {"type": "boolean"}}],
+                        "requestBody": {"content": {"application/json": {"schema": {"$ref": "#/components/schemas/User"}}}},
+                        "responses": {
+                            "404": {"description": "missing"},
+                            "200": {"content": {"application/json": {"schema": {"$ref": "#/components/schemas/User"}}}}
+                        }
+                    }
+                }
+            },
+            "components": {"schemas": {"User": {"type": "object", "properties": {"name": {"type": "string"}}}}}
+        });
+
+        let routes = ContractRoute::from_openapi(&spec);
+        assert_eq!(routes.len(), 1);
+        let route = &routes[0];
+        assert_eq!(route.label(), "PUT /users/{id}");
+        let user = json!({"type": "object", "properties": {"name": {"type": "string"}}});
+        assert_eq!(route.request_schema.as_ref(), Some(&user));
+        assert_eq!(route.response_schema.as_ref(), Some(&user));
+        assert_eq!(
+            route.parameter_schema,
+            Some(json!({
+                "type": "object",
+                "properties": {
+                    "id": {"type": "integer", "source": "path"},
+                    "dry_run": {"type": "boolean", "source": "query"}
+                },
+                "required": ["id"]
+            }))
+        );
+    }
+}
@@ -0,0 +1,329 @@
+//! gRPC call results for the in-process test client
+//!
+//! [`TestClient`](super::TestClient) frames request messages, sends them through the
+//! router like an HTTP/2 gRPC client would, and collects the reply into a
+//! [`GrpcResponse`] with the messages unframed and the final status resolved from
+//! trailers, headers or the HTTP status of calls rejected before reaching a handler.
+
+use super::SnapshotError;
+use axum::body::Body;
+use bytes::{Bytes, BytesMut};
+use futures_util::TryStreamExt;
+use http_body_util::BodyExt;
+use std::collections::HashMap;
+
+/// Headers that describe the gRPC exchange itself rather than user metadata.
+const RESERVED_HEADERS: [&str; 4] = ["content-type", "content-length", "grpc-status", "grpc-message"];
+
+/// Result of a gRPC call made with [`TestClient`](super::TestClient).
+#[derive(Debug, Clone)]
+pub struct GrpcResponse {
+    /// Final gRPC status code.
+    pub code: tonic::Code,
+    /// Decoded `grpc-message` sent with the status, or the error body of a call rejected by the router.
+    pub status_message: String,
+    /// Response metadata sent in the headers.
+    pub metadata: HashMap<String, String>,
+    /// Trailers sent after the last message.
+    pub trailers: HashMap<String, String>,
+    /// Response messages in order, without gRPC framing.
+    pub messages: Vec<Bytes>,
+}
+
+impl GrpcResponse {
+    /// Collect a routed gRPC response.
+    pub async fn from_response(response: axum::response::Response<Body>) -> Result<Self, SnapshotError> {
+        let (parts, body) = response.into_parts();
+        let headers = header_map(&parts.headers)?;
+        let collected = body
+            .collect()
+            .await
+            .map_err(|e| SnapshotError::Decompression(e.to_string()))?;
+        let trailers = collected.trailers().map(header_map).transpose()?.unwrap_or_default();
+        let bytes = collected.to_bytes();
+
+        if !parts.status.is_success() {
+            return Ok(Self {
+                code: crate::server::grpc_routing::http_status_to_grpc(parts.status),
+                status_message: String::from_utf8_lossy(&bytes).into_owned(),
+                metadata: HashMap::new(),
+                trailers,
+                messages: Vec::new(),
+            });
+        }
+
+        let messages = crate::grpc::framing::parse_grpc_client_stream(
+            Body::from(bytes),
+            usize::MAX,
+            headers.get("grpc-encoding").map(String::as_str),
+            true,
+        )
+        .await
+        .map_err(|status| SnapshotError::Decompression(status.message().to_string()))?
+        .try_collect::<Vec<_>>()
+        .await
+        .map_err(|status| SnapshotError::Decompression(status.message().to_string()))?;
+
+        let status = trailers.get("grpc-status").or_else(|| headers.get("grpc-status"));
+        let (code, status_message) = match status {
+            Some(status) => {
+                let code = status
+                    .parse::<i32>()
+                    .map_or(tonic::Code::Unknown, tonic::Code::from_i32);
+                let message = trailers
+                    .get("grpc-message")
+                    .or_else(|| headers.get("grpc-message"))
+                    .map(|message| urlencoding::decode(message).map_or_else(|_| message.clone(), |m| m.into_owned()))
+                    .unwrap_or_default();
+                (code, message)
+            }
+            None => (tonic::Code::Unknown, "Response carried no grpc-status".to_string()),
+        };
+
+        let metadata = headers
+            .into_iter()
+            .filter(|(name, _)| !RESERVED_HEADERS.contains(&name.as_str()))
+            .collect();
+
+        Ok(Self {
+            code,
+            status_message,
+            metadata,
+            trailers,
+            messages,
+        })
+    }
+
+    /// Whether the call completed with status `OK`.
+    pub fn is_ok(&self) -> bool {
+        self.code == tonic::Code::Ok
+    }
+
+    /// The first response message, the only one for unary and client-streaming calls.
+    pub fn message(&self) -> Option<&Bytes> {
+        self.messages.first()
+    }
+
+    /// Decode the first response message as protobuf.
+    pub fn decode<M: prost::Message + Default>(&self) -> Result<M, SnapshotError> {
+        let message = self
+            .message()
+            .ok_or_else(|| SnapshotError::Decompression("gRPC response has no messages".to_string()))?;
+        M::decode(message.clone()).map_err(|e| SnapshotError::Decompression(e.to_string()))
+    }
+
+    /// Decode every response message as protobuf.
+    pub fn decode_all<M: prost::Message + Default>(&self) -> Result<Vec<M>, SnapshotError> {
+        self.messages
+            .iter()
+            .map(|message| M::decode(message.clone()).map_err(|e| SnapshotError::Decompression(e.to_string())))
+            .collect()
+    }
+}
+
+/// Frame `messages` into a gRPC request body.
+pub(crate) fn encode_grpc_messages(messages: Vec<Bytes>) -> Result<Bytes, SnapshotError> {
+    let mut body = BytesMut::new();
+    for message in messages {
+        let framed = crate::grpc::framing::encode_grpc_message(message)
+            .map_err(|status| SnapshotError::Decompression(status.message().to_string()))?;
+        body.extend_from_slice(&framed);
+    }
+    Ok(body.freeze())
+}
+
+fn header_map(headers: &axum::http::HeaderMap) -> Result<HashMap<String, String>, SnapshotError> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let value = value
+                .to_str()
+                .map_err(|e| SnapshotError::InvalidHeader(e.to_string()))?;
+            Ok((name.as_str().to_ascii_lowercase(), value.to_string()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grpc::streaming::{MessageStream, StreamingRequest};
+    use crate::grpc::{
+        GrpcConfig, GrpcHandler, GrpcHandlerResult, GrpcRegistry, GrpcRequestData, GrpcResponseData, RpcMode,
+    };
+    use crate::testing::TestClient;
+    use futures_util::StreamExt;
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::sync::Arc;
+    use tonic::Status;
+
+    /// Text service: `Shout` upper-cases, `Repeat` streams copies, `Join` and `Reverse` take streams.
+    struct TextService;
+
+    impl GrpcHandler for TextService {
+        fn call(&self, request: GrpcRequestData) -> Pin<Box<dyn Future<Output = GrpcHandlerResult> + Send + '_>> {
+            Box::pin(async move {
+                if request.payload.as_ref() == b"missing" {
+                    return Err(Status::not_found("no such text: missing"));
+                }
+                let mut metadata = tonic::metadata::MetadataMap::new();
+                if let Some(tenant) = request.metadata.get("x-tenant") {
+                    metadata.insert("x-served-tenant", tenant.clone());
+                }
+                Ok(GrpcResponseData {
+                    payload: Bytes::from(String::from_utf8_lossy(&request.payload).to_uppercase()),
+                    metadata,
+                })
+            })
+        }
+
+        fn service_name(&self) -> &str {
+            "test.Text"
+        }
+
+        fn call_server_stream(
+            &self,
+            request: GrpcRequestData,
+        ) -> Pin<Box<dyn Future<Output = Result<MessageStream, Status>> + Send + '_>> {
+            Box::pin(async move {
+                let payload = request.payload;
+                let mut replies = vec![Ok(payload.clone()), Ok(payload.clone())];
+                if payload.as_ref() == b"boom" {
+                    replies.push(Err(Status::aborted("stream aborted")));
+                }
+                let stream: MessageStream = Box::pin(futures_util::stream::iter(replies));
+                Ok(stream)
+            })
+        }
+
+        fn call_client_stream(
+            &self,
+            request: StreamingRequest,
+        ) -> Pin<Box<dyn Future<Output = GrpcHandlerResult> + Send + '_>> {
+            Box::pin(async move {
+                let parts: Vec<Bytes> = request.message_stream.try_collect().await?;
+                let joined = parts
+                    .iter()
+                    .map(|part| String::from_utf8_lossy(part).into_owned())
+                    .collect::<Vec<_>>()
+                    .join(",");
+                Ok(GrpcResponseData {
+                    payload: Bytes::from(joined),
+                    metadata: tonic::metadata::MetadataMap::new(),
+                })
+            })
+        }
+
+        fn call_bidi_stream(
+            &self,
+            request: StreamingRequest,
+        ) -> Pin<Box<dyn Future<Output = Result<MessageStream, Status>> + Send + '_>> {
+            Box::pin(async move {
+                let reversed = request.message_stream.map(|message| {
+                    message.map(|message| Bytes::from(message.iter().rev().copied().collect::<Vec<_>>()))
+                });
+                let stream: MessageStream = Box::pin(reversed);
+                Ok(stream)
+            })
+        }
+    }
+
+    fn client() -> TestClient {
+        let mut registry = GrpcRegistry::new();
+        let service: Arc<dyn GrpcHandler> = Arc::new(TextService);
+        registry.register("test.Text", "Shout", Arc::clone(&service), RpcMode::Unary);
+        registry.register("test.Text", "Repeat", Arc::clone(&service), RpcMode::ServerStreaming);
+        registry.register("test.Text", "Join", Arc::clone(&service), RpcMode::ClientStreaming);
+        registry.register("test.Text", "Reverse", service, RpcMode::BidirectionalStreaming);
+        let config = crate::ServerConfig {
+            grpc: Some(GrpcConfig::default()),
+            ..Default::default()
+        };
+        let router = crate::server::build_router_with_handlers_and_config_and_grpc(
+            Vec::new(),
+            config,
+            Vec::new(),
+            Some(Arc::new(registry)),
+        )
+        .expect("router");
+        TestClient::from_router(router).expect("client")
+    }
+
+    fn tenant(name: &str) -> Option<Vec<(String, String)>> {
+        Some(vec![("x-tenant".to_string(), name.to_string())])
+    }
+
+    #[tokio::test]
+    async fn unary_call_returns_message_and_metadata() {
+        let response = client()
+            .grpc_unary("test.Text", "Shout", Bytes::from_static(b"hi"), tenant("acme"))
+            .await
+            .expect("call");
+        assert!(response.is_ok());
+        assert_eq!(response.message().expect("message").as_ref(), b"HI");
+        assert_eq!(response.metadata["x-served-tenant"], "acme");
+        assert!(!response.metadata.contains_key("grpc-status"));
+    }
+
+    #[tokio::test]
+    async fn unary_errors_and_unknown_methods_map_to_grpc_codes() {
+        let client = client();
+        let missing = client
+            .grpc_unary("test.Text", "Shout", Bytes::from_static(b"missing"), None)
+            .await
+            .expect("call");
+        assert_eq!(missing.code, tonic::Code::NotFound);
+        assert!(missing.status_message.contains("no such text"));
+        assert!(missing.messages.is_empty());
+
+        let unknown = client
+            .grpc_unary("test.Text", "Whisper", Bytes::from_static(b"hi"), None)
+            .await
+            .expect("call");
+        assert_eq!(unknown.code, tonic::Code::NotFound);
+    }
+
+    #[tokio::test]
+    async fn server_stream_collects_messages_and_trailers() {
+        let client = client();
+        let response = client
+            .grpc_server_stream("test.Text", "Repeat", Bytes::from_static(b"ok"), None)
+            .await
+            .expect("call");
+        assert!(response.is_ok());
+        assert_eq!(response.messages, vec![Bytes::from_static(b"ok"); 2]);
+        assert_eq!(response.trailers["grpc-status"], "0");
+
+        let aborted = client
+            .grpc_server_stream("test.Text", "Repeat", Bytes::from_static(b"boom"), None)
+            .await
+            .expect("call");
+        assert_eq!(aborted.code, tonic::Code::Aborted);
+        assert_eq!(aborted.status_message, "stream aborted");
+        assert_eq!(aborted.messages.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn client_and_bidi_streams_send_every_message() {
+        let client = client();
+        let messages = vec![Bytes::from_static(b"ab"), Bytes::from_static(b"cd")];
+
+        let joined = client
+            .grpc_client_stream("test.Text", "Join", messages.clone(), None)
+            .await
+            .expect("call");
+        assert!(joined.is_ok());
+        assert_eq!(joined.message().expect("message").as_ref(), b"ab,cd");
+
+        let reversed = client
+            .grpc_bidi_stream("test.Text", "Reverse", messages, None)
+            .await
+            .expect("call");
+        assert!(reversed.is_ok());
+        assert_eq!(
+            reversed.messages,
+            vec![Bytes::from_static(b"ba"), Bytes::from_static(b"dc")]
+        );
+    }
+}
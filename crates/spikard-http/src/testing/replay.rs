@@ -0,0 +1,216 @@
+//! Replay recorded fixtures through `TestClient` as regression tests
+//!
+//! Fixtures written by the recording middleware (or by hand, in the same format) are sent
+//! back through a [`TestClient`] and the response is compared with `expected_response`.
+//! Values that were redacted while recording match anything; redacted request headers,
+//! cookies and query parameters are omitted unless the caller supplies real headers.
+
+use super::{ResponseSnapshot, SnapshotError, TestClient, encode_urlencoded_body};
+use crate::recording::REDACTED;
+use axum::body::Bytes;
+use axum::http::Method;
+use serde_json::Value;
+use spikard_fixtures::Fixture;
+use std::path::Path;
+
+/// Result of replaying one fixture
+#[derive(Debug, Clone)]
+pub struct ReplayOutcome {
+    /// Fixture name
+    pub fixture: String,
+    /// Status code of the replayed response
+    pub status: u16,
+    /// Differences between the expected and the replayed response
+    pub mismatches: Vec<String>,
+}
+
+impl ReplayOutcome {
+    /// Whether the replayed response matched the fixture
+    pub fn is_success(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+}
+
+/// Load every fixture in `dir`, ordered by file name
+///
+/// Files that are not JSON objects in the fixture format are reported as errors rather
+/// than skipped, so a broken recording fails the suite.
+pub fn load_recorded_fixtures(dir: &Path) -> Result<Vec<Fixture>, SnapshotError> {
+    let read_error = |e: std::io::Error| SnapshotError::Decompression(format!("{}: {}", dir.display(), e));
+    let mut paths = std::fs::read_dir(dir)
+        .map_err(read_error)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(read_error)?;
+    paths.retain(|path| path.extension().is_some_and(|extension| extension == "json"));
+    paths.sort();
+
+    paths
+        .iter()
+        .map(|path| {
+            let content = std::fs::read_to_string(path)
+                .map_err(|e| SnapshotError::Decompression(format!("{}: {}", path.display(), e)))?;
+            serde_json::from_str(&content)
+                .map_err(|e| SnapshotError::Decompression(format!("Invalid fixture {}: {}", path.display(), e)))
+        })
+        .collect()
+}
+
+/// Send a fixture's request through `client` and compare the response with the recording
+///
+/// `headers` supplies values for redacted request headers (and a `cookie` header for
+/// redacted cookies); they replace recorded headers of the same name.
+pub async fn replay_fixture(
+    client: &TestClient,
+    fixture: &Fixture,
+    headers: &[(String, String)],
+) -> Result<ReplayOutcome, SnapshotError> {
+    let request = &fixture.request;
+    let method = Method::from_bytes(request.method.as_bytes())
+        .map_err(|e| SnapshotError::Decompression(format!("Invalid method {}: {}", request.method, e)))?;
+
+    let overridden = |name: &str| headers.iter().any(|(header, _)| header.eq_ignore_ascii_case(name));
+    let mut request_headers: Vec<(String, String)> = request
+        .headers
+        .iter()
+        .flatten()
+        .filter(|(name, value)| value.as_str() != REDACTED && !overridden(name))
+        .map(|(name, value)| (name.clone(), value.clone()))
+        .collect();
+    request_headers.extend(headers.iter().cloned());
+
+    let cookies: Vec<String> = request
+        .cookies
+        .iter()
+        .flatten()
+        .filter(|(_, value)| value.as_str() != REDACTED)
+        .map(|(name, value)| format!("{name}={value}"))
+        .collect();
+    if !cookies.is_empty() && !overridden("cookie") {
+        request_headers.push(("cookie".to_string(), cookies.join("; ")));
+    }
+
+    let (body, default_content_type) = match (&request.form_data, &request.body) {
+        (Some(form), _) => {
+            let form = Value::Object(form.clone().into_iter().collect());
+            let encoded = encode_urlencoded_body(&form).map_err(SnapshotError::Decompression)?;
+            (Bytes::from(encoded), Some("application/x-www-form-urlencoded"))
+        }
+        (None, Some(Value::String(text))) => (Bytes::from(text.clone()), None),
+        (None, Some(value)) => (Bytes::from(value.to_string()), Some("application/json")),
+        (None, None) => (Bytes::new(), None),
+    };
+    if !overridden("content-type")
+        && let Some(content_type) = request.content_type.as_deref().or(default_content_type)
+    {
+        request_headers.push(("content-type".to_string(), content_type.to_string()));
+    }
+
+    let query: Vec<(String, String)> = request
+        .query_params
+        .iter()
+        .flatten()
+        .flat_map(|(name, value)| {
+            let values = match value {
+                Value::Array(values) => values
+                    .iter()
+                    .filter(|value| value.as_str() != Some(REDACTED))
+                    .map(query_value)
+                    .collect(),
+                Value::String(text) if text == REDACTED => Vec::new(),
+                value => vec![query_value(value)],
+            };
+            values.into_iter().map(move |value| (name.clone(), value))
+        })
+        .collect();
+
+    let response = client
+        .request_raw(method, &request.path, body, Some(query), Some(request_headers))
+        .await?;
+    Ok(ReplayOutcome {
+        fixture: fixture.name.clone(),
+        status: response.status,
+        mismatches: compare(fixture, &response),
+    })
+}
+
+fn query_value(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        value => value.to_string(),
+    }
+}
+
+fn compare(fixture: &Fixture, response: &ResponseSnapshot) -> Vec<String> {
+    let expected = &fixture.expected_response;
+    let mut mismatches = Vec::new();
+
+    if response.status != expected.status_code {
+        mismatches.push(format!(
+            "status: expected {}, got {}",
+            expected.status_code, response.status
+        ));
+    }
+
+    for (name, value) in expected.headers.iter().flatten() {
+        match response.header(name) {
+            None => mismatches.push(format!("header {name}: missing")),
+            Some(_) if value == REDACTED => {}
+            Some(actual) if actual != value => {
+                mismatches.push(format!("header {name}: expected {value:?}, got {actual:?}"));
+            }
+            Some(_) => {}
+        }
+    }
+
+    match &expected.body {
+        None if !response.body.is_empty() => mismatches.push("body: expected empty".to_string()),
+        None => {}
+        Some(Value::String(text)) if text == REDACTED || response.text().is_ok_and(|actual| actual == *text) => {}
+        Some(body) => match response.json() {
+            Ok(actual) => compare_values(body, &actual, "", &mut mismatches),
+            Err(_) => mismatches.push(format!(
+                "body: expected {body}, got {:?}",
+                response.text().unwrap_or_default()
+            )),
+        },
+    }
+    mismatches
+}
+
+/// Compare JSON values structurally, reporting differences by JSON pointer.
+fn compare_values(expected: &Value, actual: &Value, pointer: &str, mismatches: &mut Vec<String>) {
+    let location = if pointer.is_empty() { "/" } else { pointer };
+    match (expected, actual) {
+        (Value::String(placeholder), _) if placeholder == REDACTED => {}
+        (Value::Object(expected), Value::Object(actual)) => {
+            for (key, value) in expected {
+                let child = format!("{pointer}/{}", key.replace('~', "~0").replace('/', "~1"));
+                match actual.get(key) {
+                    Some(actual) => compare_values(value, actual, &child, mismatches),
+                    None => mismatches.push(format!("body {child}: missing")),
+                }
+            }
+            for key in actual.keys().filter(|key| !expected.contains_key(*key)) {
+                mismatches.push(format!(
+                    "body {pointer}/{}: unexpected field",
+                    key.replace('~', "~0").replace('/', "~1")
+                ));
+            }
+        }
+        (Value::Array(expected), Value::Array(actual)) if expected.len() == actual.len() => {
+            for (index, (expected, actual)) in expected.iter().zip(actual).enumerate() {
+                compare_values(expected, actual, &format!("{pointer}/{index}"), mismatches);
+            }
+        }
+        (Value::Array(expected), Value::Array(actual)) => mismatches.push(format!(
+            "body {location}: expected {} items, got {}",
+            expected.len(),
+            actual.len()
+        )),
+        (expected, actual) if expected != actual => {
+            mismatches.push(format!("body {location}: expected {expected}, got {actual}"));
+        }
+        _ => {}
+    }
+}
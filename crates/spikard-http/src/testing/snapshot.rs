@@ -0,0 +1,414 @@
+//! Snapshot assertions for test responses
+//!
+//! A [`ResponseSnapshot`] is serialized into a stable JSON document holding the status,
+//! the selected headers and the body, with object keys sorted:
+//!
+//! ```json
+//! {
+//!   "body": {"id": "[UUID]", "name": "Ada"},
+//!   "headers": {"content-type": "application/json"},
+//!   "status": 201
+//! }
+//! ```
+//!
+//! JSON bodies are embedded as JSON, other UTF-8 bodies as a string and binary bodies as
+//! `body_base64`. Volatile values are replaced before comparison: JSON pointers into the
+//! document (`/body/created_at`, `/headers/x-request-id`, with `*` matching any key or index)
+//! become `[REDACTED]`, and with `redact_uuids` / `redact_timestamps` every string that is a
+//! UUID or an RFC 3339 timestamp becomes `[UUID]` / `[TIMESTAMP]`. The format is the same for
+//! every binding, so snapshot files can be shared by the Python, Node and Ruby test clients.
+//!
+//! Snapshot files are `<name>.snap.json` in a `snapshots` directory next to the test file.
+//! `SPIKARD_UPDATE_SNAPSHOTS` selects the [`SnapshotUpdate`] mode. The bindings expose
+//! [`check_snapshot`], [`snapshot_path`], [`assert_snapshot_file`] and [`SnapshotOutcome`]
+//! to their test clients, so they read and write the same files.
+
+use super::{ResponseSnapshot, SnapshotError};
+use crate::recording::redact_json_pointer;
+use base64::Engine;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+/// Placeholder for strings that are UUIDs
+pub const UUID_PLACEHOLDER: &str = "[UUID]";
+/// Placeholder for strings that are RFC 3339 timestamps
+pub const TIMESTAMP_PLACEHOLDER: &str = "[TIMESTAMP]";
+
+static UUID: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}$").expect("valid regex")
+});
+static TIMESTAMP: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^\d{4}-\d{2}-\d{2}[Tt ]\d{2}:\d{2}:\d{2}(\.\d+)?([Zz]|[+-]\d{2}:\d{2})?$").expect("valid regex")
+});
+
+/// What is kept in a snapshot and which values are redacted
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SnapshotOptions {
+    /// Response headers included in the snapshot (case-insensitive)
+    pub headers: Vec<String>,
+    /// JSON pointers into the snapshot document to redact; a `*` segment matches any key or index
+    pub redact: Vec<String>,
+    /// Replace every string that is a UUID with `[UUID]`
+    pub redact_uuids: bool,
+    /// Replace every string that is an RFC 3339 timestamp with `[TIMESTAMP]`
+    pub redact_timestamps: bool,
+}
+
+impl Default for SnapshotOptions {
+    fn default() -> Self {
+        Self {
+            headers: vec!["content-type".to_string()],
+            redact: Vec::new(),
+            redact_uuids: false,
+            redact_timestamps: false,
+        }
+    }
+}
+
+/// When snapshot files are written
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SnapshotUpdate {
+    /// Write missing snapshots, compare existing ones
+    Missing,
+    /// Overwrite snapshots that differ
+    Always,
+    /// Never write; a missing snapshot is a failure
+    Never,
+}
+
+impl SnapshotUpdate {
+    /// Mode selected by `SPIKARD_UPDATE_SNAPSHOTS`
+    ///
+    /// `1`, `true` or `always` overwrite, `0`, `false` or `never` only compare. When unset,
+    /// missing snapshots are written unless the `CI` variable is set.
+    pub fn from_env() -> Self {
+        match std::env::var("SPIKARD_UPDATE_SNAPSHOTS").ok().as_deref() {
+            Some("1" | "true" | "always") => Self::Always,
+            Some("0" | "false" | "never") => Self::Never,
+            Some("missing") => Self::Missing,
+            _ if std::env::var_os("CI").is_some() => Self::Never,
+            _ => Self::Missing,
+        }
+    }
+}
+
+/// Result of comparing a response with its snapshot file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum SnapshotOutcome {
+    /// The file matched the response
+    Matched,
+    /// The file did not exist and was written
+    Created,
+    /// The file differed and was overwritten
+    Updated,
+    /// The file differs from the response, or is missing and may not be written
+    Mismatched {
+        /// Snapshot file contents, `None` when the file does not exist
+        expected: Option<String>,
+        /// Serialized response
+        actual: String,
+    },
+}
+
+impl ResponseSnapshot {
+    /// Serialize status, selected headers and body into the shared snapshot format
+    pub fn to_snapshot_json(&self, options: &SnapshotOptions) -> Result<String, SnapshotError> {
+        let headers: Map<String, Value> = options
+            .headers
+            .iter()
+            .filter_map(|name| {
+                self.header(name)
+                    .map(|value| (name.to_ascii_lowercase(), Value::String(value.to_string())))
+            })
+            .collect();
+
+        let mut document = Map::new();
+        document.insert("status".to_string(), Value::from(self.status));
+        document.insert("headers".to_string(), Value::Object(headers));
+        let body = if self.body.is_empty() {
+            Value::Null
+        } else if let Ok(json) = self.json() {
+            json
+        } else if let Ok(text) = self.text() {
+            Value::String(text)
+        } else {
+            document.insert(
+                "body_base64".to_string(),
+                Value::String(base64::engine::general_purpose::STANDARD.encode(&self.body)),
+            );
+            Value::Null
+        };
+        document.insert("body".to_string(), body);
+
+        let mut document = Value::Object(document);
+        for pointer in &options.redact {
+            redact_json_pointer(&mut document, pointer);
+        }
+        if options.redact_uuids || options.redact_timestamps {
+            redact_patterns(&mut document, options);
+        }
+
+        let mut json = serde_json::to_string_pretty(&sorted(document))
+            .map_err(|e| SnapshotError::Decompression(format!("Failed to serialize snapshot: {}", e)))?;
+        json.push('\n');
+        Ok(json)
+    }
+}
+
+/// Compare `response` with the snapshot file at `path`, writing it as `update` allows
+pub fn check_snapshot(
+    response: &ResponseSnapshot,
+    path: &Path,
+    options: &SnapshotOptions,
+    update: SnapshotUpdate,
+) -> Result<SnapshotOutcome, SnapshotError> {
+    let actual = response.to_snapshot_json(options)?;
+    let io_error = |e: std::io::Error| SnapshotError::Decompression(format!("{}: {}", path.display(), e));
+
+    let expected = match std::fs::read_to_string(path) {
+        Ok(expected) => Some(expected),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+        Err(e) => return Err(io_error(e)),
+    };
+    if expected.as_deref().map(normalize_newlines) == Some(normalize_newlines(&actual)) {
+        return Ok(SnapshotOutcome::Matched);
+    }
+
+    let outcome = match (&expected, update) {
+        (None, SnapshotUpdate::Missing | SnapshotUpdate::Always) => SnapshotOutcome::Created,
+        (Some(_), SnapshotUpdate::Always) => SnapshotOutcome::Updated,
+        _ => return Ok(SnapshotOutcome::Mismatched { expected, actual }),
+    };
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(io_error)?;
+    }
+    std::fs::write(path, &actual).map_err(io_error)?;
+    Ok(outcome)
+}
+
+/// Path of the snapshot `name` for the test source file `file`
+///
+/// `file` is the `file!()` of the test, which Cargo makes relative to the workspace root;
+/// it is resolved against `manifest_dir` and its ancestors.
+pub fn snapshot_path(manifest_dir: &str, file: &str, name: &str) -> PathBuf {
+    let source = Path::new(manifest_dir)
+        .ancestors()
+        .map(|root| root.join(file))
+        .find(|candidate| candidate.exists())
+        .unwrap_or_else(|| Path::new(manifest_dir).join(file));
+    source
+        .parent()
+        .unwrap_or_else(|| Path::new(manifest_dir))
+        .join("snapshots")
+        .join(format!("{name}.snap.json"))
+}
+
+/// Assert a response against its snapshot file in the `SPIKARD_UPDATE_SNAPSHOTS` mode
+///
+/// Returns whether the snapshot matched or was written. A mismatch, or a snapshot that
+/// cannot be checked, is an error message holding both versions as a line diff:
+/// [`assert_response_snapshot!`](crate::assert_response_snapshot) panics with it and the
+/// binding test clients raise it.
+pub fn assert_snapshot_file(
+    response: &ResponseSnapshot,
+    path: &Path,
+    options: &SnapshotOptions,
+) -> Result<SnapshotOutcome, String> {
+    match check_snapshot(response, path, options, SnapshotUpdate::from_env()) {
+        Ok(SnapshotOutcome::Matched) => Ok(SnapshotOutcome::Matched),
+        Ok(SnapshotOutcome::Created) => {
+            tracing::info!("Created snapshot {}", path.display());
+            Ok(SnapshotOutcome::Created)
+        }
+        Ok(SnapshotOutcome::Updated) => {
+            tracing::info!("Updated snapshot {}", path.display());
+            Ok(SnapshotOutcome::Updated)
+        }
+        Ok(SnapshotOutcome::Mismatched { expected: None, actual }) => Err(format!(
+            "Snapshot {} does not exist; run with SPIKARD_UPDATE_SNAPSHOTS=1 to create it.\n\n{actual}",
+            path.display()
+        )),
+        Ok(SnapshotOutcome::Mismatched {
+            expected: Some(expected),
+            actual,
+        }) => Err(format!(
+            "Response does not match snapshot {}; run with SPIKARD_UPDATE_SNAPSHOTS=1 to accept it.\n\n{}",
+            path.display(),
+            line_diff(&expected, &actual)
+        )),
+        Err(err) => Err(format!("Snapshot {} could not be checked: {err}", path.display())),
+    }
+}
+
+/// Assert that a [`ResponseSnapshot`] matches `snapshots/<name>.snap.json` next to the test
+///
+/// Evaluates to the [`SnapshotOutcome`], which is never `Mismatched`: mismatches panic.
+///
+/// ```rust,ignore
+/// let response = client.get("/users/1", None, None).await?;
+/// assert_response_snapshot!("get_user", response);
+/// assert_response_snapshot!("get_user_redacted", response, SnapshotOptions {
+///     redact: vec!["/body/created_at".into()],
+///     redact_uuids: true,
+///     ..SnapshotOptions::default()
+/// });
+/// ```
+#[macro_export]
+macro_rules! assert_response_snapshot {
+    ($name:expr, $response:expr $(,)?) => {
+        $crate::assert_response_snapshot!($name, $response, $crate::testing::SnapshotOptions::default())
+    };
+    ($name:expr, $response:expr, $options:expr $(,)?) => {
+        $crate::testing::snapshot::assert_snapshot_file(
+            &$response,
+            &$crate::testing::snapshot::snapshot_path(env!("CARGO_MANIFEST_DIR"), file!(), $name),
+            &$options,
+        )
+        .unwrap_or_else(|message| panic!("{message}"))
+    };
+}
+
+fn redact_patterns(value: &mut Value, options: &SnapshotOptions) {
+    match value {
+        Value::String(text) if options.redact_uuids && UUID.is_match(text) => {
+            *text = UUID_PLACEHOLDER.to_string();
+        }
+        Value::String(text) if options.redact_timestamps && TIMESTAMP.is_match(text) => {
+            *text = TIMESTAMP_PLACEHOLDER.to_string();
+        }
+        Value::Array(items) => items.iter_mut().for_each(|item| redact_patterns(item, options)),
+        Value::Object(fields) => fields.values_mut().for_each(|field| redact_patterns(field, options)),
+        _ => {}
+    }
+}
+
+/// Rebuild objects with sorted keys so output does not depend on map ordering.
+fn sorted(value: Value) -> Value {
+    match value {
+        Value::Object(fields) => {
+            let mut fields: Vec<(String, Value)> = fields.into_iter().collect();
+            fields.sort_by(|(a, _), (b, _)| a.cmp(b));
+            Value::Object(fields.into_iter().map(|(key, value)| (key, sorted(value))).collect())
+        }
+        Value::Array(items) => Value::Array(items.into_iter().map(sorted).collect()),
+        value => value,
+    }
+}
+
+fn normalize_newlines(text: &str) -> String {
+    text.replace("\r\n", "\n")
+}
+
+/// Line-by-line diff: `-` lines only in the snapshot, `+` lines only in the response.
+fn line_diff(expected: &str, actual: &str) -> String {
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+
+    let mut common = vec![vec![0usize; actual.len() + 1]; expected.len() + 1];
+    for i in (0..expected.len()).rev() {
+        for j in (0..actual.len()).rev() {
+            common[i][j] = if expected[i] == actual[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+
+    let mut diff = String::new();
+    let (mut i, mut j) = (0, 0);
+    while i < expected.len() || j < actual.len() {
+        if i < expected.len() && j < actual.len() && expected[i] == actual[j] {
+            diff.push_str(&format!("  {}\n", expected[i]));
+            i += 1;
+            j += 1;
+        } else if i < expected.len() && (j == actual.len() || common[i + 1][j] >= common[i][j + 1]) {
+            diff.push_str(&format!("- {}\n", expected[i]));
+            i += 1;
+        } else {
+            diff.push_str(&format!("+ {}\n", actual[j]));
+            j += 1;
+        }
+    }
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn response(body: &str) -> ResponseSnapshot {
+        ResponseSnapshot {
+            status: 200,
+            headers: HashMap::from([
+                ("content-type".to_string(), "application/json".to_string()),
+                (
+                    "x-request-id".to_string(),
+                    "7b0d5c1e-3f1a-4c55-9a7e-2b8f4d6e1a90".to_string(),
+                ),
+            ]),
+            body: body.as_bytes().to_vec(),
+        }
+    }
+
+    #[test]
+    fn serialization_is_stable_and_redacted() {
+        let options = SnapshotOptions {
+            headers: vec!["Content-Type".to_string(), "x-request-id".to_string()],
+            redact: vec!["/body/items/*/secret".to_string()],
+            redact_uuids: true,
+            redact_timestamps: true,
+        };
+        let json = response(
+            r#"{"z": 1, "a": {"created": "2026-10-19T08:30:00.123Z", "date": "2026-10-19"}, "items": [{"secret": "x"}]}"#,
+        )
+        .to_snapshot_json(&options)
+        .expect("snapshot");
+
+        assert_eq!(
+            json,
+            r#"{
+  "body": {
+    "a": {
+      "created": "[TIMESTAMP]",
+      "date": "2026-10-19"
+    },
+    "items": [
+      {
+        "secret": "[REDACTED]"
+      }
+    ],
+    "z": 1
+  },
+  "headers": {
+    "content-type": "application/json",
+    "x-request-id": "[UUID]"
+  },
+  "status": 200
+}
+"#
+        );
+    }
+
+    #[test]
+    fn binary_bodies_are_base64() {
+        let mut snapshot = response("");
+        snapshot.body = vec![0xff, 0x00];
+        let json: Value =
+            serde_json::from_str(&snapshot.to_snapshot_json(&SnapshotOptions::default()).unwrap()).unwrap();
+        assert_eq!(json["body"], Value::Null);
+        assert_eq!(json["body_base64"], "/wA=");
+    }
+
+    #[test]
+    fn diff_marks_changed_lines() {
+        assert_eq!(line_diff("a\nb\nc", "a\nx\nc"), "  a\n- b\n+ x\n  c\n");
+    }
+}
@@ -0,0 +1,394 @@
+//! Incremental readers for streaming test responses
+//!
+//! [`StreamingResponse`] hands out the body of a response chunk by chunk as the
+//! handler produces it, so tests can observe `HandlerResponse::stream` bodies and
+//! Server-Sent Events streams that never end. [`SseReader`] parses the same chunks
+//! into complete [`SseEvent`]s. Every read takes a timeout, and dropping the body
+//! with `disconnect` lets tests check how the server reacts to a client going away.
+
+use super::{SnapshotError, SseEvent};
+use axum::body::Body;
+use bytes::Bytes;
+use http_body_util::BodyExt;
+use std::collections::{HashMap, VecDeque};
+use std::time::Duration;
+use tokio::time::{Instant, timeout};
+
+/// Errors raised while reading a streaming response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StreamError {
+    /// Nothing arrived within the given duration.
+    Timeout(Duration),
+    /// The client disconnected before reading.
+    Disconnected,
+    /// The response body failed mid-stream.
+    Body(String),
+}
+
+impl std::fmt::Display for StreamError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StreamError::Timeout(wait) => write!(f, "Timed out after {:?} waiting for the stream", wait),
+            StreamError::Disconnected => write!(f, "Stream was disconnected by the client"),
+            StreamError::Body(msg) => write!(f, "Stream body failed: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for StreamError {}
+
+/// A response whose body is read incrementally.
+///
+/// Returned by [`TestClient::stream`](super::TestClient::stream). The status and
+/// headers are available immediately; the body is read with [`next_chunk`](Self::next_chunk).
+#[derive(Debug)]
+pub struct StreamingResponse {
+    status: u16,
+    headers: HashMap<String, String>,
+    body: Option<Body>,
+    disconnected: bool,
+}
+
+impl StreamingResponse {
+    /// Wrap an Axum response without reading its body.
+    pub fn from_response(response: axum::response::Response<Body>) -> Result<Self, SnapshotError> {
+        let (parts, body) = response.into_parts();
+
+        let mut headers = HashMap::new();
+        for (name, value) in parts.headers.iter() {
+            let header_value = value
+                .to_str()
+                .map_err(|e| SnapshotError::InvalidHeader(e.to_string()))?;
+            headers.insert(name.to_string().to_ascii_lowercase(), header_value.to_string());
+        }
+
+        Ok(Self {
+            status: parts.status.as_u16(),
+            headers,
+            body: Some(body),
+            disconnected: false,
+        })
+    }
+
+    /// HTTP status code of the response.
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    /// Response headers, keyed by lowercase name.
+    pub fn headers(&self) -> &HashMap<String, String> {
+        &self.headers
+    }
+
+    /// Look up a header by name (case-insensitive).
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(&name.to_ascii_lowercase()).map(String::as_str)
+    }
+
+    /// Wait up to `wait` for the next body chunk.
+    ///
+    /// Returns `Ok(None)` once the body has ended.
+    pub async fn next_chunk(&mut self, wait: Duration) -> Result<Option<Bytes>, StreamError> {
+        let Some(body) = self.body.as_mut() else {
+            return if self.disconnected {
+                Err(StreamError::Disconnected)
+            } else {
+                Ok(None)
+            };
+        };
+
+        let next = async {
+            while let Some(frame) = body.frame().await {
+                let frame = frame.map_err(|e| StreamError::Body(e.to_string()))?;
+                if let Ok(data) = frame.into_data()
+                    && !data.is_empty()
+                {
+                    return Ok(Some(data));
+                }
+            }
+            Ok(None)
+        };
+
+        match timeout(wait, next).await {
+            Err(_) => Err(StreamError::Timeout(wait)),
+            Ok(Ok(None)) => {
+                self.body = None;
+                Ok(None)
+            }
+            Ok(result) => result,
+        }
+    }
+
+    /// Read the rest of the body, failing if it does not end within `wait`.
+    pub async fn read_to_end(&mut self, wait: Duration) -> Result<Bytes, StreamError> {
+        let deadline = Instant::now() + wait;
+        let mut collected = Vec::new();
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            match self.next_chunk(remaining).await {
+                Ok(Some(chunk)) => collected.extend_from_slice(&chunk),
+                Ok(None) => return Ok(Bytes::from(collected)),
+                Err(StreamError::Timeout(_)) => return Err(StreamError::Timeout(wait)),
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Close the connection from the client side, dropping the response body.
+    pub fn disconnect(&mut self) {
+        self.body = None;
+        self.disconnected = true;
+    }
+
+    /// Whether the body has ended or the client has disconnected.
+    pub fn is_closed(&self) -> bool {
+        self.body.is_none()
+    }
+
+    /// Read the body as a Server-Sent Events stream.
+    pub fn into_sse(self) -> SseReader {
+        SseReader::new(self)
+    }
+}
+
+/// Reads Server-Sent Events from a streaming response as they arrive.
+///
+/// Returned by [`TestClient::sse`](super::TestClient::sse). Events carry every
+/// field sent by the server, and reads wait only for the next complete event, so
+/// streams that never end can be tested too.
+#[derive(Debug)]
+pub struct SseReader {
+    response: StreamingResponse,
+    parser: SseParser,
+    pending: VecDeque<SseEvent>,
+}
+
+impl SseReader {
+    /// Parse the body of `response` as Server-Sent Events.
+    pub fn new(response: StreamingResponse) -> Self {
+        Self {
+            response,
+            parser: SseParser::default(),
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// HTTP status code of the response.
+    pub fn status(&self) -> u16 {
+        self.response.status()
+    }
+
+    /// Response headers, keyed by lowercase name.
+    pub fn headers(&self) -> &HashMap<String, String> {
+        self.response.headers()
+    }
+
+    /// Look up a header by name (case-insensitive).
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.response.header(name)
+    }
+
+    /// Wait up to `wait` for the next complete event.
+    ///
+    /// Returns `Ok(None)` once the stream has ended.
+    pub async fn next_event(&mut self, wait: Duration) -> Result<Option<SseEvent>, StreamError> {
+        let deadline = Instant::now() + wait;
+        loop {
+            if let Some(event) = self.pending.pop_front() {
+                return Ok(Some(event));
+            }
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            match self.response.next_chunk(remaining).await {
+                Ok(Some(chunk)) => self.pending.extend(self.parser.feed(&chunk)),
+                Ok(None) => return Ok(self.parser.finish()),
+                Err(StreamError::Timeout(_)) => return Err(StreamError::Timeout(wait)),
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Wait up to `wait` for the next `count` events.
+    ///
+    /// Returns fewer events if the stream ends first.
+    pub async fn next_events(&mut self, count: usize, wait: Duration) -> Result<Vec<SseEvent>, StreamError> {
+        let deadline = Instant::now() + wait;
+        let mut events = Vec::with_capacity(count);
+        while events.len() < count {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            match self.next_event(remaining).await {
+                Ok(Some(event)) => events.push(event),
+                Ok(None) => break,
+                Err(StreamError::Timeout(_)) => return Err(StreamError::Timeout(wait)),
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(events)
+    }
+
+    /// The most recent event id sent by the server.
+    pub fn last_event_id(&self) -> Option<&str> {
+        self.parser.last_event_id.as_deref()
+    }
+
+    /// The most recent reconnection time sent by the server, in milliseconds.
+    pub fn reconnection_time(&self) -> Option<u64> {
+        self.parser.reconnection_time
+    }
+
+    /// Close the connection from the client side, dropping the response body.
+    pub fn disconnect(&mut self) {
+        self.pending.clear();
+        self.response.disconnect();
+    }
+}
+
+/// Incremental parser for the `text/event-stream` format.
+///
+/// Follows the WHATWG event stream interpretation: lines end in LF, CR or CRLF,
+/// lines starting with `:` are comments, and a blank line dispatches the event
+/// if it has any data.
+#[derive(Debug, Default)]
+pub(crate) struct SseParser {
+    buffer: Vec<u8>,
+    data: Option<String>,
+    event: Option<String>,
+    id: Option<String>,
+    retry: Option<u64>,
+    last_event_id: Option<String>,
+    reconnection_time: Option<u64>,
+}
+
+impl SseParser {
+    /// Feed a chunk of the stream, returning the events it completes.
+    pub(crate) fn feed(&mut self, chunk: &[u8]) -> Vec<SseEvent> {
+        self.buffer.extend_from_slice(chunk);
+
+        let mut events = Vec::new();
+        let mut start = 0;
+        let mut index = 0;
+        while index < self.buffer.len() {
+            let next = match self.buffer[index] {
+                b'\n' => index + 1,
+                // A CR at the end of the buffer may be the first half of a CRLF.
+                b'\r' if index + 1 == self.buffer.len() => break,
+                b'\r' if self.buffer[index + 1] == b'\n' => index + 2,
+                b'\r' => index + 1,
+                _ => {
+                    index += 1;
+                    continue;
+                }
+            };
+            let line = String::from_utf8_lossy(&self.buffer[start..index]).into_owned();
+            events.extend(self.process_line(&line));
+            start = next;
+            index = next;
+        }
+
+        self.buffer.drain(..start);
+        events
+    }
+
+    /// Flush the final line and event of a stream that ended without a blank line.
+    pub(crate) fn finish(&mut self) -> Option<SseEvent> {
+        let rest = std::mem::take(&mut self.buffer);
+        let rest = String::from_utf8_lossy(&rest);
+        let rest = rest.trim_end_matches(['\r', '\n']);
+        if !rest.is_empty() {
+            self.process_line(rest);
+        }
+        self.dispatch()
+    }
+
+    fn process_line(&mut self, line: &str) -> Option<SseEvent> {
+        if line.is_empty() {
+            return self.dispatch();
+        }
+        if line.starts_with(':') {
+            return None;
+        }
+
+        let (field, value) = match line.split_once(':') {
+            Some((field, value)) => (field, value.strip_prefix(' ').unwrap_or(value)),
+            None => (line, ""),
+        };
+        match field.trim_start_matches('\u{feff}') {
+            "data" => match self.data.as_mut() {
+                Some(data) => {
+                    data.push('\n');
+                    data.push_str(value);
+                }
+                None => self.data = Some(value.to_string()),
+            },
+            "event" => self.event = Some(value.to_string()),
+            "id" if !value.contains('\0') => {
+                self.id = Some(value.to_string());
+                self.last_event_id = Some(value.to_string());
+            }
+            "retry" => {
+                if let Ok(retry) = value.parse::<u64>() {
+                    self.retry = Some(retry);
+                    self.reconnection_time = Some(retry);
+                }
+            }
+            _ => {}
+        }
+        None
+    }
+
+    fn dispatch(&mut self) -> Option<SseEvent> {
+        let event = self.event.take();
+        let id = self.id.take();
+        let retry = self.retry.take();
+        self.data.take().map(|data| SseEvent { data, event, id, retry })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parser_reads_every_field() {
+        let mut parser = SseParser::default();
+        let events = parser.feed(b"event: tick\nid: 7\nretry: 1500\ndata: {\"n\": 1}\n\n");
+        assert_eq!(
+            events,
+            vec![SseEvent {
+                data: "{\"n\": 1}".to_string(),
+                event: Some("tick".to_string()),
+                id: Some("7".to_string()),
+                retry: Some(1500),
+            }]
+        );
+        assert_eq!(parser.last_event_id.as_deref(), Some("7"));
+    }
+
+    #[test]
+    fn parser_joins_multiline_data_and_skips_comments() {
+        let mut parser = SseParser::default();
+        let events = parser.feed(b": keep-alive\n\ndata: first\ndata\ndata:third\n\n");
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].data, "first\n\nthird");
+        assert_eq!(events[0].event, None);
+    }
+
+    #[test]
+    fn parser_handles_chunks_split_mid_line_and_crlf() {
+        let mut parser = SseParser::default();
+        assert!(parser.feed(b"data: hel").is_empty());
+        assert!(parser.feed(b"lo\r").is_empty());
+        let events = parser.feed(b"\n\r\n");
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].data, "hello");
+    }
+
+    #[test]
+    fn parser_flushes_unterminated_event_on_finish() {
+        let mut parser = SseParser::default();
+        assert!(parser.feed(b"id: 3\ndata: last").is_empty());
+        let event = parser.finish().expect("event");
+        assert_eq!(event.data, "last");
+        assert_eq!(event.id.as_deref(), Some("3"));
+        assert!(parser.finish().is_none());
+    }
+}
@@ -7,14 +7,18 @@
 //! The core client handles all HTTP method dispatch, query params, header management,
 //! body encoding (JSON, form-data, multipart), and response snapshot capture.
 
-use super::{ResponseSnapshot, SnapshotError, snapshot_response};
+use super::{GrpcResponse, ResponseSnapshot, SnapshotError, SseReader, StreamingResponse, snapshot_response};
+use crate::jsonrpc::JsonRpcError;
 use axum::http::{HeaderName, HeaderValue, Method};
 use axum_test::TestServer;
 use bytes::Bytes;
+use serde::de::DeserializeOwned;
 use serde_json::Value;
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use tokio::time::timeout;
+use tower::ServiceExt;
 use urlencoding::encode;
 
 type MultipartPayload = Option<(Vec<(String, String)>, Vec<super::MultipartFilePart>)>;
@@ -49,6 +53,8 @@ pub struct TestClient {
     mock_server: Arc<TestServer>,
     router: axum::Router,
     http_server: Mutex<Option<Arc<TestServer>>>,
+    #[cfg(feature = "di")]
+    dependencies: Option<Arc<spikard_core::di::DependencyContainer>>,
 }
 
 impl TestClient {
@@ -61,6 +67,78 @@ impl TestClient {
             mock_server: Arc::new(mock_server),
             router,
             http_server: Mutex::new(None),
+            #[cfg(feature = "di")]
+            dependencies: None,
+        })
+    }
+
+    /// Attach the dependency container the router resolves from, enabling overrides
+    #[cfg(feature = "di")]
+    #[must_use]
+    pub fn with_dependencies(mut self, container: Arc<spikard_core::di::DependencyContainer>) -> Self {
+        self.dependencies = Some(container);
+        self
+    }
+
+    /// Replace the dependency registered as `key` until it is restored
+    ///
+    /// Singletons built from the real dependency are kept and come back once
+    /// the override is restored.
+    #[cfg(feature = "di")]
+    pub fn override_dependency(
+        &self,
+        key: &str,
+        dependency: Arc<dyn spikard_core::di::Dependency>,
+    ) -> Result<(), String> {
+        self.dependency_container()?
+            .set_override(key, dependency)
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    }
+
+    /// Replace the dependency registered as `key` with a fixed JSON value
+    ///
+    /// Language bindings use this to override dependencies with native values.
+    #[cfg(feature = "di")]
+    pub fn override_dependency_value(&self, key: &str, value: Value) -> Result<(), String> {
+        let dependency = spikard_core::di::ValueDependency::new(key, value);
+        self.override_dependency(key, Arc::new(dependency))
+    }
+
+    /// Replace the dependency registered as `key` with a fixed JSON value until the guard is dropped
+    ///
+    /// Bindings wrap the guard in a scope object so the override is restored
+    /// even when a test fails half way through.
+    #[cfg(feature = "di")]
+    pub fn override_dependency_scope(
+        &self,
+        key: &str,
+        value: Value,
+    ) -> Result<spikard_core::di::DependencyOverrideGuard, String> {
+        let dependency = spikard_core::di::ValueDependency::new(key, value);
+        self.dependency_container()?
+            .override_scope(key, Arc::new(dependency))
+            .map_err(|e| e.to_string())
+    }
+
+    /// Restore the registered dependency for `key`
+    #[cfg(feature = "di")]
+    pub fn restore_dependency(&self, key: &str) -> Result<(), String> {
+        self.dependency_container()?.remove_override(key);
+        Ok(())
+    }
+
+    /// Restore every overridden dependency
+    #[cfg(feature = "di")]
+    pub fn clear_dependency_overrides(&self) -> Result<(), String> {
+        self.dependency_container()?.clear_overrides();
+        Ok(())
+    }
+
+    #[cfg(feature = "di")]
+    fn dependency_container(&self) -> Result<&Arc<spikard_core::di::DependencyContainer>, String> {
+        self.dependencies.as_ref().ok_or_else(|| {
+            "Test client has no dependency container; build it from an app with dependencies".to_string()
         })
     }
 
@@ -91,6 +169,15 @@ impl TestClient {
         Ok(server)
     }
 
+    /// Dispatch a request through the router without buffering the response body.
+    async fn call_router(&self, request: axum::http::Request<axum::body::Body>) -> axum::response::Response {
+        self.router
+            .clone()
+            .oneshot(request)
+            .await
+            .unwrap_or_else(|never| match never {})
+    }
+
     /// Make a GET request
     #[doc(hidden)]
     pub async fn get(
@@ -170,6 +257,182 @@ impl TestClient {
         snapshot_response(response).await
     }
 
+    /// Send a request and return the response without waiting for its body.
+    ///
+    /// The body is read incrementally through the returned [`StreamingResponse`],
+    /// which makes `HandlerResponse::stream` bodies and endless streams testable.
+    pub async fn stream(
+        &self,
+        method: Method,
+        path: &str,
+        body: Bytes,
+        query_params: Option<Vec<(String, String)>>,
+        headers: Option<Vec<(String, String)>>,
+    ) -> Result<StreamingResponse, SnapshotError> {
+        let full_path = build_full_path(path, query_params.as_deref());
+        let request = build_request(method, &full_path, body, headers.unwrap_or_default())?;
+        let response = self.call_router(request).await;
+        StreamingResponse::from_response(response)
+    }
+
+    /// Open a Server-Sent Events stream with a GET request.
+    ///
+    /// Events are parsed as they arrive; see [`SseReader`].
+    pub async fn sse(
+        &self,
+        path: &str,
+        query_params: Option<Vec<(String, String)>>,
+        headers: Option<Vec<(String, String)>>,
+    ) -> Result<SseReader, SnapshotError> {
+        let mut headers = headers.unwrap_or_default();
+        if !headers.iter().any(|(key, _)| key.eq_ignore_ascii_case("accept")) {
+            headers.push(("accept".to_string(), "text/event-stream".to_string()));
+        }
+        let response = self
+            .stream(Method::GET, path, Bytes::new(), query_params, Some(headers))
+            .await?;
+        Ok(response.into_sse())
+    }
+
+    /// Make a unary gRPC call to `service`/`method`.
+    ///
+    /// `metadata` is sent as request headers; response metadata, trailers and the
+    /// final status are returned on the [`GrpcResponse`].
+    pub async fn grpc_unary(
+        &self,
+        service: &str,
+        method: &str,
+        message: Bytes,
+        metadata: Option<Vec<(String, String)>>,
+    ) -> Result<GrpcResponse, SnapshotError> {
+        self.grpc_call(service, method, vec![message], metadata).await
+    }
+
+    /// Make a server-streaming gRPC call, collecting every reply in order.
+    pub async fn grpc_server_stream(
+        &self,
+        service: &str,
+        method: &str,
+        message: Bytes,
+        metadata: Option<Vec<(String, String)>>,
+    ) -> Result<GrpcResponse, SnapshotError> {
+        self.grpc_call(service, method, vec![message], metadata).await
+    }
+
+    /// Make a client-streaming gRPC call sending `messages` in order.
+    pub async fn grpc_client_stream(
+        &self,
+        service: &str,
+        method: &str,
+        messages: Vec<Bytes>,
+        metadata: Option<Vec<(String, String)>>,
+    ) -> Result<GrpcResponse, SnapshotError> {
+        self.grpc_call(service, method, messages, metadata).await
+    }
+
+    /// Make a bidirectional-streaming gRPC call.
+    ///
+    /// The request stream carries `messages` and then ends; every reply is collected in order.
+    pub async fn grpc_bidi_stream(
+        &self,
+        service: &str,
+        method: &str,
+        messages: Vec<Bytes>,
+        metadata: Option<Vec<(String, String)>>,
+    ) -> Result<GrpcResponse, SnapshotError> {
+        self.grpc_call(service, method, messages, metadata).await
+    }
+
+    async fn grpc_call(
+        &self,
+        service: &str,
+        method: &str,
+        messages: Vec<Bytes>,
+        metadata: Option<Vec<(String, String)>>,
+    ) -> Result<GrpcResponse, SnapshotError> {
+        let body = super::grpc::encode_grpc_messages(messages)?;
+        let mut headers = vec![
+            ("content-type".to_string(), "application/grpc".to_string()),
+            ("te".to_string(), "trailers".to_string()),
+        ];
+        headers.extend(metadata.unwrap_or_default());
+
+        let request = build_request(Method::POST, &format!("/{}/{}", service, method), body, headers)?;
+        let response = self.call_router(request).await;
+        GrpcResponse::from_response(response).await
+    }
+
+    /// Call a JSON-RPC method at a custom endpoint, deserializing its result into `T`.
+    ///
+    /// The outer error reports transport failures; the inner result is the method's
+    /// `result` or the JSON-RPC error object it returned.
+    pub async fn jsonrpc_call_at<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        method: &str,
+        params: Option<Value>,
+    ) -> Result<Result<T, JsonRpcError>, SnapshotError> {
+        let request = build_jsonrpc_request(method, params, Value::from(1));
+        let response = self.post(endpoint, Some(request), None, None, None, None).await?;
+        let body = jsonrpc_response_body(&response)?;
+        parse_jsonrpc_result(body)
+    }
+
+    /// Call a JSON-RPC method at `/rpc`, deserializing its result into `T`.
+    pub async fn jsonrpc_call<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: Option<Value>,
+    ) -> Result<Result<T, JsonRpcError>, SnapshotError> {
+        self.jsonrpc_call_at("/rpc", method, params).await
+    }
+
+    /// Send a JSON-RPC batch to a custom endpoint.
+    ///
+    /// Results are returned in the order of `calls`, whatever order the server replied in.
+    pub async fn jsonrpc_batch_at(
+        &self,
+        endpoint: &str,
+        calls: Vec<(String, Option<Value>)>,
+    ) -> Result<Vec<Result<Value, JsonRpcError>>, SnapshotError> {
+        let count = calls.len();
+        let batch = calls
+            .into_iter()
+            .zip(1..)
+            .map(|((method, params), id)| build_jsonrpc_request(&method, params, Value::from(id)))
+            .collect();
+        let response = self
+            .post(endpoint, Some(Value::Array(batch)), None, None, None, None)
+            .await?;
+
+        let Value::Array(replies) = jsonrpc_response_body(&response)? else {
+            return Err(SnapshotError::Decompression(
+                "JSON-RPC batch response is not an array".to_string(),
+            ));
+        };
+        let mut by_id: HashMap<u64, Value> = replies
+            .into_iter()
+            .filter_map(|reply| Some((reply.get("id")?.as_u64()?, reply)))
+            .collect();
+
+        (1..=count as u64)
+            .map(|id| {
+                let reply = by_id.remove(&id).ok_or_else(|| {
+                    SnapshotError::Decompression(format!("JSON-RPC batch response has no reply for id {}", id))
+                })?;
+                parse_jsonrpc_result(reply)
+            })
+            .collect()
+    }
+
+    /// Send a JSON-RPC batch to `/rpc`.
+    pub async fn jsonrpc_batch(
+        &self,
+        calls: Vec<(String, Option<Value>)>,
+    ) -> Result<Vec<Result<Value, JsonRpcError>>, SnapshotError> {
+        self.jsonrpc_batch_at("/rpc", calls).await
+    }
+
     /// Make a PUT request
     #[doc(hidden)]
     pub async fn put(
@@ -571,6 +834,61 @@ pub fn build_graphql_body(query: &str, variables: Option<serde_json::Value>, ope
     body
 }
 
+/// Build a JSON-RPC 2.0 request object
+fn build_jsonrpc_request(method: &str, params: Option<Value>, id: Value) -> Value {
+    let mut request = serde_json::json!({ "jsonrpc": "2.0", "method": method, "id": id });
+    if let Some(params) = params {
+        request["params"] = params;
+    }
+    request
+}
+
+/// Parse the body of a JSON-RPC response, rejecting non-JSON replies
+fn jsonrpc_response_body(response: &ResponseSnapshot) -> Result<Value, SnapshotError> {
+    response.json().map_err(|e| {
+        SnapshotError::Decompression(format!(
+            "JSON-RPC response (status {}) is not JSON: {}",
+            response.status, e
+        ))
+    })
+}
+
+/// Split a JSON-RPC reply into its typed result or error object
+fn parse_jsonrpc_result<T: DeserializeOwned>(mut reply: Value) -> Result<Result<T, JsonRpcError>, SnapshotError> {
+    if let Some(error) = reply.get_mut("error").map(Value::take) {
+        let error = serde_json::from_value(error)
+            .map_err(|e| SnapshotError::Decompression(format!("Invalid JSON-RPC error object: {}", e)))?;
+        return Ok(Err(error));
+    }
+    let result = reply
+        .get_mut("result")
+        .map(Value::take)
+        .ok_or_else(|| SnapshotError::Decompression("JSON-RPC response has neither result nor error".to_string()))?;
+    serde_json::from_value(result)
+        .map(Ok)
+        .map_err(|e| SnapshotError::Decompression(format!("Failed to deserialize JSON-RPC result: {}", e)))
+}
+
+/// Build an in-process request, validating header names and values
+fn build_request(
+    method: Method,
+    uri: &str,
+    body: Bytes,
+    headers: Vec<(String, String)>,
+) -> Result<axum::http::Request<axum::body::Body>, SnapshotError> {
+    let mut request = axum::http::Request::builder().method(method).uri(uri);
+    for (key, value) in headers {
+        let header_name = HeaderName::from_bytes(key.as_bytes())
+            .map_err(|e| SnapshotError::InvalidHeader(format!("Invalid header name: {}", e)))?;
+        let header_value = HeaderValue::from_str(&value)
+            .map_err(|e| SnapshotError::InvalidHeader(format!("Invalid header value: {}", e)))?;
+        request = request.header(header_name, header_value);
+    }
+    request
+        .body(axum::body::Body::from(body))
+        .map_err(|e| SnapshotError::InvalidHeader(format!("Invalid request: {}", e)))
+}
+
 /// Build a full path with query parameters
 fn build_full_path(path: &str, query_params: Option<&[(String, String)]>) -> String {
     match query_params {
@@ -2,7 +2,9 @@
 //!
 //! Provides WebSocket connection handling with message validation and routing.
 
+use crate::metrics::{ConnectionKind, ConnectionMetrics};
 use axum::{
+    Extension,
     extract::{
         State,
         ws::{Message, WebSocket, WebSocketUpgrade},
@@ -13,6 +15,15 @@ use serde_json::Value;
 use std::sync::Arc;
 use tracing::{debug, error, info, warn};
 
+/// Dependencies resolved when a connection opens, kept until it closes
+///
+/// Resolved before the upgrade completes; if the upgrade never completes the scope is
+/// dropped with the upgrade callback and its cleanup still runs.
+#[cfg(feature = "di")]
+type ConnectionDependencies = crate::di_handler::DependencyScope;
+#[cfg(not(feature = "di"))]
+type ConnectionDependencies = ();
+
 fn trace_ws(message: &str) {
     if std::env::var("SPIKARD_WS_TRACE").ok().as_deref() == Some("1") {
         eprintln!("[spikard-ws] {message}");
@@ -68,6 +79,22 @@ pub trait WebSocketHandler: Send + Sync {
     /// * `None` - No response to send
     fn handle_message(&self, message: Value) -> impl std::future::Future<Output = Option<Value>> + Send;
 
+    /// Handle incoming WebSocket message with the connection's dependencies
+    ///
+    /// Called instead of `handle_message` when the endpoint declares dependencies
+    /// (see [`WebSocketState::with_dependencies`]). The dependencies are resolved once
+    /// when the connection opens and cleaned up when it closes. The default
+    /// implementation ignores them.
+    #[cfg(feature = "di")]
+    fn handle_message_with_dependencies(
+        &self,
+        message: Value,
+        dependencies: &spikard_core::di::ResolvedDependencies,
+    ) -> impl std::future::Future<Output = Option<Value>> + Send {
+        let _ = dependencies;
+        self.handle_message(message)
+    }
+
     /// Called when a client connects to the WebSocket
     ///
     /// Optional lifecycle hook invoked when a new WebSocket connection is established.
@@ -99,6 +126,9 @@ pub struct WebSocketState<H: WebSocketHandler> {
     message_schema: Option<Arc<jsonschema::Validator>>,
     /// Optional JSON Schema for validating outgoing responses
     response_schema: Option<Arc<jsonschema::Validator>>,
+    /// Dependencies resolved for each connection
+    #[cfg(feature = "di")]
+    dependencies: Option<crate::di_handler::DeclaredDependencies>,
 }
 
 impl<H: WebSocketHandler> std::fmt::Debug for WebSocketState<H> {
@@ -117,6 +147,8 @@ impl<H: WebSocketHandler> Clone for WebSocketState<H> {
             handler_factory: Arc::clone(&self.handler_factory),
             message_schema: self.message_schema.clone(),
             response_schema: self.response_schema.clone(),
+            #[cfg(feature = "di")]
+            dependencies: self.dependencies.clone(),
         }
     }
 }
@@ -145,6 +177,8 @@ impl<H: WebSocketHandler + 'static> WebSocketState<H> {
             handler,
             message_schema: None,
             response_schema: None,
+            #[cfg(feature = "di")]
+            dependencies: None,
         }
     }
 
@@ -212,6 +246,8 @@ impl<H: WebSocketHandler + 'static> WebSocketState<H> {
             handler,
             message_schema: message_validator,
             response_schema: response_validator,
+            #[cfg(feature = "di")]
+            dependencies: None,
         })
     }
 
@@ -255,9 +291,23 @@ impl<H: WebSocketHandler + 'static> WebSocketState<H> {
             handler,
             message_schema: message_validator,
             response_schema: response_validator,
+            #[cfg(feature = "di")]
+            dependencies: None,
         })
     }
 
+    /// Resolve `dependencies` when each connection opens
+    ///
+    /// Messages are then dispatched to
+    /// [`WebSocketHandler::handle_message_with_dependencies`]. If resolution fails the
+    /// upgrade is refused with the same error response HTTP handlers produce.
+    #[cfg(feature = "di")]
+    #[must_use]
+    pub fn with_dependencies(mut self, dependencies: crate::di_handler::DeclaredDependencies) -> Self {
+        self.dependencies = Some(dependencies);
+        self
+    }
+
     /// Invoke the connection hook for testing.
     pub async fn on_connect(&self) {
         self.handler.on_connect().await;
@@ -311,14 +361,53 @@ impl<H: WebSocketHandler + 'static> WebSocketState<H> {
 ///     .with_state(state);
 /// ```
 pub async fn websocket_handler<H: WebSocketHandler + 'static>(
+    #[cfg(feature = "di")] parts: axum::http::request::Parts,
     ws: WebSocketUpgrade,
     State(state): State<WebSocketState<H>>,
-) -> impl IntoResponse {
-    ws.on_upgrade(move |socket| handle_socket(socket, state))
+    connection_metrics: Option<Extension<ConnectionMetrics>>,
+) -> axum::response::Response {
+    #[cfg(feature = "di")]
+    let dependencies = match &state.dependencies {
+        Some(declared) => match declared.resolve(&parts).await {
+            Ok(resolved) => crate::di_handler::DependencyScope::new(Some(resolved)),
+            Err(e) => {
+                error!("Failed to resolve WebSocket dependencies: {}", e);
+                return crate::di_handler::dependency_error_response(&e);
+            }
+        },
+        None => crate::di_handler::DependencyScope::new(None),
+    };
+    #[cfg(not(feature = "di"))]
+    let dependencies = ();
+
+    ws.on_upgrade(move |socket| async move {
+        let _active = connection_metrics.map(|Extension(metrics)| metrics.track(ConnectionKind::WebSocket));
+        handle_socket(socket, state, dependencies).await;
+    })
+    .into_response()
+}
+
+/// Dispatch a message to the handler, with the connection's dependencies if it has any
+async fn dispatch_message<H: WebSocketHandler>(
+    handler: &H,
+    message: Value,
+    dependencies: &ConnectionDependencies,
+) -> Option<Value> {
+    #[cfg(feature = "di")]
+    if let Some(dependencies) = dependencies.dependencies() {
+        return handler.handle_message_with_dependencies(message, dependencies).await;
+    }
+    #[cfg(not(feature = "di"))]
+    let () = dependencies;
+    handler.handle_message(message).await
 }
 
 /// Handle an individual WebSocket connection
-async fn handle_socket<H: WebSocketHandler>(mut socket: WebSocket, state: WebSocketState<H>) {
+async fn handle_socket<H: WebSocketHandler>(
+    mut socket: WebSocket,
+    state: WebSocketState<H>,
+    dependencies: ConnectionDependencies,
+) {
     info!("WebSocket client connected");
     trace_ws("socket:connected");
 
@@ -327,6 +416,7 @@ async fn handle_socket<H: WebSocketHandler>(mut socket: WebSocket, state: WebSoc
         Err(err) => {
             error!("Failed to create WebSocket handler: {}", err);
             trace_ws("socket:handler-factory:error");
+            release_dependencies(dependencies).await;
             return;
         }
     };
@@ -358,7 +448,7 @@ async fn handle_socket<H: WebSocketHandler>(mut socket: WebSocket, state: WebSoc
                             continue;
                         }
 
-                        if let Some(response) = handler.handle_message(json_msg).await {
+                        if let Some(response) = dispatch_message(handler.as_ref(), json_msg, &dependencies).await {
                             trace_ws("handler:response:some");
                             if let Some(validator) = &state.response_schema
                                 && !validator.is_valid(&response)
@@ -435,9 +525,18 @@ async fn handle_socket<H: WebSocketHandler>(mut socket: WebSocket, state: WebSoc
 
     handler.on_disconnect().await;
     trace_ws("socket:on_disconnect:done");
+    release_dependencies(dependencies).await;
     info!("WebSocket client disconnected");
 }
 
+/// Run the cleanup tasks of the connection's dependencies
+async fn release_dependencies(dependencies: ConnectionDependencies) {
+    #[cfg(feature = "di")]
+    dependencies.release().await;
+    #[cfg(not(feature = "di"))]
+    let () = dependencies;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,207 @@
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use serde_json::{Value, json};
+use spikard_http::server::build_router_with_handlers_and_config;
+use spikard_http::{
+    BodyCodecRegistry, Handler, HandlerResult, Method, OpenApiConfig, RequestData, Route, RouteMetadata, ServerConfig,
+    XmlCodec, XmlMapping,
+};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+/// Echoes the decoded request body as JSON
+struct Echo;
+
+impl Handler for Echo {
+    fn call(
+        &self,
+        _request: Request<Body>,
+        request_data: RequestData,
+    ) -> Pin<Box<dyn Future<Output = HandlerResult> + Send + '_>> {
+        Box::pin(async move {
+            Ok(axum::http::Response::builder()
+                .status(StatusCode::OK)
+                .header("content-type", "application/json")
+                .body(Body::from(request_data.body.to_string()))
+                .expect("response"))
+        })
+    }
+}
+
+fn item_schema() -> Value {
+    json!({
+        "type": "object",
+        "properties": {
+            "name": { "type": "string" },
+            "price": { "type": "number" },
+            "tags": { "type": "array", "items": { "type": "string" } }
+        },
+        "required": ["name", "price"]
+    })
+}
+
+fn routes() -> Vec<(Route, Arc<dyn Handler>)> {
+    let orders_schema = json!({ "type": "array", "items": { "type": "object" } });
+    [("/items", item_schema()), ("/orders", orders_schema)]
+        .into_iter()
+        .map(|(path, schema)| {
+            let route = Route {
+                method: Method::Post,
+                path: path.to_string(),
+                handler_name: path.trim_start_matches('/').to_string(),
+                expects_json_body: true,
+                cors: None,
+                is_async: true,
+                file_params: None,
+                request_validator: Some(Arc::new(spikard_core::SchemaValidator::new(schema).expect("schema"))),
+                response_validator: None,
+                parameter_validator: None,
+                jsonrpc_method: None,
+                compression: None,
+                body_limit: None,
+                request_timeout_secs: None,
+                rate_limit: None,
+                caching: None,
+                response_cache: None,
+                auth: None,
+                #[cfg(feature = "di")]
+                handler_dependencies: Vec::new(),
+            };
+            (route, Arc::new(Echo) as Arc<dyn Handler>)
+        })
+        .collect()
+}
+
+fn server(codecs: Option<BodyCodecRegistry>) -> axum_test::TestServer {
+    let config = ServerConfig {
+        body_codecs: codecs.map(Arc::new),
+        ..Default::default()
+    };
+    let app = build_router_with_handlers_and_config(routes(), config, Vec::new()).expect("router");
+    axum_test::TestServer::new(app)
+}
+
+async fn post(
+    server: &axum_test::TestServer,
+    path: &str,
+    content_type: &str,
+    body: Vec<u8>,
+) -> axum_test::TestResponse {
+    server
+        .post(path)
+        .add_header("content-type", content_type)
+        .bytes(body.into())
+        .await
+}
+
+#[tokio::test]
+async fn binary_codecs_decode_into_validated_json() {
+    let server = server(Some(BodyCodecRegistry::with_builtin_codecs()));
+    let item = json!({"name": "Widget", "price": 9.5, "tags": ["a", "b"]});
+
+    let msgpack = rmp_serde::to_vec_named(&item).expect("msgpack");
+    let response = post(&server, "/items", "application/msgpack", msgpack).await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(response.json::<Value>(), item);
+
+    let mut cbor = Vec::new();
+    ciborium::into_writer(&item, &mut cbor).expect("cbor");
+    let response = post(&server, "/items", "application/vnd.shop.item+cbor", cbor).await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(response.json::<Value>(), item);
+
+    let mut incomplete = Vec::new();
+    ciborium::into_writer(&json!({"name": "Widget"}), &mut incomplete).expect("cbor");
+    let response = post(&server, "/items", "application/cbor", incomplete).await;
+    assert_eq!(response.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+#[tokio::test]
+async fn xml_and_ndjson_bodies_are_mapped_onto_json() {
+    let mut codecs = BodyCodecRegistry::with_builtin_codecs();
+    codecs.register(
+        "application/xml",
+        Arc::new(XmlCodec::new(XmlMapping {
+            array_elements: vec!["tags".to_string()],
+            ..Default::default()
+        })),
+    );
+    let server = server(Some(codecs));
+
+    let xml = br#"<?xml version="1.0"?><item id="7"><name>Widget</name><price>9.5</price><tags>a</tags></item>"#;
+    let response = post(&server, "/items", "application/xml; charset=utf-8", xml.to_vec()).await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(
+        response.json::<Value>(),
+        json!({"@id": 7, "name": "Widget", "price": 9.5, "tags": ["a"]})
+    );
+
+    let ndjson = b"{\"id\": 1}\n\n{\"id\": 2}\n".to_vec();
+    let response = post(&server, "/orders", "application/x-ndjson", ndjson).await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(response.json::<Value>(), json!([{"id": 1}, {"id": 2}]));
+}
+
+#[tokio::test]
+async fn malformed_and_unregistered_bodies_are_rejected() {
+    let server_with_codecs = server(Some(BodyCodecRegistry::with_builtin_codecs()));
+    let response = post(
+        &server_with_codecs,
+        "/items",
+        "application/xml",
+        b"<item><name>".to_vec(),
+    )
+    .await;
+    assert_eq!(response.status_code(), StatusCode::BAD_REQUEST);
+    assert_eq!(
+        response.json::<Value>()["type"],
+        "https://spikard.dev/errors/bad-request"
+    );
+
+    let server_without_codecs = server(None);
+    let response = post(
+        &server_without_codecs,
+        "/items",
+        "application/xml",
+        b"<item><name>Widget</name><price>1</price></item>".to_vec(),
+    )
+    .await;
+    assert_eq!(response.status_code(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+}
+
+#[tokio::test]
+async fn openapi_documents_codec_media_types() {
+    let metadata = vec![RouteMetadata {
+        method: "POST".to_string(),
+        path: "/items".to_string(),
+        handler_name: "items".to_string(),
+        request_schema: Some(item_schema()),
+        is_async: true,
+        ..Default::default()
+    }];
+    let config = ServerConfig {
+        openapi: Some(OpenApiConfig {
+            enabled: true,
+            ..OpenApiConfig::default()
+        }),
+        body_codecs: Some(Arc::new(BodyCodecRegistry::with_builtin_codecs())),
+        ..Default::default()
+    };
+    let app = build_router_with_handlers_and_config(routes(), config, metadata).expect("router");
+    let server = axum_test::TestServer::new(app);
+
+    let spec: Value = server.get("/openapi.json").await.json();
+    let content = &spec["paths"]["/items"]["post"]["requestBody"]["content"];
+    for media_type in [
+        "application/json",
+        "application/msgpack",
+        "application/cbor",
+        "application/xml",
+    ] {
+        assert_eq!(
+            content[media_type]["schema"], content["application/json"]["schema"],
+            "{media_type}"
+        );
+    }
+}
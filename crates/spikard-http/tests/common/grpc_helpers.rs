@@ -236,6 +236,8 @@ pub async fn send_unary_request(
         method_name: method.to_string(),
         payload,
         metadata,
+        #[cfg(feature = "di")]
+        dependencies: None,
     };
 
     handler.call(request).await.map_err(|e| e.message().into())
@@ -699,6 +701,8 @@ mod tests {
             method_name: "TestMethod".to_string(),
             payload: Bytes::from("request"),
             metadata: MetadataMap::new(),
+            #[cfg(feature = "di")]
+            dependencies: None,
         };
 
         let result = handler.call(request).await;
@@ -718,6 +722,8 @@ mod tests {
             method_name: "GetUser".to_string(),
             payload: Bytes::from("{}"),
             metadata: MetadataMap::new(),
+            #[cfg(feature = "di")]
+            dependencies: None,
         };
 
         let result = handler.call(request).await;
@@ -737,6 +743,8 @@ mod tests {
             method_name: "GetResource".to_string(),
             payload: Bytes::new(),
             metadata: MetadataMap::new(),
+            #[cfg(feature = "di")]
+            dependencies: None,
         };
 
         let result = handler.call(request).await;
@@ -757,6 +765,8 @@ mod tests {
             method_name: "Echo".to_string(),
             payload: payload.clone(),
             metadata: MetadataMap::new(),
+            #[cfg(feature = "di")]
+            dependencies: None,
         };
 
         let result = handler.call(request).await;
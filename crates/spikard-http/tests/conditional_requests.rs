@@ -0,0 +1,181 @@
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use serde_json::{Value, json};
+use spikard_http::server::build_router_with_handlers_and_config;
+use spikard_http::{CachingConfig, ETagMode, Handler, HandlerResult, Method, RequestData, Route, ServerConfig};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+const LAST_MODIFIED: &str = "Wed, 21 Oct 2026 07:28:00 GMT";
+
+/// Serves or replaces a single shared document
+struct Document {
+    state: Arc<Mutex<Value>>,
+}
+
+impl Handler for Document {
+    fn call(
+        &self,
+        request: Request<Body>,
+        request_data: RequestData,
+    ) -> Pin<Box<dyn Future<Output = HandlerResult> + Send + '_>> {
+        Box::pin(async move {
+            let mut state = self.state.lock().expect("state");
+            if request.method() == axum::http::Method::PUT {
+                *state = (*request_data.body).clone();
+            }
+            Ok(axum::http::Response::builder()
+                .status(StatusCode::OK)
+                .header("content-type", "application/json")
+                .header("last-modified", LAST_MODIFIED)
+                .body(Body::from(state.to_string()))
+                .expect("response"))
+        })
+    }
+}
+
+/// Sets its own strong validator
+struct Versioned;
+
+impl Handler for Versioned {
+    fn call(
+        &self,
+        _request: Request<Body>,
+        _request_data: RequestData,
+    ) -> Pin<Box<dyn Future<Output = HandlerResult> + Send + '_>> {
+        Box::pin(async move {
+            Ok(axum::http::Response::builder()
+                .status(StatusCode::OK)
+                .header("etag", "\"v7\"")
+                .body(Body::from("version 7"))
+                .expect("response"))
+        })
+    }
+}
+
+fn route(method: Method, path: &str, caching: CachingConfig, handler: Arc<dyn Handler>) -> (Route, Arc<dyn Handler>) {
+    let route = Route {
+        method,
+        path: path.to_string(),
+        handler_name: path.trim_start_matches('/').to_string(),
+        expects_json_body: false,
+        cors: None,
+        is_async: true,
+        file_params: None,
+        request_validator: None,
+        response_validator: None,
+        parameter_validator: None,
+        jsonrpc_method: None,
+        compression: None,
+        body_limit: None,
+        request_timeout_secs: None,
+        rate_limit: None,
+        caching: Some(caching),
+        response_cache: None,
+        auth: None,
+        #[cfg(feature = "di")]
+        handler_dependencies: Vec::new(),
+    };
+    (route, handler)
+}
+
+fn server() -> axum_test::TestServer {
+    let document = Arc::new(Document {
+        state: Arc::new(Mutex::new(json!({"title": "draft"}))),
+    });
+    let caching = CachingConfig {
+        etag: ETagMode::Strong,
+        cache_control: Some("private, max-age=60".to_string()),
+        vary: vec!["accept-language".to_string()],
+    };
+    let routes = vec![
+        route(Method::Get, "/document", caching.clone(), document.clone()),
+        route(Method::Put, "/document", caching, document),
+        route(
+            Method::Get,
+            "/versioned",
+            CachingConfig {
+                etag: ETagMode::Handler,
+                ..Default::default()
+            },
+            Arc::new(Versioned),
+        ),
+    ];
+    let app = build_router_with_handlers_and_config(routes, ServerConfig::default(), Vec::new()).expect("router");
+    axum_test::TestServer::new(app)
+}
+
+#[tokio::test]
+async fn responses_carry_validators_and_cache_headers() {
+    let server = server();
+
+    let response = server.get("/document").await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let etag = response.headers()["etag"].to_str().expect("ascii").to_string();
+    assert!(etag.starts_with('"') && etag.ends_with('"'), "strong ETag: {etag}");
+    assert_eq!(response.headers()["cache-control"], "private, max-age=60");
+    assert_eq!(response.headers()["vary"], "accept-language");
+
+    let versioned = server.get("/versioned").await;
+    assert_eq!(versioned.headers()["etag"], "\"v7\"");
+    assert!(versioned.headers().get("cache-control").is_none());
+}
+
+#[tokio::test]
+async fn matching_validators_answer_not_modified() {
+    let server = server();
+    let etag = server.get("/document").await.headers()["etag"].clone();
+
+    let by_etag = server.get("/document").add_header("if-none-match", etag.clone()).await;
+    assert_eq!(by_etag.status_code(), StatusCode::NOT_MODIFIED);
+    assert!(by_etag.as_bytes().is_empty());
+    assert_eq!(by_etag.headers()["etag"], etag);
+    assert_eq!(by_etag.headers()["cache-control"], "private, max-age=60");
+
+    let weak = format!("W/{}", etag.to_str().expect("ascii"));
+    let by_weak_etag = server.get("/document").add_header("if-none-match", weak.as_str()).await;
+    assert_eq!(by_weak_etag.status_code(), StatusCode::NOT_MODIFIED);
+
+    let by_date = server
+        .get("/document")
+        .add_header("if-modified-since", LAST_MODIFIED)
+        .await;
+    assert_eq!(by_date.status_code(), StatusCode::NOT_MODIFIED);
+
+    let stale = server.get("/document").add_header("if-none-match", "\"stale\"").await;
+    assert_eq!(stale.status_code(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn if_match_guards_concurrent_updates() {
+    let server = server();
+    let etag = server.get("/document").await.headers()["etag"].clone();
+
+    let first = server
+        .put("/document")
+        .add_header("if-match", etag.clone())
+        .json(&json!({"title": "final"}))
+        .await;
+    assert_eq!(first.status_code(), StatusCode::OK);
+    assert_ne!(first.headers()["etag"], etag);
+
+    let conflicting = server
+        .put("/document")
+        .add_header("if-match", etag)
+        .json(&json!({"title": "overwritten"}))
+        .await;
+    assert_eq!(conflicting.status_code(), StatusCode::PRECONDITION_FAILED);
+    assert_eq!(
+        conflicting.json::<Value>()["type"],
+        "https://spikard.dev/errors/precondition-failed"
+    );
+    assert_eq!(server.get("/document").await.json::<Value>(), json!({"title": "final"}));
+
+    let create_only = server
+        .put("/document")
+        .add_header("if-none-match", "*")
+        .json(&json!({"title": "new"}))
+        .await;
+    assert_eq!(create_only.status_code(), StatusCode::PRECONDITION_FAILED);
+}
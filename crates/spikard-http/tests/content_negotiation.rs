@@ -0,0 +1,159 @@
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use serde_json::{Value, json};
+use spikard_http::server::build_router_with_handlers_and_config;
+use spikard_http::{
+    Handler, HandlerResult, Method, OpenApiConfig, RequestData, ResponseEncoderRegistry, Route, RouteMetadata,
+    ServerConfig,
+};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+/// Returns a fixed JSON document
+struct Fixed(Value);
+
+impl Handler for Fixed {
+    fn call(
+        &self,
+        _request: Request<Body>,
+        _request_data: RequestData,
+    ) -> Pin<Box<dyn Future<Output = HandlerResult> + Send + '_>> {
+        Box::pin(async move {
+            Ok(axum::http::Response::builder()
+                .status(StatusCode::OK)
+                .header("content-type", "application/json")
+                .body(Body::from(self.0.to_string()))
+                .expect("response"))
+        })
+    }
+}
+
+fn items() -> Value {
+    json!([{"id": 1, "name": "Widget"}, {"id": 2, "name": "Gadget"}])
+}
+
+fn route(path: &str, body: Value) -> (Route, Arc<dyn Handler>) {
+    let route = Route {
+        method: Method::Get,
+        path: path.to_string(),
+        handler_name: path.trim_start_matches('/').to_string(),
+        expects_json_body: false,
+        cors: None,
+        is_async: true,
+        file_params: None,
+        request_validator: None,
+        response_validator: None,
+        parameter_validator: None,
+        jsonrpc_method: None,
+        compression: None,
+        body_limit: None,
+        request_timeout_secs: None,
+        rate_limit: None,
+        caching: None,
+        response_cache: None,
+        auth: None,
+        #[cfg(feature = "di")]
+        handler_dependencies: Vec::new(),
+    };
+    (route, Arc::new(Fixed(body)))
+}
+
+fn server(metadata: Vec<RouteMetadata>, openapi: Option<OpenApiConfig>) -> axum_test::TestServer {
+    let config = ServerConfig {
+        response_encoders: Some(Arc::new(ResponseEncoderRegistry::with_builtin_encoders())),
+        openapi,
+        ..Default::default()
+    };
+    let routes = vec![route("/items", items()), route("/count", json!(2))];
+    let app = build_router_with_handlers_and_config(routes, config, metadata).expect("router");
+    axum_test::TestServer::new(app)
+}
+
+#[tokio::test]
+async fn accept_selects_a_registered_encoder() {
+    let server = server(Vec::new(), None);
+
+    let response = server.get("/items").add_header("accept", "application/msgpack").await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(response.headers()["content-type"], "application/msgpack");
+    assert_eq!(response.headers()["vary"], "accept");
+    let decoded: Value = rmp_serde::from_slice(response.as_bytes()).expect("msgpack");
+    assert_eq!(decoded, items());
+
+    let csv = server
+        .get("/items")
+        .add_header("accept", "application/json;q=0.4, text/csv;q=0.8")
+        .await;
+    assert_eq!(csv.headers()["content-type"], "text/csv");
+    assert_eq!(csv.text(), "id,name\r\n1,Widget\r\n2,Gadget\r\n");
+
+    let yaml = server.get("/items").add_header("accept", "application/yaml").await;
+    assert!(yaml.text().contains("name: Widget"), "{}", yaml.text());
+}
+
+#[tokio::test]
+async fn json_remains_the_default_representation() {
+    let server = server(Vec::new(), None);
+
+    let response = server.get("/items").await;
+    assert_eq!(response.headers()["content-type"], "application/json");
+    assert_eq!(response.headers()["vary"], "accept");
+    assert_eq!(response.json::<Value>(), items());
+
+    let wildcard = server.get("/items").add_header("accept", "text/html, */*;q=0.8").await;
+    assert_eq!(wildcard.headers()["content-type"], "application/json");
+}
+
+#[tokio::test]
+async fn unacceptable_requests_get_406() {
+    let server = server(Vec::new(), None);
+
+    let response = server.get("/items").add_header("accept", "text/html").await;
+    assert_eq!(response.status_code(), StatusCode::NOT_ACCEPTABLE);
+    let problem: Value = response.json();
+    assert_eq!(problem["type"], "https://spikard.dev/errors/not-acceptable");
+    assert!(
+        problem["available"]
+            .as_array()
+            .is_some_and(|types| types.contains(&json!("text/csv")))
+    );
+
+    let scalar_as_csv = server.get("/count").add_header("accept", "text/csv").await;
+    assert_eq!(scalar_as_csv.status_code(), StatusCode::NOT_ACCEPTABLE);
+
+    let with_fallback = server
+        .get("/count")
+        .add_header("accept", "text/csv, application/json;q=0.1")
+        .await;
+    assert_eq!(with_fallback.status_code(), StatusCode::OK);
+    assert_eq!(with_fallback.json::<Value>(), json!(2));
+}
+
+#[tokio::test]
+async fn openapi_lists_alternative_response_media_types() {
+    let metadata = vec![RouteMetadata {
+        method: "GET".to_string(),
+        path: "/items".to_string(),
+        handler_name: "items".to_string(),
+        response_schema: Some(json!({"type": "array", "items": {"type": "object"}})),
+        is_async: true,
+        ..Default::default()
+    }];
+    let openapi = OpenApiConfig {
+        enabled: true,
+        ..OpenApiConfig::default()
+    };
+    let server = server(metadata, Some(openapi));
+
+    let spec: Value = server.get("/openapi.json").await.json();
+    let content = &spec["paths"]["/items"]["get"]["responses"]["200"]["content"];
+    for media_type in [
+        "application/json",
+        "application/msgpack",
+        "application/yaml",
+        "text/csv",
+    ] {
+        assert!(content[media_type]["schema"].is_object(), "{media_type}");
+    }
+}
@@ -35,6 +35,9 @@ fn minimal_request_data() -> RequestData {
         cookies: Arc::new(HashMap::new()),
         method: "GET".to_string(),
         path: "/".to_string(),
+        client_ip: None,
+        scheme: None,
+        host: None,
         #[cfg(feature = "di")]
         dependencies: None,
     }
@@ -82,6 +82,9 @@ async fn test_di_value_injection() {
         cookies: Arc::new(HashMap::new()),
         method: "GET".to_string(),
         path: "/".to_string(),
+        client_ip: None,
+        scheme: None,
+        host: None,
         #[cfg(feature = "di")]
         dependencies: None,
     };
@@ -124,6 +127,9 @@ async fn test_di_missing_dependency_error() {
         cookies: Arc::new(HashMap::new()),
         method: "GET".to_string(),
         path: "/".to_string(),
+        client_ip: None,
+        scheme: None,
+        host: None,
         #[cfg(feature = "di")]
         dependencies: None,
     };
@@ -171,6 +177,9 @@ async fn test_di_multiple_value_dependencies() {
         cookies: Arc::new(HashMap::new()),
         method: "GET".to_string(),
         path: "/".to_string(),
+        client_ip: None,
+        scheme: None,
+        host: None,
         #[cfg(feature = "di")]
         dependencies: None,
     };
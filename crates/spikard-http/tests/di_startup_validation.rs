@@ -0,0 +1,114 @@
+#![cfg(feature = "di")]
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use spikard_core::di::{DependencyContainer, ValueDependency};
+use spikard_http::server::build_router_with_handlers_and_config;
+use spikard_http::{Handler, HandlerResult, HealthChecks, Method, RequestData, Route, ServerConfig, health_check};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Answers 200 without touching its dependencies
+struct Ok200;
+
+impl Handler for Ok200 {
+    fn call(
+        &self,
+        _request: Request<Body>,
+        _request_data: RequestData,
+    ) -> Pin<Box<dyn Future<Output = HandlerResult> + Send + '_>> {
+        Box::pin(async {
+            Ok(axum::http::Response::builder()
+                .status(StatusCode::OK)
+                .body(Body::empty())
+                .expect("response"))
+        })
+    }
+}
+
+fn route(path: &str, dependencies: &[&str]) -> (Route, Arc<dyn Handler>) {
+    let route = Route {
+        method: Method::Get,
+        path: path.to_string(),
+        handler_name: path.trim_start_matches('/').to_string(),
+        expects_json_body: false,
+        cors: None,
+        is_async: true,
+        file_params: None,
+        request_validator: None,
+        response_validator: None,
+        parameter_validator: None,
+        jsonrpc_method: None,
+        compression: None,
+        body_limit: None,
+        request_timeout_secs: None,
+        rate_limit: None,
+        caching: None,
+        response_cache: None,
+        auth: None,
+        handler_dependencies: dependencies.iter().map(ToString::to_string).collect(),
+    };
+    (route, Arc::new(Ok200))
+}
+
+fn config(lenient: bool) -> ServerConfig {
+    let mut container = DependencyContainer::new();
+    for key in ["db_pool", "metrics"] {
+        container
+            .register(key.to_string(), Arc::new(ValueDependency::new(key, key.to_string())))
+            .expect("register");
+    }
+    ServerConfig {
+        di_container: Some(Arc::new(container)),
+        lenient_dependencies: lenient,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn miswired_dependencies_fail_the_build_with_a_report() {
+    for lenient in [false, true] {
+        let routes = vec![route("/users", &["db_pool"]), route("/orders", &["cart"])];
+        let error = build_router_with_handlers_and_config(routes, config(lenient), Vec::new())
+            .expect_err("unknown dependency must fail the build");
+
+        assert!(
+            error.contains("unknown dependency 'cart' required by route GET /orders"),
+            "{error}"
+        );
+        assert!(error.contains("unused registration 'metrics'"), "{error}");
+    }
+}
+
+#[test]
+fn endpoint_and_health_check_dependencies_are_validated() {
+    let mut checks = HealthChecks::new();
+    checks.add_with_dependencies(
+        health_check("database", |_| async { Ok(()) }),
+        vec!["db_pool".to_string()],
+        Duration::from_secs(1),
+    );
+    let mut server_config = config(false);
+    server_config.health_checks = Some(Arc::new(checks));
+    server_config.endpoint_dependencies = vec![("WEBSOCKET /chat".to_string(), vec!["session".to_string()])];
+
+    let error = build_router_with_handlers_and_config(Vec::new(), server_config, Vec::new())
+        .expect_err("unknown endpoint dependency must fail the build");
+    assert!(
+        error.contains("unknown dependency 'session' required by route WEBSOCKET /chat"),
+        "{error}"
+    );
+    assert!(!error.contains("unused registration 'db_pool'"), "{error}");
+}
+
+#[tokio::test]
+async fn unused_registrations_only_warn() {
+    for lenient in [false, true] {
+        let routes = vec![route("/users", &["db_pool"])];
+        let app = build_router_with_handlers_and_config(routes, config(lenient), Vec::new()).expect("router");
+        let server = axum_test::TestServer::new(app);
+        assert_eq!(server.get("/users").await.status_code(), StatusCode::OK);
+    }
+}
@@ -0,0 +1,365 @@
+//! Dependency injection for WebSocket, SSE, JSON-RPC and gRPC endpoints.
+#![cfg(feature = "di")]
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use axum::routing::get;
+use http_body_util::BodyExt;
+use serde_json::{Value, json};
+use spikard_core::di::{DependencyContainer, DependencyError, FactoryDependency, ResolvedDependencies};
+use spikard_core::router::JsonRpcMethodInfo;
+use spikard_http::grpc::{
+    DependencyInjectingGrpcHandler, GrpcHandler, GrpcHandlerResult, GrpcRequestData, GrpcResponseData,
+};
+use spikard_http::server::build_router_with_handlers_and_config;
+use spikard_http::testing::connect_websocket;
+use spikard_http::{
+    DeclaredDependencies, Handler, HandlerResult, JsonRpcConfig, Method, RequestData, Route, ServerConfig, SseEvent,
+    SseEventProducer, SseState, WebSocketHandler, WebSocketState, sse_handler, websocket_handler,
+};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tokio::time::{Duration, timeout};
+use tower::ServiceExt;
+
+/// Counts how often the `session` dependency is resolved and cleaned up
+#[derive(Default)]
+struct Counters {
+    resolved: AtomicUsize,
+    cleaned_up: AtomicUsize,
+}
+
+/// A container whose `session` names the path or `x-tenant` header it was resolved for,
+/// and whose `broken` dependency always fails to resolve
+fn container(counters: &Arc<Counters>) -> Arc<DependencyContainer> {
+    let counters = Arc::clone(counters);
+    let session = FactoryDependency::builder("session")
+        .factory(move |request, _data, resolved: &ResolvedDependencies| {
+            let number = counters.resolved.fetch_add(1, Ordering::SeqCst) + 1;
+            let owner = request
+                .headers()
+                .get("x-tenant")
+                .and_then(|value| value.to_str().ok())
+                .map_or_else(|| request.uri().path().to_string(), ToString::to_string);
+            let counters = Arc::clone(&counters);
+            resolved.add_cleanup_task(Box::new(move || {
+                Box::pin(async move {
+                    counters.cleaned_up.fetch_add(1, Ordering::SeqCst);
+                })
+            }));
+            Box::pin(async move { Ok(Arc::new(format!("{owner}#{number}")) as Arc<dyn std::any::Any + Send + Sync>) })
+        })
+        .build()
+        .expect("factory");
+
+    let broken = FactoryDependency::builder("broken")
+        .factory(|_request, _data, _resolved| {
+            Box::pin(async {
+                Err(DependencyError::ResolutionFailed {
+                    message: "backend unavailable".to_string(),
+                })
+            })
+        })
+        .build()
+        .expect("factory");
+
+    let mut container = DependencyContainer::new();
+    container
+        .register("session".to_string(), Arc::new(session))
+        .expect("register");
+    container
+        .register("broken".to_string(), Arc::new(broken))
+        .expect("register");
+    Arc::new(container)
+}
+
+fn session(dependencies: &ResolvedDependencies) -> String {
+    dependencies
+        .get::<String>("session")
+        .map(|session| session.as_str().to_string())
+        .unwrap_or_default()
+}
+
+async fn eventually(check: impl Fn() -> bool + Send + Sync) {
+    timeout(Duration::from_secs(2), async {
+        while !check() {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("condition not reached in time");
+}
+
+struct SessionEcho;
+
+impl WebSocketHandler for SessionEcho {
+    async fn handle_message(&self, _message: Value) -> Option<Value> {
+        Some(json!({"session": null}))
+    }
+
+    async fn handle_message_with_dependencies(
+        &self,
+        message: Value,
+        dependencies: &ResolvedDependencies,
+    ) -> Option<Value> {
+        Some(json!({"echo": message, "session": session(dependencies)}))
+    }
+}
+
+#[tokio::test]
+async fn websocket_resolves_once_per_connection_and_cleans_up_on_close() {
+    let counters = Arc::new(Counters::default());
+    let declared = DeclaredDependencies::new(container(&counters), vec!["session".to_string()]);
+    let app = axum::Router::new()
+        .route("/ws", get(websocket_handler::<SessionEcho>))
+        .with_state(WebSocketState::new(SessionEcho).with_dependencies(declared));
+    let server = axum_test::TestServer::new_with_config(
+        app,
+        axum_test::TestServerConfig {
+            transport: Some(axum_test::Transport::HttpRandomPort),
+            ..axum_test::TestServerConfig::default()
+        },
+    );
+
+    let mut conn = connect_websocket(&server, "/ws").await;
+    conn.send_json(&json!(1)).await;
+    let first: Value = conn.receive_json().await;
+    conn.send_json(&json!(2)).await;
+    let second: Value = conn.receive_json().await;
+
+    assert_eq!(first, json!({"echo": 1, "session": "/ws#1"}));
+    assert_eq!(second, json!({"echo": 2, "session": "/ws#1"}));
+    assert_eq!(counters.cleaned_up.load(Ordering::SeqCst), 0);
+
+    conn.close().await.expect("close");
+    eventually(|| counters.cleaned_up.load(Ordering::SeqCst) == 1).await;
+    assert_eq!(counters.resolved.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn websocket_cleans_up_when_the_upgrade_never_completes() {
+    let counters = Arc::new(Counters::default());
+    let declared = DeclaredDependencies::new(container(&counters), vec!["session".to_string()]);
+    let app = axum::Router::new()
+        .route("/ws", get(websocket_handler::<SessionEcho>))
+        .with_state(WebSocketState::new(SessionEcho).with_dependencies(declared));
+
+    let mut request = Request::get("/ws")
+        .header("connection", "upgrade")
+        .header("upgrade", "websocket")
+        .header("sec-websocket-key", "dGhlIHNhbXBsZSBub25jZQ==")
+        .header("sec-websocket-version", "13")
+        .body(Body::empty())
+        .expect("request");
+    let on_upgrade = hyper::upgrade::on(&mut request);
+    request.extensions_mut().insert(on_upgrade);
+
+    let response = app.oneshot(request).await.expect("response");
+    assert_eq!(response.status(), StatusCode::SWITCHING_PROTOCOLS);
+    assert_eq!(counters.resolved.load(Ordering::SeqCst), 1);
+    eventually(|| counters.cleaned_up.load(Ordering::SeqCst) == 1).await;
+}
+
+struct SessionEvents {
+    remaining: AtomicUsize,
+}
+
+impl SseEventProducer for SessionEvents {
+    async fn next_event(&self) -> Option<SseEvent> {
+        None
+    }
+
+    async fn next_event_with_dependencies(&self, dependencies: &ResolvedDependencies) -> Option<SseEvent> {
+        let previous = self.remaining.fetch_sub(1, Ordering::SeqCst);
+        (previous > 0).then(|| SseEvent::new(json!({"session": session(dependencies)})))
+    }
+}
+
+fn sse_app(container: Arc<DependencyContainer>, keys: &[&str]) -> axum::Router {
+    let keys = keys.iter().map(ToString::to_string).collect();
+    let producer = SessionEvents {
+        remaining: AtomicUsize::new(2),
+    };
+    axum::Router::new()
+        .route("/events", get(sse_handler::<SessionEvents>))
+        .with_state(SseState::new(producer).with_dependencies(DeclaredDependencies::new(container, keys)))
+}
+
+#[tokio::test]
+async fn sse_resolves_once_per_stream_and_cleans_up_when_dropped() {
+    let counters = Arc::new(Counters::default());
+    let response = sse_app(container(&counters), &["session"])
+        .oneshot(Request::builder().uri("/events").body(Body::empty()).expect("request"))
+        .await
+        .expect("response");
+
+    let bytes = timeout(Duration::from_secs(5), response.into_body().collect())
+        .await
+        .expect("stream ended")
+        .expect("body")
+        .to_bytes();
+    let body = String::from_utf8_lossy(&bytes);
+
+    assert_eq!(body.matches(r#"data: {"session":"/events#1"}"#).count(), 2, "{body}");
+    eventually(|| counters.cleaned_up.load(Ordering::SeqCst) == 1).await;
+    assert_eq!(counters.resolved.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn sse_refuses_the_stream_when_resolution_fails() {
+    let counters = Arc::new(Counters::default());
+    let response = sse_app(container(&counters), &["missing"])
+        .oneshot(Request::builder().uri("/events").body(Body::empty()).expect("request"))
+        .await
+        .expect("response");
+
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    let bytes = response.into_body().collect().await.expect("body").to_bytes();
+    let problem: Value = serde_json::from_slice(&bytes).expect("problem details");
+    assert_eq!(problem["errors"][0]["dependency_key"], "missing");
+}
+
+struct SessionResult;
+
+impl Handler for SessionResult {
+    fn call(
+        &self,
+        _request: Request<Body>,
+        request_data: RequestData,
+    ) -> Pin<Box<dyn Future<Output = HandlerResult> + Send + '_>> {
+        Box::pin(async move {
+            let session = request_data.dependencies.as_deref().map(session);
+            Ok(axum::http::Response::builder()
+                .status(StatusCode::OK)
+                .header("content-type", "application/json")
+                .body(Body::from(json!({"session": session}).to_string()))
+                .expect("response"))
+        })
+    }
+}
+
+fn rpc_route(method_name: &str, dependencies: &[&str]) -> (Route, Arc<dyn Handler>) {
+    let route = Route {
+        method: Method::Post,
+        path: format!("/{method_name}"),
+        handler_name: method_name.to_string(),
+        expects_json_body: false,
+        cors: None,
+        is_async: true,
+        file_params: None,
+        request_validator: None,
+        response_validator: None,
+        parameter_validator: None,
+        jsonrpc_method: Some(JsonRpcMethodInfo {
+            method_name: method_name.to_string(),
+            description: None,
+            params_schema: None,
+            result_schema: None,
+            deprecated: false,
+            tags: Vec::new(),
+        }),
+        compression: None,
+        body_limit: None,
+        request_timeout_secs: None,
+        rate_limit: None,
+        caching: None,
+        response_cache: None,
+        auth: None,
+        handler_dependencies: dependencies.iter().map(ToString::to_string).collect(),
+    };
+    (route, Arc::new(SessionResult))
+}
+
+#[tokio::test]
+async fn jsonrpc_resolves_dependencies_per_call() {
+    let counters = Arc::new(Counters::default());
+    let config = ServerConfig {
+        di_container: Some(container(&counters)),
+        jsonrpc: Some(JsonRpcConfig {
+            enabled: true,
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let routes = vec![
+        rpc_route("session.get", &["session"]),
+        rpc_route("broken.get", &["broken"]),
+    ];
+    let app = build_router_with_handlers_and_config(routes, config, Vec::new()).expect("router");
+    let server = axum_test::TestServer::new(app);
+
+    for expected in ["/rpc#1", "/rpc#2"] {
+        let response: Value = server
+            .post("/rpc")
+            .json(&json!({"jsonrpc": "2.0", "method": "session.get", "id": 1}))
+            .await
+            .json();
+        assert_eq!(response["result"], json!({"session": expected}));
+    }
+    assert_eq!(counters.cleaned_up.load(Ordering::SeqCst), 2);
+
+    let response: Value = server
+        .post("/rpc")
+        .json(&json!({"jsonrpc": "2.0", "method": "broken.get", "id": 2}))
+        .await
+        .json();
+    assert_eq!(response["error"]["code"], -32603);
+    assert_eq!(response["error"]["message"], "Dependency resolution failed");
+    assert_eq!(response["error"]["data"]["errors"][0]["msg"], "backend unavailable");
+}
+
+struct SessionService;
+
+impl GrpcHandler for SessionService {
+    fn call(&self, request: GrpcRequestData) -> Pin<Box<dyn Future<Output = GrpcHandlerResult> + Send + '_>> {
+        Box::pin(async move {
+            let session = request.dependencies.as_deref().map(session).unwrap_or_default();
+            Ok(GrpcResponseData {
+                payload: session.into(),
+                metadata: tonic::metadata::MetadataMap::new(),
+            })
+        })
+    }
+
+    fn service_name(&self) -> &'static str {
+        "test.Sessions"
+    }
+}
+
+fn grpc_request(tenant: Option<&'static str>) -> GrpcRequestData {
+    let mut metadata = tonic::metadata::MetadataMap::new();
+    if let Some(tenant) = tenant {
+        metadata.insert("x-tenant", tenant.parse().expect("metadata value"));
+    }
+    GrpcRequestData {
+        service_name: "test.Sessions".to_string(),
+        method_name: "Get".to_string(),
+        payload: bytes::Bytes::new(),
+        metadata,
+        dependencies: None,
+    }
+}
+
+#[tokio::test]
+async fn grpc_calls_resolve_dependencies_from_metadata() {
+    let counters = Arc::new(Counters::default());
+    let handler = DependencyInjectingGrpcHandler::new(
+        Arc::new(SessionService),
+        DeclaredDependencies::new(container(&counters), vec!["session".to_string()]),
+    );
+
+    let tenant = handler.call(grpc_request(Some("acme"))).await.expect("call");
+    assert_eq!(tenant.payload, "acme#1");
+    let path = handler.call(grpc_request(None)).await.expect("call");
+    assert_eq!(path.payload, "/test.Sessions/Get#2");
+    assert_eq!(counters.cleaned_up.load(Ordering::SeqCst), 2);
+
+    let broken = DependencyInjectingGrpcHandler::new(
+        Arc::new(SessionService),
+        DeclaredDependencies::new(container(&counters), vec!["missing".to_string()]),
+    );
+    let status = broken.call(grpc_request(None)).await.expect_err("missing dependency");
+    assert_eq!(status.code(), tonic::Code::Internal);
+}
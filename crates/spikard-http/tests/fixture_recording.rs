@@ -0,0 +1,256 @@
+//! Recording live traffic into fixtures, replaying them, and bootstrapping `OpenAPI` from them.
+
+use axum::body::Body;
+use axum::http::{Method, Request, StatusCode};
+use serde_json::{Value, json};
+use spikard_codegen::openapi::{OpenApiOptions, fixtures_to_openapi, load_fixtures_from_dir};
+use spikard_http::recording::REDACTED;
+use spikard_http::server::build_router_with_handlers_and_config;
+use spikard_http::testing::{TestClient, load_recorded_fixtures, replay_fixture};
+use spikard_http::{
+    Handler, HandlerResult, RecordingConfig, RequestData, Route, RouteMetadata, SchemaRegistry, ServerConfig,
+};
+use std::future::Future;
+use std::path::Path;
+use std::pin::Pin;
+use std::sync::Arc;
+
+/// Returns the user named in the path; requires `authorization: Bearer secret`.
+struct GetUser {
+    name: &'static str,
+}
+
+impl Handler for GetUser {
+    fn call(
+        &self,
+        request: Request<Body>,
+        _request_data: RequestData,
+    ) -> Pin<Box<dyn Future<Output = HandlerResult> + Send + '_>> {
+        Box::pin(async move {
+            if request.headers().get("authorization").and_then(|v| v.to_str().ok()) != Some("Bearer secret") {
+                return Ok(json_response(
+                    StatusCode::UNAUTHORIZED,
+                    &json!({"error": "unauthorized"}),
+                ));
+            }
+            let id: u64 = request
+                .uri()
+                .path()
+                .rsplit('/')
+                .next()
+                .and_then(|id| id.parse().ok())
+                .unwrap_or_default();
+            Ok(json_response(
+                StatusCode::OK,
+                &json!({"id": id, "name": self.name, "request_id": uuid_like(id)}),
+            ))
+        })
+    }
+}
+
+/// Exchanges credentials for a session token.
+struct Login;
+
+impl Handler for Login {
+    fn call(
+        &self,
+        _request: Request<Body>,
+        request_data: RequestData,
+    ) -> Pin<Box<dyn Future<Output = HandlerResult> + Send + '_>> {
+        Box::pin(async move {
+            let body: Value = match &request_data.raw_body {
+                Some(raw) => serde_json::from_slice(raw).unwrap_or(Value::Null),
+                None => (*request_data.body).clone(),
+            };
+            Ok(json_response(
+                StatusCode::CREATED,
+                &json!({"username": body["username"], "token": format!("tok-{}", body["password"])}),
+            ))
+        })
+    }
+}
+
+fn uuid_like(seed: u64) -> String {
+    format!("{seed:08x}-0000-4000-8000-{:012x}", seed * 7919)
+}
+
+fn json_response(status: StatusCode, body: &Value) -> axum::http::Response<Body> {
+    axum::http::Response::builder()
+        .status(status)
+        .header("content-type", "application/json")
+        .header("x-request-time", "2026-10-19T12:00:00Z")
+        .body(Body::from(body.to_string()))
+        .expect("response")
+}
+
+fn metadata(method: &str, path: &str) -> RouteMetadata {
+    serde_json::from_value(json!({
+        "method": method,
+        "path": path,
+        "handler_name": format!("{method} {path}"),
+        "is_async": true,
+        "cors": null
+    }))
+    .expect("route metadata")
+}
+
+fn app_client(user_name: &'static str, recording: Option<RecordingConfig>) -> TestClient {
+    let registry = SchemaRegistry::new();
+    let routes: Vec<(RouteMetadata, Arc<dyn Handler>)> = vec![
+        (metadata("GET", "/users/{id}"), Arc::new(GetUser { name: user_name })),
+        (metadata("POST", "/login"), Arc::new(Login)),
+    ];
+    let metadata = routes.iter().map(|(metadata, _)| metadata.clone()).collect();
+    let routes = routes
+        .into_iter()
+        .map(|(metadata, handler)| (Route::from_metadata(metadata, &registry).expect("route"), handler))
+        .collect();
+    let config = ServerConfig {
+        recording,
+        ..ServerConfig::default()
+    };
+    let app = build_router_with_handlers_and_config(routes, config, metadata).expect("router");
+    TestClient::from_router(app).expect("client")
+}
+
+fn recording_config(dir: &Path) -> RecordingConfig {
+    RecordingConfig {
+        output_dir: dir.display().to_string(),
+        redact_body_fields: vec!["/password".to_string(), "/token".to_string(), "/request_id".to_string()],
+        ..RecordingConfig::default()
+    }
+}
+
+async fn record_traffic(dir: &Path) {
+    let client = app_client("Ada", Some(recording_config(dir)));
+    for id in [1, 2] {
+        let response = client
+            .request_raw(
+                Method::GET,
+                &format!("/users/{id}"),
+                Vec::new().into(),
+                Some(vec![("expand".to_string(), "teams".to_string())]),
+                Some(vec![
+                    ("authorization".to_string(), "Bearer secret".to_string()),
+                    ("cookie".to_string(), "session=abc123".to_string()),
+                ]),
+            )
+            .await
+            .expect("get user");
+        assert_eq!(response.status, 200);
+    }
+    let response = client
+        .request_raw(
+            Method::POST,
+            "/login",
+            json!({"username": "ada", "password": "hunter2"}).to_string().into(),
+            None,
+            Some(vec![("content-type".to_string(), "application/json".to_string())]),
+        )
+        .await
+        .expect("login");
+    assert_eq!(response.status, 201);
+}
+
+#[tokio::test]
+async fn traffic_is_recorded_as_redacted_fixtures() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    record_traffic(dir.path()).await;
+
+    let mut files: Vec<String> = std::fs::read_dir(dir.path())
+        .expect("read dir")
+        .map(|entry| entry.expect("entry").file_name().to_string_lossy().into_owned())
+        .collect();
+    files.sort();
+    assert_eq!(
+        files,
+        [
+            "get_users_id_200.json",
+            "get_users_id_200_2.json",
+            "post_login_201.json"
+        ]
+    );
+
+    let user: Value =
+        serde_json::from_str(&std::fs::read_to_string(dir.path().join("get_users_id_200.json")).expect("read"))
+            .expect("json");
+    assert_eq!(user["name"], "get_users_id_200");
+    assert_eq!(user["handler"], json!({"route": "/users/{id}", "method": "GET"}));
+    assert_eq!(user["request"]["path"], "/users/1");
+    assert_eq!(user["request"]["query_params"], json!({"expand": "teams"}));
+    assert_eq!(user["request"]["headers"]["authorization"], REDACTED);
+    assert_eq!(user["request"]["cookies"], json!({"session": REDACTED}));
+    assert_eq!(
+        user["expected_response"],
+        json!({
+            "status_code": 200,
+            "body": {"id": 1, "name": "Ada", "request_id": REDACTED},
+            "headers": {"content-type": "application/json"}
+        })
+    );
+
+    let login: Value =
+        serde_json::from_str(&std::fs::read_to_string(dir.path().join("post_login_201.json")).expect("read"))
+            .expect("json");
+    assert_eq!(
+        login["request"]["body"],
+        json!({"username": "ada", "password": REDACTED})
+    );
+    assert_eq!(login["request"]["content_type"], "application/json");
+    assert_eq!(login["expected_response"]["body"]["token"], REDACTED);
+}
+
+#[tokio::test]
+async fn recorded_fixtures_replay_as_regression_tests() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    record_traffic(dir.path()).await;
+    let fixtures = load_recorded_fixtures(dir.path()).expect("fixtures");
+    assert_eq!(fixtures.len(), 3);
+
+    let auth = [("authorization".to_string(), "Bearer secret".to_string())];
+    let client = app_client("Ada", None);
+    for fixture in &fixtures {
+        let outcome = replay_fixture(&client, fixture, &auth).await.expect("replay");
+        assert!(outcome.is_success(), "{}: {:?}", outcome.fixture, outcome.mismatches);
+    }
+
+    let unauthenticated = replay_fixture(&client, &fixtures[0], &[]).await.expect("replay");
+    assert_eq!(unauthenticated.status, 401);
+    assert!(
+        unauthenticated
+            .mismatches
+            .contains(&"status: expected 200, got 401".to_string())
+    );
+
+    let regressed = app_client("Grace", None);
+    let outcome = replay_fixture(&regressed, &fixtures[0], &auth).await.expect("replay");
+    assert_eq!(outcome.mismatches, [r#"body /name: expected "Ada", got "Grace""#]);
+}
+
+#[tokio::test]
+async fn recorded_fixtures_bootstrap_an_openapi_spec() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    record_traffic(dir.path()).await;
+
+    let fixtures = load_fixtures_from_dir(dir.path()).expect("codegen fixtures");
+    assert_eq!(fixtures.len(), 3);
+    let spec = fixtures_to_openapi(&fixtures, OpenApiOptions::default()).expect("spec");
+    let spec = serde_json::to_value(&spec).expect("spec json");
+
+    let get_user = &spec["paths"]["/users/{id}"]["get"];
+    assert_eq!(
+        get_user["responses"]["200"]["content"]["application/json"]["example"]["name"],
+        "Ada"
+    );
+    let parameters: Vec<(&str, &str)> = get_user["parameters"]
+        .as_array()
+        .expect("parameters")
+        .iter()
+        .map(|parameter| (parameter["name"].as_str().unwrap(), parameter["in"].as_str().unwrap()))
+        .collect();
+    assert_eq!(parameters, [("id", "path"), ("expand", "query")]);
+
+    let login_body = &spec["paths"]["/login"]["post"]["requestBody"]["content"]["application/json"];
+    assert_eq!(login_body["schema"]["properties"]["username"]["type"], "string");
+    assert_eq!(login_body["example"]["password"], REDACTED);
+}
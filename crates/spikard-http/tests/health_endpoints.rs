@@ -0,0 +1,205 @@
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use spikard_http::server::build_router_with_handlers_and_config;
+use spikard_http::{
+    ApiKeyConfig, CheckStatus, DrainSignal, Handler, HandlerResult, HealthChecks, HealthConfig, Method,
+    RateLimitConfig, ReadinessReport, ReadinessStatus, RequestData, Route, ServerConfig, health_check,
+};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+struct OkHandler;
+
+impl Handler for OkHandler {
+    fn call(
+        &self,
+        _request: Request<Body>,
+        _request_data: RequestData,
+    ) -> Pin<Box<dyn Future<Output = HandlerResult> + Send + '_>> {
+        Box::pin(async {
+            Ok(axum::http::Response::builder()
+                .status(StatusCode::OK)
+                .body(Body::from("{}"))
+                .expect("response"))
+        })
+    }
+}
+
+fn route(path: &str) -> (Route, Arc<dyn Handler>) {
+    let route = Route {
+        method: Method::Get,
+        path: path.to_string(),
+        handler_name: "handler".to_string(),
+        expects_json_body: false,
+        cors: None,
+        is_async: true,
+        file_params: None,
+        request_validator: None,
+        response_validator: None,
+        parameter_validator: None,
+        jsonrpc_method: None,
+        compression: None,
+        body_limit: None,
+        request_timeout_secs: None,
+        rate_limit: None,
+        caching: None,
+        response_cache: None,
+        auth: None,
+        #[cfg(feature = "di")]
+        handler_dependencies: Vec::new(),
+    };
+    (route, Arc::new(OkHandler))
+}
+
+fn router(config: ServerConfig) -> axum::Router {
+    build_router_with_handlers_and_config(vec![route("/orders")], config, Vec::new()).expect("router")
+}
+
+fn config_with(checks: HealthChecks) -> ServerConfig {
+    ServerConfig {
+        health: Some(HealthConfig::default()),
+        health_checks: Some(Arc::new(checks)),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn health_endpoints_bypass_auth_and_rate_limiting() {
+    let config = ServerConfig {
+        health: Some(HealthConfig::default()),
+        api_key_auth: Some(ApiKeyConfig {
+            keys: vec!["secret".to_string()],
+            header_name: "X-API-Key".to_string(),
+        }),
+        rate_limit: Some(RateLimitConfig {
+            per_second: 1,
+            burst: 1,
+            ip_based: false,
+            key: None,
+        }),
+        ..Default::default()
+    };
+    let server = axum_test::TestServer::new(router(config));
+
+    for _ in 0..3 {
+        let live = server.get("/healthz").await;
+        assert_eq!(live.status_code(), StatusCode::OK);
+        assert_eq!(live.json::<serde_json::Value>(), serde_json::json!({"status": "ok"}));
+        assert_eq!(server.get("/readyz").await.status_code(), StatusCode::OK);
+    }
+    assert_eq!(server.get("/orders").await.status_code(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn readiness_reports_each_check_and_fails_when_one_fails() {
+    let mut checks = HealthChecks::new();
+    checks.add(health_check("database", |_ctx| async { Ok(()) }));
+    checks.add_with_timeout(
+        health_check("search", |_ctx| async {
+            tokio::time::sleep(Duration::from_secs(5)).await;
+            Ok(())
+        }),
+        Duration::from_millis(20),
+    );
+    let server = axum_test::TestServer::new(router(config_with(checks)));
+
+    let response = server.get("/readyz").await;
+    assert_eq!(response.status_code(), StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(response.header("content-type"), "application/json");
+    let report: ReadinessReport = response.json();
+    assert_eq!(report.status, ReadinessStatus::NotReady);
+    assert_eq!(report.checks["database"].status, CheckStatus::Ok);
+    assert_eq!(report.checks["search"].status, CheckStatus::Timeout);
+}
+
+#[tokio::test]
+async fn readiness_flips_when_draining() {
+    let mut checks = HealthChecks::new();
+    checks.add(health_check("database", |_ctx| async { Ok(()) }));
+    let drain = DrainSignal::new();
+    let server = axum_test::TestServer::new(drain.attach(router(config_with(checks))));
+
+    assert_eq!(server.get("/readyz").await.status_code(), StatusCode::OK);
+
+    drain.begin();
+    let response = server.get("/readyz").await;
+    assert_eq!(response.status_code(), StatusCode::SERVICE_UNAVAILABLE);
+    let report: ReadinessReport = response.json();
+    assert_eq!(report.status, ReadinessStatus::ShuttingDown);
+    assert_eq!(server.get("/healthz").await.status_code(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn paths_are_configurable_and_endpoints_are_opt_in() {
+    let disabled = axum_test::TestServer::new(router(ServerConfig::default()));
+    assert_eq!(disabled.get("/healthz").await.status_code(), StatusCode::NOT_FOUND);
+
+    let config = ServerConfig {
+        health: Some(HealthConfig {
+            liveness_path: "/_/live".to_string(),
+            readiness_path: "/_/ready".to_string(),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let server = axum_test::TestServer::new(router(config));
+    assert_eq!(server.get("/_/live").await.status_code(), StatusCode::OK);
+    assert_eq!(server.get("/_/ready").await.status_code(), StatusCode::OK);
+}
+
+#[cfg(feature = "di")]
+#[tokio::test]
+async fn checks_receive_resolved_dependencies() {
+    use spikard_core::di::{DependencyContainer, DependencyError, FactoryDependency, ValueDependency};
+    use spikard_http::HealthCheckContext;
+
+    let mut container = DependencyContainer::new();
+    container
+        .register(
+            "database_url".to_string(),
+            Arc::new(ValueDependency::new("database_url", "postgres://db".to_string())),
+        )
+        .expect("register");
+    let queue_url = FactoryDependency::builder("queue_url")
+        .factory(|_request, _data, _resolved| {
+            Box::pin(async {
+                Err(DependencyError::ResolutionFailed {
+                    message: "queue unreachable".to_string(),
+                })
+            })
+        })
+        .build()
+        .expect("factory");
+    container
+        .register("queue_url".to_string(), Arc::new(queue_url))
+        .expect("register");
+
+    let mut checks = HealthChecks::new();
+    checks.add_with_dependencies(
+        health_check("database", |ctx: HealthCheckContext| async move {
+            match ctx.get::<String>("database_url") {
+                Some(url) if url.starts_with("postgres://") => Ok(()),
+                _ => Err("database_url missing".to_string()),
+            }
+        }),
+        vec!["database_url".to_string()],
+        Duration::from_secs(1),
+    );
+    checks.add_with_dependencies(
+        health_check("queue", |_ctx| async { Ok(()) }),
+        vec!["queue_url".to_string()],
+        Duration::from_secs(1),
+    );
+    let config = ServerConfig {
+        di_container: Some(Arc::new(container)),
+        ..config_with(checks)
+    };
+    let server = axum_test::TestServer::new(router(config));
+
+    let report: ReadinessReport = server.get("/readyz").await.json();
+    assert_eq!(report.checks["database"].status, CheckStatus::Ok);
+    assert_eq!(report.checks["queue"].status, CheckStatus::Error);
+    assert_eq!(report.status, ReadinessStatus::NotReady);
+}
@@ -0,0 +1,102 @@
+#![cfg(feature = "di")]
+
+use spikard_core::di::DependencyContainer;
+use spikard_http::server::Server;
+use spikard_http::{LifespanContext, LifespanError, LifespanHooks, LifespanPhase, ServerConfig, lifespan_hook};
+use std::sync::Arc;
+
+#[derive(Clone)]
+struct Pool {
+    url: String,
+}
+
+fn config_with(hooks: LifespanHooks) -> ServerConfig {
+    ServerConfig {
+        lifespan: Some(Arc::new(hooks)),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn startup_values_become_singleton_dependencies() {
+    let mut hooks = LifespanHooks::new();
+    hooks.add_startup(lifespan_hook("connect", |ctx: LifespanContext| async move {
+        ctx.provide(
+            "pool",
+            Pool {
+                url: "postgres://localhost".to_string(),
+            },
+        );
+        Ok(())
+    }));
+    let mut config = config_with(hooks);
+
+    Server::startup(&mut config).await.expect("startup succeeds");
+
+    let container = config.di_container.as_ref().expect("container created");
+    assert!(container.contains("pool"));
+    let lifespan = config.lifespan.as_ref().expect("lifespan");
+    assert!(lifespan.has_started());
+    let pool = lifespan.context().get::<Pool>("pool").expect("value kept in context");
+    assert_eq!(pool.url, "postgres://localhost");
+}
+
+#[tokio::test]
+async fn startup_values_extend_existing_container() {
+    let mut hooks = LifespanHooks::new();
+    hooks.add_startup(lifespan_hook("cache", |ctx: LifespanContext| async move {
+        ctx.provide("cache", 42_u32);
+        Ok(())
+    }));
+    let mut config = config_with(hooks);
+    let mut container = DependencyContainer::new();
+    container
+        .register(
+            "settings".to_string(),
+            Arc::new(spikard_core::di::ValueDependency::new("settings", "on".to_string())),
+        )
+        .expect("register settings");
+    config.di_container = Some(Arc::new(container));
+
+    Server::startup(&mut config).await.expect("startup succeeds");
+
+    let container = config.di_container.as_ref().expect("container");
+    assert!(container.contains("settings"));
+    assert!(container.contains("cache"));
+}
+
+#[tokio::test]
+async fn failing_startup_hook_aborts_boot() {
+    let mut hooks = LifespanHooks::new();
+    hooks.add_startup(lifespan_hook("connect", |_ctx| async {
+        Err("connection refused".to_string())
+    }));
+    let mut config = config_with(hooks);
+
+    let err = Server::startup(&mut config).await.expect_err("startup must fail");
+    assert!(matches!(
+        err,
+        LifespanError::Failed {
+            phase: LifespanPhase::Startup,
+            ..
+        }
+    ));
+    assert_eq!(err.to_string(), "startup hook 'connect' failed: connection refused");
+    assert!(config.di_container.is_none());
+}
+
+#[tokio::test]
+async fn shared_container_is_reported() {
+    let mut hooks = LifespanHooks::new();
+    hooks.add_startup(lifespan_hook("cache", |ctx: LifespanContext| async move {
+        ctx.provide("cache", 1_u8);
+        Ok(())
+    }));
+    let mut config = config_with(hooks);
+    let container = Arc::new(DependencyContainer::new());
+    config.di_container = Some(Arc::clone(&container));
+
+    let err = Server::startup(&mut config).await.expect_err("container is shared");
+    assert!(matches!(err, LifespanError::Dependencies(_)));
+    assert!(config.di_container.is_some());
+}
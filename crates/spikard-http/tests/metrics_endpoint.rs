@@ -0,0 +1,215 @@
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use spikard_http::background::BackgroundRuntime;
+use spikard_http::metrics::PROMETHEUS_CONTENT_TYPE;
+use spikard_http::server::build_router_with_handlers_and_config;
+use spikard_http::{
+    BackgroundJobError, BackgroundTaskConfig, Handler, HandlerResult, JsonRpcConfig, JsonRpcMethodInfo, Method,
+    MetricsConfig, MetricsRegistry, RequestData, Route, ServerConfig,
+};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+struct StatusHandler(StatusCode);
+
+impl Handler for StatusHandler {
+    fn call(
+        &self,
+        _request: Request<Body>,
+        _request_data: RequestData,
+    ) -> Pin<Box<dyn Future<Output = HandlerResult> + Send + '_>> {
+        let status = self.0;
+        Box::pin(async move {
+            if status.is_success() {
+                Ok(axum::http::Response::builder()
+                    .status(status)
+                    .body(Body::from("{}"))
+                    .expect("response"))
+            } else {
+                Err((status, "failed".to_string()))
+            }
+        })
+    }
+}
+
+fn route(method: Method, path: &str, jsonrpc_method: Option<&str>, status: StatusCode) -> (Route, Arc<dyn Handler>) {
+    let route = Route {
+        method,
+        path: path.to_string(),
+        handler_name: "handler".to_string(),
+        expects_json_body: false,
+        cors: None,
+        is_async: true,
+        file_params: None,
+        request_validator: None,
+        response_validator: None,
+        parameter_validator: None,
+        jsonrpc_method: jsonrpc_method.map(|name| JsonRpcMethodInfo {
+            method_name: name.to_string(),
+            description: None,
+            params_schema: None,
+            result_schema: None,
+            deprecated: false,
+            tags: Vec::new(),
+        }),
+        compression: None,
+        body_limit: None,
+        request_timeout_secs: None,
+        rate_limit: None,
+        caching: None,
+        response_cache: None,
+        auth: None,
+        #[cfg(feature = "di")]
+        handler_dependencies: Vec::new(),
+    };
+    (route, Arc::new(StatusHandler(status)))
+}
+
+fn server(routes: Vec<(Route, Arc<dyn Handler>)>, config: ServerConfig) -> axum_test::TestServer {
+    let app = build_router_with_handlers_and_config(routes, config, Vec::new()).expect("router");
+    axum_test::TestServer::new(app)
+}
+
+fn metrics_config() -> ServerConfig {
+    ServerConfig {
+        metrics: Some(MetricsConfig::default()),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn http_requests_are_labeled_by_route_template() {
+    let server = server(
+        vec![
+            route(Method::Get, "/users/{id}", None, StatusCode::OK),
+            route(Method::Post, "/users", None, StatusCode::BAD_REQUEST),
+        ],
+        metrics_config(),
+    );
+
+    server.get("/users/1").await;
+    server.get("/users/2").await;
+    server.post("/users").await;
+    server.get("/nowhere").await;
+
+    let response = server.get("/metrics").await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(response.header("content-type"), PROMETHEUS_CONTENT_TYPE);
+    let text = response.text();
+    assert!(text.contains("spikard_http_requests_total{method=\"GET\",route=\"/users/{id}\",status=\"200\"} 2\n"));
+    assert!(text.contains("spikard_http_requests_total{method=\"POST\",route=\"/users\",status=\"400\"} 1\n"));
+    assert!(text.contains(
+        "spikard_http_request_duration_seconds_count{method=\"GET\",route=\"/users/{id}\",status=\"200\"} 2\n"
+    ));
+    assert!(text.contains("spikard_http_requests_total{method=\"GET\",route=\"unmatched\",status=\"404\"} 1\n"));
+    assert!(!text.contains("route=\"/users/1\""));
+    assert!(!text.contains("route=\"/metrics\""));
+}
+
+#[tokio::test]
+async fn metrics_endpoint_is_opt_in_and_path_is_configurable() {
+    let disabled = server(
+        vec![route(Method::Get, "/ping", None, StatusCode::OK)],
+        ServerConfig::default(),
+    );
+    assert_eq!(disabled.get("/metrics").await.status_code(), StatusCode::NOT_FOUND);
+
+    let config = ServerConfig {
+        metrics: Some(MetricsConfig {
+            path: "/internal/metrics".to_string(),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let custom = server(vec![route(Method::Get, "/ping", None, StatusCode::OK)], config);
+    custom.get("/ping").await;
+    let text = custom.get("/internal/metrics").await.text();
+    assert!(text.contains("route=\"/ping\""));
+}
+
+#[tokio::test]
+async fn jsonrpc_calls_are_recorded_per_method() {
+    let config = ServerConfig {
+        jsonrpc: Some(JsonRpcConfig::default()),
+        ..metrics_config()
+    };
+    let server = server(
+        vec![
+            route(Method::Post, "/rpc/add", Some("math.add"), StatusCode::OK),
+            route(
+                Method::Post,
+                "/rpc/fail",
+                Some("math.fail"),
+                StatusCode::INTERNAL_SERVER_ERROR,
+            ),
+        ],
+        config,
+    );
+
+    for method in ["math.add", "math.add", "math.fail"] {
+        server
+            .post("/rpc")
+            .json(&serde_json::json!({"jsonrpc": "2.0", "method": method, "params": {}, "id": 1}))
+            .await;
+    }
+
+    let text = server.get("/metrics").await.text();
+    assert!(text.contains("spikard_jsonrpc_requests_total{method=\"math.add\",outcome=\"success\"} 2\n"));
+    assert!(text.contains("spikard_jsonrpc_requests_total{method=\"math.fail\",outcome=\"error\"} 1\n"));
+    assert!(text.contains("spikard_jsonrpc_request_duration_seconds_count{method=\"math.add\"} 2\n"));
+}
+
+#[tokio::test]
+async fn custom_metrics_and_background_tasks_share_the_registry() {
+    let registry = Arc::new(MetricsRegistry::new());
+    let orders = registry
+        .counter("shop_orders_total", "Orders placed", &["region"])
+        .expect("counter");
+    orders.inc(&["eu"]);
+
+    let runtime = BackgroundRuntime::start(BackgroundTaskConfig::default()).await;
+    registry.track_background(runtime.handle());
+    runtime
+        .handle()
+        .spawn(|| async { Err(BackgroundJobError::from("boom")) })
+        .expect("spawn");
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    let config = ServerConfig {
+        metrics_registry: Some(Arc::clone(&registry)),
+        ..metrics_config()
+    };
+    let server = server(Vec::new(), config);
+
+    let text = server.get("/metrics").await.text();
+    assert!(text.contains("# TYPE shop_orders_total counter\n"));
+    assert!(text.contains("shop_orders_total{region=\"eu\"} 1\n"));
+    assert!(text.contains("spikard_background_tasks_failed_total 1\n"));
+    assert!(text.contains("spikard_background_tasks_queued 0\n"));
+    assert!(text.contains("spikard_background_tasks_running 0\n"));
+
+    // Repeated scrapes add only the failures that happened in between.
+    assert!(
+        server
+            .get("/metrics")
+            .await
+            .text()
+            .contains("spikard_background_tasks_failed_total 1\n")
+    );
+    runtime
+        .handle()
+        .spawn(|| async { Err(BackgroundJobError::from("boom")) })
+        .expect("spawn");
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert!(
+        server
+            .get("/metrics")
+            .await
+            .text()
+            .contains("spikard_background_tasks_failed_total 2\n")
+    );
+
+    runtime.shutdown().await.expect("shutdown");
+}
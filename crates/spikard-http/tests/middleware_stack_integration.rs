@@ -142,7 +142,7 @@ async fn test_rate_limit_allows_requests_below_threshold() {
 /// When rate limit is exceeded (100 requests/sec), the 101st request
 /// should receive 429 Too Many Requests.
 ///
-/// Note: Actual rate limiting is handled by tower_governor layer.
+/// Note: Actual rate limiting is handled by the rate limit middleware layer.
 /// This test demonstrates the expected behavior when limit is exceeded.
 #[tokio::test]
 async fn test_rate_limit_blocks_requests_above_threshold() {
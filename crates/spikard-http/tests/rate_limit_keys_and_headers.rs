@@ -0,0 +1,334 @@
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use jsonwebtoken::{EncodingKey, Header, encode};
+use spikard_http::rate_limit::{RateLimitFuture, TYPE_RATE_LIMITED};
+use spikard_http::server::build_router_with_handlers_and_config;
+use spikard_http::{
+    Claims, Handler, HandlerResult, InMemoryRateLimitStore, JwtConfig, Method, RateLimitConfig, RateLimitKey,
+    RateLimitKeyExtractor, RateLimitQuota, RateLimitStore, RequestData, Route, ServerConfig,
+};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+struct OkHandler;
+
+impl Handler for OkHandler {
+    fn call(
+        &self,
+        _request: Request<Body>,
+        _request_data: RequestData,
+    ) -> Pin<Box<dyn Future<Output = HandlerResult> + Send + '_>> {
+        Box::pin(async move {
+            Ok(axum::http::Response::builder()
+                .status(StatusCode::OK)
+                .body(Body::from("ok"))
+                .expect("response"))
+        })
+    }
+}
+
+fn route(path: &str, rate_limit: Option<RateLimitConfig>) -> (Route, Arc<dyn Handler>) {
+    let route = Route {
+        method: Method::Get,
+        path: path.to_string(),
+        handler_name: "ok".to_string(),
+        expects_json_body: false,
+        cors: None,
+        is_async: true,
+        file_params: None,
+        request_validator: None,
+        response_validator: None,
+        parameter_validator: None,
+        jsonrpc_method: None,
+        compression: None,
+        body_limit: None,
+        request_timeout_secs: None,
+        rate_limit,
+        caching: None,
+        response_cache: None,
+        auth: None,
+        #[cfg(feature = "di")]
+        handler_dependencies: Vec::new(),
+    };
+    (route, Arc::new(OkHandler))
+}
+
+const fn limit(burst: u32, key: RateLimitKey) -> RateLimitConfig {
+    RateLimitConfig {
+        per_second: 1,
+        burst,
+        ip_based: true,
+        key: Some(key),
+    }
+}
+
+fn server(routes: Vec<(Route, Arc<dyn Handler>)>, config: ServerConfig) -> axum_test::TestServer {
+    let app = build_router_with_handlers_and_config(routes, config, Vec::new()).expect("router");
+    axum_test::TestServer::new(app.into_make_service_with_connect_info::<std::net::SocketAddr>())
+}
+
+#[tokio::test]
+async fn global_limit_emits_headers_and_problem_details() {
+    let config = ServerConfig {
+        rate_limit: Some(limit(2, RateLimitKey::Ip)),
+        ..Default::default()
+    };
+    let server = server(vec![route("/items", None)], config);
+
+    let first = server.get("/items").await;
+    assert_eq!(first.status_code(), StatusCode::OK);
+    assert_eq!(first.header("ratelimit-limit"), "2");
+    assert_eq!(first.header("ratelimit-remaining"), "1");
+    assert_eq!(first.header("ratelimit-reset"), "1");
+
+    let second = server.get("/items").await;
+    assert_eq!(second.header("ratelimit-remaining"), "0");
+
+    let rejected = server.get("/items").await;
+    assert_eq!(rejected.status_code(), StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(rejected.header("retry-after"), "1");
+    assert_eq!(rejected.header("ratelimit-remaining"), "0");
+    assert!(
+        rejected
+            .header("content-type")
+            .to_str()
+            .unwrap()
+            .starts_with("application/problem+json")
+    );
+    let body: serde_json::Value = rejected.json();
+    assert_eq!(body["type"], TYPE_RATE_LIMITED);
+    assert_eq!(body["status"], 429);
+    assert_eq!(body["retry_after"], 1);
+}
+
+#[tokio::test]
+async fn route_limit_applies_only_to_its_route() {
+    let server = server(
+        vec![
+            route("/limited", Some(limit(1, RateLimitKey::Global))),
+            route("/open", None),
+        ],
+        ServerConfig::default(),
+    );
+
+    assert_eq!(server.get("/limited").await.status_code(), StatusCode::OK);
+    assert_eq!(
+        server.get("/limited").await.status_code(),
+        StatusCode::TOO_MANY_REQUESTS
+    );
+
+    let open = server.get("/open").await;
+    assert_eq!(open.status_code(), StatusCode::OK);
+    assert!(open.maybe_header("ratelimit-limit").is_none());
+}
+
+#[tokio::test]
+async fn stricter_route_limit_wins_response_headers() {
+    let config = ServerConfig {
+        rate_limit: Some(limit(100, RateLimitKey::Ip)),
+        ..Default::default()
+    };
+    let server = server(vec![route("/strict", Some(limit(3, RateLimitKey::Ip)))], config);
+
+    let response = server.get("/strict").await;
+    assert_eq!(response.header("ratelimit-limit"), "3");
+    assert_eq!(response.header("ratelimit-remaining"), "2");
+}
+
+#[tokio::test]
+async fn header_and_api_key_keys_isolate_clients() {
+    let server = server(
+        vec![
+            route(
+                "/tenant",
+                Some(limit(
+                    1,
+                    RateLimitKey::Header {
+                        name: "x-tenant".to_string(),
+                    },
+                )),
+            ),
+            route("/keyed", Some(limit(1, RateLimitKey::ApiKey { header: None }))),
+        ],
+        ServerConfig::default(),
+    );
+
+    assert_eq!(
+        server.get("/tenant").add_header("x-tenant", "a").await.status_code(),
+        StatusCode::OK
+    );
+    assert_eq!(
+        server.get("/tenant").add_header("x-tenant", "b").await.status_code(),
+        StatusCode::OK
+    );
+    assert_eq!(
+        server.get("/tenant").add_header("x-tenant", "a").await.status_code(),
+        StatusCode::TOO_MANY_REQUESTS
+    );
+
+    assert_eq!(
+        server.get("/keyed").add_header("x-api-key", "k1").await.status_code(),
+        StatusCode::OK
+    );
+    assert_eq!(
+        server.get("/keyed").add_header("x-api-key", "k2").await.status_code(),
+        StatusCode::OK
+    );
+    assert_eq!(
+        server.get("/keyed").add_header("x-api-key", "k1").await.status_code(),
+        StatusCode::TOO_MANY_REQUESTS
+    );
+}
+
+#[tokio::test]
+async fn jwt_subject_key_uses_validated_claims() {
+    let secret = "secret";
+    let config = ServerConfig {
+        jwt_auth: Some(JwtConfig {
+            secret: secret.to_string(),
+            algorithm: "HS256".to_string(),
+            audience: None,
+            issuer: None,
+            leeway: 0,
+        }),
+        rate_limit: Some(limit(1, RateLimitKey::JwtSubject)),
+        ..Default::default()
+    };
+    let server = server(vec![route("/me", None)], config);
+
+    let exp = SystemTime::now().duration_since(UNIX_EPOCH).expect("time") + Duration::from_mins(1);
+    let token_for = |sub: &str| {
+        let claims = Claims {
+            sub: sub.to_string(),
+            exp: usize::try_from(exp.as_secs()).expect("timestamp fits usize"),
+            iat: None,
+            nbf: None,
+            aud: None,
+            iss: None,
+        };
+        let token = encode(
+            &Header::default(),
+            &claims,
+            &EncodingKey::from_secret(secret.as_bytes()),
+        )
+        .expect("token");
+        format!("Bearer {token}")
+    };
+
+    let alice = token_for("alice");
+    let bob = token_for("bob");
+    assert_eq!(
+        server
+            .get("/me")
+            .add_header("authorization", &alice)
+            .await
+            .status_code(),
+        StatusCode::OK
+    );
+    assert_eq!(
+        server.get("/me").add_header("authorization", &bob).await.status_code(),
+        StatusCode::OK
+    );
+    assert_eq!(
+        server
+            .get("/me")
+            .add_header("authorization", &alice)
+            .await
+            .status_code(),
+        StatusCode::TOO_MANY_REQUESTS
+    );
+}
+
+#[tokio::test]
+async fn custom_key_uses_registered_extractor() {
+    let extractor = |request: &Request<Body>| {
+        request
+            .uri()
+            .query()
+            .and_then(|query| query.strip_prefix("user="))
+            .map(str::to_string)
+    };
+    let config = ServerConfig {
+        rate_limit: Some(limit(1, RateLimitKey::Custom)),
+        rate_limit_key_extractor: Some(Arc::new(extractor) as Arc<dyn RateLimitKeyExtractor>),
+        ..Default::default()
+    };
+    let server = server(vec![route("/custom", None)], config);
+
+    assert_eq!(server.get("/custom?user=1").await.status_code(), StatusCode::OK);
+    assert_eq!(server.get("/custom?user=2").await.status_code(), StatusCode::OK);
+    assert_eq!(
+        server.get("/custom?user=1").await.status_code(),
+        StatusCode::TOO_MANY_REQUESTS
+    );
+}
+
+#[test]
+fn custom_key_without_extractor_is_rejected() {
+    let config = ServerConfig {
+        rate_limit: Some(limit(1, RateLimitKey::Custom)),
+        ..Default::default()
+    };
+    let err = build_router_with_handlers_and_config(vec![route("/custom", None)], config, Vec::new())
+        .expect_err("custom key requires an extractor");
+    assert!(err.contains("key extractor"));
+}
+
+/// Store that counts calls and delegates to an in-memory store, standing in for a shared backend.
+#[derive(Default)]
+struct CountingStore {
+    calls: AtomicUsize,
+    inner: InMemoryRateLimitStore,
+}
+
+impl RateLimitStore for CountingStore {
+    fn check<'a>(&'a self, key: &'a str, quota: RateLimitQuota) -> RateLimitFuture<'a> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        self.inner.check(key, quota)
+    }
+}
+
+/// Store whose backend is unavailable; requests must fail open.
+struct FailingStore;
+
+impl RateLimitStore for FailingStore {
+    fn check<'a>(&'a self, _key: &'a str, _quota: RateLimitQuota) -> RateLimitFuture<'a> {
+        Box::pin(async { Err("backend unavailable".to_string()) })
+    }
+}
+
+#[tokio::test]
+async fn configured_store_is_shared_by_routers() {
+    let store = Arc::new(CountingStore::default());
+    let config = || ServerConfig {
+        rate_limit: Some(limit(1, RateLimitKey::Global)),
+        rate_limit_store: Some(Arc::clone(&store) as Arc<dyn RateLimitStore>),
+        ..Default::default()
+    };
+
+    let first = server(vec![route("/shared", None)], config());
+    let second = server(vec![route("/shared", None)], config());
+
+    assert_eq!(first.get("/shared").await.status_code(), StatusCode::OK);
+    assert_eq!(second.get("/shared").await.status_code(), StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(store.calls.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn store_errors_fail_open() {
+    let config = ServerConfig {
+        rate_limit: Some(limit(1, RateLimitKey::Global)),
+        rate_limit_store: Some(Arc::new(FailingStore) as Arc<dyn RateLimitStore>),
+        ..Default::default()
+    };
+    let server = server(vec![route("/flaky", None)], config);
+
+    for _ in 0..3 {
+        let response = server.get("/flaky").await;
+        assert_eq!(response.status_code(), StatusCode::OK);
+        assert!(response.maybe_header("ratelimit-limit").is_none());
+    }
+}
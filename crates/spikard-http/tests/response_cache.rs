@@ -0,0 +1,231 @@
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use spikard_http::server::build_router_with_handlers_and_config;
+use spikard_http::{
+    ApiKeyConfig, Handler, HandlerResult, Method, RequestData, ResponseCacheConfig, Route, RouteAuthConfig,
+    ServerConfig,
+};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+/// Answers with the number of times it has been called
+struct Counter {
+    calls: Arc<AtomicUsize>,
+    delay: Duration,
+}
+
+impl Handler for Counter {
+    fn call(
+        &self,
+        _request: Request<Body>,
+        _request_data: RequestData,
+    ) -> Pin<Box<dyn Future<Output = HandlerResult> + Send + '_>> {
+        Box::pin(async move {
+            let call = self.calls.fetch_add(1, Ordering::SeqCst) + 1;
+            tokio::time::sleep(self.delay).await;
+            Ok(axum::http::Response::builder()
+                .status(StatusCode::OK)
+                .body(Body::from(call.to_string()))
+                .expect("response"))
+        })
+    }
+}
+
+/// Updates an item and invalidates its cached representations
+struct Update;
+
+impl Handler for Update {
+    fn call(
+        &self,
+        request: Request<Body>,
+        _request_data: RequestData,
+    ) -> Pin<Box<dyn Future<Output = HandlerResult> + Send + '_>> {
+        let item = request.uri().path().rsplit('/').next().unwrap_or_default().to_string();
+        Box::pin(async move {
+            Ok(axum::http::Response::builder()
+                .status(StatusCode::NO_CONTENT)
+                .header("x-spikard-cache-invalidate", format!("item:{item}"))
+                .body(Body::empty())
+                .expect("response"))
+        })
+    }
+}
+
+fn route(
+    method: Method,
+    path: &str,
+    response_cache: Option<ResponseCacheConfig>,
+    handler: Arc<dyn Handler>,
+) -> (Route, Arc<dyn Handler>) {
+    let route = Route {
+        method,
+        path: path.to_string(),
+        handler_name: path.trim_start_matches('/').to_string(),
+        expects_json_body: false,
+        cors: None,
+        is_async: true,
+        file_params: None,
+        request_validator: None,
+        response_validator: None,
+        parameter_validator: None,
+        jsonrpc_method: None,
+        compression: None,
+        body_limit: None,
+        request_timeout_secs: None,
+        rate_limit: None,
+        caching: None,
+        response_cache,
+        auth: None,
+        #[cfg(feature = "di")]
+        handler_dependencies: Vec::new(),
+    };
+    (route, handler)
+}
+
+fn counter(delay: Duration) -> (Arc<AtomicUsize>, Arc<dyn Handler>) {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let handler = Arc::new(Counter {
+        calls: Arc::clone(&calls),
+        delay,
+    });
+    (calls, handler)
+}
+
+fn server(routes: Vec<(Route, Arc<dyn Handler>)>) -> axum_test::TestServer {
+    let app = build_router_with_handlers_and_config(routes, ServerConfig::default(), Vec::new()).expect("router");
+    axum_test::TestServer::new(app)
+}
+
+#[tokio::test]
+async fn repeated_requests_are_served_from_cache_per_key() {
+    let (calls, handler) = counter(Duration::ZERO);
+    let config = ResponseCacheConfig {
+        query_params: vec!["page".to_string()],
+        ..Default::default()
+    };
+    let server = server(vec![route(Method::Get, "/items", Some(config), handler)]);
+
+    let first = server.get("/items?page=1").await;
+    assert_eq!(first.text(), "1");
+    let cached = server.get("/items?page=1&sort=name").await;
+    assert_eq!(cached.text(), "1");
+    assert!(cached.headers().contains_key("age"));
+
+    assert_eq!(server.get("/items?page=2").await.text(), "2");
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn concurrent_misses_share_one_handler_call() {
+    let (calls, handler) = counter(Duration::from_millis(100));
+    let server = server(vec![route(
+        Method::Get,
+        "/report",
+        Some(ResponseCacheConfig::default()),
+        handler,
+    )]);
+
+    let responses =
+        futures::future::join_all((0..5).map(|_| std::future::IntoFuture::into_future(server.get("/report")))).await;
+    for response in responses {
+        assert_eq!(response.text(), "1");
+    }
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn stale_responses_are_served_while_revalidating() {
+    let (calls, handler) = counter(Duration::ZERO);
+    let config = ResponseCacheConfig {
+        ttl_secs: 0,
+        stale_while_revalidate_secs: 60,
+        ..Default::default()
+    };
+    let server = server(vec![route(Method::Get, "/feed", Some(config), handler)]);
+
+    assert_eq!(server.get("/feed").await.text(), "1");
+    assert_eq!(server.get("/feed").await.text(), "1");
+
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+    assert_eq!(server.get("/feed").await.text(), "2");
+}
+
+#[tokio::test]
+async fn handlers_invalidate_cached_responses_by_tag() {
+    let (calls, handler) = counter(Duration::ZERO);
+    let config = ResponseCacheConfig {
+        tags: vec!["item:{id}".to_string()],
+        ..Default::default()
+    };
+    let server = server(vec![
+        route(Method::Get, "/items/{id}", Some(config), handler),
+        route(Method::Post, "/items/{id}", None, Arc::new(Update)),
+    ]);
+
+    assert_eq!(server.get("/items/1").await.text(), "1");
+    assert_eq!(server.get("/items/2").await.text(), "2");
+
+    let update = server.post("/items/1").await;
+    assert_eq!(update.status_code(), StatusCode::NO_CONTENT);
+    assert!(!update.headers().contains_key("x-spikard-cache-invalidate"));
+
+    assert_eq!(server.get("/items/1").await.text(), "3");
+    assert_eq!(server.get("/items/2").await.text(), "2");
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn responses_over_the_entry_limit_are_not_cached() {
+    let (calls, handler) = counter(Duration::ZERO);
+    let config = ResponseCacheConfig {
+        max_entry_bytes: 0,
+        ..Default::default()
+    };
+    let server = server(vec![route(Method::Get, "/large", Some(config), handler)]);
+
+    assert_eq!(server.get("/large").await.text(), "1");
+    let uncached = server.get("/large").await;
+    assert_eq!(uncached.text(), "2");
+    assert!(!uncached.headers().contains_key("age"));
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn api_key_clients_get_separate_cache_entries() {
+    let (calls, handler) = counter(Duration::ZERO);
+    let (mut cached, handler) = route(Method::Get, "/me", Some(ResponseCacheConfig::default()), handler);
+    cached.auth = Some(RouteAuthConfig {
+        jwt: None,
+        api_key: Some(ApiKeyConfig {
+            keys: vec!["alice-key".to_string(), "bob-key".to_string()],
+            header_name: "X-API-Key".to_string(),
+        }),
+    });
+    let server = server(vec![(cached, handler)]);
+
+    let alice = server.get("/me").add_header("X-API-Key", "alice-key").await;
+    assert_eq!(alice.text(), "1");
+    let bob = server.get("/me").add_header("X-API-Key", "bob-key").await;
+    assert_eq!(bob.text(), "2");
+    let alice_again = server.get("/me").add_header("X-API-Key", "alice-key").await;
+    assert_eq!(alice_again.text(), "1");
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn response_cache_is_rejected_on_unsafe_methods() {
+    let (_, handler) = counter(Duration::ZERO);
+    let routes = vec![route(
+        Method::Post,
+        "/items",
+        Some(ResponseCacheConfig::default()),
+        handler,
+    )];
+    let error = build_router_with_handlers_and_config(routes, ServerConfig::default(), Vec::new())
+        .expect_err("POST routes cannot cache responses");
+    assert!(error.contains("only supported on GET routes"), "{error}");
+}
@@ -0,0 +1,98 @@
+//! Snapshot assertions over `TestClient` responses, with redaction of volatile values.
+
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::IntoResponse;
+use axum::{Json, Router, routing::get};
+use serde_json::json;
+use spikard_http::assert_response_snapshot;
+use spikard_http::testing::{
+    ResponseSnapshot, SnapshotOptions, SnapshotOutcome, SnapshotUpdate, TestClient, check_snapshot, snapshot_path,
+};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+static REQUESTS: AtomicU64 = AtomicU64::new(1);
+
+/// Returns a user with per-request ids and timestamps.
+async fn get_user() -> impl IntoResponse {
+    let n = REQUESTS.fetch_add(1, Ordering::Relaxed);
+    let mut headers = HeaderMap::new();
+    headers.insert("x-request-id", format!("req-{n}").parse().expect("header value"));
+    let body = json!({
+        "id": format!("{n:08x}-0000-4000-8000-000000000000"),
+        "name": "Ada",
+        "created_at": format!("2026-10-19T08:{:02}:00Z", n % 60),
+        "session": {"token": format!("tok-{n}"), "scopes": ["read", "write"]},
+    });
+    (StatusCode::OK, headers, Json(body))
+}
+
+fn client() -> TestClient {
+    TestClient::from_router(Router::new().route("/users/me", get(get_user))).expect("client")
+}
+
+fn options() -> SnapshotOptions {
+    SnapshotOptions {
+        headers: vec!["content-type".to_string(), "x-request-id".to_string()],
+        redact: vec!["/headers/x-request-id".to_string(), "/body/session/token".to_string()],
+        redact_uuids: true,
+        redact_timestamps: true,
+    }
+}
+
+#[tokio::test]
+async fn responses_match_committed_snapshots() {
+    let client = client();
+    for _ in 0..2 {
+        let response = client.get("/users/me", None, None).await.expect("response");
+        assert_response_snapshot!("get_user_redacted", response, options());
+    }
+}
+
+#[tokio::test]
+async fn snapshot_files_follow_the_update_mode() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let path = dir.path().join("snapshots").join("user.snap.json");
+    let response = client().get("/users/me", None, None).await.expect("response");
+    let options = options();
+
+    let missing = check_snapshot(&response, &path, &options, SnapshotUpdate::Never).expect("check");
+    assert!(matches!(missing, SnapshotOutcome::Mismatched { expected: None, .. }));
+    assert!(!path.exists());
+
+    let created = check_snapshot(&response, &path, &options, SnapshotUpdate::Missing).expect("check");
+    assert_eq!(created, SnapshotOutcome::Created);
+    assert_eq!(
+        std::fs::read_to_string(&path).expect("snapshot"),
+        response.to_snapshot_json(&options).expect("json")
+    );
+
+    let next = client().get("/users/me", None, None).await.expect("response");
+    let matched = check_snapshot(&next, &path, &options, SnapshotUpdate::Never).expect("check");
+    assert_eq!(matched, SnapshotOutcome::Matched);
+
+    let changed = ResponseSnapshot { status: 404, ..next };
+    let mismatched = check_snapshot(&changed, &path, &options, SnapshotUpdate::Missing).expect("check");
+    let SnapshotOutcome::Mismatched {
+        expected: Some(expected),
+        actual,
+    } = mismatched
+    else {
+        panic!("expected a mismatch, got {mismatched:?}");
+    };
+    assert!(expected.contains("\"status\": 200"));
+    assert!(actual.contains("\"status\": 404"));
+
+    let updated = check_snapshot(&changed, &path, &options, SnapshotUpdate::Always).expect("check");
+    assert_eq!(updated, SnapshotOutcome::Updated);
+    assert_eq!(
+        check_snapshot(&changed, &path, &options, SnapshotUpdate::Never).expect("check"),
+        SnapshotOutcome::Matched
+    );
+}
+
+#[test]
+fn snapshot_files_live_next_to_the_test() {
+    let path = snapshot_path(env!("CARGO_MANIFEST_DIR"), file!(), "get_user_redacted");
+    assert!(path.ends_with("tests/snapshots/get_user_redacted.snap.json"));
+    assert!(path.exists(), "{} is committed", path.display());
+}
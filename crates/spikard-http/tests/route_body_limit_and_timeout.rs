@@ -65,6 +65,10 @@ fn body_limit_route(max_bytes: usize) -> Route {
         compression: None,
         body_limit: Some(max_bytes),
         request_timeout_secs: None,
+        rate_limit: None,
+        caching: None,
+        response_cache: None,
+        auth: None,
         #[cfg(feature = "di")]
         handler_dependencies: vec![],
     }
@@ -86,6 +90,10 @@ fn timeout_route(timeout_secs: u64) -> Route {
         compression: None,
         body_limit: None,
         request_timeout_secs: Some(timeout_secs),
+        rate_limit: None,
+        caching: None,
+        response_cache: None,
+        auth: None,
         #[cfg(feature = "di")]
         handler_dependencies: vec![],
     }
@@ -255,6 +255,9 @@ mod common_handler_tests {
             cookies: Arc::new(HashMap::new()),
             method: "GET".to_string(),
             path: "/test".to_string(),
+            client_ip: None,
+            scheme: None,
+            host: None,
             #[cfg(feature = "di")]
             dependencies: None,
         }
@@ -50,6 +50,10 @@ async fn router_generates_cors_preflight_when_missing_options_handler() {
         compression: None,
         body_limit: None,
         request_timeout_secs: None,
+        rate_limit: None,
+        caching: None,
+        response_cache: None,
+        auth: None,
         #[cfg(feature = "di")]
         handler_dependencies: vec![],
     };
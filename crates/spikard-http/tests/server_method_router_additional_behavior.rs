@@ -75,6 +75,10 @@ fn route(method: Method, path: &str, handler_name: &str) -> Route {
         compression: None,
         body_limit: None,
         request_timeout_secs: None,
+        rate_limit: None,
+        caching: None,
+        response_cache: None,
+        auth: None,
         #[cfg(feature = "di")]
         handler_dependencies: Vec::new(),
     }
@@ -48,6 +48,10 @@ fn route(method: Method, path: &str, expects_json_body: bool) -> Route {
         compression: None,
         body_limit: None,
         request_timeout_secs: None,
+        rate_limit: None,
+        caching: None,
+        response_cache: None,
+        auth: None,
         #[cfg(feature = "di")]
         handler_dependencies: Vec::new(),
     }
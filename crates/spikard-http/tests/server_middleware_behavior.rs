@@ -43,6 +43,10 @@ fn route(path: &str) -> Route {
         compression: None,
         body_limit: None,
         request_timeout_secs: None,
+        rate_limit: None,
+        caching: None,
+        response_cache: None,
+        auth: None,
         #[cfg(feature = "di")]
         handler_dependencies: vec![],
     }
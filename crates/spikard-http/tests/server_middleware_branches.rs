@@ -48,6 +48,10 @@ fn basic_route(method: Method, path: &str, expects_json_body: bool) -> Route {
         compression: None,
         body_limit: None,
         request_timeout_secs: None,
+        rate_limit: None,
+        caching: None,
+        response_cache: None,
+        auth: None,
         #[cfg(feature = "di")]
         handler_dependencies: Vec::new(),
     }
@@ -182,6 +186,7 @@ async fn rate_limit_builder_covers_ip_and_global_key_extractors() {
             per_second: 100,
             burst: 10,
             ip_based: true,
+            key: None,
         }),
         ..Default::default()
     };
@@ -196,6 +201,7 @@ async fn rate_limit_builder_covers_ip_and_global_key_extractors() {
             per_second: 100,
             burst: 10,
             ip_based: false,
+            key: None,
         }),
         ..Default::default()
     };
@@ -230,6 +236,10 @@ async fn grpc_content_type_is_not_rejected_on_json_route() {
         compression: None,
         body_limit: None,
         request_timeout_secs: None,
+        rate_limit: None,
+        caching: None,
+        response_cache: None,
+        auth: None,
         #[cfg(feature = "di")]
         handler_dependencies: Vec::new(),
     };
@@ -70,6 +70,10 @@ fn build_routes(path: &str) -> Vec<(Route, Arc<dyn Handler>)> {
                 compression: None,
                 body_limit: None,
                 request_timeout_secs: None,
+                rate_limit: None,
+                caching: None,
+                response_cache: None,
+                auth: None,
                 #[cfg(feature = "di")]
                 handler_dependencies: Vec::new(),
             },
@@ -91,6 +95,10 @@ fn build_routes(path: &str) -> Vec<(Route, Arc<dyn Handler>)> {
                 compression: None,
                 body_limit: None,
                 request_timeout_secs: None,
+                rate_limit: None,
+                caching: None,
+                response_cache: None,
+                auth: None,
                 #[cfg(feature = "di")]
                 handler_dependencies: Vec::new(),
             },
@@ -145,6 +153,11 @@ fn build_route_metadata(path: &str) -> Vec<RouteMetadata> {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            tags: Vec::new(),
+            auth: None,
         },
         RouteMetadata {
             method: "POST".to_string(),
@@ -164,6 +177,11 @@ fn build_route_metadata(path: &str) -> Vec<RouteMetadata> {
             static_response: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            tags: Vec::new(),
+            auth: None,
         },
     ]
 }
@@ -187,6 +205,7 @@ fn build_config(static_dir: &Path) -> ServerConfig {
             per_second: 100,
             burst: 10,
             ip_based: false,
+            key: None,
         }),
         ..Default::default()
     }
@@ -349,6 +368,11 @@ async fn static_response_route_serves_pre_built_response() {
         static_response: None,
         body_limit: None,
         request_timeout_secs: None,
+        rate_limit: None,
+        caching: None,
+        response_cache: None,
+        tags: Vec::new(),
+        auth: None,
     };
 
     let route = spikard_http::Route::from_metadata(route_meta.clone(), &spikard_http::SchemaRegistry::new())
@@ -398,6 +422,11 @@ async fn static_and_dynamic_routes_coexist() {
         static_response: None,
         body_limit: None,
         request_timeout_secs: None,
+        rate_limit: None,
+        caching: None,
+        response_cache: None,
+        tags: Vec::new(),
+        auth: None,
     };
 
     let dynamic_meta = RouteMetadata {
@@ -418,6 +447,11 @@ async fn static_and_dynamic_routes_coexist() {
         static_response: None,
         body_limit: None,
         request_timeout_secs: None,
+        rate_limit: None,
+        caching: None,
+        response_cache: None,
+        tags: Vec::new(),
+        auth: None,
     };
 
     let registry = spikard_http::SchemaRegistry::new();
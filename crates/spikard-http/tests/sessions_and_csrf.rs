@@ -0,0 +1,411 @@
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use serde_json::{Value, json};
+use spikard_http::server::build_router_with_handlers_and_config;
+use spikard_http::{
+    CsrfConfig, CsrfMode, CsrfToken, Handler, HandlerResult, Method, RequestData, Route, ServerConfig, Session,
+    SessionConfig, SessionStorage,
+};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+const SECRET: &str = "an-example-session-secret-of-32-bytes!";
+const PREVIOUS_SECRET: &str = "the-previous-session-secret-of-32-bytes";
+
+/// Counts visits in the session and reports the CSRF token
+struct Visits;
+
+impl Handler for Visits {
+    fn call(
+        &self,
+        request: Request<Body>,
+        _request_data: RequestData,
+    ) -> Pin<Box<dyn Future<Output = HandlerResult> + Send + '_>> {
+        Box::pin(async move {
+            let session = request.extensions().get::<Session>().cloned().expect("session");
+            let visits = session.get::<u64>("visits").unwrap_or(0) + 1;
+            session.insert("visits", visits).expect("insert");
+            let csrf = request.extensions().get::<CsrfToken>().map(|token| token.0.clone());
+            Ok(json_response(&json!({"visits": visits, "csrf": csrf})))
+        })
+    }
+}
+
+/// Reads the visit count and CSRF token without writing to the session
+struct Peek;
+
+impl Handler for Peek {
+    fn call(
+        &self,
+        request: Request<Body>,
+        _request_data: RequestData,
+    ) -> Pin<Box<dyn Future<Output = HandlerResult> + Send + '_>> {
+        Box::pin(async move {
+            let session = request.extensions().get::<Session>().cloned().expect("session");
+            let csrf = request.extensions().get::<CsrfToken>().map(|token| token.0.clone());
+            Ok(json_response(
+                &json!({"visits": session.get::<u64>("visits"), "csrf": csrf}),
+            ))
+        })
+    }
+}
+
+/// Mimics a binding handler: reads session JSON from the request and replaces it via the response
+struct BindingStyle;
+
+impl Handler for BindingStyle {
+    fn call(
+        &self,
+        _request: Request<Body>,
+        request_data: RequestData,
+    ) -> Pin<Box<dyn Future<Output = HandlerResult> + Send + '_>> {
+        Box::pin(async move {
+            let current: Value = request_data
+                .headers
+                .get("x-spikard-session")
+                .map_or(Value::Null, |raw| serde_json::from_str(raw).expect("session json"));
+            let mut response = json_response(&json!({"seen": current}));
+            response
+                .headers_mut()
+                .insert("x-spikard-session", r#"{"user":"ada"}"#.parse().expect("header"));
+            Ok(response)
+        })
+    }
+}
+
+/// Mimics a binding login: rebuilds the session from its JSON and hands the changes back
+struct BindingLogin;
+
+impl Handler for BindingLogin {
+    fn call(
+        &self,
+        _request: Request<Body>,
+        request_data: RequestData,
+    ) -> Pin<Box<dyn Future<Output = HandlerResult> + Send + '_>> {
+        Box::pin(async move {
+            let data = request_data
+                .headers
+                .get("x-spikard-session")
+                .map(|raw| serde_json::from_str(raw).expect("session json"))
+                .unwrap_or_default();
+            let session = Session::from_data(data);
+            session.insert("user", "ada").expect("insert");
+            session.regenerate();
+            let mut response = json_response(&json!({"ok": true}));
+            for (name, value) in session.binding_headers() {
+                response.headers_mut().insert(
+                    axum::http::HeaderName::try_from(name).expect("header name"),
+                    value.parse().expect("header value"),
+                );
+            }
+            Ok(response)
+        })
+    }
+}
+
+struct Logout;
+
+impl Handler for Logout {
+    fn call(
+        &self,
+        request: Request<Body>,
+        _request_data: RequestData,
+    ) -> Pin<Box<dyn Future<Output = HandlerResult> + Send + '_>> {
+        Box::pin(async move {
+            request.extensions().get::<Session>().expect("session").destroy();
+            Ok(json_response(&json!({"ok": true})))
+        })
+    }
+}
+
+fn json_response(body: &Value) -> axum::http::Response<Body> {
+    axum::http::Response::builder()
+        .status(StatusCode::OK)
+        .header("content-type", "application/json")
+        .body(Body::from(body.to_string()))
+        .expect("response")
+}
+
+fn route(method: Method, path: &str, handler: Arc<dyn Handler>) -> (Route, Arc<dyn Handler>) {
+    let route = Route {
+        method,
+        path: path.to_string(),
+        handler_name: path.trim_start_matches('/').to_string(),
+        expects_json_body: false,
+        cors: None,
+        is_async: true,
+        file_params: None,
+        request_validator: None,
+        response_validator: None,
+        parameter_validator: None,
+        jsonrpc_method: None,
+        compression: None,
+        body_limit: None,
+        request_timeout_secs: None,
+        rate_limit: None,
+        caching: None,
+        response_cache: None,
+        auth: None,
+        #[cfg(feature = "di")]
+        handler_dependencies: Vec::new(),
+    };
+    (route, handler)
+}
+
+fn server(config: ServerConfig) -> axum_test::TestServer {
+    let routes = vec![
+        route(Method::Get, "/visits", Arc::new(Visits)),
+        route(Method::Post, "/visits", Arc::new(Visits)),
+        route(Method::Get, "/peek", Arc::new(Peek)),
+        route(Method::Post, "/binding", Arc::new(BindingStyle)),
+        route(Method::Post, "/binding-login", Arc::new(BindingLogin)),
+        route(Method::Post, "/logout", Arc::new(Logout)),
+    ];
+    let app = build_router_with_handlers_and_config(routes, config, Vec::new()).expect("router");
+    axum_test::TestServer::new(app)
+}
+
+fn session_config(secret: &str, storage: SessionStorage) -> SessionConfig {
+    SessionConfig {
+        secret: secret.to_string(),
+        storage,
+        secure: false,
+        ..Default::default()
+    }
+}
+
+/// `name=value` pair from the response's `Set-Cookie` for `name`
+fn cookie_pair(response: &axum_test::TestResponse, name: &str) -> Option<String> {
+    response
+        .headers()
+        .get_all("set-cookie")
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .find(|value| value.starts_with(&format!("{name}=")))
+        .map(|value| value.split(';').next().unwrap_or_default().to_string())
+}
+
+#[tokio::test]
+async fn server_and_cookie_sessions_persist_between_requests() {
+    for storage in [SessionStorage::Server, SessionStorage::Cookie] {
+        let server = server(ServerConfig {
+            session: Some(SessionConfig {
+                encrypt: storage == SessionStorage::Cookie,
+                ..session_config(SECRET, storage)
+            }),
+            ..Default::default()
+        });
+
+        let first = server.get("/visits").await;
+        assert_eq!(first.json::<Value>()["visits"], 1);
+        let cookie = cookie_pair(&first, "spikard_session").expect("session cookie");
+        assert!(
+            !cookie.contains("visits"),
+            "session data must not be readable: {cookie}"
+        );
+
+        let second = server.get("/visits").add_header("cookie", cookie.as_str()).await;
+        assert_eq!(second.json::<Value>()["visits"], 2);
+
+        let tampered = format!("{cookie}x");
+        let forged = server.get("/visits").add_header("cookie", tampered.as_str()).await;
+        assert_eq!(forged.json::<Value>()["visits"], 1);
+    }
+}
+
+#[tokio::test]
+async fn rotated_secrets_are_accepted_and_reissued() {
+    let old = server(ServerConfig {
+        session: Some(session_config(PREVIOUS_SECRET, SessionStorage::Cookie)),
+        ..Default::default()
+    });
+    let cookie = cookie_pair(&old.get("/visits").await, "spikard_session").expect("session cookie");
+
+    let rotated = server(ServerConfig {
+        session: Some(SessionConfig {
+            previous_secrets: vec![PREVIOUS_SECRET.to_string()],
+            ..session_config(SECRET, SessionStorage::Cookie)
+        }),
+        ..Default::default()
+    });
+    let response = rotated.get("/visits").add_header("cookie", cookie.as_str()).await;
+    assert_eq!(response.json::<Value>()["visits"], 2);
+    let reissued = cookie_pair(&response, "spikard_session").expect("reissued cookie");
+    assert_ne!(reissued, cookie);
+}
+
+#[tokio::test]
+async fn binding_handlers_read_and_replace_session_data() {
+    let server = server(ServerConfig {
+        session: Some(session_config(SECRET, SessionStorage::Server)),
+        ..Default::default()
+    });
+
+    let first = server.post("/binding").await;
+    assert_eq!(first.json::<Value>()["seen"], json!({}));
+    assert!(first.headers().get("x-spikard-session").is_none());
+    let cookie = cookie_pair(&first, "spikard_session").expect("session cookie");
+
+    let second = server.post("/binding").add_header("cookie", cookie.as_str()).await;
+    assert_eq!(second.json::<Value>()["seen"], json!({"user": "ada"}));
+
+    let logout = server.post("/logout").add_header("cookie", cookie.as_str()).await;
+    let removal = logout.headers().get("set-cookie").expect("removal cookie");
+    assert!(removal.to_str().expect("ascii").contains("Max-Age=0"));
+    let after = server.post("/binding").add_header("cookie", cookie.as_str()).await;
+    assert_eq!(after.json::<Value>()["seen"], json!({}));
+}
+
+#[tokio::test]
+async fn binding_handlers_regenerate_the_session_id_through_binding_headers() {
+    let server = server(ServerConfig {
+        session: Some(session_config(SECRET, SessionStorage::Server)),
+        ..Default::default()
+    });
+
+    let first = server.post("/visits").await;
+    let cookie = cookie_pair(&first, "spikard_session").expect("session cookie");
+
+    let login = server
+        .post("/binding-login")
+        .add_header("cookie", cookie.as_str())
+        .await;
+    assert!(login.headers().get("x-spikard-session").is_none());
+    assert!(login.headers().get("x-spikard-session-regenerate").is_none());
+    let rotated = cookie_pair(&login, "spikard_session").expect("regenerated session cookie");
+    assert_ne!(rotated, cookie);
+
+    let seen = server.post("/binding").add_header("cookie", rotated.as_str()).await;
+    assert_eq!(seen.json::<Value>()["seen"], json!({"visits": 1, "user": "ada"}));
+}
+
+#[tokio::test]
+async fn double_submit_csrf_rejects_unsafe_requests_without_the_token() {
+    let server = server(ServerConfig {
+        session: Some(session_config(SECRET, SessionStorage::Server)),
+        csrf: Some(CsrfConfig {
+            secure: false,
+            exempt_paths: vec!["/logout".to_string()],
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+
+    let page = server.get("/visits").await;
+    let token = page.json::<Value>()["csrf"].as_str().expect("token").to_string();
+    let csrf_cookie = cookie_pair(&page, "csrf_token").expect("csrf cookie");
+    assert_eq!(csrf_cookie, format!("csrf_token={token}"));
+
+    let missing = server.post("/visits").add_header("cookie", csrf_cookie.as_str()).await;
+    assert_eq!(missing.status_code(), StatusCode::FORBIDDEN);
+    assert_eq!(
+        missing.json::<Value>()["type"],
+        "https://spikard.dev/errors/csrf-failed"
+    );
+
+    let wrong = server
+        .post("/visits")
+        .add_header("cookie", csrf_cookie.as_str())
+        .add_header("x-csrf-token", "not-the-token")
+        .await;
+    assert_eq!(wrong.status_code(), StatusCode::FORBIDDEN);
+
+    let ok = server
+        .post("/visits")
+        .add_header("cookie", csrf_cookie.as_str())
+        .add_header("x-csrf-token", token.as_str())
+        .await;
+    assert_eq!(ok.status_code(), StatusCode::OK);
+
+    let exempt = server.post("/logout").await;
+    assert_eq!(exempt.status_code(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn synchronizer_csrf_tokens_live_in_the_session() {
+    let server = server(ServerConfig {
+        session: Some(session_config(SECRET, SessionStorage::Server)),
+        csrf: Some(CsrfConfig {
+            mode: CsrfMode::Synchronizer,
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+
+    let page = server.get("/visits").await;
+    let token = page.json::<Value>()["csrf"].as_str().expect("token").to_string();
+    assert!(cookie_pair(&page, "csrf_token").is_none());
+    let session = cookie_pair(&page, "spikard_session").expect("session cookie");
+
+    let without_session = server.post("/visits").add_header("x-csrf-token", token.as_str()).await;
+    assert_eq!(without_session.status_code(), StatusCode::FORBIDDEN);
+
+    let ok = server
+        .post("/visits")
+        .add_header("cookie", session.as_str())
+        .add_header("x-csrf-token", token.as_str())
+        .await;
+    assert_eq!(ok.status_code(), StatusCode::OK);
+    assert_eq!(ok.json::<Value>()["visits"], 2);
+}
+
+#[tokio::test]
+async fn synchronizer_csrf_does_not_start_sessions_for_anonymous_clients() {
+    let server = server(ServerConfig {
+        session: Some(session_config(SECRET, SessionStorage::Server)),
+        csrf: Some(CsrfConfig {
+            mode: CsrfMode::Synchronizer,
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+
+    let anonymous = server.get("/peek").await;
+    assert!(anonymous.json::<Value>()["csrf"].is_string());
+    assert!(cookie_pair(&anonymous, "spikard_session").is_none());
+
+    let started = server.get("/visits").await;
+    let session = cookie_pair(&started, "spikard_session").expect("session cookie");
+    let token = started.json::<Value>()["csrf"].as_str().expect("token").to_string();
+
+    let peek = server.get("/peek").add_header("cookie", session.as_str()).await;
+    assert_eq!(peek.json::<Value>()["csrf"], token.as_str());
+}
+
+#[tokio::test]
+async fn rolling_sessions_are_refreshed_on_every_request() {
+    for (rolling, refreshed) in [(true, true), (false, false)] {
+        let server = server(ServerConfig {
+            session: Some(SessionConfig {
+                rolling,
+                ..session_config(SECRET, SessionStorage::Server)
+            }),
+            ..Default::default()
+        });
+        let first = server.get("/visits").await;
+        let session = cookie_pair(&first, "spikard_session").expect("session cookie");
+
+        let peek = server.get("/peek").add_header("cookie", session.as_str()).await;
+        assert_eq!(peek.json::<Value>()["visits"], 1);
+        assert_eq!(cookie_pair(&peek, "spikard_session"), refreshed.then_some(session));
+    }
+}
+
+#[test]
+fn invalid_session_and_csrf_configs_fail_the_build() {
+    let short_secret = ServerConfig {
+        session: Some(session_config("short", SessionStorage::Server)),
+        ..Default::default()
+    };
+    assert!(build_router_with_handlers_and_config(Vec::new(), short_secret, Vec::new()).is_err());
+
+    let synchronizer_without_sessions = ServerConfig {
+        csrf: Some(CsrfConfig {
+            mode: CsrfMode::Synchronizer,
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    assert!(build_router_with_handlers_and_config(Vec::new(), synchronizer_without_sessions, Vec::new()).is_err());
+}
@@ -0,0 +1,264 @@
+#![cfg(feature = "otel")]
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use opentelemetry::trace::{SpanKind, TracerProvider as _};
+use opentelemetry_sdk::trace::{InMemorySpanExporter, SdkTracerProvider, SpanData};
+use spikard_http::background::BackgroundRuntime;
+use spikard_http::server::build_router_with_handlers_and_config;
+use spikard_http::{
+    BackgroundHandle, BackgroundTaskConfig, Handler, HandlerResult, LifecycleHooks, Method, RequestData, Route,
+    ServerConfig, TelemetryConfig, request_hook,
+};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::subscriber::DefaultGuard;
+use tracing_subscriber::layer::SubscriberExt;
+
+const TRACE_ID: &str = "4bf92f3577b34da6a3ce929d0e0e4736";
+const PARENT_SPAN_ID: &str = "00f067aa0ba902b7";
+
+struct Collector {
+    exporter: InMemorySpanExporter,
+    provider: SdkTracerProvider,
+    _guard: DefaultGuard,
+}
+
+impl Collector {
+    fn install() -> Self {
+        let exporter = InMemorySpanExporter::default();
+        let provider = SdkTracerProvider::builder()
+            .with_simple_exporter(exporter.clone())
+            .build();
+        let subscriber =
+            tracing_subscriber::registry().with(tracing_opentelemetry::layer().with_tracer(provider.tracer("test")));
+        let guard = tracing::subscriber::set_default(subscriber);
+        Self {
+            exporter,
+            provider,
+            _guard: guard,
+        }
+    }
+
+    fn spans(&self) -> Vec<SpanData> {
+        self.provider.force_flush().expect("flush");
+        self.exporter.get_finished_spans().expect("finished spans")
+    }
+}
+
+fn find<'a>(spans: &'a [SpanData], name: &str) -> &'a SpanData {
+    spans
+        .iter()
+        .find(|span| span.name == name)
+        .unwrap_or_else(|| panic!("span {name} not recorded: {:?}", names(spans)))
+}
+
+fn names(spans: &[SpanData]) -> Vec<String> {
+    spans.iter().map(|span| span.name.to_string()).collect()
+}
+
+struct OkHandler {
+    background: Option<BackgroundHandle>,
+}
+
+impl Handler for OkHandler {
+    fn call(
+        &self,
+        _request: Request<Body>,
+        _request_data: RequestData,
+    ) -> Pin<Box<dyn Future<Output = HandlerResult> + Send + '_>> {
+        Box::pin(async move {
+            if let Some(ref background) = self.background {
+                background
+                    .spawn(|| async {
+                        tracing::info!("sending welcome email");
+                        Ok(())
+                    })
+                    .expect("spawn");
+            }
+            Ok(axum::http::Response::builder()
+                .status(StatusCode::OK)
+                .body(Body::from("{}"))
+                .expect("response"))
+        })
+    }
+}
+
+fn route(path: &str) -> Route {
+    Route {
+        method: Method::Get,
+        path: path.to_string(),
+        handler_name: "handler".to_string(),
+        expects_json_body: false,
+        cors: None,
+        is_async: true,
+        file_params: None,
+        request_validator: None,
+        response_validator: None,
+        parameter_validator: None,
+        jsonrpc_method: None,
+        compression: None,
+        body_limit: None,
+        request_timeout_secs: None,
+        rate_limit: None,
+        caching: None,
+        response_cache: None,
+        auth: None,
+        #[cfg(feature = "di")]
+        handler_dependencies: Vec::new(),
+    }
+}
+
+fn server(route: Route, handler: OkHandler, config: ServerConfig) -> axum_test::TestServer {
+    let routes: Vec<(Route, Arc<dyn Handler>)> = vec![(route, Arc::new(handler))];
+    let app = build_router_with_handlers_and_config(routes, config, Vec::new()).expect("router");
+    axum_test::TestServer::new(app)
+}
+
+fn telemetry_config() -> ServerConfig {
+    ServerConfig {
+        telemetry: Some(TelemetryConfig::default()),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn server_span_is_named_by_route_and_continues_remote_trace() {
+    let collector = Collector::install();
+    let server = server(route("/users/{id}"), OkHandler { background: None }, telemetry_config());
+
+    let response = server
+        .get("/users/42")
+        .add_header("traceparent", format!("00-{TRACE_ID}-{PARENT_SPAN_ID}-01"))
+        .add_header("tracestate", "vendor=abc")
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+
+    let spans = collector.spans();
+    let request = find(&spans, "GET /users/{id}");
+    assert_eq!(request.span_kind, SpanKind::Server);
+    assert_eq!(request.span_context.trace_id().to_string(), TRACE_ID);
+    assert_eq!(request.parent_span_id.to_string(), PARENT_SPAN_ID);
+    assert!(request.parent_span_is_remote);
+    assert!(
+        request
+            .attributes
+            .iter()
+            .any(|kv| kv.key.as_str() == "http.route" && kv.value.as_str() == "/users/{id}")
+    );
+
+    for child in ["validation", "handler"] {
+        let span = find(&spans, child);
+        assert_eq!(span.parent_span_id, request.span_context.span_id(), "{child} parent");
+    }
+
+    let traceparent = response.header("traceparent");
+    assert_eq!(
+        traceparent.to_str().expect("ascii"),
+        format!("00-{TRACE_ID}-{}-01", request.span_context.span_id())
+    );
+    assert_eq!(response.header("tracestate"), "vendor=abc");
+}
+
+#[tokio::test]
+async fn lifecycle_hooks_get_child_spans_and_unmatched_requests_use_method_name() {
+    let collector = Collector::install();
+    let hooks = LifecycleHooks::builder()
+        .on_request(request_hook("audit", |req| async move {
+            Ok(spikard_http::HookResult::Continue(req))
+        }))
+        .build();
+    let config = ServerConfig {
+        lifecycle_hooks: Some(Arc::new(hooks)),
+        ..telemetry_config()
+    };
+    let server = server(route("/ping"), OkHandler { background: None }, config);
+
+    server.get("/ping").await;
+    server.get("/missing").await;
+
+    let spans = collector.spans();
+    let request = find(&spans, "GET /ping");
+    assert_eq!(
+        find(&spans, "lifecycle.on_request").parent_span_id,
+        request.span_context.span_id()
+    );
+    let unmatched = find(&spans, "GET");
+    assert!(
+        unmatched
+            .attributes
+            .iter()
+            .any(|kv| kv.key.as_str() == "http.response.status_code" && kv.value.as_str() == "404")
+    );
+}
+
+#[tokio::test]
+async fn background_tasks_join_the_request_trace() {
+    let collector = Collector::install();
+    let runtime = BackgroundRuntime::start(BackgroundTaskConfig::default()).await;
+    let server = server(
+        route("/signup"),
+        OkHandler {
+            background: Some(runtime.handle()),
+        },
+        telemetry_config(),
+    );
+
+    server.get("/signup").await;
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    let spans = collector.spans();
+    let request = find(&spans, "GET /signup");
+    let task = find(&spans, "background_task");
+    assert_eq!(task.span_context.trace_id(), request.span_context.trace_id());
+    assert_ne!(task.parent_span_id, opentelemetry::trace::SpanId::INVALID);
+
+    runtime.shutdown().await.expect("shutdown");
+}
+
+#[tokio::test]
+async fn tracing_is_opt_in() {
+    let collector = Collector::install();
+    let server = server(route("/ping"), OkHandler { background: None }, ServerConfig::default());
+
+    let response = server
+        .get("/ping")
+        .add_header("traceparent", format!("00-{TRACE_ID}-{PARENT_SPAN_ID}-01"))
+        .await;
+
+    assert!(response.maybe_header("traceparent").is_none());
+    assert!(!names(&collector.spans()).iter().any(|name| name == "GET /ping"));
+}
+
+#[cfg(feature = "di")]
+#[tokio::test]
+async fn dependency_resolution_is_traced() {
+    use spikard_core::di::{DependencyContainer, ValueDependency};
+
+    let collector = Collector::install();
+    let mut container = DependencyContainer::new();
+    container
+        .register(
+            "settings".to_string(),
+            Arc::new(ValueDependency::new("settings", "on".to_string())),
+        )
+        .expect("register");
+    let config = ServerConfig {
+        di_container: Some(Arc::new(container)),
+        ..telemetry_config()
+    };
+    let mut route = route("/settings");
+    route.handler_dependencies = vec!["settings".to_string()];
+    let server = server(route, OkHandler { background: None }, config);
+
+    server.get("/settings").await;
+
+    let spans = collector.spans();
+    let request = find(&spans, "GET /settings");
+    assert_eq!(
+        find(&spans, "resolve_dependencies").span_context.trace_id(),
+        request.span_context.trace_id()
+    );
+}
@@ -0,0 +1,189 @@
+//! Contract testing generates requests from route schemas and runs them through `TestClient`.
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use serde_json::{Value, json};
+use spikard_http::server::build_router_with_handlers_and_config;
+use spikard_http::testing::{CaseKind, ContractRoute, TestClient, generate_cases, run_contract};
+use spikard_http::{Handler, HandlerResult, RequestData, Route, RouteMetadata, SchemaRegistry, ServerConfig};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+/// Stores an item and echoes it back; `fail_at` makes names of that length crash.
+struct CreateItem {
+    fail_at: Option<usize>,
+}
+
+impl Handler for CreateItem {
+    fn call(
+        &self,
+        _request: Request<Body>,
+        request_data: RequestData,
+    ) -> Pin<Box<dyn Future<Output = HandlerResult> + Send + '_>> {
+        Box::pin(async move {
+            let body: Value = match &request_data.raw_body {
+                Some(raw) => serde_json::from_slice(raw).unwrap_or(Value::Null),
+                None => (*request_data.body).clone(),
+            };
+            let name = body["name"].as_str().unwrap_or_default().to_string();
+            if self.fail_at == Some(name.chars().count()) {
+                return Err((StatusCode::INTERNAL_SERVER_ERROR, "name overflow".to_string()));
+            }
+            Ok(json_response(&json!({"id": 1, "name": name})))
+        })
+    }
+}
+
+/// Returns users without the `email` their response schema requires.
+struct GetUser;
+
+impl Handler for GetUser {
+    fn call(
+        &self,
+        _request: Request<Body>,
+        _request_data: RequestData,
+    ) -> Pin<Box<dyn Future<Output = HandlerResult> + Send + '_>> {
+        Box::pin(async move { Ok(json_response(&json!({"id": 7}))) })
+    }
+}
+
+fn json_response(body: &Value) -> axum::http::Response<Body> {
+    axum::http::Response::builder()
+        .status(StatusCode::OK)
+        .header("content-type", "application/json")
+        .body(Body::from(body.to_string()))
+        .expect("response")
+}
+
+fn metadata(method: &str, path: &str) -> RouteMetadata {
+    RouteMetadata {
+        method: method.to_string(),
+        path: path.to_string(),
+        handler_name: format!("{method} {path}"),
+        request_schema: None,
+        response_schema: None,
+        parameter_schema: None,
+        file_params: None,
+        is_async: true,
+        cors: None,
+        body_param_name: None,
+        #[cfg(feature = "di")]
+        handler_dependencies: None,
+        jsonrpc_method: None,
+        compression: None,
+        static_response: None,
+        body_limit: None,
+        request_timeout_secs: None,
+        rate_limit: None,
+        caching: None,
+        response_cache: None,
+        tags: Vec::new(),
+        auth: None,
+    }
+}
+
+fn create_item_metadata() -> RouteMetadata {
+    RouteMetadata {
+        parameter_schema: Some(json!({
+            "type": "object",
+            "properties": {"limit": {"type": "integer", "source": "query", "minimum": 1, "maximum": 50}}
+        })),
+        request_schema: Some(json!({
+            "type": "object",
+            "properties": {
+                "name": {"type": "string", "minLength": 1, "maxLength": 5},
+                "tags": {"type": "array", "items": {"type": "string"}, "maxItems": 3}
+            },
+            "required": ["name"],
+            "additionalProperties": false
+        })),
+        response_schema: Some(json!({
+            "type": "object",
+            "properties": {"id": {"type": "integer"}, "name": {"type": "string"}},
+            "required": ["id", "name"]
+        })),
+        ..metadata("POST", "/items")
+    }
+}
+
+fn client(routes: Vec<(RouteMetadata, Arc<dyn Handler>)>) -> (TestClient, Vec<ContractRoute>) {
+    let registry = SchemaRegistry::new();
+    let contract_routes = routes
+        .iter()
+        .map(|(metadata, _)| ContractRoute::from_metadata(metadata))
+        .collect();
+    let metadata = routes.iter().map(|(metadata, _)| metadata.clone()).collect();
+    let routes: Vec<(Route, Arc<dyn Handler>)> = routes
+        .into_iter()
+        .map(|(metadata, handler)| (Route::from_metadata(metadata, &registry).expect("route"), handler))
+        .collect();
+    let app = build_router_with_handlers_and_config(routes, ServerConfig::default(), metadata).expect("router");
+    (TestClient::from_router(app).expect("client"), contract_routes)
+}
+
+#[tokio::test]
+async fn conforming_routes_pass_every_case() {
+    let (client, routes) = client(vec![(create_item_metadata(), Arc::new(CreateItem { fail_at: None }))]);
+
+    let report = run_contract(&client, &routes).await.expect("contract run");
+    assert!(report.is_success(), "{:#?}", report.failures);
+    assert_eq!(report.routes, 1);
+    assert_eq!(report.cases, report.passed);
+
+    let cases = generate_cases(&routes[0]).expect("cases");
+    assert_eq!(report.cases, cases.len());
+    for kind in [CaseKind::Valid, CaseKind::Boundary, CaseKind::Invalid] {
+        assert!(cases.iter().any(|case| case.kind == kind), "no {kind} case");
+    }
+}
+
+#[tokio::test]
+async fn failing_cases_are_shrunk() {
+    let (client, routes) = client(vec![(
+        create_item_metadata(),
+        Arc::new(CreateItem { fail_at: Some(5) }),
+    )]);
+
+    let report = run_contract(&client, &routes).await.expect("contract run");
+    let failure = report
+        .failures
+        .iter()
+        .find(|failure| failure.case.description == "body `/name` at maxLength 5")
+        .expect("maxLength failure");
+    assert_eq!(failure.route, "POST /items");
+    assert_eq!(failure.status, 500);
+    assert_eq!(failure.case.body, Some(json!({"name": "aaaaa"})));
+    assert!(failure.case.params.is_empty());
+    assert_eq!(failure.case.uri(), "/items");
+    assert!(failure.shrink_steps >= 2);
+}
+
+#[tokio::test]
+#[allow(clippy::literal_string_with_formatting_args)]
+async fn responses_are_checked_against_the_response_schema() {
+    let get_user = RouteMetadata {
+        response_schema: Some(json!({
+            "type": "object",
+            "properties": {"id": {"type": "integer"}, "email": {"type": "string"}},
+            "required": ["id", "email"]
+        })),
+        ..metadata("GET", "/users/{id:int}")
+    };
+    let (client, routes) = client(vec![(get_user, Arc::new(GetUser))]);
+
+    let report = run_contract(&client, &routes).await.expect("contract run");
+    assert!(!report.is_success());
+    let failure = &report.failures[0];
+    assert_eq!(failure.case.kind, CaseKind::Valid);
+    assert!(failure.reason.contains("response_schema"), "{}", failure.reason);
+
+    assert!(
+        report
+            .failures
+            .iter()
+            .all(|failure| failure.case.kind != CaseKind::Invalid),
+        "invalid path parameters must be rejected with ProblemDetails"
+    );
+    assert!(report.passed > 0);
+}
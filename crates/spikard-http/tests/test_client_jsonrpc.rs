@@ -0,0 +1,155 @@
+//! `TestClient` JSON-RPC helpers return typed results and error objects.
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use serde_json::json;
+use spikard_core::router::JsonRpcMethodInfo;
+use spikard_http::jsonrpc::error_codes;
+use spikard_http::server::build_router_with_handlers_and_config;
+use spikard_http::testing::TestClient;
+use spikard_http::{Handler, HandlerResult, JsonRpcConfig, Method, RequestData, Route, ServerConfig};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+#[derive(Debug, PartialEq, serde::Deserialize)]
+struct Answer {
+    answer: u32,
+}
+
+/// Answers 42, or fails when built with `fail`.
+struct Oracle {
+    fail: bool,
+}
+
+impl Handler for Oracle {
+    fn call(
+        &self,
+        _request: Request<Body>,
+        _request_data: RequestData,
+    ) -> Pin<Box<dyn Future<Output = HandlerResult> + Send + '_>> {
+        Box::pin(async move {
+            if self.fail {
+                return Err((StatusCode::INTERNAL_SERVER_ERROR, "oracle is asleep".to_string()));
+            }
+            Ok(axum::http::Response::builder()
+                .status(StatusCode::OK)
+                .header("content-type", "application/json")
+                .body(Body::from(json!({"answer": 42}).to_string()))
+                .expect("response"))
+        })
+    }
+}
+
+fn rpc_route(method_name: &str, fail: bool) -> (Route, Arc<dyn Handler>) {
+    let route = Route {
+        method: Method::Post,
+        path: format!("/{method_name}"),
+        handler_name: method_name.to_string(),
+        expects_json_body: false,
+        cors: None,
+        is_async: true,
+        file_params: None,
+        request_validator: None,
+        response_validator: None,
+        parameter_validator: None,
+        jsonrpc_method: Some(JsonRpcMethodInfo {
+            method_name: method_name.to_string(),
+            description: None,
+            params_schema: None,
+            result_schema: None,
+            deprecated: false,
+            tags: Vec::new(),
+        }),
+        compression: None,
+        body_limit: None,
+        request_timeout_secs: None,
+        rate_limit: None,
+        caching: None,
+        response_cache: None,
+        auth: None,
+        #[cfg(feature = "di")]
+        handler_dependencies: Vec::new(),
+    };
+    (route, Arc::new(Oracle { fail }))
+}
+
+fn client(endpoint_path: &str) -> TestClient {
+    let config = ServerConfig {
+        jsonrpc: Some(JsonRpcConfig {
+            enabled: true,
+            endpoint_path: endpoint_path.to_string(),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let routes = vec![rpc_route("oracle.ask", false), rpc_route("oracle.sleep", true)];
+    let app = build_router_with_handlers_and_config(routes, config, Vec::new()).expect("router");
+    TestClient::from_router(app).expect("client")
+}
+
+#[tokio::test]
+async fn jsonrpc_call_deserializes_the_result() {
+    let client = client("/rpc");
+    let answer = client
+        .jsonrpc_call::<Answer>("oracle.ask", Some(json!({"question": "everything"})))
+        .await
+        .expect("transport");
+    assert_eq!(answer.expect("result"), Answer { answer: 42 });
+}
+
+#[tokio::test]
+async fn jsonrpc_call_returns_error_objects() {
+    let client = client("/rpc");
+    let asleep = client
+        .jsonrpc_call::<Answer>("oracle.sleep", None)
+        .await
+        .expect("transport")
+        .expect_err("handler error");
+    assert_eq!(asleep.code, error_codes::INTERNAL_ERROR);
+    assert_eq!(asleep.data.expect("data")["details"], "oracle is asleep");
+
+    let missing = client
+        .jsonrpc_call::<Answer>("oracle.missing", None)
+        .await
+        .expect("transport")
+        .expect_err("unknown method");
+    assert_eq!(missing.code, error_codes::METHOD_NOT_FOUND);
+}
+
+#[tokio::test]
+async fn jsonrpc_call_reports_results_of_the_wrong_type() {
+    let client = client("/rpc");
+    let error = client
+        .jsonrpc_call::<String>("oracle.ask", None)
+        .await
+        .expect_err("type mismatch");
+    assert!(error.to_string().contains("JSON-RPC result"), "{error}");
+}
+
+#[tokio::test]
+async fn jsonrpc_batch_keeps_request_order_at_custom_endpoints() {
+    let client = client("/api/rpc");
+    let results = client
+        .jsonrpc_batch_at(
+            "/api/rpc",
+            vec![
+                ("oracle.sleep".to_string(), None),
+                ("oracle.ask".to_string(), Some(json!([1, 2]))),
+                ("oracle.missing".to_string(), None),
+            ],
+        )
+        .await
+        .expect("transport");
+
+    assert_eq!(results.len(), 3);
+    assert_eq!(
+        results[0].as_ref().expect_err("asleep").code,
+        error_codes::INTERNAL_ERROR
+    );
+    assert_eq!(results[1].as_ref().expect("answer"), &json!({"answer": 42}));
+    assert_eq!(
+        results[2].as_ref().expect_err("missing").code,
+        error_codes::METHOD_NOT_FOUND
+    );
+}
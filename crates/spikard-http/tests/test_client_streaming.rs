@@ -0,0 +1,170 @@
+//! `TestClient` reads SSE and chunked responses incrementally.
+
+use axum::http::Method;
+use axum::{Router, routing::get};
+use bytes::Bytes;
+use futures::StreamExt;
+use serde_json::json;
+use spikard_http::HandlerResponse;
+use spikard_http::sse::{SseEvent, SseEventProducer, SseState, sse_handler};
+use spikard_http::testing::{StreamError, TestClient};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::Duration;
+
+const WAIT: Duration = Duration::from_secs(2);
+
+/// Emits numbered `tick` events forever.
+struct Ticker {
+    next: AtomicUsize,
+}
+
+impl SseEventProducer for Ticker {
+    async fn next_event(&self) -> Option<SseEvent> {
+        let n = self.next.fetch_add(1, Ordering::Relaxed);
+        Some(
+            SseEvent::with_type("tick", json!({"n": n}))
+                .with_id(n.to_string())
+                .with_retry(1000),
+        )
+    }
+}
+
+/// Sets its flag when the response body holding it is dropped.
+struct DropFlag(Arc<AtomicBool>);
+
+impl Drop for DropFlag {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+}
+
+fn ticker_client() -> TestClient {
+    let state = SseState::new(Ticker {
+        next: AtomicUsize::new(0),
+    });
+    let app = Router::new()
+        .route("/ticks", get(sse_handler::<Ticker>))
+        .with_state(state);
+    TestClient::from_router(app).expect("client")
+}
+
+fn chunked_client(dropped: Arc<AtomicBool>) -> TestClient {
+    let app = Router::new()
+        .route(
+            "/finite",
+            get(|| async {
+                let chunks = ["alpha,", "beta,", "gamma"].map(|chunk| Ok::<_, std::io::Error>(Bytes::from(chunk)));
+                HandlerResponse::stream(futures::stream::iter(chunks)).into_response()
+            }),
+        )
+        .route(
+            "/stalled",
+            get(move || {
+                let flag = DropFlag(Arc::clone(&dropped));
+                async move {
+                    let first = futures::stream::once(async { Ok::<_, std::io::Error>(Bytes::from_static(b"first")) });
+                    let stalled = futures::stream::pending().map(move |chunk| {
+                        let _flag = &flag;
+                        chunk
+                    });
+                    let stream = first.chain(stalled);
+                    HandlerResponse::stream(stream).into_response()
+                }
+            }),
+        );
+    TestClient::from_router(app).expect("client")
+}
+
+#[tokio::test]
+async fn sse_reader_yields_events_with_all_fields_from_an_endless_stream() {
+    let client = ticker_client();
+    let mut events = client.sse("/ticks", None, None).await.expect("sse");
+    assert_eq!(events.status(), 200);
+    assert!(
+        events
+            .header("Content-Type")
+            .expect("content type")
+            .starts_with("text/event-stream")
+    );
+
+    let first = events.next_event(WAIT).await.expect("read").expect("event");
+    assert_eq!(first.event.as_deref(), Some("tick"));
+    assert_eq!(first.id.as_deref(), Some("0"));
+    assert_eq!(first.retry, Some(1000));
+    assert_eq!(first.as_json().expect("json"), json!({"n": 0}));
+
+    let more = events.next_events(2, WAIT).await.expect("read");
+    let ids: Vec<_> = more.iter().filter_map(|event| event.id.as_deref()).collect();
+    assert_eq!(ids, ["1", "2"]);
+    assert_eq!(events.last_event_id(), Some("2"));
+    assert_eq!(events.reconnection_time(), Some(1000));
+
+    events.disconnect();
+    assert_eq!(events.next_event(WAIT).await, Err(StreamError::Disconnected));
+}
+
+#[tokio::test]
+async fn streaming_response_reads_chunks_until_the_end() {
+    let client = chunked_client(Arc::default());
+    let mut response = client
+        .stream(Method::GET, "/finite", Bytes::new(), None, None)
+        .await
+        .expect("stream");
+    assert_eq!(response.status(), 200);
+
+    let first = response.next_chunk(WAIT).await.expect("read").expect("chunk");
+    assert_eq!(first, "alpha,");
+    assert_eq!(response.read_to_end(WAIT).await.expect("rest"), "beta,gamma");
+    assert!(response.is_closed());
+    assert_eq!(response.next_chunk(WAIT).await, Ok(None));
+}
+
+#[tokio::test]
+async fn streaming_response_times_out_and_disconnects_from_a_stalled_stream() {
+    let dropped = Arc::new(AtomicBool::new(false));
+    let client = chunked_client(Arc::clone(&dropped));
+    let mut response = client
+        .stream(Method::GET, "/stalled", Bytes::new(), None, None)
+        .await
+        .expect("stream");
+
+    let first = response.next_chunk(WAIT).await.expect("read").expect("chunk");
+    assert_eq!(first, "first");
+    let wait = Duration::from_millis(50);
+    assert_eq!(response.next_chunk(wait).await, Err(StreamError::Timeout(wait)));
+    assert!(!dropped.load(Ordering::Relaxed));
+
+    response.disconnect();
+    assert!(
+        dropped.load(Ordering::Relaxed),
+        "server stream should be dropped on disconnect"
+    );
+    assert_eq!(response.next_chunk(WAIT).await, Err(StreamError::Disconnected));
+}
+
+#[tokio::test]
+async fn chunked_responses_can_be_read_as_sse() {
+    let app = Router::new().route(
+        "/events",
+        get(|| async {
+            let chunks = ["event: greet\nda", "ta: hello\ndata: world\n", "\nid: 9\ndata: bye\n\n"]
+                .map(|chunk| Ok::<_, std::io::Error>(Bytes::from(chunk)));
+            HandlerResponse::stream(futures::stream::iter(chunks)).into_response()
+        }),
+    );
+    let client = TestClient::from_router(app).expect("client");
+    let mut events = client
+        .stream(Method::GET, "/events", Bytes::new(), None, None)
+        .await
+        .expect("stream")
+        .into_sse();
+
+    let greeting = events.next_event(WAIT).await.expect("read").expect("event");
+    assert_eq!(greeting.event.as_deref(), Some("greet"));
+    assert_eq!(greeting.data, "hello\nworld");
+    let goodbye = events.next_event(WAIT).await.expect("read").expect("event");
+    assert_eq!(goodbye.id.as_deref(), Some("9"));
+    assert_eq!(goodbye.event, None);
+    assert_eq!(events.next_event(WAIT).await, Ok(None));
+}
@@ -0,0 +1,185 @@
+use axum::Extension;
+use axum::body::Body;
+use axum::extract::ConnectInfo;
+use axum::http::{Request, StatusCode};
+use serde_json::{Value, json};
+use spikard_http::server::build_router_with_handlers_and_config;
+use spikard_http::{
+    Handler, HandlerResult, Method, OpenApiConfig, RateLimitConfig, RequestData, Route, ServerConfig,
+    TrustedProxyConfig,
+};
+use std::future::Future;
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::sync::Arc;
+
+struct ClientEcho;
+
+impl Handler for ClientEcho {
+    fn call(
+        &self,
+        _request: Request<Body>,
+        request_data: RequestData,
+    ) -> Pin<Box<dyn Future<Output = HandlerResult> + Send + '_>> {
+        Box::pin(async move {
+            let body = json!({
+                "client_ip": request_data.client_ip,
+                "scheme": request_data.scheme,
+                "host": request_data.host,
+            });
+            Ok(axum::http::Response::builder()
+                .status(StatusCode::OK)
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string()))
+                .expect("response"))
+        })
+    }
+}
+
+fn route(path: &str) -> (Route, Arc<dyn Handler>) {
+    let route = Route {
+        method: Method::Get,
+        path: path.to_string(),
+        handler_name: "client_echo".to_string(),
+        expects_json_body: false,
+        cors: None,
+        is_async: true,
+        file_params: None,
+        request_validator: None,
+        response_validator: None,
+        parameter_validator: None,
+        jsonrpc_method: None,
+        compression: None,
+        body_limit: None,
+        request_timeout_secs: None,
+        rate_limit: None,
+        caching: None,
+        response_cache: None,
+        auth: None,
+        #[cfg(feature = "di")]
+        handler_dependencies: Vec::new(),
+    };
+    (route, Arc::new(ClientEcho))
+}
+
+fn server(config: ServerConfig, peer: &str) -> axum_test::TestServer {
+    let app = build_router_with_handlers_and_config(vec![route("/whoami")], config, Vec::new()).expect("router");
+    let peer: SocketAddr = peer.parse().expect("peer address");
+    axum_test::TestServer::new(app.layer(Extension(ConnectInfo(peer))))
+}
+
+fn behind_proxy() -> ServerConfig {
+    ServerConfig {
+        trusted_proxies: Some(TrustedProxyConfig {
+            proxies: vec!["10.0.0.0/8".to_string()],
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn request_data_carries_the_forwarded_client() {
+    let server = server(behind_proxy(), "10.0.0.2:41000");
+
+    let response = server
+        .get("/whoami")
+        .add_header("x-forwarded-for", "203.0.113.7, 10.0.0.9")
+        .add_header("x-forwarded-proto", "https")
+        .add_header("x-forwarded-host", "api.example.com")
+        .await;
+
+    assert_eq!(
+        response.json::<Value>(),
+        json!({"client_ip": "203.0.113.7", "scheme": "https", "host": "api.example.com"})
+    );
+}
+
+#[test]
+fn hops_without_a_proxy_allowlist_are_rejected_at_startup() {
+    let config = ServerConfig {
+        trusted_proxies: Some(TrustedProxyConfig {
+            hops: Some(1),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let error = build_router_with_handlers_and_config(vec![route("/whoami")], config, Vec::new())
+        .expect_err("an empty allowlist must not trust every peer implicitly");
+    assert!(error.contains("trust_all"), "{error}");
+}
+
+#[tokio::test]
+async fn trust_all_honours_forwarding_headers_from_any_peer() {
+    let config = ServerConfig {
+        trusted_proxies: Some(TrustedProxyConfig {
+            hops: Some(1),
+            trust_all: true,
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let server = server(config, "198.51.100.20:41000");
+
+    let response = server
+        .get("/whoami")
+        .add_header("x-forwarded-for", "192.0.2.9, 203.0.113.7")
+        .await;
+
+    assert_eq!(response.json::<Value>()["client_ip"], "203.0.113.7");
+}
+
+#[tokio::test]
+async fn forwarding_headers_from_untrusted_peers_are_ignored() {
+    let server = server(behind_proxy(), "198.51.100.20:41000");
+
+    let response = server
+        .get("/whoami")
+        .add_header("forwarded", "for=203.0.113.7;proto=https")
+        .await;
+
+    let body = response.json::<Value>();
+    assert_eq!(body["client_ip"], "198.51.100.20");
+    assert_eq!(body["scheme"], "http");
+}
+
+#[tokio::test]
+async fn ip_rate_limits_are_keyed_by_the_real_client() {
+    let config = ServerConfig {
+        rate_limit: Some(RateLimitConfig {
+            per_second: 1,
+            burst: 1,
+            ip_based: true,
+            key: None,
+        }),
+        ..behind_proxy()
+    };
+    let server = server(config, "10.0.0.2:41000");
+
+    let first = server.get("/whoami").add_header("x-forwarded-for", "203.0.113.7").await;
+    assert_eq!(first.status_code(), StatusCode::OK);
+    let repeat = server.get("/whoami").add_header("x-forwarded-for", "203.0.113.7").await;
+    assert_eq!(repeat.status_code(), StatusCode::TOO_MANY_REQUESTS);
+    let other = server.get("/whoami").add_header("x-forwarded-for", "192.0.2.44").await;
+    assert_eq!(other.status_code(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn openapi_servers_default_to_the_forwarded_origin() {
+    let config = ServerConfig {
+        openapi: Some(OpenApiConfig {
+            enabled: true,
+            ..Default::default()
+        }),
+        ..behind_proxy()
+    };
+    let server = server(config, "10.0.0.2:41000");
+
+    let spec: Value = server
+        .get("/openapi.json")
+        .add_header("forwarded", "for=203.0.113.7;proto=https;host=api.example.com")
+        .await
+        .json();
+
+    assert_eq!(spec["servers"], json!([{"url": "https://api.example.com"}]));
+}
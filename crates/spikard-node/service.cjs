@@ -174,6 +174,41 @@ class App {
       return fn;
     };
   }
+  /**
+   * Called once before the listener binds; a failing hook aborts boot.
+   */
+  onStartup(hook) {
+    this._app.onStartup(hook.name || "onStartup", hook);
+    return this;
+  }
+  /**
+   * Called once after graceful shutdown completes, in reverse registration order.
+   */
+  onShutdown(hook) {
+    this._app.onShutdown(hook.name || "onShutdown", hook);
+    return this;
+  }
+  /**
+   * Readiness check run on every readiness probe under its own timeout; an error reports not ready.
+   */
+  healthCheck(name, check) {
+    this._app.healthCheck(name, check);
+    return this;
+  }
+  /**
+   * Derives the bucket key for custom-keyed rate limits; returning nothing falls back to the client IP.
+   */
+  rateLimitKeyExtractor(extractor) {
+    this._app.rateLimitKeyExtractor(extractor);
+    return this;
+  }
+  /**
+   * Named batch loader for GraphQL field loaders; returns one value per key, in order.
+   */
+  dataLoader(name, loader) {
+    this._app.dataLoader(name, loader);
+    return this;
+  }
   /**
    * Run the HTTP server using the configured routes.
    *
@@ -202,6 +202,24 @@ pub struct JsFieldErrorSpec {
     pub message: String,
 }
 
+/// Routes a single object field through a named batch loader.
+#[derive(Clone, Default, serde::Serialize, serde::Deserialize)]
+#[napi(object, js_name = "FieldLoaderSpec")]
+pub struct JsFieldLoaderSpec {
+    /// The object type declaring the field (e.g. `"Post"`).
+    #[napi(js_name = "typeName")]
+    #[serde(rename = "typeName")]
+    pub type_name: String,
+    /// The field resolved through the loader (e.g. `"author"`).
+    pub field: String,
+    /// Name of the loader registered with `App.dataLoader`.
+    pub loader: String,
+    /// Parent JSON field (or root-field argument) holding the key or list of keys.
+    #[napi(js_name = "keyField")]
+    #[serde(rename = "keyField")]
+    pub key_field: String,
+}
+
 /// Configuration for building and executing a dynamic-SDL schema.
 #[derive(Clone, Default, serde::Serialize, serde::Deserialize)]
 #[napi(object, js_name = "DynamicSchemaConfig")]
@@ -222,6 +240,10 @@ pub struct JsDynamicSchemaConfig {
     #[napi(js_name = "fieldErrors")]
     #[serde(rename = "fieldErrors")]
     pub field_errors: Option<Vec<JsFieldErrorSpec>>,
+    /// Fields resolved through batch loaders rather than `response_data`.
+    #[napi(js_name = "fieldLoaders")]
+    #[serde(rename = "fieldLoaders")]
+    pub field_loaders: Option<Vec<JsFieldLoaderSpec>>,
 }
 
 /// Configuration for GraphQL routes
@@ -856,6 +878,146 @@ pub struct JsApiKeyConfig {
     pub header_name: String,
 }
 
+/// JWT claims structure - can be extended based on needs
+#[derive(Clone, Default, serde::Serialize, serde::Deserialize)]
+#[napi(object, js_name = "Claims")]
+pub struct JsClaims {
+    pub sub: String,
+    pub exp: i64,
+    pub iat: Option<i64>,
+    pub nbf: Option<i64>,
+    pub aud: Option<Vec<String>>,
+    pub iss: Option<String>,
+}
+
+/// Client as seen by the outermost trusted proxy
+#[derive(Clone, Default, serde::Serialize, serde::Deserialize)]
+#[napi(object, js_name = "ClientInfo")]
+pub struct JsClientInfo {
+    /// Client IP address (None when the transport does not expose one)
+    pub ip: Option<String>,
+    /// Scheme the client used (`"http"` or `"https"`)
+    pub scheme: String,
+    /// Host the client addressed, including any port
+    pub host: Option<String>,
+}
+
+/// Origin the client addressed (e.g. `"https://api.example.com"`)
+#[napi(js_name = "clientInfoOrigin")]
+pub fn client_info_origin(cfg: JsClientInfo) -> Option<String> {
+    spikard::ClientInfo::from(cfg).origin()
+}
+
+/// Session of the current request
+#[derive(Clone)]
+#[napi(js_name = "Session")]
+pub struct JsSession {
+    inner: spikard::Session,
+}
+
+#[napi]
+impl JsSession {
+    /// Session holding `data`, detached from any request
+    #[allow(clippy::missing_errors_doc)]
+    #[napi(factory, js_name = "fromData")]
+    pub fn from_data(data: serde_json::Value) -> napi::Result<JsSession> {
+        let data =
+            serde_json::from_value(data).map_err(|e| napi::Error::new(napi::Status::InvalidArg, e.to_string()))?;
+        Ok(Self {
+            inner: spikard::Session::from_data(data),
+        })
+    }
+
+    /// Whether the request arrived without a valid session
+    #[napi(js_name = "isNew")]
+    pub fn is_new(&self) -> bool {
+        self.inner.is_new()
+    }
+
+    /// Raw JSON value stored under `key`
+    #[napi(js_name = "getValue")]
+    pub fn get_value(&self, key: String) -> Option<serde_json::Value> {
+        self.inner.get_value(&key)
+    }
+
+    /// Store `value` under `key`
+    #[allow(clippy::missing_errors_doc)]
+    #[napi]
+    pub fn insert(&self, key: String, value: serde_json::Value) -> napi::Result<()> {
+        self.inner
+            .insert(key, value)
+            .map_err(|e| napi::Error::new(napi::Status::InvalidArg, e.to_string()))
+    }
+
+    /// Remove and return the value stored under `key`
+    #[napi]
+    pub fn remove(&self, key: String) -> Option<serde_json::Value> {
+        self.inner.remove(&key)
+    }
+
+    /// Remove every value, keeping the session itself
+    #[napi]
+    pub fn clear(&self) {
+        self.inner.clear()
+    }
+
+    /// Replace all session data
+    #[allow(clippy::missing_errors_doc)]
+    #[napi]
+    pub fn replace(&self, data: serde_json::Value) -> napi::Result<()> {
+        let data =
+            serde_json::from_value(data).map_err(|e| napi::Error::new(napi::Status::InvalidArg, e.to_string()))?;
+        self.inner.replace(data);
+        Ok(())
+    }
+
+    /// Snapshot of the session data
+    #[napi]
+    pub fn data(&self) -> serde_json::Value {
+        serde_json::Value::Object(self.inner.data())
+    }
+
+    /// Issue a new session id, keeping the data
+    #[napi]
+    pub fn regenerate(&self) {
+        self.inner.regenerate()
+    }
+
+    /// Delete the session and expire its cookie
+    #[napi]
+    pub fn destroy(&self) {
+        self.inner.destroy()
+    }
+
+    /// Internal response headers that hand this session's changes back to the server
+    #[napi(js_name = "bindingHeaders")]
+    pub fn binding_headers(&self) -> HashMap<String, String> {
+        self.inner.binding_headers()
+    }
+}
+
+/// CSRF token expected on the client's next unsafe request
+#[derive(Clone)]
+#[napi(js_name = "CsrfToken")]
+pub struct JsCsrfToken {
+    inner: spikard::CsrfToken,
+}
+
+#[napi]
+impl JsCsrfToken {
+    #[napi(constructor)]
+    pub fn new(token: String) -> Self {
+        Self {
+            inner: spikard::CsrfToken(token),
+        }
+    }
+
+    #[napi(js_name = "asStr")]
+    pub fn as_str(&self) -> String {
+        self.inner.as_str().to_string()
+    }
+}
+
 /// Static file serving configuration
 #[derive(Clone, Default, serde::Serialize, serde::Deserialize)]
 #[napi(object, js_name = "StaticFilesConfig")]
@@ -1022,6 +1184,8 @@ impl JsApp {
     /// argument-dependent results (e.g. `user(id: "user-42")`) must already be baked into
     /// `response_data` at the correct path. See `spikard_graphql.dynamic` for the full
     /// resolver-data encoding and field-level-error support via `config.field_errors`.
+    /// Fields listed in `config.field_loaders` batch through the loaders registered with
+    /// `App.data_loader`.
     ///
     /// # Arguments
     ///
@@ -1050,10 +1214,7 @@ impl JsApp {
             .map_err(|e| napi::Error::new(napi::Status::GenericFailure, e.to_string()))?;
         let method_core: spikard::Method = serde_json::from_str(&method_json)
             .map_err(|e| napi::Error::new(napi::Status::GenericFailure, e.to_string()))?;
-        let config_json = serde_json::to_string(&config)
-            .map_err(|e| napi::Error::new(napi::Status::GenericFailure, e.to_string()))?;
-        let config_core: spikard::DynamicSchemaConfig = serde_json::from_str(&config_json)
-            .map_err(|e| napi::Error::new(napi::Status::GenericFailure, e.to_string()))?;
+        let config_core: spikard::DynamicSchemaConfig = config.into();
         self.inner
             .lock()
             .unwrap()
@@ -1217,6 +1378,95 @@ impl JsRouteBuilder {
     }
 }
 
+/// Settings shared by a group of routes, folded into each route's builder with `apply`.
+#[derive(Clone)]
+#[napi(js_name = "RouteGroup")]
+pub struct JsRouteGroup {
+    inner: Arc<spikard::RouteGroupScope>,
+}
+
+#[napi]
+impl JsRouteGroup {
+    /// Settings for a top-level group under `prefix`.
+    #[napi(constructor)]
+    pub fn new(prefix: String) -> Self {
+        Self {
+            inner: Arc::new(spikard::RouteGroupScope::new(&prefix)),
+        }
+    }
+
+    /// The path prefix shared by the routes of the group.
+    #[napi]
+    pub fn prefix(&self) -> String {
+        self.inner.prefix().to_string()
+    }
+
+    /// The full path of a route registered under the group as `path`.
+    #[napi]
+    pub fn path(&self, path: String) -> String {
+        self.inner.path(&path)
+    }
+
+    /// Settings for a group nested under this one.
+    #[napi]
+    pub fn group(&self, prefix: String) -> JsRouteGroup {
+        Self {
+            inner: Arc::new(self.inner.nested(&prefix)),
+        }
+    }
+
+    /// Tag the group's routes in the `OpenAPI` document.
+    #[napi]
+    pub fn tag(&self, tag: String) -> JsRouteGroup {
+        let mut scope = (*self.inner).clone();
+        scope.tag(tag);
+        Self { inner: Arc::new(scope) }
+    }
+
+    /// Declare dependency keys resolved for every route in the group.
+    #[napi(js_name = "handlerDependencies")]
+    pub fn handler_dependencies(&self, dependencies: Vec<String>) -> JsRouteGroup {
+        let mut scope = (*self.inner).clone();
+        scope.handler_dependencies(dependencies);
+        Self { inner: Arc::new(scope) }
+    }
+
+    /// Apply a CORS configuration to routes that do not set their own.
+    #[napi]
+    pub fn cors(&self, cors: JsCorsConfig) -> JsRouteGroup {
+        let mut scope = (*self.inner).clone();
+        scope.cors(cors.into());
+        Self { inner: Arc::new(scope) }
+    }
+
+    /// Apply a rate limit to routes that do not set their own.
+    #[napi(js_name = "rateLimit")]
+    pub fn rate_limit(&self, rate_limit: JsRateLimitConfig) -> JsRouteGroup {
+        let mut scope = (*self.inner).clone();
+        scope.rate_limit(rate_limit.into());
+        Self { inner: Arc::new(scope) }
+    }
+
+    /// Require authentication on routes that do not set their own.
+    #[napi]
+    pub fn auth(&self, jwt: Option<JsJwtConfig>, api_key: Option<JsApiKeyConfig>) -> JsRouteGroup {
+        let mut scope = (*self.inner).clone();
+        scope.auth(spikard::RouteAuthConfig {
+            jwt: jwt.map(Into::into),
+            api_key: api_key.map(Into::into),
+        });
+        Self { inner: Arc::new(scope) }
+    }
+
+    /// Fold the group settings into `builder`; settings on the route itself win.
+    #[napi]
+    pub fn apply(&self, builder: &JsRouteBuilder) -> JsRouteBuilder {
+        JsRouteBuilder {
+            inner: Arc::new(self.inner.apply((*builder.inner).clone())),
+        }
+    }
+}
+
 /// JSON-RPC method metadata for routes that support JSON-RPC
 ///
 /// This struct captures the metadata needed to expose HTTP routes as JSON-RPC methods,
@@ -1461,12 +1711,48 @@ pub struct JsResponseSnapshot {
     pub body: JsBytes,
 }
 
+/// What is kept in a response snapshot and which values are redacted.
+#[derive(Clone, Default, serde::Serialize, serde::Deserialize)]
+#[napi(object, js_name = "SnapshotOptions")]
+pub struct JsSnapshotOptions {
+    /// Response headers included in the snapshot (case-insensitive), `content-type` by default.
+    pub headers: Option<Vec<String>>,
+    /// JSON pointers into the snapshot document to redact; a `*` segment matches any key or index.
+    pub redact: Option<Vec<String>>,
+    /// Replace every string that is a UUID with `[UUID]`.
+    #[napi(js_name = "redactUuids")]
+    #[serde(rename = "redactUuids")]
+    pub redact_uuids: Option<bool>,
+    /// Replace every string that is an RFC 3339 timestamp with `[TIMESTAMP]`.
+    #[napi(js_name = "redactTimestamps")]
+    #[serde(rename = "redactTimestamps")]
+    pub redact_timestamps: Option<bool>,
+}
+
+/// Result of comparing a response with its snapshot file.
+#[derive(Clone, Default, serde::Serialize, serde::Deserialize)]
+#[napi(object, js_name = "SnapshotOutcome")]
+pub struct JsSnapshotOutcome {
+    /// `matched`, `created`, `updated` or `mismatched`.
+    pub status: String,
+    /// Snapshot file contents of a mismatch, absent when the file does not exist.
+    pub expected: Option<String>,
+    /// Serialized response of a mismatch.
+    pub actual: Option<String>,
+}
+
 /// A single Server-Sent Event.
 #[derive(Clone, Default, serde::Serialize, serde::Deserialize)]
 #[napi(object, js_name = "TestingSseEvent")]
 pub struct JsTestingSseEvent {
-    /// The data field of the event.
+    /// The data of the event; multiple `data` lines are joined with `\n`.
     pub data: String,
+    /// The `event` type, if the server set one.
+    pub event: Option<String>,
+    /// The `id` field, if the server set one.
+    pub id: Option<String>,
+    /// The `retry` reconnection time in milliseconds, if the server set one.
+    pub retry: Option<i64>,
 }
 
 /// Snapshot of a GraphQL subscription exchange over WebSocket.
@@ -1506,6 +1792,51 @@ pub struct JsTestClient {
 
 #[napi]
 impl JsTestClient {
+    /// Replace the dependency registered as `key` with `value` until it is restored
+    #[allow(clippy::missing_errors_doc)]
+    #[napi(js_name = "overrideDependency")]
+    pub fn override_dependency(&self, key: String, value: serde_json::Value) -> Result<()> {
+        self.inner
+            .override_dependency_value(&key, value)
+            .map_err(|e| napi::Error::new(napi::Status::InvalidArg, e))
+    }
+
+    /// Replace the dependency registered as `key` with `value` until the returned guard is restored
+    #[allow(clippy::missing_errors_doc)]
+    #[napi(js_name = "overrideDependencyScope")]
+    pub fn override_dependency_scope(
+        &self,
+        key: String,
+        value: serde_json::Value,
+    ) -> Result<JsDependencyOverrideGuard> {
+        let guard = self
+            .inner
+            .override_dependency_scope(&key, value)
+            .map_err(|e| napi::Error::new(napi::Status::InvalidArg, e))?;
+        Ok(JsDependencyOverrideGuard {
+            key,
+            inner: std::sync::Mutex::new(Some(guard)),
+        })
+    }
+
+    /// Restore the registered dependency for `key`
+    #[allow(clippy::missing_errors_doc)]
+    #[napi(js_name = "restoreDependency")]
+    pub fn restore_dependency(&self, key: String) -> Result<()> {
+        self.inner
+            .restore_dependency(&key)
+            .map_err(|e| napi::Error::new(napi::Status::GenericFailure, e))
+    }
+
+    /// Restore every overridden dependency
+    #[allow(clippy::missing_errors_doc)]
+    #[napi(js_name = "clearDependencyOverrides")]
+    pub fn clear_dependency_overrides(&self) -> Result<()> {
+        self.inner
+            .clear_dependency_overrides()
+            .map_err(|e| napi::Error::new(napi::Status::GenericFailure, e))
+    }
+
     ///  a GraphQL query/mutation to a custom endpoint
     #[allow(clippy::missing_errors_doc)]
     #[napi(js_name = "graphqlAt")]
@@ -1582,6 +1913,31 @@ impl JsTestClient {
     }
 }
 
+/// Restores a dependency overridden through `TestClient.overrideDependencyScope`
+#[napi(js_name = "DependencyOverrideGuard")]
+pub struct JsDependencyOverrideGuard {
+    key: String,
+    inner: std::sync::Mutex<Option<spikard_core::di::DependencyOverrideGuard>>,
+}
+
+#[napi]
+impl JsDependencyOverrideGuard {
+    /// The overridden dependency key
+    #[napi]
+    pub fn key(&self) -> String {
+        self.key.clone()
+    }
+
+    /// Put back whatever the override replaced; later calls do nothing
+    #[napi]
+    pub fn restore(&self) {
+        self.inner
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .take();
+    }
+}
+
 #[derive(Clone)]
 #[napi(js_name = "HandlerResult")]
 pub struct JsHandlerResult {
@@ -1738,6 +2094,63 @@ pub fn schema_full() -> JsFullSchemaConfig {
     spikard::schema_full().into()
 }
 
+/// Serialize a response into the snapshot format shared with the Rust, Python and Ruby test clients.
+#[napi(js_name = "responseSnapshotJson")]
+pub fn response_snapshot_json(response: JsResponseSnapshot, options: Option<JsSnapshotOptions>) -> Result<String> {
+    spikard_http::ResponseSnapshot::from(response)
+        .to_snapshot_json(&options.unwrap_or_default().into())
+        .map_err(|e| napi::Error::new(napi::Status::GenericFailure, e.to_string()))
+}
+
+/// Compare a response with the snapshot file at `path`, writing it as `update` allows.
+///
+/// `update` is `missing`, `always` or `never`; by default `SPIKARD_UPDATE_SNAPSHOTS` selects it.
+#[napi(js_name = "checkSnapshot")]
+pub fn check_snapshot(
+    response: JsResponseSnapshot,
+    path: String,
+    options: Option<JsSnapshotOptions>,
+    update: Option<String>,
+) -> Result<JsSnapshotOutcome> {
+    let update = match update {
+        Some(mode) => serde_json::from_value(serde_json::Value::String(mode))
+            .map_err(|e| napi::Error::new(napi::Status::InvalidArg, format!("Invalid snapshot update mode: {e}")))?,
+        None => spikard_http::testing::SnapshotUpdate::from_env(),
+    };
+    spikard_http::testing::check_snapshot(
+        &response.into(),
+        std::path::Path::new(&path),
+        &options.unwrap_or_default().into(),
+        update,
+    )
+    .map(Into::into)
+    .map_err(|e| napi::Error::new(napi::Status::GenericFailure, e.to_string()))
+}
+
+/// Path of the snapshot `name` in the `snapshots` directory next to the test file `file`.
+#[napi(js_name = "snapshotPath")]
+pub fn snapshot_path(root_dir: String, file: String, name: String) -> String {
+    spikard_http::testing::snapshot_path(&root_dir, &file, &name)
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// Assert a response against its snapshot file, throwing with a line diff on mismatch.
+#[napi(js_name = "assertSnapshotFile")]
+pub fn assert_snapshot_file(
+    response: JsResponseSnapshot,
+    path: String,
+    options: Option<JsSnapshotOptions>,
+) -> Result<JsSnapshotOutcome> {
+    spikard_http::testing::assert_snapshot_file(
+        &response.into(),
+        std::path::Path::new(&path),
+        &options.unwrap_or_default().into(),
+    )
+    .map(Into::into)
+    .map_err(|message| napi::Error::new(napi::Status::GenericFailure, message))
+}
+
 pub mod service;
 
 #[allow(clippy::redundant_closure, clippy::useless_conversion)]
@@ -1773,6 +2186,30 @@ impl From<spikard::FieldErrorSpec> for JsFieldErrorSpec {
     }
 }
 
+#[allow(clippy::redundant_closure, clippy::useless_conversion)]
+impl From<JsFieldLoaderSpec> for spikard::FieldLoaderSpec {
+    fn from(val: JsFieldLoaderSpec) -> Self {
+        Self {
+            type_name: val.type_name,
+            field: val.field,
+            loader: val.loader,
+            key_field: val.key_field,
+        }
+    }
+}
+
+#[allow(clippy::redundant_closure, clippy::useless_conversion)]
+impl From<spikard::FieldLoaderSpec> for JsFieldLoaderSpec {
+    fn from(val: spikard::FieldLoaderSpec) -> Self {
+        Self {
+            type_name: val.type_name.to_string(),
+            field: val.field.to_string(),
+            loader: val.loader.to_string(),
+            key_field: val.key_field.to_string(),
+        }
+    }
+}
+
 #[allow(clippy::needless_update)]
 #[allow(clippy::field_reassign_with_default, clippy::let_and_return)]
 #[allow(clippy::redundant_closure, clippy::useless_conversion)]
@@ -1787,6 +2224,9 @@ impl From<JsDynamicSchemaConfig> for spikard::DynamicSchemaConfig {
         if let Some(__v) = val.field_errors {
             __result.field_errors = __v.into_iter().map(Into::into).collect();
         }
+        if let Some(__v) = val.field_loaders {
+            __result.field_loaders = __v.into_iter().map(Into::into).collect();
+        }
         __result
     }
 }
@@ -1799,6 +2239,7 @@ impl From<spikard::DynamicSchemaConfig> for JsDynamicSchemaConfig {
             max_complexity: val.max_complexity.map(|v| v as i64),
             max_depth: val.max_depth.map(|v| v as i64),
             field_errors: Some(val.field_errors.into_iter().map(Into::into).collect()),
+            field_loaders: Some(val.field_loaders.into_iter().map(Into::into).collect()),
         }
     }
 }
@@ -2323,6 +2764,56 @@ impl From<spikard::ApiKeyConfig> for JsApiKeyConfig {
     }
 }
 
+#[allow(clippy::redundant_closure, clippy::useless_conversion)]
+impl From<JsClaims> for spikard::Claims {
+    fn from(val: JsClaims) -> Self {
+        Self {
+            sub: val.sub,
+            exp: val.exp as usize,
+            iat: val.iat.map(|v| v as usize),
+            nbf: val.nbf.map(|v| v as usize),
+            aud: val.aud.map(|v| v.into_iter().collect()),
+            iss: val.iss,
+        }
+    }
+}
+
+#[allow(clippy::redundant_closure, clippy::useless_conversion)]
+impl From<spikard::Claims> for JsClaims {
+    fn from(val: spikard::Claims) -> Self {
+        Self {
+            sub: val.sub.to_string(),
+            exp: val.exp as i64,
+            iat: val.iat.map(|v| v as i64),
+            nbf: val.nbf.map(|v| v as i64),
+            aud: val.aud.map(|v| v.into_iter().collect()),
+            iss: val.iss.map(|v| v.to_string()),
+        }
+    }
+}
+
+#[allow(clippy::redundant_closure, clippy::useless_conversion)]
+impl From<JsClientInfo> for spikard::ClientInfo {
+    fn from(val: JsClientInfo) -> Self {
+        Self {
+            ip: val.ip.and_then(|v| v.parse().ok()),
+            scheme: val.scheme,
+            host: val.host,
+        }
+    }
+}
+
+#[allow(clippy::redundant_closure, clippy::useless_conversion)]
+impl From<spikard::ClientInfo> for JsClientInfo {
+    fn from(val: spikard::ClientInfo) -> Self {
+        Self {
+            ip: val.ip.map(|v| v.to_string()),
+            scheme: val.scheme.to_string(),
+            host: val.host.map(|v| v.to_string()),
+        }
+    }
+}
+
 #[allow(clippy::redundant_closure, clippy::useless_conversion)]
 impl From<JsStaticFilesConfig> for spikard::StaticFilesConfig {
     fn from(val: JsStaticFilesConfig) -> Self {
@@ -2623,11 +3114,46 @@ impl From<spikard_http::ResponseSnapshot> for JsResponseSnapshot {
     }
 }
 
+impl From<JsSnapshotOptions> for spikard_http::testing::SnapshotOptions {
+    fn from(val: JsSnapshotOptions) -> Self {
+        let mut __result = spikard_http::testing::SnapshotOptions::default();
+        if let Some(__v) = val.headers {
+            __result.headers = __v;
+        }
+        __result.redact = val.redact.unwrap_or_default();
+        __result.redact_uuids = val.redact_uuids.unwrap_or_default();
+        __result.redact_timestamps = val.redact_timestamps.unwrap_or_default();
+        __result
+    }
+}
+
+impl From<spikard_http::testing::SnapshotOutcome> for JsSnapshotOutcome {
+    fn from(val: spikard_http::testing::SnapshotOutcome) -> Self {
+        let status = |status: &str| Self {
+            status: status.to_string(),
+            ..Self::default()
+        };
+        match val {
+            spikard_http::testing::SnapshotOutcome::Matched => status("matched"),
+            spikard_http::testing::SnapshotOutcome::Created => status("created"),
+            spikard_http::testing::SnapshotOutcome::Updated => status("updated"),
+            spikard_http::testing::SnapshotOutcome::Mismatched { expected, actual } => Self {
+                status: "mismatched".to_string(),
+                expected,
+                actual: Some(actual),
+            },
+        }
+    }
+}
+
 #[allow(clippy::redundant_closure, clippy::useless_conversion)]
 impl From<spikard_http::testing::SseEvent> for JsTestingSseEvent {
     fn from(val: spikard_http::testing::SseEvent) -> Self {
         Self {
             data: val.data.to_string(),
+            event: val.event.map(|v| v.to_string()),
+            id: val.id.map(|v| v.to_string()),
+            retry: val.retry.map(|v| v as i64),
         }
     }
 }
@@ -2858,7 +3384,12 @@ impl From<JsValidateRequest> for spikard_http::ValidateRequest {
 #[allow(clippy::redundant_closure, clippy::useless_conversion)]
 impl From<JsTestingSseEvent> for spikard_http::testing::SseEvent {
     fn from(val: JsTestingSseEvent) -> Self {
-        Self { data: val.data }
+        Self {
+            data: val.data,
+            event: val.event,
+            id: val.id,
+            retry: val.retry.map(|v| v as u64),
+        }
     }
 }
 
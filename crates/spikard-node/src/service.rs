@@ -93,6 +93,177 @@ impl spikard::Handler for HandlerBridge {
         })
     }
 }
+/// Generated NAPI bridge for the `LifespanHook` contract.
+///
+/// Wraps a zero-argument JavaScript callable via ThreadsafeFunction so it can be
+/// used as `Arc<dyn LifespanHook>`; throwing (or rejecting) fails the hook.
+pub struct LifespanHookBridge {
+    name: String,
+    hook_fn: Arc<ThreadsafeFunction<(), Either<Promise<()>, ()>>>,
+}
+
+impl LifespanHookBridge {
+    /// Create a bridge from a hook name and a JavaScript callable.
+    pub fn new(name: String, hook_fn: ThreadsafeFunction<(), Either<Promise<()>, ()>>) -> Self {
+        Self {
+            name,
+            hook_fn: Arc::new(hook_fn),
+        }
+    }
+}
+
+// SAFETY: ThreadsafeFunction is Send+Sync. We call it only from async contexts
+// where the NAPI env is valid (within the async task spawned by call_async).
+unsafe impl Send for LifespanHookBridge {}
+unsafe impl Sync for LifespanHookBridge {}
+impl spikard::LifespanHook for LifespanHookBridge {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn run(&self, _context: spikard::LifespanContext) -> spikard::LifespanFuture {
+        let hook_fn = Arc::clone(&self.hook_fn);
+        Box::pin(async move {
+            // A sync hook returns `undefined`; an async hook returns a Promise that
+            // is awaited here, so both hook styles work.
+            match hook_fn.call_async(Ok(())).await.map_err(|e| e.reason)? {
+                Either::A(promise) => promise.await.map_err(|e| e.reason),
+                Either::B(()) => Ok(()),
+            }
+        })
+    }
+}
+/// Generated NAPI bridge for the `HealthCheck` contract.
+///
+/// Wraps a zero-argument JavaScript callable via ThreadsafeFunction so it can be
+/// used as `Arc<dyn HealthCheck>`; throwing (or rejecting) reports the
+/// application as not ready.
+pub struct HealthCheckBridge {
+    name: String,
+    check_fn: Arc<ThreadsafeFunction<(), Either<Promise<()>, ()>>>,
+}
+
+impl HealthCheckBridge {
+    /// Create a bridge from a check name and a JavaScript callable.
+    pub fn new(name: String, check_fn: ThreadsafeFunction<(), Either<Promise<()>, ()>>) -> Self {
+        Self {
+            name,
+            check_fn: Arc::new(check_fn),
+        }
+    }
+}
+
+// SAFETY: ThreadsafeFunction is Send+Sync. We call it only from async contexts
+// where the NAPI env is valid (within the async task spawned by call_async).
+unsafe impl Send for HealthCheckBridge {}
+unsafe impl Sync for HealthCheckBridge {}
+impl spikard::HealthCheck for HealthCheckBridge {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn check(&self, _context: spikard::HealthCheckContext) -> spikard::HealthCheckFuture {
+        let check_fn = Arc::clone(&self.check_fn);
+        Box::pin(async move {
+            match check_fn.call_async(Ok(())).await.map_err(|e| e.reason)? {
+                Either::A(promise) => promise.await.map_err(|e| e.reason),
+                Either::B(()) => Ok(()),
+            }
+        })
+    }
+}
+/// JavaScript callable behind a `RateLimitKeyExtractor` bridge, called without a leading error argument.
+pub type RateLimitKeyExtractorFn =
+    ThreadsafeFunction<serde_json::Value, Option<String>, serde_json::Value, napi::Status, false>;
+
+/// Generated NAPI bridge for the `RateLimitKeyExtractor` contract.
+///
+/// Wraps a synchronous JavaScript callable via ThreadsafeFunction so it can be
+/// used as `Arc<dyn RateLimitKeyExtractor>`. The calling thread blocks until the callable
+/// returns the bucket key; throwing, or returning anything but a string, falls back
+/// to the client IP.
+pub struct RateLimitKeyExtractorBridge {
+    extract_fn: RateLimitKeyExtractorFn,
+}
+
+impl RateLimitKeyExtractorBridge {
+    /// Create a bridge from a JavaScript callable.
+    pub fn new(extract_fn: RateLimitKeyExtractorFn) -> Self {
+        Self { extract_fn }
+    }
+}
+
+// SAFETY: ThreadsafeFunction is Send+Sync. We call it only from server worker
+// threads, never from the JavaScript main thread it dispatches to.
+unsafe impl Send for RateLimitKeyExtractorBridge {}
+unsafe impl Sync for RateLimitKeyExtractorBridge {}
+impl spikard::RateLimitKeyExtractor for RateLimitKeyExtractorBridge {
+    fn extract_key(&self, request: &spikard::Request<spikard::Body>) -> Option<String> {
+        let req_value = serde_json::to_value(spikard::RateLimitKeyRequest::from_request(request)).ok()?;
+        let (sender, receiver) = std::sync::mpsc::sync_channel(1);
+        let status = self.extract_fn.call_with_return_value(
+            req_value,
+            napi::threadsafe_function::ThreadsafeFunctionCallMode::NonBlocking,
+            move |key: napi::Result<Option<String>>, _env| {
+                let _ = sender.send(key.ok().flatten());
+                Ok(())
+            },
+        );
+        if status != napi::Status::Ok {
+            return None;
+        }
+        receiver.recv().ok().flatten()
+    }
+}
+/// JavaScript callable behind a `BatchLoader` bridge, called without a leading error argument.
+pub type BatchLoaderFn = ThreadsafeFunction<
+    serde_json::Value,
+    Either<Promise<HandlerReturn>, HandlerReturn>,
+    serde_json::Value,
+    napi::Status,
+    false,
+>;
+
+/// Generated NAPI bridge for the `BatchLoader` contract.
+///
+/// Wraps a JavaScript callable (sync or async) via ThreadsafeFunction so it can be
+/// used as `Arc<dyn BatchLoader>`. The callable receives the batch of keys as an
+/// array and returns one value (or `null`) per key, in order; throwing (or
+/// rejecting) fails every key in the batch.
+pub struct BatchLoaderBridge {
+    load_fn: Arc<BatchLoaderFn>,
+}
+
+impl BatchLoaderBridge {
+    /// Create a bridge from a JavaScript callable.
+    pub fn new(load_fn: BatchLoaderFn) -> Self {
+        Self {
+            load_fn: Arc::new(load_fn),
+        }
+    }
+}
+
+// SAFETY: ThreadsafeFunction is Send+Sync. We call it only from async contexts
+// where the NAPI env is valid (within the async task spawned by call_async_catch).
+unsafe impl Send for BatchLoaderBridge {}
+unsafe impl Sync for BatchLoaderBridge {}
+impl spikard::BatchLoader for BatchLoaderBridge {
+    fn load(&self, keys: Vec<serde_json::Value>) -> spikard::BatchLoadFuture {
+        let load_fn = Arc::clone(&self.load_fn);
+        Box::pin(async move {
+            // `HandlerReturn` validates as an object, which covers the returned array.
+            let values = match load_fn
+                .call_async_catch(serde_json::Value::Array(keys))
+                .await
+                .map_err(|e| e.reason)?
+            {
+                Either::A(promise) => promise.await.map_err(|e| e.reason)?.0,
+                Either::B(values) => values.0,
+            };
+            serde_json::from_value(values).map_err(|e| e.to_string())
+        })
+    }
+}
 /// Drive `spikard::App::run` from JavaScript.
 ///
 /// Each entry in `registrations` is a `[method_name, metadata, callback]` triple
@@ -321,6 +492,65 @@ impl JsApp {
             .map_err(|e| napi::Error::new(napi::Status::GenericFailure, e.to_string()))?;
         Ok(())
     }
+    /// Register a `on_startup` lifespan hook.
+    ///
+    /// Called once before the listener binds; a failing hook aborts boot.
+    #[napi]
+    pub fn on_startup(&self, name: String, hook: ThreadsafeFunction<(), Either<Promise<()>, ()>>) -> napi::Result<()> {
+        let bridge = LifespanHookBridge::new(name, hook);
+        let hook_arc: std::sync::Arc<dyn spikard::LifespanHook> = std::sync::Arc::new(bridge);
+        let mut inner = self.inner.lock().expect("app mutex poisoned");
+        inner.on_startup(hook_arc);
+        Ok(())
+    }
+    /// Register a `on_shutdown` lifespan hook.
+    ///
+    /// Called once after graceful shutdown completes, in reverse registration order.
+    #[napi]
+    pub fn on_shutdown(&self, name: String, hook: ThreadsafeFunction<(), Either<Promise<()>, ()>>) -> napi::Result<()> {
+        let bridge = LifespanHookBridge::new(name, hook);
+        let hook_arc: std::sync::Arc<dyn spikard::LifespanHook> = std::sync::Arc::new(bridge);
+        let mut inner = self.inner.lock().expect("app mutex poisoned");
+        inner.on_shutdown(hook_arc);
+        Ok(())
+    }
+    /// Register a named `health_check` readiness check.
+    ///
+    /// Readiness check run on every readiness probe under its own timeout; an error reports not ready.
+    #[napi]
+    pub fn health_check(
+        &self,
+        name: String,
+        check: ThreadsafeFunction<(), Either<Promise<()>, ()>>,
+    ) -> napi::Result<()> {
+        let bridge = HealthCheckBridge::new(name, check);
+        let check_arc: std::sync::Arc<dyn spikard::HealthCheck> = std::sync::Arc::new(bridge);
+        let mut inner = self.inner.lock().expect("app mutex poisoned");
+        inner.health_check(check_arc);
+        Ok(())
+    }
+    /// Register the `rate_limit_key_extractor` callback.
+    ///
+    /// Derives the bucket key for custom-keyed rate limits; returning nothing falls back to the client IP.
+    #[napi]
+    pub fn rate_limit_key_extractor(&self, extractor: RateLimitKeyExtractorFn) -> napi::Result<()> {
+        let bridge = RateLimitKeyExtractorBridge::new(extractor);
+        let extractor_arc: std::sync::Arc<dyn spikard::RateLimitKeyExtractor> = std::sync::Arc::new(bridge);
+        let mut inner = self.inner.lock().expect("app mutex poisoned");
+        inner.rate_limit_key_extractor(extractor_arc);
+        Ok(())
+    }
+    /// Register a named `data_loader` batch loader.
+    ///
+    /// Named batch loader for GraphQL field loaders; returns one value per key, in order.
+    #[napi]
+    pub fn data_loader(&self, name: String, loader: BatchLoaderFn) -> napi::Result<()> {
+        let bridge = BatchLoaderBridge::new(loader);
+        let loader_arc: std::sync::Arc<dyn spikard::BatchLoader> = std::sync::Arc::new(bridge);
+        let mut inner = self.inner.lock().expect("app mutex poisoned");
+        inner.data_loader(name, loader_arc);
+        Ok(())
+    }
     /// Apply configuration via the `config` configurator on the native app.
     ///
     /// Set the server configuration.
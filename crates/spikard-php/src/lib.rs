@@ -200,6 +200,56 @@ impl FieldErrorSpec {
     }
 }
 
+#[derive(Clone, serde::Serialize, serde::Deserialize, Default)]
+#[php_class]
+#[php(name = "Spikard\\Php\\FieldLoaderSpec")]
+#[serde(default)]
+pub struct FieldLoaderSpec {
+    /// The object type declaring the field (e.g. `"Post"`).
+    #[php(prop, name = "typeName")]
+    #[serde(alias = "typeName")]
+    pub type_name: String,
+    /// The field resolved through the loader (e.g. `"author"`).
+    #[php(prop, name = "field")]
+    pub field: String,
+    /// Name of the loader registered with `App::data_loader`.
+    #[php(prop, name = "loader")]
+    pub loader: String,
+    /// Parent JSON field (or root-field argument) holding the key or list of keys.
+    #[php(prop, name = "keyField")]
+    #[serde(alias = "keyField")]
+    pub key_field: String,
+}
+
+#[php_impl]
+impl FieldLoaderSpec {
+    #[php(constructor)]
+    pub fn new(typeName: String, field: String, loader: String, keyField: String) -> Self {
+        Self {
+            type_name: typeName,
+            field: field,
+            loader: loader,
+            key_field: keyField,
+        }
+    }
+
+    pub fn get_type_name(&self) -> String {
+        self.type_name.clone()
+    }
+
+    pub fn get_field(&self) -> String {
+        self.field.clone()
+    }
+
+    pub fn get_loader(&self) -> String {
+        self.loader.clone()
+    }
+
+    pub fn get_key_field(&self) -> String {
+        self.key_field.clone()
+    }
+}
+
 #[derive(Clone, serde::Serialize, serde::Deserialize)]
 #[php_class]
 #[php(name = "Spikard\\Php\\DynamicSchemaConfig")]
@@ -220,6 +270,9 @@ pub struct DynamicSchemaConfig {
     /// Field-level errors to inject at specific response paths.
     #[serde(alias = "fieldErrors")]
     pub field_errors: Vec<FieldErrorSpec>,
+    /// Fields resolved through batch loaders rather than `response_data`.
+    #[serde(alias = "fieldLoaders")]
+    pub field_loaders: Vec<FieldLoaderSpec>,
 }
 
 impl Default for DynamicSchemaConfig {
@@ -242,6 +295,7 @@ impl DynamicSchemaConfig {
             max_complexity: maxComplexity,
             max_depth: maxDepth,
             field_errors: Default::default(),
+            field_loaders: Default::default(),
         }
     }
 
@@ -260,6 +314,10 @@ impl DynamicSchemaConfig {
     pub fn get_field_errors(&self) -> Vec<FieldErrorSpec> {
         self.field_errors.clone()
     }
+
+    pub fn get_field_loaders(&self) -> Vec<FieldLoaderSpec> {
+        self.field_loaders.clone()
+    }
 }
 
 #[derive(Clone)]
@@ -1617,6 +1675,225 @@ impl ApiKeyConfig {
     }
 }
 
+#[derive(Clone, serde::Serialize, serde::Deserialize, Default)]
+#[php_class]
+#[php(name = "Spikard\\Php\\Claims")]
+#[serde(default)]
+pub struct Claims {
+    #[php(prop, name = "sub")]
+    pub sub: String,
+    #[php(prop, name = "exp")]
+    pub exp: i64,
+    #[php(prop, name = "iat")]
+    pub iat: Option<i64>,
+    #[php(prop, name = "nbf")]
+    pub nbf: Option<i64>,
+    #[php(prop, name = "aud")]
+    pub aud: Option<Vec<String>>,
+    #[php(prop, name = "iss")]
+    pub iss: Option<String>,
+}
+
+#[php_impl]
+impl Claims {
+    #[php(name = "from_json")]
+    pub fn from_json(json: String) -> PhpResult<Self> {
+        serde_json::from_str(&json).map_err(|e| PhpException::default(e.to_string()))
+    }
+
+    #[php(constructor)]
+    pub fn new(
+        sub: String,
+        exp: i64,
+        iat: Option<i64>,
+        nbf: Option<i64>,
+        aud: Option<Vec<String>>,
+        iss: Option<String>,
+    ) -> Self {
+        Self {
+            sub: sub,
+            exp: exp,
+            iat: iat,
+            nbf: nbf,
+            aud: aud,
+            iss: iss,
+        }
+    }
+
+    pub fn get_sub(&self) -> String {
+        self.sub.clone()
+    }
+
+    pub fn get_exp(&self) -> i64 {
+        self.exp.clone()
+    }
+
+    pub fn get_iat(&self) -> Option<i64> {
+        self.iat.clone()
+    }
+
+    pub fn get_nbf(&self) -> Option<i64> {
+        self.nbf.clone()
+    }
+
+    pub fn get_aud(&self) -> Option<Vec<String>> {
+        self.aud.clone()
+    }
+
+    pub fn get_iss(&self) -> Option<String> {
+        self.iss.clone()
+    }
+}
+
+#[derive(Clone, serde::Serialize, serde::Deserialize, Default)]
+#[php_class]
+#[php(name = "Spikard\\Php\\ClientInfo")]
+#[serde(default)]
+pub struct ClientInfo {
+    /// Client IP address (None when the transport does not expose one)
+    #[php(prop, name = "ip")]
+    pub ip: Option<String>,
+    /// Scheme the client used (`"http"` or `"https"`)
+    #[php(prop, name = "scheme")]
+    pub scheme: String,
+    /// Host the client addressed, including any port
+    #[php(prop, name = "host")]
+    pub host: Option<String>,
+}
+
+#[php_impl]
+impl ClientInfo {
+    #[php(name = "from_json")]
+    pub fn from_json(json: String) -> PhpResult<Self> {
+        serde_json::from_str(&json).map_err(|e| PhpException::default(e.to_string()))
+    }
+
+    #[php(constructor)]
+    pub fn new(scheme: String, ip: Option<String>, host: Option<String>) -> Self {
+        Self {
+            ip: ip,
+            scheme: scheme,
+            host: host,
+        }
+    }
+
+    pub fn get_ip(&self) -> Option<String> {
+        self.ip.clone()
+    }
+
+    pub fn get_scheme(&self) -> String {
+        self.scheme.clone()
+    }
+
+    pub fn get_host(&self) -> Option<String> {
+        self.host.clone()
+    }
+
+    pub fn origin(&self) -> Option<String> {
+        let core_self = spikard::ClientInfo {
+            ip: self.ip.as_deref().and_then(|v| v.parse().ok()),
+            scheme: self.scheme.clone(),
+            host: self.host.clone(),
+        };
+        core_self.origin()
+    }
+}
+
+#[derive(Clone)]
+#[php_class]
+#[php(name = "Spikard\\Php\\Session")]
+pub struct Session {
+    inner: spikard::Session,
+}
+
+#[php_impl]
+impl Session {
+    /// Session holding `data`, detached from any request
+    pub fn from_data(data: String) -> PhpResult<Session> {
+        let data = serde_json::from_str(&data).map_err(|e| PhpException::default(e.to_string()))?;
+        Ok(Self {
+            inner: spikard::Session::from_data(data),
+        })
+    }
+
+    /// Whether the request arrived without a valid session
+    pub fn is_new(&self) -> bool {
+        self.inner.is_new()
+    }
+
+    /// Raw JSON value stored under `key`
+    pub fn get_value(&self, key: String) -> Option<String> {
+        self.inner.get_value(&key).map(|v| v.to_string())
+    }
+
+    /// Store the JSON `value` under `key`
+    pub fn insert(&self, key: String, value: String) -> PhpResult<()> {
+        let value: serde_json::Value =
+            serde_json::from_str(&value).map_err(|e| PhpException::default(e.to_string()))?;
+        self.inner
+            .insert(key, value)
+            .map_err(|e| PhpException::default(e.to_string()))
+    }
+
+    /// Remove and return the value stored under `key`
+    pub fn remove(&self, key: String) -> Option<String> {
+        self.inner.remove(&key).map(|v| v.to_string())
+    }
+
+    /// Remove every value, keeping the session itself
+    pub fn clear(&self) {
+        self.inner.clear()
+    }
+
+    /// Replace all session data
+    pub fn replace(&self, data: String) -> PhpResult<()> {
+        let data = serde_json::from_str(&data).map_err(|e| PhpException::default(e.to_string()))?;
+        self.inner.replace(data);
+        Ok(())
+    }
+
+    /// Snapshot of the session data
+    pub fn data(&self) -> String {
+        serde_json::to_string(&self.inner.data()).unwrap_or_default()
+    }
+
+    /// Issue a new session id, keeping the data
+    pub fn regenerate(&self) {
+        self.inner.regenerate()
+    }
+
+    /// Delete the session and expire its cookie
+    pub fn destroy(&self) {
+        self.inner.destroy()
+    }
+
+    /// Internal response headers that hand this session's changes back to the server
+    pub fn binding_headers(&self) -> HashMap<String, String> {
+        self.inner.binding_headers()
+    }
+}
+
+#[derive(Clone)]
+#[php_class]
+#[php(name = "Spikard\\Php\\CsrfToken")]
+pub struct CsrfToken {
+    inner: spikard::CsrfToken,
+}
+
+#[php_impl]
+impl CsrfToken {
+    #[php(constructor)]
+    pub fn new(token: String) -> Self {
+        Self {
+            inner: spikard::CsrfToken(token),
+        }
+    }
+
+    pub fn as_str(&self) -> String {
+        self.inner.as_str().to_string()
+    }
+}
+
 #[derive(Clone, serde::Serialize, serde::Deserialize, Default)]
 #[php_class]
 #[php(name = "Spikard\\Php\\StaticFilesConfig")]
@@ -1941,6 +2218,8 @@ impl App {
     /// argument-dependent results (e.g. `user(id: "user-42")`) must already be baked into
     /// `response_data` at the correct path. See `spikard_graphql::dynamic` for the full
     /// resolver-data encoding and field-level-error support via `config.field_errors`.
+    /// Fields listed in `config.field_loaders` batch through the loaders registered with
+    /// `App::data_loader`.
     ///
     /// # Arguments
     ///
@@ -2118,6 +2397,86 @@ impl RouteBuilder {
     }
 }
 
+/// Settings shared by a group of routes, folded into each route's builder.
+#[derive(Clone)]
+#[php_class]
+#[php(name = "Spikard\\Php\\RouteGroup")]
+pub struct RouteGroup {
+    inner: Arc<spikard::RouteGroupScope>,
+}
+
+#[php_impl]
+impl RouteGroup {
+    #[php(constructor)]
+    pub fn new(prefix: String) -> Self {
+        Self {
+            inner: Arc::new(spikard::RouteGroupScope::new(&prefix)),
+        }
+    }
+
+    /// The path prefix shared by the routes of this group.
+    pub fn prefix(&self) -> String {
+        self.inner.prefix().to_string()
+    }
+
+    /// Join `path` onto the group prefix.
+    pub fn path(&self, path: String) -> String {
+        self.inner.path(&path)
+    }
+
+    /// Start a group nested under this one, inheriting its settings.
+    pub fn group(&self, prefix: String) -> RouteGroup {
+        Self {
+            inner: Arc::new(self.inner.nested(&prefix)),
+        }
+    }
+
+    /// Add an OpenAPI tag to every route in the group.
+    pub fn tag(&self, tag: String) -> RouteGroup {
+        let mut scope = (*self.inner).clone();
+        scope.tag(tag);
+        Self { inner: Arc::new(scope) }
+    }
+
+    /// Declare dependency keys resolved for every route in the group.
+    pub fn handler_dependencies(&self, dependencies: Vec<String>) -> RouteGroup {
+        let mut scope = (*self.inner).clone();
+        scope.handler_dependencies(dependencies);
+        Self { inner: Arc::new(scope) }
+    }
+
+    /// Attach a CORS configuration to routes that do not set their own.
+    pub fn cors(&self, cors: &CorsConfig) -> RouteGroup {
+        let mut scope = (*self.inner).clone();
+        scope.cors(cors.clone().into());
+        Self { inner: Arc::new(scope) }
+    }
+
+    /// Attach a rate limit to routes that do not set their own.
+    pub fn rate_limit(&self, rate_limit: &RateLimitConfig) -> RouteGroup {
+        let mut scope = (*self.inner).clone();
+        scope.rate_limit(rate_limit.clone().into());
+        Self { inner: Arc::new(scope) }
+    }
+
+    /// Require JWT and/or API key authentication on routes that do not set their own.
+    pub fn auth(&self, jwt: Option<&JwtConfig>, api_key: Option<&ApiKeyConfig>) -> RouteGroup {
+        let mut scope = (*self.inner).clone();
+        scope.auth(spikard::RouteAuthConfig {
+            jwt: jwt.map(|jwt| jwt.clone().into()),
+            api_key: api_key.map(|api_key| api_key.clone().into()),
+        });
+        Self { inner: Arc::new(scope) }
+    }
+
+    /// Prefix `builder`'s path and fold the group settings into it.
+    pub fn apply(&self, builder: &RouteBuilder) -> RouteBuilder {
+        RouteBuilder {
+            inner: Arc::new(self.inner.apply((*builder.inner).clone())),
+        }
+    }
+}
+
 #[derive(Clone, serde::Serialize, serde::Deserialize, Default)]
 #[php_class]
 #[php(name = "Spikard\\Php\\JsonRpcMethodInfo")]
@@ -3091,6 +3450,30 @@ impl From<spikard::FieldErrorSpec> for FieldErrorSpec {
     }
 }
 
+#[allow(clippy::redundant_closure, clippy::useless_conversion)]
+impl From<FieldLoaderSpec> for spikard::FieldLoaderSpec {
+    fn from(val: FieldLoaderSpec) -> Self {
+        Self {
+            type_name: val.type_name,
+            field: val.field,
+            loader: val.loader,
+            key_field: val.key_field,
+        }
+    }
+}
+
+#[allow(clippy::redundant_closure, clippy::useless_conversion)]
+impl From<spikard::FieldLoaderSpec> for FieldLoaderSpec {
+    fn from(val: spikard::FieldLoaderSpec) -> Self {
+        Self {
+            type_name: val.type_name.to_string(),
+            field: val.field.to_string(),
+            loader: val.loader.to_string(),
+            key_field: val.key_field.to_string(),
+        }
+    }
+}
+
 #[allow(clippy::needless_update)]
 #[allow(clippy::redundant_closure, clippy::useless_conversion)]
 impl From<DynamicSchemaConfig> for spikard::DynamicSchemaConfig {
@@ -3100,6 +3483,7 @@ impl From<DynamicSchemaConfig> for spikard::DynamicSchemaConfig {
             max_complexity: val.max_complexity.map(|v| v as usize),
             max_depth: val.max_depth.map(|v| v as usize),
             field_errors: val.field_errors.into_iter().map(Into::into).collect(),
+            field_loaders: val.field_loaders.into_iter().map(Into::into).collect(),
             ..Default::default()
         }
     }
@@ -3113,6 +3497,7 @@ impl From<spikard::DynamicSchemaConfig> for DynamicSchemaConfig {
             max_complexity: val.max_complexity.map(|v| v as i64),
             max_depth: val.max_depth.map(|v| v as i64),
             field_errors: val.field_errors.into_iter().map(Into::into).collect(),
+            field_loaders: val.field_loaders.into_iter().map(Into::into).collect(),
         }
     }
 }
@@ -3561,6 +3946,56 @@ impl From<spikard::ApiKeyConfig> for ApiKeyConfig {
     }
 }
 
+#[allow(clippy::redundant_closure, clippy::useless_conversion)]
+impl From<Claims> for spikard::Claims {
+    fn from(val: Claims) -> Self {
+        Self {
+            sub: val.sub,
+            exp: val.exp as usize,
+            iat: val.iat.map(|v| v as usize),
+            nbf: val.nbf.map(|v| v as usize),
+            aud: val.aud.map(|v| v.into_iter().collect()),
+            iss: val.iss,
+        }
+    }
+}
+
+#[allow(clippy::redundant_closure, clippy::useless_conversion)]
+impl From<spikard::Claims> for Claims {
+    fn from(val: spikard::Claims) -> Self {
+        Self {
+            sub: val.sub.to_string(),
+            exp: val.exp as i64,
+            iat: val.iat.map(|v| v as i64),
+            nbf: val.nbf.map(|v| v as i64),
+            aud: val.aud.map(|v| v.into_iter().collect()),
+            iss: val.iss.map(|v| v.to_string()),
+        }
+    }
+}
+
+#[allow(clippy::redundant_closure, clippy::useless_conversion)]
+impl From<ClientInfo> for spikard::ClientInfo {
+    fn from(val: ClientInfo) -> Self {
+        Self {
+            ip: val.ip.and_then(|v| v.parse().ok()),
+            scheme: val.scheme,
+            host: val.host,
+        }
+    }
+}
+
+#[allow(clippy::redundant_closure, clippy::useless_conversion)]
+impl From<spikard::ClientInfo> for ClientInfo {
+    fn from(val: spikard::ClientInfo) -> Self {
+        Self {
+            ip: val.ip.map(|v| v.to_string()),
+            scheme: val.scheme.to_string(),
+            host: val.host.map(|v| v.to_string()),
+        }
+    }
+}
+
 #[allow(clippy::redundant_closure, clippy::useless_conversion)]
 impl From<StaticFilesConfig> for spikard::StaticFilesConfig {
     fn from(val: StaticFilesConfig) -> Self {
@@ -4261,6 +4696,7 @@ pub extern "C" fn get_module() -> *mut ::ext_php_rs::zend::ModuleEntry {
         let builder = builder
             .class::<UploadFile>()
             .class::<FieldErrorSpec>()
+            .class::<FieldLoaderSpec>()
             .class::<DynamicSchemaConfig>()
             .class::<GraphQLRouteConfig>()
             .class::<SchemaConfig>()
@@ -4280,10 +4716,15 @@ pub extern "C" fn get_module() -> *mut ::ext_php_rs::zend::ModuleEntry {
             .class::<SseEvent>()
             .class::<JwtConfig>()
             .class::<ApiKeyConfig>()
+            .class::<Claims>()
+            .class::<ClientInfo>()
+            .class::<Session>()
+            .class::<CsrfToken>()
             .class::<StaticFilesConfig>()
             .class::<ServerConfig>()
             .class::<App>()
             .class::<RouteBuilder>()
+            .class::<RouteGroup>()
             .class::<JsonRpcMethodInfo>()
             .class::<ProblemDetails>()
             .class::<ParsedChannel>()
@@ -85,6 +85,166 @@ impl spikard::Handler for PhpHandlerBridge {
         })
     }
 }
+/// Generated ext-php-rs bridge for the `LifespanHook` contract.
+///
+/// Wraps a zero-argument PHP callable (stored as an index in the thread-local
+/// registry) so it can be used as `Arc<dyn LifespanHook>`; throwing fails the hook.
+pub struct PhpLifespanHookBridge {
+    name: String,
+    handler_index: usize,
+}
+
+impl PhpLifespanHookBridge {
+    /// Create a bridge from a hook name and a handler index.
+    pub fn new(name: String, handler_index: usize) -> Self {
+        Self { name, handler_index }
+    }
+}
+
+impl spikard::LifespanHook for PhpLifespanHookBridge {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn run(&self, _context: spikard::LifespanContext) -> spikard::LifespanFuture {
+        // Invoke the PHP callable synchronously: lifespan hooks run on the thread
+        // that registered them, inside the runtime `app_run` blocks on.
+        let outcome = call_php_hook(self.handler_index);
+        Box::pin(std::future::ready(outcome))
+    }
+}
+
+/// Generated ext-php-rs bridge for the `HealthCheck` contract.
+///
+/// Wraps a zero-argument PHP callable (stored as an index in the thread-local
+/// registry) so it can be used as `Arc<dyn HealthCheck>`; throwing reports the
+/// application as not ready.
+pub struct PhpHealthCheckBridge {
+    name: String,
+    handler_index: usize,
+}
+
+impl PhpHealthCheckBridge {
+    /// Create a bridge from a check name and a handler index.
+    pub fn new(name: String, handler_index: usize) -> Self {
+        Self { name, handler_index }
+    }
+}
+
+impl spikard::HealthCheck for PhpHealthCheckBridge {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn check(&self, _context: spikard::HealthCheckContext) -> spikard::HealthCheckFuture {
+        // Invoke the PHP callable synchronously (blocking), like `PhpHandlerBridge`.
+        let outcome = call_php_hook(self.handler_index);
+        Box::pin(std::future::ready(outcome))
+    }
+}
+
+/// Generated ext-php-rs bridge for the `RateLimitKeyExtractor` contract.
+///
+/// Wraps a PHP callable (stored as an index in the thread-local registry) so it can
+/// be used as `Arc<dyn RateLimitKeyExtractor>`. The callable receives the request as an
+/// array and returns the bucket key or null; throwing, or returning anything but a
+/// string, falls back to the client IP.
+pub struct PhpRateLimitKeyExtractorBridge {
+    handler_index: usize,
+}
+
+impl PhpRateLimitKeyExtractorBridge {
+    /// Create a bridge from a handler index.
+    pub fn new(handler_index: usize) -> Self {
+        Self { handler_index }
+    }
+}
+
+impl spikard::RateLimitKeyExtractor for PhpRateLimitKeyExtractorBridge {
+    fn extract_key(&self, request: &spikard::Request<spikard::Body>) -> Option<String> {
+        let req_value = serde_json::to_value(spikard::RateLimitKeyRequest::from_request(request)).ok()?;
+        // Invoke the PHP callable synchronously (blocking), like `PhpHandlerBridge`.
+        std::panic::catch_unwind(AssertUnwindSafe(|| {
+            PHP_HANDLER_REGISTRY.with(|registry| {
+                let registry = registry.borrow();
+                let callable = registry.get(self.handler_index)?;
+                let req_zval = serde_json::json!(req_value).into();
+                callable.try_call(vec![&req_zval]).ok()?.string()
+            })
+        }))
+        .ok()
+        .flatten()
+    }
+}
+
+/// Generated ext-php-rs bridge for the `BatchLoader` contract.
+///
+/// Wraps a PHP callable (stored as an index in the thread-local registry) so it can
+/// be used as `Arc<dyn BatchLoader>`. The callable receives the batch of keys as
+/// an array and returns one value (or null) per key, in order; throwing fails every
+/// key in the batch.
+pub struct PhpBatchLoaderBridge {
+    handler_index: usize,
+}
+
+impl PhpBatchLoaderBridge {
+    /// Create a bridge from a handler index.
+    pub fn new(handler_index: usize) -> Self {
+        Self { handler_index }
+    }
+}
+
+impl spikard::BatchLoader for PhpBatchLoaderBridge {
+    fn load(&self, keys: Vec<serde_json::Value>) -> spikard::BatchLoadFuture {
+        // Invoke the PHP callable synchronously (blocking), like `PhpHandlerBridge`.
+        let outcome = std::panic::catch_unwind(AssertUnwindSafe(|| {
+            PHP_HANDLER_REGISTRY.with(|registry| -> Result<String, String> {
+                let registry = registry.borrow();
+                let Some(callable) = registry.get(self.handler_index) else {
+                    return Err(format!("Handler not found at index {}", self.handler_index));
+                };
+                let keys_zval = serde_json::json!(keys).into();
+                let values_zval = callable
+                    .try_call(vec![&keys_zval])
+                    .map_err(|e| format!("PHP callable invocation failed: {:?}", e))?;
+                serde_json::to_string(&values_zval).map_err(|e| e.to_string())
+            })
+        }))
+        .map_err(|_| "PHP data loader panicked".to_string())
+        .and_then(|values| values)
+        .and_then(|values_json| serde_json::from_str(&values_json).map_err(|e| e.to_string()));
+        Box::pin(std::future::ready(outcome))
+    }
+}
+
+/// Call a zero-argument PHP callable from the registry, mapping a thrown exception to its message.
+fn call_php_hook(handler_index: usize) -> Result<(), String> {
+    std::panic::catch_unwind(AssertUnwindSafe(|| {
+        PHP_HANDLER_REGISTRY.with(|registry| -> Result<(), String> {
+            let registry = registry.borrow();
+            let Some(callable) = registry.get(handler_index) else {
+                return Err(format!("Handler not found at index {handler_index}"));
+            };
+            callable
+                .try_call(vec![])
+                .map(|_| ())
+                .map_err(|e| format!("PHP callable invocation failed: {:?}", e))
+        })
+    }))
+    .map_err(|_| "PHP hook panicked".to_string())?
+}
+
+/// Store a PHP callable in the thread-local registry and return its index.
+fn register_php_callable(callable: &Zval) -> PhpResult<usize> {
+    PHP_HANDLER_REGISTRY.with(|registry| {
+        let mut registry = registry.borrow_mut();
+        let idx = registry.len();
+        let zen_callable = ZendCallable::new_owned(callable.clone())
+            .map_err(|_| PhpException::default("Failed to register callable".into()))?;
+        registry.push(zen_callable);
+        Ok(idx)
+    })
+}
 /// Drive `spikard::App::run` from PHP.
 ///
 /// Each entry in `registrations` is an array of `[method_name, metadata_array, callable]`
@@ -130,6 +290,56 @@ pub fn app_run(registrations: &Bound<'_, Zval>) -> PhpResult<()> {
                             .route(builder, handler)
                             .map_err(|e| PhpException::default(e.to_string()))?;
                     }
+                    "on_startup" => {
+                        let handler_index = register_php_callable(&callable)?;
+                        let meta: Vec<Zval> = tuple[1].clone().try_into()?;
+                        let name: String = meta
+                            .get(0)
+                            .ok_or_else(|| PhpException::default("Missing metadata at index 0".into()))?
+                            .try_into()?;
+                        let bridge = PhpLifespanHookBridge::new(name, handler_index);
+                        let hook: Arc<dyn spikard::LifespanHook> = Arc::new(bridge);
+                        owner.on_startup(hook);
+                    }
+                    "on_shutdown" => {
+                        let handler_index = register_php_callable(&callable)?;
+                        let meta: Vec<Zval> = tuple[1].clone().try_into()?;
+                        let name: String = meta
+                            .get(0)
+                            .ok_or_else(|| PhpException::default("Missing metadata at index 0".into()))?
+                            .try_into()?;
+                        let bridge = PhpLifespanHookBridge::new(name, handler_index);
+                        let hook: Arc<dyn spikard::LifespanHook> = Arc::new(bridge);
+                        owner.on_shutdown(hook);
+                    }
+                    "health_check" => {
+                        let handler_index = register_php_callable(&callable)?;
+                        let meta: Vec<Zval> = tuple[1].clone().try_into()?;
+                        let name: String = meta
+                            .get(0)
+                            .ok_or_else(|| PhpException::default("Missing metadata at index 0".into()))?
+                            .try_into()?;
+                        let bridge = PhpHealthCheckBridge::new(name, handler_index);
+                        let check: Arc<dyn spikard::HealthCheck> = Arc::new(bridge);
+                        owner.health_check(check);
+                    }
+                    "rate_limit_key_extractor" => {
+                        let handler_index = register_php_callable(&callable)?;
+                        let bridge = PhpRateLimitKeyExtractorBridge::new(handler_index);
+                        let extractor: Arc<dyn spikard::RateLimitKeyExtractor> = Arc::new(bridge);
+                        owner.rate_limit_key_extractor(extractor);
+                    }
+                    "data_loader" => {
+                        let handler_index = register_php_callable(&callable)?;
+                        let meta: Vec<Zval> = tuple[1].clone().try_into()?;
+                        let name: String = meta
+                            .get(0)
+                            .ok_or_else(|| PhpException::default("Missing metadata at index 0".into()))?
+                            .try_into()?;
+                        let bridge = PhpBatchLoaderBridge::new(handler_index);
+                        let loader: Arc<dyn spikard::BatchLoader> = Arc::new(bridge);
+                        owner.data_loader(name, loader);
+                    }
                     _ => {
                         return Err(PhpException::default(format!(
                             "unknown registration method: {method_name}"
@@ -191,6 +401,56 @@ pub fn app_into_router(registrations: &Bound<'_, Zval>) -> PhpResult<()> {
                             .route(builder, handler)
                             .map_err(|e| PhpException::default(e.to_string()))?;
                     }
+                    "on_startup" => {
+                        let handler_index = register_php_callable(&callable)?;
+                        let meta: Vec<Zval> = tuple[1].clone().try_into()?;
+                        let name: String = meta
+                            .get(0)
+                            .ok_or_else(|| PhpException::default("Missing metadata at index 0".into()))?
+                            .try_into()?;
+                        let bridge = PhpLifespanHookBridge::new(name, handler_index);
+                        let hook: Arc<dyn spikard::LifespanHook> = Arc::new(bridge);
+                        owner.on_startup(hook);
+                    }
+                    "on_shutdown" => {
+                        let handler_index = register_php_callable(&callable)?;
+                        let meta: Vec<Zval> = tuple[1].clone().try_into()?;
+                        let name: String = meta
+                            .get(0)
+                            .ok_or_else(|| PhpException::default("Missing metadata at index 0".into()))?
+                            .try_into()?;
+                        let bridge = PhpLifespanHookBridge::new(name, handler_index);
+                        let hook: Arc<dyn spikard::LifespanHook> = Arc::new(bridge);
+                        owner.on_shutdown(hook);
+                    }
+                    "health_check" => {
+                        let handler_index = register_php_callable(&callable)?;
+                        let meta: Vec<Zval> = tuple[1].clone().try_into()?;
+                        let name: String = meta
+                            .get(0)
+                            .ok_or_else(|| PhpException::default("Missing metadata at index 0".into()))?
+                            .try_into()?;
+                        let bridge = PhpHealthCheckBridge::new(name, handler_index);
+                        let check: Arc<dyn spikard::HealthCheck> = Arc::new(bridge);
+                        owner.health_check(check);
+                    }
+                    "rate_limit_key_extractor" => {
+                        let handler_index = register_php_callable(&callable)?;
+                        let bridge = PhpRateLimitKeyExtractorBridge::new(handler_index);
+                        let extractor: Arc<dyn spikard::RateLimitKeyExtractor> = Arc::new(bridge);
+                        owner.rate_limit_key_extractor(extractor);
+                    }
+                    "data_loader" => {
+                        let handler_index = register_php_callable(&callable)?;
+                        let meta: Vec<Zval> = tuple[1].clone().try_into()?;
+                        let name: String = meta
+                            .get(0)
+                            .ok_or_else(|| PhpException::default("Missing metadata at index 0".into()))?
+                            .try_into()?;
+                        let bridge = PhpBatchLoaderBridge::new(handler_index);
+                        let loader: Arc<dyn spikard::BatchLoader> = Arc::new(bridge);
+                        owner.data_loader(name, loader);
+                    }
                     _ => {
                         return Err(PhpException::default(format!(
                             "unknown registration method: {method_name}"
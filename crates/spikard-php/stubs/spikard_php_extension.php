@@ -92,6 +92,23 @@ namespace Spikard\Php {
         ) {}
     }
 
+    /**
+     * Routes a single object field through a named batch loader.
+     */
+    final class FieldLoaderSpec
+    {
+        public function __construct(
+            /** @var string The object type declaring the field (e.g. `"Post"`). */
+            public readonly string $typeName,
+            /** @var string The field resolved through the loader (e.g. `"author"`). */
+            public readonly string $field,
+            /** @var string Name of the loader registered with `App::dataLoader`. */
+            public readonly string $loader,
+            /** @var string Parent JSON field (or root-field argument) holding the key or list of keys. */
+            public readonly string $keyField,
+        ) {}
+    }
+
     /**
      * Configuration for building and executing a dynamic-SDL schema.
      */
@@ -102,6 +119,8 @@ namespace Spikard\Php {
             public readonly bool $introspectionEnabled,
             /** @var array<FieldErrorSpec> Field-level errors to inject at specific response paths. */
             public readonly array $fieldErrors,
+            /** @var array<FieldLoaderSpec> Fields resolved through batch loaders rather than `response_data`. */
+            public readonly array $fieldLoaders,
             /** @var ?int Maximum query complexity (`None` = unlimited). */
             public readonly ?int $maxComplexity = null,
             /** @var ?int Maximum query depth (`None` = unlimited). */
@@ -603,6 +622,122 @@ namespace Spikard\Php {
         ) {}
     }
 
+    /**
+     * JWT claims structure - can be extended based on needs
+     */
+    final class Claims
+    {
+        public function __construct(
+            /** @var string */
+            public readonly string $sub,
+            /** @var int */
+            public readonly int $exp,
+            /** @var ?int */
+            public readonly ?int $iat = null,
+            /** @var ?int */
+            public readonly ?int $nbf = null,
+            /** @var ?array<string> */
+            public readonly ?array $aud = null,
+            /** @var ?string */
+            public readonly ?string $iss = null,
+        ) {}
+    }
+
+    /**
+     * Client as seen by the outermost trusted proxy
+     */
+    final class ClientInfo
+    {
+        public function __construct(
+            /** @var string Scheme the client used (`"http"` or `"https"`) */
+            public readonly string $scheme,
+            /** @var ?string Client IP address (None when the transport does not expose one) */
+            public readonly ?string $ip = null,
+            /** @var ?string Host the client addressed, including any port */
+            public readonly ?string $host = null,
+        ) {}
+
+        public function origin(): ?string
+        {
+            throw new \RuntimeException('Not implemented — provided by the native extension.');
+        }
+    }
+
+    /**
+     * Session of the current request
+     */
+    final class Session
+    {
+        public static function fromData(string $data): Session
+        {
+            throw new \RuntimeException('Not implemented — provided by the native extension.');
+        }
+
+        public function isNew(): bool
+        {
+            throw new \RuntimeException('Not implemented — provided by the native extension.');
+        }
+
+        public function getValue(string $key): ?string
+        {
+            throw new \RuntimeException('Not implemented — provided by the native extension.');
+        }
+
+        public function insert(string $key, string $value): void
+        {
+            throw new \RuntimeException('Not implemented — provided by the native extension.');
+        }
+
+        public function remove(string $key): ?string
+        {
+            throw new \RuntimeException('Not implemented — provided by the native extension.');
+        }
+
+        public function clear(): void
+        {
+            throw new \RuntimeException('Not implemented — provided by the native extension.');
+        }
+
+        public function replace(string $data): void
+        {
+            throw new \RuntimeException('Not implemented — provided by the native extension.');
+        }
+
+        public function data(): string
+        {
+            throw new \RuntimeException('Not implemented — provided by the native extension.');
+        }
+
+        public function regenerate(): void
+        {
+            throw new \RuntimeException('Not implemented — provided by the native extension.');
+        }
+
+        public function destroy(): void
+        {
+            throw new \RuntimeException('Not implemented — provided by the native extension.');
+        }
+
+        /** @return array<string, string> */
+        public function bindingHeaders(): array
+        {
+            throw new \RuntimeException('Not implemented — provided by the native extension.');
+        }
+    }
+
+    /**
+     * CSRF token expected on the client's next unsafe request
+     */
+    final class CsrfToken
+    {
+        public function __construct(string $token) {}
+
+        public function asStr(): string
+        {
+            throw new \RuntimeException('Not implemented — provided by the native extension.');
+        }
+    }
+
     /**
      * Static file serving configuration
      */
@@ -672,6 +807,89 @@ namespace Spikard\Php {
         }
     }
 
+    /**
+     * Settings shared by a group of routes, folded into each route's builder.
+     */
+    final class RouteGroup
+    {
+        public function __construct(string $prefix)
+        {
+            throw new \RuntimeException('Not implemented — provided by the native extension.');
+        }
+
+        /**
+         * The path prefix shared by the routes of this group.
+         */
+        public function prefix(): string
+        {
+            throw new \RuntimeException('Not implemented — provided by the native extension.');
+        }
+
+        /**
+         * Join $path onto the group prefix.
+         */
+        public function path(string $path): string
+        {
+            throw new \RuntimeException('Not implemented — provided by the native extension.');
+        }
+
+        /**
+         * Start a group nested under this one, inheriting its settings.
+         */
+        public function group(string $prefix): RouteGroup
+        {
+            throw new \RuntimeException('Not implemented — provided by the native extension.');
+        }
+
+        /**
+         * Add an OpenAPI tag to every route in the group.
+         */
+        public function tag(string $tag): RouteGroup
+        {
+            throw new \RuntimeException('Not implemented — provided by the native extension.');
+        }
+
+        /**
+         * Declare dependency keys resolved for every route in the group.
+         */
+        public function handlerDependencies(array $dependencies): RouteGroup
+        {
+            throw new \RuntimeException('Not implemented — provided by the native extension.');
+        }
+
+        /**
+         * Attach a CORS configuration to routes that do not set their own.
+         */
+        public function cors(CorsConfig $cors): RouteGroup
+        {
+            throw new \RuntimeException('Not implemented — provided by the native extension.');
+        }
+
+        /**
+         * Attach a rate limit to routes that do not set their own.
+         */
+        public function rateLimit(RateLimitConfig $rateLimit): RouteGroup
+        {
+            throw new \RuntimeException('Not implemented — provided by the native extension.');
+        }
+
+        /**
+         * Require JWT and/or API key authentication on routes that do not set their own.
+         */
+        public function auth(?JwtConfig $jwt = null, ?ApiKeyConfig $apiKey = null): RouteGroup
+        {
+            throw new \RuntimeException('Not implemented — provided by the native extension.');
+        }
+
+        /**
+         * Prefix the builder's path and fold the group settings into it.
+         */
+        public function apply(RouteBuilder $builder): RouteBuilder
+        {
+            throw new \RuntimeException('Not implemented — provided by the native extension.');
+        }
+    }
+
     /**
      * JSON-RPC method metadata for routes that support JSON-RPC
      *
@@ -1433,6 +1433,195 @@ impl ApiKeyConfig {
     }
 }
 
+#[derive(Clone, Default, serde::Serialize, serde::Deserialize)]
+#[pyclass(frozen, from_py_object)]
+pub struct Claims {
+    #[pyo3(get)]
+    pub sub: String,
+    #[pyo3(get)]
+    pub exp: usize,
+    #[pyo3(get)]
+    pub iat: Option<usize>,
+    #[pyo3(get)]
+    pub nbf: Option<usize>,
+    #[pyo3(get)]
+    pub aud: Option<Vec<String>>,
+    #[pyo3(get)]
+    pub iss: Option<String>,
+}
+
+#[pymethods]
+impl Claims {
+    #[allow(clippy::too_many_arguments)]
+    #[must_use]
+    #[pyo3(signature = (sub, exp, iat=None, nbf=None, aud=None, iss=None))]
+    #[new]
+    pub fn new(
+        sub: String,
+        exp: usize,
+        iat: Option<usize>,
+        nbf: Option<usize>,
+        aud: Option<Vec<String>>,
+        iss: Option<String>,
+    ) -> Self {
+        Self {
+            sub,
+            exp,
+            iat,
+            nbf,
+            aud,
+            iss,
+        }
+    }
+
+    #[staticmethod]
+    fn from_json(json_str: String) -> pyo3::PyResult<Self> {
+        serde_json::from_str::<Self>(&json_str).map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+    }
+}
+
+#[derive(Clone, Default, serde::Serialize, serde::Deserialize)]
+#[pyclass(frozen, from_py_object)]
+pub struct ClientInfo {
+    /// Client IP address (None when the transport does not expose one)
+    #[pyo3(get)]
+    pub ip: Option<String>,
+    /// Scheme the client used (`"http"` or `"https"`)
+    #[pyo3(get)]
+    pub scheme: String,
+    /// Host the client addressed, including any port
+    #[pyo3(get)]
+    pub host: Option<String>,
+}
+
+#[pymethods]
+impl ClientInfo {
+    #[allow(clippy::too_many_arguments)]
+    #[must_use]
+    #[pyo3(signature = (scheme, ip=None, host=None))]
+    #[new]
+    pub fn new(scheme: String, ip: Option<String>, host: Option<String>) -> Self {
+        Self { ip, scheme, host }
+    }
+
+    #[pyo3(signature = ())]
+    pub fn origin(&self) -> Option<String> {
+        let core_self = spikard::ClientInfo {
+            ip: self.ip.as_deref().and_then(|v| v.parse().ok()),
+
+            scheme: self.scheme.clone(),
+
+            host: self.host.clone(),
+        };
+        core_self.origin()
+    }
+
+    #[staticmethod]
+    fn from_json(json_str: String) -> pyo3::PyResult<Self> {
+        serde_json::from_str::<Self>(&json_str).map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+    }
+}
+
+#[derive(Clone)]
+#[pyclass(frozen, from_py_object)]
+pub struct Session {
+    inner: spikard::Session,
+}
+
+#[pymethods]
+impl Session {
+    #[allow(clippy::missing_errors_doc)]
+    #[staticmethod]
+    #[pyo3(signature = (data))]
+    pub fn from_data(data: String) -> PyResult<Session> {
+        let data = serde_json::from_str(&data).map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        Ok(Self {
+            inner: spikard::Session::from_data(data),
+        })
+    }
+
+    #[pyo3(signature = ())]
+    pub fn is_new(&self) -> bool {
+        self.inner.is_new()
+    }
+
+    #[pyo3(signature = (key))]
+    pub fn get_value(&self, key: String) -> Option<String> {
+        self.inner.get_value(&key).map(|v| v.to_string())
+    }
+
+    #[allow(clippy::missing_errors_doc)]
+    #[pyo3(signature = (key, value))]
+    pub fn insert(&self, key: String, value: String) -> PyResult<()> {
+        let value: serde_json::Value =
+            serde_json::from_str(&value).map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        self.inner
+            .insert(key, value)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+    }
+
+    #[pyo3(signature = (key))]
+    pub fn remove(&self, key: String) -> Option<String> {
+        self.inner.remove(&key).map(|v| v.to_string())
+    }
+
+    #[pyo3(signature = ())]
+    pub fn clear(&self) {
+        self.inner.clear()
+    }
+
+    #[allow(clippy::missing_errors_doc)]
+    #[pyo3(signature = (data))]
+    pub fn replace(&self, data: String) -> PyResult<()> {
+        let data = serde_json::from_str(&data).map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        self.inner.replace(data);
+        Ok(())
+    }
+
+    #[pyo3(signature = ())]
+    pub fn data(&self) -> String {
+        serde_json::to_string(&self.inner.data()).unwrap_or_default()
+    }
+
+    #[pyo3(signature = ())]
+    pub fn regenerate(&self) {
+        self.inner.regenerate()
+    }
+
+    #[pyo3(signature = ())]
+    pub fn destroy(&self) {
+        self.inner.destroy()
+    }
+
+    #[pyo3(signature = ())]
+    pub fn binding_headers(&self) -> HashMap<String, String> {
+        self.inner.binding_headers()
+    }
+}
+
+#[derive(Clone)]
+#[pyclass(frozen, from_py_object)]
+pub struct CsrfToken {
+    inner: spikard::CsrfToken,
+}
+
+#[pymethods]
+impl CsrfToken {
+    #[must_use]
+    #[pyo3(signature = (token))]
+    #[new]
+    pub fn new(token: String) -> Self {
+        Self {
+            inner: spikard::CsrfToken(token),
+        }
+    }
+
+    #[pyo3(signature = ())]
+    pub fn as_str(&self) -> String {
+        self.inner.as_str().to_string()
+    }
+}
+
 #[derive(Clone, Default, serde::Serialize, serde::Deserialize)]
 #[pyclass(frozen, from_py_object)]
 pub struct StaticFilesConfig {
@@ -1725,6 +1914,86 @@ impl RouteBuilder {
     }
 }
 
+#[derive(Clone)]
+#[pyclass(unsendable, from_py_object)]
+pub struct RouteGroup {
+    inner: Arc<spikard::RouteGroupScope>,
+}
+
+#[pymethods]
+impl RouteGroup {
+    #[must_use]
+    #[pyo3(signature = (prefix))]
+    #[new]
+    pub fn new(prefix: String) -> Self {
+        Self {
+            inner: Arc::new(spikard::RouteGroupScope::new(&prefix)),
+        }
+    }
+
+    #[pyo3(signature = ())]
+    pub fn prefix(&self) -> String {
+        self.inner.prefix().to_string()
+    }
+
+    #[pyo3(signature = (path))]
+    pub fn path(&self, path: String) -> String {
+        self.inner.path(&path)
+    }
+
+    #[pyo3(signature = (prefix))]
+    pub fn group(&self, prefix: String) -> RouteGroup {
+        Self {
+            inner: Arc::new(self.inner.nested(&prefix)),
+        }
+    }
+
+    #[pyo3(signature = (tag))]
+    pub fn tag(&self, tag: String) -> RouteGroup {
+        let mut scope = (*self.inner).clone();
+        scope.tag(tag);
+        Self { inner: Arc::new(scope) }
+    }
+
+    #[pyo3(signature = (dependencies))]
+    pub fn handler_dependencies(&self, dependencies: Vec<String>) -> RouteGroup {
+        let mut scope = (*self.inner).clone();
+        scope.handler_dependencies(dependencies);
+        Self { inner: Arc::new(scope) }
+    }
+
+    #[pyo3(signature = (cors))]
+    pub fn cors(&self, cors: CorsConfig) -> RouteGroup {
+        let mut scope = (*self.inner).clone();
+        scope.cors(cors.into());
+        Self { inner: Arc::new(scope) }
+    }
+
+    #[pyo3(signature = (rate_limit))]
+    pub fn rate_limit(&self, rate_limit: RateLimitConfig) -> RouteGroup {
+        let mut scope = (*self.inner).clone();
+        scope.rate_limit(rate_limit.into());
+        Self { inner: Arc::new(scope) }
+    }
+
+    #[pyo3(signature = (jwt=None, api_key=None))]
+    pub fn auth(&self, jwt: Option<JwtConfig>, api_key: Option<ApiKeyConfig>) -> RouteGroup {
+        let mut scope = (*self.inner).clone();
+        scope.auth(spikard::RouteAuthConfig {
+            jwt: jwt.map(Into::into),
+            api_key: api_key.map(Into::into),
+        });
+        Self { inner: Arc::new(scope) }
+    }
+
+    #[pyo3(signature = (builder))]
+    pub fn apply(&self, builder: RouteBuilder) -> RouteBuilder {
+        RouteBuilder {
+            inner: Arc::new(self.inner.apply((*builder.inner).clone())),
+        }
+    }
+}
+
 #[derive(Clone, Default, serde::Serialize, serde::Deserialize)]
 #[pyclass(frozen, from_py_object)]
 pub struct JsonRpcMethodInfo {
@@ -2349,6 +2618,84 @@ impl ResponseSnapshot {
         core_self.header(&name).map(|v| v.to_owned())
     }
 
+    #[allow(clippy::missing_errors_doc)]
+    #[pyo3(signature = (options=None))]
+    pub fn to_snapshot_json(&self, options: Option<SnapshotOptions>) -> PyResult<String> {
+        spikard_http::ResponseSnapshot::from(self.clone())
+            .to_snapshot_json(&options.unwrap_or_default().into())
+            .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))
+    }
+
+    #[staticmethod]
+    fn from_json(json_str: String) -> pyo3::PyResult<Self> {
+        serde_json::from_str::<Self>(&json_str).map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+    }
+}
+
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+#[pyclass(frozen, from_py_object)]
+pub struct SnapshotOptions {
+    /// Response headers included in the snapshot (case-insensitive)
+    #[pyo3(get)]
+    pub headers: Vec<String>,
+    /// JSON pointers into the snapshot document to redact; a `*` segment matches any key or index
+    #[pyo3(get)]
+    pub redact: Vec<String>,
+    /// Replace every string that is a UUID with `[UUID]`
+    #[pyo3(get)]
+    pub redact_uuids: bool,
+    /// Replace every string that is an RFC 3339 timestamp with `[TIMESTAMP]`
+    #[pyo3(get)]
+    pub redact_timestamps: bool,
+}
+
+impl Default for SnapshotOptions {
+    fn default() -> Self {
+        spikard_http::testing::SnapshotOptions::default().into()
+    }
+}
+
+#[pymethods]
+impl SnapshotOptions {
+    #[must_use]
+    #[pyo3(signature = (headers=None, redact=None, redact_uuids=false, redact_timestamps=false))]
+    #[new]
+    pub fn new(
+        headers: Option<Vec<String>>,
+        redact: Option<Vec<String>>,
+        redact_uuids: bool,
+        redact_timestamps: bool,
+    ) -> Self {
+        Self {
+            headers: headers.unwrap_or_else(|| Self::default().headers),
+            redact: redact.unwrap_or_default(),
+            redact_uuids,
+            redact_timestamps,
+        }
+    }
+
+    #[staticmethod]
+    fn from_json(json_str: String) -> pyo3::PyResult<Self> {
+        serde_json::from_str::<Self>(&json_str).map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+    }
+}
+
+#[derive(Clone, Default, serde::Serialize, serde::Deserialize)]
+#[pyclass(frozen, from_py_object)]
+pub struct SnapshotOutcome {
+    /// `matched`, `created`, `updated` or `mismatched`
+    #[pyo3(get)]
+    pub status: String,
+    /// Snapshot file contents of a mismatch, `None` when the file does not exist
+    #[pyo3(get)]
+    pub expected: Option<String>,
+    /// Serialized response of a mismatch
+    #[pyo3(get)]
+    pub actual: Option<String>,
+}
+
+#[pymethods]
+impl SnapshotOutcome {
     #[staticmethod]
     fn from_json(json_str: String) -> pyo3::PyResult<Self> {
         serde_json::from_str::<Self>(&json_str).map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
@@ -2358,19 +2705,28 @@ impl ResponseSnapshot {
 #[derive(Clone, Default, serde::Serialize, serde::Deserialize)]
 #[pyclass(frozen, from_py_object)]
 pub struct TestingSseEvent {
-    /// The data field of the event.
+    /// The data of the event; multiple `data` lines are joined with `\n`.
     #[pyo3(get)]
     pub data: String,
+    /// The `event` type, if the server set one.
+    #[pyo3(get)]
+    pub event: Option<String>,
+    /// The `id` field, if the server set one.
+    #[pyo3(get)]
+    pub id: Option<String>,
+    /// The `retry` reconnection time in milliseconds, if the server set one.
+    #[pyo3(get)]
+    pub retry: Option<u64>,
 }
 
 #[pymethods]
 impl TestingSseEvent {
     #[allow(clippy::too_many_arguments)]
     #[must_use]
-    #[pyo3(signature = (data))]
+    #[pyo3(signature = (data, event=None, id=None, retry=None))]
     #[new]
-    pub fn new(data: String) -> Self {
-        Self { data }
+    pub fn new(data: String, event: Option<String>, id: Option<String>, retry: Option<u64>) -> Self {
+        Self { data, event, id, retry }
     }
 
     #[staticmethod]
@@ -2436,6 +2792,45 @@ pub struct TestClient {
 
 #[pymethods]
 impl TestClient {
+    #[allow(clippy::missing_errors_doc)]
+    #[pyo3(signature = (key, value))]
+    pub fn override_dependency(&self, key: String, value: String) -> PyResult<()> {
+        let value = serde_json::from_str(&value).map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        self.inner
+            .override_dependency_value(&key, value)
+            .map_err(PyErr::new::<PyRuntimeError, _>)
+    }
+
+    #[allow(clippy::missing_errors_doc)]
+    #[pyo3(signature = (key, value))]
+    pub fn override_dependency_scope(&self, key: String, value: String) -> PyResult<DependencyOverrideGuard> {
+        let value = serde_json::from_str(&value).map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        let guard = self
+            .inner
+            .override_dependency_scope(&key, value)
+            .map_err(PyErr::new::<PyRuntimeError, _>)?;
+        Ok(DependencyOverrideGuard {
+            key,
+            inner: std::sync::Mutex::new(Some(guard)),
+        })
+    }
+
+    #[allow(clippy::missing_errors_doc)]
+    #[pyo3(signature = (key))]
+    pub fn restore_dependency(&self, key: String) -> PyResult<()> {
+        self.inner
+            .restore_dependency(&key)
+            .map_err(PyErr::new::<PyRuntimeError, _>)
+    }
+
+    #[allow(clippy::missing_errors_doc)]
+    #[pyo3(signature = ())]
+    pub fn clear_dependency_overrides(&self) -> PyResult<()> {
+        self.inner
+            .clear_dependency_overrides()
+            .map_err(PyErr::new::<PyRuntimeError, _>)
+    }
+
     #[allow(clippy::missing_errors_doc)]
     #[pyo3(signature = (endpoint, query, variables=None, operation_name=None))]
     pub fn graphql_at<'py>(
@@ -2541,6 +2936,43 @@ impl TestClient {
     }
 }
 
+#[pyclass(frozen)]
+pub struct DependencyOverrideGuard {
+    key: String,
+    inner: std::sync::Mutex<Option<spikard_core::di::DependencyOverrideGuard>>,
+}
+
+#[pymethods]
+impl DependencyOverrideGuard {
+    #[pyo3(signature = ())]
+    pub fn key(&self) -> String {
+        self.key.clone()
+    }
+
+    #[pyo3(signature = ())]
+    pub fn restore(&self) {
+        self.inner
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .take();
+    }
+
+    fn __enter__(slf: Py<Self>) -> Py<Self> {
+        slf
+    }
+
+    #[pyo3(signature = (_exc_type=None, _exc_value=None, _traceback=None))]
+    fn __exit__(
+        &self,
+        _exc_type: Option<Bound<'_, PyAny>>,
+        _exc_value: Option<Bound<'_, PyAny>>,
+        _traceback: Option<Bound<'_, PyAny>>,
+    ) -> bool {
+        self.restore();
+        false
+    }
+}
+
 #[derive(Clone)]
 #[pyclass(unsendable, from_py_object)]
 pub struct HandlerResult {
@@ -3110,6 +3542,59 @@ pub fn schema_full(py: Python<'_>) -> FullSchemaConfig {
     py.detach(spikard::schema_full).into()
 }
 
+/// Snapshot update mode named `update`, or the `SPIKARD_UPDATE_SNAPSHOTS` mode when `None`.
+fn snapshot_update(update: Option<String>) -> PyResult<spikard_http::testing::SnapshotUpdate> {
+    match update {
+        Some(mode) => serde_json::from_value(serde_json::Value::String(mode))
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Invalid snapshot update mode: {e}"))),
+        None => Ok(spikard_http::testing::SnapshotUpdate::from_env()),
+    }
+}
+
+#[allow(clippy::missing_errors_doc)]
+#[pyfunction]
+#[pyo3(signature = (response, path, options=None, update=None))]
+pub fn check_snapshot(
+    response: ResponseSnapshot,
+    path: String,
+    options: Option<SnapshotOptions>,
+    update: Option<String>,
+) -> PyResult<SnapshotOutcome> {
+    spikard_http::testing::check_snapshot(
+        &response.into(),
+        std::path::Path::new(&path),
+        &options.unwrap_or_default().into(),
+        snapshot_update(update)?,
+    )
+    .map(Into::into)
+    .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))
+}
+
+#[pyfunction]
+#[pyo3(signature = (root_dir, file, name))]
+pub fn snapshot_path(root_dir: String, file: String, name: String) -> String {
+    spikard_http::testing::snapshot_path(&root_dir, &file, &name)
+        .to_string_lossy()
+        .into_owned()
+}
+
+#[allow(clippy::missing_errors_doc)]
+#[pyfunction]
+#[pyo3(signature = (response, path, options=None))]
+pub fn assert_snapshot_file(
+    response: ResponseSnapshot,
+    path: String,
+    options: Option<SnapshotOptions>,
+) -> PyResult<SnapshotOutcome> {
+    spikard_http::testing::assert_snapshot_file(
+        &response.into(),
+        std::path::Path::new(&path),
+        &options.unwrap_or_default().into(),
+    )
+    .map(Into::into)
+    .map_err(pyo3::exceptions::PyAssertionError::new_err)
+}
+
 // Error types
 pyo3::create_exception!(_spikard, AppError, pyo3::exceptions::PyException);
 pyo3::create_exception!(_spikard, RouteError, AppError);
@@ -3753,6 +4238,56 @@ impl From<spikard::ApiKeyConfig> for ApiKeyConfig {
     }
 }
 
+#[allow(clippy::redundant_closure, clippy::useless_conversion)]
+impl From<Claims> for spikard::Claims {
+    fn from(val: Claims) -> Self {
+        Self {
+            sub: val.sub,
+            exp: val.exp,
+            iat: val.iat,
+            nbf: val.nbf,
+            aud: val.aud.map(|v| v.into_iter().collect()),
+            iss: val.iss,
+        }
+    }
+}
+
+#[allow(clippy::redundant_closure, clippy::useless_conversion)]
+impl From<spikard::Claims> for Claims {
+    fn from(val: spikard::Claims) -> Self {
+        Self {
+            sub: val.sub.to_string(),
+            exp: val.exp,
+            iat: val.iat,
+            nbf: val.nbf,
+            aud: val.aud.map(|v| v.into_iter().collect()),
+            iss: val.iss.map(|v| v.to_string()),
+        }
+    }
+}
+
+#[allow(clippy::redundant_closure, clippy::useless_conversion)]
+impl From<ClientInfo> for spikard::ClientInfo {
+    fn from(val: ClientInfo) -> Self {
+        Self {
+            ip: val.ip.and_then(|v| v.parse().ok()),
+            scheme: val.scheme,
+            host: val.host,
+        }
+    }
+}
+
+#[allow(clippy::redundant_closure, clippy::useless_conversion)]
+impl From<spikard::ClientInfo> for ClientInfo {
+    fn from(val: spikard::ClientInfo) -> Self {
+        Self {
+            ip: val.ip.map(|v| v.to_string()),
+            scheme: val.scheme.to_string(),
+            host: val.host.map(|v| v.to_string()),
+        }
+    }
+}
+
 #[allow(clippy::redundant_closure, clippy::useless_conversion)]
 impl From<StaticFilesConfig> for spikard::StaticFilesConfig {
     fn from(val: StaticFilesConfig) -> Self {
@@ -4045,11 +4580,55 @@ impl From<spikard_http::ResponseSnapshot> for ResponseSnapshot {
     }
 }
 
+impl From<SnapshotOptions> for spikard_http::testing::SnapshotOptions {
+    fn from(val: SnapshotOptions) -> Self {
+        Self {
+            headers: val.headers,
+            redact: val.redact,
+            redact_uuids: val.redact_uuids,
+            redact_timestamps: val.redact_timestamps,
+        }
+    }
+}
+
+impl From<spikard_http::testing::SnapshotOptions> for SnapshotOptions {
+    fn from(val: spikard_http::testing::SnapshotOptions) -> Self {
+        Self {
+            headers: val.headers,
+            redact: val.redact,
+            redact_uuids: val.redact_uuids,
+            redact_timestamps: val.redact_timestamps,
+        }
+    }
+}
+
+impl From<spikard_http::testing::SnapshotOutcome> for SnapshotOutcome {
+    fn from(val: spikard_http::testing::SnapshotOutcome) -> Self {
+        let status = |status: &str| Self {
+            status: status.to_string(),
+            ..Self::default()
+        };
+        match val {
+            spikard_http::testing::SnapshotOutcome::Matched => status("matched"),
+            spikard_http::testing::SnapshotOutcome::Created => status("created"),
+            spikard_http::testing::SnapshotOutcome::Updated => status("updated"),
+            spikard_http::testing::SnapshotOutcome::Mismatched { expected, actual } => Self {
+                status: "mismatched".to_string(),
+                expected,
+                actual: Some(actual),
+            },
+        }
+    }
+}
+
 #[allow(clippy::redundant_closure, clippy::useless_conversion)]
 impl From<spikard_http::testing::SseEvent> for TestingSseEvent {
     fn from(val: spikard_http::testing::SseEvent) -> Self {
         Self {
             data: val.data.to_string(),
+            event: val.event.map(|v| v.to_string()),
+            id: val.id.map(|v| v.to_string()),
+            retry: val.retry,
         }
     }
 }
@@ -4154,9 +4733,14 @@ pub fn _spikard(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<SseEvent>()?;
     m.add_class::<JwtConfig>()?;
     m.add_class::<ApiKeyConfig>()?;
+    m.add_class::<Claims>()?;
+    m.add_class::<ClientInfo>()?;
+    m.add_class::<Session>()?;
+    m.add_class::<CsrfToken>()?;
     m.add_class::<StaticFilesConfig>()?;
     m.add_class::<ServerConfig>()?;
     m.add_class::<RouteBuilder>()?;
+    m.add_class::<RouteGroup>()?;
     m.add_class::<JsonRpcMethodInfo>()?;
     m.add_class::<ProblemDetails>()?;
     m.add_class::<ParsedChannel>()?;
@@ -4170,9 +4754,12 @@ pub fn _spikard(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<LicenseInfo>()?;
     m.add_class::<ServerInfo>()?;
     m.add_class::<ResponseSnapshot>()?;
+    m.add_class::<SnapshotOptions>()?;
+    m.add_class::<SnapshotOutcome>()?;
     m.add_class::<TestingSseEvent>()?;
     m.add_class::<GraphQLSubscriptionSnapshot>()?;
     m.add_class::<TestClient>()?;
+    m.add_class::<DependencyOverrideGuard>()?;
     m.add_class::<HandlerResult>()?;
     m.add_class::<Request>()?;
     m.add_class::<RequestData>()?;
@@ -4183,6 +4770,9 @@ pub fn _spikard(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(schema_query_only, m)?)?;
     m.add_function(wrap_pyfunction!(schema_query_mutation, m)?)?;
     m.add_function(wrap_pyfunction!(schema_full, m)?)?;
+    m.add_function(wrap_pyfunction!(check_snapshot, m)?)?;
+    m.add_function(wrap_pyfunction!(snapshot_path, m)?)?;
+    m.add_function(wrap_pyfunction!(assert_snapshot_file, m)?)?;
     m.add("RouteError", m.py().get_type::<RouteError>())?;
     m.add("ServerError", m.py().get_type::<ServerError>())?;
     m.add("DecodeError", m.py().get_type::<DecodeError>())?;
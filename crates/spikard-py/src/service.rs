@@ -98,6 +98,203 @@ impl spikard::Handler for PyHandlerBridge {
         })
     }
 }
+/// Generated pyo3 bridge for the `LifespanHook` contract.
+///
+/// Wraps a zero-argument Python callable (sync or async) so it can be used
+/// as `Arc<dyn LifespanHook>`; raising fails the hook.
+pub struct PyLifespanHookBridge {
+    name: String,
+    callable: Py<PyAny>,
+    is_async: bool,
+}
+
+impl PyLifespanHookBridge {
+    /// Create a bridge from a hook name and a Python callable.
+    pub fn new(py: Python<'_>, name: String, callable: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let is_async = py
+            .import("inspect")?
+            .call_method1("iscoroutinefunction", (callable,))?
+            .is_truthy()
+            .unwrap_or(false);
+        Ok(Self {
+            name,
+            callable: callable.clone().unbind(),
+            is_async,
+        })
+    }
+}
+
+// SAFETY: Py<PyAny> is Send+Sync when we never alias it without the GIL.
+unsafe impl Send for PyLifespanHookBridge {}
+unsafe impl Sync for PyLifespanHookBridge {}
+impl spikard::LifespanHook for PyLifespanHookBridge {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn run(&self, _context: spikard::LifespanContext) -> spikard::LifespanFuture {
+        let callable = pyo3::Python::attach(|py| self.callable.clone_ref(py));
+        Box::pin(call_py_hook(callable, self.is_async))
+    }
+}
+/// Generated pyo3 bridge for the `HealthCheck` contract.
+///
+/// Wraps a zero-argument Python callable (sync or async) so it can be used
+/// as `Arc<dyn HealthCheck>`; raising reports the application as not ready.
+pub struct PyHealthCheckBridge {
+    name: String,
+    callable: Py<PyAny>,
+    is_async: bool,
+}
+
+impl PyHealthCheckBridge {
+    /// Create a bridge from a check name and a Python callable.
+    pub fn new(py: Python<'_>, name: String, callable: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let is_async = py
+            .import("inspect")?
+            .call_method1("iscoroutinefunction", (callable,))?
+            .is_truthy()
+            .unwrap_or(false);
+        Ok(Self {
+            name,
+            callable: callable.clone().unbind(),
+            is_async,
+        })
+    }
+}
+
+// SAFETY: Py<PyAny> is Send+Sync when we never alias it without the GIL.
+unsafe impl Send for PyHealthCheckBridge {}
+unsafe impl Sync for PyHealthCheckBridge {}
+impl spikard::HealthCheck for PyHealthCheckBridge {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn check(&self, _context: spikard::HealthCheckContext) -> spikard::HealthCheckFuture {
+        let callable = pyo3::Python::attach(|py| self.callable.clone_ref(py));
+        Box::pin(call_py_hook(callable, self.is_async))
+    }
+}
+/// Generated pyo3 bridge for the `RateLimitKeyExtractor` contract.
+///
+/// Wraps a synchronous Python callable so it can be used as `Arc<dyn RateLimitKeyExtractor>`.
+/// The callable receives the request as a dict and returns the bucket key or `None`;
+/// raising reports the exception as unraisable and falls back to the client IP.
+pub struct PyRateLimitKeyExtractorBridge {
+    callable: Py<PyAny>,
+}
+
+impl PyRateLimitKeyExtractorBridge {
+    /// Create a bridge from a synchronous Python callable.
+    pub fn new(py: Python<'_>, callable: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let is_async = py
+            .import("inspect")?
+            .call_method1("iscoroutinefunction", (callable,))?
+            .is_truthy()
+            .unwrap_or(false);
+        if is_async {
+            return Err(pyo3::exceptions::PyTypeError::new_err(
+                "a rate limit key extractor must be a synchronous callable",
+            ));
+        }
+        Ok(Self {
+            callable: callable.clone().unbind(),
+        })
+    }
+
+    fn call(&self, py: Python<'_>, req_json: &str) -> PyResult<Option<String>> {
+        let req_obj = py.import("json")?.call_method1("loads", (req_json,))?;
+        self.callable.call1(py, (req_obj,))?.extract(py)
+    }
+}
+
+// SAFETY: Py<PyAny> is Send+Sync when we never alias it without the GIL.
+unsafe impl Send for PyRateLimitKeyExtractorBridge {}
+unsafe impl Sync for PyRateLimitKeyExtractorBridge {}
+impl spikard::RateLimitKeyExtractor for PyRateLimitKeyExtractorBridge {
+    fn extract_key(&self, request: &spikard::Request<spikard::Body>) -> Option<String> {
+        let req_json = serde_json::to_string(&spikard::RateLimitKeyRequest::from_request(request)).ok()?;
+        pyo3::Python::attach(|py| {
+            self.call(py, &req_json)
+                .map_err(|err| err.write_unraisable(py, Some(self.callable.bind(py))))
+                .ok()
+                .flatten()
+        })
+    }
+}
+/// Generated pyo3 bridge for the `BatchLoader` contract.
+///
+/// Wraps a Python callable (sync or async) so it can be used as `Arc<dyn BatchLoader>`.
+/// The callable receives the batch of keys as a list and returns one value (or `None`)
+/// per key, in order; raising fails every key in the batch with the exception message.
+pub struct PyBatchLoaderBridge {
+    callable: Py<PyAny>,
+    is_async: bool,
+}
+
+impl PyBatchLoaderBridge {
+    /// Create a bridge from a Python callable.
+    pub fn new(py: Python<'_>, callable: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let is_async = py
+            .import("inspect")?
+            .call_method1("iscoroutinefunction", (callable,))?
+            .is_truthy()
+            .unwrap_or(false);
+        Ok(Self {
+            callable: callable.clone().unbind(),
+            is_async,
+        })
+    }
+}
+
+// SAFETY: Py<PyAny> is Send+Sync when we never alias it without the GIL.
+unsafe impl Send for PyBatchLoaderBridge {}
+unsafe impl Sync for PyBatchLoaderBridge {}
+impl spikard::BatchLoader for PyBatchLoaderBridge {
+    fn load(&self, keys: Vec<serde_json::Value>) -> spikard::BatchLoadFuture {
+        let callable = pyo3::Python::attach(|py| self.callable.clone_ref(py));
+        let is_async = self.is_async;
+        Box::pin(async move {
+            let keys_json = serde_json::to_string(&keys).map_err(|e| e.to_string())?;
+            // Like lifespan hooks, the loader runs in a blocking thread so the GIL is never
+            // held on the async executor; a coroutine is driven there with `asyncio.run`.
+            let values_json = tokio::task::spawn_blocking(move || {
+                pyo3::Python::attach(|py| -> PyResult<String> {
+                    let json = py.import("json")?;
+                    let keys_obj = json.call_method1("loads", (keys_json,))?;
+                    let mut values = callable.call1(py, (keys_obj,))?.into_bound(py);
+                    if is_async {
+                        values = py.import("asyncio")?.call_method1("run", (values,))?;
+                    }
+                    json.call_method1("dumps", (values,))?.extract()
+                })
+            })
+            .await
+            .map_err(|e| e.to_string())?
+            .map_err(|e| e.to_string())?;
+            serde_json::from_str(&values_json).map_err(|e| e.to_string())
+        })
+    }
+}
+/// Call a zero-argument Python hook, mapping a raised exception to its message.
+///
+/// The hook runs in a blocking thread so the GIL is never held on the async executor;
+/// a coroutine function is driven to completion there with `asyncio.run`.
+async fn call_py_hook(callable: Py<PyAny>, is_async: bool) -> Result<(), String> {
+    tokio::task::spawn_blocking(move || {
+        pyo3::Python::attach(|py| -> PyResult<()> {
+            let result = callable.call0(py)?;
+            if is_async {
+                py.import("asyncio")?.call_method1("run", (result,))?;
+            }
+            Ok(())
+        })
+    })
+    .await
+    .map_err(|e| e.to_string())?
+    .map_err(|e| e.to_string())
+}
 /// Drive `spikard::App::run` from Python.
 ///
 /// Each entry in `registrations` is a `(method_name, metadata_tuple, callable)` triple
@@ -123,6 +320,43 @@ pub fn app_run(_py: Python<'_>, registrations: &Bound<'_, PyList>) -> PyResult<(
                     .route(builder, handler)
                     .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
             }
+            "on_startup" => {
+                let meta_item = tuple.get_item(1)?;
+                let meta: &Bound<'_, PyTuple> = meta_item.cast()?;
+                let name: String = meta.get_item(0)?.extract()?;
+                let bridge = PyLifespanHookBridge::new(_py, name, &callable)?;
+                let hook: Arc<dyn spikard::LifespanHook> = Arc::new(bridge);
+                owner.on_startup(hook);
+            }
+            "on_shutdown" => {
+                let meta_item = tuple.get_item(1)?;
+                let meta: &Bound<'_, PyTuple> = meta_item.cast()?;
+                let name: String = meta.get_item(0)?.extract()?;
+                let bridge = PyLifespanHookBridge::new(_py, name, &callable)?;
+                let hook: Arc<dyn spikard::LifespanHook> = Arc::new(bridge);
+                owner.on_shutdown(hook);
+            }
+            "health_check" => {
+                let meta_item = tuple.get_item(1)?;
+                let meta: &Bound<'_, PyTuple> = meta_item.cast()?;
+                let name: String = meta.get_item(0)?.extract()?;
+                let bridge = PyHealthCheckBridge::new(_py, name, &callable)?;
+                let check: Arc<dyn spikard::HealthCheck> = Arc::new(bridge);
+                owner.health_check(check);
+            }
+            "rate_limit_key_extractor" => {
+                let bridge = PyRateLimitKeyExtractorBridge::new(_py, &callable)?;
+                let extractor: Arc<dyn spikard::RateLimitKeyExtractor> = Arc::new(bridge);
+                owner.rate_limit_key_extractor(extractor);
+            }
+            "data_loader" => {
+                let meta_item = tuple.get_item(1)?;
+                let meta: &Bound<'_, PyTuple> = meta_item.cast()?;
+                let name: String = meta.get_item(0)?.extract()?;
+                let bridge = PyBatchLoaderBridge::new(_py, &callable)?;
+                let loader: Arc<dyn spikard::BatchLoader> = Arc::new(bridge);
+                owner.data_loader(name, loader);
+            }
             _ => {
                 return Err(pyo3::exceptions::PyValueError::new_err(format!(
                     "unknown registration method: {method_name}"
@@ -160,6 +394,43 @@ pub fn app_into_router(_py: Python<'_>, registrations: &Bound<'_, PyList>) -> Py
                     .route(builder, handler)
                     .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
             }
+            "on_startup" => {
+                let meta_item = tuple.get_item(1)?;
+                let meta: &Bound<'_, PyTuple> = meta_item.cast()?;
+                let name: String = meta.get_item(0)?.extract()?;
+                let bridge = PyLifespanHookBridge::new(_py, name, &callable)?;
+                let hook: Arc<dyn spikard::LifespanHook> = Arc::new(bridge);
+                owner.on_startup(hook);
+            }
+            "on_shutdown" => {
+                let meta_item = tuple.get_item(1)?;
+                let meta: &Bound<'_, PyTuple> = meta_item.cast()?;
+                let name: String = meta.get_item(0)?.extract()?;
+                let bridge = PyLifespanHookBridge::new(_py, name, &callable)?;
+                let hook: Arc<dyn spikard::LifespanHook> = Arc::new(bridge);
+                owner.on_shutdown(hook);
+            }
+            "health_check" => {
+                let meta_item = tuple.get_item(1)?;
+                let meta: &Bound<'_, PyTuple> = meta_item.cast()?;
+                let name: String = meta.get_item(0)?.extract()?;
+                let bridge = PyHealthCheckBridge::new(_py, name, &callable)?;
+                let check: Arc<dyn spikard::HealthCheck> = Arc::new(bridge);
+                owner.health_check(check);
+            }
+            "rate_limit_key_extractor" => {
+                let bridge = PyRateLimitKeyExtractorBridge::new(_py, &callable)?;
+                let extractor: Arc<dyn spikard::RateLimitKeyExtractor> = Arc::new(bridge);
+                owner.rate_limit_key_extractor(extractor);
+            }
+            "data_loader" => {
+                let meta_item = tuple.get_item(1)?;
+                let meta: &Bound<'_, PyTuple> = meta_item.cast()?;
+                let name: String = meta.get_item(0)?.extract()?;
+                let bridge = PyBatchLoaderBridge::new(_py, &callable)?;
+                let loader: Arc<dyn spikard::BatchLoader> = Arc::new(bridge);
+                owner.data_loader(name, loader);
+            }
             _ => {
                 return Err(pyo3::exceptions::PyValueError::new_err(format!(
                     "unknown registration method: {method_name}"
@@ -153,8 +153,8 @@ impl BuiltinGraphQLSchema {
     }
 }
 
-/// Apply shared [`spikard_graphql::SchemaConfig`] knobs (introspection/complexity/depth)
-/// to an async-graphql `SchemaBuilder`.
+/// Apply shared [`spikard_graphql::SchemaConfig`] knobs (introspection/complexity/depth, and
+/// federation with the `federation` feature) to an async-graphql `SchemaBuilder`.
 pub fn apply_schema_config<Q, M, S>(
     builder: async_graphql::SchemaBuilder<Q, M, S>,
     config: &spikard_graphql::SchemaConfig,
@@ -171,6 +171,10 @@ where
     if let Some(limit) = config.depth_limit {
         builder = builder.limit_depth(limit);
     }
+    #[cfg(feature = "federation")]
+    if config.federation {
+        builder = builder.enable_federation();
+    }
     builder
 }
 
@@ -0,0 +1,296 @@
+//! Route groups sharing a path prefix and route policies.
+//!
+//! A [`RouteGroup`] registers routes on its [`App`] with the group's settings
+//! folded into each route's [`RouteMetadata`](crate::RouteMetadata), so `OpenAPI`
+//! generation and the language bindings see the same flattened routes as if each
+//! had been configured individually. Bindings fold the settings in themselves
+//! through a [`RouteGroupScope`].
+
+use std::sync::Arc;
+
+use crate::{
+    App, AppError, Body, CorsConfig, IntoHandler, LifecycleHook, LifecycleHooks, RateLimitConfig, RouteAuthConfig,
+    RouteBuilder, default_handler_name, normalize_path,
+};
+
+type Hook = Arc<dyn LifecycleHook<axum::http::Request<Body>, axum::http::Response<Body>>>;
+
+/// Settings a route group applies to its routes, including those inherited from enclosing groups.
+///
+/// [`RouteGroup`] keeps one while it registers routes on its [`App`]. Language
+/// bindings register routes themselves, so they hold a scope directly and fold
+/// it into each route with [`RouteGroupScope::apply`].
+#[derive(Clone, Default)]
+pub struct RouteGroupScope {
+    prefix: String,
+    tags: Vec<String>,
+    #[cfg(feature = "di")]
+    dependencies: Vec<String>,
+    cors: Option<CorsConfig>,
+    rate_limit: Option<RateLimitConfig>,
+    auth: Option<RouteAuthConfig>,
+    hooks: LifecycleHooks,
+}
+
+impl RouteGroupScope {
+    /// Settings for a top-level group under `prefix`.
+    #[must_use]
+    pub fn new(prefix: &str) -> Self {
+        Self::default().nested(prefix)
+    }
+
+    /// The path prefix shared by the routes of the group.
+    #[must_use]
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// Settings for a group nested under this one, inheriting everything configured so far.
+    #[must_use]
+    pub fn nested(&self, prefix: &str) -> Self {
+        Self {
+            prefix: join_paths(&self.prefix, prefix),
+            ..self.clone()
+        }
+    }
+
+    /// The full path of a route registered under the group as `path`.
+    #[must_use]
+    pub fn path(&self, path: &str) -> String {
+        join_paths(&self.prefix, path)
+    }
+
+    /// Tag the group's routes in the `OpenAPI` document.
+    pub fn tag(&mut self, tag: impl Into<String>) -> &mut Self {
+        let tag = tag.into();
+        if !self.tags.contains(&tag) {
+            self.tags.push(tag);
+        }
+        self
+    }
+
+    /// Declare dependency keys resolved for every route in the group.
+    #[cfg(feature = "di")]
+    pub fn handler_dependencies(&mut self, dependencies: Vec<String>) -> &mut Self {
+        self.dependencies = merge_unique(&self.dependencies, dependencies);
+        self
+    }
+
+    /// Apply a CORS configuration to routes that do not set their own.
+    pub fn cors(&mut self, cors: CorsConfig) -> &mut Self {
+        self.cors = Some(cors);
+        self
+    }
+
+    /// Apply a rate limit to routes that do not set their own.
+    pub fn rate_limit(&mut self, rate_limit: RateLimitConfig) -> &mut Self {
+        self.rate_limit = Some(rate_limit);
+        self
+    }
+
+    /// Require authentication on routes that do not set their own.
+    pub fn auth(&mut self, auth: RouteAuthConfig) -> &mut Self {
+        self.auth = Some(auth);
+        self
+    }
+
+    /// Register an `on_request` hook for the group's routes.
+    pub fn on_request(&mut self, hook: Hook) -> &mut Self {
+        self.hooks.add_on_request(hook);
+        self
+    }
+
+    /// Register a `pre_validation` hook for the group's routes.
+    pub fn pre_validation(&mut self, hook: Hook) -> &mut Self {
+        self.hooks.add_pre_validation(hook);
+        self
+    }
+
+    /// Register a `pre_handler` hook for the group's routes.
+    pub fn pre_handler(&mut self, hook: Hook) -> &mut Self {
+        self.hooks.add_pre_handler(hook);
+        self
+    }
+
+    /// Register an `on_response` hook for the group's routes.
+    pub fn on_response(&mut self, hook: Hook) -> &mut Self {
+        self.hooks.add_on_response(hook);
+        self
+    }
+
+    /// Register an `on_error` hook for the group's routes.
+    pub fn on_error(&mut self, hook: Hook) -> &mut Self {
+        self.hooks.add_on_error(hook);
+        self
+    }
+
+    /// Fold the group settings into `builder`; settings on the route itself win.
+    #[must_use]
+    pub fn apply(&self, mut builder: RouteBuilder) -> RouteBuilder {
+        let default_name = builder.handler_name == default_handler_name(&builder.method, &builder.path);
+        builder.path = join_paths(&self.prefix, &builder.path);
+        if default_name {
+            builder.handler_name = default_handler_name(&builder.method, &builder.path);
+        }
+
+        builder.tags = merge_unique(&self.tags, builder.tags);
+        #[cfg(feature = "di")]
+        if !self.dependencies.is_empty() {
+            let own = builder.handler_dependencies.take().unwrap_or_default();
+            builder.handler_dependencies = Some(merge_unique(&self.dependencies, own));
+        }
+        builder.cors = builder.cors.or_else(|| self.cors.clone());
+        builder.rate_limit = builder.rate_limit.or_else(|| self.rate_limit.clone());
+        builder.auth = builder.auth.or_else(|| self.auth.clone());
+
+        let mut hooks = self.hooks.clone();
+        hooks.extend(&builder.hooks);
+        builder.hooks = hooks;
+        builder
+    }
+}
+
+/// A set of routes registered under a shared path prefix.
+///
+/// Created with [`App::group`]. Settings apply to the routes registered after
+/// them, and nested groups inherit everything configured on their parent:
+///
+/// - paths are prefixed with every enclosing group's prefix
+/// - tags and dependencies accumulate, outermost group first
+/// - CORS, rate limit and auth settings of the nearest group or of the route itself win
+/// - hooks of the outer groups run before those of inner groups, after the global hooks
+///
+/// # Examples
+///
+/// ```ignore
+/// let mut admin = app.group("/admin");
+/// admin.tag("admin").auth(RouteAuthConfig { jwt: Some(jwt), api_key: None });
+/// admin.route(get("/users"), list_users)?;
+///
+/// let mut reports = admin.group("/reports");
+/// reports.tag("reports");
+/// reports.route(get("/daily"), daily_report)?;
+/// ```
+pub struct RouteGroup<'a> {
+    app: &'a mut App,
+    scope: RouteGroupScope,
+}
+
+impl<'a> RouteGroup<'a> {
+    pub(crate) fn new(app: &'a mut App, prefix: &str) -> Self {
+        Self {
+            app,
+            scope: RouteGroupScope::new(prefix),
+        }
+    }
+
+    /// The path prefix shared by the routes of this group.
+    #[must_use]
+    pub fn prefix(&self) -> &str {
+        self.scope.prefix()
+    }
+
+    /// Register a route under this group.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if route construction fails.
+    pub fn route<H>(&mut self, builder: RouteBuilder, handler: H) -> std::result::Result<&mut Self, AppError>
+    where
+        H: IntoHandler + 'static,
+    {
+        self.app.route(self.scope.apply(builder), handler)?;
+        Ok(self)
+    }
+
+    /// Start a group nested under this one.
+    pub fn group(&mut self, prefix: &str) -> RouteGroup<'_> {
+        RouteGroup {
+            scope: self.scope.nested(prefix),
+            app: &mut *self.app,
+        }
+    }
+
+    /// Tag the group's routes in the `OpenAPI` document.
+    pub fn tag(&mut self, tag: impl Into<String>) -> &mut Self {
+        self.scope.tag(tag);
+        self
+    }
+
+    /// Declare dependency keys resolved for every route in the group.
+    #[cfg(feature = "di")]
+    pub fn handler_dependencies(&mut self, dependencies: Vec<String>) -> &mut Self {
+        self.scope.handler_dependencies(dependencies);
+        self
+    }
+
+    /// Apply a CORS configuration to routes that do not set their own.
+    pub fn cors(&mut self, cors: CorsConfig) -> &mut Self {
+        self.scope.cors(cors);
+        self
+    }
+
+    /// Apply a rate limit to routes that do not set their own.
+    pub fn rate_limit(&mut self, rate_limit: RateLimitConfig) -> &mut Self {
+        self.scope.rate_limit(rate_limit);
+        self
+    }
+
+    /// Require authentication on routes that do not set their own.
+    pub fn auth(&mut self, auth: RouteAuthConfig) -> &mut Self {
+        self.scope.auth(auth);
+        self
+    }
+
+    /// Register an `on_request` hook for the group's routes.
+    pub fn on_request(&mut self, hook: Hook) -> &mut Self {
+        self.scope.on_request(hook);
+        self
+    }
+
+    /// Register a `pre_validation` hook for the group's routes.
+    pub fn pre_validation(&mut self, hook: Hook) -> &mut Self {
+        self.scope.pre_validation(hook);
+        self
+    }
+
+    /// Register a `pre_handler` hook for the group's routes.
+    pub fn pre_handler(&mut self, hook: Hook) -> &mut Self {
+        self.scope.pre_handler(hook);
+        self
+    }
+
+    /// Register an `on_response` hook for the group's routes.
+    pub fn on_response(&mut self, hook: Hook) -> &mut Self {
+        self.scope.on_response(hook);
+        self
+    }
+
+    /// Register an `on_error` hook for the group's routes.
+    pub fn on_error(&mut self, hook: Hook) -> &mut Self {
+        self.scope.on_error(hook);
+        self
+    }
+}
+
+/// `first` followed by the entries of `second` it does not already contain.
+fn merge_unique(first: &[String], second: Vec<String>) -> Vec<String> {
+    let mut merged = first.to_vec();
+    for item in second {
+        if !merged.contains(&item) {
+            merged.push(item);
+        }
+    }
+    merged
+}
+
+fn join_paths(prefix: &str, path: &str) -> String {
+    let prefix = prefix.trim_end_matches('/');
+    let path = path.trim_start_matches('/');
+    match (prefix.is_empty(), path.is_empty()) {
+        (true, true) => "/".to_string(),
+        (true, false) => format!("/{path}"),
+        (false, true) => normalize_path(prefix.to_string()),
+        (false, false) => format!("{}/{path}", normalize_path(prefix.to_string())),
+    }
+}
@@ -7,6 +7,7 @@
 //! middleware behaviour across languages.
 
 mod graphql_schemas;
+mod group;
 pub mod upload;
 pub mod validation;
 
@@ -25,30 +26,43 @@ use serde::de::DeserializeOwned;
 #[cfg(feature = "di")]
 use spikard_core::di;
 pub use spikard_graphql::{
-    DynamicSchemaConfig, FieldErrorSpec, FullSchemaConfig, GraphQLRouteConfig, QueryMutationConfig, QueryOnlyConfig,
-    SchemaConfig, schema_full, schema_query_mutation, schema_query_only,
+    BatchLoadFuture, BatchLoadResult, BatchLoader, ClientKeySource, CostBudgetConfig, CostConfig, DataLoaderConfig,
+    DataLoaderRegistry, DataLoaders, DynamicSchemaConfig, EntityResolverSpec, FieldErrorSpec, FieldLoaderSpec,
+    FullSchemaConfig, GraphQLRouteConfig, QueryMutationConfig, QueryOnlyConfig, SchemaConfig, schema_full,
+    schema_query_mutation, schema_query_only,
 };
 #[cfg(not(target_arch = "wasm32"))]
 use spikard_http::server::Server;
 pub use spikard_http::{
-    ApiKeyConfig, AsyncApiConfig, BackgroundJobError, BackgroundJobMetadata, BackgroundTaskConfig, CompressionConfig,
-    CorsConfig, GrpcConfig, JsonRpcConfig, JwtConfig, LifecycleHook, LifecycleHooks, LifecycleHooksBuilder, Method,
-    OpenApiConfig, RateLimitConfig, Response, Route, RouteMetadata, ServerConfig, SseEvent, StaticFilesConfig,
+    ApiKeyConfig, AsyncApiConfig, BackgroundJobError, BackgroundJobMetadata, BackgroundTaskConfig, BodyCodec,
+    BodyCodecRegistry, CachingConfig, Claims, CompressionConfig, CorsConfig, CsrfConfig, CsrfMode, CsrfToken, ETagMode,
+    GrpcConfig, HealthCheck, HealthCheckContext, HealthChecks, HealthConfig, InMemoryRateLimitStore,
+    InMemoryResponseCacheStore, JsonRpcConfig, JwtConfig, LifecycleHook, LifecycleHooks, LifecycleHooksBuilder,
+    LifespanContext, LifespanError, LifespanHook, LifespanHooks, LifespanPhase, Method, MetricsConfig, MetricsRegistry,
+    OpenApiConfig, RateLimitConfig, RateLimitKey, RateLimitKeyExtractor, RateLimitKeyRequest, RateLimitStore,
+    RecordingConfig, Response, ResponseCache, ResponseCacheConfig, ResponseCacheStore, ResponseEncoder,
+    ResponseEncoderRegistry, Route, RouteAuthConfig, RouteMetadata, ServerConfig, Session, SessionConfig,
+    SessionStorage, SessionStore, SseEvent, StaticFilesConfig, TelemetryConfig, TrustedProxyConfig, XmlCodec,
+    XmlMapping,
     cors::{add_cors_headers, handle_preflight, validate_cors_request},
     handler_response::HandlerResponse,
     handler_trait::HandlerResult,
+    health::{HealthCheckFuture, ReadinessReport, health_check},
     lifecycle::{HookResult, request_hook, response_hook},
+    lifespan::{LifespanFuture, lifespan_hook},
+    proxy::ClientInfo,
     sse::SseEventProducer,
     websocket::WebSocketHandler,
 };
 pub use spikard_http::{JsonRpcMethodInfo, ProblemDetails};
 pub use spikard_http::{RequestData, handler_trait::Handler};
 use spikard_http::{
-    SchemaRegistry,
+    RouteHooksHandler, SchemaRegistry,
     sse::{SseState, sse_handler},
     websocket::{WebSocketState, websocket_handler},
 };
 
+pub use group::{RouteGroup, RouteGroupScope};
 pub use upload::UploadFile;
 
 /// Convert a binding-side handler outcome into the framework's `HandlerResult`.
@@ -95,6 +109,7 @@ pub struct App {
     routes: Vec<(Route, Arc<dyn Handler>)>,
     metadata: Vec<RouteMetadata>,
     attached_routers: Vec<AxumRouter>,
+    data_loaders: DataLoaderRegistry,
 }
 
 impl App {
@@ -107,6 +122,7 @@ impl App {
             routes: Vec::new(),
             metadata: Vec::new(),
             attached_routers: Vec::new(),
+            data_loaders: DataLoaderRegistry::new(),
         }
     }
 
@@ -117,6 +133,27 @@ impl App {
         self
     }
 
+    /// The dependency container handlers resolve from, if any dependencies are registered.
+    ///
+    /// Overrides set on the returned container apply to routers already built from this app.
+    #[cfg(feature = "di")]
+    #[must_use]
+    pub fn dependency_container(&self) -> Option<Arc<di::DependencyContainer>> {
+        self.config.di_container.as_ref().map(Arc::clone)
+    }
+
+    /// Validate the dependency wiring of the registered routes, endpoints and readiness checks.
+    ///
+    /// The report lists unknown keys, scope violations, cycles and unused
+    /// registrations, and dumps the checked graph with
+    /// [`DependencyReport::to_json`](di::DependencyReport::to_json) or
+    /// [`DependencyReport::to_dot`](di::DependencyReport::to_dot).
+    #[cfg(feature = "di")]
+    #[must_use]
+    pub fn dependency_report(&self) -> di::DependencyReport {
+        spikard_http::server::dependency_report(&self.routes, &self.config)
+    }
+
     /// Register an `on_request` lifecycle hook (runs before validation and handler dispatch).
     pub fn on_request(
         &mut self,
@@ -162,6 +199,116 @@ impl App {
         self
     }
 
+    /// Register a startup hook (runs before the listener binds, in registration order).
+    ///
+    /// Values the hook provides through its [`LifespanContext`] become singleton
+    /// dependencies. A failing startup hook aborts [`App::run`].
+    pub fn on_startup(&mut self, hook: Arc<dyn LifespanHook>) -> &mut Self {
+        self.ensure_lifespan().add_startup(hook);
+        self
+    }
+
+    /// Register a startup hook with an explicit timeout.
+    pub fn on_startup_with_timeout(&mut self, hook: Arc<dyn LifespanHook>, timeout: std::time::Duration) -> &mut Self {
+        self.ensure_lifespan().add_startup_with_timeout(hook, timeout);
+        self
+    }
+
+    /// Register a shutdown hook (runs after the server stops, in reverse registration order).
+    pub fn on_shutdown(&mut self, hook: Arc<dyn LifespanHook>) -> &mut Self {
+        self.ensure_lifespan().add_shutdown(hook);
+        self
+    }
+
+    /// Register a shutdown hook with an explicit timeout.
+    pub fn on_shutdown_with_timeout(&mut self, hook: Arc<dyn LifespanHook>, timeout: std::time::Duration) -> &mut Self {
+        self.ensure_lifespan().add_shutdown_with_timeout(hook, timeout);
+        self
+    }
+
+    /// Register a readiness check with the default timeout.
+    ///
+    /// Enables the health endpoints with their default paths unless `ServerConfig::health`
+    /// is already set.
+    pub fn health_check(&mut self, check: Arc<dyn HealthCheck>) -> &mut Self {
+        self.ensure_health_checks().add(check);
+        self
+    }
+
+    /// Register a readiness check with an explicit timeout.
+    pub fn health_check_with_timeout(
+        &mut self,
+        check: Arc<dyn HealthCheck>,
+        timeout: std::time::Duration,
+    ) -> &mut Self {
+        self.ensure_health_checks().add_with_timeout(check, timeout);
+        self
+    }
+
+    /// Register a readiness check that receives the named dependencies in its context.
+    pub fn health_check_with_dependencies(
+        &mut self,
+        check: Arc<dyn HealthCheck>,
+        dependencies: Vec<String>,
+        timeout: std::time::Duration,
+    ) -> &mut Self {
+        self.ensure_health_checks()
+            .add_with_dependencies(check, dependencies, timeout);
+        self
+    }
+
+    /// Set the extractor that keys rate limits configured with `RateLimitKey::Custom`.
+    pub fn rate_limit_key_extractor(&mut self, extractor: Arc<dyn RateLimitKeyExtractor>) -> &mut Self {
+        self.config.rate_limit_key_extractor = Some(extractor);
+        self
+    }
+
+    /// Register a named batch loader for dynamic-SDL GraphQL routes.
+    ///
+    /// Routes registered afterwards with [`App::register_graphql_sdl_route`] resolve the
+    /// `config.field_loaders` that name this loader through it. Registering the same name
+    /// twice replaces the earlier loader.
+    pub fn data_loader(&mut self, name: impl Into<String>, loader: Arc<dyn BatchLoader>) -> &mut Self {
+        let registry = std::mem::take(&mut self.data_loaders);
+        self.data_loaders = registry.register_arc(name, loader, DataLoaderConfig::default());
+        self
+    }
+
+    /// Registry for custom metrics, served with the built-in ones when metrics are enabled.
+    ///
+    /// The registry is created on first use with the configured histogram buckets.
+    pub fn metrics_registry(&mut self) -> Arc<MetricsRegistry> {
+        let buckets = self.config.metrics.as_ref().map(|metrics| metrics.buckets.clone());
+        let registry = self
+            .config
+            .metrics_registry
+            .get_or_insert_with(|| Arc::new(buckets.map_or_else(MetricsRegistry::new, MetricsRegistry::with_buckets)));
+        Arc::clone(registry)
+    }
+
+    /// Retrieve a mutable reference to the `LifespanHooks`, creating it if absent.
+    ///
+    /// Hooks shared with a caller-supplied `ServerConfig` are copied before being extended.
+    fn ensure_lifespan(&mut self) -> &mut LifespanHooks {
+        let hooks = self
+            .config
+            .lifespan
+            .get_or_insert_with(|| Arc::new(LifespanHooks::new()));
+        Arc::make_mut(hooks)
+    }
+
+    /// Retrieve a mutable reference to the `HealthChecks`, creating it if absent.
+    ///
+    /// Checks shared with a caller-supplied `ServerConfig` are copied before being extended.
+    fn ensure_health_checks(&mut self) -> &mut HealthChecks {
+        self.config.health.get_or_insert_with(HealthConfig::default);
+        let checks = self
+            .config
+            .health_checks
+            .get_or_insert_with(|| Arc::new(HealthChecks::new()));
+        Arc::make_mut(checks)
+    }
+
     /// Retrieve a mutable reference to the `LifecycleHooks`, creating it if absent.
     /// Registration always happens before `run()`, so the Arc is never shared here.
     fn ensure_lifecycle_hooks(&mut self) -> &mut LifecycleHooks {
@@ -186,14 +333,29 @@ impl App {
     where
         H: IntoHandler + 'static,
     {
+        let mut builder = builder;
+        let hooks = std::mem::take(&mut builder.hooks);
         let metadata = builder.into_metadata();
         let route = Route::from_metadata(metadata.clone(), &self.registry).map_err(AppError::Route)?;
         let handler = handler.into_handler();
+        let handler: Arc<dyn Handler> = if hooks.is_empty() {
+            handler
+        } else {
+            Arc::new(RouteHooksHandler::new(handler, Arc::new(hooks)))
+        };
         self.routes.push((route, handler));
         self.metadata.push(metadata);
         Ok(self)
     }
 
+    /// Start a group of routes sharing `prefix` and the policies configured on the group.
+    ///
+    /// See [`RouteGroup`] for how group settings combine with those of nested groups
+    /// and of the routes themselves.
+    pub fn group(&mut self, prefix: &str) -> RouteGroup<'_> {
+        RouteGroup::new(self, prefix)
+    }
+
     /// Register a `/graphql`-style route backed by one of Spikard's built-in
     /// async-graphql test schemas.
     ///
@@ -213,12 +375,12 @@ impl App {
     /// * `path` - The HTTP path to serve GraphQL requests on (e.g. `/graphql`).
     /// * `method` - The HTTP method to accept (fixtures always use `POST`).
     /// * `schema_type` - One of `"query_only"`, `"query_mutation"`, or `"full"`.
-    /// * `config` - Introspection/complexity/depth limits applied to the selected schema.
+    /// * `config` - Introspection/complexity/depth limits and cost analysis applied to the selected schema.
     ///
     /// # Errors
     ///
     /// Returns [`AppError::GraphQL`] if `schema_type` is not a recognized built-in schema
-    /// name, and [`AppError::Route`] if route construction fails.
+    /// name or `config.cost` is invalid, and [`AppError::Route`] if route construction fails.
     pub fn register_graphql_route(
         &mut self,
         path: impl Into<String>,
@@ -254,10 +416,9 @@ impl App {
                     async_graphql::EmptySubscription,
                 );
                 let schema = apply_schema_config(builder, config).finish();
-                Arc::new(GraphQLHandler::new(Arc::new(GraphQLExecutor::with_introspection(
-                    schema,
-                    config.introspection_enabled,
-                ))))
+                let executor = GraphQLExecutor::from_schema_config(schema, config)
+                    .map_err(|err| AppError::GraphQL(err.to_string()))?;
+                Arc::new(GraphQLHandler::new(Arc::new(executor)))
             }
             BuiltinGraphQLSchema::QueryMutation => {
                 let builder = async_graphql::Schema::build(
@@ -266,19 +427,17 @@ impl App {
                     async_graphql::EmptySubscription,
                 );
                 let schema = apply_schema_config(builder, config).finish();
-                Arc::new(GraphQLHandler::new(Arc::new(GraphQLExecutor::with_introspection(
-                    schema,
-                    config.introspection_enabled,
-                ))))
+                let executor = GraphQLExecutor::from_schema_config(schema, config)
+                    .map_err(|err| AppError::GraphQL(err.to_string()))?;
+                Arc::new(GraphQLHandler::new(Arc::new(executor)))
             }
             BuiltinGraphQLSchema::Full => {
                 let builder =
                     async_graphql::Schema::build(FullQueryRoot, MutationRoot, async_graphql::EmptySubscription);
                 let schema = apply_schema_config(builder, config).finish();
-                Arc::new(GraphQLHandler::new(Arc::new(GraphQLExecutor::with_introspection(
-                    schema,
-                    config.introspection_enabled,
-                ))))
+                let executor = GraphQLExecutor::from_schema_config(schema, config)
+                    .map_err(|err| AppError::GraphQL(err.to_string()))?;
+                Arc::new(GraphQLHandler::new(Arc::new(executor)))
             }
         };
 
@@ -297,6 +456,8 @@ impl App {
     /// argument-dependent results (e.g. `user(id: "user-42")`) must already be baked into
     /// `response_data` at the correct path. See [`spikard_graphql::dynamic`] for the full
     /// resolver-data encoding and field-level-error support via `config.field_errors`.
+    /// Fields listed in `config.field_loaders` batch through the loaders registered with
+    /// [`App::data_loader`].
     ///
     /// # Arguments
     ///
@@ -318,6 +479,35 @@ impl App {
         sdl: &str,
         response_data: serde_json::Value,
         config: &spikard_graphql::DynamicSchemaConfig,
+    ) -> std::result::Result<&mut Self, AppError> {
+        self.register_graphql_sdl_route_with_data_loaders(
+            path,
+            method,
+            sdl,
+            response_data,
+            config,
+            self.data_loaders.clone(),
+        )
+    }
+
+    /// Register a dynamic-SDL GraphQL route whose resolvers can batch through `data_loaders`.
+    ///
+    /// Fields listed in `config.field_loaders` resolve through the named loaders; a fresh,
+    /// request-scoped set of loaders is created for every request. When `config.cost` is set,
+    /// operations are priced and budgeted as described in [`spikard_graphql::cost`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the SDL cannot be built into a schema or the cost configuration is
+    /// invalid.
+    pub fn register_graphql_sdl_route_with_data_loaders(
+        &mut self,
+        path: impl Into<String>,
+        method: Method,
+        sdl: &str,
+        response_data: serde_json::Value,
+        config: &spikard_graphql::DynamicSchemaConfig,
+        data_loaders: DataLoaderRegistry,
     ) -> std::result::Result<&mut Self, AppError> {
         use spikard_graphql::{DynamicGraphQLHandler, build_dynamic_schema};
 
@@ -335,7 +525,13 @@ impl App {
             "required": ["query"]
         });
 
-        let handler: Arc<dyn Handler> = Arc::new(DynamicGraphQLHandler::new(schema));
+        let mut handler = DynamicGraphQLHandler::new(schema).with_data_loaders(data_loaders);
+        if let Some(cost) = &config.cost {
+            handler = handler
+                .with_cost_analysis(sdl, cost)
+                .map_err(|e| AppError::GraphQL(e.to_string()))?;
+        }
+        let handler: Arc<dyn Handler> = Arc::new(handler);
         let route_builder = RouteBuilder::new(method, path).request_schema_json(request_schema);
         self.route(route_builder, handler)
     }
@@ -405,6 +601,72 @@ impl App {
         Ok(self.register_stateful_sse_route(path, state))
     }
 
+    /// Register a WebSocket handler whose connections resolve `dependencies`.
+    ///
+    /// The dependencies are resolved when a connection opens, passed to
+    /// [`WebSocketHandler::handle_message_with_dependencies`], and cleaned up
+    /// when it closes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no dependency container is configured.
+    #[cfg(feature = "di")]
+    pub fn websocket_with_dependencies<H>(
+        &mut self,
+        path: impl Into<String>,
+        handler: H,
+        dependencies: Vec<String>,
+    ) -> std::result::Result<&mut Self, AppError>
+    where
+        H: WebSocketHandler + Send + Sync + 'static,
+    {
+        let path = normalize_path(path.into());
+        let declared = self.declared_dependencies(format!("WEBSOCKET {path}"), dependencies)?;
+        let state = WebSocketState::new(handler).with_dependencies(declared);
+        Ok(self.register_stateful_ws_route(path, state))
+    }
+
+    /// Register an SSE producer whose streams resolve `dependencies`.
+    ///
+    /// The dependencies are resolved when a client connects, passed to
+    /// [`SseEventProducer::next_event_with_dependencies`], and cleaned up when
+    /// the stream is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no dependency container is configured.
+    #[cfg(feature = "di")]
+    pub fn sse_with_dependencies<P>(
+        &mut self,
+        path: impl Into<String>,
+        producer: P,
+        dependencies: Vec<String>,
+    ) -> std::result::Result<&mut Self, AppError>
+    where
+        P: SseEventProducer + Send + Sync + 'static,
+    {
+        let path = normalize_path(path.into());
+        let declared = self.declared_dependencies(format!("SSE {path}"), dependencies)?;
+        let state = SseState::new(producer).with_dependencies(declared);
+        Ok(self.register_stateful_sse_route(path, state))
+    }
+
+    /// Internal helper: bind dependency keys to the configured container.
+    ///
+    /// The keys are recorded under `label` so building the router checks them.
+    #[cfg(feature = "di")]
+    fn declared_dependencies(
+        &mut self,
+        label: String,
+        dependencies: Vec<String>,
+    ) -> std::result::Result<spikard_http::DeclaredDependencies, AppError> {
+        let container = self
+            .dependency_container()
+            .ok_or_else(|| AppError::Route("dependencies require a configured dependency container".to_string()))?;
+        self.config.endpoint_dependencies.push((label, dependencies.clone()));
+        Ok(spikard_http::DeclaredDependencies::new(container, dependencies))
+    }
+
     /// Internal helper: register a WebSocket state with route normalization.
     fn register_stateful_ws_route<H: WebSocketHandler + Send + Sync + 'static>(
         &mut self,
@@ -455,11 +717,7 @@ impl App {
             attached_routers,
             ..
         } = self;
-        let mut router = Server::with_handlers_and_metadata(config, routes, metadata).map_err(AppError::Server)?;
-        for extra in attached_routers {
-            router = router.merge(extra);
-        }
-        Ok(router)
+        assemble_router(config, routes, metadata, attached_routers).map(|(router, _)| router)
     }
 
     /// Decompose the application into its Axum router and server configuration.
@@ -479,32 +737,29 @@ impl App {
             attached_routers,
             ..
         } = self;
-        let mut router =
-            Server::with_handlers_and_metadata(config.clone(), routes, metadata).map_err(AppError::Server)?;
-        for extra in attached_routers {
-            router = router.merge(extra);
-        }
-        Ok((router, config))
+        assemble_router(config, routes, metadata, attached_routers)
     }
 
     /// Run the HTTP server using the configured routes.
     ///
+    /// Startup hooks run before the router is built so the values they provide are
+    /// injectable; shutdown hooks run once the server stops.
+    ///
     /// # Errors
     ///
-    /// Returns an error if server construction or execution fails.
+    /// Returns an error if a startup hook fails, or if server construction or execution fails.
     pub async fn run(self) -> std::result::Result<(), AppError> {
         let Self {
-            config,
+            mut config,
             routes,
             metadata,
             attached_routers,
             ..
         } = self;
-        let mut router =
-            Server::with_handlers_and_metadata(config.clone(), routes, metadata).map_err(AppError::Server)?;
-        for extra in attached_routers {
-            router = router.merge(extra);
-        }
+        Server::startup(&mut config)
+            .await
+            .map_err(|err| AppError::Lifespan(err.to_string()))?;
+        let (router, config) = assemble_router(config, routes, metadata, attached_routers)?;
         Server::run_with_config(router, config)
             .await
             .map_err(|err| AppError::Server(err.to_string()))
@@ -517,6 +772,36 @@ impl Default for App {
     }
 }
 
+/// Build the server router and merge the attached routers into it.
+///
+/// Attached routers (WebSocket, SSE, raw Axum) are instrumented with the same metrics
+/// registry as the server routes when metrics are enabled.
+fn assemble_router(
+    mut config: ServerConfig,
+    routes: Vec<(Route, Arc<dyn Handler>)>,
+    metadata: Vec<RouteMetadata>,
+    attached_routers: Vec<AxumRouter>,
+) -> std::result::Result<(AxumRouter, ServerConfig), AppError> {
+    let metrics_registry = spikard_http::metrics::ensure_registry(&mut config);
+    let mut app_router =
+        Server::with_handlers_and_metadata(config.clone(), routes, metadata).map_err(AppError::Server)?;
+    let telemetry = config.telemetry.clone().map(Arc::new);
+    for extra in attached_routers {
+        let extra = match metrics_registry {
+            Some(ref registry) => spikard_http::metrics::instrument_router(extra, Arc::clone(registry)),
+            None => extra,
+        };
+        let extra = match telemetry {
+            Some(ref telemetry) => spikard_http::telemetry::trace_router(extra, Arc::clone(telemetry)),
+            None => extra,
+        };
+        let extra = spikard_http::proxy::client_info_router(extra, config.trusted_proxies.as_ref())
+            .map_err(AppError::Server)?;
+        app_router = app_router.merge(extra);
+    }
+    Ok((app_router, config))
+}
+
 /// Builder for defining a route.
 #[derive(Clone)]
 pub struct RouteBuilder {
@@ -531,6 +816,12 @@ pub struct RouteBuilder {
     compression: Option<CompressionConfig>,
     body_limit: Option<usize>,
     request_timeout_secs: Option<u64>,
+    rate_limit: Option<RateLimitConfig>,
+    caching: Option<CachingConfig>,
+    response_cache: Option<ResponseCacheConfig>,
+    tags: Vec<String>,
+    auth: Option<RouteAuthConfig>,
+    hooks: LifecycleHooks,
     is_async: bool,
     #[cfg(feature = "di")]
     handler_dependencies: Option<Vec<String>>,
@@ -554,6 +845,12 @@ impl RouteBuilder {
             compression: None,
             body_limit: None,
             request_timeout_secs: None,
+            rate_limit: None,
+            caching: None,
+            response_cache: None,
+            tags: Vec::new(),
+            auth: None,
+            hooks: LifecycleHooks::new(),
             is_async: true,
             #[cfg(feature = "di")]
             handler_dependencies: None,
@@ -644,6 +941,94 @@ impl RouteBuilder {
         self
     }
 
+    /// Attach a per-route rate limit, applied in addition to the server-global limit.
+    #[must_use]
+    pub fn rate_limit(mut self, rate_limit: RateLimitConfig) -> Self {
+        self.rate_limit = Some(rate_limit);
+        self
+    }
+
+    /// Attach HTTP caching metadata: `ETag`, `Cache-Control`, `Vary` and conditional requests.
+    #[must_use]
+    pub fn caching(mut self, caching: CachingConfig) -> Self {
+        self.caching = Some(caching);
+        self
+    }
+
+    /// Cache whole responses of this GET route on the server.
+    #[must_use]
+    pub fn response_cache(mut self, response_cache: ResponseCacheConfig) -> Self {
+        self.response_cache = Some(response_cache);
+        self
+    }
+
+    /// Group the route under an `OpenAPI` tag.
+    #[must_use]
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Require JWT and/or API key authentication for this route.
+    #[must_use]
+    pub fn auth(mut self, auth: RouteAuthConfig) -> Self {
+        self.auth = Some(auth);
+        self
+    }
+
+    /// Register an `on_request` hook that runs only for this route.
+    ///
+    /// Route hooks run after the global hooks and the hooks of enclosing route
+    /// groups in the same phase.
+    #[must_use]
+    pub fn on_request(
+        mut self,
+        hook: Arc<dyn LifecycleHook<axum::http::Request<Body>, axum::http::Response<Body>>>,
+    ) -> Self {
+        self.hooks.add_on_request(hook);
+        self
+    }
+
+    /// Register a `pre_validation` hook that runs only for this route.
+    #[must_use]
+    pub fn pre_validation(
+        mut self,
+        hook: Arc<dyn LifecycleHook<axum::http::Request<Body>, axum::http::Response<Body>>>,
+    ) -> Self {
+        self.hooks.add_pre_validation(hook);
+        self
+    }
+
+    /// Register a `pre_handler` hook that runs only for this route.
+    #[must_use]
+    pub fn pre_handler(
+        mut self,
+        hook: Arc<dyn LifecycleHook<axum::http::Request<Body>, axum::http::Response<Body>>>,
+    ) -> Self {
+        self.hooks.add_pre_handler(hook);
+        self
+    }
+
+    /// Register an `on_response` hook that runs only for this route.
+    #[must_use]
+    pub fn on_response(
+        mut self,
+        hook: Arc<dyn LifecycleHook<axum::http::Request<Body>, axum::http::Response<Body>>>,
+    ) -> Self {
+        self.hooks.add_on_response(hook);
+        self
+    }
+
+    /// Register an `on_error` hook that runs only for this route.
+    #[must_use]
+    pub fn on_error(
+        mut self,
+        hook: Arc<dyn LifecycleHook<axum::http::Request<Body>, axum::http::Response<Body>>>,
+    ) -> Self {
+        self.hooks.add_on_error(hook);
+        self
+    }
+
     /// Mark the route as synchronous.
     #[must_use]
     pub const fn sync(mut self) -> Self {
@@ -675,6 +1060,11 @@ impl RouteBuilder {
                 compression: self.compression,
                 body_limit: self.body_limit,
                 request_timeout_secs: self.request_timeout_secs,
+                rate_limit: self.rate_limit,
+                caching: self.caching,
+                response_cache: self.response_cache,
+                tags: self.tags,
+                auth: self.auth,
                 body_param_name: None,
                 handler_dependencies: self.handler_dependencies,
                 jsonrpc_method: None,
@@ -696,6 +1086,11 @@ impl RouteBuilder {
                 compression: self.compression,
                 body_limit: self.body_limit,
                 request_timeout_secs: self.request_timeout_secs,
+                rate_limit: self.rate_limit,
+                caching: self.caching,
+                response_cache: self.response_cache,
+                tags: self.tags,
+                auth: self.auth,
                 body_param_name: None,
                 jsonrpc_method: None,
                 static_response: None,
@@ -804,12 +1199,15 @@ pub enum AppError {
     /// GraphQL route registration failed (e.g. an unrecognized `schema_type`).
     #[error("Failed to register GraphQL route: {0}")]
     GraphQL(String),
+    /// A startup hook failed or timed out.
+    #[error("Application startup failed: {0}")]
+    Lifespan(String),
 }
 
 impl From<AppError> for (StatusCode, String) {
     fn from(err: AppError) -> Self {
         match err {
-            AppError::Route(msg) | AppError::Server(msg) | AppError::GraphQL(msg) => {
+            AppError::Route(msg) | AppError::Server(msg) | AppError::GraphQL(msg) | AppError::Lifespan(msg) => {
                 (StatusCode::INTERNAL_SERVER_ERROR, msg)
             }
             AppError::Decode(msg) => (StatusCode::BAD_REQUEST, msg),
@@ -940,6 +1338,42 @@ impl RequestContext {
     pub fn path_str(&self) -> &str {
         &self.data.path
     }
+
+    /// Return the client IP address, resolved through trusted proxies when configured.
+    #[must_use]
+    pub fn client_ip(&self) -> Option<&str> {
+        self.data.client_ip.as_deref()
+    }
+
+    /// Return the scheme the client used (`"http"` or `"https"`).
+    #[must_use]
+    pub fn scheme(&self) -> Option<&str> {
+        self.data.scheme.as_deref()
+    }
+
+    /// Return the host the client addressed, including any port.
+    #[must_use]
+    pub fn host(&self) -> Option<&str> {
+        self.data.host.as_deref()
+    }
+
+    /// Return the session of this request when sessions are configured.
+    #[must_use]
+    pub fn session(&self) -> Option<&Session> {
+        self.request.extensions().get::<Session>()
+    }
+
+    /// Return the CSRF token the client must echo on unsafe requests.
+    #[must_use]
+    pub fn csrf_token(&self) -> Option<&str> {
+        self.request.extensions().get::<CsrfToken>().map(CsrfToken::as_str)
+    }
+
+    /// Return the server's response cache, for invalidating cached responses by tag.
+    #[must_use]
+    pub fn response_cache(&self) -> Option<&ResponseCache> {
+        self.request.extensions().get::<ResponseCache>()
+    }
 }
 
 /// Convert user-facing handler functions into the low-level `Handler` trait.
@@ -1088,6 +1522,9 @@ mod tests {
         let data = RequestData {
             method: "POST".to_string(),
             path: "/users/{id}".to_string(),
+            client_ip: None,
+            scheme: None,
+            host: None,
             headers: std::sync::Arc::new(headers),
             cookies: std::sync::Arc::new(cookies),
             query_params: std::sync::Arc::new(serde_json::Value::Object(serde_json::Map::new())),
@@ -1165,4 +1602,29 @@ mod tests {
         let response = router.oneshot(request).await.unwrap();
         assert_eq!(response.status(), StatusCode::OK);
     }
+
+    #[test]
+    fn hooks_shared_with_supplied_config_are_copied_not_mutated() {
+        let shared_hooks = Arc::new(LifespanHooks::new());
+        let shared_checks = Arc::new(HealthChecks::new());
+        let config = ServerConfig {
+            lifespan: Some(Arc::clone(&shared_hooks)),
+            health_checks: Some(Arc::clone(&shared_checks)),
+            ..ServerConfig::default()
+        };
+
+        let mut app = App::new().config(config);
+        app.on_startup(lifespan_hook("connect", |_ctx| async { Ok(()) }))
+            .health_check(health_check("database", |_ctx| async { Ok(()) }));
+
+        assert!(shared_hooks.is_empty());
+        assert!(shared_checks.is_empty());
+        assert!(!app.config.lifespan.as_ref().is_some_and(|hooks| hooks.is_empty()));
+        assert!(
+            !app.config
+                .health_checks
+                .as_ref()
+                .is_some_and(|checks| checks.is_empty())
+        );
+    }
 }
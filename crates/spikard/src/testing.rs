@@ -22,10 +22,15 @@
 use super::{App, AppError};
 use axum_test::{TestServer as AxumTestServer, TestServerConfig, Transport};
 
+pub use spikard_http::assert_response_snapshot;
 pub use spikard_http::testing::SseEvent as TestSseEvent;
 pub use spikard_http::testing::{
-    GraphQLSubscriptionSnapshot, MultipartFilePart, ResponseSnapshot, SnapshotError, SseStream, TestClient,
-    WebSocketConnection, WebSocketMessage, build_multipart_body, encode_urlencoded_body,
+    CaseKind, ContractCase, ContractFailure, ContractParam, ContractReport, ContractRoute, ContractTransport, Fixture,
+    FixtureExpectedResponse, FixtureHandler, FixtureRequest, GraphQLSubscriptionSnapshot, GrpcResponse,
+    MultipartFilePart, ParamLocation, ReplayOutcome, ResponseSnapshot, SnapshotError, SnapshotOptions, SnapshotOutcome,
+    SnapshotUpdate, SseReader, SseStream, StreamError, StreamingResponse, TestClient, WebSocketConnection,
+    WebSocketMessage, assert_snapshot_file, build_multipart_body, check_snapshot, encode_urlencoded_body,
+    generate_cases, load_recorded_fixtures, replay_fixture, run_contract, snapshot_path,
 };
 
 /// Construct a [`TestClient`] from a fully-built [`App`].
@@ -38,11 +43,37 @@ pub use spikard_http::testing::{
 ///
 /// Returns an error if the application router cannot be built (invalid route
 /// configuration) or if the test server cannot be initialized.
+///
+/// When the application registers dependencies, the client can override them
+/// with [`TestClient::override_dependency`].
 pub fn test_client_from_app(app: App) -> Result<TestClient, SnapshotError> {
-    let router = app
-        .into_router()
+    let (router, config) = app
+        .into_router_and_config()
         .map_err(|e| SnapshotError::Decompression(format!("Failed to build app router: {e}")))?;
-    TestClient::from_router(router).map_err(SnapshotError::Decompression)
+    let client = TestClient::from_router(router).map_err(SnapshotError::Decompression)?;
+    #[cfg(feature = "di")]
+    let client = match config.di_container {
+        Some(container) => client.with_dependencies(container),
+        None => client,
+    };
+    #[cfg(not(feature = "di"))]
+    let _ = config;
+    Ok(client)
+}
+
+/// Contract-test every route of an [`App`] against its schemas.
+///
+/// Valid, boundary and invalid requests are generated from each route's parameter and
+/// request schemas and sent through a [`TestClient`]; see [`run_contract`].
+///
+/// # Errors
+///
+/// Returns an error if the application cannot be built, a route schema does not compile,
+/// or a request cannot be sent.
+pub async fn contract_test_app(app: App) -> Result<ContractReport, SnapshotError> {
+    let routes: Vec<ContractRoute> = app.metadata.iter().map(ContractRoute::from_metadata).collect();
+    let client = test_client_from_app(app)?;
+    run_contract(&client, &routes).await
 }
 
 /// Spikard-native test server wrapper that hides the Axum test harness.
@@ -52,6 +83,8 @@ pub fn test_client_from_app(app: App) -> Result<TestClient, SnapshotError> {
 pub struct TestServer {
     mock_server: AxumTestServer,
     http_server: AxumTestServer,
+    #[cfg(feature = "di")]
+    dependencies: Option<std::sync::Arc<spikard_core::di::DependencyContainer>>,
 }
 
 impl TestServer {
@@ -61,8 +94,18 @@ impl TestServer {
     ///
     /// Returns an error if the application router construction fails.
     pub fn from_app(app: App) -> Result<Self, AppError> {
-        let router = app.into_router()?;
-        Self::from_router(router)
+        let (router, config) = app.into_router_and_config()?;
+        #[cfg(feature = "di")]
+        {
+            let mut server = Self::from_router(router)?;
+            server.dependencies = config.di_container;
+            Ok(server)
+        }
+        #[cfg(not(feature = "di"))]
+        {
+            let _ = config;
+            Self::from_router(router)
+        }
     }
 
     /// Build a test server from an Axum router.
@@ -81,9 +124,32 @@ impl TestServer {
         Ok(Self {
             mock_server,
             http_server,
+            #[cfg(feature = "di")]
+            dependencies: None,
         })
     }
 
+    /// Override the dependency registered as `key` until the returned guard is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the server was not built from an `App` with dependencies,
+    /// or if no dependency is registered under `key`.
+    #[cfg(feature = "di")]
+    pub fn override_dependency(
+        &self,
+        key: &str,
+        dependency: std::sync::Arc<dyn spikard_core::di::Dependency>,
+    ) -> Result<spikard_core::di::DependencyOverrideGuard, AppError> {
+        let container = self
+            .dependencies
+            .as_ref()
+            .ok_or_else(|| AppError::Server("test server has no dependency container".to_string()))?;
+        container
+            .override_scope(key, dependency)
+            .map_err(|err| AppError::Server(err.to_string()))
+    }
+
     /// Execute an HTTP request and return a snapshot of the response.
     ///
     /// # Errors
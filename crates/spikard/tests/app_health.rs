@@ -0,0 +1,24 @@
+//! Readiness checks registered on an `App` are served by the built-in health endpoints.
+
+use spikard::{App, health_check, testing::test_client_from_app};
+
+#[tokio::test]
+async fn registered_checks_enable_health_endpoints() {
+    let mut app = App::new();
+    app.health_check(health_check("database", |_ctx| async { Ok(()) }))
+        .health_check(health_check("broker", |_ctx| async {
+            Err("broker unreachable".to_string())
+        }));
+
+    let client = test_client_from_app(app).expect("test client");
+
+    let live = client.get("/healthz", None, None).await.expect("GET /healthz");
+    assert_eq!(live.status, 200);
+
+    let ready = client.get("/readyz", None, None).await.expect("GET /readyz");
+    assert_eq!(ready.status, 503);
+    let report = ready.json().expect("json report");
+    assert_eq!(report["status"], "not_ready");
+    assert_eq!(report["checks"]["database"]["status"], "ok");
+    assert_eq!(report["checks"]["broker"]["error"], "broker unreachable");
+}
@@ -0,0 +1,47 @@
+//! Metrics collected across server routes and routers attached to an `App`.
+
+use spikard::{App, MetricsConfig, ServerConfig, SseEvent, SseEventProducer, testing::test_client_from_app};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+struct OneEvent {
+    sent: AtomicBool,
+}
+
+impl SseEventProducer for OneEvent {
+    async fn next_event(&self) -> Option<SseEvent> {
+        if self.sent.swap(true, Ordering::SeqCst) {
+            None
+        } else {
+            Some(SseEvent::new(serde_json::json!({"hello": "world"})))
+        }
+    }
+}
+
+#[tokio::test]
+async fn attached_sse_routes_and_custom_metrics_are_exported() {
+    let mut app = App::new().config(ServerConfig {
+        metrics: Some(MetricsConfig::default()),
+        ..Default::default()
+    });
+    let signups = app
+        .metrics_registry()
+        .counter("app_signups_total", "Accounts created", &[])
+        .expect("counter");
+    signups.inc(&[]);
+    app.sse(
+        "/events",
+        OneEvent {
+            sent: AtomicBool::new(false),
+        },
+    );
+
+    let client = test_client_from_app(app).expect("test client");
+    let events = client.get("/events", None, None).await.expect("GET /events");
+    assert_eq!(events.status, 200);
+
+    let metrics = client.get("/metrics", None, None).await.expect("GET /metrics");
+    let text = metrics.text().expect("utf-8 metrics");
+    assert!(text.contains("app_signups_total 1\n"));
+    assert!(text.contains("spikard_http_requests_total{method=\"GET\",route=\"/events\",status=\"200\"} 1\n"));
+    assert!(text.contains("spikard_sse_connections_active{route=\"/events\"} 0\n"));
+}
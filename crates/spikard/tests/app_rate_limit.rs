@@ -0,0 +1,45 @@
+//! Rate limits with a custom key are keyed by the extractor registered on the `App`.
+
+use axum::body::Body;
+use axum::http::StatusCode;
+use spikard::testing::test_client_from_app;
+use spikard::{App, RateLimitConfig, RateLimitKey, RateLimitKeyRequest, RequestContext, get};
+use std::sync::Arc;
+
+async fn ok_handler(_ctx: RequestContext) -> spikard::HandlerResult {
+    Ok(axum::http::Response::builder()
+        .status(StatusCode::OK)
+        .body(Body::from("ok"))
+        .expect("response"))
+}
+
+fn tenant(name: &str) -> Vec<(String, String)> {
+    vec![("x-tenant".to_string(), name.to_string())]
+}
+
+#[tokio::test]
+async fn custom_keys_come_from_the_registered_extractor() {
+    let mut app = App::new();
+    app.rate_limit_key_extractor(Arc::new(|request: &axum::http::Request<Body>| {
+        RateLimitKeyRequest::from_request(request).headers.remove("x-tenant")
+    }));
+    app.route(
+        get("/reports").rate_limit(RateLimitConfig {
+            per_second: 1,
+            burst: 1,
+            ip_based: false,
+            key: Some(RateLimitKey::Custom),
+        }),
+        ok_handler,
+    )
+    .expect("route");
+
+    let client = test_client_from_app(app).expect("test client");
+
+    let first = client.get("/reports", None, Some(tenant("acme"))).await.expect("GET");
+    assert_eq!(first.status, 200);
+    let other = client.get("/reports", None, Some(tenant("globex"))).await.expect("GET");
+    assert_eq!(other.status, 200, "tenants are limited independently");
+    let second = client.get("/reports", None, Some(tenant("acme"))).await.expect("GET");
+    assert_eq!(second.status, 429);
+}
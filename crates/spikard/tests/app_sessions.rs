@@ -0,0 +1,47 @@
+//! Sessions configured on an `App` are available through `RequestContext`.
+
+use axum::body::Body;
+use axum::http::StatusCode;
+use spikard::{App, Method, RouteBuilder, ServerConfig, SessionConfig, testing::test_client_from_app};
+
+#[tokio::test]
+async fn request_context_exposes_the_session() {
+    let mut app = App::new().config(ServerConfig {
+        session: Some(SessionConfig {
+            secret: "an-example-session-secret-of-32-bytes!".to_string(),
+            secure: false,
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+    app.route(
+        RouteBuilder::new(Method::Get, "/counter"),
+        |ctx: spikard::RequestContext| async move {
+            let session = ctx.session().expect("session configured");
+            let count = session.get::<u64>("count").unwrap_or(0) + 1;
+            session.insert("count", count).expect("serializable count");
+            let response = axum::http::Response::builder()
+                .status(StatusCode::OK)
+                .body(Body::from(count.to_string()))
+                .expect("response");
+            Ok(response)
+        },
+    )
+    .expect("route registration");
+
+    let client = test_client_from_app(app).expect("test client");
+
+    let first = client.get("/counter", None, None).await.expect("GET /counter");
+    assert_eq!(first.text().expect("utf-8"), "1");
+    let cookie = first.headers["set-cookie"]
+        .split(';')
+        .next()
+        .expect("cookie pair")
+        .to_string();
+
+    let second = client
+        .get("/counter", None, Some(vec![("cookie".to_string(), cookie)]))
+        .await
+        .expect("GET /counter");
+    assert_eq!(second.text().expect("utf-8"), "2");
+}
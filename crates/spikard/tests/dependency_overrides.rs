@@ -0,0 +1,131 @@
+//! Dependency overrides through `TestClient` and `TestServer`.
+#![cfg(feature = "di")]
+
+use axum::body::Body;
+use axum::http::StatusCode;
+use spikard::testing::{TestServer, test_client_from_app};
+use spikard::{App, Method, RequestContext, RouteBuilder, ServerConfig};
+use spikard_core::di::ValueDependency;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+fn app(connections: &Arc<AtomicUsize>) -> App {
+    let connections = Arc::clone(connections);
+    let config = ServerConfig::builder()
+        .provide_factory("db_pool", move |_resolved| {
+            let connection = connections.fetch_add(1, Ordering::SeqCst) + 1;
+            async move { Ok(format!("postgres#{connection}")) }
+        })
+        .build();
+
+    let mut app = App::new().config(config);
+    app.route(
+        RouteBuilder::new(Method::Get, "/pool").handler_dependencies(vec!["db_pool".to_string()]),
+        |ctx: RequestContext| async move {
+            // Bindings override with JSON values rather than the registered Rust type.
+            let resolved = ctx.dependencies().expect("dependencies are resolved");
+            let pool = resolved
+                .get::<String>("db_pool")
+                .map(|pool| pool.as_str().to_string())
+                .or_else(|| {
+                    resolved
+                        .get::<serde_json::Value>("db_pool")
+                        .map(|pool| pool.to_string())
+                })
+                .expect("db_pool is resolved");
+            Ok(axum::http::Response::builder()
+                .status(StatusCode::OK)
+                .body(Body::from(pool))
+                .expect("response"))
+        },
+    )
+    .expect("route registration");
+    app
+}
+
+#[tokio::test]
+async fn test_client_overrides_and_restores_dependencies() {
+    let connections = Arc::new(AtomicUsize::new(0));
+    let client = test_client_from_app(app(&connections)).expect("test client");
+
+    let real = client.get("/pool", None, None).await.expect("GET /pool");
+    assert_eq!(real.text().expect("text"), "postgres#1");
+
+    client
+        .override_dependency("db_pool", Arc::new(ValueDependency::new("db_pool", "fake".to_string())))
+        .expect("override");
+    let fake = client.get("/pool", None, None).await.expect("GET /pool");
+    assert_eq!(fake.text().expect("text"), "fake");
+
+    client.restore_dependency("db_pool").expect("restore");
+    let restored = client.get("/pool", None, None).await.expect("GET /pool");
+    assert_eq!(restored.text().expect("text"), "postgres#2");
+
+    let missing = client.override_dependency("cache", Arc::new(ValueDependency::new("cache", 1u8)));
+    assert!(missing.is_err());
+}
+
+#[tokio::test]
+async fn test_client_value_overrides_last_for_the_guard_scope() {
+    let connections = Arc::new(AtomicUsize::new(0));
+    let client = test_client_from_app(app(&connections)).expect("test client");
+
+    let guard = client
+        .override_dependency_scope("db_pool", serde_json::json!("fake"))
+        .expect("override");
+    assert_eq!(guard.key(), "db_pool");
+    let fake = client.get("/pool", None, None).await.expect("GET /pool");
+    assert_eq!(fake.text().expect("text"), "\"fake\"");
+
+    drop(guard);
+    let restored = client.get("/pool", None, None).await.expect("GET /pool");
+    assert_eq!(restored.text().expect("text"), "postgres#1");
+}
+
+#[tokio::test]
+async fn test_server_overrides_last_for_the_guard_scope() {
+    let connections = Arc::new(AtomicUsize::new(0));
+    let server = TestServer::from_app(app(&connections)).expect("test server");
+    let get = || {
+        server.call(
+            axum::http::Request::builder()
+                .uri("/pool")
+                .body(Body::empty())
+                .expect("request"),
+        )
+    };
+
+    {
+        let _guard = server
+            .override_dependency("db_pool", Arc::new(ValueDependency::new("db_pool", "fake".to_string())))
+            .expect("override");
+        assert_eq!(get().await.expect("GET /pool").text().expect("text"), "fake");
+    }
+
+    assert_eq!(get().await.expect("GET /pool").text().expect("text"), "postgres#1");
+    assert_eq!(connections.load(Ordering::SeqCst), 1);
+}
+
+struct Echo;
+
+impl spikard::WebSocketHandler for Echo {
+    async fn handle_message(&self, message: serde_json::Value) -> Option<serde_json::Value> {
+        Some(message)
+    }
+}
+
+#[test]
+fn websocket_dependencies_are_checked_when_the_router_is_built() {
+    let connections = Arc::new(AtomicUsize::new(0));
+    let mut app = app(&connections);
+    app.websocket_with_dependencies("chat", Echo, vec!["cache".to_string()])
+        .expect("websocket registration");
+
+    let report = app.dependency_report();
+    assert_eq!(report.unknown.len(), 1);
+    assert_eq!(report.unknown[0].key, "cache");
+    assert_eq!(report.unknown[0].required_by.to_string(), "route WEBSOCKET /chat");
+
+    let error = app.into_router().expect_err("unknown dependency must fail the build");
+    assert!(error.to_string().contains("unknown dependency 'cache'"), "{error}");
+}
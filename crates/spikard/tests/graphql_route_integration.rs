@@ -5,7 +5,7 @@
 //! `fixtures/graphql_schema.json`.
 
 use spikard::testing::test_client_from_app;
-use spikard::{App, Method, SchemaConfig};
+use spikard::{App, CostConfig, Method, SchemaConfig};
 
 async fn post_graphql(app: App, query: &str) -> serde_json::Value {
     let client = test_client_from_app(app).expect("test client from app");
@@ -138,6 +138,44 @@ async fn test_complexity_limit_exceeded_rejects_expensive_query() {
     );
 }
 
+#[tokio::test]
+async fn test_cost_limit_exceeded_rejects_expensive_query() {
+    let mut app = App::new();
+    let config = SchemaConfig {
+        cost: Some(CostConfig {
+            max_cost: Some(2),
+            ..CostConfig::default()
+        }),
+        ..SchemaConfig::default()
+    };
+    app.register_graphql_route("/graphql", Method::Post, "query_only", &config)
+        .expect("register graphql route");
+    let client = test_client_from_app(app).expect("test client from app");
+
+    let cheap = client
+        .graphql("{ user { id } }", None, None)
+        .await
+        .expect("POST /graphql");
+    assert_eq!(cheap.json().expect("parse json body")["data"]["user"]["id"], "1");
+
+    let expensive = client
+        .graphql("{ user { id name email } }", None, None)
+        .await
+        .expect("POST /graphql");
+    assert_eq!(expensive.status, 400);
+    assert_eq!(
+        expensive.json().expect("parse json body"),
+        serde_json::json!({
+            "type": "https://spikard.dev/errors/graphql-cost-limit-exceeded",
+            "title": "GraphQL cost limit exceeded",
+            "status": 400,
+            "detail": "Operation cost 4 exceeds the maximum of 2",
+            "cost": 4,
+            "maximum": 2
+        })
+    );
+}
+
 #[tokio::test]
 async fn test_unknown_schema_type_is_rejected() {
     let mut app = App::new();
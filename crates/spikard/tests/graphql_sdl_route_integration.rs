@@ -8,7 +8,9 @@
 
 use serde_json::json;
 use spikard::testing::test_client_from_app;
-use spikard::{App, DynamicSchemaConfig, FieldErrorSpec, Method};
+use spikard::{App, DynamicSchemaConfig, FieldErrorSpec, FieldLoaderSpec, Method};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 async fn post_graphql(
     sdl: &str,
@@ -354,3 +356,55 @@ async fn test_invalid_sdl_is_rejected_at_registration() {
 
     assert!(result.is_err());
 }
+
+/// Fields listed in `field_loaders` batch through the loaders registered with `App::data_loader`.
+#[tokio::test]
+async fn test_field_loaders_batch_through_app_data_loaders() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let seen = Arc::clone(&calls);
+    let mut app = App::new();
+    app.data_loader(
+        "user",
+        Arc::new(move |keys: Vec<serde_json::Value>| {
+            seen.fetch_add(1, Ordering::SeqCst);
+            async move {
+                Ok(keys
+                    .iter()
+                    .map(|id| Some(json!({"id": id, "name": format!("user {}", id.as_str().unwrap_or_default())})))
+                    .collect())
+            }
+        }),
+    );
+    let config = DynamicSchemaConfig {
+        field_loaders: vec![FieldLoaderSpec {
+            type_name: "Post".to_string(),
+            field: "author".to_string(),
+            loader: "user".to_string(),
+            key_field: "author_id".to_string(),
+        }],
+        ..enabled_config()
+    };
+    app.register_graphql_sdl_route(
+        "/graphql",
+        Method::Post,
+        "type Query { posts: [Post!]! } type Post { id: ID! author: User } type User { id: ID! name: String! }",
+        json!({"posts": [{"id": "p1", "author_id": "u1"}, {"id": "p2", "author_id": "u2"}]}),
+        &config,
+    )
+    .expect("register dynamic graphql route");
+
+    let client = test_client_from_app(app).expect("test client from app");
+    let snapshot = client
+        .graphql("{ posts { id author { name } } }", None, None)
+        .await
+        .expect("POST /graphql");
+
+    assert_eq!(
+        snapshot.json().expect("parse json body"),
+        json!({"data": {"posts": [
+            {"id": "p1", "author": {"name": "user u1"}},
+            {"id": "p2", "author": {"name": "user u2"}}
+        ]}})
+    );
+    assert_eq!(calls.load(Ordering::SeqCst), 1, "both authors load in one batch");
+}
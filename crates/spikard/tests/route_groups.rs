@@ -0,0 +1,194 @@
+//! Route groups fold their prefix and policies into the routes they register.
+
+use axum::body::Body;
+use axum::http::{HeaderValue, StatusCode};
+use spikard::testing::test_client_from_app;
+use spikard::{
+    ApiKeyConfig, App, HookResult, LifecycleHook, OpenApiConfig, RequestContext, RouteAuthConfig, RouteGroupScope,
+    ServerConfig, get, post, response_hook,
+};
+use std::sync::Arc;
+
+type Hook = Arc<dyn LifecycleHook<axum::http::Request<Body>, axum::http::Response<Body>>>;
+
+/// Appends `name` to the `x-trace` response header
+fn trace(name: &'static str) -> Hook {
+    response_hook(name, move |mut response: axum::http::Response<Body>| async move {
+        let trace = response
+            .headers()
+            .get("x-trace")
+            .and_then(|value| value.to_str().ok())
+            .map_or_else(|| name.to_string(), |existing| format!("{existing},{name}"));
+        response
+            .headers_mut()
+            .insert("x-trace", HeaderValue::from_str(&trace).expect("header value"));
+        Ok(HookResult::Continue(response))
+    })
+}
+
+async fn path_handler(ctx: RequestContext) -> spikard::HandlerResult {
+    Ok(axum::http::Response::builder()
+        .status(StatusCode::OK)
+        .body(Body::from(ctx.path_str().to_string()))
+        .expect("response"))
+}
+
+fn api_key(key: &str) -> RouteAuthConfig {
+    RouteAuthConfig {
+        jwt: None,
+        api_key: Some(ApiKeyConfig {
+            keys: vec![key.to_string()],
+            header_name: "x-api-key".to_string(),
+        }),
+    }
+}
+
+fn app() -> App {
+    let mut app = App::new().config(ServerConfig {
+        openapi: Some(OpenApiConfig {
+            enabled: true,
+            ..OpenApiConfig::default()
+        }),
+        ..ServerConfig::default()
+    });
+    app.on_response(trace("global"));
+    app.route(get("/public"), path_handler).expect("public route");
+
+    let mut admin = app.group("/admin");
+    admin
+        .tag("admin")
+        .auth(api_key("admin-key"))
+        .on_response(trace("admin"));
+    admin.route(get("/users"), path_handler).expect("admin route");
+    admin
+        .route(post("/login").auth(api_key("login-key")), path_handler)
+        .expect("login route");
+
+    let mut reports = admin.group("reports/");
+    reports.tag("reports").on_response(trace("reports"));
+    reports
+        .route(get("/daily").tag("daily"), path_handler)
+        .expect("nested route");
+    app
+}
+
+#[tokio::test]
+async fn group_prefixes_apply_to_nested_groups() {
+    let client = test_client_from_app(app()).expect("test client");
+    let key = Some(vec![("x-api-key".to_string(), "admin-key".to_string())]);
+
+    let users = client.get("/admin/users", None, key.clone()).await.expect("GET");
+    assert_eq!(users.status, 200);
+    assert_eq!(users.text().expect("text"), "/admin/users");
+
+    let daily = client.get("/admin/reports/daily", None, key).await.expect("GET");
+    assert_eq!(daily.status, 200);
+    assert_eq!(daily.text().expect("text"), "/admin/reports/daily");
+}
+
+#[tokio::test]
+async fn group_auth_applies_unless_the_route_sets_its_own() {
+    let client = test_client_from_app(app()).expect("test client");
+
+    let public = client.get("/public", None, None).await.expect("GET");
+    assert_eq!(public.status, 200);
+
+    let anonymous = client.get("/admin/users", None, None).await.expect("GET");
+    assert_eq!(anonymous.status, 401);
+    let nested = client.get("/admin/reports/daily", None, None).await.expect("GET");
+    assert_eq!(nested.status, 401);
+
+    let admin_key = Some(vec![("x-api-key".to_string(), "admin-key".to_string())]);
+    let login_key = Some(vec![("x-api-key".to_string(), "login-key".to_string())]);
+    let login = client.post("/admin/login", None, None, None, None, admin_key).await;
+    assert_eq!(login.expect("POST").status, 401);
+    let login = client.post("/admin/login", None, None, None, None, login_key).await;
+    assert_eq!(login.expect("POST").status, 200);
+}
+
+#[tokio::test]
+async fn group_hooks_run_after_global_hooks_outermost_group_first() {
+    let client = test_client_from_app(app()).expect("test client");
+    let key = Some(vec![("x-api-key".to_string(), "admin-key".to_string())]);
+
+    let public = client.get("/public", None, None).await.expect("GET");
+    assert_eq!(public.headers["x-trace"], "global");
+
+    let users = client.get("/admin/users", None, key.clone()).await.expect("GET");
+    assert_eq!(users.headers["x-trace"], "global,admin");
+
+    let daily = client.get("/admin/reports/daily", None, key).await.expect("GET");
+    assert_eq!(daily.headers["x-trace"], "global,admin,reports");
+}
+
+#[tokio::test]
+async fn detached_group_scopes_fold_into_routes_registered_on_the_app() {
+    let mut admin = RouteGroupScope::new("/admin");
+    admin.auth(api_key("admin-key")).on_response(trace("admin"));
+    let reports = admin.nested("reports");
+    assert_eq!(reports.prefix(), "/admin/reports");
+    assert_eq!(reports.path("/daily"), "/admin/reports/daily");
+
+    let mut app = App::new();
+    app.route(reports.apply(get("/daily")), path_handler)
+        .expect("scoped route");
+    let client = test_client_from_app(app).expect("test client");
+
+    let anonymous = client.get("/admin/reports/daily", None, None).await.expect("GET");
+    assert_eq!(anonymous.status, 401);
+    let key = Some(vec![("x-api-key".to_string(), "admin-key".to_string())]);
+    let daily = client.get("/admin/reports/daily", None, key).await.expect("GET");
+    assert_eq!(daily.text().expect("text"), "/admin/reports/daily");
+    assert_eq!(daily.headers["x-trace"], "admin");
+}
+
+#[tokio::test]
+async fn group_tags_and_auth_appear_in_openapi() {
+    let client = test_client_from_app(app()).expect("test client");
+    let spec = client
+        .get("/openapi.json", None, None)
+        .await
+        .expect("GET")
+        .json()
+        .expect("json");
+
+    let daily = &spec["paths"]["/admin/reports/daily"]["get"];
+    assert_eq!(daily["tags"], serde_json::json!(["admin", "reports", "daily"]));
+    assert_eq!(daily["security"], serde_json::json!([{"apiKeyAuth": []}]));
+    assert!(spec["paths"]["/public"]["get"].get("security").is_none());
+    assert_eq!(spec["components"]["securitySchemes"]["apiKeyAuth"]["name"], "x-api-key");
+}
+
+#[cfg(feature = "di")]
+mod dependencies {
+    use super::*;
+
+    #[tokio::test]
+    async fn group_dependencies_combine_with_route_dependencies() {
+        let config = ServerConfig::builder()
+            .provide_value("tenant", "acme".to_string())
+            .provide_value("audit", "on".to_string())
+            .build();
+        let mut app = App::new().config(config);
+        let mut tenants = app.group("/tenants");
+        tenants.handler_dependencies(vec!["tenant".to_string()]);
+        tenants
+            .route(
+                get("/me").handler_dependencies(vec!["audit".to_string(), "tenant".to_string()]),
+                |ctx: RequestContext| async move {
+                    let resolved = ctx.dependencies().expect("dependencies");
+                    let tenant: Arc<String> = resolved.get("tenant").expect("tenant");
+                    let audit: Arc<String> = resolved.get("audit").expect("audit");
+                    Ok(axum::http::Response::builder()
+                        .status(StatusCode::OK)
+                        .body(Body::from(format!("{tenant}/{audit}")))
+                        .expect("response"))
+                },
+            )
+            .expect("route");
+
+        let client = test_client_from_app(app).expect("test client");
+        let me = client.get("/tenants/me", None, None).await.expect("GET");
+        assert_eq!(me.text().expect("text"), "acme/on");
+    }
+}
@@ -0,0 +1,128 @@
+//! Lifecycle hooks attached to a single route run after the global and group hooks.
+
+use axum::body::Body;
+use axum::http::{HeaderValue, StatusCode};
+use spikard::testing::test_client_from_app;
+use spikard::{App, HookResult, LifecycleHook, RequestContext, get, post, request_hook, response_hook};
+use std::sync::{Arc, Mutex};
+
+type Hook = Arc<dyn LifecycleHook<axum::http::Request<Body>, axum::http::Response<Body>>>;
+
+/// Names of the request hooks in the order they ran
+type Log = Arc<Mutex<Vec<&'static str>>>;
+
+/// Appends `value` to the comma-separated `x-trace` header of `headers`
+fn append_trace(headers: &mut axum::http::HeaderMap, value: &str) {
+    let trace = headers
+        .get("x-trace")
+        .and_then(|existing| existing.to_str().ok())
+        .map_or_else(|| value.to_string(), |existing| format!("{existing},{value}"));
+    headers.insert("x-trace", HeaderValue::from_str(&trace).expect("header value"));
+}
+
+fn request_trace(log: &Log, name: &'static str) -> Hook {
+    let log = Arc::clone(log);
+    request_hook(name, move |request: axum::http::Request<Body>| {
+        log.lock().expect("log").push(name);
+        async move { Ok(HookResult::Continue(request)) }
+    })
+}
+
+fn take(log: &Log) -> Vec<&'static str> {
+    std::mem::take(&mut *log.lock().expect("log"))
+}
+
+fn response_trace(name: &'static str) -> Hook {
+    response_hook(name, move |mut response: axum::http::Response<Body>| async move {
+        append_trace(response.headers_mut(), name);
+        Ok(HookResult::Continue(response))
+    })
+}
+
+async fn ok(_ctx: RequestContext) -> spikard::HandlerResult {
+    Ok(axum::http::Response::builder()
+        .status(StatusCode::OK)
+        .body(Body::empty())
+        .expect("response"))
+}
+
+fn app(log: &Log) -> App {
+    let mut app = App::new();
+    app.on_request(request_trace(log, "global"))
+        .pre_handler(request_trace(log, "global-pre"))
+        .on_response(response_trace("global"));
+
+    app.route(get("/plain"), ok).expect("plain route");
+    app.route(
+        get("/audited")
+            .on_request(request_trace(log, "route"))
+            .pre_handler(request_trace(log, "route-pre"))
+            .on_response(response_trace("route")),
+        ok,
+    )
+    .expect("audited route");
+    app.route(
+        post("/locked").pre_handler(request_hook("deny", |_request| async {
+            Ok(HookResult::ShortCircuit(
+                axum::http::Response::builder()
+                    .status(StatusCode::FORBIDDEN)
+                    .body(Body::empty())
+                    .expect("response"),
+            ))
+        })),
+        ok,
+    )
+    .expect("locked route");
+
+    let mut tenants = app.group("/t");
+    tenants
+        .on_request(request_trace(log, "group"))
+        .on_response(response_trace("group"));
+    tenants
+        .route(
+            get("/{tenant}/orders")
+                .on_request(request_trace(log, "route"))
+                .on_response(response_trace("route")),
+            ok,
+        )
+        .expect("tenant route");
+    app
+}
+
+#[tokio::test]
+async fn route_hooks_only_run_for_their_route() {
+    let log = Log::default();
+    let client = test_client_from_app(app(&log)).expect("test client");
+
+    let plain = client.get("/plain", None, None).await.expect("GET");
+    assert_eq!(take(&log), ["global", "global-pre"]);
+    assert_eq!(plain.headers["x-trace"], "global");
+
+    let audited = client.get("/audited", None, None).await.expect("GET");
+    assert_eq!(take(&log), ["global", "route", "global-pre", "route-pre"]);
+    assert_eq!(audited.headers["x-trace"], "global,route");
+}
+
+#[tokio::test]
+async fn route_hooks_can_short_circuit_their_route() {
+    let log = Log::default();
+    let client = test_client_from_app(app(&log)).expect("test client");
+
+    let locked = client
+        .post("/locked", None, None, None, None, None)
+        .await
+        .expect("POST");
+    assert_eq!(locked.status, 403);
+    let plain = client.get("/plain", None, None).await.expect("GET");
+    assert_eq!(plain.status, 200);
+}
+
+#[tokio::test]
+async fn route_hooks_run_after_group_hooks() {
+    let log = Log::default();
+    let client = test_client_from_app(app(&log)).expect("test client");
+
+    let orders = client.get("/t/acme/orders", None, None).await.expect("GET");
+    assert_eq!(take(&log), ["global", "group", "route", "global-pre"]);
+    assert_eq!(orders.headers["x-trace"], "global,group,route");
+}
@@ -63,3 +63,36 @@ async fn test_client_from_app_post_echo() {
     let json = snapshot.json().expect("parse json");
     assert_eq!(json["key"], "value");
 }
+
+#[tokio::test]
+async fn contract_test_app_checks_every_route() {
+    let mut app = App::new();
+    app.route(
+        RouteBuilder::new(Method::Post, "/notes")
+            .request_schema_json(serde_json::json!({
+                "type": "object",
+                "properties": {"text": {"type": "string", "maxLength": 8}},
+                "required": ["text"]
+            }))
+            .response_schema_json(serde_json::json!({
+                "type": "object",
+                "properties": {"text": {"type": "string"}},
+                "required": ["text"]
+            })),
+        |ctx: spikard::RequestContext| async move {
+            let response = axum::http::Response::builder()
+                .status(StatusCode::CREATED)
+                .header("content-type", "application/json")
+                .body(Body::from(ctx.body_value().to_string()))
+                .unwrap();
+            Ok(response)
+        },
+    )
+    .expect("route registration");
+
+    let report = spikard::testing::contract_test_app(app).await.expect("contract run");
+
+    assert!(report.is_success(), "{:#?}", report.failures);
+    assert_eq!(report.routes, 1);
+    assert!(report.cases >= 4);
+}
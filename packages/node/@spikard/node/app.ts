@@ -12,6 +12,8 @@
  * `import { App } from 'spikard/service'` for advanced use cases.
  */
 
+import type { Claims } from "./index";
+import { RouteGroup as NativeRouteGroup } from "./index";
 import * as service from "./service.js";
 import type { z } from "zod";
 import { zodToJsonSchema } from "zod-to-json-schema";
@@ -37,6 +39,18 @@ export interface TypedRequest<T = unknown> {
   path: string;
   /** Content-Type header value, if present */
   contentType?: string;
+  /** Client IP address, resolved through trusted proxies when configured */
+  clientIp?: string;
+  /** Scheme the client used ("http" or "https") */
+  scheme?: string;
+  /** Host the client addressed, including any port */
+  host?: string;
+  /** Session data, when sessions are configured */
+  session?: Record<string, unknown>;
+  /** CSRF token the client must echo on unsafe requests, when CSRF protection is configured */
+  csrfToken?: string;
+  /** Validated JWT claims, when JWT authentication is configured */
+  claims?: Claims;
 }
 
 /**
@@ -50,6 +64,10 @@ export interface TypedResponse<T = unknown> {
   content?: T;
   /** Response headers */
   headers?: Record<string, string>;
+  /** Replacement session data; `null` destroys the session */
+  session?: Record<string, unknown> | null;
+  /** Issue a new session id, keeping the data (e.g. after login) */
+  regenerateSession?: boolean;
 }
 
 /**
@@ -59,6 +77,53 @@ export type Handler<ReqBody = unknown, ResBody = unknown> = (
   req: TypedRequest<ReqBody>,
 ) => Promise<TypedResponse<ResBody>>;
 
+/**
+ * A lifecycle hook callback, as accepted by `App.onRequest` and friends.
+ */
+export type LifecycleHookFn = (...args: any[]) => Promise<void>;
+
+/**
+ * A lifespan hook callback, as accepted by `App.onStartup` and `App.onShutdown`.
+ */
+export type LifespanHookFn = () => Promise<void> | void;
+
+/**
+ * A readiness check callback, as accepted by `App.healthCheck`.
+ */
+export type HealthCheckFn = () => Promise<void> | void;
+
+/**
+ * The request a rate-limit key extractor sees, as accepted by `App.rateLimitKeyExtractor`.
+ */
+export interface RateLimitKeyRequest {
+  method: string;
+  path: string;
+  query?: string;
+  headers: Record<string, string>;
+  clientIp?: string;
+}
+
+/**
+ * A rate-limit key extractor, as accepted by `App.rateLimitKeyExtractor`.
+ */
+export type RateLimitKeyExtractorFn = (request: RateLimitKeyRequest) => string | null | undefined;
+
+/**
+ * A batch loader, as accepted by `App.dataLoader`.
+ */
+export type DataLoaderFn = (keys: any[]) => Promise<any[]> | any[];
+
+/**
+ * Lifecycle hooks that run only for one route, after the global hooks of the same phase.
+ */
+export interface RouteHooks {
+  onRequest?: LifecycleHookFn[];
+  preValidation?: LifecycleHookFn[];
+  preHandler?: LifecycleHookFn[];
+  onResponse?: LifecycleHookFn[];
+  onError?: LifecycleHookFn[];
+}
+
 /**
  * Route configuration with optional schema validation.
  * Schemas are converted to JSON Schema and attached to the Rust core for validation.
@@ -70,6 +135,66 @@ export interface RouteConfig<ReqBody = unknown> {
   query?: z.ZodType<unknown>;
   /** Optional path parameters schema (for future use) */
   params?: z.ZodType<unknown>;
+  /** Lifecycle hooks scoped to this route */
+  hooks?: RouteHooks;
+}
+
+/**
+ * Settings shared by the routes of a group, as accepted by `App.group`.
+ */
+export interface GroupConfig {
+  /** OpenAPI tags added to every route in the group */
+  tags?: string[];
+  /** Dependency keys resolved for every route in the group */
+  dependencies?: string[];
+  /** Lifecycle hooks run after the global hooks and before the hooks of each route */
+  hooks?: RouteHooks;
+}
+
+/**
+ * Lifecycle phases a route can attach its own hooks to, in execution order.
+ */
+const HOOK_PHASES = ["onRequest", "preValidation", "preHandler", "onResponse", "onError"] as const;
+
+/**
+ * Combine hooks per phase, those of the enclosing group first.
+ */
+function mergeHooks(outer: RouteHooks | undefined, inner: RouteHooks | undefined): RouteHooks {
+  const merged: RouteHooks = {};
+  for (const phase of HOOK_PHASES) {
+    const hooks = [...(outer?.[phase] ?? []), ...(inner?.[phase] ?? [])];
+    if (hooks.length > 0) {
+      merged[phase] = hooks;
+    }
+  }
+  return merged;
+}
+
+/**
+ * Registers a route of a group on the owning `App`.
+ */
+type RegisterGroupRoute = (
+  method: string,
+  path: string,
+  config: RouteConfig<any>,
+  handler: Handler<any, any>,
+  group: NativeRouteGroup,
+) => void;
+
+/**
+ * Session data handed over by the Rust session middleware in an internal header.
+ */
+function sessionFrom(headers: Record<string, string> | undefined): Record<string, unknown> | undefined {
+  const raw = headers?.["x-spikard-session"];
+  return raw === undefined ? undefined : JSON.parse(raw);
+}
+
+/**
+ * Validated JWT claims handed over by the Rust auth middleware in an internal header.
+ */
+function claimsFrom(headers: Record<string, string> | undefined): Claims | undefined {
+  const raw = headers?.["x-spikard-jwt-claims"];
+  return raw === undefined ? undefined : JSON.parse(raw);
 }
 
 /**
@@ -164,6 +289,19 @@ export class App {
     this.registerRoute("OPTIONS", path, config, handler);
   }
 
+  /**
+   * Start a group of routes sharing `prefix` and the tags, dependencies and hooks in `config`.
+   *
+   * Group hooks run after the global hooks and before the hooks of each route.
+   */
+  group(prefix: string, config: GroupConfig = {}): RouteGroup {
+    return new RouteGroup(
+      (method, path, routeConfig, handler, group) => this.registerRoute(method, path, routeConfig, handler, group),
+      new NativeRouteGroup(prefix),
+      config,
+    );
+  }
+
   /**
    * Register a generic route with the given method.
    *
@@ -176,6 +314,7 @@ export class App {
     path: string,
     config: RouteConfig<ReqBody>,
     handler: Handler<ReqBody, ResBody>,
+    group?: NativeRouteGroup,
   ): void {
     // Get the method enum value
     const methodEnum = this.getMethodEnum(method);
@@ -183,8 +322,11 @@ export class App {
       throw new Error(`Unknown HTTP method: ${method}`);
     }
 
-    // Create the route builder
+    // Create the route builder, prefixed and configured by its group if any
     let builder = new service.RouteBuilder(methodEnum, path);
+    if (group) {
+      builder = group.apply(builder);
+    }
 
     // Convert zod schema to JSON Schema and attach to builder
     // The Rust core will validate the request body against this schema
@@ -194,6 +336,13 @@ export class App {
       builder = builder.requestSchemaJson(jsonSchema);
     }
 
+    // Route-scoped lifecycle hooks run after the global hooks of the same phase
+    for (const phase of HOOK_PHASES) {
+      for (const hook of config.hooks?.[phase] ?? []) {
+        builder = builder[phase](hook);
+      }
+    }
+
     // Create the low-level handler that bridges the typed interface to the raw contract
     // At this point, the request body is already validated by the Rust core
     const bridgeHandler = async (requestData: Record<string, any>): Promise<Record<string, any>> => {
@@ -221,6 +370,12 @@ export class App {
           method: requestData.method || "GET",
           path: requestData.path || "/",
           contentType: requestData.content_type,
+          clientIp: requestData.client_ip,
+          scheme: requestData.scheme,
+          host: requestData.host,
+          session: sessionFrom(requestData.headers),
+          csrfToken: requestData.headers?.["x-spikard-csrf-token"],
+          claims: claimsFrom(requestData.headers),
         };
 
         // Call the user's handler
@@ -228,10 +383,17 @@ export class App {
 
         // Convert the TypedResponse to the low-level contract
         // Note: field name is 'content', not 'body'
+        const headers = { ...(response.headers ?? {}) };
+        if (response.session !== undefined) {
+          headers["x-spikard-session"] = JSON.stringify(response.session);
+        }
+        if (response.regenerateSession) {
+          headers["x-spikard-session-regenerate"] = "1";
+        }
         return {
           status_code: response.statusCode ?? 200,
           content: response.content,
-          headers: response.headers ?? {},
+          headers,
         };
       } catch (error: any) {
         // Unexpected error: return 500 Internal Server Error
@@ -269,6 +431,69 @@ export class App {
     return methodMap[method.toUpperCase()];
   }
 
+  /**
+   * Register a hook run once before the listener binds.
+   *
+   * Hooks run in registration order; one that throws or rejects aborts boot, so `run()`
+   * rejects instead of serving.
+   */
+  onStartup(hook: LifespanHookFn): this {
+    this.serviceApp.onStartup(hook);
+    return this;
+  }
+
+  /**
+   * Register a hook run once after the server stops.
+   *
+   * Hooks run in reverse registration order, releasing resources in the opposite order
+   * startup hooks acquired them.
+   */
+  onShutdown(hook: LifespanHookFn): this {
+    this.serviceApp.onShutdown(hook);
+    return this;
+  }
+
+  /**
+   * Register a named readiness check run on every readiness probe.
+   *
+   * A check that throws or rejects reports the application as not ready, keyed by
+   * `name` in the readiness report. Registering a check enables the health endpoints.
+   */
+  healthCheck(name: string, check: HealthCheckFn): this {
+    this.serviceApp.healthCheck(name, check);
+    return this;
+  }
+
+  /**
+   * Register the function that keys rate limits configured with a custom key.
+   *
+   * It is called synchronously for every limited request and returns the bucket key;
+   * returning `null` or `undefined`, or throwing, falls back to the client IP.
+   */
+  rateLimitKeyExtractor(extractor: RateLimitKeyExtractorFn): this {
+    this.serviceApp.rateLimitKeyExtractor((request: Record<string, any>) =>
+      extractor({
+        method: request.method,
+        path: request.path,
+        query: request.query ?? undefined,
+        headers: request.headers ?? {},
+        clientIp: request.client_ip ?? undefined,
+      }),
+    );
+    return this;
+  }
+
+  /**
+   * Register the batch loader named `name` for GraphQL field loaders.
+   *
+   * It receives an array of keys and returns (or resolves to) one value per key, in
+   * order, with `null` for a missing key; throwing or rejecting fails every key in the batch.
+   */
+  dataLoader(name: string, loader: DataLoaderFn): this {
+    this.serviceApp.dataLoader(name, loader);
+    return this;
+  }
+
   /**
    * Configure the server (host, port, etc.).
    */
@@ -297,3 +522,136 @@ export class App {
     return this.serviceApp;
   }
 }
+
+/**
+ * Routes registered under a shared path prefix, created with `App.group`.
+ *
+ * Tags and dependencies accumulate and hooks run outermost group first; nested groups
+ * inherit everything configured on their parent.
+ */
+export class RouteGroup {
+  private readonly nativeGroup: NativeRouteGroup;
+  private readonly hooks: RouteHooks;
+
+  constructor(
+    private readonly register: RegisterGroupRoute,
+    nativeGroup: NativeRouteGroup,
+    config: GroupConfig = {},
+  ) {
+    let group = nativeGroup;
+    for (const tag of config.tags ?? []) {
+      group = group.tag(tag);
+    }
+    if (config.dependencies?.length) {
+      group = group.handlerDependencies(config.dependencies);
+    }
+    this.nativeGroup = group;
+    this.hooks = config.hooks ?? {};
+  }
+
+  /**
+   * The path prefix shared by the routes of this group.
+   */
+  get prefix(): string {
+    return this.nativeGroup.prefix();
+  }
+
+  /**
+   * Start a group nested under this one.
+   */
+  group(prefix: string, config: GroupConfig = {}): RouteGroup {
+    return new RouteGroup(this.register, this.nativeGroup.group(prefix), {
+      ...config,
+      hooks: mergeHooks(this.hooks, config.hooks),
+    });
+  }
+
+  /**
+   * Register a GET route under the group.
+   */
+  get<ReqBody = unknown, ResBody = unknown>(
+    path: string,
+    config: RouteConfig<ReqBody> = {},
+    handler: Handler<ReqBody, ResBody>,
+  ): void {
+    this.registerRoute("GET", path, config, handler);
+  }
+
+  /**
+   * Register a POST route under the group.
+   */
+  post<ReqBody = unknown, ResBody = unknown>(
+    path: string,
+    config: RouteConfig<ReqBody> = {},
+    handler: Handler<ReqBody, ResBody>,
+  ): void {
+    this.registerRoute("POST", path, config, handler);
+  }
+
+  /**
+   * Register a PUT route under the group.
+   */
+  put<ReqBody = unknown, ResBody = unknown>(
+    path: string,
+    config: RouteConfig<ReqBody> = {},
+    handler: Handler<ReqBody, ResBody>,
+  ): void {
+    this.registerRoute("PUT", path, config, handler);
+  }
+
+  /**
+   * Register a PATCH route under the group.
+   */
+  patch<ReqBody = unknown, ResBody = unknown>(
+    path: string,
+    config: RouteConfig<ReqBody> = {},
+    handler: Handler<ReqBody, ResBody>,
+  ): void {
+    this.registerRoute("PATCH", path, config, handler);
+  }
+
+  /**
+   * Register a DELETE route under the group.
+   */
+  delete<ReqBody = unknown, ResBody = unknown>(
+    path: string,
+    config: RouteConfig<ReqBody> = {},
+    handler: Handler<ReqBody, ResBody>,
+  ): void {
+    this.registerRoute("DELETE", path, config, handler);
+  }
+
+  /**
+   * Register a HEAD route under the group.
+   */
+  head<ReqBody = unknown, ResBody = unknown>(
+    path: string,
+    config: RouteConfig<ReqBody> = {},
+    handler: Handler<ReqBody, ResBody>,
+  ): void {
+    this.registerRoute("HEAD", path, config, handler);
+  }
+
+  /**
+   * Register an OPTIONS route under the group.
+   */
+  options<ReqBody = unknown, ResBody = unknown>(
+    path: string,
+    config: RouteConfig<ReqBody> = {},
+    handler: Handler<ReqBody, ResBody>,
+  ): void {
+    this.registerRoute("OPTIONS", path, config, handler);
+  }
+
+  /**
+   * Register a route on the owning App with the group's hooks ahead of the route's own.
+   */
+  private registerRoute<ReqBody = unknown, ResBody = unknown>(
+    method: string,
+    path: string,
+    config: RouteConfig<ReqBody>,
+    handler: Handler<ReqBody, ResBody>,
+  ): void {
+    this.register(method, path, { ...config, hooks: mergeHooks(this.hooks, config.hooks) }, handler, this.nativeGroup);
+  }
+}
@@ -2,7 +2,12 @@
 
 declare(strict_types=1);
 
+use Spikard\Php\Claims;
+use Spikard\Php\ClientInfo;
+use Spikard\Php\CsrfToken;
 use Spikard\Php\RouteBuilder;
+use Spikard\Php\RouteGroup as NativeRouteGroup;
+use Spikard\Php\Session;
 
 /**
  * Ergonomic typed-handler application layer for Spikard.
@@ -23,6 +28,9 @@ use Spikard\Php\RouteBuilder;
  */
 final class App
 {
+    /** Lifecycle phases a route can attach its own hooks to, in execution order. */
+    private const HOOK_PHASES = ['onRequest', 'preValidation', 'preHandler', 'onResponse', 'onError'];
+
     /** @var list<array{string, list<RouteBuilder>, callable}> */
     private array $registrations = [];
 
@@ -34,10 +42,12 @@ final class App
 
     /**
      * Register a GET route.
+     *
+     * @param array<string, list<callable>> $hooks  Route-scoped lifecycle hooks keyed by phase.
      */
-    public function get(string $path, callable $handler): self
+    public function get(string $path, callable $handler, array $hooks = []): self
     {
-        return $this->addRoute('Get', $path, null, $handler);
+        return $this->addRoute('Get', $path, null, $handler, $hooks);
     }
 
     /**
@@ -45,54 +55,63 @@ final class App
      *
      * @param class-string|null $dtoClass  PHP 8.2+ readonly class for body hydration.
      *                                     Pass null for routes without a request body.
+     * @param array<string, list<callable>> $hooks  Route-scoped lifecycle hooks keyed by phase.
      */
-    public function post(string $path, ?string $dtoClass, callable $handler): self
+    public function post(string $path, ?string $dtoClass, callable $handler, array $hooks = []): self
     {
-        return $this->addRoute('Post', $path, $dtoClass, $handler);
+        return $this->addRoute('Post', $path, $dtoClass, $handler, $hooks);
     }
 
     /**
      * Register a PUT route with an optional body DTO class.
      *
      * @param class-string|null $dtoClass
+     * @param array<string, list<callable>> $hooks  Route-scoped lifecycle hooks keyed by phase.
      */
-    public function put(string $path, ?string $dtoClass, callable $handler): self
+    public function put(string $path, ?string $dtoClass, callable $handler, array $hooks = []): self
     {
-        return $this->addRoute('Put', $path, $dtoClass, $handler);
+        return $this->addRoute('Put', $path, $dtoClass, $handler, $hooks);
     }
 
     /**
      * Register a PATCH route with an optional body DTO class.
      *
      * @param class-string|null $dtoClass
+     * @param array<string, list<callable>> $hooks  Route-scoped lifecycle hooks keyed by phase.
      */
-    public function patch(string $path, ?string $dtoClass, callable $handler): self
+    public function patch(string $path, ?string $dtoClass, callable $handler, array $hooks = []): self
     {
-        return $this->addRoute('Patch', $path, $dtoClass, $handler);
+        return $this->addRoute('Patch', $path, $dtoClass, $handler, $hooks);
     }
 
     /**
      * Register a DELETE route.
+     *
+     * @param array<string, list<callable>> $hooks  Route-scoped lifecycle hooks keyed by phase.
      */
-    public function delete(string $path, callable $handler): self
+    public function delete(string $path, callable $handler, array $hooks = []): self
     {
-        return $this->addRoute('Delete', $path, null, $handler);
+        return $this->addRoute('Delete', $path, null, $handler, $hooks);
     }
 
     /**
      * Register a HEAD route.
+     *
+     * @param array<string, list<callable>> $hooks  Route-scoped lifecycle hooks keyed by phase.
      */
-    public function head(string $path, callable $handler): self
+    public function head(string $path, callable $handler, array $hooks = []): self
     {
-        return $this->addRoute('Head', $path, null, $handler);
+        return $this->addRoute('Head', $path, null, $handler, $hooks);
     }
 
     /**
      * Register an OPTIONS route.
+     *
+     * @param array<string, list<callable>> $hooks  Route-scoped lifecycle hooks keyed by phase.
      */
-    public function options(string $path, callable $handler): self
+    public function options(string $path, callable $handler, array $hooks = []): self
     {
-        return $this->addRoute('Options', $path, null, $handler);
+        return $this->addRoute('Options', $path, null, $handler, $hooks);
     }
 
     /**
@@ -114,6 +133,87 @@ final class App
         return $this;
     }
 
+    /**
+     * Start a group of routes sharing $prefix and the given tags, dependencies and hooks.
+     *
+     * Group hooks run after the global hooks and before the hooks of each route.
+     *
+     * @param list<string> $tags  OpenAPI tags added to every route in the group.
+     * @param list<string> $dependencies  Dependency keys resolved for every route in the group.
+     * @param array<string, list<callable>> $hooks  Group lifecycle hooks keyed by phase.
+     */
+    public function group(string $prefix, array $tags = [], array $dependencies = [], array $hooks = []): RouteGroup
+    {
+        return new RouteGroup(
+            $this->addRoute(...),
+            new NativeRouteGroup($prefix),
+            $tags,
+            $dependencies,
+            $hooks,
+        );
+    }
+
+    /**
+     * Register a hook run once before the listener binds.
+     *
+     * Hooks run in registration order; one that throws aborts boot, so run() throws
+     * instead of serving.
+     */
+    public function onStartup(callable $hook): self
+    {
+        $this->registrations[] = ['on_startup', ['on_startup'], $hook];
+        return $this;
+    }
+
+    /**
+     * Register a hook run once after the server stops.
+     *
+     * Hooks run in reverse registration order, releasing resources in the opposite
+     * order startup hooks acquired them.
+     */
+    public function onShutdown(callable $hook): self
+    {
+        $this->registrations[] = ['on_shutdown', ['on_shutdown'], $hook];
+        return $this;
+    }
+
+    /**
+     * Register a named readiness check run on every readiness probe.
+     *
+     * A check that throws reports the application as not ready, keyed by $name in the
+     * readiness report. Registering a check enables the health endpoints.
+     */
+    public function healthCheck(string $name, callable $check): self
+    {
+        $this->registrations[] = ['health_check', [$name], $check];
+        return $this;
+    }
+
+    /**
+     * Register the callable that keys rate limits configured with a custom key.
+     *
+     * It is called for every limited request with an array of method, path, query,
+     * headers and client_ip, and returns the bucket key; returning null or throwing
+     * falls back to the client IP.
+     */
+    public function rateLimitKeyExtractor(callable $extractor): self
+    {
+        $this->registrations[] = ['rate_limit_key_extractor', [], $extractor];
+        return $this;
+    }
+
+    /**
+     * Register the callable as the batch loader named $name for GraphQL field loaders.
+     *
+     * It receives an array of keys and returns one value per key, in order, with null
+     * for a missing key; throwing fails every key in the batch.
+     */
+    public function dataLoader(string $name, callable $loader): self
+    {
+        $this->registrations[] = ['data_loader', [$name], $loader];
+        return $this;
+    }
+
     /**
      * Run the HTTP server (binds 127.0.0.1:8000 by default).
      */
@@ -129,13 +229,25 @@ final class App
     /**
      * Build a RouteBuilder for the given method + path, attach the JSON Schema
      * derived from $dtoClass (if any), wrap the handler in a request adapter,
-     * and append the registration tuple.
+     * and append the registration tuple. $group, if any, prefixes the path and
+     * adds its settings.
      *
      * @param class-string|null $dtoClass
+     * @param array<string, list<callable>> $hooks
      */
-    private function addRoute(string $method, string $path, ?string $dtoClass, callable $handler): self
-    {
+    private function addRoute(
+        string $method,
+        string $path,
+        ?string $dtoClass,
+        callable $handler,
+        array $hooks = [],
+        ?NativeRouteGroup $group = null,
+    ): self {
         $builder = RouteBuilder::new($method, $path);
+        if ($group !== null) {
+            $builder = $group->apply($builder);
+        }
+        $builder = self::attachHooks($builder, $hooks);
 
         if ($dtoClass !== null) {
             $schema = self::deriveJsonSchema($dtoClass);
@@ -148,6 +260,28 @@ final class App
         return $this;
     }
 
+    /**
+     * Attach route-scoped lifecycle hooks to $builder.
+     *
+     * $hooks maps lifecycle phases (onRequest, preValidation, preHandler,
+     * onResponse, onError) to hooks that run only for this route, after the
+     * global hooks of the same phase.
+     *
+     * @param array<string, list<callable>> $hooks
+     */
+    private static function attachHooks(RouteBuilder $builder, array $hooks): RouteBuilder
+    {
+        foreach ($hooks as $phase => $phaseHooks) {
+            if (!in_array($phase, self::HOOK_PHASES, true)) {
+                throw new \InvalidArgumentException("Unknown lifecycle hook phase: {$phase}");
+            }
+            foreach ($phaseHooks as $hook) {
+                $builder = $builder->{$phase}($hook);
+            }
+        }
+        return $builder;
+    }
+
     /**
      * Derive a JSON Schema object from a PHP 8.2+ readonly-class constructor.
      *
@@ -224,14 +358,19 @@ final class App
      * RequestData JSON (arrives as a PHP array).  The adapter:
      *   1. Extracts the "body" key from the request data.
      *   2. Hydrates a DTO instance from the body array (if $dtoClass is set).
-     *   3. Calls the user handler with the hydrated DTO (or no arguments for bodyless routes).
-     *   4. Wraps the result in the wire response envelope expected by spikard::Response.
+     *   3. Calls the user handler with the hydrated DTO (or no arguments for bodyless routes),
+     *      plus any ClientInfo / Claims / Session / CsrfToken parameters read from the
+     *      request context.
+     *   4. Wraps the result in the wire response envelope expected by spikard::Response,
+     *      handing session changes back to the server in its internal headers.
      *
      * @param class-string|null $dtoClass
      */
     private static function makeAdapter(?string $dtoClass, callable $handler): callable
     {
-        return static function (mixed $requestData) use ($dtoClass, $handler): mixed {
+        $contextParams = self::contextParams($handler);
+
+        return static function (mixed $requestData) use ($dtoClass, $handler, $contextParams): mixed {
             /** @var array<string, mixed> $data */
             $data = match (true) {
                 is_array($requestData) => $requestData,
@@ -253,9 +392,86 @@ final class App
                 $args[] = self::hydrateDto($dtoClass, $bodyArr);
             }
 
+            foreach ($contextParams as $name => $type) {
+                $args[$name] = self::readContext($type, $data);
+            }
+
             $result = $handler(...$args);
+            $envelope = self::toEnvelope($result);
 
-            return self::toEnvelope($result);
+            foreach ($args as $arg) {
+                if ($arg instanceof Session) {
+                    $envelope['headers'] = (object) array_merge((array) $envelope['headers'], $arg->bindingHeaders());
+                }
+            }
+
+            return $envelope;
+        };
+    }
+
+    /**
+     * Map each handler parameter typed ClientInfo, Claims, Session or CsrfToken to that type.
+     *
+     * These parameters are filled from the request context the Rust core resolved,
+     * not from the request data.
+     *
+     * @return array<string, class-string>
+     */
+    private static function contextParams(callable $handler): array
+    {
+        $params = [];
+        foreach ((new \ReflectionFunction(\Closure::fromCallable($handler)))->getParameters() as $param) {
+            $type = $param->getType();
+            if (
+                $type instanceof \ReflectionNamedType
+                && in_array(
+                    $type->getName(),
+                    [ClientInfo::class, Claims::class, Session::class, CsrfToken::class],
+                    true,
+                )
+            ) {
+                $params[$param->getName()] = $type->getName();
+            }
+        }
+
+        return $params;
+    }
+
+    /**
+     * Read a request-context value for a ClientInfo, Claims, Session or CsrfToken parameter.
+     *
+     * The client address is resolved through trusted proxies when configured. The
+     * other values arrive in internal headers set by the Rust middleware, and are
+     * null when that middleware is not configured.
+     *
+     * @param class-string         $type
+     * @param array<string, mixed> $data
+     */
+    private static function readContext(string $type, array $data): ?object
+    {
+        if ($type === ClientInfo::class) {
+            return new ClientInfo(
+                is_string($data['scheme'] ?? null) ? $data['scheme'] : 'http',
+                is_string($data['client_ip'] ?? null) ? $data['client_ip'] : null,
+                is_string($data['host'] ?? null) ? $data['host'] : null,
+            );
+        }
+
+        $headers = is_array($data['headers'] ?? null) ? $data['headers'] : [];
+        $header = match ($type) {
+            Claims::class => 'x-spikard-jwt-claims',
+            Session::class => 'x-spikard-session',
+            default => 'x-spikard-csrf-token',
+        };
+        $raw = $headers[$header] ?? null;
+        if (!is_string($raw)) {
+            return null;
+        }
+
+        return match ($type) {
+            Claims::class => Claims::from_json($raw),
+            Session::class => Session::fromData($raw),
+            default => new CsrfToken($raw),
         };
     }
 
@@ -342,3 +558,162 @@ final class App
         return ['status_code' => 200, 'content' => $content, 'headers' => new \stdClass()];
     }
 }
+
+/**
+ * Routes registered under a shared path prefix, created with App::group().
+ *
+ * Tags and dependencies accumulate and hooks run outermost group first; nested
+ * groups inherit everything configured on their parent.
+ */
+final class RouteGroup
+{
+    private readonly NativeRouteGroup $group;
+
+    /**
+     * @param \Closure(string, string, ?string, callable, array, NativeRouteGroup): App $register  App::addRoute
+     * @param list<string> $tags
+     * @param list<string> $dependencies
+     * @param array<string, list<callable>> $hooks
+     */
+    public function __construct(
+        private readonly \Closure $register,
+        NativeRouteGroup $group,
+        array $tags = [],
+        array $dependencies = [],
+        private readonly array $hooks = [],
+    ) {
+        foreach ($tags as $tag) {
+            $group = $group->tag($tag);
+        }
+        if ($dependencies !== []) {
+            $group = $group->handlerDependencies($dependencies);
+        }
+        $this->group = $group;
+    }
+
+    /**
+     * The path prefix shared by the routes of this group.
+     */
+    public function prefix(): string
+    {
+        return $this->group->prefix();
+    }
+
+    /**
+     * Start a group nested under this one.
+     *
+     * @param list<string> $tags
+     * @param list<string> $dependencies
+     * @param array<string, list<callable>> $hooks
+     */
+    public function group(string $prefix, array $tags = [], array $dependencies = [], array $hooks = []): self
+    {
+        return new self(
+            $this->register,
+            $this->group->group($prefix),
+            $tags,
+            $dependencies,
+            self::mergeHooks($this->hooks, $hooks),
+        );
+    }
+
+    /**
+     * Register a GET route under the group.
+     *
+     * @param array<string, list<callable>> $hooks  Route-scoped lifecycle hooks keyed by phase.
+     */
+    public function get(string $path, callable $handler, array $hooks = []): self
+    {
+        return $this->addRoute('Get', $path, null, $handler, $hooks);
+    }
+
+    /**
+     * Register a POST route under the group with an optional body DTO class.
+     *
+     * @param class-string|null $dtoClass
+     * @param array<string, list<callable>> $hooks  Route-scoped lifecycle hooks keyed by phase.
+     */
+    public function post(string $path, ?string $dtoClass, callable $handler, array $hooks = []): self
+    {
+        return $this->addRoute('Post', $path, $dtoClass, $handler, $hooks);
+    }
+
+    /**
+     * Register a PUT route under the group with an optional body DTO class.
+     *
+     * @param class-string|null $dtoClass
+     * @param array<string, list<callable>> $hooks  Route-scoped lifecycle hooks keyed by phase.
+     */
+    public function put(string $path, ?string $dtoClass, callable $handler, array $hooks = []): self
+    {
+        return $this->addRoute('Put', $path, $dtoClass, $handler, $hooks);
+    }
+
+    /**
+     * Register a PATCH route under the group with an optional body DTO class.
+     *
+     * @param class-string|null $dtoClass
+     * @param array<string, list<callable>> $hooks  Route-scoped lifecycle hooks keyed by phase.
+     */
+    public function patch(string $path, ?string $dtoClass, callable $handler, array $hooks = []): self
+    {
+        return $this->addRoute('Patch', $path, $dtoClass, $handler, $hooks);
+    }
+
+    /**
+     * Register a DELETE route under the group.
+     *
+     * @param array<string, list<callable>> $hooks  Route-scoped lifecycle hooks keyed by phase.
+     */
+    public function delete(string $path, callable $handler, array $hooks = []): self
+    {
+        return $this->addRoute('Delete', $path, null, $handler, $hooks);
+    }
+
+    /**
+     * Register a HEAD route under the group.
+     *
+     * @param array<string, list<callable>> $hooks  Route-scoped lifecycle hooks keyed by phase.
+     */
+    public function head(string $path, callable $handler, array $hooks = []): self
+    {
+        return $this->addRoute('Head', $path, null, $handler, $hooks);
+    }
+
+    /**
+     * Register an OPTIONS route under the group.
+     *
+     * @param array<string, list<callable>> $hooks  Route-scoped lifecycle hooks keyed by phase.
+     */
+    public function options(string $path, callable $handler, array $hooks = []): self
+    {
+        return $this->addRoute('Options', $path, null, $handler, $hooks);
+    }
+
+    /**
+     * Register a route on the owning App with the group's hooks ahead of the route's own.
+     *
+     * @param class-string|null $dtoClass
+     * @param array<string, list<callable>> $hooks
+     */
+    private function addRoute(string $method, string $path, ?string $dtoClass, callable $handler, array $hooks): self
+    {
+        ($this->register)($method, $path, $dtoClass, $handler, self::mergeHooks($this->hooks, $hooks), $this->group);
+        return $this;
+    }
+
+    /**
+     * Combine hooks per phase, the enclosing group's first.
+     *
+     * @param array<string, list<callable>> $outer
+     * @param array<string, list<callable>> $inner
+     * @return array<string, list<callable>>
+     */
+    private static function mergeHooks(array $outer, array $inner): array
+    {
+        foreach ($inner as $phase => $phaseHooks) {
+            $outer[$phase] = [...($outer[$phase] ?? []), ...$phaseHooks];
+        }
+        return $outer;
+    }
+}
@@ -181,6 +181,41 @@ final class App
         };
     }
 
+    // Called once before the listener binds; a failing hook aborts boot.
+    public function onStartup(callable $hook): self
+    {
+        $this->registrations[] = ['on_startup', ['on_startup'], $hook];
+        return $this;
+    }
+
+    // Called once after graceful shutdown completes, in reverse registration order.
+    public function onShutdown(callable $hook): self
+    {
+        $this->registrations[] = ['on_shutdown', ['on_shutdown'], $hook];
+        return $this;
+    }
+
+    // Readiness check run on every readiness probe under its own timeout; an error reports not ready.
+    public function healthCheck(string $name, callable $check): self
+    {
+        $this->registrations[] = ['health_check', [$name], $check];
+        return $this;
+    }
+
+    // Derives the bucket key for custom-keyed rate limits; returning nothing falls back to the client IP.
+    public function rateLimitKeyExtractor(callable $extractor): self
+    {
+        $this->registrations[] = ['rate_limit_key_extractor', [], $extractor];
+        return $this;
+    }
+
+    // Named batch loader for GraphQL field loaders; returns one value per key, in order.
+    public function dataLoader(string $name, callable $loader): self
+    {
+        $this->registrations[] = ['data_loader', [$name], $loader];
+        return $this;
+    }
+
     /**
      * Run the HTTP server using the configured routes.
      *
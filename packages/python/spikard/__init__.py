@@ -9,7 +9,10 @@ Version: 0.17.0-rc.11
 
 from ._spikard import (
     ApiKeyConfig,
+    Claims,
+    ClientInfo,
     ContactInfo,
+    CsrfToken,
     FieldErrorSpec,
     GraphQLRouteConfig,
     GraphQLSubscriptionSnapshot,
@@ -29,6 +32,7 @@ from ._spikard import (
     ResponseSnapshot,
     SecuritySchemeInfo,
     ServerInfo,
+    Session,
     SnapshotError,
     SseEvent,
     StaticFilesConfig,
@@ -95,10 +99,13 @@ __all__ = [
     "BackgroundJobMetadata",
     "BackgroundTaskConfig",
     "BuildingFailedError",
+    "Claims",
+    "ClientInfo",
     "ComplexityLimitExceededError",
     "CompressionConfig",
     "ContactInfo",
     "CorsConfig",
+    "CsrfToken",
     "DecodeError",
     "DepthLimitExceededError",
     "DynamicSchemaConfig",
@@ -146,6 +153,7 @@ __all__ = [
     "ServerConfig",
     "ServerError",
     "ServerInfo",
+    "Session",
     "SnapshotError",
     "SseEvent",
     "StaticFilesConfig",
@@ -165,5 +173,9 @@ __version__ = "0.17.0-rc.11"
 from .app import App  # noqa: F811
 from .params import Body, Cookie, Header, Path, Query
 from ._internal.converters import register_decoder
+from ._spikard import DependencyOverrideGuard, SnapshotOptions, SnapshotOutcome
+from ._spikard import assert_snapshot_file, check_snapshot, snapshot_path
 
 __all__ += ["Body", "Cookie", "Header", "Path", "Query", "register_decoder"]
+__all__ += ["DependencyOverrideGuard", "SnapshotOptions", "SnapshotOutcome"]
+__all__ += ["assert_snapshot_file", "check_snapshot", "snapshot_path"]
@@ -301,6 +301,48 @@ class ApiKeyConfig:
     header_name: str
     def __init__(self, keys: list[str], header_name: str) -> None: ...
 
+class Claims:
+    sub: str
+    exp: int
+    iat: int | None
+    nbf: int | None
+    aud: list[str] | None
+    iss: str | None
+    def __init__(
+        self,
+        sub: str,
+        exp: int,
+        iat: int | None = None,
+        nbf: int | None = None,
+        aud: list[str] | None = None,
+        iss: str | None = None,
+    ) -> None: ...
+
+class ClientInfo:
+    ip: str | None
+    scheme: str
+    host: str | None
+    def __init__(self, scheme: str, ip: str | None = None, host: str | None = None) -> None: ...
+    def origin(self) -> str | None: ...
+
+class Session:
+    @staticmethod
+    def from_data(data: str) -> Session: ...
+    def is_new(self) -> bool: ...
+    def get_value(self, key: str) -> str | None: ...
+    def insert(self, key: str, value: str) -> None: ...
+    def remove(self, key: str) -> str | None: ...
+    def clear(self) -> None: ...
+    def replace(self, data: str) -> None: ...
+    def data(self) -> str: ...
+    def regenerate(self) -> None: ...
+    def destroy(self) -> None: ...
+    def binding_headers(self) -> dict[str, str]: ...
+
+class CsrfToken:
+    def __init__(self, token: str) -> None: ...
+    def as_str(self) -> str: ...
+
 class StaticFilesConfig:
     directory: str
     route_prefix: str
@@ -496,10 +538,34 @@ class ResponseSnapshot:
     def __init__(self, status: int, headers: dict[str, str], body: bytes) -> None: ...
     def text(self) -> str: ...
     def header(self, name: str) -> str | None: ...
+    def to_snapshot_json(self, options: SnapshotOptions | None = None) -> str: ...
+
+class SnapshotOptions:
+    headers: list[str]
+    redact: list[str]
+    redact_uuids: bool
+    redact_timestamps: bool
+    def __init__(
+        self,
+        headers: list[str] | None = None,
+        redact: list[str] | None = None,
+        redact_uuids: bool = False,
+        redact_timestamps: bool = False,
+    ) -> None: ...
+
+class SnapshotOutcome:
+    status: str
+    expected: str | None
+    actual: str | None
 
 class TestingSseEvent:
     data: str
-    def __init__(self, data: str) -> None: ...
+    event: str | None
+    id: str | None
+    retry: int | None
+    def __init__(
+        self, data: str, event: str | None = None, id: str | None = None, retry: int | None = None
+    ) -> None: ...
 
 class GraphQLSubscriptionSnapshot:
     operation_id: str
@@ -545,7 +611,23 @@ class RouteBuilder:
     @staticmethod
     def new(method: Method, path: str) -> RouteBuilder: ...
 
+class RouteGroup:
+    def __init__(self, prefix: str) -> None: ...
+    def prefix(self) -> str: ...
+    def path(self, path: str) -> str: ...
+    def group(self, prefix: str) -> RouteGroup: ...
+    def tag(self, tag: str) -> RouteGroup: ...
+    def handler_dependencies(self, dependencies: list[str]) -> RouteGroup: ...
+    def cors(self, cors: CorsConfig) -> RouteGroup: ...
+    def rate_limit(self, rate_limit: RateLimitConfig) -> RouteGroup: ...
+    def auth(self, jwt: JwtConfig | None = None, api_key: ApiKeyConfig | None = None) -> RouteGroup: ...
+    def apply(self, builder: RouteBuilder) -> RouteBuilder: ...
+
 class TestClient:
+    def override_dependency(self, key: str, value: str) -> None: ...
+    def override_dependency_scope(self, key: str, value: str) -> DependencyOverrideGuard: ...
+    def restore_dependency(self, key: str) -> None: ...
+    def clear_dependency_overrides(self) -> None: ...
     async def graphql_at(
         self,
         endpoint: str,
@@ -573,6 +655,17 @@ class TestClient:
         operation_name: str | None = None,
     ) -> GraphQLSubscriptionSnapshot: ...
 
+class DependencyOverrideGuard:
+    def key(self) -> str: ...
+    def restore(self) -> None: ...
+    def __enter__(self) -> DependencyOverrideGuard: ...
+    def __exit__(
+        self,
+        exc_type: type[BaseException] | None = None,
+        exc_value: BaseException | None = None,
+        traceback: Any | None = None,
+    ) -> bool: ...
+
 class HandlerResult: ...
 class Request: ...
 class RequestData: ...
@@ -679,5 +772,12 @@ class BuildingFailedError(SchemaError): ...
 def schema_query_only() -> QueryOnlyConfig: ...
 def schema_query_mutation() -> QueryMutationConfig: ...
 def schema_full() -> FullSchemaConfig: ...
+def check_snapshot(
+    response: ResponseSnapshot, path: str, options: SnapshotOptions | None = None, update: str | None = None
+) -> SnapshotOutcome: ...
+def snapshot_path(root_dir: str, file: str, name: str) -> str: ...
+def assert_snapshot_file(
+    response: ResponseSnapshot, path: str, options: SnapshotOptions | None = None
+) -> SnapshotOutcome: ...
 def app_run(registrations: list[Any]) -> None: ...
 def app_into_router(registrations: list[Any]) -> Any: ...
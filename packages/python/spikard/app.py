@@ -20,6 +20,7 @@ serialization at request time.
 from __future__ import annotations
 
 import asyncio
+import functools
 import inspect
 import json
 import re
@@ -33,19 +34,21 @@ from spikard._internal import (
     field_definition_to_json_schema,
     to_builtins,
 )
+from spikard._spikard import Claims, ClientInfo, CsrfToken, Session
 from spikard._spikard import Method as _Method
 from spikard._spikard import RouteBuilder as _RouteBuilder
+from spikard._spikard import RouteGroup as _RouteGroup
 from spikard.introspection import _is_structured_type
 from spikard.params import Body, Cookie, Header, ParamBase, Path, Query
 from spikard.schema import extract_json_schema
 from spikard.service import App as _LowLevelApp
 
 if TYPE_CHECKING:
-    from collections.abc import Callable
+    from collections.abc import Callable, Mapping, Sequence
 
     from spikard.options import ServerConfig
 
-__all__ = ["App"]
+__all__ = ["App", "RouteGroup"]
 
 # HTTP methods that never carry a request body.
 _BODYLESS_METHODS = frozenset({"GET", "HEAD", "OPTIONS", "TRACE", "CONNECT", "DELETE"})
@@ -56,6 +59,9 @@ _SPECIAL_PARAM_NAMES = frozenset({"self", "cls", "request", "req"})
 # Matches ``{name}`` and typed ``{name:converter}`` path segments.
 _PATH_PARAM_RE = re.compile(r"\{(\w+)(?::[^{}]+)?\}")
 
+# Lifecycle phases a route can attach its own hooks to, in execution order.
+_HOOK_PHASES = ("on_request", "pre_validation", "pre_handler", "on_response", "on_error")
+
 # Sentinel distinguishing "no value supplied" from a legitimate ``None``.
 _MISSING = object()
 
@@ -200,10 +206,48 @@ def _classify_parameter(
     return "query", _strip_optional(annotation), False
 
 
+def _client_info(request_dict: dict[str, Any]) -> ClientInfo:
+    """Build the client address the Rust core resolved, through trusted proxies when configured."""
+    return ClientInfo(request_dict.get("scheme") or "http", request_dict.get("client_ip"), request_dict.get("host"))
+
+
+def _jwt_claims(request_dict: dict[str, Any]) -> Claims | None:
+    """Read the validated JWT claims the Rust auth middleware hands over in an internal header."""
+    raw = (request_dict.get("headers") or {}).get("x-spikard-jwt-claims")
+    return None if raw is None else Claims(**json.loads(raw))
+
+
+def _session(request_dict: dict[str, Any]) -> Session | None:
+    """Rebuild the session the Rust session middleware hands over in an internal header."""
+    raw = (request_dict.get("headers") or {}).get("x-spikard-session")
+    return None if raw is None else Session.from_data(raw)
+
+
+def _csrf_token(request_dict: dict[str, Any]) -> CsrfToken | None:
+    """Read the CSRF token the Rust CSRF middleware hands over in an internal header."""
+    raw = (request_dict.get("headers") or {}).get("x-spikard-csrf-token")
+    return None if raw is None else CsrfToken(raw)
+
+
+# Handler parameter types filled from the request context instead of the request data.
+_CONTEXT_READERS: tuple[tuple[type, Callable[[dict[str, Any]], Any]], ...] = (
+    (ClientInfo, _client_info),
+    (Claims, _jwt_claims),
+    (Session, _session),
+    (CsrfToken, _csrf_token),
+)
+
+
+def _context_reader(annotation: Any) -> Callable[[dict[str, Any]], Any] | None:
+    """Return the request-context reader for a context-typed annotation (e.g. ``Session``), else ``None``."""
+    target = _strip_optional(annotation)
+    return next((reader for context_type, reader in _CONTEXT_READERS if target is context_type), None)
+
+
 class _RouteSpec:
     """Introspected, per-route metadata derived from a handler at registration time."""
 
-    __slots__ = ("bindings", "body_param_name", "body_type", "handler")
+    __slots__ = ("bindings", "body_param_name", "body_type", "context_readers", "handler")
 
     def __init__(
         self,
@@ -211,11 +255,13 @@ class _RouteSpec:
         bindings: list[_Binding],
         body_param_name: str | None,
         body_type: Any,
+        context_readers: dict[str, Callable[[dict[str, Any]], Any]],
     ) -> None:
         self.handler = handler
         self.bindings = bindings
         self.body_param_name = body_param_name
         self.body_type = body_type
+        self.context_readers = context_readers
 
 
 def _introspect(func: Callable[..., Any], method: str, path: str) -> _RouteSpec:
@@ -228,6 +274,7 @@ def _introspect(func: Callable[..., Any], method: str, path: str) -> _RouteSpec:
     bindings: list[_Binding] = []
     body_param_name: str | None = None
     body_type: Any = None
+    context_readers: dict[str, Callable[[dict[str, Any]], Any]] = {}
 
     for pname, param in signature.parameters.items():
         if pname in _SPECIAL_PARAM_NAMES:
@@ -239,6 +286,11 @@ def _introspect(func: Callable[..., Any], method: str, path: str) -> _RouteSpec:
         if annotation is inspect.Parameter.empty:
             annotation = Any
 
+        reader = _context_reader(annotation)
+        if reader is not None:
+            context_readers[pname] = reader
+            continue
+
         default = param.default if param.default is not inspect.Parameter.empty else _MISSING
         param_default = None if default is _MISSING else default
 
@@ -263,7 +315,7 @@ def _introspect(func: Callable[..., Any], method: str, path: str) -> _RouteSpec:
             )
         )
 
-    return _RouteSpec(func, bindings, body_param_name, body_type)
+    return _RouteSpec(func, bindings, body_param_name, body_type, context_readers)
 
 
 def _build_params_schema(bindings: list[_Binding]) -> dict[str, Any] | None:
@@ -349,6 +401,9 @@ def _build_kwargs(request_dict: dict[str, Any], spec: _RouteSpec) -> dict[str, A
 
         kwargs[binding.name] = convert_value(raw, binding.target_type)
 
+    for name, reader in spec.context_readers.items():
+        kwargs[name] = reader(request_dict)
+
     if spec.body_param_name is not None:
         body = request_dict.get("body")
         raw_body = request_dict.get("raw_body")
@@ -372,12 +427,134 @@ def _make_adapter(spec: _RouteSpec, *, is_async: bool) -> Callable[[dict[str, An
     def adapter(request_dict: dict[str, Any]) -> dict[str, Any]:
         kwargs = _build_kwargs(request_dict, spec)
         result = _run_coroutine(handler(**kwargs)) if is_async else handler(**kwargs)
-        return _to_envelope(result)
+        envelope = _to_envelope(result)
+        for name in spec.context_readers:
+            session = kwargs.get(name)
+            if isinstance(session, Session):
+                envelope["headers"].update(session.binding_headers())
+        return envelope
+
+    return adapter
+
+
+def _make_hook_adapter(hook: Callable[[], Any]) -> Callable[[], Any]:
+    """Create the synchronous callable the Rust bridge invokes for a lifespan hook or health check.
+
+    Coroutine hooks run on the shared background loop, so resources they open are bound to
+    the same loop that drives coroutine handlers.
+    """
+    if not inspect.iscoroutinefunction(hook):
+        return hook
+
+    @functools.wraps(hook)
+    def adapter() -> None:
+        _run_coroutine(hook())
 
     return adapter
 
 
-class App:
+def _merge_hooks(
+    outer: Mapping[str, Sequence[Callable[..., Any]]],
+    inner: Mapping[str, Sequence[Callable[..., Any]]] | None,
+) -> dict[str, list[Callable[..., Any]]]:
+    """Combine hooks per phase, ``outer`` (an enclosing group's) first."""
+    merged = {phase: list(phase_hooks) for phase, phase_hooks in outer.items()}
+    for phase, phase_hooks in (inner or {}).items():
+        merged.setdefault(phase, []).extend(phase_hooks)
+    return merged
+
+
+class _RouteRegistrar:
+    """Verb decorators shared by :class:`App` and :class:`RouteGroup`."""
+
+    def add_route(
+        self,
+        method: str,
+        path: str,
+        handler: Callable[..., Any],
+        *,
+        hooks: Mapping[str, Sequence[Callable[..., Any]]] | None = None,
+    ) -> Callable[..., Any]:
+        """Register ``handler`` for ``method`` and ``path`` and return it unchanged."""
+        raise NotImplementedError
+
+    def _method_decorator(
+        self, method: str, hooks: Mapping[str, Sequence[Callable[..., Any]]] | None = None
+    ) -> Callable[[str], Callable[[Callable[..., Any]], Callable[..., Any]]]:
+        def register(path: str) -> Callable[[Callable[..., Any]], Callable[..., Any]]:
+            def decorator(func: Callable[..., Any]) -> Callable[..., Any]:
+                return self.add_route(method, path, func, hooks=hooks)
+
+            return decorator
+
+        return register
+
+    def get(
+        self, path: str, *, hooks: Mapping[str, Sequence[Callable[..., Any]]] | None = None
+    ) -> Callable[[Callable[..., Any]], Callable[..., Any]]:
+        """Register a GET route."""
+        return self._method_decorator("GET", hooks)(path)
+
+    def post(
+        self, path: str, *, hooks: Mapping[str, Sequence[Callable[..., Any]]] | None = None
+    ) -> Callable[[Callable[..., Any]], Callable[..., Any]]:
+        """Register a POST route."""
+        return self._method_decorator("POST", hooks)(path)
+
+    def put(
+        self, path: str, *, hooks: Mapping[str, Sequence[Callable[..., Any]]] | None = None
+    ) -> Callable[[Callable[..., Any]], Callable[..., Any]]:
+        """Register a PUT route."""
+        return self._method_decorator("PUT", hooks)(path)
+
+    def patch(
+        self, path: str, *, hooks: Mapping[str, Sequence[Callable[..., Any]]] | None = None
+    ) -> Callable[[Callable[..., Any]], Callable[..., Any]]:
+        """Register a PATCH route."""
+        return self._method_decorator("PATCH", hooks)(path)
+
+    def delete(
+        self, path: str, *, hooks: Mapping[str, Sequence[Callable[..., Any]]] | None = None
+    ) -> Callable[[Callable[..., Any]], Callable[..., Any]]:
+        """Register a DELETE route."""
+        return self._method_decorator("DELETE", hooks)(path)
+
+    def head(
+        self, path: str, *, hooks: Mapping[str, Sequence[Callable[..., Any]]] | None = None
+    ) -> Callable[[Callable[..., Any]], Callable[..., Any]]:
+        """Register a HEAD route."""
+        return self._method_decorator("HEAD", hooks)(path)
+
+    def options(
+        self, path: str, *, hooks: Mapping[str, Sequence[Callable[..., Any]]] | None = None
+    ) -> Callable[[Callable[..., Any]], Callable[..., Any]]:
+        """Register an OPTIONS route."""
+        return self._method_decorator("OPTIONS", hooks)(path)
+
+    def connect(
+        self, path: str, *, hooks: Mapping[str, Sequence[Callable[..., Any]]] | None = None
+    ) -> Callable[[Callable[..., Any]], Callable[..., Any]]:
+        """Register a CONNECT route."""
+        return self._method_decorator("CONNECT", hooks)(path)
+
+    def trace(
+        self, path: str, *, hooks: Mapping[str, Sequence[Callable[..., Any]]] | None = None
+    ) -> Callable[[Callable[..., Any]], Callable[..., Any]]:
+        """Register a TRACE route."""
+        return self._method_decorator("TRACE", hooks)(path)
+
+    def route(
+        self,
+        path: str,
+        method: str = "GET",
+        *,
+        hooks: Mapping[str, Sequence[Callable[..., Any]]] | None = None,
+    ) -> Callable[[Callable[..., Any]], Callable[..., Any]]:
+        """Register a route with an explicit HTTP method (defaults to GET)."""
+        return self._method_decorator(method, hooks)(path)
+
+
+class App(_RouteRegistrar):
     """Ergonomic Spikard application with typed handlers and DTO binding.
 
     Example::
@@ -420,17 +597,56 @@ class App:
         self._app.config(config)
         return self
 
-    def add_route(self, method: str, path: str, handler: Callable[..., Any]) -> Callable[..., Any]:
-        """Register ``handler`` for ``method`` and ``path`` and return it unchanged."""
+    def add_route(
+        self,
+        method: str,
+        path: str,
+        handler: Callable[..., Any],
+        *,
+        hooks: Mapping[str, Sequence[Callable[..., Any]]] | None = None,
+    ) -> Callable[..., Any]:
+        """Register ``handler`` for ``method`` and ``path`` and return it unchanged.
+
+        ``hooks`` maps lifecycle phases (``"on_request"``, ``"pre_validation"``,
+        ``"pre_handler"``, ``"on_response"``, ``"on_error"``) to hooks that run only for
+        this route, after the global hooks of the same phase.
+        """
+        return self._register(method, path, handler, hooks=hooks, group=None)
+
+    def group(
+        self,
+        prefix: str,
+        *,
+        tags: Sequence[str] | None = None,
+        dependencies: Sequence[str] | None = None,
+        hooks: Mapping[str, Sequence[Callable[..., Any]]] | None = None,
+    ) -> RouteGroup:
+        """Start a group of routes sharing ``prefix``, ``tags``, ``dependencies`` and ``hooks``.
+
+        Group hooks run after the global hooks and before the hooks of the route itself.
+        """
+        return RouteGroup(self._register, _RouteGroup(prefix), tags=tags, dependencies=dependencies, hooks=hooks)
+
+    def _register(
+        self,
+        method: str,
+        path: str,
+        handler: Callable[..., Any],
+        *,
+        hooks: Mapping[str, Sequence[Callable[..., Any]]] | None,
+        group: _RouteGroup | None,
+    ) -> Callable[..., Any]:
         method_upper = method.upper()
         method_enum = getattr(_Method, method_upper, None)
         if method_enum is None:
             raise ValueError(f"Unsupported HTTP method: {method!r}")
 
-        spec = _introspect(handler, method_upper, path)
+        spec = _introspect(handler, method_upper, path if group is None else group.path(path))
         is_async = inspect.iscoroutinefunction(handler)
 
         builder = _RouteBuilder.new(method_enum, path)
+        if group is not None:
+            builder = group.apply(builder)
         builder = builder.handler_name(getattr(handler, "__name__", "handler"))
 
         if spec.body_param_name is not None and method_upper not in _BODYLESS_METHODS:
@@ -451,6 +667,12 @@ class App:
         # route sync regardless of whether the user's handler is a coroutine function.
         builder = builder.sync()
 
+        for phase, phase_hooks in (hooks or {}).items():
+            if phase not in _HOOK_PHASES:
+                raise ValueError(f"Unknown lifecycle hook phase: {phase!r}")
+            for hook in phase_hooks:
+                builder = getattr(builder, phase)(hook)
+
         adapter = _make_adapter(spec, is_async=is_async)
         self._app.register_route(builder, adapter)
         return handler
@@ -465,54 +687,66 @@ class App:
         except (TypeError, ValueError):
             return None
 
-    def _method_decorator(self, method: str) -> Callable[[str], Callable[[Callable[..., Any]], Callable[..., Any]]]:
-        def register(path: str) -> Callable[[Callable[..., Any]], Callable[..., Any]]:
-            def decorator(func: Callable[..., Any]) -> Callable[..., Any]:
-                return self.add_route(method, path, func)
+    def on_startup(self, hook: Callable[[], Any]) -> Callable[[], Any]:
+        """Register ``hook`` to run once before the listener binds and return it unchanged.
 
-            return decorator
+        Hooks run in registration order; one that raises aborts boot, so :meth:`run` raises
+        instead of serving.
+        """
+        self._app.on_startup(_make_hook_adapter(hook))
+        return hook
 
-        return register
+    def on_shutdown(self, hook: Callable[[], Any]) -> Callable[[], Any]:
+        """Register ``hook`` to run once after the server stops and return it unchanged.
 
-    def get(self, path: str) -> Callable[[Callable[..., Any]], Callable[..., Any]]:
-        """Register a GET route."""
-        return self._method_decorator("GET")(path)
+        Hooks run in reverse registration order, releasing resources in the opposite order
+        startup hooks acquired them.
+        """
+        self._app.on_shutdown(_make_hook_adapter(hook))
+        return hook
 
-    def post(self, path: str) -> Callable[[Callable[..., Any]], Callable[..., Any]]:
-        """Register a POST route."""
-        return self._method_decorator("POST")(path)
+    def health_check(self, name: str) -> Callable[[Callable[[], Any]], Callable[[], Any]]:
+        """Register the decorated function as a readiness check named ``name``.
 
-    def put(self, path: str) -> Callable[[Callable[..., Any]], Callable[..., Any]]:
-        """Register a PUT route."""
-        return self._method_decorator("PUT")(path)
+        The check runs on every readiness probe; raising reports the application as not
+        ready, keyed by ``name`` in the readiness report. Registering a check enables the
+        health endpoints.
+        """
 
-    def patch(self, path: str) -> Callable[[Callable[..., Any]], Callable[..., Any]]:
-        """Register a PATCH route."""
-        return self._method_decorator("PATCH")(path)
+        def decorator(check: Callable[[], Any]) -> Callable[[], Any]:
+            self._app.health_check(name, _make_hook_adapter(check))
+            return check
 
-    def delete(self, path: str) -> Callable[[Callable[..., Any]], Callable[..., Any]]:
-        """Register a DELETE route."""
-        return self._method_decorator("DELETE")(path)
+        return decorator
 
-    def head(self, path: str) -> Callable[[Callable[..., Any]], Callable[..., Any]]:
-        """Register a HEAD route."""
-        return self._method_decorator("HEAD")(path)
+    def rate_limit_key_extractor(
+        self, extractor: Callable[[dict[str, Any]], str | None]
+    ) -> Callable[[dict[str, Any]], str | None]:
+        """Register ``extractor`` as the key for rate limits with a custom key and return it unchanged.
 
-    def options(self, path: str) -> Callable[[Callable[..., Any]], Callable[..., Any]]:
-        """Register an OPTIONS route."""
-        return self._method_decorator("OPTIONS")(path)
+        It is called synchronously for every limited request with a dict of ``method``,
+        ``path``, ``query``, ``headers`` and ``client_ip``, and returns the bucket key;
+        returning ``None`` or raising falls back to the client IP. Coroutine functions are
+        rejected, since the key is computed on the server thread before the handler runs.
+        """
+        if inspect.iscoroutinefunction(extractor):
+            raise TypeError("rate_limit_key_extractor requires a synchronous function")
+        self._app.rate_limit_key_extractor(extractor)
+        return extractor
 
-    def connect(self, path: str) -> Callable[[Callable[..., Any]], Callable[..., Any]]:
-        """Register a CONNECT route."""
-        return self._method_decorator("CONNECT")(path)
+    def data_loader(self, name: str) -> Callable[[Callable[[list[Any]], Any]], Callable[[list[Any]], Any]]:
+        """Register the decorated function as the batch loader named ``name``.
 
-    def trace(self, path: str) -> Callable[[Callable[..., Any]], Callable[..., Any]]:
-        """Register a TRACE route."""
-        return self._method_decorator("TRACE")(path)
+        GraphQL fields whose field loader names ``name`` are resolved through it: the function
+        (sync or async) receives a list of keys and returns one value per key, in order, with
+        ``None`` for a missing key. Raising fails every key in the batch.
+        """
 
-    def route(self, path: str, method: str = "GET") -> Callable[[Callable[..., Any]], Callable[..., Any]]:
-        """Register a route with an explicit HTTP method (defaults to GET)."""
-        return self._method_decorator(method)(path)
+        def decorator(loader: Callable[[list[Any]], Any]) -> Callable[[list[Any]], Any]:
+            self._app.data_loader(name, loader)
+            return loader
+
+        return decorator
 
     def run(self) -> None:
         """Run the HTTP server using the configured routes."""
@@ -521,3 +755,70 @@ class App:
     def into_router(self) -> Any:
         """Build the underlying Axum router (for embedding/testing)."""
         return self._app.into_router()
+
+
+class RouteGroup(_RouteRegistrar):
+    """Routes registered under a shared path prefix, created with :meth:`App.group`.
+
+    Tags and dependencies accumulate and hooks run outermost group first; nested groups
+    (:meth:`group`) inherit everything configured on their parent.
+
+    Example::
+
+        admin = app.group("/admin", tags=["admin"], hooks={"pre_handler": [require_admin]})
+
+
+        @admin.get("/users")
+        async def list_users() -> list[dict]: ...
+    """
+
+    def __init__(
+        self,
+        register: Callable[..., Callable[..., Any]],
+        group: _RouteGroup,
+        *,
+        tags: Sequence[str] | None = None,
+        dependencies: Sequence[str] | None = None,
+        hooks: Mapping[str, Sequence[Callable[..., Any]]] | None = None,
+    ) -> None:
+        """Wrap the native ``group``; ``register`` adds its routes to the owning :class:`App`."""
+        for tag in tags or ():
+            group = group.tag(tag)
+        if dependencies:
+            group = group.handler_dependencies(list(dependencies))
+        self._register = register
+        self._group = group
+        self._hooks = hooks or {}
+
+    @property
+    def prefix(self) -> str:
+        """The path prefix shared by the routes of this group."""
+        return self._group.prefix()
+
+    def add_route(
+        self,
+        method: str,
+        path: str,
+        handler: Callable[..., Any],
+        *,
+        hooks: Mapping[str, Sequence[Callable[..., Any]]] | None = None,
+    ) -> Callable[..., Any]:
+        """Register ``handler`` for ``method`` and ``path`` under the group and return it unchanged."""
+        return self._register(method, path, handler, hooks=_merge_hooks(self._hooks, hooks), group=self._group)
+
+    def group(
+        self,
+        prefix: str,
+        *,
+        tags: Sequence[str] | None = None,
+        dependencies: Sequence[str] | None = None,
+        hooks: Mapping[str, Sequence[Callable[..., Any]]] | None = None,
+    ) -> RouteGroup:
+        """Start a group nested under this one."""
+        return RouteGroup(
+            self._register,
+            self._group.group(prefix),
+            tags=tags,
+            dependencies=dependencies,
+            hooks=_merge_hooks(self._hooks, hooks),
+        )
@@ -188,6 +188,31 @@ class App:
 
         return _decorator
 
+    def on_startup(self, hook: Callable[..., Any]) -> App:
+        """Called once before the listener binds; a failing hook aborts boot."""
+        self._registrations.append(("on_startup", (getattr(hook, "__name__", "on_startup"),), hook))
+        return self
+
+    def on_shutdown(self, hook: Callable[..., Any]) -> App:
+        """Called once after graceful shutdown completes, in reverse registration order."""
+        self._registrations.append(("on_shutdown", (getattr(hook, "__name__", "on_shutdown"),), hook))
+        return self
+
+    def health_check(self, name: str, check: Callable[..., Any]) -> App:
+        """Readiness check run on every readiness probe under its own timeout; an error reports not ready."""
+        self._registrations.append(("health_check", (name,), check))
+        return self
+
+    def rate_limit_key_extractor(self, extractor: Callable[..., Any]) -> App:
+        """Derives the bucket key for custom-keyed rate limits; returning nothing falls back to the client IP."""
+        self._registrations.append(("rate_limit_key_extractor", (), extractor))
+        return self
+
+    def data_loader(self, name: str, loader: Callable[..., Any]) -> App:
+        """Named batch loader for GraphQL field loaders; returns one value per key, in order."""
+        self._registrations.append(("data_loader", (name,), loader))
+        return self
+
     def run(self) -> None:
         """Run the HTTP server using the configured routes.
 
@@ -0,0 +1,28 @@
+"""Batch loaders for GraphQL field loaders are named functions registered on the app."""
+
+from typing import Any
+
+import pytest
+
+from spikard import App
+
+
+def test_data_loaders_are_handed_to_the_native_app() -> None:
+    """Sync and async loaders are accepted by the native app and returned unchanged."""
+    app = App()
+
+    @app.data_loader("user")
+    def users(keys: list[Any]) -> list[Any]:
+        return [{"id": key} for key in keys]
+
+    @app.data_loader("post")
+    async def posts(keys: list[Any]) -> list[Any]:
+        return [None for _ in keys]
+
+    @app.on_startup
+    def stop() -> None:
+        raise RuntimeError("stop before binding")
+
+    with pytest.raises(RuntimeError, match="stop before binding"):
+        app.run()
+    assert users(["u1"]) == [{"id": "u1"}]
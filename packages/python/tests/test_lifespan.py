@@ -0,0 +1,36 @@
+"""Lifespan hooks: a failing startup hook aborts boot before the listener binds."""
+
+import pytest
+
+from spikard import App
+
+
+def test_failing_startup_hook_aborts_boot() -> None:
+    """App.run raises the startup hook's error and skips the remaining hooks."""
+    app = App()
+    ran: list[str] = []
+
+    @app.on_startup
+    def connect() -> None:
+        ran.append("connect")
+        raise RuntimeError("database unreachable")
+
+    @app.on_startup
+    def warm_cache() -> None:
+        ran.append("warm_cache")
+
+    with pytest.raises(RuntimeError, match="startup hook 'connect' failed: RuntimeError: database unreachable"):
+        app.run()
+    assert ran == ["connect"]
+
+
+def test_failing_async_startup_hook_aborts_boot() -> None:
+    """Coroutine startup hooks are awaited, and their errors abort boot too."""
+    app = App()
+
+    @app.on_startup
+    async def connect() -> None:
+        raise RuntimeError("database unreachable")
+
+    with pytest.raises(RuntimeError, match="startup hook 'connect' failed: RuntimeError: database unreachable"):
+        app.run()
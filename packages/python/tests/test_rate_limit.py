@@ -0,0 +1,35 @@
+"""Rate-limit key extractors are plain synchronous functions registered on the app."""
+
+from typing import Any
+
+import pytest
+
+from spikard import App
+
+
+def test_rate_limit_key_extractor_is_handed_to_the_native_app() -> None:
+    """The registration is accepted by the native app and the function is returned unchanged."""
+    app = App()
+
+    @app.rate_limit_key_extractor
+    def tenant(request: dict[str, Any]) -> str | None:
+        return request["headers"].get("x-tenant")
+
+    @app.on_startup
+    def stop() -> None:
+        raise RuntimeError("stop before binding")
+
+    with pytest.raises(RuntimeError, match="stop before binding"):
+        app.run()
+    assert tenant({"headers": {"x-tenant": "acme"}}) == "acme"
+
+
+def test_async_rate_limit_key_extractor_is_rejected() -> None:
+    """Keys are computed synchronously, so coroutine functions are refused at registration."""
+    app = App()
+
+    async def tenant(request: dict[str, Any]) -> str | None:
+        return None
+
+    with pytest.raises(TypeError, match="synchronous"):
+        app.rate_limit_key_extractor(tenant)
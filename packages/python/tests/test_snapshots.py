@@ -0,0 +1,61 @@
+"""Snapshot assertions share their file format and update modes with the Rust test client."""
+
+import json
+from pathlib import Path
+
+import pytest
+
+from spikard import (
+    ResponseSnapshot,
+    SnapshotOptions,
+    assert_snapshot_file,
+    check_snapshot,
+    snapshot_path,
+)
+
+REQUEST_ID = "7b0d5c1e-3f1a-4c55-9a7e-2b8f4d6e1a90"
+
+
+def _response(name: str) -> ResponseSnapshot:
+    body = json.dumps({"id": REQUEST_ID, "name": name}).encode()
+    return ResponseSnapshot(200, {"content-type": "application/json", "x-request-id": REQUEST_ID}, body)
+
+
+def test_snapshots_use_the_shared_format() -> None:
+    """Selected headers and the body are serialized with sorted keys and redactions applied."""
+    options = SnapshotOptions(headers=["content-type"], redact=["/body/name"], redact_uuids=True)
+
+    document = json.loads(_response("Ada").to_snapshot_json(options))
+
+    assert document == {
+        "body": {"id": "[UUID]", "name": "[REDACTED]"},
+        "headers": {"content-type": "application/json"},
+        "status": 200,
+    }
+
+
+def test_snapshot_files_follow_the_update_mode(tmp_path: Path) -> None:
+    """Missing files are written, matches pass and mismatches report both versions."""
+    path = snapshot_path(str(tmp_path), "test_users.py", "get_user")
+    assert path == str(tmp_path / "snapshots" / "get_user.snap.json")
+
+    assert check_snapshot(_response("Ada"), path, update="never").status == "mismatched"
+    assert check_snapshot(_response("Ada"), path, update="missing").status == "created"
+    assert check_snapshot(_response("Ada"), path, update="never").status == "matched"
+
+    mismatched = check_snapshot(_response("Grace"), path, update="missing")
+    assert mismatched.status == "mismatched"
+    assert mismatched.expected is not None
+    assert "Ada" in mismatched.expected
+    assert mismatched.actual is not None
+    assert "Grace" in mismatched.actual
+
+
+def test_assert_snapshot_file_raises_with_a_diff(tmp_path: Path) -> None:
+    """A response that differs from its snapshot raises AssertionError holding a line diff."""
+    path = snapshot_path(str(tmp_path), "test_users.py", "get_user")
+    check_snapshot(_response("Ada"), path, update="always")
+
+    assert assert_snapshot_file(_response("Ada"), path).status == "matched"
+    with pytest.raises(AssertionError, match=r'\+ +"name": "Grace"'):
+        assert_snapshot_file(_response("Grace"), path)
@@ -2030,6 +2030,314 @@ impl ApiKeyConfig {
     }
 }
 
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+#[magnus::wrap(class = "Spikard::Claims")]
+pub struct Claims {
+    sub: String,
+    exp: usize,
+    iat: Option<usize>,
+    nbf: Option<usize>,
+    aud: Option<Vec<String>>,
+    iss: Option<String>,
+}
+
+unsafe impl IntoValueFromNative for Claims {}
+
+impl magnus::TryConvert for Claims {
+    fn try_convert(val: magnus::Value) -> Result<Self, magnus::Error> {
+        if let Ok(r) = <&Claims as magnus::TryConvert>::try_convert(val) {
+            return Ok(r.clone());
+        }
+        let json_str: String = if let Ok(s) = <String as magnus::TryConvert>::try_convert(val) {
+            s
+        } else {
+            val.funcall::<_, _, String>("to_json", ()).map_err(|e| {
+                magnus::Error::new(
+                    unsafe { magnus::Ruby::get_unchecked() }.exception_type_error(),
+                    format!("no implicit conversion into Claims: {}", e),
+                )
+            })?
+        };
+        serde_json::from_str::<Claims>(&json_str).map_err(|e| {
+            magnus::Error::new(
+                unsafe { magnus::Ruby::get_unchecked() }.exception_type_error(),
+                format!("failed to deserialize Claims: {}", e),
+            )
+        })
+    }
+}
+
+unsafe impl TryConvertOwned for Claims {}
+
+impl Default for Claims {
+    fn default() -> Self {
+        Self {
+            sub: String::new(),
+            exp: 0,
+            iat: None,
+            nbf: None,
+            aud: None,
+            iss: None,
+        }
+    }
+}
+
+impl Claims {
+    fn new(args: &[magnus::Value]) -> Result<Self, magnus::Error> {
+        let ruby = unsafe { magnus::Ruby::get_unchecked() };
+        let args = magnus::scan_args::scan_args::<(), (Option<magnus::RHash>,), (), (), (), ()>(args)?;
+        let (kwargs_opt,) = args.optional;
+        let kwargs = kwargs_opt.unwrap_or_else(|| ruby.hash_new());
+        Ok(Self {
+            sub: kwargs
+                .get(ruby.to_symbol("sub"))
+                .and_then(|v| String::try_convert(v).ok())
+                .unwrap_or(String::new()),
+            exp: kwargs
+                .get(ruby.to_symbol("exp"))
+                .and_then(|v| usize::try_convert(v).ok())
+                .unwrap_or(0),
+            iat: kwargs
+                .get(ruby.to_symbol("iat"))
+                .and_then(|v| usize::try_convert(v).ok()),
+            nbf: kwargs
+                .get(ruby.to_symbol("nbf"))
+                .and_then(|v| usize::try_convert(v).ok()),
+            aud: kwargs
+                .get(ruby.to_symbol("aud"))
+                .and_then(|v| <Vec<String>>::try_convert(v).ok()),
+            iss: kwargs
+                .get(ruby.to_symbol("iss"))
+                .and_then(|v| String::try_convert(v).ok()),
+        })
+    }
+
+    fn sub(&self) -> String {
+        self.sub.clone()
+    }
+
+    fn exp(&self) -> usize {
+        self.exp
+    }
+
+    fn iat(&self) -> Option<usize> {
+        self.iat
+    }
+
+    fn nbf(&self) -> Option<usize> {
+        self.nbf
+    }
+
+    fn aud(&self) -> Option<Vec<String>> {
+        self.aud.clone()
+    }
+
+    fn iss(&self) -> Option<String> {
+        self.iss.clone()
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+#[magnus::wrap(class = "Spikard::ClientInfo")]
+pub struct ClientInfo {
+    ip: Option<String>,
+    scheme: String,
+    host: Option<String>,
+}
+
+unsafe impl IntoValueFromNative for ClientInfo {}
+
+impl magnus::TryConvert for ClientInfo {
+    fn try_convert(val: magnus::Value) -> Result<Self, magnus::Error> {
+        if let Ok(r) = <&ClientInfo as magnus::TryConvert>::try_convert(val) {
+            return Ok(r.clone());
+        }
+        let json_str: String = if let Ok(s) = <String as magnus::TryConvert>::try_convert(val) {
+            s
+        } else {
+            val.funcall::<_, _, String>("to_json", ()).map_err(|e| {
+                magnus::Error::new(
+                    unsafe { magnus::Ruby::get_unchecked() }.exception_type_error(),
+                    format!("no implicit conversion into ClientInfo: {}", e),
+                )
+            })?
+        };
+        serde_json::from_str::<ClientInfo>(&json_str).map_err(|e| {
+            magnus::Error::new(
+                unsafe { magnus::Ruby::get_unchecked() }.exception_type_error(),
+                format!("failed to deserialize ClientInfo: {}", e),
+            )
+        })
+    }
+}
+
+unsafe impl TryConvertOwned for ClientInfo {}
+
+impl Default for ClientInfo {
+    fn default() -> Self {
+        Self {
+            ip: None,
+            scheme: String::new(),
+            host: None,
+        }
+    }
+}
+
+impl ClientInfo {
+    fn new(args: &[magnus::Value]) -> Result<Self, magnus::Error> {
+        let ruby = unsafe { magnus::Ruby::get_unchecked() };
+        let args = magnus::scan_args::scan_args::<(), (Option<magnus::RHash>,), (), (), (), ()>(args)?;
+        let (kwargs_opt,) = args.optional;
+        let kwargs = kwargs_opt.unwrap_or_else(|| ruby.hash_new());
+        Ok(Self {
+            ip: kwargs
+                .get(ruby.to_symbol("ip"))
+                .and_then(|v| String::try_convert(v).ok()),
+            scheme: kwargs
+                .get(ruby.to_symbol("scheme"))
+                .and_then(|v| String::try_convert(v).ok())
+                .unwrap_or(String::new()),
+            host: kwargs
+                .get(ruby.to_symbol("host"))
+                .and_then(|v| String::try_convert(v).ok()),
+        })
+    }
+
+    fn ip(&self) -> Option<String> {
+        self.ip.clone()
+    }
+
+    fn scheme(&self) -> String {
+        self.scheme.clone()
+    }
+
+    fn host(&self) -> Option<String> {
+        self.host.clone()
+    }
+
+    fn origin(&self) -> Option<String> {
+        spikard::ClientInfo::from(self.clone()).origin()
+    }
+}
+
+#[derive(Clone)]
+#[magnus::wrap(class = "Spikard::Session")]
+pub struct Session {
+    inner: spikard::Session,
+}
+
+unsafe impl IntoValueFromNative for Session {}
+
+impl magnus::TryConvert for Session {
+    fn try_convert(val: magnus::Value) -> Result<Self, magnus::Error> {
+        let r: &Session = magnus::TryConvert::try_convert(val)?;
+        Ok(r.clone())
+    }
+}
+
+unsafe impl TryConvertOwned for Session {}
+
+impl Session {
+    fn from_data(data: String) -> Result<Session, magnus::Error> {
+        let data = serde_json::from_str(&data).map_err(|e| {
+            magnus::Error::new(
+                unsafe { magnus::Ruby::get_unchecked() }.exception_arg_error(),
+                e.to_string(),
+            )
+        })?;
+        Ok(Self {
+            inner: spikard::Session::from_data(data),
+        })
+    }
+
+    fn is_new(&self) -> bool {
+        self.inner.is_new()
+    }
+
+    fn get_value(&self, key: String) -> Option<String> {
+        self.inner.get_value(&key).map(|v| v.to_string())
+    }
+
+    fn insert(&self, key: String, value: String) -> Result<(), magnus::Error> {
+        let value: serde_json::Value = serde_json::from_str(&value).map_err(|e| {
+            magnus::Error::new(
+                unsafe { magnus::Ruby::get_unchecked() }.exception_arg_error(),
+                e.to_string(),
+            )
+        })?;
+        self.inner.insert(key, value).map_err(|e| {
+            magnus::Error::new(
+                unsafe { magnus::Ruby::get_unchecked() }.exception_arg_error(),
+                e.to_string(),
+            )
+        })
+    }
+
+    fn remove(&self, key: String) -> Option<String> {
+        self.inner.remove(&key).map(|v| v.to_string())
+    }
+
+    fn clear(&self) {
+        self.inner.clear()
+    }
+
+    fn replace(&self, data: String) -> Result<(), magnus::Error> {
+        let data = serde_json::from_str(&data).map_err(|e| {
+            magnus::Error::new(
+                unsafe { magnus::Ruby::get_unchecked() }.exception_arg_error(),
+                e.to_string(),
+            )
+        })?;
+        self.inner.replace(data);
+        Ok(())
+    }
+
+    fn data(&self) -> String {
+        serde_json::to_string(&self.inner.data()).unwrap_or_default()
+    }
+
+    fn regenerate(&self) {
+        self.inner.regenerate()
+    }
+
+    fn destroy(&self) {
+        self.inner.destroy()
+    }
+
+    fn binding_headers(&self) -> HashMap<String, String> {
+        self.inner.binding_headers()
+    }
+}
+
+#[derive(Clone)]
+#[magnus::wrap(class = "Spikard::CsrfToken")]
+pub struct CsrfToken {
+    inner: spikard::CsrfToken,
+}
+
+unsafe impl IntoValueFromNative for CsrfToken {}
+
+impl magnus::TryConvert for CsrfToken {
+    fn try_convert(val: magnus::Value) -> Result<Self, magnus::Error> {
+        let r: &CsrfToken = magnus::TryConvert::try_convert(val)?;
+        Ok(r.clone())
+    }
+}
+
+unsafe impl TryConvertOwned for CsrfToken {}
+
+impl CsrfToken {
+    fn new(token: String) -> Self {
+        Self {
+            inner: spikard::CsrfToken(token),
+        }
+    }
+
+    fn as_str(&self) -> String {
+        self.inner.as_str().to_string()
+    }
+}
+
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 #[magnus::wrap(class = "Spikard::StaticFilesConfig")]
 pub struct StaticFilesConfig {
@@ -2447,6 +2755,84 @@ impl RouteBuilder {
     }
 }
 
+#[derive(Clone)]
+#[magnus::wrap(class = "Spikard::RouteGroup")]
+pub struct RouteGroup {
+    inner: Arc<spikard::RouteGroupScope>,
+}
+
+unsafe impl IntoValueFromNative for RouteGroup {}
+
+impl magnus::TryConvert for RouteGroup {
+    fn try_convert(val: magnus::Value) -> Result<Self, magnus::Error> {
+        let r: &RouteGroup = magnus::TryConvert::try_convert(val)?;
+        Ok(r.clone())
+    }
+}
+
+unsafe impl TryConvertOwned for RouteGroup {}
+
+impl RouteGroup {
+    fn new(prefix: String) -> Self {
+        Self {
+            inner: Arc::new(spikard::RouteGroupScope::new(&prefix)),
+        }
+    }
+
+    fn prefix(&self) -> String {
+        self.inner.prefix().to_string()
+    }
+
+    fn path(&self, path: String) -> String {
+        self.inner.path(&path)
+    }
+
+    fn group(&self, prefix: String) -> RouteGroup {
+        Self {
+            inner: Arc::new(self.inner.nested(&prefix)),
+        }
+    }
+
+    fn tag(&self, tag: String) -> RouteGroup {
+        let mut scope = (*self.inner).clone();
+        scope.tag(tag);
+        Self { inner: Arc::new(scope) }
+    }
+
+    fn handler_dependencies(&self, dependencies: Vec<String>) -> RouteGroup {
+        let mut scope = (*self.inner).clone();
+        scope.handler_dependencies(dependencies);
+        Self { inner: Arc::new(scope) }
+    }
+
+    fn cors(&self, cors: CorsConfig) -> RouteGroup {
+        let mut scope = (*self.inner).clone();
+        scope.cors(cors.into());
+        Self { inner: Arc::new(scope) }
+    }
+
+    fn rate_limit(&self, rate_limit: RateLimitConfig) -> RouteGroup {
+        let mut scope = (*self.inner).clone();
+        scope.rate_limit(rate_limit.into());
+        Self { inner: Arc::new(scope) }
+    }
+
+    fn auth(&self, jwt: Option<JwtConfig>, api_key: Option<ApiKeyConfig>) -> RouteGroup {
+        let mut scope = (*self.inner).clone();
+        scope.auth(spikard::RouteAuthConfig {
+            jwt: jwt.map(Into::into),
+            api_key: api_key.map(Into::into),
+        });
+        Self { inner: Arc::new(scope) }
+    }
+
+    fn apply(&self, builder: RouteBuilder) -> RouteBuilder {
+        RouteBuilder {
+            inner: Arc::new(self.inner.apply((*builder.inner).clone())),
+        }
+    }
+}
+
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 #[magnus::wrap(class = "Spikard::JsonRpcMethodInfo")]
 pub struct JsonRpcMethodInfo {
@@ -3562,6 +3948,130 @@ impl ResponseSnapshot {
         };
         core_self.header(&name).map(|v| v.to_owned())
     }
+
+    fn to_snapshot_json(&self, args: &[magnus::Value]) -> Result<String, Error> {
+        let args = magnus::scan_args::scan_args::<(), (Option<Option<SnapshotOptions>>,), (), (), (), ()>(args)?;
+        let (options,) = args.optional;
+        spikard_http::ResponseSnapshot::from(self.clone())
+            .to_snapshot_json(&options.flatten().unwrap_or_default().into())
+            .map_err(|e| {
+                magnus::Error::new(
+                    unsafe { Ruby::get_unchecked() }.exception_runtime_error(),
+                    e.to_string(),
+                )
+            })
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+#[magnus::wrap(class = "Spikard::SnapshotOptions")]
+pub struct SnapshotOptions {
+    headers: Vec<String>,
+    redact: Vec<String>,
+    redact_uuids: bool,
+    redact_timestamps: bool,
+}
+
+unsafe impl IntoValueFromNative for SnapshotOptions {}
+
+impl magnus::TryConvert for SnapshotOptions {
+    fn try_convert(val: magnus::Value) -> Result<Self, magnus::Error> {
+        if let Ok(r) = <&SnapshotOptions as magnus::TryConvert>::try_convert(val) {
+            return Ok(r.clone());
+        }
+        let json_str: String = if let Ok(s) = <String as magnus::TryConvert>::try_convert(val) {
+            s
+        } else {
+            val.funcall::<_, _, String>("to_json", ()).map_err(|e| {
+                magnus::Error::new(
+                    unsafe { magnus::Ruby::get_unchecked() }.exception_type_error(),
+                    format!("no implicit conversion into SnapshotOptions: {}", e),
+                )
+            })?
+        };
+        serde_json::from_str::<SnapshotOptions>(&json_str).map_err(|e| {
+            magnus::Error::new(
+                unsafe { magnus::Ruby::get_unchecked() }.exception_type_error(),
+                format!("failed to deserialize SnapshotOptions: {}", e),
+            )
+        })
+    }
+}
+
+unsafe impl TryConvertOwned for SnapshotOptions {}
+
+impl Default for SnapshotOptions {
+    fn default() -> Self {
+        spikard_http::testing::SnapshotOptions::default().into()
+    }
+}
+
+impl SnapshotOptions {
+    fn new(args: &[magnus::Value]) -> Result<Self, magnus::Error> {
+        let ruby = unsafe { magnus::Ruby::get_unchecked() };
+        let args = magnus::scan_args::scan_args::<(), (Option<magnus::RHash>,), (), (), (), ()>(args)?;
+        let (kwargs_opt,) = args.optional;
+        let kwargs = kwargs_opt.unwrap_or_else(|| ruby.hash_new());
+        Ok(Self {
+            headers: kwargs
+                .get(ruby.to_symbol("headers"))
+                .and_then(|v| <Vec<String>>::try_convert(v).ok())
+                .unwrap_or_else(|| Self::default().headers),
+            redact: kwargs
+                .get(ruby.to_symbol("redact"))
+                .and_then(|v| <Vec<String>>::try_convert(v).ok())
+                .unwrap_or_default(),
+            redact_uuids: kwargs
+                .get(ruby.to_symbol("redact_uuids"))
+                .and_then(|v| bool::try_convert(v).ok())
+                .unwrap_or_default(),
+            redact_timestamps: kwargs
+                .get(ruby.to_symbol("redact_timestamps"))
+                .and_then(|v| bool::try_convert(v).ok())
+                .unwrap_or_default(),
+        })
+    }
+
+    fn headers(&self) -> Vec<String> {
+        self.headers.clone()
+    }
+
+    fn redact(&self) -> Vec<String> {
+        self.redact.clone()
+    }
+
+    fn redact_uuids(&self) -> bool {
+        self.redact_uuids
+    }
+
+    fn redact_timestamps(&self) -> bool {
+        self.redact_timestamps
+    }
+}
+
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize)]
+#[magnus::wrap(class = "Spikard::SnapshotOutcome")]
+pub struct SnapshotOutcome {
+    status: String,
+    expected: Option<String>,
+    actual: Option<String>,
+}
+
+unsafe impl IntoValueFromNative for SnapshotOutcome {}
+
+impl SnapshotOutcome {
+    fn status(&self) -> String {
+        self.status.clone()
+    }
+
+    fn expected(&self) -> Option<String> {
+        self.expected.clone()
+    }
+
+    fn actual(&self) -> Option<String> {
+        self.actual.clone()
+    }
 }
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
@@ -3705,6 +4215,25 @@ impl GraphQLSubscriptionSnapshot {
     }
 }
 
+#[magnus::wrap(class = "Spikard::DependencyOverrideGuard")]
+pub struct DependencyOverrideGuard {
+    key: String,
+    inner: Mutex<Option<spikard_core::di::DependencyOverrideGuard>>,
+}
+
+impl DependencyOverrideGuard {
+    fn key(&self) -> String {
+        self.key.clone()
+    }
+
+    fn restore(&self) {
+        self.inner
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .take();
+    }
+}
+
 #[derive(Clone)]
 #[magnus::wrap(class = "Spikard::TestClient")]
 pub struct TestClient {
@@ -3723,6 +4252,39 @@ impl magnus::TryConvert for TestClient {
 unsafe impl TryConvertOwned for TestClient {}
 
 impl TestClient {
+    fn override_dependency(&self, key: String, value: String) -> Result<(), Error> {
+        let value = serde_json::from_str(&value)
+            .map_err(|e| magnus::Error::new(unsafe { Ruby::get_unchecked() }.exception_arg_error(), e.to_string()))?;
+        self.inner
+            .override_dependency_value(&key, value)
+            .map_err(|e| magnus::Error::new(unsafe { Ruby::get_unchecked() }.exception_arg_error(), e))
+    }
+
+    fn override_dependency_scope(&self, key: String, value: String) -> Result<DependencyOverrideGuard, Error> {
+        let value = serde_json::from_str(&value)
+            .map_err(|e| magnus::Error::new(unsafe { Ruby::get_unchecked() }.exception_arg_error(), e.to_string()))?;
+        let guard = self
+            .inner
+            .override_dependency_scope(&key, value)
+            .map_err(|e| magnus::Error::new(unsafe { Ruby::get_unchecked() }.exception_arg_error(), e))?;
+        Ok(DependencyOverrideGuard {
+            key,
+            inner: Mutex::new(Some(guard)),
+        })
+    }
+
+    fn restore_dependency(&self, key: String) -> Result<(), Error> {
+        self.inner
+            .restore_dependency(&key)
+            .map_err(|e| magnus::Error::new(unsafe { Ruby::get_unchecked() }.exception_runtime_error(), e))
+    }
+
+    fn clear_dependency_overrides(&self) -> Result<(), Error> {
+        self.inner
+            .clear_dependency_overrides()
+            .map_err(|e| magnus::Error::new(unsafe { Ruby::get_unchecked() }.exception_runtime_error(), e))
+    }
+
     fn graphql_at_async(
         &self,
         endpoint: String,
@@ -4116,6 +4678,63 @@ fn schema_full() -> FullSchemaConfig {
     spikard::schema_full().into()
 }
 
+fn check_snapshot(args: &[magnus::Value]) -> Result<SnapshotOutcome, Error> {
+    let args = magnus::scan_args::scan_args::<
+        (ResponseSnapshot, String),
+        (Option<Option<SnapshotOptions>>, Option<Option<String>>),
+        (),
+        (),
+        (),
+        (),
+    >(args)?;
+    let (response, path) = args.required;
+    let (options, update) = args.optional;
+    let update = match update.flatten() {
+        Some(mode) => serde_json::from_value(serde_json::Value::String(mode)).map_err(|e| {
+            magnus::Error::new(
+                unsafe { Ruby::get_unchecked() }.exception_arg_error(),
+                format!("Invalid snapshot update mode: {e}"),
+            )
+        })?,
+        None => spikard_http::testing::SnapshotUpdate::from_env(),
+    };
+    spikard_http::testing::check_snapshot(
+        &response.into(),
+        std::path::Path::new(&path),
+        &options.flatten().unwrap_or_default().into(),
+        update,
+    )
+    .map(Into::into)
+    .map_err(|e| {
+        magnus::Error::new(
+            unsafe { Ruby::get_unchecked() }.exception_runtime_error(),
+            e.to_string(),
+        )
+    })
+}
+
+fn snapshot_path(root_dir: String, file: String, name: String) -> String {
+    spikard_http::testing::snapshot_path(&root_dir, &file, &name)
+        .to_string_lossy()
+        .into_owned()
+}
+
+fn assert_snapshot_file(args: &[magnus::Value]) -> Result<SnapshotOutcome, Error> {
+    let args =
+        magnus::scan_args::scan_args::<(ResponseSnapshot, String), (Option<Option<SnapshotOptions>>,), (), (), (), ()>(
+            args,
+        )?;
+    let (response, path) = args.required;
+    let (options,) = args.optional;
+    spikard_http::testing::assert_snapshot_file(
+        &response.into(),
+        std::path::Path::new(&path),
+        &options.flatten().unwrap_or_default().into(),
+    )
+    .map(Into::into)
+    .map_err(|message| magnus::Error::new(unsafe { Ruby::get_unchecked() }.exception_runtime_error(), message))
+}
+
 #[allow(clippy::redundant_closure, clippy::useless_conversion)]
 impl From<spikard::UploadFile> for UploadFile {
     fn from(val: spikard::UploadFile) -> Self {
@@ -4619,6 +5238,56 @@ impl From<spikard::ApiKeyConfig> for ApiKeyConfig {
     }
 }
 
+#[allow(clippy::redundant_closure, clippy::useless_conversion)]
+impl From<Claims> for spikard::Claims {
+    fn from(val: Claims) -> Self {
+        Self {
+            sub: val.sub,
+            exp: val.exp,
+            iat: val.iat,
+            nbf: val.nbf,
+            aud: val.aud.map(|v| v.into_iter().collect()),
+            iss: val.iss,
+        }
+    }
+}
+
+#[allow(clippy::redundant_closure, clippy::useless_conversion)]
+impl From<spikard::Claims> for Claims {
+    fn from(val: spikard::Claims) -> Self {
+        Self {
+            sub: val.sub.to_string(),
+            exp: val.exp,
+            iat: val.iat,
+            nbf: val.nbf,
+            aud: val.aud.map(|v| v.into_iter().collect()),
+            iss: val.iss.map(|v| v.to_string()),
+        }
+    }
+}
+
+#[allow(clippy::redundant_closure, clippy::useless_conversion)]
+impl From<ClientInfo> for spikard::ClientInfo {
+    fn from(val: ClientInfo) -> Self {
+        Self {
+            ip: val.ip.and_then(|v| v.parse().ok()),
+            scheme: val.scheme,
+            host: val.host,
+        }
+    }
+}
+
+#[allow(clippy::redundant_closure, clippy::useless_conversion)]
+impl From<spikard::ClientInfo> for ClientInfo {
+    fn from(val: spikard::ClientInfo) -> Self {
+        Self {
+            ip: val.ip.map(|v| v.to_string()),
+            scheme: val.scheme.to_string(),
+            host: val.host.map(|v| v.to_string()),
+        }
+    }
+}
+
 #[allow(clippy::redundant_closure, clippy::useless_conversion)]
 impl From<StaticFilesConfig> for spikard::StaticFilesConfig {
     fn from(val: StaticFilesConfig) -> Self {
@@ -4890,6 +5559,47 @@ impl From<spikard_http::ServerInfo> for ServerInfo {
 }
 
 #[allow(clippy::redundant_closure, clippy::useless_conversion)]
+impl From<SnapshotOptions> for spikard_http::testing::SnapshotOptions {
+    fn from(val: SnapshotOptions) -> Self {
+        Self {
+            headers: val.headers,
+            redact: val.redact,
+            redact_uuids: val.redact_uuids,
+            redact_timestamps: val.redact_timestamps,
+        }
+    }
+}
+
+impl From<spikard_http::testing::SnapshotOptions> for SnapshotOptions {
+    fn from(val: spikard_http::testing::SnapshotOptions) -> Self {
+        Self {
+            headers: val.headers,
+            redact: val.redact,
+            redact_uuids: val.redact_uuids,
+            redact_timestamps: val.redact_timestamps,
+        }
+    }
+}
+
+impl From<spikard_http::testing::SnapshotOutcome> for SnapshotOutcome {
+    fn from(val: spikard_http::testing::SnapshotOutcome) -> Self {
+        let status = |status: &str| Self {
+            status: status.to_string(),
+            ..Self::default()
+        };
+        match val {
+            spikard_http::testing::SnapshotOutcome::Matched => status("matched"),
+            spikard_http::testing::SnapshotOutcome::Created => status("created"),
+            spikard_http::testing::SnapshotOutcome::Updated => status("updated"),
+            spikard_http::testing::SnapshotOutcome::Mismatched { expected, actual } => Self {
+                status: "mismatched".to_string(),
+                expected,
+                actual: Some(actual),
+            },
+        }
+    }
+}
+
 impl From<ResponseSnapshot> for spikard_http::ResponseSnapshot {
     fn from(val: ResponseSnapshot) -> Self {
         Self {
@@ -5402,6 +6112,64 @@ fn ruby_init(ruby: &Ruby) -> Result<(), Error> {
 
     class.define_method("header_name", method!(ApiKeyConfig::header_name, 0))?;
 
+    let class = module.define_class("Claims", ruby.class_object())?;
+
+    class.define_singleton_method("new", function!(Claims::new, -1))?;
+
+    class.define_method("sub", method!(Claims::sub, 0))?;
+
+    class.define_method("exp", method!(Claims::exp, 0))?;
+
+    class.define_method("iat", method!(Claims::iat, 0))?;
+
+    class.define_method("nbf", method!(Claims::nbf, 0))?;
+
+    class.define_method("aud", method!(Claims::aud, 0))?;
+
+    class.define_method("iss", method!(Claims::iss, 0))?;
+
+    let class = module.define_class("ClientInfo", ruby.class_object())?;
+
+    class.define_singleton_method("new", function!(ClientInfo::new, -1))?;
+
+    class.define_method("ip", method!(ClientInfo::ip, 0))?;
+
+    class.define_method("scheme", method!(ClientInfo::scheme, 0))?;
+
+    class.define_method("host", method!(ClientInfo::host, 0))?;
+
+    class.define_method("origin", method!(ClientInfo::origin, 0))?;
+
+    let class = module.define_class("Session", ruby.class_object())?;
+
+    class.define_singleton_method("from_data", function!(Session::from_data, 1))?;
+
+    class.define_method("is_new", method!(Session::is_new, 0))?;
+
+    class.define_method("get_value", method!(Session::get_value, 1))?;
+
+    class.define_method("insert", method!(Session::insert, 2))?;
+
+    class.define_method("remove", method!(Session::remove, 1))?;
+
+    class.define_method("clear", method!(Session::clear, 0))?;
+
+    class.define_method("replace", method!(Session::replace, 1))?;
+
+    class.define_method("data", method!(Session::data, 0))?;
+
+    class.define_method("regenerate", method!(Session::regenerate, 0))?;
+
+    class.define_method("destroy", method!(Session::destroy, 0))?;
+
+    class.define_method("binding_headers", method!(Session::binding_headers, 0))?;
+
+    let class = module.define_class("CsrfToken", ruby.class_object())?;
+
+    class.define_singleton_method("new", function!(CsrfToken::new, 1))?;
+
+    class.define_method("as_str", method!(CsrfToken::as_str, 0))?;
+
     let class = module.define_class("StaticFilesConfig", ruby.class_object())?;
 
     class.define_singleton_method("new", function!(StaticFilesConfig::new, -1))?;
@@ -5482,6 +6250,28 @@ fn ruby_init(ruby: &Ruby) -> Result<(), Error> {
 
     class.define_method("handler_dependencies", method!(RouteBuilder::handler_dependencies, 1))?;
 
+    let class = module.define_class("RouteGroup", ruby.class_object())?;
+
+    class.define_singleton_method("new", function!(RouteGroup::new, 1))?;
+
+    class.define_method("prefix", method!(RouteGroup::prefix, 0))?;
+
+    class.define_method("path", method!(RouteGroup::path, 1))?;
+
+    class.define_method("group", method!(RouteGroup::group, 1))?;
+
+    class.define_method("tag", method!(RouteGroup::tag, 1))?;
+
+    class.define_method("handler_dependencies", method!(RouteGroup::handler_dependencies, 1))?;
+
+    class.define_method("cors", method!(RouteGroup::cors, 1))?;
+
+    class.define_method("rate_limit", method!(RouteGroup::rate_limit, 1))?;
+
+    class.define_method("auth", method!(RouteGroup::auth, 2))?;
+
+    class.define_method("apply", method!(RouteGroup::apply, 1))?;
+
     let class = module.define_class("JsonRpcMethodInfo", ruby.class_object())?;
 
     class.define_singleton_method("new", function!(JsonRpcMethodInfo::new, -1))?;
@@ -5634,6 +6424,28 @@ fn ruby_init(ruby: &Ruby) -> Result<(), Error> {
 
     class.define_method("header", method!(ResponseSnapshot::header, 1))?;
 
+    class.define_method("to_snapshot_json", method!(ResponseSnapshot::to_snapshot_json, -1))?;
+
+    let class = module.define_class("SnapshotOptions", ruby.class_object())?;
+
+    class.define_singleton_method("new", function!(SnapshotOptions::new, -1))?;
+
+    class.define_method("headers", method!(SnapshotOptions::headers, 0))?;
+
+    class.define_method("redact", method!(SnapshotOptions::redact, 0))?;
+
+    class.define_method("redact_uuids", method!(SnapshotOptions::redact_uuids, 0))?;
+
+    class.define_method("redact_timestamps", method!(SnapshotOptions::redact_timestamps, 0))?;
+
+    let class = module.define_class("SnapshotOutcome", ruby.class_object())?;
+
+    class.define_method("status", method!(SnapshotOutcome::status, 0))?;
+
+    class.define_method("expected", method!(SnapshotOutcome::expected, 0))?;
+
+    class.define_method("actual", method!(SnapshotOutcome::actual, 0))?;
+
     let class = module.define_class("TestingSseEvent", ruby.class_object())?;
 
     class.define_singleton_method("new", function!(TestingSseEvent::new, -1))?;
@@ -5657,8 +6469,28 @@ fn ruby_init(ruby: &Ruby) -> Result<(), Error> {
         method!(GraphQLSubscriptionSnapshot::complete_received, 0),
     )?;
 
+    let class = module.define_class("DependencyOverrideGuard", ruby.class_object())?;
+
+    class.define_method("key", method!(DependencyOverrideGuard::key, 0))?;
+
+    class.define_method("restore", method!(DependencyOverrideGuard::restore, 0))?;
+
     let class = module.define_class("TestClient", ruby.class_object())?;
 
+    class.define_method("override_dependency", method!(TestClient::override_dependency, 2))?;
+
+    class.define_method(
+        "override_dependency_scope",
+        method!(TestClient::override_dependency_scope, 2),
+    )?;
+
+    class.define_method("restore_dependency", method!(TestClient::restore_dependency, 1))?;
+
+    class.define_method(
+        "clear_dependency_overrides",
+        method!(TestClient::clear_dependency_overrides, 0),
+    )?;
+
     class.define_method("graphql_at_async", method!(TestClient::graphql_at_async, 4))?;
 
     class.define_method("graphql_async", method!(TestClient::graphql_async, 3))?;
@@ -5683,6 +6515,12 @@ fn ruby_init(ruby: &Ruby) -> Result<(), Error> {
 
     module.define_module_function("schema_full", function!(schema_full, 0))?;
 
+    module.define_module_function("check_snapshot", function!(check_snapshot, -1))?;
+
+    module.define_module_function("snapshot_path", function!(snapshot_path, 3))?;
+
+    module.define_module_function("assert_snapshot_file", function!(assert_snapshot_file, -1))?;
+
     let graph_q_l_error_info_class = module.define_class("GraphQLErrorInfo", ruby.class_object())?;
     graph_q_l_error_info_class.define_method("status_code", magnus::method!(GraphQLErrorInfo::status_code, 0))?;
     graph_q_l_error_info_class.define_method("transient?", magnus::method!(GraphQLErrorInfo::transient, 0))?;
@@ -163,6 +163,134 @@ extern "C" fn ruby_proc_gvl_callback(data: *mut std::ffi::c_void) -> *mut std::f
     }
     std::ptr::null_mut()
 }
+/// Generated Magnus bridge for the `LifespanHook` contract.
+///
+/// Wraps a Ruby proc so it can be used as `Arc<dyn LifespanHook>`; raising fails
+/// the hook. Calls the proc with GVL acquired.
+pub struct RbLifespanHookBridge {
+    name: String,
+    proc_handle: Opaque<Value>,
+}
+
+impl RbLifespanHookBridge {
+    /// Create a bridge from a hook name and a Ruby proc.
+    pub fn new(name: String, proc_handle: Opaque<Value>) -> Self {
+        Self { name, proc_handle }
+    }
+}
+
+// SAFETY: Opaque<Value> is Send+Sync; calls acquire the GVL.
+unsafe impl Send for RbLifespanHookBridge {}
+unsafe impl Sync for RbLifespanHookBridge {}
+
+impl spikard::LifespanHook for RbLifespanHookBridge {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn run(&self, _context: spikard::LifespanContext) -> spikard::LifespanFuture {
+        let proc_handle = self.proc_handle.clone();
+        Box::pin(async move {
+            // Lifespan hooks run inside the runtime `app_run` drives after releasing
+            // the GVL, on the same OS thread, exactly like `RbHandlerBridge`.
+            call_ruby_proc_with_gvl(&proc_handle, "null")
+                .map(|_| ())
+                .map_err(|e| e.to_string())
+        })
+    }
+}
+/// Generated Magnus bridge for the `HealthCheck` contract.
+///
+/// Wraps a Ruby proc so it can be used as `Arc<dyn HealthCheck>`; raising
+/// reports the application as not ready. Calls the proc with GVL acquired.
+pub struct RbHealthCheckBridge {
+    name: String,
+    proc_handle: Opaque<Value>,
+}
+
+impl RbHealthCheckBridge {
+    /// Create a bridge from a check name and a Ruby proc.
+    pub fn new(name: String, proc_handle: Opaque<Value>) -> Self {
+        Self { name, proc_handle }
+    }
+}
+
+// SAFETY: Opaque<Value> is Send+Sync; calls acquire the GVL.
+unsafe impl Send for RbHealthCheckBridge {}
+unsafe impl Sync for RbHealthCheckBridge {}
+
+impl spikard::HealthCheck for RbHealthCheckBridge {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn check(&self, _context: spikard::HealthCheckContext) -> spikard::HealthCheckFuture {
+        let proc_handle = self.proc_handle.clone();
+        Box::pin(async move {
+            call_ruby_proc_with_gvl(&proc_handle, "null")
+                .map(|_| ())
+                .map_err(|e| e.to_string())
+        })
+    }
+}
+/// Generated Magnus bridge for the `RateLimitKeyExtractor` contract.
+///
+/// Wraps a Ruby proc so it can be used as `Arc<dyn RateLimitKeyExtractor>`. The proc
+/// receives the request as a Hash and returns the bucket key or nil; raising, or
+/// returning anything but a String, falls back to the client IP. Calls the proc
+/// with GVL acquired.
+pub struct RbRateLimitKeyExtractorBridge {
+    proc_handle: Opaque<Value>,
+}
+
+impl RbRateLimitKeyExtractorBridge {
+    /// Create a bridge from a Ruby proc.
+    pub fn new(proc_handle: Opaque<Value>) -> Self {
+        Self { proc_handle }
+    }
+}
+
+// SAFETY: Opaque<Value> is Send+Sync; calls acquire the GVL.
+unsafe impl Send for RbRateLimitKeyExtractorBridge {}
+unsafe impl Sync for RbRateLimitKeyExtractorBridge {}
+
+impl spikard::RateLimitKeyExtractor for RbRateLimitKeyExtractorBridge {
+    fn extract_key(&self, request: &spikard::Request<spikard::Body>) -> Option<String> {
+        let req_json = serde_json::to_string(&spikard::RateLimitKeyRequest::from_request(request)).ok()?;
+        let key_json = call_ruby_proc_with_gvl(&self.proc_handle, &req_json).ok()?;
+        serde_json::from_str::<Option<String>>(&key_json).ok().flatten()
+    }
+}
+/// Generated Magnus bridge for the `BatchLoader` contract.
+///
+/// Wraps a Ruby proc so it can be used as `Arc<dyn BatchLoader>`. The proc
+/// receives the batch of keys as an Array and returns one value (or nil) per key,
+/// in order; raising fails every key in the batch. Calls the proc with GVL acquired.
+pub struct RbBatchLoaderBridge {
+    proc_handle: Opaque<Value>,
+}
+
+impl RbBatchLoaderBridge {
+    /// Create a bridge from a Ruby proc.
+    pub fn new(proc_handle: Opaque<Value>) -> Self {
+        Self { proc_handle }
+    }
+}
+
+// SAFETY: Opaque<Value> is Send+Sync; calls acquire the GVL.
+unsafe impl Send for RbBatchLoaderBridge {}
+unsafe impl Sync for RbBatchLoaderBridge {}
+
+impl spikard::BatchLoader for RbBatchLoaderBridge {
+    fn load(&self, keys: Vec<serde_json::Value>) -> spikard::BatchLoadFuture {
+        let proc_handle = self.proc_handle.clone();
+        Box::pin(async move {
+            let keys_json = serde_json::to_string(&keys).map_err(|e| e.to_string())?;
+            let values_json = call_ruby_proc_with_gvl(&proc_handle, &keys_json).map_err(|e| e.to_string())?;
+            serde_json::from_str(&values_json).map_err(|e| e.to_string())
+        })
+    }
+}
 /// Drive `spikard::App::run` from Ruby.
 ///
 /// Each entry in `registrations` is a `[method_name, metadata_array, proc]` triple
@@ -366,6 +494,67 @@ pub fn app_run(registrations: Value) -> magnus::error::Result<()> {
                     .route(builder, handler)
                     .map_err(|e| magnus::Error::new(ruby.exception_runtime_error(), e.to_string()))?;
             }
+            "on_startup" => {
+                let meta_array = RArray::try_convert(
+                    entry_array
+                        .entry::<Value>(1 as isize)
+                        .map_err(|e| magnus::Error::new(ruby.exception_type_error(), e.to_string()))?,
+                )
+                .map_err(|e| magnus::Error::new(ruby.exception_type_error(), e.to_string()))?;
+                let name: String = meta_array
+                    .entry::<String>(0)
+                    .map_err(|e| magnus::Error::new(ruby.exception_type_error(), e.to_string()))?;
+                let bridge = RbLifespanHookBridge::new(name, proc_value.into());
+                let hook: Arc<dyn spikard::LifespanHook> = Arc::new(bridge);
+                owner.on_startup(hook);
+            }
+            "on_shutdown" => {
+                let meta_array = RArray::try_convert(
+                    entry_array
+                        .entry::<Value>(1 as isize)
+                        .map_err(|e| magnus::Error::new(ruby.exception_type_error(), e.to_string()))?,
+                )
+                .map_err(|e| magnus::Error::new(ruby.exception_type_error(), e.to_string()))?;
+                let name: String = meta_array
+                    .entry::<String>(0)
+                    .map_err(|e| magnus::Error::new(ruby.exception_type_error(), e.to_string()))?;
+                let bridge = RbLifespanHookBridge::new(name, proc_value.into());
+                let hook: Arc<dyn spikard::LifespanHook> = Arc::new(bridge);
+                owner.on_shutdown(hook);
+            }
+            "health_check" => {
+                let meta_array = RArray::try_convert(
+                    entry_array
+                        .entry::<Value>(1 as isize)
+                        .map_err(|e| magnus::Error::new(ruby.exception_type_error(), e.to_string()))?,
+                )
+                .map_err(|e| magnus::Error::new(ruby.exception_type_error(), e.to_string()))?;
+                let name: String = meta_array
+                    .entry::<String>(0)
+                    .map_err(|e| magnus::Error::new(ruby.exception_type_error(), e.to_string()))?;
+                let bridge = RbHealthCheckBridge::new(name, proc_value.into());
+                let check: Arc<dyn spikard::HealthCheck> = Arc::new(bridge);
+                owner.health_check(check);
+            }
+            "rate_limit_key_extractor" => {
+                let bridge = RbRateLimitKeyExtractorBridge::new(proc_value.into());
+                let extractor: Arc<dyn spikard::RateLimitKeyExtractor> = Arc::new(bridge);
+                owner.rate_limit_key_extractor(extractor);
+            }
+            "data_loader" => {
+                let meta_array = RArray::try_convert(
+                    entry_array
+                        .entry::<Value>(1 as isize)
+                        .map_err(|e| magnus::Error::new(ruby.exception_type_error(), e.to_string()))?,
+                )
+                .map_err(|e| magnus::Error::new(ruby.exception_type_error(), e.to_string()))?;
+                let name: String = meta_array
+                    .entry::<String>(0)
+                    .map_err(|e| magnus::Error::new(ruby.exception_type_error(), e.to_string()))?;
+                let bridge = RbBatchLoaderBridge::new(proc_value.into());
+                let loader: Arc<dyn spikard::BatchLoader> = Arc::new(bridge);
+                owner.data_loader(name, loader);
+            }
             _ => {
                 return Err(magnus::Error::new(
                     ruby.exception_arg_error(),
@@ -636,6 +825,67 @@ pub fn app_into_router(registrations: Value) -> magnus::error::Result<()> {
                     .route(builder, handler)
                     .map_err(|e| magnus::Error::new(ruby.exception_runtime_error(), e.to_string()))?;
             }
+            "on_startup" => {
+                let meta_array = RArray::try_convert(
+                    entry_array
+                        .entry::<Value>(1 as isize)
+                        .map_err(|e| magnus::Error::new(ruby.exception_type_error(), e.to_string()))?,
+                )
+                .map_err(|e| magnus::Error::new(ruby.exception_type_error(), e.to_string()))?;
+                let name: String = meta_array
+                    .entry::<String>(0)
+                    .map_err(|e| magnus::Error::new(ruby.exception_type_error(), e.to_string()))?;
+                let bridge = RbLifespanHookBridge::new(name, proc_value.into());
+                let hook: Arc<dyn spikard::LifespanHook> = Arc::new(bridge);
+                owner.on_startup(hook);
+            }
+            "on_shutdown" => {
+                let meta_array = RArray::try_convert(
+                    entry_array
+                        .entry::<Value>(1 as isize)
+                        .map_err(|e| magnus::Error::new(ruby.exception_type_error(), e.to_string()))?,
+                )
+                .map_err(|e| magnus::Error::new(ruby.exception_type_error(), e.to_string()))?;
+                let name: String = meta_array
+                    .entry::<String>(0)
+                    .map_err(|e| magnus::Error::new(ruby.exception_type_error(), e.to_string()))?;
+                let bridge = RbLifespanHookBridge::new(name, proc_value.into());
+                let hook: Arc<dyn spikard::LifespanHook> = Arc::new(bridge);
+                owner.on_shutdown(hook);
+            }
+            "health_check" => {
+                let meta_array = RArray::try_convert(
+                    entry_array
+                        .entry::<Value>(1 as isize)
+                        .map_err(|e| magnus::Error::new(ruby.exception_type_error(), e.to_string()))?,
+                )
+                .map_err(|e| magnus::Error::new(ruby.exception_type_error(), e.to_string()))?;
+                let name: String = meta_array
+                    .entry::<String>(0)
+                    .map_err(|e| magnus::Error::new(ruby.exception_type_error(), e.to_string()))?;
+                let bridge = RbHealthCheckBridge::new(name, proc_value.into());
+                let check: Arc<dyn spikard::HealthCheck> = Arc::new(bridge);
+                owner.health_check(check);
+            }
+            "rate_limit_key_extractor" => {
+                let bridge = RbRateLimitKeyExtractorBridge::new(proc_value.into());
+                let extractor: Arc<dyn spikard::RateLimitKeyExtractor> = Arc::new(bridge);
+                owner.rate_limit_key_extractor(extractor);
+            }
+            "data_loader" => {
+                let meta_array = RArray::try_convert(
+                    entry_array
+                        .entry::<Value>(1 as isize)
+                        .map_err(|e| magnus::Error::new(ruby.exception_type_error(), e.to_string()))?,
+                )
+                .map_err(|e| magnus::Error::new(ruby.exception_type_error(), e.to_string()))?;
+                let name: String = meta_array
+                    .entry::<String>(0)
+                    .map_err(|e| magnus::Error::new(ruby.exception_type_error(), e.to_string()))?;
+                let bridge = RbBatchLoaderBridge::new(proc_value.into());
+                let loader: Arc<dyn spikard::BatchLoader> = Arc::new(bridge);
+                owner.data_loader(name, loader);
+            }
             _ => {
                 return Err(magnus::Error::new(
                     ruby.exception_arg_error(),
@@ -43,6 +43,9 @@ module Spikard
     # Parameter names that are never treated as request-derived kwargs
     SPECIAL_PARAM_NAMES = Set.new(%w[self]).freeze
 
+    # Lifecycle phases a route can attach its own hooks to, in execution order
+    HOOK_PHASES = %i[on_request pre_validation pre_handler on_response on_error].freeze
+
     def initialize(config = nil)
       # Create a low-level App via the native extension interface
       # The low-level App is already available in the Spikard namespace
@@ -57,16 +60,22 @@ module Spikard
     end
 
     # Register a handler for method and path and return it unchanged.
-    def add_route(method, path, handler, body_type: nil)
+    #
+    # +hooks+ maps lifecycle phases (+:on_request+, +:pre_validation+, +:pre_handler+,
+    # +:on_response+, +:on_error+) to callables that run only for this route, after
+    # the global hooks of the same phase. +group+ is the native +Spikard::RouteGroup+
+    # whose prefix and settings apply to the route, as passed by +RouteGroup+.
+    def add_route(method, path, handler, body_type: nil, hooks: nil, group: nil)
       method_upper = method.upcase
       raise "Unsupported HTTP method: #{method.inspect}" unless VALID_METHODS.include?(method_upper)
 
-      spec = Introspection.introspect(handler, method_upper, path, body_type)
+      spec = Introspection.introspect(handler, method_upper, group ? group.path(path) : path, body_type)
 
       # Create a RouteBuilder and attach schemas for Rust-side validation.
       # The native RouteBuilder.new accepts the lowercase HTTP method string
       # (e.g. "post") and maps it to the core Method internally.
-      builder = create_route_builder_with_schemas(method_upper.downcase, path, spec)
+      builder = create_route_builder_with_schemas(method_upper.downcase, path, spec, group)
+      builder = attach_route_hooks(builder, hooks)
 
       # Build the adapter that bridges Rust RequestData → handler kwargs → response
       adapter = make_adapter(spec)
@@ -80,53 +89,116 @@ module Spikard
     end
 
     # Register a GET route.
-    def get(path, &block)
-      add_route("GET", path, block)
+    def get(path, hooks: nil, &block)
+      add_route("GET", path, block, hooks: hooks)
     end
 
     # Register a POST route with optional body DTO class.
-    def post(path, body: nil, &block)
-      add_route("POST", path, block, body_type: body)
+    def post(path, body: nil, hooks: nil, &block)
+      add_route("POST", path, block, body_type: body, hooks: hooks)
     end
 
     # Register a PUT route with optional body DTO class.
-    def put(path, body: nil, &block)
-      add_route("PUT", path, block, body_type: body)
+    def put(path, body: nil, hooks: nil, &block)
+      add_route("PUT", path, block, body_type: body, hooks: hooks)
     end
 
     # Register a PATCH route with optional body DTO class.
-    def patch(path, body: nil, &block)
-      add_route("PATCH", path, block, body_type: body)
+    def patch(path, body: nil, hooks: nil, &block)
+      add_route("PATCH", path, block, body_type: body, hooks: hooks)
     end
 
     # Register a DELETE route with optional body DTO class.
-    def delete(path, body: nil, &block)
-      add_route("DELETE", path, block, body_type: body)
+    def delete(path, body: nil, hooks: nil, &block)
+      add_route("DELETE", path, block, body_type: body, hooks: hooks)
     end
 
     # Register a HEAD route.
-    def head(path, &block)
-      add_route("HEAD", path, block)
+    def head(path, hooks: nil, &block)
+      add_route("HEAD", path, block, hooks: hooks)
     end
 
     # Register an OPTIONS route.
-    def options(path, &block)
-      add_route("OPTIONS", path, block)
+    def options(path, hooks: nil, &block)
+      add_route("OPTIONS", path, block, hooks: hooks)
     end
 
     # Register a TRACE route.
-    def trace(path, &block)
-      add_route("TRACE", path, block)
+    def trace(path, hooks: nil, &block)
+      add_route("TRACE", path, block, hooks: hooks)
     end
 
     # Register a CONNECT route.
-    def connect(path, &block)
-      add_route("CONNECT", path, block)
+    def connect(path, hooks: nil, &block)
+      add_route("CONNECT", path, block, hooks: hooks)
     end
 
     # Register a route with an explicit HTTP method (defaults to GET).
-    def route(path, method: "GET", body: nil, &block)
-      add_route(method, path, block, body_type: body)
+    def route(path, method: "GET", body: nil, hooks: nil, &block)
+      add_route(method, path, block, body_type: body, hooks: hooks)
+    end
+
+    # Start a group of routes sharing +prefix+ and the given tags, dependencies and hooks.
+    #
+    # Group hooks run after the global hooks and before the hooks of each route. The
+    # group is yielded when a block is given, and returned either way.
+    #
+    # @example
+    #   app.group("/admin", tags: ["admin"]) do |admin|
+    #     admin.get("/stats") { {ok: true} }
+    #   end
+    def group(prefix, tags: [], dependencies: [], hooks: nil)
+      route_group = RouteGroup.new(
+        self, Spikard::RouteGroup.new(prefix), tags: tags, dependencies: dependencies, hooks: hooks
+      )
+      yield route_group if block_given?
+      route_group
+    end
+
+    # Register a block run once before the listener binds.
+    #
+    # Blocks run in registration order; one that raises aborts boot, so +run+ raises
+    # instead of serving.
+    def on_startup(&block)
+      @registrations.push(["on_startup", ["on_startup"], block])
+      self
+    end
+
+    # Register a block run once after the server stops.
+    #
+    # Blocks run in reverse registration order, releasing resources in the opposite
+    # order startup blocks acquired them.
+    def on_shutdown(&block)
+      @registrations.push(["on_shutdown", ["on_shutdown"], block])
+      self
+    end
+
+    # Register a named readiness check run on every readiness probe.
+    #
+    # A block that raises reports the application as not ready, keyed by +name+ in the
+    # readiness report. Registering a check enables the health endpoints.
+    def health_check(name, &block)
+      @registrations.push(["health_check", [name], block])
+      self
+    end
+
+    # Register the block that keys rate limits configured with a custom key.
+    #
+    # The block is called for every limited request with a Hash of +"method"+, +"path"+,
+    # +"query"+, +"headers"+ and +"client_ip"+, and returns the bucket key; returning nil
+    # or raising falls back to the client IP.
+    def rate_limit_key_extractor(&block)
+      @registrations.push(["rate_limit_key_extractor", [], block])
+      self
+    end
+
+    # Register the block as the batch loader named +name+ for GraphQL field loaders.
+    #
+    # The block receives an Array of keys and returns one value per key, in order, with
+    # nil for a missing key; raising fails every key in the batch.
+    def data_loader(name, &block)
+      @registrations.push(["data_loader", [name], block])
+      self
     end
 
     # Run the HTTP server using the configured routes.
@@ -142,11 +214,13 @@ module Spikard
     private
 
     # Create a RouteBuilder with request and params schemas attached for Rust-side validation.
-    def create_route_builder_with_schemas(method_name, path, spec)
+    def create_route_builder_with_schemas(method_name, path, spec, group = nil)
       # Create the base RouteBuilder with the HTTP method and path.
       # method_name is the lowercase HTTP verb (e.g. "post"); the native
-      # RouteBuilder maps it to the core Method.
+      # RouteBuilder maps it to the core Method. A group prefixes the path and
+      # adds its settings.
       builder = Spikard::RouteBuilder.new(method_name, path)
+      builder = group.apply(builder) if group
       builder = builder.handler_name(spec.handler.respond_to?(:name) ? spec.handler.name : "handler")
 
       # Attach request schema for body validation if a body parameter exists.
@@ -168,6 +242,42 @@ module Spikard
       builder
     end
 
+    # Attach route-scoped lifecycle hooks to the builder.
+    def attach_route_hooks(builder, hooks)
+      (hooks || {}).each do |phase, phase_hooks|
+        raise ArgumentError, "Unknown lifecycle hook phase: #{phase.inspect}" unless HOOK_PHASES.include?(phase.to_sym)
+
+        Array(phase_hooks).each { |hook| builder = builder.public_send(phase.to_sym, hook) }
+      end
+      builder
+    end
+
+    # Read a request-context value the Rust core resolved for this request.
+    #
+    # +client+ is the client address, resolved through trusted proxies when configured.
+    # +claims+, +session+ and +csrf_token+ are handed over by the Rust middleware in
+    # internal headers, and are nil when that middleware is not configured.
+    def read_context(name, request_dict)
+      headers = request_dict["headers"] || {}
+      case name
+      when "client"
+        Spikard::ClientInfo.new(
+          ip: request_dict["client_ip"],
+          scheme: request_dict["scheme"] || "http",
+          host: request_dict["host"]
+        )
+      when "claims"
+        raw = headers["x-spikard-jwt-claims"]
+        raw && Spikard::Claims.new(**JSON.parse(raw, symbolize_names: true))
+      when "session"
+        raw = headers["x-spikard-session"]
+        raw && Spikard::Session.from_data(raw)
+      when "csrf_token"
+        raw = headers["x-spikard-csrf-token"]
+        raw && Spikard::CsrfToken.new(raw)
+      end
+    end
+
     # Create the synchronous Ruby callable registered with the Rust bridge for one route.
     #
     # The Rust bridge invokes the returned callable as adapter.call(request_data_hash)
@@ -181,7 +291,11 @@ module Spikard
       lambda do |request_dict|
         positional, kwargs = build_call_args(request_dict, spec)
         result = handler.call(*positional, **kwargs)
-        to_envelope(result)
+        envelope = to_envelope(result)
+        session = kwargs[:session]
+        next envelope unless session
+
+        envelope.merge(headers: envelope[:headers].merge(session.binding_headers))
       end
     end
 
@@ -234,6 +348,11 @@ module Spikard
         kwargs[binding.name.to_sym] = convert_value(raw, binding.target_type)
       end
 
+      # Keyword args: request-context values (+client:+, +claims:+, +session:+, +csrf_token:+)
+      spec.context_params.each do |name|
+        kwargs[name.to_sym] = read_context(name, request_dict)
+      end
+
       [positional, kwargs]
     end
 
@@ -334,5 +453,99 @@ module Spikard
         "string"
       end
     end
+
+    # Routes registered under a shared path prefix, created with +App#group+.
+    #
+    # Tags and dependencies accumulate and hooks run outermost group first; nested
+    # groups inherit everything configured on their parent.
+    class RouteGroup
+      def initialize(app, group, tags: [], dependencies: [], hooks: nil)
+        tags.each { |tag| group = group.tag(tag) }
+        group = group.handler_dependencies(dependencies) unless dependencies.empty?
+        @app = app
+        @group = group
+        @hooks = hooks || {}
+      end
+
+      # The path prefix shared by the routes of this group.
+      def prefix
+        @group.prefix
+      end
+
+      # Start a group nested under this one, yielded when a block is given.
+      def group(prefix, tags: [], dependencies: [], hooks: nil)
+        nested = RouteGroup.new(
+          @app, @group.group(prefix), tags: tags, dependencies: dependencies, hooks: merge_hooks(hooks)
+        )
+        yield nested if block_given?
+        nested
+      end
+
+      # Register a handler under the group, with the group's hooks ahead of the route's own.
+      def add_route(method, path, handler, body_type: nil, hooks: nil)
+        @app.add_route(method, path, handler, body_type: body_type, hooks: merge_hooks(hooks), group: @group)
+      end
+
+      # Register a GET route.
+      def get(path, hooks: nil, &block)
+        add_route("GET", path, block, hooks: hooks)
+      end
+
+      # Register a POST route with optional body DTO class.
+      def post(path, body: nil, hooks: nil, &block)
+        add_route("POST", path, block, body_type: body, hooks: hooks)
+      end
+
+      # Register a PUT route with optional body DTO class.
+      def put(path, body: nil, hooks: nil, &block)
+        add_route("PUT", path, block, body_type: body, hooks: hooks)
+      end
+
+      # Register a PATCH route with optional body DTO class.
+      def patch(path, body: nil, hooks: nil, &block)
+        add_route("PATCH", path, block, body_type: body, hooks: hooks)
+      end
+
+      # Register a DELETE route with optional body DTO class.
+      def delete(path, body: nil, hooks: nil, &block)
+        add_route("DELETE", path, block, body_type: body, hooks: hooks)
+      end
+
+      # Register a HEAD route.
+      def head(path, hooks: nil, &block)
+        add_route("HEAD", path, block, hooks: hooks)
+      end
+
+      # Register an OPTIONS route.
+      def options(path, hooks: nil, &block)
+        add_route("OPTIONS", path, block, hooks: hooks)
+      end
+
+      # Register a TRACE route.
+      def trace(path, hooks: nil, &block)
+        add_route("TRACE", path, block, hooks: hooks)
+      end
+
+      # Register a CONNECT route.
+      def connect(path, hooks: nil, &block)
+        add_route("CONNECT", path, block, hooks: hooks)
+      end
+
+      # Register a route with an explicit HTTP method (defaults to GET).
+      def route(path, method: "GET", body: nil, hooks: nil, &block)
+        add_route(method, path, block, body_type: body, hooks: hooks)
+      end
+
+      private
+
+      # Combine the group's hooks with +hooks+ per phase, the group's first.
+      def merge_hooks(hooks)
+        merged = @hooks.to_h { |phase, phase_hooks| [phase.to_sym, Array(phase_hooks)] }
+        (hooks || {}).each do |phase, phase_hooks|
+          merged[phase.to_sym] = merged.fetch(phase.to_sym, []) + Array(phase_hooks)
+        end
+        merged
+      end
+    end
   end
 end
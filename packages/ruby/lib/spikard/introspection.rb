@@ -28,13 +28,14 @@ module Spikard
 
     # Introspected, per-route metadata derived from a handler at registration time.
     class RouteSpec
-      attr_reader :handler, :bindings, :body_param_name, :body_type
+      attr_reader :handler, :bindings, :body_param_name, :body_type, :context_params
 
-      def initialize(handler, bindings, body_param_name, body_type)
+      def initialize(handler, bindings, body_param_name, body_type, context_params)
         @handler = handler
         @bindings = bindings
         @body_param_name = body_param_name
         @body_type = body_type
+        @context_params = context_params
       end
     end
 
@@ -106,6 +107,7 @@ module Spikard
 
       bindings = []
       body_param_name = nil
+      context_params = []
 
       # The first positional parameter (if any) becomes the body param when
       # body_type is provided. All other parameters become keyword-arg bindings.
@@ -131,6 +133,13 @@ module Spikard
           next
         end
 
+        # Keyword parameters named after request-context values are filled from the
+        # request context instead of the request data
+        if (param_type == :key || param_type == :keyreq) && CONTEXT_PARAM_NAMES.include?(param_name_str)
+          context_params << param_name_str
+          next
+        end
+
         # Keyword parameters become bindings (query, path, header, cookie)
         if param_type == :key || param_type == :keyreq
           # Determine source by name (path > query)
@@ -150,7 +159,7 @@ module Spikard
         end
       end
 
-      RouteSpec.new(handler, bindings, body_param_name, body_type)
+      RouteSpec.new(handler, bindings, body_param_name, body_type, context_params)
     end
 
     # Return the set of path-parameter names in path (handles {id} and {id:int}).
@@ -161,5 +170,6 @@ module Spikard
 
     BODYLESS_METHODS = Set.new(%w[GET HEAD OPTIONS TRACE CONNECT]).freeze
     SPECIAL_PARAM_NAMES = Set.new(%w[self]).freeze
+    CONTEXT_PARAM_NAMES = Set.new(%w[client claims session csrf_token]).freeze
   end
 end
@@ -28,6 +28,36 @@ def on_error(&block)
   self
 end
 
+def on_startup(&block)
+  # Called once before the listener binds; a failing hook aborts boot.
+  @registrations.push(["on_startup", ["on_startup"], block])
+  self
+end
+
+def on_shutdown(&block)
+  # Called once after graceful shutdown completes, in reverse registration order.
+  @registrations.push(["on_shutdown", ["on_shutdown"], block])
+  self
+end
+
+def health_check(name, &block)
+  # Readiness check run on every readiness probe under its own timeout; an error reports not ready.
+  @registrations.push(["health_check", [name], block])
+  self
+end
+
+def rate_limit_key_extractor(&block)
+  # Derives the bucket key for custom-keyed rate limits; returning nothing falls back to the client IP.
+  @registrations.push(["rate_limit_key_extractor", [], block])
+  self
+end
+
+def data_loader(name, &block)
+  # Named batch loader for GraphQL field loaders; returns one value per key, in order.
+  @registrations.push(["data_loader", [name], block])
+  self
+end
+
 def websocket(path, &block)
   # Register a WebSocket upgrade handler at the given path.
   @registrations.push(["websocket", [path], block])